@@ -0,0 +1,89 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use orbtk_api::{
+    prelude::*,
+    theme::default_theme,
+    utils::{Brush, CornerRadius, Thickness},
+    World,
+};
+
+// Number of themed widgets of the benchmark tree.
+const WIDGETS: usize = 1000;
+
+// Resolves the style of the given widgets, widgets with an unchanged selector are skipped.
+fn update_styles(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entities: &[Entity],
+    theme: &ThemeValue,
+) {
+    for entity in entities {
+        WidgetContainer::new(*entity, ecm, theme).update_properties_by_theme();
+    }
+}
+
+// Marks the selectors of the given widgets as dirty like a theme switch.
+fn mark_dirty(ecm: &mut EntityComponentManager<Tree, StringComponentStore>, entities: &[Entity]) {
+    for entity in entities {
+        ecm.component_store_mut()
+            .get_mut::<Selector>("selector", *entity)
+            .unwrap()
+            .set_dirty(true);
+    }
+}
+
+fn bench_style(c: &mut Criterion) {
+    let theme = default_theme();
+    let mut world = World::from_stores(Tree::default(), StringComponentStore::default());
+    let ecm = world.entity_component_manager();
+    let root = ecm.create_entity().build();
+    let mut entities = vec![];
+
+    // a window with 1,000 buttons with the properties that are read from the theme
+    for _ in 0..WIDGETS {
+        let entity = ecm.create_entity().build();
+        ecm.entity_store_mut().append_child(root, entity).unwrap();
+
+        let store = ecm.component_store_mut();
+        store.register("selector", entity, Selector::from("button"));
+        store.register("foreground", entity, Brush::default());
+        store.register("background", entity, Brush::default());
+        store.register("border_brush", entity, Brush::default());
+        store.register("border_radius", entity, CornerRadius::default());
+        store.register("border_width", entity, Thickness::default());
+        store.register("opacity", entity, 1.0_f32);
+        store.register("font_size", entity, 0.0_f64);
+        store.register("font_family", entity, String::new());
+        store.register("padding", entity, Thickness::default());
+
+        entities.push(entity);
+    }
+
+    update_styles(ecm, &entities, &theme);
+
+    c.bench_function("resolve styles of all 1000 widgets", |b| {
+        b.iter(|| {
+            mark_dirty(ecm, &entities);
+            update_styles(ecm, &entities, &theme);
+        })
+    });
+
+    c.bench_function("resolve styles of 1 changed of 1000 widgets", |b| {
+        let mut active = false;
+
+        b.iter(|| {
+            active = !active;
+            let mut widget = WidgetContainer::new(entities[0], ecm, &theme);
+
+            if active {
+                add_selector_to_widget("active", &mut widget);
+            } else {
+                remove_selector_from_widget("active", &mut widget);
+            }
+
+            update_styles(ecm, &entities, &theme);
+        })
+    });
+}
+
+criterion_group!(benches, bench_style);
+criterion_main!(benches);
@@ -0,0 +1,244 @@
+use std::{
+    collections::VecDeque,
+    fmt::Write as FmtWrite,
+    fs::{create_dir_all, File},
+    io::Write,
+    panic,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use dces::prelude::{Entity, EntityComponentManager, StringComponentStore};
+
+use crate::{css_engine::Selector, tree::Tree, utils::Rectangle};
+
+// Number of frames that are used to calculate the average frame time.
+const FRAME_TIME_COUNT: usize = 60;
+
+// The widget tree dump is refreshed at least after this number of frames.
+const TREE_DUMP_INTERVAL: u64 = 60;
+
+struct CrashReportData {
+    directory: PathBuf,
+    max_events: usize,
+    theme_name: String,
+    events: VecDeque<String>,
+    tree_dump: String,
+    tree_len: usize,
+    frames: u64,
+    last_frame: Option<Instant>,
+    frame_times: VecDeque<Duration>,
+}
+
+/// The `CrashReporter` writes a crash report bundle to disk if the application panics. The bundle
+/// is a directory that contains the panic message, the theme name, frame statistics, the last
+/// input events and a dump of the widget tree, so it could be attached to an issue.
+///
+/// Crash reports are opt-in, use `Application::crash_reporter` to enable it.
+#[derive(Clone)]
+pub struct CrashReporter {
+    data: Arc<Mutex<CrashReportData>>,
+}
+
+impl CrashReporter {
+    /// Creates a new crash reporter that writes the bundles into the given directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        CrashReporter {
+            data: Arc::new(Mutex::new(CrashReportData {
+                directory: directory.into(),
+                max_events: 50,
+                theme_name: "default".to_string(),
+                events: VecDeque::new(),
+                tree_dump: String::new(),
+                tree_len: 0,
+                frames: 0,
+                last_frame: None,
+                frame_times: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Sets the number of the last input events that are written to the report.
+    pub fn max_events(self, max_events: usize) -> Self {
+        self.data.lock().unwrap().max_events = max_events;
+        self
+    }
+
+    /// Sets the name of the used theme that is written to the report.
+    pub fn theme_name(self, theme_name: impl Into<String>) -> Self {
+        self.data.lock().unwrap().theme_name = theme_name.into();
+        self
+    }
+
+    /// Installs the panic hook that writes the report. The previous hook is called afterwards.
+    pub fn install(&self) {
+        let previous_hook = panic::take_hook();
+        let reporter = self.clone();
+
+        panic::set_hook(Box::new(move |info| {
+            match reporter.write_report(&info.to_string()) {
+                Ok(path) => eprintln!("Crash report is written to {}", path.display()),
+                Err(message) => eprintln!("{}", message),
+            }
+
+            previous_hook(info);
+        }));
+    }
+
+    /// Records an input event.
+    pub fn record_event(&self, event: impl Into<String>) {
+        if let Ok(mut data) = self.data.lock() {
+            if data.max_events == 0 {
+                return;
+            }
+
+            if data.events.len() == data.max_events {
+                data.events.pop_front();
+            }
+
+            data.events.push_back(event.into());
+        }
+    }
+
+    /// Records the time of a frame and refreshes the dump of the widget tree if it has changed.
+    pub fn record_frame(&self, ecm: &EntityComponentManager<Tree, StringComponentStore>) {
+        let mut data = match self.data.lock() {
+            Ok(data) => data,
+            Err(_) => return,
+        };
+
+        let now = Instant::now();
+
+        if let Some(last_frame) = data.last_frame {
+            if data.frame_times.len() == FRAME_TIME_COUNT {
+                data.frame_times.pop_front();
+            }
+
+            data.frame_times.push_back(now - last_frame);
+        }
+
+        data.last_frame = Some(now);
+        data.frames += 1;
+
+        let tree_len = ecm.entity_store().len();
+
+        if tree_len != data.tree_len || data.frames % TREE_DUMP_INTERVAL == 1 {
+            data.tree_len = tree_len;
+            data.tree_dump = dump_tree(ecm);
+        }
+    }
+
+    /// Writes the report bundle and returns the path of the bundle directory.
+    pub fn write_report(&self, panic_message: &str) -> Result<PathBuf, String> {
+        // the data could be poisoned if the panic occurs while recording
+        let data = match self.data.lock() {
+            Ok(data) => data,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let path = data.directory.join(format!("crash-{}", timestamp));
+
+        create_dir_all(&path).map_err(|e| {
+            format!(
+                "CrashReporter.write_report: Could not create directory {}: {}",
+                path.display(),
+                e
+            )
+        })?;
+
+        let average_frame_time = if data.frame_times.is_empty() {
+            0.0
+        } else {
+            data.frame_times.iter().sum::<Duration>().as_secs_f64() * 1000.0
+                / data.frame_times.len() as f64
+        };
+
+        let mut report = String::new();
+        let _ = writeln!(report, "panic: {}", panic_message);
+        let _ = writeln!(report, "theme: {}", data.theme_name);
+        let _ = writeln!(report, "frames: {}", data.frames);
+        let _ = writeln!(report, "average frame time: {:.2} ms", average_frame_time);
+
+        let mut events = String::new();
+
+        for event in &data.events {
+            let _ = writeln!(events, "{}", event);
+        }
+
+        write_file(&path, "report.txt", &report)?;
+        write_file(&path, "events.txt", &events)?;
+        write_file(&path, "tree.txt", &data.tree_dump)?;
+
+        Ok(path)
+    }
+}
+
+fn write_file(directory: &PathBuf, name: &str, content: &str) -> Result<(), String> {
+    let path = directory.join(name);
+
+    File::create(&path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|e| {
+            format!(
+                "CrashReporter.write_report: Could not write file {}: {}",
+                path.display(),
+                e
+            )
+        })
+}
+
+// Writes each widget of the tree with its name, selector and bounds in a line.
+fn dump_tree(ecm: &EntityComponentManager<Tree, StringComponentStore>) -> String {
+    let mut dump = String::new();
+    dump_node(ecm, ecm.entity_store().root(), 0, &mut dump);
+    dump
+}
+
+fn dump_node(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    depth: usize,
+    dump: &mut String,
+) {
+    let store = ecm.component_store();
+
+    let name = store
+        .get::<String>("name", entity)
+        .map(|name| name.clone())
+        .unwrap_or_else(|_| "unnamed".to_string());
+
+    let selector = store
+        .get::<Selector>("selector", entity)
+        .map(|selector| selector.to_string())
+        .unwrap_or_default();
+
+    let bounds = store
+        .get::<Rectangle>("bounds", entity)
+        .map(|bounds| *bounds)
+        .unwrap_or_default();
+
+    let _ = writeln!(
+        dump,
+        "{}{} {:?} {} ({}, {}, {}, {})",
+        "  ".repeat(depth),
+        name,
+        entity,
+        selector,
+        bounds.x(),
+        bounds.y(),
+        bounds.width(),
+        bounds.height()
+    );
+
+    if let Some(children) = ecm.entity_store().children.get(&entity) {
+        for child in children {
+            dump_node(ecm, *child, depth + 1, dump);
+        }
+    }
+}
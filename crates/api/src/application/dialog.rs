@@ -0,0 +1,190 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context as TaskContext, Poll, Waker},
+};
+
+use dces::prelude::Entity;
+
+use crate::prelude::*;
+
+type DialogBuilder = Box<dyn FnOnce(&mut BuildContext) -> Entity + 'static>;
+
+type DialogCallback = Box<dyn FnOnce(&mut Context<'_>, Option<Box<dyn Any>>) + 'static>;
+
+/// Describes a modal dialog that is requested by `Context::show_dialog`. The dialog is shown by
+/// the `DialogHost` of the window until it is closed with a result or canceled.
+pub struct DialogRequest {
+    builder: RefCell<Option<DialogBuilder>>,
+    on_close: RefCell<Option<DialogCallback>>,
+    result: RefCell<Option<Box<dyn Any>>>,
+    closed: Cell<bool>,
+}
+
+impl DialogRequest {
+    /// Creates a new dialog with the builder of its content and the callback that is called with
+    /// the result after the dialog is closed.
+    pub fn new<B, F>(builder: B, on_close: F) -> Self
+    where
+        B: FnOnce(&mut BuildContext) -> Entity + 'static,
+        F: FnOnce(&mut Context<'_>, Option<Box<dyn Any>>) + 'static,
+    {
+        DialogRequest {
+            builder: RefCell::new(Some(Box::new(builder))),
+            on_close: RefCell::new(Some(Box::new(on_close))),
+            result: RefCell::new(None),
+            closed: Cell::new(false),
+        }
+    }
+
+    /// Builds the content of the dialog. Returns `None` if the content is already built.
+    pub fn build(&self, ctx: &mut BuildContext) -> Option<Entity> {
+        let builder = self.builder.borrow_mut().take()?;
+        Some(builder(ctx))
+    }
+
+    /// Closes the dialog with the given result, `None` cancels the dialog. Only the first result
+    /// of a dialog is kept.
+    pub fn close(&self, result: Option<Box<dyn Any>>) {
+        if self.closed.get() {
+            return;
+        }
+
+        *self.result.borrow_mut() = result;
+        self.closed.set(true);
+    }
+
+    /// Returns `true` if the dialog is closed or canceled.
+    pub fn is_closed(&self) -> bool {
+        self.closed.get()
+    }
+
+    /// Calls the callback of a closed dialog with its result. Called by the dialog host after
+    /// the dialog is removed.
+    pub fn finish(&self, ctx: &mut Context<'_>) {
+        if !self.closed.get() {
+            return;
+        }
+
+        if let Some(on_close) = self.on_close.borrow_mut().take() {
+            on_close(ctx, self.result.borrow_mut().take());
+        }
+    }
+}
+
+struct DialogFutureState<R> {
+    result: Option<Option<R>>,
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+// Completes the future of a dialog. If the callback of the dialog is dropped without being
+// called, e.g. the window is closed, the future is completed without result.
+pub(crate) struct DialogCompleter<R> {
+    state: Arc<Mutex<DialogFutureState<R>>>,
+}
+
+impl<R> DialogCompleter<R> {
+    pub(crate) fn complete(&self, result: Option<R>) {
+        let mut state = self.state.lock().unwrap();
+
+        if state.completed {
+            return;
+        }
+
+        state.result = Some(result);
+        state.completed = true;
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<R> Drop for DialogCompleter<R> {
+    fn drop(&mut self) {
+        self.complete(None);
+    }
+}
+
+/// Future that is returned by `Context::show_dialog_async` and resolves with the result of the
+/// dialog after it is closed, `None` if the dialog is canceled.
+pub struct DialogFuture<R> {
+    state: Arc<Mutex<DialogFutureState<R>>>,
+}
+
+impl<R> DialogFuture<R> {
+    pub(crate) fn new() -> (Self, DialogCompleter<R>) {
+        let state = Arc::new(Mutex::new(DialogFutureState {
+            result: None,
+            completed: false,
+            waker: None,
+        }));
+
+        (
+            DialogFuture {
+                state: state.clone(),
+            },
+            DialogCompleter { state },
+        )
+    }
+}
+
+impl<R> Future for DialogFuture<R> {
+    type Output = Option<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(result) = state.result.take() {
+            return Poll::Ready(result);
+        }
+
+        if state.completed {
+            return Poll::Ready(None);
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::{RawWaker, RawWakerVTable};
+
+    use super::*;
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    }
+
+    fn poll<R>(future: &mut DialogFuture<R>) -> Poll<Option<R>> {
+        let waker = noop_waker();
+        Pin::new(future).poll(&mut TaskContext::from_waker(&waker))
+    }
+
+    #[test]
+    fn test_dialog_future() {
+        let (mut future, completer) = DialogFuture::new();
+        assert_eq!(poll(&mut future), Poll::Pending);
+
+        completer.complete(Some(42));
+        assert_eq!(poll(&mut future), Poll::Ready(Some(42)));
+
+        // a dropped completer cancels the dialog
+        let (mut future, completer) = DialogFuture::<usize>::new();
+        drop(completer);
+        assert_eq!(poll(&mut future), Poll::Ready(None));
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use dces::prelude::Entity;
+
+use crate::{prelude::*, shell::ShellRequest};
+
+/// Pushes an event that is sent from an other thread into the event queue of the window.
+pub type SentEvent = Box<dyn FnOnce(&mut EventQueue) + Send + 'static>;
+
+/// Queues the events of all `EventSender`s of a window until they are moved into the event
+/// queue on the ui thread.
+pub struct SentEventQueue {
+    sender: Sender<SentEvent>,
+    receiver: Receiver<SentEvent>,
+}
+
+impl Default for SentEventQueue {
+    fn default() -> Self {
+        let (sender, receiver) = channel();
+        SentEventQueue { sender, receiver }
+    }
+}
+
+impl SentEventQueue {
+    /// Returns a sender that could be used to enqueue events.
+    pub fn sender(&self) -> Sender<SentEvent> {
+        self.sender.clone()
+    }
+
+    /// Moves all enqueued events into the given event queue. Returns `true` if events are moved.
+    pub fn dequeue_into(&self, event_queue: &mut EventQueue) -> bool {
+        let mut moved = false;
+
+        for push in self.receiver.try_iter() {
+            push(event_queue);
+            moved = true;
+        }
+
+        moved
+    }
+}
+
+/// The `EventSender` could be cloned and moved to other threads, e.g. a hardware callback or a
+/// network reader, to push application defined events into the event queue of the window. The
+/// event loop is woken up for each sent event.
+///
+/// The events are handled on the ui thread, e.g. with `CustomHandler::on_event`.
+///
+/// Use `Context::event_sender` to get a sender for the current widget.
+#[derive(Clone)]
+pub struct EventSender {
+    entity: Entity,
+    event_sender: Sender<SentEvent>,
+    request_sender: Sender<ShellRequest>,
+}
+
+impl EventSender {
+    /// Creates a new event sender for the given widget.
+    pub fn new(
+        entity: Entity,
+        event_sender: Sender<SentEvent>,
+        request_sender: Sender<ShellRequest>,
+    ) -> Self {
+        EventSender {
+            entity,
+            event_sender,
+            request_sender,
+        }
+    }
+
+    /// Returns the widget the events are sent to.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns a new sender for an other widget of the same window.
+    pub fn for_entity(&self, entity: Entity) -> EventSender {
+        EventSender {
+            entity,
+            ..self.clone()
+        }
+    }
+
+    /// Sends the event directly to the handlers of the widget. Returns an error if the window is
+    /// closed.
+    pub fn send<E: Event + Send>(&self, event: E) -> Result<(), String> {
+        self.send_with_strategy(event, EventStrategy::Direct)
+            .map_err(|_| "EventSender.send: The window is closed.".to_string())
+    }
+
+    /// Sends the event to the handlers of the widget and its children, the most inner handler is
+    /// called first. Returns an error if the window is closed.
+    pub fn broadcast<E: Event + Send>(&self, event: E) -> Result<(), String> {
+        self.send_with_strategy(event, EventStrategy::BottomUp)
+            .map_err(|_| "EventSender.broadcast: The window is closed.".to_string())
+    }
+
+    fn send_with_strategy<E: Event + Send>(
+        &self,
+        event: E,
+        strategy: EventStrategy,
+    ) -> Result<(), ()> {
+        let entity = self.entity;
+
+        self.event_sender
+            .send(Box::new(move |event_queue: &mut EventQueue| {
+                event_queue.register_event_with_strategy(event, strategy, entity)
+            }))
+            .map_err(|_| ())?;
+        let _ = self.request_sender.send(ShellRequest::Update);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    struct ReadingEvent(f64);
+
+    impl Event for ReadingEvent {}
+
+    #[test]
+    fn test_send() {
+        let queue = SentEventQueue::default();
+        let (request_sender, requests) = channel();
+        let sender = EventSender::new(Entity(1), queue.sender(), request_sender);
+
+        let other = sender.clone();
+        thread::spawn(move || {
+            other.send(ReadingEvent(21.5)).unwrap();
+            other
+                .for_entity(Entity(0))
+                .broadcast(ReadingEvent(0.0))
+                .unwrap();
+        })
+        .join()
+        .unwrap();
+
+        let mut event_queue = EventQueue::default();
+        assert!(queue.dequeue_into(&mut event_queue));
+        assert!(!queue.dequeue_into(&mut event_queue));
+        assert_eq!(event_queue.len(), 2);
+        assert_eq!(requests.try_iter().count(), 2);
+
+        let event = event_queue.dequeue().unwrap();
+        assert_eq!(event.source, Entity(1));
+        assert_eq!(event.strategy, EventStrategy::Direct);
+        assert_eq!(
+            event.downcast::<ReadingEvent>().ok().map(|e| e.0),
+            Some(21.5)
+        );
+
+        let event = event_queue.dequeue().unwrap();
+        assert_eq!(event.source, Entity(0));
+        assert_eq!(event.strategy, EventStrategy::BottomUp);
+    }
+
+    #[test]
+    fn test_closed_window() {
+        let (request_sender, _) = channel();
+        let sender = {
+            let queue = SentEventQueue::default();
+            EventSender::new(Entity(1), queue.sender(), request_sender)
+        };
+
+        assert!(sender.send(ReadingEvent(0.0)).is_err());
+    }
+}
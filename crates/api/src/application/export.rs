@@ -0,0 +1,196 @@
+use std::path::{Path, PathBuf};
+
+use dces::prelude::Entity;
+
+use crate::utils::Rectangle;
+
+/// Describes the file format of an export.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExportFormat {
+    /// Image with the pixels of the widgets.
+    Png,
+
+    /// Document with the drawing commands of the widgets as vector graphic. Images are left out.
+    Pdf,
+}
+
+impl ExportFormat {
+    /// Returns the format by the extension of the given path, `Png` if it is not `pdf`.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("pdf") => ExportFormat::Pdf,
+            _ => ExportFormat::Png,
+        }
+    }
+}
+
+type ExportCallback = Box<dyn FnOnce(Result<(), String>) + 'static>;
+
+/// Describes an export of the rendered widgets of a window into a file, that is requested by
+/// `Context::export`. The widgets are rendered offscreen with their current layout, the window
+/// itself is not changed.
+pub struct ExportRequest {
+    pub(crate) entity: Option<Entity>,
+    pub(crate) path: PathBuf,
+    pub(crate) format: ExportFormat,
+    scale: f64,
+    size: Option<(u32, u32)>,
+    on_finish: Option<ExportCallback>,
+}
+
+impl ExportRequest {
+    /// Creates an export of the whole window into the file with the given path. The format is
+    /// chosen by the extension of the path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+
+        ExportRequest {
+            entity: None,
+            format: ExportFormat::from_path(&path),
+            path,
+            scale: 1.0,
+            size: None,
+            on_finish: None,
+        }
+    }
+
+    /// Exports only the given widget and its children.
+    pub fn entity(mut self, entity: Entity) -> Self {
+        self.entity = Some(entity);
+        self
+    }
+
+    /// Sets the format of the file.
+    pub fn format(mut self, format: ExportFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the number of pixels per pixel of the window, e.g. `2.0` for a sharp print.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the resolution of the export in dots per inch, a window has `96` dots per inch.
+    pub fn dpi(self, dpi: f64) -> Self {
+        self.scale(dpi / 96.0)
+    }
+
+    /// Sets the size of the image in pixels. The widget is scaled to fit into the image and
+    /// centered.
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Sets the callback that is called with the result after the file is written.
+    pub fn on_finish<F: FnOnce(Result<(), String>) + 'static>(mut self, on_finish: F) -> Self {
+        self.on_finish = Some(Box::new(on_finish));
+        self
+    }
+
+    // Returns the number of points of the PDF page per pixel of the export, a window has 96
+    // pixels and a page 72 points per inch.
+    pub(crate) fn points_per_pixel(&self) -> f64 {
+        72.0 / (96.0 * self.scale)
+    }
+
+    // Returns the size of the image in pixels and the scale and offset that move the given
+    // bounds of the widget in window coordinates into the image.
+    pub(crate) fn transform(
+        &self,
+        bounds: Rectangle,
+    ) -> Result<((u32, u32), f64, (f64, f64)), String> {
+        export_transform(bounds, self.scale, self.size)
+    }
+
+    // Calls the callback with the result of the export or prints the error if there is no
+    // callback.
+    pub(crate) fn finish(self, result: Result<(), String>) {
+        match self.on_finish {
+            Some(on_finish) => on_finish(result),
+            None => {
+                if let Err(error) = result {
+                    eprintln!("Export: {}", error);
+                }
+            }
+        }
+    }
+}
+
+// Returns the size of the image in pixels and the scale and offset that map the bounds into it.
+// A given size scales the widget uniformly to fit into the image and centers it.
+fn export_transform(
+    bounds: Rectangle,
+    scale: f64,
+    size: Option<(u32, u32)>,
+) -> Result<((u32, u32), f64, (f64, f64)), String> {
+    if bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+        return Err("Could not export a widget without size.".to_string());
+    }
+
+    let (width, height, scale) = match size {
+        Some((width, height)) => (
+            width,
+            height,
+            (width as f64 / bounds.width()).min(height as f64 / bounds.height()),
+        ),
+        None => (
+            (bounds.width() * scale).ceil() as u32,
+            (bounds.height() * scale).ceil() as u32,
+            scale,
+        ),
+    };
+
+    if width == 0 || height == 0 || scale <= 0.0 {
+        return Err("Could not export into an empty image.".to_string());
+    }
+
+    let offset = (
+        (width as f64 - bounds.width() * scale) / 2.0 - bounds.x() * scale,
+        (height as f64 - bounds.height() * scale) / 2.0 - bounds.y() * scale,
+    );
+
+    Ok(((width, height), scale, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_format() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("report.PDF")),
+            ExportFormat::Pdf
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("screenshot.png")),
+            ExportFormat::Png
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("export")),
+            ExportFormat::Png
+        );
+    }
+
+    #[test]
+    fn test_export_transform() {
+        let bounds = Rectangle::new(10.0, 20.0, 100.0, 50.0);
+
+        assert_eq!(
+            export_transform(bounds, 2.0, None),
+            Ok(((200, 100), 2.0, (-20.0, -40.0)))
+        );
+
+        // the widget fits into the size and is centered
+        assert_eq!(
+            export_transform(bounds, 1.0, Some((50, 50))),
+            Ok(((50, 50), 0.5, (-5.0, 2.5)))
+        );
+
+        assert!(export_transform(Rectangle::new(0.0, 0.0, 0.0, 10.0), 1.0, None).is_err());
+        assert!(export_transform(bounds, 0.0, None).is_err());
+    }
+}
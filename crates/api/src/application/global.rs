@@ -1,8 +1,8 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc, time::Duration};
 
 use dces::prelude::Entity;
 
-use crate::shell::Key;
+use crate::{prelude::DialogRequest, shell::Key};
 
 #[derive(Default, Clone)]
 /// The `Global` struct is used to define global `properties` that could be access application width.
@@ -15,6 +15,47 @@ pub struct Global {
 
     /// Stores the state of the keyboard
     pub keyboard_state: KeyboardState,
+
+    /// Contains the toasts that are requested by `Context::show_toast` and not shown yet.
+    pub toasts: Vec<ToastMessage>,
+
+    /// Contains the navigation requests of `Context::navigate` and `Context::navigate_back` that
+    /// are not handled yet.
+    pub navigation_requests: Vec<NavigationRequest>,
+
+    /// Contains the modal dialogs that are requested by `Context::show_dialog` and not closed
+    /// yet, the top most dialog is the last one.
+    pub dialogs: Vec<Rc<DialogRequest>>,
+}
+
+/// Describes a request to the `NavigationView` of a window.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NavigationRequest {
+    /// Pushes the screen with the given name.
+    Navigate(String),
+
+    /// Pops the current screen and shows the screen below it.
+    Back,
+}
+
+/// Describes a transient notification that is shown by `Context::show_toast`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastMessage {
+    /// Text of the notification.
+    pub text: String,
+
+    /// Time the notification is shown.
+    pub duration: Duration,
+}
+
+impl ToastMessage {
+    /// Creates a new notification with the given text that is shown for the given duration.
+    pub fn new(text: impl Into<String>, duration: Duration) -> Self {
+        ToastMessage {
+            text: text.into(),
+            duration,
+        }
+    }
 }
 
 #[derive(Clone, Default)]
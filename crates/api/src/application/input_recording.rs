@@ -0,0 +1,273 @@
+use std::{
+    collections::VecDeque,
+    fs::{read_to_string, File},
+    io::{BufWriter, Write},
+    path::Path,
+    time::Instant,
+};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::shell::ShellEvent;
+
+use super::ManualRunner;
+
+/// Input event of a recording with the frame and the time in milliseconds after the start of the
+/// recording, when it was received by the window.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    pub frame: u64,
+
+    pub time: u64,
+
+    pub event: ShellEvent,
+}
+
+/// The `InputRecorder` writes the input events of the windows of an application to a file, one
+/// `RecordedEvent` per line. Use `Application::record_input` to enable it.
+pub struct InputRecorder {
+    writer: Box<dyn Write>,
+    start: Instant,
+    frame: u64,
+}
+
+impl InputRecorder {
+    /// Creates a recorder that writes into the file with the given path. An existing file is
+    /// replaced.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, String> {
+        let file = File::create(path.as_ref()).map_err(|e| {
+            format!(
+                "InputRecorder.create: Could not create file {}: {}",
+                path.as_ref().display(),
+                e
+            )
+        })?;
+
+        Ok(InputRecorder::from_writer(BufWriter::new(file)))
+    }
+
+    /// Creates a recorder that writes into the given writer.
+    pub fn from_writer(writer: impl Write + 'static) -> Self {
+        InputRecorder {
+            writer: Box::new(writer),
+            start: Instant::now(),
+            frame: 0,
+        }
+    }
+
+    /// Records the events of the next frame. Frames without events are counted, but nothing is
+    /// written.
+    pub fn record(&mut self, events: &[ShellEvent]) -> Result<(), String> {
+        let frame = self.frame;
+        let time = self.start.elapsed().as_millis() as u64;
+        self.frame += 1;
+
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        for event in events {
+            let line = ron::ser::to_string(&RecordedEvent {
+                frame,
+                time,
+                event: event.clone(),
+            })
+            .map_err(|e| format!("InputRecorder.record: Could not serialize event: {}", e))?;
+
+            writeln!(self.writer, "{}", line)
+                .map_err(|e| format!("InputRecorder.record: Could not write event: {}", e))?;
+        }
+
+        // flushed after each frame, the recording of a crashed application is still complete
+        self.writer
+            .flush()
+            .map_err(|e| format!("InputRecorder.record: Could not write event: {}", e))
+    }
+}
+
+/// The `InputReplay` passes the events of a recording to an application, e.g. to reproduce a bug
+/// or as regression test of an interaction.
+///
+/// A live window replays the events at their recorded time with `Application::replay_input`.
+/// A headless runner replays them deterministically frame by frame with `run`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputReplay {
+    events: VecDeque<RecordedEvent>,
+    frame: u64,
+    start: Option<Instant>,
+}
+
+impl InputReplay {
+    /// Loads the recording from the file with the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let text = read_to_string(path.as_ref()).map_err(|e| {
+            format!(
+                "InputReplay.load: Could not read file {}: {}",
+                path.as_ref().display(),
+                e
+            )
+        })?;
+
+        InputReplay::parse(&text)
+    }
+
+    /// Parses a recording with one `RecordedEvent` per line. Empty lines are skipped.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut events = vec![];
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            events.push(ron::de::from_str(line).map_err(|e| {
+                format!(
+                    "InputReplay.parse: Could not parse event in line {}: {}",
+                    index + 1,
+                    e
+                )
+            })?);
+        }
+
+        Ok(InputReplay::from_events(events))
+    }
+
+    /// Creates a replay of the given events.
+    pub fn from_events(events: impl IntoIterator<Item = RecordedEvent>) -> Self {
+        InputReplay {
+            events: events.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the number of events that are not replayed yet.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if all events are replayed.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the events of the next frame of the recording.
+    pub fn next_frame(&mut self) -> Vec<ShellEvent> {
+        let mut events = vec![];
+
+        while self
+            .events
+            .front()
+            .map_or(false, |event| event.frame <= self.frame)
+        {
+            if let Some(recorded_event) = self.events.pop_front() {
+                events.push(recorded_event.event);
+            }
+        }
+
+        self.frame += 1;
+        events
+    }
+
+    /// Returns the events whose recorded time is elapsed since the first call. Used as event
+    /// source of a live window.
+    pub fn poll_events(&mut self) -> Vec<ShellEvent> {
+        let elapsed = self.start.get_or_insert_with(Instant::now).elapsed();
+        let mut events = vec![];
+
+        while self
+            .events
+            .front()
+            .map_or(false, |event| event.time <= elapsed.as_millis() as u64)
+        {
+            if let Some(recorded_event) = self.events.pop_front() {
+                events.push(recorded_event.event);
+            }
+        }
+
+        events
+    }
+
+    /// Replays the remaining events on the given runner, each recorded frame is one update of the
+    /// runner. Returns `false` if the application is quit.
+    pub fn run(&mut self, runner: &mut ManualRunner) -> bool {
+        while !self.is_empty() {
+            for event in self.next_frame() {
+                runner.handle_event(event);
+            }
+
+            if !runner.update() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, io, rc::Rc};
+
+    use super::*;
+    use crate::shell::{ButtonState, MouseButton, MouseEvent};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let buffer = Buffer::default();
+        let mut recorder = InputRecorder::from_writer(buffer.clone());
+        let click = ShellEvent::MouseEvent(MouseEvent {
+            x: 4.0,
+            y: 2.0,
+            button: MouseButton::Left,
+            state: ButtonState::Down,
+        });
+
+        recorder
+            .record(&[ShellEvent::Mouse(4.0, 2.0), click.clone()])
+            .unwrap();
+        recorder.record(&[]).unwrap();
+        recorder
+            .record(&[ShellEvent::TextInput("a\nb".to_string())])
+            .unwrap();
+
+        let text = String::from_utf8(buffer.0.borrow().clone()).unwrap();
+        assert_eq!(text.lines().count(), 3);
+
+        let mut replay = InputReplay::parse(&text).unwrap();
+        assert_eq!(replay.len(), 3);
+        assert_eq!(
+            replay.next_frame(),
+            vec![ShellEvent::Mouse(4.0, 2.0), click]
+        );
+        assert!(replay.next_frame().is_empty());
+        assert_eq!(
+            replay.next_frame(),
+            vec![ShellEvent::TextInput("a\nb".to_string())]
+        );
+        assert!(replay.is_empty());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(InputReplay::parse("\n(frame: 0, time: 0, event: Quit)\n").is_ok());
+        assert_eq!(
+            InputReplay::parse("(frame: 0, time: 0, event: Quit)\nfoo")
+                .err()
+                .map(|e| e.starts_with("InputReplay.parse: Could not parse event in line 2")),
+            Some(true)
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    prelude::*,
+    render::RenderTarget,
+    shell::{
+        ButtonState, EventSource, MouseButton, MouseEvent, Presenter, ShellEvent, ShellRunner,
+        WindowBackend, WindowFactory, WindowSettings,
+    },
+    utils::Rectangle,
+};
+
+// Stores the last presented frame of an embedded window.
+#[derive(Default)]
+struct Frame {
+    data: Vec<u32>,
+    width: usize,
+    height: usize,
+}
+
+// Creates backends without a native window, that share their frames with the manual runner.
+struct EmbeddedWindowFactory {
+    frame: Rc<RefCell<Frame>>,
+}
+
+impl WindowFactory for EmbeddedWindowFactory {
+    fn create(&mut self, settings: &WindowSettings) -> Result<Box<dyn WindowBackend>, String> {
+        Ok(Box::new(EmbeddedBackend {
+            frame: self.frame.clone(),
+            bounds: settings.bounds,
+        }))
+    }
+}
+
+struct EmbeddedBackend {
+    frame: Rc<RefCell<Frame>>,
+    bounds: Rectangle,
+}
+
+impl EventSource for EmbeddedBackend {
+    // the events are passed by the embedder with `ManualRunner::handle_event`
+    fn poll_events(&mut self) -> Vec<ShellEvent> {
+        vec![]
+    }
+}
+
+impl Presenter for EmbeddedBackend {
+    fn present(&mut self, data: &[u32], width: usize, height: usize) {
+        let mut frame = self.frame.borrow_mut();
+        frame.data.clear();
+        frame.data.extend_from_slice(data);
+        frame.width = width;
+        frame.height = height;
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (self.bounds.width as usize, self.bounds.height as usize)
+    }
+}
+
+/// The `ManualRunner` embeds the first window of an application into an external main loop,
+/// e.g. of a game or a winit application. It is returned by `Application::run_manual`.
+///
+/// Pass the input events of the loop with `handle_event` and call `draw` once per frame.
+///
+/// Without a draw target the runner could also be used to run an application headless, e.g. in
+/// integration tests: script the input with `click` and `text_input`, call `update` and read the
+/// resulting state with `query`. With `Application::run_stepped` the runner uses a manual clock,
+/// that is advanced with `advance`.
+pub struct ManualRunner {
+    runner: ShellRunner<WindowAdapter>,
+    frame: Rc<RefCell<Frame>>,
+    events: Vec<ShellEvent>,
+    clock: Clock,
+}
+
+impl ManualRunner {
+    pub(crate) fn new(
+        application: &Application,
+        create_fn: &dyn Fn(&mut BuildContext) -> Entity,
+    ) -> Self {
+        let frame = Rc::new(RefCell::new(Frame::default()));
+
+        let runner = application.create_runner(
+            create_fn,
+            Some(Box::new(EmbeddedWindowFactory {
+                frame: frame.clone(),
+            })),
+        );
+
+        ManualRunner {
+            runner,
+            frame,
+            events: vec![],
+            clock: application.clock.clone(),
+        }
+    }
+
+    /// Enqueues an input event, e.g. a mouse or key event, that is handled on the next `draw`.
+    /// Use `ShellEvent::Resize` if the drawing area of the window is resized.
+    pub fn handle_event(&mut self, event: ShellEvent) {
+        self.events.push(event);
+    }
+
+    /// Handles the enqueued events, updates the widgets and draws the last rendered frame into
+    /// the given target. Parts of the frame outside of the target are clipped. Returns `false`
+    /// if the application is quit.
+    pub fn draw(&mut self, target: &mut RenderTarget) -> bool {
+        let running = self.update();
+
+        let frame = self.frame.borrow();
+        let target_width = target.width() as usize;
+        let width = frame.width.min(target_width);
+        let height = frame.height.min(target.height() as usize);
+
+        for y in 0..height {
+            let source = &frame.data[y * frame.width..y * frame.width + width];
+            target.data_mut()[y * target_width..y * target_width + width].copy_from_slice(source);
+        }
+
+        running
+    }
+
+    /// Handles the enqueued events and updates the widgets without drawing the frame. Returns
+    /// `false` if the application is quit.
+    pub fn update(&mut self) -> bool {
+        // several runners could be used on the same thread, e.g. in a test
+        self.clock.make_current();
+        self.runner.tick(self.events.drain(..))
+    }
+
+    /// Runs exactly one update, layout and render cycle with the enqueued and the given events.
+    /// Returns `false` if the application is quit.
+    pub fn tick(&mut self, events: impl IntoIterator<Item = ShellEvent>) -> bool {
+        self.events.extend(events);
+        self.update()
+    }
+
+    /// Returns the clock service of the application.
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Advances the time of a manual clock by the given duration, without running a cycle. The
+    /// system clock is not changed.
+    pub fn advance(&mut self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+
+    /// Enqueues a left click with the mouse at the given position of the window.
+    pub fn click(&mut self, x: f64, y: f64) {
+        self.handle_event(ShellEvent::Mouse(x, y));
+
+        for state in &[ButtonState::Down, ButtonState::Up] {
+            self.handle_event(ShellEvent::MouseEvent(MouseEvent {
+                x,
+                y,
+                button: MouseButton::Left,
+                state: *state,
+            }));
+        }
+    }
+
+    /// Enqueues a text input, that is inserted e.g. into the focused text box.
+    pub fn text_input(&mut self, text: impl Into<String>) {
+        self.handle_event(ShellEvent::TextInput(text.into()));
+    }
+
+    /// Returns the root entity of the window.
+    pub fn root(&self) -> Entity {
+        self.runner.window_shell.borrow_mut().adapter().root
+    }
+
+    /// Returns a handle to run closures with the `Context` of the root widget of the window.
+    pub fn ui_handle(&self) -> UiHandle {
+        let mut window_shell = self.runner.window_shell.borrow_mut();
+        let request_sender = window_shell.request_sender();
+        let adapter = window_shell.adapter();
+
+        UiHandle::new(adapter.root, adapter.ui_tasks.sender(), request_sender)
+    }
+
+    /// Returns a sender that could be moved to other threads to send events to the root of the
+    /// window.
+    pub fn event_sender(&self) -> EventSender {
+        let mut window_shell = self.runner.window_shell.borrow_mut();
+        let request_sender = window_shell.request_sender();
+        let adapter = window_shell.adapter();
+
+        EventSender::new(adapter.root, adapter.sent_events.sender(), request_sender)
+    }
+
+    /// Updates the widgets and returns the result of the given closure, that is executed with
+    /// the `Context` of the given widget. Returns an error if the closure could not be executed,
+    /// e.g. the widget does not exist anymore.
+    pub fn query<R, F>(&mut self, entity: Entity, task: F) -> Result<R, String>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut Context<'_>) -> R + Send + 'static,
+    {
+        let result = Arc::new(Mutex::new(None));
+        let task_result = result.clone();
+
+        self.ui_handle()
+            .for_entity(entity)
+            .run(move |ctx| *task_result.lock().unwrap() = Some(task(ctx)))?;
+        self.update();
+
+        let result = result.lock().unwrap().take();
+        result.ok_or_else(|| "ManualRunner.query: The closure is not executed.".to_string())
+    }
+}
@@ -1,6 +1,6 @@
 //! This module contains the base elements of an OrbTk application (Application, WindowBuilder and Window).
 
-use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+use std::{any::Any, cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use dces::prelude::{Entity, World};
 
@@ -8,22 +8,70 @@ use crate::{
     prelude::*,
     shell::{ShellRunner, WindowBuilder},
     tree::*,
-    utils::{Point, Rectangle},
+    utils::{Point, Rectangle, RenderMode},
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::crash_reporter::*;
+pub use self::dialog::*;
+pub use self::event_sender::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::export::*;
 pub use self::global::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::input_recording::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::manual_runner::*;
 pub use self::overlay::*;
+pub use self::property_feed::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::system_tray::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::ui_driver::*;
+pub use self::ui_handle::*;
 pub use self::window::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod crash_reporter;
+mod dialog;
+mod event_sender;
+#[cfg(not(target_arch = "wasm32"))]
+mod export;
 mod global;
+#[cfg(not(target_arch = "wasm32"))]
+mod input_recording;
+#[cfg(not(target_arch = "wasm32"))]
+mod manual_runner;
 mod overlay;
+mod property_feed;
+#[cfg(not(target_arch = "wasm32"))]
+mod system_tray;
+#[cfg(not(target_arch = "wasm32"))]
+mod ui_driver;
+mod ui_handle;
 mod window;
 
 /// The `Application` represents the entry point of an OrbTk based application.
 #[derive(Default)]
 pub struct Application {
-    runners: Vec<ShellRunner<WindowAdapter>>,
+    windows: Vec<Box<dyn Fn(&mut BuildContext) -> Entity>>,
     name: Box<str>,
+    localization: Localization,
+    clipboard: Clipboard,
+    clock: Clock,
+    resources: Resources,
+    settings_storage: Option<Rc<dyn SettingsStorage>>,
+    templates: TemplateOverrides,
+    #[cfg(not(target_arch = "wasm32"))]
+    crash_reporter: Option<CrashReporter>,
+    #[cfg(not(target_arch = "wasm32"))]
+    fonts: Vec<(String, &'static [u8])>,
+    #[cfg(not(target_arch = "wasm32"))]
+    input_recorder: Option<Rc<RefCell<InputRecorder>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    input_replay: Option<InputReplay>,
+    #[cfg(not(target_arch = "wasm32"))]
+    system_tray: Option<SystemTray>,
 }
 
 impl Application {
@@ -40,27 +88,135 @@ impl Application {
         }
     }
 
+    /// Enables crash reports that are written if the application panics. Call it before the
+    /// windows are added, to record their input events and widget trees.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn crash_reporter(mut self, crash_reporter: CrashReporter) -> Self {
+        crash_reporter.install();
+        self.crash_reporter = Some(crash_reporter);
+        self
+    }
+
+    /// Registers a font, e.g. an icon font, for all windows. The font is used with the given
+    /// family name, e.g. by the `icon_font` property. On the web fonts are loaded by the css of
+    /// the page.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
+        self.fonts.push((family.into(), font_file));
+        self
+    }
+
+    /// Records the input events of all windows with the given recorder, e.g. to reproduce a bug
+    /// with `replay_input` or `InputReplay::run`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn record_input(mut self, input_recorder: InputRecorder) -> Self {
+        self.input_recorder = Some(Rc::new(RefCell::new(input_recorder)));
+        self
+    }
+
+    /// Replays the events of the given recording at their recorded time, in addition to the input
+    /// of the windows. Used by `run`, headless runners replay it with `InputReplay::run`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn replay_input(mut self, input_replay: InputReplay) -> Self {
+        self.input_replay = Some(input_replay);
+        self
+    }
+
+    /// Sets the clock service of the application, e.g. a manual clock that is advanced by a test.
+    /// By default the system time is used.
+    pub fn clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Registers a resource that is shared by the states of all windows, e.g. a database handle or
+    /// the configuration of the application. A previous resource of the same type is replaced.
+    pub fn resource<T: Any>(self, resource: T) -> Self {
+        self.resources.insert(resource);
+        self
+    }
+
+    /// Sets the localization service with the translations of the application. Call it before the
+    /// windows are added, each window gets its own copy of the service.
+    pub fn localization(mut self, localization: Localization) -> Self {
+        self.localization = localization;
+        self
+    }
+
+    /// Sets the storage backend of the settings service, e.g. a `MemoryStorage`. By default the
+    /// settings are stored in files or in the local storage of the browser.
+    pub fn settings_storage(mut self, storage: impl SettingsStorage + 'static) -> Self {
+        self.settings_storage = Some(Rc::new(storage));
+        self
+    }
+
+    /// Shows the given system tray icon while the application is running.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn system_tray(mut self, system_tray: SystemTray) -> Self {
+        self.system_tray = Some(system_tray);
+        self
+    }
+
+    /// Replaces the template of the widget type `W` in all windows of the application, e.g. to give
+    /// every `Button` a custom visual tree. The given function is called instead of
+    /// `Template::template`.
+    pub fn template<W: Template + 'static>(
+        mut self,
+        template: impl Fn(W, Entity, &mut BuildContext) -> W + 'static,
+    ) -> Self {
+        self.templates.register(template);
+        self
+    }
+
     /// Creates a new window and add it to the application.
     pub fn window<F: Fn(&mut BuildContext) -> Entity + 'static>(mut self, create_fn: F) -> Self {
+        self.windows.push(Box::new(create_fn));
+        self
+    }
+
+    // Builds the widget tree, the window shell and the systems of a window.
+    fn create_runner(
+        &self,
+        create_fn: &dyn Fn(&mut BuildContext) -> Entity,
+        factory: Option<Box<dyn WindowFactory>>,
+    ) -> ShellRunner<WindowAdapter> {
         let mut world = World::from_stores(Tree::default(), StringComponentStore::default());
 
-        let render_objects = Rc::new(RefCell::new(BTreeMap::new()));
-        let layouts = Rc::new(RefCell::new(BTreeMap::new()));
+        let render_objects = Rc::new(RefCell::new(ArenaMap::new()));
+        let layouts = Rc::new(RefCell::new(ArenaMap::new()));
         let handlers = Rc::new(RefCell::new(BTreeMap::new()));
         let states = Rc::new(RefCell::new(BTreeMap::new()));
         let registry = Rc::new(RefCell::new(Registry::new()));
+        let templates = Rc::new(self.templates.clone());
 
         // register settings service.
-        if self.name.is_empty() {
-            registry
-                .borrow_mut()
-                .register("settings", Settings::default());
+        let app_name = if self.name.is_empty() {
+            "orbtk-app"
         } else {
-            registry
-                .borrow_mut()
-                .register("settings", Settings::new(&*self.name));
+            &*self.name
+        };
+
+        let settings = match &self.settings_storage {
+            Some(storage) => Settings::from_shared_storage(app_name, storage.clone()),
+            None => Settings::new(app_name),
         };
 
+        registry.borrow_mut().register("settings", settings.clone());
+
+        // register localization service.
+        registry
+            .borrow_mut()
+            .register("localization", self.localization.clone());
+
+        // register clipboard service, that is shared between the windows.
+        registry
+            .borrow_mut()
+            .register("clipboard", self.clipboard.clone());
+
+        // register clock service, it is also the current clock of the thread.
+        registry.borrow_mut().register("clock", self.clock.clone());
+        self.clock.make_current();
+
         let window = {
             let overlay = Overlay::create().build(&mut BuildContext::new(
                 world.entity_component_manager(),
@@ -69,6 +225,7 @@ impl Application {
                 &mut handlers.borrow_mut(),
                 &mut states.borrow_mut(),
                 &mut crate::theme::default_theme(),
+                templates.clone(),
             ));
             
             {
@@ -83,6 +240,7 @@ impl Application {
                 &mut handlers.borrow_mut(),
                 &mut states.borrow_mut(),
                 &mut crate::theme::default_theme(),
+                templates.clone(),
             ));
 
             {
@@ -104,6 +262,12 @@ impl Application {
             .component_store()
             .get::<bool>("resizeable", window)
             .unwrap();
+        let borderless = world
+            .entity_component_manager()
+            .component_store()
+            .get::<bool>("borderless", window)
+            .map(|b| *b)
+            .unwrap_or_default();
         let position = *world
             .entity_component_manager()
             .component_store()
@@ -114,6 +278,24 @@ impl Application {
             .component_store()
             .get::<Constraint>("constraint", window)
             .unwrap();
+        let render_mode = world
+            .entity_component_manager()
+            .component_store()
+            .get::<RenderMode>("render_mode", window)
+            .map(|r| *r)
+            .unwrap_or_default();
+        let max_fps = world
+            .entity_component_manager()
+            .component_store()
+            .get::<u32>("max_fps", window)
+            .map(|m| *m)
+            .unwrap_or_default();
+        let vsync = world
+            .entity_component_manager()
+            .component_store()
+            .get::<bool>("vsync", window)
+            .map(|v| *v)
+            .unwrap_or(true);
 
         world
             .entity_component_manager()
@@ -123,6 +305,10 @@ impl Application {
             .entity_component_manager()
             .component_store_mut()
             .register("global", window, Global::default());
+        world
+            .entity_component_manager()
+            .component_store_mut()
+            .register("settings", window, settings);
         world
             .entity_component_manager()
             .component_store_mut()
@@ -132,25 +318,43 @@ impl Application {
                 Rectangle::from((0.0, 0.0, constraint.width(), constraint.height())),
             );
 
-        let window_shell = Rc::new(RefCell::new(
-            WindowBuilder::new(WindowAdapter {
-                root: window,
-                render_objects: render_objects.clone(),
-                layouts: layouts.clone(),
-                handlers: handlers.clone(),
-                states: states.clone(),
-                ..Default::default()
-            })
-            .title(&(title)[..])
-            .bounds(Rectangle::from((
-                position.x,
-                position.y,
-                constraint.width(),
-                constraint.height(),
-            )))
-            .resizeable(resizeable)
-            .build(),
-        ));
+        let window_builder = WindowBuilder::new(WindowAdapter {
+            root: window,
+            render_objects: render_objects.clone(),
+            layouts: layouts.clone(),
+            handlers: handlers.clone(),
+            states: states.clone(),
+            templates,
+            resources: self.resources.clone(),
+            window_size: (constraint.width(), constraint.height()),
+            #[cfg(not(target_arch = "wasm32"))]
+            crash_reporter: self.crash_reporter.clone(),
+            ..Default::default()
+        })
+        .title(&(title)[..])
+        .bounds(Rectangle::from((
+            position.x,
+            position.y,
+            constraint.width(),
+            constraint.height(),
+        )))
+        .resizeable(resizeable)
+        .borderless(borderless)
+        .render_mode(render_mode)
+        .max_fps(max_fps)
+        .vsync(vsync);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let window_builder = match factory {
+            Some(factory) => window_builder.factory(factory),
+            None => window_builder,
+        };
+
+        // the web shell always renders into the canvas of the page
+        #[cfg(target_arch = "wasm32")]
+        let _ = factory;
+
+        let window_shell = Rc::new(RefCell::new(window_builder.build()));
 
         #[cfg(not(target_arch = "wasm32"))]
         window_shell
@@ -179,6 +383,25 @@ impl Application {
             .render_context_2_d()
             .register_font("OpenMoji", crate::theme::fonts::OPEN_MOJI_COLOR_FONT);
 
+        #[cfg(not(target_arch = "wasm32"))]
+        for (family, font_file) in &self.fonts {
+            window_shell
+                .borrow_mut()
+                .render_context_2_d()
+                .register_font(family, *font_file);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(input_recorder) = self.input_recorder.clone() {
+                window_shell.borrow_mut().set_event_observer(move |events| {
+                    if let Err(message) = input_recorder.borrow_mut().record(events) {
+                        eprintln!("{}", message);
+                    }
+                });
+            }
+        }
+
         world.register_init_system(InitSystem {
             shell: window_shell.clone(),
             layouts: layouts.clone(),
@@ -193,6 +416,7 @@ impl Application {
                 shell: window_shell.clone(),
                 handlers: handlers.clone(),
                 mouse_down_nodes: RefCell::new(vec![]),
+                touch_down_nodes: RefCell::new(BTreeMap::new()),
                 render_objects: render_objects.clone(),
                 states: states.clone(),
                 layouts: layouts.clone(),
@@ -232,23 +456,110 @@ impl Application {
             .with_priority(3)
             .build();
 
-        self.runners.push(ShellRunner {
+        world
+            .create_system(AccessibilitySystem {
+                shell: window_shell.clone(),
+            })
+            .with_priority(4)
+            .build();
+
+        ShellRunner {
             updater: Box::new(WorldWrapper { world }),
             window_shell,
-        });
-
-        self
+        }
     }
 
     /// Starts the application and run it until quit is requested.
-    pub fn run(mut self) {
-        while let Some(runner) = self.runners.pop() {
+    pub fn run(self) {
+        let mut runners: Vec<ShellRunner<WindowAdapter>> = self
+            .windows
+            .iter()
+            .map(|create_fn| self.create_runner(create_fn.as_ref(), None))
+            .collect();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut system_tray = self.system_tray;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut input_replay = self.input_replay;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(Err(message)) = system_tray.as_mut().map(SystemTray::show) {
+                eprintln!("{}", message);
+            }
+        }
+
+        while let Some(runner) = runners.pop() {
             #[cfg(not(target_arch = "wasm32"))]
-            let mut runner = runner;
+            {
+                let mut runner = runner;
+
+                runner.run_with(|| {
+                    let mut events = vec![];
+
+                    if let Some(system_tray) = &mut system_tray {
+                        events.append(&mut system_tray.poll_events());
+                    }
+
+                    if let Some(input_replay) = &mut input_replay {
+                        events.append(&mut input_replay.poll_events());
+                    }
+
+                    events
+                });
+            }
 
             #[cfg(target_arch = "wasm32")]
-            let runner = runner;
             runner.run();
         }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(system_tray) = &mut system_tray {
+                system_tray.hide();
+            }
+        }
+    }
+
+    /// Starts the application without an own event loop and returns a runner that embeds the
+    /// first window into an external loop one frame at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no window is added.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run_manual(self) -> ManualRunner {
+        let create_fn = self
+            .windows
+            .first()
+            .expect("Application.run_manual: No window is added.");
+
+        ManualRunner::new(&self, create_fn.as_ref())
+    }
+
+    /// Starts the application headless with a manual clock and returns a runner that embeds the
+    /// first window. Each `ManualRunner::tick` runs exactly one update, layout and render cycle and
+    /// the time only changes with `ManualRunner::advance`, so tests of states and layouts are
+    /// deterministic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no window is added.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run_stepped(mut self) -> ManualRunner {
+        self.clock = Clock::manual();
+        self.run_manual()
+    }
+
+    /// Starts the application headless and returns a driver that finds the widgets of the first
+    /// window by their automation id and synthesizes the input, e.g. for end-to-end ui tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no window is added.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run_driver(self) -> UiDriver {
+        UiDriver::new(self.run_manual())
     }
 }
@@ -0,0 +1,146 @@
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{thread, time::Duration};
+
+use dces::prelude::Component;
+
+use crate::prelude::*;
+
+struct FeedSlot<T> {
+    value: Option<T>,
+    pending: bool,
+    throttled: bool,
+}
+
+/// Connects a stream of values, e.g. from a socket or a sensor, to a property of a widget.
+///
+/// Values that are pushed faster than the ui could apply them are conflated, only the latest
+/// value is set on the next update of the ui. With `throttle` the property is updated at most
+/// once per interval.
+///
+/// Use `UiHandle::feed` to create a feed.
+pub struct PropertyFeed<T> {
+    key: Arc<String>,
+    ui_handle: UiHandle,
+    slot: Arc<Mutex<FeedSlot<T>>>,
+}
+
+impl<T> Clone for PropertyFeed<T> {
+    fn clone(&self) -> Self {
+        PropertyFeed {
+            key: self.key.clone(),
+            ui_handle: self.ui_handle.clone(),
+            slot: self.slot.clone(),
+        }
+    }
+}
+
+impl<T> PropertyFeed<T>
+where
+    T: Component + Clone + Send,
+{
+    /// Creates a new feed for the property with the given key of the widget of the handle.
+    pub fn new(ui_handle: UiHandle, key: impl Into<String>) -> Self {
+        PropertyFeed {
+            key: Arc::new(key.into()),
+            ui_handle,
+            slot: Arc::new(Mutex::new(FeedSlot {
+                value: None,
+                pending: false,
+                throttled: false,
+            })),
+        }
+    }
+
+    /// Updates the property at most once per the given interval. The latest pushed value is used.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn throttle(self, interval: Duration) -> Self {
+        self.slot.lock().unwrap().throttled = true;
+
+        let key = self.key.clone();
+        let ui_handle = self.ui_handle.clone();
+        let slot = Arc::downgrade(&self.slot);
+
+        // stops if all feeds are dropped or the window is closed
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+
+            let slot = match slot.upgrade() {
+                Some(slot) => slot,
+                None => break,
+            };
+
+            if schedule(&key, &ui_handle, &slot).is_err() {
+                break;
+            }
+        });
+
+        self
+    }
+
+    /// Pushes a new value. Returns an error if the window is closed.
+    pub fn push(&self, value: T) -> Result<(), String> {
+        {
+            let mut slot = self.slot.lock().unwrap();
+            slot.value = Some(value);
+
+            if slot.throttled {
+                return Ok(());
+            }
+        }
+
+        schedule(&self.key, &self.ui_handle, &self.slot)
+    }
+
+    /// Pushes all values of the given iterator, e.g. of a channel receiver, on a background
+    /// thread. The thread stops if the iterator ends or the window is closed.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pipe<I>(self, values: I) -> thread::JoinHandle<()>
+    where
+        I: IntoIterator<Item = T> + Send + 'static,
+    {
+        thread::spawn(move || {
+            for value in values {
+                if self.push(value).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+}
+
+// Enqueues a task that sets the latest value, if there is no pending task.
+fn schedule<T>(
+    key: &Arc<String>,
+    ui_handle: &UiHandle,
+    slot: &Arc<Mutex<FeedSlot<T>>>,
+) -> Result<(), String>
+where
+    T: Component + Clone + Send,
+{
+    {
+        let mut slot = slot.lock().unwrap();
+
+        if slot.pending || slot.value.is_none() {
+            return Ok(());
+        }
+
+        slot.pending = true;
+    }
+
+    let key = key.clone();
+    let slot = slot.clone();
+
+    ui_handle.run(move |ctx| {
+        let value = {
+            let mut slot = slot.lock().unwrap();
+            slot.pending = false;
+            slot.value.take()
+        };
+
+        if let Some(value) = value {
+            ctx.widget().set(key.as_str(), value);
+        }
+    })
+}
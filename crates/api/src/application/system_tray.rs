@@ -0,0 +1,62 @@
+use crate::shell::{ShellEvent, TrayBackend, TrayMenuItem, TraySettings};
+
+/// The `SystemTray` shows an icon with a menu in the system tray while the application is
+/// running, e.g. for utility applications that live in the tray. The events of the icon are sent
+/// to the root of the window and could be handled with `on_tray_event` of the `Window`.
+///
+/// The icon is shown by the given platform `TrayBackend`, e.g. `Win32Tray` on Windows. On other
+/// platforms a backend could be implemented with the tray api of the platform or a tray crate.
+pub struct SystemTray {
+    backend: Box<dyn TrayBackend>,
+    settings: TraySettings,
+}
+
+impl SystemTray {
+    /// Creates a new system tray with the given backend.
+    pub fn new(backend: impl TrayBackend + 'static) -> Self {
+        SystemTray {
+            backend: Box::new(backend),
+            settings: TraySettings::default(),
+        }
+    }
+
+    /// Sets the path of the icon image.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.settings.icon = icon.into();
+        self
+    }
+
+    /// Sets the tooltip of the icon.
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.settings.tooltip = tooltip.into();
+        self
+    }
+
+    /// Adds an item to the menu of the icon. The id is passed with `TrayEvent::MenuItem` if the
+    /// item is selected.
+    pub fn menu_item(mut self, id: impl Into<String>, text: impl Into<String>) -> Self {
+        self.settings.menu.push(TrayMenuItem::new(id, text));
+        self
+    }
+
+    // Shows the icon.
+    pub(crate) fn show(&mut self) -> Result<(), String> {
+        self.backend
+            .show(&self.settings)
+            .map_err(|e| format!("SystemTray.show: {}", e))
+    }
+
+    // Removes the icon.
+    pub(crate) fn hide(&mut self) {
+        self.backend.hide();
+    }
+
+    // Returns the events of the icon as shell events.
+    pub(crate) fn poll_events(&mut self) -> Vec<ShellEvent> {
+        self.backend
+            .poll_events()
+            .into_iter()
+            .map(ShellEvent::Tray)
+            .collect()
+    }
+}
@@ -0,0 +1,166 @@
+use dces::prelude::{Component, Entity};
+
+use crate::{
+    prelude::*,
+    shell::{ButtonState, Key, KeyEvent, ShellEvent},
+    utils::{Point, Rectangle, Visibility},
+};
+
+/// The `UiDriver` runs an application headless and drives it like a user, e.g. in end-to-end ui
+/// tests. Widgets are found by their `automation_id`, the input is synthesized and passed through
+/// the normal event pipeline and property values could be read back.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut driver = Application::new().window(create_window).run_driver();
+///
+/// driver.click("name")?;
+/// driver.type_text("Ada");
+/// driver.click("greet")?;
+///
+/// assert_eq!(driver.text("greeting")?, "Hello Ada");
+/// ```
+pub struct UiDriver {
+    runner: ManualRunner,
+}
+
+impl UiDriver {
+    /// Creates a driver for the window of the given runner and lays the window out.
+    pub fn new(runner: ManualRunner) -> Self {
+        let mut driver = UiDriver { runner };
+
+        // the first update builds the states, the second one lays out their changes
+        driver.update();
+        driver.update();
+        driver
+    }
+
+    /// Returns the runner of the window, e.g. to pass other input events.
+    pub fn runner(&mut self) -> &mut ManualRunner {
+        &mut self.runner
+    }
+
+    /// Handles the synthesized input and updates the widgets. Returns `false` if the application
+    /// is quit.
+    pub fn update(&mut self) -> bool {
+        self.runner.update()
+    }
+
+    /// Returns the entity of the widget with the given automation id.
+    pub fn find(&mut self, automation_id: &str) -> Result<Entity, String> {
+        let root = self.runner.root();
+        let id = automation_id.to_string();
+
+        self.runner
+            .query(root, move |ctx| ctx.entity_of_automation_id(&id))?
+            .ok_or_else(|| {
+                format!(
+                    "UiDriver.find: Could not find widget with automation id '{}'.",
+                    automation_id
+                )
+            })
+    }
+
+    /// Returns `true` if a widget with the given automation id exists.
+    pub fn exists(&mut self, automation_id: &str) -> bool {
+        self.find(automation_id).is_ok()
+    }
+
+    /// Clicks in the center of the widget with the given automation id and updates the widgets.
+    /// Returns an error if the widget could not be found or is not visible.
+    pub fn click(&mut self, automation_id: &str) -> Result<(), String> {
+        let entity = self.find(automation_id)?;
+
+        let (visibility, position, bounds) = self.runner.query(entity, |ctx| {
+            let widget = ctx.widget();
+            (
+                widget.clone_or_default::<Visibility>("visibility"),
+                widget.clone_or_default::<Point>("position"),
+                widget.clone_or_default::<Rectangle>("bounds"),
+            )
+        })?;
+
+        if visibility != Visibility::Visible || bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+            return Err(format!(
+                "UiDriver.click: The widget with automation id '{}' is not visible.",
+                automation_id
+            ));
+        }
+
+        self.runner.click(
+            position.x + bounds.width() / 2.0,
+            position.y + bounds.height() / 2.0,
+        );
+        self.update();
+
+        Ok(())
+    }
+
+    /// Presses and releases the given key, e.g. `Key::Enter`, and updates the widgets. The key
+    /// event is handled by the focused widget.
+    pub fn key(&mut self, key: Key) {
+        for state in &[ButtonState::Down, ButtonState::Up] {
+            self.runner.handle_event(ShellEvent::KeyEvent(KeyEvent {
+                key,
+                state: *state,
+                repeat: false,
+            }));
+        }
+
+        self.update();
+    }
+
+    /// Types the given text into the focused widget and updates the widgets.
+    pub fn type_text(&mut self, text: impl Into<String>) {
+        self.runner.text_input(text);
+        self.update();
+    }
+
+    /// Returns the value of the property with the given key of the widget with the given
+    /// automation id.
+    pub fn get<P>(&mut self, automation_id: &str, key: &str) -> Result<P, String>
+    where
+        P: Component + Clone + Send,
+    {
+        let entity = self.find(automation_id)?;
+        let key = key.to_string();
+
+        self.runner
+            .query(entity, move |ctx| ctx.widget().try_clone::<P>(&key))?
+            .ok_or_else(|| {
+                format!(
+                    "UiDriver.get: The widget with automation id '{}' has no property of the given type.",
+                    automation_id
+                )
+            })
+    }
+
+    /// Sets the value of the property with the given key of the widget with the given
+    /// automation id and updates the widgets.
+    pub fn set<P>(&mut self, automation_id: &str, key: &str, value: P) -> Result<(), String>
+    where
+        P: Component + Clone + Send,
+    {
+        let entity = self.find(automation_id)?;
+        let key = key.to_string();
+
+        self.runner
+            .query(entity, move |ctx| ctx.widget().set::<P>(&key, value))?;
+        self.update();
+
+        Ok(())
+    }
+
+    /// Returns the text of the widget with the given automation id.
+    pub fn text(&mut self, automation_id: &str) -> Result<String, String> {
+        self.get::<String16>(automation_id, "text")
+            .map(|text| text.to_string())
+    }
+}
+
+impl From<ManualRunner> for UiDriver {
+    fn from(runner: ManualRunner) -> Self {
+        UiDriver::new(runner)
+    }
+}
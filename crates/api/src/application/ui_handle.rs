@@ -0,0 +1,186 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Mutex,
+    },
+    task::{Context as TaskContext, Poll, Waker},
+};
+
+use dces::prelude::{Component, Entity};
+
+use crate::{prelude::*, shell::ShellRequest};
+
+/// A closure that is executed on the ui thread with the `Context` of a widget.
+pub type UiTask = Box<dyn FnOnce(&mut Context<'_>) + Send + 'static>;
+
+/// Queues the tasks of all `UiHandle`s of a window until they are executed on the ui thread.
+pub struct UiTaskQueue {
+    sender: Sender<(Entity, UiTask)>,
+    receiver: Receiver<(Entity, UiTask)>,
+}
+
+impl Default for UiTaskQueue {
+    fn default() -> Self {
+        let (sender, receiver) = channel();
+        UiTaskQueue { sender, receiver }
+    }
+}
+
+impl UiTaskQueue {
+    /// Returns a sender that could be used to enqueue tasks.
+    pub fn sender(&self) -> Sender<(Entity, UiTask)> {
+        self.sender.clone()
+    }
+
+    /// Removes and returns all enqueued tasks.
+    pub fn dequeue(&self) -> Vec<(Entity, UiTask)> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+/// The `UiHandle` could be cloned and moved to background threads, e.g. a server or a device
+/// driver thread, to run closures with the `Context` of a widget on the ui thread.
+///
+/// Use `Context::ui_handle` to get a handle for the current widget.
+#[derive(Clone)]
+pub struct UiHandle {
+    entity: Entity,
+    task_sender: Sender<(Entity, UiTask)>,
+    request_sender: Sender<ShellRequest>,
+}
+
+impl UiHandle {
+    /// Creates a new handle for the given widget.
+    pub fn new(
+        entity: Entity,
+        task_sender: Sender<(Entity, UiTask)>,
+        request_sender: Sender<ShellRequest>,
+    ) -> Self {
+        UiHandle {
+            entity,
+            task_sender,
+            request_sender,
+        }
+    }
+
+    /// Returns the widget the closures are executed for.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns a new handle for an other widget of the same window.
+    pub fn for_entity(&self, entity: Entity) -> UiHandle {
+        UiHandle {
+            entity,
+            ..self.clone()
+        }
+    }
+
+    /// Enqueues a closure that is executed on the ui thread with the `Context` of the widget.
+    /// Returns an error if the window is closed.
+    pub fn run<F: FnOnce(&mut Context<'_>) + Send + 'static>(&self, task: F) -> Result<(), String> {
+        self.task_sender
+            .send((self.entity, Box::new(task)))
+            .map_err(|_| "UiHandle.run: The window is closed.".to_string())?;
+        let _ = self.request_sender.send(ShellRequest::Update);
+        Ok(())
+    }
+
+    /// Creates a feed that sets the pushed values on the property with the given key.
+    pub fn feed<T>(&self, key: impl Into<String>) -> PropertyFeed<T>
+    where
+        T: Component + Clone + Send,
+    {
+        PropertyFeed::new(self.clone(), key)
+    }
+
+    /// Enqueues a closure that is executed on the ui thread with the `Context` of the widget and
+    /// returns a future that resolves with the result of the closure. The future is independent
+    /// of the async runtime, so it could be awaited e.g. inside of a tokio or async-std task.
+    pub fn call<R, F>(&self, task: F) -> UiCall<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut Context<'_>) -> R + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(UiCallState {
+            result: None,
+            completed: false,
+            waker: None,
+        }));
+
+        let completer = UiCallCompleter {
+            state: state.clone(),
+        };
+
+        // if the window is closed the completer is dropped and completes the call with an error
+        let _ = self.run(move |ctx| completer.complete(Ok(task(ctx))));
+
+        UiCall { state }
+    }
+}
+
+struct UiCallState<R> {
+    result: Option<Result<R, String>>,
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+// Completes the call. If the task is dropped without running, e.g. the window is closed, the call
+// is completed with an error.
+struct UiCallCompleter<R> {
+    state: Arc<Mutex<UiCallState<R>>>,
+}
+
+impl<R> UiCallCompleter<R> {
+    fn complete(&self, result: Result<R, String>) {
+        let mut state = self.state.lock().unwrap();
+
+        if state.completed {
+            return;
+        }
+
+        state.result = Some(result);
+        state.completed = true;
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<R> Drop for UiCallCompleter<R> {
+    fn drop(&mut self) {
+        self.complete(Err(
+            "UiHandle.call: The window or the widget is removed.".to_string()
+        ));
+    }
+}
+
+/// Future that is returned by `UiHandle::call` and resolves after the closure is executed on the
+/// ui thread.
+pub struct UiCall<R> {
+    state: Arc<Mutex<UiCallState<R>>>,
+}
+
+impl<R> Future for UiCall<R> {
+    type Output = Result<R, String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(result) = state.result.take() {
+            return Poll::Ready(result);
+        }
+
+        if state.completed {
+            return Poll::Ready(Err(
+                "UiHandle.call: The result is already taken.".to_string()
+            ));
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
@@ -7,14 +7,45 @@ use crate::{prelude::*, shell, tree::Tree, utils::Point};
 /// Represents a window. Each window has its own tree, event pipeline and shell.
 #[derive(Default)]
 pub struct WindowAdapter {
-    pub render_objects: Rc<RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>>,
-    pub layouts: Rc<RefCell<BTreeMap<Entity, Box<dyn Layout>>>>,
+    pub render_objects: Rc<RefCell<ArenaMap<dyn RenderObject>>>,
+    pub layouts: Rc<RefCell<ArenaMap<dyn Layout>>>,
     pub handlers: Rc<RefCell<EventHandlerMap>>,
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
     pub event_queue: EventQueue,
     pub messages: BTreeMap<Entity, Vec<MessageBox>>,
     pub root: Entity,
     pub mouse_position: Point,
+    pub primary_touch: Option<u64>,
+    pub window_size: (f64, f64),
+    pub virtual_resolution: VirtualResolution,
+    pub ui_tasks: UiTaskQueue,
+    pub sent_events: SentEventQueue,
+    pub detached_widgets: Vec<Entity>,
+    pub templates: Rc<TemplateOverrides>,
+    pub resources: Resources,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub crash_reporter: Option<CrashReporter>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub exports: Vec<ExportRequest>,
+}
+
+impl WindowAdapter {
+    // Records the input event for the crash report.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn record_event(&self, event: impl FnOnce() -> String) {
+        if let Some(crash_reporter) = &self.crash_reporter {
+            crash_reporter.record_event(event());
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn record_event(&self, _: impl FnOnce() -> String) {}
+
+    // Maps a position in the window to the virtual resolution of the window if it is set.
+    fn to_virtual(&self, x: f64, y: f64) -> (f64, f64) {
+        self.virtual_resolution
+            .to_virtual(self.window_size.0, self.window_size.1, x, y)
+    }
 }
 
 pub struct WorldWrapper {
@@ -29,6 +60,8 @@ impl shell::Updater for WorldWrapper {
 
 impl shell::WindowAdapter for WindowAdapter {
     fn resize(&mut self, width: f64, height: f64) {
+        self.record_event(|| format!("resize: {} x {}", width, height));
+        self.window_size = (width, height);
         self.event_queue.register_event_with_strategy(
             WindowEvent::Resize { width, height },
             EventStrategy::Direct,
@@ -37,6 +70,7 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn mouse(&mut self, x: f64, y: f64) {
+        let (x, y) = self.to_virtual(x, y);
         self.mouse_position = Point::new(x, y);
         self.event_queue.register_event(
             MouseMoveEvent {
@@ -48,6 +82,7 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn scroll(&mut self, delta_x: f64, delta_y: f64) {
+        self.record_event(|| format!("scroll: ({}, {})", delta_x, delta_y));
         self.event_queue.register_event(
             ScrollEvent {
                 delta: Point::new(delta_x, delta_y),
@@ -57,6 +92,16 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn mouse_event(&mut self, event: shell::MouseEvent) {
+        self.record_event(|| {
+            format!(
+                "mouse {:?} {:?}: ({}, {})",
+                event.button, event.state, event.x, event.y
+            )
+        });
+
+        let (x, y) = self.to_virtual(event.x, event.y);
+        let event = shell::MouseEvent { x, y, ..event };
+
         match event.state {
             shell::ButtonState::Up => self.event_queue.register_event(
                 MouseUpEvent {
@@ -82,6 +127,9 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn key_event(&mut self, event: shell::KeyEvent) {
+        // the text is not recorded to not write typed passwords to disk
+        self.record_event(|| format!("key {:?} {:?}", event.key, event.state));
+
         match event.state {
             shell::ButtonState::Up => self
                 .event_queue
@@ -92,18 +140,94 @@ impl shell::WindowAdapter for WindowAdapter {
         }
     }
 
+    fn text_input(&mut self, text: String) {
+        self.record_event(|| "text input".to_string());
+        self.event_queue
+            .register_event(TextInputEvent { text }, self.root);
+    }
+
+    fn composition_event(&mut self, event: shell::CompositionEvent) {
+        self.record_event(|| "composition".to_string());
+        self.event_queue
+            .register_event(TextCompositionEvent { event }, self.root);
+    }
+
+    fn touch_event(&mut self, event: shell::TouchEvent) {
+        self.record_event(|| {
+            format!(
+                "touch {} {:?}: ({}, {})",
+                event.id, event.phase, event.x, event.y
+            )
+        });
+
+        let (x, y) = self.to_virtual(event.x, event.y);
+        self.event_queue.register_event(
+            TouchInputEvent {
+                event: shell::TouchEvent { x, y, ..event },
+            },
+            self.root,
+        );
+
+        // the first touch point is mapped to mouse events, mouse positions are mapped by `mouse`
+        if self.primary_touch.is_none() && event.phase == shell::TouchPhase::Down {
+            self.primary_touch = Some(event.id);
+        }
+
+        if self.primary_touch != Some(event.id) {
+            return;
+        }
+
+        match event.phase {
+            shell::TouchPhase::Down => {
+                self.mouse(event.x, event.y);
+                self.mouse_event(shell::MouseEvent {
+                    x: event.x,
+                    y: event.y,
+                    button: shell::MouseButton::Left,
+                    state: shell::ButtonState::Down,
+                });
+            }
+            shell::TouchPhase::Move => self.mouse(event.x, event.y),
+            shell::TouchPhase::Up | shell::TouchPhase::Cancel => {
+                self.primary_touch = None;
+                self.mouse_event(shell::MouseEvent {
+                    x: event.x,
+                    y: event.y,
+                    button: shell::MouseButton::Left,
+                    state: shell::ButtonState::Up,
+                });
+            }
+        }
+    }
+
     fn quite_event(&mut self) {
         self.event_queue
             .register_event(SystemEvent::Quit, self.root);
     }
 
+    fn tray_event(&mut self, event: shell::TrayEvent) {
+        self.record_event(|| format!("tray {:?}", event));
+        self.event_queue
+            .register_event(SystemTrayEvent { event }, self.root);
+    }
+
     fn active(&mut self, active: bool) {
+        self.record_event(|| format!("active: {}", active));
         self.event_queue.register_event_with_strategy(
             WindowEvent::ActiveChanged(active),
             EventStrategy::Direct,
             self.root,
         );
     }
+
+    fn minimized(&mut self, minimized: bool) {
+        self.record_event(|| format!("minimized: {}", minimized));
+        self.event_queue.register_event_with_strategy(
+            WindowEvent::MinimizedChanged(minimized),
+            EventStrategy::Direct,
+            self.root,
+        );
+    }
 }
 
 impl Into<Box<dyn shell::WindowAdapter>> for WindowAdapter {
@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, shell::CompositionEvent};
+
+use super::{Event, EventBox, EventHandler};
+
+/// Occurs if the composition string of an input method (IME) is changed, committed or canceled.
+/// The event is delivered to the focused widget.
+pub struct TextCompositionEvent {
+    pub event: CompositionEvent,
+}
+
+impl Event for TextCompositionEvent {}
+
+pub type CompositionHandlerFn = dyn Fn(&mut StatesContext, CompositionEvent) -> bool + 'static;
+
+/// Used to handle text composition events. Could be attached to a widget.
+pub struct TextCompositionEventHandler {
+    handler: Rc<CompositionHandlerFn>,
+}
+
+impl Into<Rc<dyn EventHandler>> for TextCompositionEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for TextCompositionEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TextCompositionEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.event.clone())
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TextCompositionEvent>()
+    }
+}
+
+pub trait CompositionHandler: Sized + Widget {
+    /// Inserts a handler.
+    fn on_composition<H: Fn(&mut StatesContext, CompositionEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TextCompositionEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use crate::prelude::*;
+
+/// Used to handle application defined events, e.g. events that are sent with an `EventSender`
+/// from other threads. Could be attached to a widget.
+pub struct CustomEventHandler<E: Event> {
+    handler: Rc<dyn Fn(&mut StatesContext, &E) -> bool + 'static>,
+    event: PhantomData<E>,
+}
+
+impl<E: Event> CustomEventHandler<E> {
+    /// Creates a handler that calls the given closure with the events of type `E`.
+    pub fn new<H: Fn(&mut StatesContext, &E) -> bool + 'static>(handler: H) -> Self {
+        CustomEventHandler {
+            handler: Rc::new(handler),
+            event: PhantomData,
+        }
+    }
+}
+
+impl<E: Event> Into<Rc<dyn EventHandler>> for CustomEventHandler<E> {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl<E: Event> EventHandler for CustomEventHandler<E> {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<E>() {
+            return (self.handler)(states, event);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<E>()
+    }
+}
+
+/// Contains the handler method of application defined events.
+pub trait CustomHandler: Sized + Widget {
+    /// Inserts a handler that is called with the events of type `E`.
+    fn on_event<E: Event, H: Fn(&mut StatesContext, &E) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(CustomEventHandler::new(handler))
+    }
+}
@@ -1,3 +1,5 @@
+use std::{cell::Cell, rc::Rc};
+
 use crate::{event::EventBox, widget::StatesContext};
 
 /// This trait is used to define an event handler.
@@ -7,4 +9,132 @@ pub trait EventHandler {
 
     /// Check if the handler could handle the given event box.
     fn handles_event(&self, event: &EventBox) -> bool;
+
+    /// If it returns `true` the handler is removed from its widget after the current events are
+    /// handled.
+    fn is_expired(&self) -> bool {
+        false
+    }
+}
+
+/// Wraps an event handler that is only called for the first event it handles. After that the
+/// handler is removed from its widget.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Button::create()
+///     .insert_handler(OnceHandler::new(handler))
+///     .build(ctx)
+/// ```
+pub struct OnceHandler {
+    handler: Rc<dyn EventHandler>,
+    invoked: Cell<bool>,
+}
+
+impl OnceHandler {
+    /// Creates a new once handler that wraps the given `handler`.
+    pub fn new(handler: impl Into<Rc<dyn EventHandler>>) -> Self {
+        OnceHandler {
+            handler: handler.into(),
+            invoked: Cell::new(false),
+        }
+    }
+}
+
+impl Into<Rc<dyn EventHandler>> for OnceHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for OnceHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if !self.handles_event(event) {
+            return false;
+        }
+
+        self.invoked.set(true);
+        self.handler.handle_event(state_context, event)
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        !self.invoked.get() && self.handler.handles_event(event)
+    }
+
+    fn is_expired(&self) -> bool {
+        self.invoked.get()
+    }
+}
+
+/// Returns `true` if both handlers are the same instance.
+pub fn is_same_handler(left: &Rc<dyn EventHandler>, right: &Rc<dyn EventHandler>) -> bool {
+    Rc::as_ptr(left) as *const u8 == Rc::as_ptr(right) as *const u8
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use dces::entity::Entity;
+
+    use super::*;
+    use crate::event::{Event, EventStrategy};
+
+    struct TestEvent;
+
+    impl Event for TestEvent {}
+
+    struct OtherEvent;
+
+    impl Event for OtherEvent {}
+
+    struct TestHandler {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl EventHandler for TestHandler {
+        fn handle_event(&self, _: &mut StatesContext, _: &EventBox) -> bool {
+            self.calls.set(self.calls.get() + 1);
+            true
+        }
+
+        fn handles_event(&self, event: &EventBox) -> bool {
+            event.is_type::<TestEvent>()
+        }
+    }
+
+    #[test]
+    fn test_once_handler() {
+        let calls = Rc::new(Cell::new(0));
+        let handler = OnceHandler::new(Rc::new(TestHandler {
+            calls: calls.clone(),
+        }) as Rc<dyn EventHandler>);
+        let mut states = BTreeMap::new();
+        let mut states_context = StatesContext::new(&mut states);
+        let other = EventBox::new(OtherEvent, EventStrategy::Direct, Entity(0));
+        let event = EventBox::new(TestEvent, EventStrategy::Direct, Entity(0));
+
+        assert!(!handler.handle_event(&mut states_context, &other));
+        assert!(!handler.is_expired());
+
+        assert!(handler.handles_event(&event));
+        assert!(handler.handle_event(&mut states_context, &event));
+        assert!(handler.is_expired());
+        assert!(!handler.handles_event(&event));
+        assert!(!handler.handle_event(&mut states_context, &event));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_is_same_handler() {
+        let calls = Rc::new(Cell::new(0));
+        let first: Rc<dyn EventHandler> = Rc::new(TestHandler {
+            calls: calls.clone(),
+        });
+        let second: Rc<dyn EventHandler> = Rc::new(TestHandler { calls });
+
+        assert!(is_same_handler(&first, &first.clone()));
+        assert!(!is_same_handler(&first, &second));
+    }
 }
@@ -0,0 +1,496 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use crate::{
+    prelude::*,
+    shell::{MouseButton, TouchEvent, TouchPhase},
+    utils::Point,
+};
+
+use super::{EventBox, EventHandler};
+
+/// Maximum distance in pixels a pointer could move to be recognized as tap or long press.
+pub const TAP_SLOP: f64 = 10.0;
+
+/// Time in seconds a pointer has to be held down to be recognized as long press.
+pub const LONG_PRESS_DELAY: f64 = 0.5;
+
+/// Minimum distance in pixels a pointer has to move to be recognized as swipe.
+pub const SWIPE_DISTANCE: f64 = 50.0;
+
+/// Pointer id that is used for the mouse.
+pub const MOUSE_POINTER_ID: u64 = std::u64::MAX;
+
+/// Describes the direction of a swipe gesture.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Describes the kinds of gestures a `GestureRecognizer` could recognize.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GestureKind {
+    Tap,
+    LongPress,
+    Swipe,
+    Pinch,
+}
+
+/// Represents a recognized gesture.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Gesture {
+    /// Pointer is pressed and released at the given position.
+    Tap(Point),
+
+    /// Pointer is held down at the given position.
+    LongPress(Point),
+
+    /// Pointer is moved fast in the given direction and released.
+    Swipe(SwipeDirection),
+
+    /// Two pointers are moved together or apart. The scale is relative to the distance of the
+    /// pointers at the start of the gesture.
+    Pinch { center: Point, scale: f64 },
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum RecognizerState {
+    Possible,
+    Recognized(GestureKind),
+    Failed,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Pointer {
+    start: Point,
+    current: Point,
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    ((b.x - a.x).powi(2) + (b.y - a.y).powi(2)).sqrt()
+}
+
+/// The `GestureRecognizer` recognizes gestures from a sequence of pointer events, that starts
+/// with the first pressed pointer and ends if all pointers are released.
+///
+/// Only one gesture kind is recognized per sequence. Conflicts are resolved in the order pinch,
+/// swipe, long press and tap, e.g. a tap is not recognized if the pointer is held long enough to be a
+/// long press or a second pointer starts a pinch.
+///
+/// A long press is recognized with the first pointer event after `LONG_PRESS_DELAY`.
+#[derive(Clone, Debug)]
+pub struct GestureRecognizer {
+    kinds: Vec<GestureKind>,
+    pointers: BTreeMap<u64, Pointer>,
+    state: RecognizerState,
+    start_time: f64,
+    pinch_distance: f64,
+}
+
+impl GestureRecognizer {
+    /// Creates a new recognizer for the given gesture kinds.
+    pub fn new(kinds: &[GestureKind]) -> Self {
+        GestureRecognizer {
+            kinds: kinds.to_vec(),
+            pointers: BTreeMap::new(),
+            state: RecognizerState::Failed,
+            start_time: 0.0,
+            pinch_distance: 0.0,
+        }
+    }
+
+    /// Returns `true` if the given gesture kind is recognized.
+    pub fn recognizes(&self, kind: GestureKind) -> bool {
+        self.kinds.contains(&kind)
+    }
+
+    /// Returns the number of pressed pointers.
+    pub fn pointer_count(&self) -> usize {
+        self.pointers.len()
+    }
+
+    /// Processes the pointer event at the given time in seconds and returns the recognized
+    /// gesture.
+    pub fn input(&mut self, event: TouchEvent, time: f64) -> Option<Gesture> {
+        let position = Point::new(event.x, event.y);
+
+        match event.phase {
+            TouchPhase::Down => {
+                self.down(event.id, position, time);
+                None
+            }
+            TouchPhase::Move => {
+                if let Some(pointer) = self.pointers.get_mut(&event.id) {
+                    pointer.current = position;
+                    self.moved(time)
+                } else {
+                    None
+                }
+            }
+            TouchPhase::Up | TouchPhase::Cancel => {
+                if let Some(mut pointer) = self.pointers.remove(&event.id) {
+                    pointer.current = position;
+                    self.up(pointer, event.phase == TouchPhase::Cancel, time)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn down(&mut self, id: u64, position: Point, time: f64) {
+        if self.pointers.is_empty() {
+            self.state = RecognizerState::Possible;
+            self.start_time = time;
+        }
+
+        self.pointers.insert(
+            id,
+            Pointer {
+                start: position,
+                current: position,
+            },
+        );
+
+        if self.pointers.len() < 2 {
+            return;
+        }
+
+        match self.state {
+            RecognizerState::Possible | RecognizerState::Recognized(GestureKind::LongPress)
+                if self.recognizes(GestureKind::Pinch) =>
+            {
+                self.state = RecognizerState::Recognized(GestureKind::Pinch);
+                let (a, b) = self.pinch_pointers();
+                self.pinch_distance = distance(a, b);
+            }
+            RecognizerState::Recognized(GestureKind::Pinch) => {}
+            _ => self.state = RecognizerState::Failed,
+        }
+    }
+
+    fn moved(&mut self, time: f64) -> Option<Gesture> {
+        match self.state {
+            RecognizerState::Recognized(GestureKind::Pinch) if self.pointers.len() >= 2 => {
+                let (a, b) = self.pinch_pointers();
+
+                if self.pinch_distance <= 0.0 {
+                    return None;
+                }
+
+                Some(Gesture::Pinch {
+                    center: Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+                    scale: distance(a, b) / self.pinch_distance,
+                })
+            }
+            RecognizerState::Possible => {
+                let pointer = *self.pointers.values().next()?;
+
+                if distance(pointer.start, pointer.current) > TAP_SLOP {
+                    // a moved pointer could only be a swipe
+                    if !self.recognizes(GestureKind::Swipe) {
+                        self.state = RecognizerState::Failed;
+                    }
+
+                    return None;
+                }
+
+                self.long_press(pointer, time)
+            }
+            _ => None,
+        }
+    }
+
+    fn up(&mut self, pointer: Pointer, cancel: bool, time: f64) -> Option<Gesture> {
+        let possible = self.state == RecognizerState::Possible && !cancel;
+        self.state = RecognizerState::Failed;
+
+        if !possible {
+            return None;
+        }
+
+        let delta = Point::new(
+            pointer.current.x - pointer.start.x,
+            pointer.current.y - pointer.start.y,
+        );
+
+        if distance(pointer.start, pointer.current) > TAP_SLOP {
+            if !self.recognizes(GestureKind::Swipe)
+                || distance(pointer.start, pointer.current) < SWIPE_DISTANCE
+            {
+                return None;
+            }
+
+            let direction = if delta.x.abs() > delta.y.abs() {
+                if delta.x < 0.0 {
+                    SwipeDirection::Left
+                } else {
+                    SwipeDirection::Right
+                }
+            } else if delta.y < 0.0 {
+                SwipeDirection::Up
+            } else {
+                SwipeDirection::Down
+            };
+
+            return Some(Gesture::Swipe(direction));
+        }
+
+        if let Some(long_press) = self.long_press(pointer, time) {
+            return Some(long_press);
+        }
+
+        if self.recognizes(GestureKind::Tap) {
+            return Some(Gesture::Tap(pointer.current));
+        }
+
+        None
+    }
+
+    fn long_press(&mut self, pointer: Pointer, time: f64) -> Option<Gesture> {
+        if time - self.start_time < LONG_PRESS_DELAY {
+            return None;
+        }
+
+        if self.recognizes(GestureKind::LongPress) {
+            self.state = RecognizerState::Recognized(GestureKind::LongPress);
+            return Some(Gesture::LongPress(pointer.start));
+        }
+
+        None
+    }
+
+    // Returns the current positions of the first two pointers.
+    fn pinch_pointers(&self) -> (Point, Point) {
+        let mut pointers = self.pointers.values();
+        let a = pointers.next().map(|p| p.current).unwrap_or_default();
+        let b = pointers.next().map(|p| p.current).unwrap_or_default();
+        (a, b)
+    }
+}
+
+// Returns the current time of the application clock in seconds.
+fn now() -> f64 {
+    Clock::current().now().as_secs_f64()
+}
+
+pub type GestureHandlerFn = dyn Fn(&mut StatesContext, Gesture) -> bool + 'static;
+
+/// Used to recognize gestures from touch and mouse events. Could be attached to a widget.
+pub struct GestureEventHandler {
+    recognizer: RefCell<GestureRecognizer>,
+    handler: Rc<GestureHandlerFn>,
+}
+
+impl GestureEventHandler {
+    // Converts mouse and touch events to pointer events. Mouse events are ignored while touch
+    // points are pressed, because the first touch point is also delivered as mouse events.
+    fn pointer_event(&self, event: &EventBox) -> Option<TouchEvent> {
+        if let Ok(event) = event.downcast_ref::<TouchInputEvent>() {
+            return Some(event.event);
+        }
+
+        let mouse = |x, y, phase| TouchEvent {
+            id: MOUSE_POINTER_ID,
+            x,
+            y,
+            phase,
+        };
+
+        if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
+            if event.button != MouseButton::Left || self.recognizer.borrow().pointer_count() > 0 {
+                return None;
+            }
+
+            return Some(mouse(event.x, event.y, TouchPhase::Down));
+        }
+
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            return Some(mouse(event.x, event.y, TouchPhase::Move));
+        }
+
+        if let Ok(event) = event.downcast_ref::<MouseUpEvent>() {
+            return Some(mouse(event.x, event.y, TouchPhase::Up));
+        }
+
+        None
+    }
+}
+
+impl Into<Rc<dyn EventHandler>> for GestureEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for GestureEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        let event = match self.pointer_event(event) {
+            Some(event) => event,
+            None => return false,
+        };
+
+        let gesture = self.recognizer.borrow_mut().input(event, now());
+
+        gesture.map_or(false, |gesture| (self.handler)(state_context, gesture))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchInputEvent>()
+            || event.is_type::<MouseDownEvent>()
+            || event.is_type::<MouseMoveEvent>()
+            || event.is_type::<MouseUpEvent>()
+    }
+}
+
+pub trait GestureHandler: Sized + Widget {
+    /// Inserts a handler that recognizes the given gesture kinds. Conflicts between the kinds are
+    /// resolved by the recognizer, so a long press is not also reported as tap.
+    fn on_gesture<H: Fn(&mut StatesContext, Gesture) -> bool + 'static>(
+        self,
+        kinds: &[GestureKind],
+        handler: H,
+    ) -> Self {
+        self.insert_handler(GestureEventHandler {
+            recognizer: RefCell::new(GestureRecognizer::new(kinds)),
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [GestureKind; 4] = [
+        GestureKind::Tap,
+        GestureKind::LongPress,
+        GestureKind::Swipe,
+        GestureKind::Pinch,
+    ];
+
+    fn event(id: u64, x: f64, y: f64, phase: TouchPhase) -> TouchEvent {
+        TouchEvent { id, x, y, phase }
+    }
+
+    #[test]
+    fn test_tap() {
+        let mut recognizer = GestureRecognizer::new(&ALL);
+        assert_eq!(
+            recognizer.input(event(0, 10.0, 10.0, TouchPhase::Down), 0.0),
+            None
+        );
+        assert_eq!(
+            recognizer.input(event(0, 12.0, 10.0, TouchPhase::Move), 0.1),
+            None
+        );
+        assert_eq!(
+            recognizer.input(event(0, 12.0, 10.0, TouchPhase::Up), 0.2),
+            Some(Gesture::Tap(Point::new(12.0, 10.0)))
+        );
+
+        // canceled pointer
+        recognizer.input(event(0, 10.0, 10.0, TouchPhase::Down), 1.0);
+        assert_eq!(
+            recognizer.input(event(0, 10.0, 10.0, TouchPhase::Cancel), 1.1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_long_press() {
+        let mut recognizer = GestureRecognizer::new(&ALL);
+        recognizer.input(event(0, 10.0, 10.0, TouchPhase::Down), 0.0);
+        assert_eq!(
+            recognizer.input(event(0, 11.0, 10.0, TouchPhase::Move), 0.6),
+            Some(Gesture::LongPress(Point::new(10.0, 10.0)))
+        );
+        assert_eq!(
+            recognizer.input(event(0, 11.0, 10.0, TouchPhase::Up), 0.7),
+            None
+        );
+
+        // recognized on release
+        recognizer.input(event(0, 10.0, 10.0, TouchPhase::Down), 1.0);
+        assert_eq!(
+            recognizer.input(event(0, 10.0, 10.0, TouchPhase::Up), 2.0),
+            Some(Gesture::LongPress(Point::new(10.0, 10.0)))
+        );
+
+        // without long press recognizer it is a tap
+        let mut recognizer = GestureRecognizer::new(&[GestureKind::Tap]);
+        recognizer.input(event(0, 10.0, 10.0, TouchPhase::Down), 0.0);
+        assert_eq!(
+            recognizer.input(event(0, 10.0, 10.0, TouchPhase::Up), 1.0),
+            Some(Gesture::Tap(Point::new(10.0, 10.0)))
+        );
+    }
+
+    #[test]
+    fn test_swipe() {
+        let mut recognizer = GestureRecognizer::new(&ALL);
+        recognizer.input(event(0, 100.0, 10.0, TouchPhase::Down), 0.0);
+        recognizer.input(event(0, 60.0, 15.0, TouchPhase::Move), 0.1);
+        assert_eq!(
+            recognizer.input(event(0, 20.0, 20.0, TouchPhase::Up), 0.2),
+            Some(Gesture::Swipe(SwipeDirection::Left))
+        );
+
+        recognizer.input(event(0, 10.0, 10.0, TouchPhase::Down), 1.0);
+        assert_eq!(
+            recognizer.input(event(0, 15.0, 80.0, TouchPhase::Up), 1.2),
+            Some(Gesture::Swipe(SwipeDirection::Down))
+        );
+
+        // too short for a swipe and too long for a tap
+        recognizer.input(event(0, 10.0, 10.0, TouchPhase::Down), 2.0);
+        assert_eq!(
+            recognizer.input(event(0, 40.0, 10.0, TouchPhase::Up), 2.2),
+            None
+        );
+
+        // moved pointer is no long press
+        recognizer.input(event(0, 10.0, 10.0, TouchPhase::Down), 3.0);
+        assert_eq!(
+            recognizer.input(event(0, 40.0, 10.0, TouchPhase::Move), 4.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pinch() {
+        let mut recognizer = GestureRecognizer::new(&ALL);
+        recognizer.input(event(0, 0.0, 0.0, TouchPhase::Down), 0.0);
+        recognizer.input(event(1, 100.0, 0.0, TouchPhase::Down), 0.1);
+
+        match recognizer.input(event(1, 200.0, 0.0, TouchPhase::Move), 0.2) {
+            Some(Gesture::Pinch { center, scale }) => {
+                assert!((center.x - 100.0).abs() < std::f64::EPSILON);
+                assert!((scale - 2.0).abs() < std::f64::EPSILON);
+            }
+            gesture => panic!("unexpected gesture {:?}", gesture),
+        }
+
+        // no tap after the pinch
+        assert_eq!(
+            recognizer.input(event(1, 200.0, 0.0, TouchPhase::Up), 0.3),
+            None
+        );
+        assert_eq!(
+            recognizer.input(event(0, 0.0, 0.0, TouchPhase::Up), 0.3),
+            None
+        );
+
+        // a second pointer fails the sequence if pinch is not recognized
+        let mut recognizer = GestureRecognizer::new(&[GestureKind::Tap]);
+        recognizer.input(event(0, 0.0, 0.0, TouchPhase::Down), 0.0);
+        recognizer.input(event(1, 100.0, 0.0, TouchPhase::Down), 0.1);
+        recognizer.input(event(1, 100.0, 0.0, TouchPhase::Up), 0.2);
+        assert_eq!(
+            recognizer.input(event(0, 0.0, 0.0, TouchPhase::Up), 0.3),
+            None
+        );
+    }
+}
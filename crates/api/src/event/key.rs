@@ -71,3 +71,51 @@ pub trait KeyDownHandler: Sized + Widget {
         )
     }
 }
+
+/// Occurs if a character is received from the keyboard. In contrast to `KeyDownEvent` it contains
+/// the typed text, e.g. shifted or AltGr characters.
+pub struct TextInputEvent {
+    pub text: String,
+}
+
+impl Event for TextInputEvent {}
+
+pub type TextInputHandlerFn = dyn Fn(&mut StatesContext, String) -> bool + 'static;
+
+/// Used to handle text input events. Could be attached to a widget.
+pub struct TextInputEventHandler {
+    handler: Rc<TextInputHandlerFn>,
+}
+
+impl Into<Rc<dyn EventHandler>> for TextInputEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for TextInputEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TextInputEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.text.clone())
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TextInputEvent>()
+    }
+}
+
+pub trait TextInputHandler: Sized + Widget {
+    /// Inserts a handler.
+    fn on_text_input<H: Fn(&mut StatesContext, String) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TextInputEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -6,20 +6,40 @@ use dces::entity::Entity;
 
 pub use crate::widget::StatesContext;
 
+pub use self::composition::*;
+pub use self::custom::*;
 pub use self::editable::*;
 pub use self::event_handler::*;
 pub use self::event_queue::*;
+pub use self::gesture::*;
 pub use self::key::*;
 pub use self::mouse::*;
+pub use self::navigation::*;
+pub use self::selection::*;
+pub use self::size::*;
+pub use self::sort::*;
 pub use self::system::*;
+pub use self::touch::*;
+pub use self::tray::*;
+pub use self::value::*;
 pub use self::window::*;
 
+mod composition;
+mod custom;
 mod editable;
 mod event_handler;
 mod event_queue;
+mod gesture;
 mod key;
 mod mouse;
+mod navigation;
+mod selection;
+mod size;
+mod sort;
 mod system;
+mod touch;
+mod tray;
+mod value;
 mod window;
 
 /// Defines the strategy a event moves through the tree.
@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use crate::{prelude::*, shell::MouseButton, utils::*};
+use crate::{prelude::*, shell::MouseButton, tree::Tree, utils::*};
 
 /// Checks if the given point is inside of a widget.
 pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>) -> bool {
@@ -15,6 +15,63 @@ pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>
     rect.contains((mouse_position.x, mouse_position.y))
 }
 
+// Checks if the given point is inside of the rendered bounds of the widget.
+fn contains_point(store: &StringComponentStore, entity: Entity, point: Point) -> bool {
+    match (
+        store.get::<Point>("position", entity),
+        store.get::<Rectangle>("bounds", entity),
+    ) {
+        (Ok(position), Ok(bounds)) => {
+            Rectangle::new(position.x, position.y, bounds.width(), bounds.height())
+                .contains((point.x, point.y))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the widgets under the given point in window coordinates, the top most widget first,
+/// respecting the `z_index` of the widgets. Like the mouse dispatch, a widget is hit if the point
+/// is inside of its bounds at its rendered position and inside of all of its clipping parents.
+/// Widgets that are not visible and their children are skipped.
+pub fn hit_test(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    point: Point,
+) -> Vec<Entity> {
+    let store = ecm.component_store();
+    let tree = ecm.entity_store();
+    let visible = |entity: Entity| {
+        store.get::<Visibility>("visibility", entity).ok() == Some(&Visibility::Visible)
+    };
+
+    let mut hits: Vec<Entity> = paint_order(ecm, tree.root())
+        .into_iter()
+        .filter(|node| {
+            if !visible(*node) || !contains_point(store, *node, point) {
+                return false;
+            }
+
+            let mut current = tree.parent.get(node).copied().flatten();
+
+            while let Some(parent) = current {
+                if !visible(parent)
+                    || (store.get::<bool>("clip", parent).ok() == Some(&true)
+                        && !contains_point(store, parent, point))
+                {
+                    return false;
+                }
+
+                current = tree.parent.get(&parent).copied().flatten();
+            }
+
+            true
+        })
+        .collect();
+
+    // the last rendered widget is on top
+    hits.reverse();
+    hits
+}
+
 pub struct MouseMoveEvent {
     pub x: f64,
 
@@ -190,6 +247,13 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Inserts a click handler that is removed after the first click.
+    fn on_click_once<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler_once(ClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse down handler.
     fn on_mouse_down<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(MouseDownEventHandler {
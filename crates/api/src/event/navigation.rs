@@ -0,0 +1,73 @@
+use std::rc::Rc;
+
+use crate::prelude::*;
+
+/// Describes if a screen of a navigation view is entered or left.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NavigationPhase {
+    /// The screen is shown, e.g. after it is pushed or after the screen above it is popped.
+    Enter,
+
+    /// The screen is hidden, e.g. after another screen is pushed or after it is popped.
+    Leave,
+}
+
+/// Occurs if a navigation view enters or leaves one of its screens. Contains the name of the
+/// screen.
+pub struct NavigationEvent {
+    pub screen: String,
+
+    pub phase: NavigationPhase,
+}
+
+impl Event for NavigationEvent {}
+
+pub type NavigationHandlerFunction = dyn Fn(&mut StatesContext, &str) + 'static;
+
+/// Used to handle navigation events of one phase. Could be attached to a widget.
+pub struct NavigationEventHandler {
+    phase: NavigationPhase,
+
+    handler: Rc<NavigationHandlerFunction>,
+}
+
+impl Into<Rc<dyn EventHandler>> for NavigationEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for NavigationEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<NavigationEvent>() {
+            if event.phase == self.phase {
+                (self.handler)(state_context, event.screen.as_str());
+            }
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<NavigationEvent>()
+    }
+}
+
+/// Contains navigation related handler methods.
+pub trait NavigationHandler: Sized + Widget {
+    /// Inserts a handler that is called with the name of each screen that is entered.
+    fn on_enter<H: Fn(&mut StatesContext, &str) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(NavigationEventHandler {
+            phase: NavigationPhase::Enter,
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called with the name of each screen that is left.
+    fn on_leave<H: Fn(&mut StatesContext, &str) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(NavigationEventHandler {
+            phase: NavigationPhase::Leave,
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use std::rc::Rc;
+
+use crate::prelude::*;
+
+/// Occurs if the selected items of an items widget are changed, e.g. by a click or by the
+/// keyboard. Contains the sorted indices of the selected items.
+pub struct SelectionChangedEvent(pub Vec<usize>);
+
+impl Event for SelectionChangedEvent {}
+
+pub type SelectionChangedHandlerFunction = dyn Fn(&mut StatesContext, &[usize]) + 'static;
+
+/// Used to handle selection changed events. Could be attached to a widget.
+pub struct SelectionChangedEventHandler {
+    handler: Rc<SelectionChangedHandlerFunction>,
+}
+
+impl Into<Rc<dyn EventHandler>> for SelectionChangedEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for SelectionChangedEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<SelectionChangedEvent>() {
+            (self.handler)(state_context, &event.0);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SelectionChangedEvent>()
+    }
+}
+
+/// Contains selection related handler methods.
+pub trait SelectionChangedHandler: Sized + Widget {
+    /// Inserts a handler that is called with the sorted indices of the selected items.
+    fn on_selection_changed<H: Fn(&mut StatesContext, &[usize]) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SelectionChangedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,113 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, tree::Tree};
+
+/// Key of the component that stores the size of a widget with a size changed handler after the
+/// last layout.
+pub static LAST_SIZE: &str = "last_size";
+
+/// Occurs after the layout if the size of the bounds of a widget is changed. The event is only
+/// sent to widgets with a size changed handler.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SizeChangedEvent {
+    pub old_size: (f64, f64),
+    pub new_size: (f64, f64),
+}
+
+impl Event for SizeChangedEvent {
+    fn strategy(&self) -> EventStrategy {
+        EventStrategy::Direct
+    }
+}
+
+pub type SizeChangedHandlerFn =
+    dyn Fn(&mut StatesContext, Entity, (f64, f64), (f64, f64)) + 'static;
+
+/// Used to handle size changed events. Could be attached to a widget.
+pub struct SizeChangedEventHandler {
+    handler: Rc<SizeChangedHandlerFn>,
+}
+
+impl Into<Rc<dyn EventHandler>> for SizeChangedEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for SizeChangedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(size_changed) = event.downcast_ref::<SizeChangedEvent>() {
+            (self.handler)(
+                states,
+                event.source,
+                size_changed.old_size,
+                size_changed.new_size,
+            );
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SizeChangedEvent>()
+    }
+}
+
+/// Contains the size changed handler method, it is implemented by all widgets.
+pub trait SizeChangedHandler: Sized + Widget {
+    /// Inserts a handler that is called with the widget, the old and the new size after the
+    /// layout whenever the size of the widget is changed. The first call has an old size of
+    /// `(0.0, 0.0)`.
+    fn on_size_changed<H: Fn(&mut StatesContext, Entity, (f64, f64), (f64, f64)) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SizeChangedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// Returns the size changed events of all widgets with a size changed handler whose size is
+/// changed since the last call and stores their current size.
+pub fn size_changed_events(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    handlers: &EventHandlerMap,
+) -> Vec<EventBox> {
+    let mut events = vec![];
+
+    for (entity, widget_handlers) in handlers {
+        let new_size = match ecm.component_store().get::<Rectangle>("bounds", *entity) {
+            Ok(bounds) => (bounds.width(), bounds.height()),
+            Err(_) => continue,
+        };
+        let old_size = ecm
+            .component_store()
+            .get::<(f64, f64)>(LAST_SIZE, *entity)
+            .map(|size| *size)
+            .unwrap_or_default();
+
+        if new_size == old_size {
+            continue;
+        }
+
+        let event = EventBox::new(
+            SizeChangedEvent { old_size, new_size },
+            EventStrategy::Direct,
+            *entity,
+        );
+
+        if !widget_handlers
+            .iter()
+            .any(|handler| handler.handles_event(&event))
+        {
+            continue;
+        }
+
+        ecm.component_store_mut()
+            .register(LAST_SIZE, *entity, new_size);
+        events.push(event);
+    }
+
+    events
+}
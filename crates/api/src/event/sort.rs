@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, utils::SortDirection};
+
+/// Occurs if the sort order of a column is changed, e.g. by clicking on a column header.
+pub struct SortEvent {
+    pub column: usize,
+
+    pub direction: SortDirection,
+}
+
+impl Event for SortEvent {}
+
+pub type SortHandlerFunction = dyn Fn(&mut StatesContext, usize, SortDirection) + 'static;
+
+/// Used to handle sort events. Could be attached to a widget.
+pub struct SortEventHandler {
+    handler: Rc<SortHandlerFunction>,
+}
+
+impl Into<Rc<dyn EventHandler>> for SortEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for SortEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<SortEvent>() {
+            (self.handler)(state_context, event.column, event.direction);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SortEvent>()
+    }
+}
+
+/// Contains sort related handler methods.
+pub trait SortHandler: Sized + Widget {
+    /// Inserts a handler that is called with the column index and the new sort direction.
+    fn on_sort<H: Fn(&mut StatesContext, usize, SortDirection) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SortEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
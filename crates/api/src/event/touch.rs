@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, shell::TouchEvent};
+
+use super::{Event, EventBox, EventHandler};
+
+/// Occurs if a touch point is pressed, moved or released. The event is delivered to the widgets
+/// under the point where the touch is started until it is released. The first touch point is also
+/// delivered as mouse events.
+pub struct TouchInputEvent {
+    pub event: TouchEvent,
+}
+
+impl Event for TouchInputEvent {}
+
+pub type TouchHandlerFn = dyn Fn(&mut StatesContext, TouchEvent) -> bool + 'static;
+
+/// Used to handle touch events. Could be attached to a widget.
+pub struct TouchInputEventHandler {
+    handler: Rc<TouchHandlerFn>,
+}
+
+impl Into<Rc<dyn EventHandler>> for TouchInputEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for TouchInputEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TouchInputEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.event))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchInputEvent>()
+    }
+}
+
+/// Gives widgets access to the raw touch points, e.g. to implement pinch zoom.
+pub trait TouchHandler: Sized + Widget {
+    /// Inserts a handler.
+    fn on_touch<H: Fn(&mut StatesContext, TouchEvent) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TouchInputEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
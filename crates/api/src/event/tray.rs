@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, shell::TrayEvent};
+
+/// Occurs if the system tray icon of the application is clicked or an item of its menu is
+/// selected. The event is sent to the root of the window.
+pub struct SystemTrayEvent {
+    pub event: TrayEvent,
+}
+
+impl Event for SystemTrayEvent {}
+
+pub type SystemTrayHandlerFn = dyn Fn(&mut StatesContext, TrayEvent) -> bool + 'static;
+
+/// Used to handle system tray events. Could be attached to a widget.
+pub struct SystemTrayEventHandler {
+    handler: Rc<SystemTrayHandlerFn>,
+}
+
+impl Into<Rc<dyn EventHandler>> for SystemTrayEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for SystemTrayEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<SystemTrayEvent>() {
+            return (self.handler)(states, event.event.clone());
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SystemTrayEvent>()
+    }
+}
+
+/// Contains system tray related handler methods.
+pub trait SystemTrayHandler: Sized + Widget {
+    /// Inserts a handler that is called with the events of the system tray icon.
+    fn on_tray_event<H: Fn(&mut StatesContext, TrayEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SystemTrayEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use crate::prelude::*;
+
+/// Occurs if the numeric value of a widget is changed, e.g. the value of a `NumericBox`.
+pub struct ValueChangedEvent {
+    pub value: f64,
+}
+
+impl Event for ValueChangedEvent {}
+
+pub type ValueChangedHandlerFunction = dyn Fn(&mut StatesContext, f64) + 'static;
+
+/// Used to handle value changed events. Could be attached to a widget.
+pub struct ValueChangedEventHandler {
+    handler: Rc<ValueChangedHandlerFunction>,
+}
+
+impl Into<Rc<dyn EventHandler>> for ValueChangedEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for ValueChangedEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<ValueChangedEvent>() {
+            (self.handler)(state_context, event.value);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ValueChangedEvent>()
+    }
+}
+
+/// Contains value changed related handler methods.
+pub trait ValueChangedHandler: Sized + Widget {
+    /// Inserts a handler that is called with the new value.
+    fn on_value_changed<H: Fn(&mut StatesContext, f64) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(ValueChangedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
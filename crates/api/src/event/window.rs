@@ -2,10 +2,21 @@ use std::rc::Rc;
 
 use crate::prelude::*;
 
-#[derive(Clone)]
+/// Describes a change of the window, it is sent to the root of the window.
+#[derive(Clone, Debug, PartialEq)]
 pub enum WindowEvent {
-    Resize { width: f64, height: f64 },
+    /// The window is resized to the given size.
+    Resize {
+        width: f64,
+        height: f64,
+    },
+
+    /// The window gained (`true`) or lost (`false`) the focus.
     ActiveChanged(bool),
+
+    /// The window is minimized (`true`) or restored (`false`).
+    MinimizedChanged(bool),
+
     None,
 }
 
@@ -36,3 +47,41 @@ impl EventHandler for WindowEventHandler {
         event.is_type::<WindowEvent>()
     }
 }
+
+/// Occurs if the user requested to close the window. The event is sent to the root of the window.
+pub struct CloseRequestedEvent;
+
+impl Event for CloseRequestedEvent {}
+
+pub type CloseRequestedHandlerFn = dyn Fn(&mut StatesContext) -> bool + 'static;
+
+/// Used to handle close requests of the window. The handler returns `true` to allow the window to
+/// close, if one of the handlers returns `false` the window stays open.
+///
+/// On the desktop the close request of a window is only reported once. After a veto the close
+/// button of the window has no effect, so the application has to offer its own way to close the
+/// window, e.g. a button that calls `Context::close_window`.
+pub struct CloseRequestedEventHandler {
+    pub handler: Rc<CloseRequestedHandlerFn>,
+}
+
+impl Into<Rc<dyn EventHandler>> for CloseRequestedEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for CloseRequestedEventHandler {
+    // Returns `true` if the close request is vetoed.
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if event.is_type::<CloseRequestedEvent>() {
+            return !(self.handler)(states);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<CloseRequestedEvent>()
+    }
+}
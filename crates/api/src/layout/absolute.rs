@@ -1,7 +1,4 @@
-use std::{
-    cell::RefCell,
-    collections::BTreeMap,
-};
+use std::cell::RefCell;
 
 use dces::prelude::Entity;
 
@@ -27,14 +24,16 @@ impl Layout for AbsoluteLayout {
         render_context_2_d: &mut RenderContext2D,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> DirtySize {
         let window = ecm.entity_store().root();
 
         if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", window) {
-            self.desired_size.borrow_mut().set_size(bounds.width(), bounds.height());
-        } 
+            self.desired_size
+                .borrow_mut()
+                .set_size(bounds.width(), bounds.height());
+        }
 
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
@@ -57,7 +56,7 @@ impl Layout for AbsoluteLayout {
         _parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> (f64, f64) {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -96,4 +95,4 @@ impl Into<Box<dyn Layout>> for AbsoluteLayout {
     fn into(self) -> Box<dyn Layout> {
         Box::new(self)
     }
-}
\ No newline at end of file
+}
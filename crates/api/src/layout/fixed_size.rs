@@ -1,19 +1,22 @@
-use std::{
-    cell::{Cell, RefCell},
-    collections::BTreeMap,
-};
+use std::cell::{Cell, RefCell};
 
 use dces::prelude::Entity;
 
-use crate::{prelude::*, render::Image, render::RenderContext2D, tree::Tree, utils::prelude::*};
+use crate::{
+    prelude::*,
+    render::{Image, RenderContext2D, Svg},
+    tree::Tree,
+    utils::prelude::*,
+};
 
-use super::{component, component_try_mut, Layout};
+use super::{apply_aspect_ratio, component, component_or_default, component_try_mut, Layout};
 
 /// Fixed size layout is defined by fixed bounds like the size of an image or the size of a text.
 #[derive(Default)]
 pub struct FixedSizeLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    available_width: Cell<f64>,
 }
 
 impl FixedSizeLayout {
@@ -28,7 +31,7 @@ impl Layout for FixedSizeLayout {
         render_context_2_d: &mut RenderContext2D,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> DirtySize {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -47,11 +50,41 @@ impl Layout for FixedSizeLayout {
             self.desired_size.borrow_mut().set_dirty(true);
         }
 
+        let text_trimming = widget.clone_or_default::<TextTrimming>("text_trimming");
+        let text_wrap = widget.clone_or_default::<TextWrap>("text_wrap");
+
+        // a wrapped text fits into the width that is available since the last arrange
+        let wrap_width = {
+            let max_width = widget.get::<Constraint>("constraint").max_width();
+            let available_width = self.available_width.get();
+
+            if available_width > 0.0 {
+                available_width.min(max_width)
+            } else {
+                max_width
+            }
+        };
+
         let size = widget
             .try_get::<Image>("image")
             .map(|image| (image.width(), image.height()))
+            .or_else(|| {
+                widget
+                    .try_get::<Svg>("svg")
+                    .map(|svg| (svg.width(), svg.height()))
+            })
             .or_else(|| {
                 widget.try_get::<String16>("text").and_then(|text| {
+                    // the composition string of an input method is measured as part of the text
+                    let mut text = text.clone();
+
+                    if let Some(composition) = widget
+                        .try_get::<TextComposition>("composition")
+                        .filter(|composition| !composition.is_empty())
+                    {
+                        text.insert_str(composition.index.min(text.len()), &composition.text);
+                    }
+
                     let font = widget.get::<String>("font");
                     let font_size = widget.get::<f64>("font_size");
 
@@ -67,6 +100,28 @@ impl Layout for FixedSizeLayout {
                                 );
                                 (text_metrics.width, text_metrics.height)
                             })
+                    } else if text_wrap == TextWrap::Wrap {
+                        let text = text.to_string();
+                        let line_height = render_context_2_d
+                            .measure(text.as_str(), *font_size, font.as_str())
+                            .height;
+
+                        let lines = text_wrap.wrap(text.as_str(), wrap_width, |line| {
+                            render_context_2_d
+                                .measure(line, *font_size, font.as_str())
+                                .width
+                        });
+
+                        let width = lines
+                            .iter()
+                            .map(|line| {
+                                render_context_2_d
+                                    .measure(line.as_str(), *font_size, font.as_str())
+                                    .width
+                            })
+                            .fold(0.0, f64::max);
+
+                        Some((width, line_height * lines.len() as f64))
                     } else {
                         let text_metrics = render_context_2_d.measure(
                             text.to_string().as_str(),
@@ -95,6 +150,13 @@ impl Layout for FixedSizeLayout {
 
         if let Some(size) = size {
             if let Some(constraint) = component_try_mut::<Constraint>(ecm, entity, "constraint") {
+                // a trimmed text could be smaller than its measured size
+                let size = if text_trimming != TextTrimming::None && constraint.max_width() > 0.0 {
+                    (size.0.min(constraint.max_width()), size.1)
+                } else {
+                    size
+                };
+
                 constraint.set_width(size.0 as f64);
                 constraint.set_height(size.1 as f64);
             }
@@ -102,15 +164,10 @@ impl Layout for FixedSizeLayout {
 
         let constraint: Constraint = component(ecm, entity, "constraint");
 
-        if constraint.width() > 0.0 {
-            self.desired_size.borrow_mut().set_width(constraint.width());
-        }
-
-        if constraint.height() > 0.0 {
-            self.desired_size
-                .borrow_mut()
-                .set_height(constraint.height());
-        }
+        let desired_size = constraint.measure(self.desired_size.borrow().size());
+        self.desired_size
+            .borrow_mut()
+            .set_size(desired_size.0, desired_size.1);
 
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
@@ -130,10 +187,10 @@ impl Layout for FixedSizeLayout {
     fn arrange(
         &self,
         render_context_2_d: &mut RenderContext2D,
-        _parent_size: (f64, f64),
+        parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> (f64, f64) {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -141,30 +198,59 @@ impl Layout for FixedSizeLayout {
             return (0.0, 0.0);
         }
 
+        // the next measure wraps the text at the available width
+        if component_or_default::<TextWrap>(ecm, entity, "text_wrap") == TextWrap::Wrap {
+            let margin: Thickness = component_or_default(ecm, entity, "margin");
+            self.available_width
+                .set((parent_size.0 - margin.left() - margin.right()).max(0.0));
+        }
+
+        // a widget with an aspect ratio, e.g. an image, is stretched inside of the parent
+        let size = if ecm
+            .component_store()
+            .get::<AspectRatio>("aspect_ratio", entity)
+            .is_ok()
+        {
+            let horizontal_alignment: Alignment = component(ecm, entity, "horizontal_alignment");
+            let vertical_alignment: Alignment = component(ecm, entity, "vertical_alignment");
+            let margin: Thickness = component_or_default(ecm, entity, "margin");
+
+            apply_aspect_ratio(
+                ecm,
+                entity,
+                (
+                    horizontal_alignment.align_measure(
+                        parent_size.0,
+                        self.desired_size.borrow().width(),
+                        margin.left(),
+                        margin.right(),
+                    ),
+                    vertical_alignment.align_measure(
+                        parent_size.1,
+                        self.desired_size.borrow().height(),
+                        margin.top(),
+                        margin.bottom(),
+                    ),
+                ),
+            )
+        } else {
+            self.desired_size.borrow().size()
+        };
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
-            bounds.set_width(self.desired_size.borrow().width());
-            bounds.set_height(self.desired_size.borrow().height());
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
         }
 
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
             if let Some(child_layout) = layouts.get(&child) {
-                child_layout.arrange(
-                    render_context_2_d,
-                    (
-                        self.desired_size.borrow().width(),
-                        self.desired_size.borrow().height(),
-                    ),
-                    child,
-                    ecm,
-                    layouts,
-                    theme,
-                );
+                child_layout.arrange(render_context_2_d, size, child, ecm, layouts, theme);
             }
         }
 
         self.desired_size.borrow_mut().set_dirty(false);
-        self.desired_size.borrow().size()
+        size
     }
 }
 
@@ -1,28 +1,132 @@
-use std::{
-    cell::{Cell, RefCell},
-    collections::BTreeMap,
-};
+use std::collections::BTreeMap;
 
 use dces::prelude::Entity;
 
 use crate::{prelude::*, render::RenderContext2D, tree::Tree, utils::prelude::*};
 
-use super::{component, component_try_mut, Layout};
+use super::{
+    apply_aspect_ratio, available_size, component, component_or_default, component_try_mut,
+    mirror_children, outer_size, Layout,
+};
+
+/// Key of the `GridLayoutCache` component of a grid.
+pub static GRID_LAYOUT_CACHE: &str = "grid_layout_cache";
+
+/// Stores the results of the measure pass of a grid that are used by the arrange pass. The cache
+/// is stored as component of the grid entity.
+#[derive(Clone, Default, PartialEq)]
+pub struct GridLayoutCache {
+    /// Desired size of the grid.
+    pub desired_size: DirtySize,
+
+    /// Desired sizes of the children of the grid.
+    pub children_sizes: BTreeMap<Entity, (f64, f64)>,
+}
+
+// Takes the layout cache of the given grid or returns a new one.
+fn take_cache(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> GridLayoutCache {
+    component_try_mut::<GridLayoutCache>(ecm, entity, GRID_LAYOUT_CACHE)
+        .map(std::mem::take)
+        .unwrap_or_default()
+}
+
+// Stores the layout cache of the given grid.
+fn store_cache(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    cache: GridLayoutCache,
+) {
+    if let Some(old_cache) = component_try_mut::<GridLayoutCache>(ecm, entity, GRID_LAYOUT_CACHE) {
+        *old_cache = cache;
+        return;
+    }
+
+    ecm.component_store_mut()
+        .register(GRID_LAYOUT_CACHE, entity, cache);
+}
 
 /// Orders its children in a grid layout with columns and rows. If now columns and rows are defined
 /// the gird layout could also be used as alignment layout.
-#[derive(Default)]
-pub struct GridLayout {
-    desired_size: RefCell<DirtySize>,
-    children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
-    old_alignment: Cell<(Alignment, Alignment)>,
-}
+///
+/// The layout has no state of its own, the sizes of a layout pass are cached in the
+/// `GridLayoutCache` component of each grid. This way the same layout could be used for multiple
+/// grids.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GridLayout;
 
 impl GridLayout {
     pub fn new() -> Self {
         GridLayout::default()
     }
 
+    // Returns the column and the row of each child. The children without a cell are placed by the
+    // auto flow of the grid, the rows or columns the flow needs beyond the defined ones are added
+    // as auto rows or columns.
+    fn child_cells(
+        &self,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        entity: Entity,
+    ) -> BTreeMap<Entity, (Option<usize>, Option<usize>)> {
+        let children = ecm.entity_store().children[&entity].clone();
+        let store = ecm.component_store();
+        let cells: Vec<(Option<usize>, Option<usize>)> = children
+            .iter()
+            .map(|child| {
+                (
+                    store.get::<usize>("column", *child).ok().copied(),
+                    store.get::<usize>("row", *child).ok().copied(),
+                )
+            })
+            .collect();
+        let flow = store
+            .get::<GridFlow>("auto_flow", entity)
+            .map(|flow| *flow)
+            .unwrap_or_default();
+        let column_count = store
+            .get::<Columns>("columns", entity)
+            .map_or(0, |columns| columns.len());
+        let row_count = store
+            .get::<Rows>("rows", entity)
+            .map_or(0, |rows| rows.len());
+
+        let cells = flow.place(&cells, column_count, row_count);
+
+        match flow {
+            GridFlow::Row => {
+                let row_count = cells
+                    .iter()
+                    .filter_map(|cell| cell.1)
+                    .max()
+                    .map_or(0, |row| row + 1);
+
+                if let Some(rows) = component_try_mut::<Rows>(ecm, entity, "rows") {
+                    while rows.len() < row_count {
+                        rows.push(Row::from("auto"));
+                    }
+                }
+            }
+            GridFlow::Column => {
+                let column_count = cells
+                    .iter()
+                    .filter_map(|cell| cell.0)
+                    .max()
+                    .map_or(0, |column| column + 1);
+
+                if let Some(columns) = component_try_mut::<Columns>(ecm, entity, "columns") {
+                    while columns.len() < column_count {
+                        columns.push(Column::from("auto"));
+                    }
+                }
+            }
+            GridFlow::None => {}
+        }
+
+        children.into_iter().zip(cells).collect()
+    }
+
     // calculates the available width for a column
     fn get_column_x_and_width(
         &self,
@@ -73,6 +177,7 @@ impl GridLayout {
 
     fn calculate_column_width(
         &self,
+        children_sizes: &BTreeMap<Entity, (f64, f64)>,
         child: Entity,
         column: Column,
         grid_column: usize,
@@ -82,19 +187,22 @@ impl GridLayout {
         if column.width != ColumnWidth::Auto {
             return;
         }
-        let child_width = self.children_sizes.borrow().get(&child).unwrap().0;
+        let child_width = children_sizes
+            .get(&child)
+            .map_or(0.0, |size| outer_size(*size, margin).0);
 
         if let Some(width) = column_widths.get(&grid_column) {
-            if *width < child_width + margin.left() + margin.right() {
-                column_widths.insert(grid_column, child_width + margin.left() + margin.right());
+            if *width < child_width {
+                column_widths.insert(grid_column, child_width);
             }
         } else {
-            column_widths.insert(grid_column, child_width + margin.left() + margin.right());
+            column_widths.insert(grid_column, child_width);
         }
     }
 
     fn calculate_row_height(
         &self,
+        children_sizes: &BTreeMap<Entity, (f64, f64)>,
         child: Entity,
         row: Row,
         grid_row: usize,
@@ -105,14 +213,16 @@ impl GridLayout {
             return;
         }
 
-        let child_height = self.children_sizes.borrow().get(&child).unwrap().1;
+        let child_height = children_sizes
+            .get(&child)
+            .map_or(0.0, |size| outer_size(*size, margin).1);
 
         if let Some(height) = row_heights.get(&grid_row) {
-            if *height < child_height + margin.top() + margin.bottom() {
-                row_heights.insert(grid_row, child_height + margin.top() + margin.bottom());
+            if *height < child_height {
+                row_heights.insert(grid_row, child_height);
             }
         } else {
-            row_heights.insert(grid_row, child_height + margin.top() + margin.bottom());
+            row_heights.insert(grid_row, child_height);
         }
     }
 
@@ -247,24 +357,27 @@ impl Layout for GridLayout {
         render_context_2_d: &mut RenderContext2D,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> DirtySize {
+        let mut cache = take_cache(ecm, entity);
+
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return *self.desired_size.borrow();
+            cache.desired_size.set_size(0.0, 0.0);
+            let desired_size = cache.desired_size;
+            store_cache(ecm, entity, cache);
+            return desired_size;
         }
 
         let horizontal_alignment: Alignment = component(ecm, entity, "horizontal_alignment");
         let vertical_alignment: Alignment = component(ecm, entity, "vertical_alignment");
 
-        if horizontal_alignment != self.old_alignment.get().1
-            || vertical_alignment != self.old_alignment.get().0
-        {
-            self.desired_size.borrow_mut().set_dirty(true);
+        // grids that are not stretched are arranged on each pass
+        if horizontal_alignment != Alignment::Stretch || vertical_alignment != Alignment::Stretch {
+            cache.desired_size.set_dirty(true);
         }
 
-        self.children_sizes.borrow_mut().clear();
+        cache.children_sizes.clear();
         let mut desired_size: (f64, f64) = (0.0, 0.0);
 
         for index in 0..ecm.entity_store().children[&entity].len() {
@@ -273,31 +386,32 @@ impl Layout for GridLayout {
                 let child_desired_size =
                     child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
 
-                let dirty = child_desired_size.dirty() || self.desired_size.borrow().dirty();
+                let dirty = child_desired_size.dirty() || cache.desired_size.dirty();
 
-                self.desired_size.borrow_mut().set_dirty(dirty);
-                desired_size.0 = desired_size.0.max(child_desired_size.width());
-                desired_size.1 = desired_size.1.max(child_desired_size.height());
+                cache.desired_size.set_dirty(dirty);
+                let child_size = outer_size(
+                    child_desired_size.size(),
+                    component_or_default(ecm, child, "margin"),
+                );
+                desired_size.0 = desired_size.0.max(child_size.0);
+                desired_size.1 = desired_size.1.max(child_size.1);
 
-                self.children_sizes.borrow_mut().insert(
+                cache.children_sizes.insert(
                     child,
                     (child_desired_size.width(), child_desired_size.height()),
                 );
             }
         }
 
-        self.desired_size
-            .borrow_mut()
-            .set_size(desired_size.0, desired_size.1);
+        cache.desired_size.set_size(desired_size.0, desired_size.1);
 
-        let size = ecm
-            .component_store()
-            .get::<Constraint>("constraint", entity)
-            .unwrap()
-            .perform(self.desired_size.borrow().size());
-        self.desired_size.borrow_mut().set_size(size.0, size.1);
+        let size =
+            component::<Constraint>(ecm, entity, "constraint").measure(cache.desired_size.size());
+        cache.desired_size.set_size(size.0, size.1);
 
-        *self.desired_size.borrow()
+        let desired_size = cache.desired_size;
+        store_cache(ecm, entity, cache);
+        desired_size
     }
 
     fn arrange(
@@ -306,38 +420,49 @@ impl Layout for GridLayout {
         parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> (f64, f64) {
+        let mut cache = take_cache(ecm, entity);
+
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            cache.desired_size.set_size(0.0, 0.0);
+            store_cache(ecm, entity, cache);
             return (0.0, 0.0);
         }
 
-        if !self.desired_size.borrow().dirty() {
-            return self.desired_size.borrow().size();
+        if !cache.desired_size.dirty() {
+            let size = cache.desired_size.size();
+            store_cache(ecm, entity, cache);
+            return size;
         }
 
         let horizontal_alignment: Alignment = component(ecm, entity, "horizontal_alignment");
         let vertical_alignment: Alignment = component(ecm, entity, "vertical_alignment");
         let margin: Thickness = *ecm.component_store().get("margin", entity).unwrap();
-        let constraint: Constraint = component(ecm, entity, "constraint");
-
-        let size = constraint.perform((
-            horizontal_alignment.align_measure(
-                parent_size.0,
-                self.desired_size.borrow().width(),
-                margin.left(),
-                margin.right(),
+        let constraint = component::<Constraint>(ecm, entity, "constraint").resolve(parent_size);
+
+        let size = constraint.fit(
+            (
+                horizontal_alignment.align_measure(
+                    parent_size.0,
+                    cache.desired_size.width(),
+                    margin.left(),
+                    margin.right(),
+                ),
+                vertical_alignment.align_measure(
+                    parent_size.1,
+                    cache.desired_size.height(),
+                    margin.top(),
+                    margin.bottom(),
+                ),
             ),
-            vertical_alignment.align_measure(
-                parent_size.1,
-                self.desired_size.borrow().height(),
-                margin.top(),
-                margin.bottom(),
-            ),
-        ));
+            available_size(parent_size, margin),
+        );
+
+        let size = apply_aspect_ratio(ecm, entity, size);
 
+        let cells = self.child_cells(ecm, entity);
         let mut columns_cache = Vec::new();
         let mut column_widths = BTreeMap::new();
         let mut rows_cache = Vec::new();
@@ -350,13 +475,14 @@ impl Layout for GridLayout {
 
             let margin: Thickness = component(ecm, entity, "margin");
 
-            if let Ok(grid_column) = ecm.component_store().get::<usize>("column", child) {
+            if let Some(grid_column) = cells[&child].0 {
                 if let Ok(columns) = ecm.component_store().get::<Columns>("columns", entity) {
-                    if let Some(column) = columns.get(*grid_column) {
+                    if let Some(column) = columns.get(grid_column) {
                         self.calculate_column_width(
+                            &cache.children_sizes,
                             child,
                             *column,
-                            *grid_column,
+                            grid_column,
                             &mut column_widths,
                             margin,
                         );
@@ -364,12 +490,17 @@ impl Layout for GridLayout {
                 }
             }
 
-            if let Ok(grid_row) = ecm.component_store().get::<usize>("row", child) {
-                let grid_row = *grid_row;
-
+            if let Some(grid_row) = cells[&child].1 {
                 if let Ok(rows) = ecm.component_store().get::<Rows>("rows", entity) {
                     if let Some(row) = rows.get(grid_row) {
-                        self.calculate_row_height(child, *row, grid_row, &mut row_heights, margin);
+                        self.calculate_row_height(
+                            &cache.children_sizes,
+                            child,
+                            *row,
+                            grid_row,
+                            &mut row_heights,
+                            margin,
+                        );
                     }
                 }
             }
@@ -414,12 +545,7 @@ impl Layout for GridLayout {
 
             // column
             if has_columns {
-                let grid_column =
-                    if let Ok(grid_column) = ecm.component_store().get::<usize>("column", child) {
-                        *grid_column
-                    } else {
-                        0
-                    };
+                let grid_column = cells[&child].0.unwrap_or(0);
 
                 let (offset_x, available_width) = self.get_column_x_and_width(
                     &columns_cache,
@@ -442,12 +568,7 @@ impl Layout for GridLayout {
 
             // rows
             if has_rows {
-                let grid_row =
-                    if let Ok(grid_row) = ecm.component_store().get::<usize>("row", child) {
-                        *grid_row
-                    } else {
-                        0
-                    };
+                let grid_row = cells[&child].1.unwrap_or(0);
 
                 let (offset_y, available_height) = self.get_row_y_and_height(
                     &rows_cache,
@@ -509,12 +630,15 @@ impl Layout for GridLayout {
             }
         }
 
+        mirror_children(ecm, entity, size.0);
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
             bounds.set_height(size.1);
         }
 
-        self.desired_size.borrow_mut().set_dirty(false);
+        cache.desired_size.set_dirty(false);
+        store_cache(ecm, entity, cache);
         size
     }
 }
@@ -524,3 +648,29 @@ impl Into<Box<dyn Layout>> for GridLayout {
         Box::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use dces::prelude::World;
+
+    use super::*;
+
+    #[test]
+    fn test_cache_by_entity() {
+        let mut world = World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+        let first = ecm.create_entity().build();
+        let second = ecm.create_entity().build();
+
+        let mut cache = take_cache(ecm, first);
+        assert!(cache.desired_size.dirty());
+
+        cache.desired_size.set_size(10.0, 20.0);
+        cache.desired_size.set_dirty(false);
+        cache.children_sizes.insert(second, (5.0, 5.0));
+        store_cache(ecm, first, cache.clone());
+
+        assert!(take_cache(ecm, second) == GridLayoutCache::default());
+        assert!(take_cache(ecm, first) == cache);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{any::Any, collections::BTreeMap};
+use std::any::Any;
 
 use dces::prelude::{Component, Entity, EntityComponentManager};
 
@@ -21,14 +21,26 @@ mod stack;
 mod text_selection;
 
 /// A layout is used to dynamic order the children of a widget.
+///
+/// The layout system runs in two passes. The `measure` pass walks the tree from the root and
+/// each layout measures all of its children before it calculates its own desired size. The
+/// `arrange` pass then passes the available size down the tree and each layout sizes and
+/// positions its children with the desired sizes of the measure pass.
+///
+/// All layouts handle margins and overflow in the same way. The margin of a child is part of the
+/// space it takes in its parent. A negative margin moves the child over the edge of that space
+/// and lets it overlap its neighbours, but the space never gets smaller than zero. A child that
+/// is larger than the space of its parent keeps its size and overflows the parent on the side
+/// opposite to its alignment. Overflowing children are drawn and receive mouse events outside of
+/// their parent, unless the `clip` property of the parent is set.
 pub trait Layout: Any {
-    // Measure all children before the arrangement.
+    /// Measures all children and returns the desired size of the widget.
     fn measure(
         &self,
         render_context_2_d: &mut RenderContext2D,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> DirtySize;
 
@@ -39,11 +51,48 @@ pub trait Layout: Any {
         parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> (f64, f64);
 }
 
+/// Stores the layout of the given widget. The built-in layouts are stored unboxed in an arena per
+/// layout type, other layouts are stored boxed.
+pub fn insert_layout(layouts: &mut ArenaMap<dyn Layout>, entity: Entity, layout: Box<dyn Layout>) {
+    let layout = Some(layout)
+        .and_then(|layout| insert_as::<AbsoluteLayout>(layouts, entity, layout))
+        .and_then(|layout| insert_as::<FixedSizeLayout>(layouts, entity, layout))
+        .and_then(|layout| insert_as::<GridLayout>(layouts, entity, layout))
+        .and_then(|layout| insert_as::<PaddingLayout>(layouts, entity, layout))
+        .and_then(|layout| insert_as::<ScrollLayout>(layouts, entity, layout))
+        .and_then(|layout| insert_as::<StackLayout>(layouts, entity, layout))
+        .and_then(|layout| insert_as::<TextSelectionLayout>(layouts, entity, layout));
+
+    if let Some(layout) = layout {
+        layouts.insert_boxed(entity, layout);
+    }
+}
+
+// Inserts the layout into the arena of `L` if it is a `L`, otherwise it is returned.
+fn insert_as<L: Layout>(
+    layouts: &mut ArenaMap<dyn Layout>,
+    entity: Entity,
+    layout: Box<dyn Layout>,
+) -> Option<Box<dyn Layout>> {
+    if !(&*layout as &dyn Any).is::<L>() {
+        return Some(layout);
+    }
+
+    let layout: Box<dyn Any> = layout;
+    layouts.insert(
+        entity,
+        *layout.downcast::<L>().unwrap(),
+        |layout| layout,
+        |layout| layout,
+    );
+    None
+}
+
 fn component<C: Component + Clone>(
     ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
     entity: Entity,
@@ -75,3 +124,98 @@ fn component_try_mut<'a, C: Component>(
         .get_mut::<C>(component, entity)
         .ok()
 }
+
+// Returns the size that is available for a widget with the given margin inside of its parent.
+fn available_size(parent_size: (f64, f64), margin: Thickness) -> (f64, f64) {
+    (
+        (parent_size.0 - margin.left() - margin.right()).max(0.0),
+        (parent_size.1 - margin.top() - margin.bottom()).max(0.0),
+    )
+}
+
+// Returns the space a widget with the given size and margin takes in its parent.
+fn outer_size(size: (f64, f64), margin: Thickness) -> (f64, f64) {
+    (
+        (size.0 + margin.left() + margin.right()).max(0.0),
+        (size.1 + margin.top() + margin.bottom()).max(0.0),
+    )
+}
+
+// Fits the given size into the aspect ratio of the widget if it has one.
+fn apply_aspect_ratio(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    size: (f64, f64),
+) -> (f64, f64) {
+    ecm.component_store()
+        .get::<AspectRatio>("aspect_ratio", entity)
+        .map_or(size, |aspect_ratio| aspect_ratio.fit(size))
+}
+
+/// Returns the flow direction of the widget. If the widget has no own flow direction, it is
+/// inherited from its parents.
+pub fn flow_direction(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> FlowDirection {
+    let mut current = Some(entity);
+
+    while let Some(entity) = current {
+        if let Ok(flow_direction) = ecm
+            .component_store()
+            .get::<FlowDirection>("flow_direction", entity)
+        {
+            return *flow_direction;
+        }
+
+        current = ecm.entity_store().parent.get(&entity).copied().flatten();
+    }
+
+    FlowDirection::default()
+}
+
+/// Returns the bounds of the widget after the last layout pass with its position in window
+/// coordinates. The bounds of a widget are relative to its parent, so the positions of all
+/// parents are added. Returns `None` if the widget has no bounds.
+pub fn window_bounds(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> Option<Rectangle> {
+    let mut bounds = *ecm
+        .component_store()
+        .get::<Rectangle>("bounds", entity)
+        .ok()?;
+    let mut current = ecm.entity_store().parent.get(&entity).copied().flatten();
+
+    while let Some(parent) = current {
+        if let Ok(parent_bounds) = ecm.component_store().get::<Rectangle>("bounds", parent) {
+            bounds.set_x(bounds.x() + parent_bounds.x());
+            bounds.set_y(bounds.y() + parent_bounds.y());
+        }
+
+        current = ecm.entity_store().parent.get(&parent).copied().flatten();
+    }
+
+    Some(bounds)
+}
+
+// Mirrors the horizontal positions of the children inside of the given width, if the flow
+// direction of the widget is right to left. This also mirrors the order, the horizontal
+// alignment and the margins of the children.
+fn mirror_children(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    width: f64,
+) {
+    if !flow_direction(ecm, entity).is_right_to_left() {
+        return;
+    }
+
+    for index in 0..ecm.entity_store().children[&entity].len() {
+        let child = ecm.entity_store().children[&entity][index];
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+            bounds.set_x(width - bounds.x() - bounds.width());
+        }
+    }
+}
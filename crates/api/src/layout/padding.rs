@@ -1,13 +1,13 @@
-use std::{
-    cell::{Cell, RefCell},
-    collections::BTreeMap,
-};
+use std::cell::{Cell, RefCell};
 
 use dces::prelude::Entity;
 
 use crate::{prelude::*, render::RenderContext2D, tree::Tree, utils::prelude::*};
 
-use super::{component, component_try_mut, Layout};
+use super::{
+    apply_aspect_ratio, available_size, component, component_try_mut, mirror_children, outer_size,
+    Layout,
+};
 
 /// Add padding to the widget.
 #[derive(Default)]
@@ -28,7 +28,7 @@ impl Layout for PaddingLayout {
         render_context_2_d: &mut RenderContext2D,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> DirtySize {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -45,17 +45,6 @@ impl Layout for PaddingLayout {
             self.desired_size.borrow_mut().set_dirty(true);
         }
 
-        let constraint: Constraint = component(ecm, entity, "constraint");
-        if constraint.width() > 0.0 {
-            self.desired_size.borrow_mut().set_width(constraint.width());
-        }
-
-        if constraint.height() > 0.0 {
-            self.desired_size
-                .borrow_mut()
-                .set_height(constraint.height());
-        }
-
         let padding: Thickness = component(ecm, entity, "padding");
 
         for index in 0..ecm.entity_store().children[&entity].len() {
@@ -74,20 +63,14 @@ impl Layout for PaddingLayout {
                     .get::<Thickness>("margin", child)
                     .unwrap();
 
-                desired_size.0 = desired_size.0.max(
-                    child_desired_size.width()
-                        + padding.left()
-                        + padding.right()
-                        + child_margin.left()
-                        + child_margin.right(),
-                );
-                desired_size.1 = desired_size.1.max(
-                    child_desired_size.height()
-                        + padding.top()
-                        + padding.bottom()
-                        + child_margin.top()
-                        + child_margin.left(),
-                );
+                let child_size = outer_size(child_desired_size.size(), child_margin);
+
+                desired_size.0 = desired_size
+                    .0
+                    .max(child_size.0 + padding.left() + padding.right());
+                desired_size.1 = desired_size
+                    .1
+                    .max(child_size.1 + padding.top() + padding.bottom());
 
                 self.desired_size
                     .borrow_mut()
@@ -95,6 +78,12 @@ impl Layout for PaddingLayout {
             }
         }
 
+        let desired_size = component::<Constraint>(ecm, entity, "constraint")
+            .measure(self.desired_size.borrow().size());
+        self.desired_size
+            .borrow_mut()
+            .set_size(desired_size.0, desired_size.1);
+
         *self.desired_size.borrow()
     }
 
@@ -104,7 +93,7 @@ impl Layout for PaddingLayout {
         parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> (f64, f64) {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -123,22 +112,27 @@ impl Layout for PaddingLayout {
             .get::<Thickness>("margin", entity)
             .unwrap();
         let padding: Thickness = component(ecm, entity, "padding");
-        let constraint: Constraint = component(ecm, entity, "constraint");
-
-        let size = constraint.perform((
-            horizontal_alignment.align_measure(
-                parent_size.0,
-                self.desired_size.borrow().width(),
-                margin.left(),
-                margin.right(),
-            ),
-            vertical_alignment.align_measure(
-                parent_size.1,
-                self.desired_size.borrow().height(),
-                margin.top(),
-                margin.bottom(),
+        let constraint = component::<Constraint>(ecm, entity, "constraint").resolve(parent_size);
+
+        let size = constraint.fit(
+            (
+                horizontal_alignment.align_measure(
+                    parent_size.0,
+                    self.desired_size.borrow().width(),
+                    margin.left(),
+                    margin.right(),
+                ),
+                vertical_alignment.align_measure(
+                    parent_size.1,
+                    self.desired_size.borrow().height(),
+                    margin.top(),
+                    margin.bottom(),
+                ),
             ),
-        ));
+            available_size(parent_size, margin),
+        );
+
+        let size = apply_aspect_ratio(ecm, entity, size);
 
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
@@ -146,14 +140,14 @@ impl Layout for PaddingLayout {
         }
 
         let available_size = (
-            size.0 - padding.left() - padding.right(),
-            size.1 - padding.top() - padding.bottom(),
+            (size.0 - padding.left() - padding.right()).max(0.0),
+            (size.1 - padding.top() - padding.bottom()).max(0.0),
         );
 
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
 
-            let child_margin: Thickness = component(ecm, entity, "margin");
+            let child_margin: Thickness = component(ecm, child, "margin");
 
             if let Some(child_layout) = layouts.get(&child) {
                 child_layout.arrange(
@@ -199,6 +193,8 @@ impl Layout for PaddingLayout {
             }
         }
 
+        mirror_children(ecm, entity, size.0);
+
         self.desired_size.borrow_mut().set_dirty(false);
         size
     }
@@ -1,6 +1,5 @@
 use std::{
     cell::{Cell, RefCell},
-    collections::BTreeMap,
     f64,
 };
 
@@ -8,7 +7,7 @@ use dces::prelude::Entity;
 
 use crate::{prelude::*, render::RenderContext2D, tree::Tree, utils::prelude::*};
 
-use super::{component, component_try_mut, Layout};
+use super::{available_size, component, component_try_mut, Layout};
 
 /// IMPORTANT: The scroll layout will only work for the text box now. A update will follow!!!!
 #[derive(Default)]
@@ -31,7 +30,7 @@ impl Layout for ScrollLayout {
         render_context_2_d: &mut RenderContext2D,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> DirtySize {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -48,17 +47,11 @@ impl Layout for ScrollLayout {
             self.desired_size.borrow_mut().set_dirty(true);
         }
 
-        let constraint: Constraint = component(ecm, entity, "constraint");
-
-        if constraint.width() > 0.0 {
-            self.desired_size.borrow_mut().set_width(constraint.width());
-        }
-
-        if constraint.height() > 0.0 {
-            self.desired_size
-                .borrow_mut()
-                .set_height(constraint.height());
-        }
+        let desired_size = component::<Constraint>(ecm, entity, "constraint")
+            .measure(self.desired_size.borrow().size());
+        self.desired_size
+            .borrow_mut()
+            .set_size(desired_size.0, desired_size.1);
 
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
@@ -91,7 +84,7 @@ impl Layout for ScrollLayout {
         parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> (f64, f64) {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -107,22 +100,25 @@ impl Layout for ScrollLayout {
         let vertical_alignment: Alignment = component(ecm, entity, "vertical_alignment");
         let margin: Thickness = *ecm.component_store().get("margin", entity).unwrap();
         // let _padding = Thickness::get("padding", entity, ecm.component_store());
-        let constraint: Constraint = component(ecm, entity, "constraint");
-
-        let size = constraint.perform((
-            horizontal_alignment.align_measure(
-                parent_size.0,
-                self.desired_size.borrow().width(),
-                margin.left(),
-                margin.right(),
+        let constraint = component::<Constraint>(ecm, entity, "constraint").resolve(parent_size);
+
+        let size = constraint.fit(
+            (
+                horizontal_alignment.align_measure(
+                    parent_size.0,
+                    self.desired_size.borrow().width(),
+                    margin.left(),
+                    margin.right(),
+                ),
+                vertical_alignment.align_measure(
+                    parent_size.1,
+                    self.desired_size.borrow().height(),
+                    margin.top(),
+                    margin.bottom(),
+                ),
             ),
-            vertical_alignment.align_measure(
-                parent_size.1,
-                self.desired_size.borrow().height(),
-                margin.top(),
-                margin.bottom(),
-            ),
-        ));
+            available_size(parent_size, margin),
+        );
 
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
@@ -186,12 +182,9 @@ impl Layout for ScrollLayout {
             }
 
             match scroll_viewer_mode.horizontal {
+                // the offset is set from outside, it is only clamped to the size of the child
                 ScrollMode::Custom => {
-                    if child_size.0 > size.0 {
-                        offset.0 = (offset.0 + old_child_size.0 - child_size.0).min(0.0);
-                    } else {
-                        offset.0 = 0.0;
-                    }
+                    offset.0 = offset.0.min(0.0).max((size.0 - child_size.0).min(0.0));
                 }
                 ScrollMode::Auto => {
                     // todo: refactor * 1.5
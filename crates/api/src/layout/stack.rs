@@ -1,13 +1,13 @@
-use std::{
-    cell::{Cell, RefCell},
-    collections::BTreeMap,
-};
+use std::cell::{Cell, RefCell};
 
 use dces::prelude::Entity;
 
 use crate::{prelude::*, render::RenderContext2D, tree::Tree, utils::prelude::*};
 
-use super::{component, component_or_default, component_try_mut, Layout};
+use super::{
+    apply_aspect_ratio, available_size, component, component_or_default, component_try_mut,
+    mirror_children, outer_size, Layout,
+};
 
 /// Stacks visual the children widgets vertical or horizontal.
 #[derive(Default)]
@@ -32,7 +32,7 @@ impl Layout for StackLayout {
         render_context_2_d: &mut RenderContext2D,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> DirtySize {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -90,6 +90,7 @@ impl Layout for StackLayout {
 
         self.set_dirty(dirty);
 
+        let desired_size = component::<Constraint>(ecm, entity, "constraint").measure(desired_size);
         let mut desired = self.desired_size.borrow_mut();
         desired.set_size(desired_size.0, desired_size.1);
         desired.clone()
@@ -101,7 +102,7 @@ impl Layout for StackLayout {
         parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        layouts: &ArenaMap<dyn Layout>,
         theme: &ThemeValue,
     ) -> (f64, f64) {
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
@@ -116,25 +117,30 @@ impl Layout for StackLayout {
         let halign: Alignment = component(ecm, entity, "horizontal_alignment");
         let valign: Alignment = component(ecm, entity, "vertical_alignment");
         let margin: Thickness = component(ecm, entity, "margin");
-        let constraint: Constraint = component(ecm, entity, "constraint");
+        let constraint = component::<Constraint>(ecm, entity, "constraint").resolve(parent_size);
         let orientation: Orientation = component(ecm, entity, "orientation");
 
         let mut size_counter = 0.0;
 
-        let mut size = constraint.perform((
-            halign.align_measure(
-                parent_size.0,
-                self.desired_size.borrow().width(),
-                margin.left(),
-                margin.right(),
-            ),
-            valign.align_measure(
-                parent_size.1,
-                self.desired_size.borrow().height(),
-                margin.top(),
-                margin.bottom(),
+        let mut size = constraint.fit(
+            (
+                halign.align_measure(
+                    parent_size.0,
+                    self.desired_size.borrow().width(),
+                    margin.left(),
+                    margin.right(),
+                ),
+                valign.align_measure(
+                    parent_size.1,
+                    self.desired_size.borrow().height(),
+                    margin.top(),
+                    margin.bottom(),
+                ),
             ),
-        ));
+            available_size(parent_size, margin),
+        );
+
+        size = apply_aspect_ratio(ecm, entity, size);
 
         let available_size = size;
         let nchildren = ecm.entity_store().children[&entity].len();
@@ -204,6 +210,11 @@ impl Layout for StackLayout {
             }
         }
 
+        // the stack is sized to its children, but a fixed or min size of the stack still wins
+        size = constraint.perform(size);
+
+        mirror_children(ecm, entity, size.0);
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
             bounds.set_height(size.1);
@@ -245,7 +256,7 @@ fn apply_arrangement(
                 margin.bottom(),
             );
 
-            size = bounds.width() + margin.left() + margin.right();
+            size = outer_size((bounds.width(), bounds.height()), margin).0;
         }
         _ => {
             xpos = alignment.0.align_position(
@@ -263,7 +274,7 @@ fn apply_arrangement(
                     margin.bottom(),
                 );
 
-            size = bounds.height() + margin.top() + margin.bottom();
+            size = outer_size((bounds.width(), bounds.height()), margin).1;
         }
     };
 
@@ -305,8 +316,7 @@ fn accumulate_desired_size(
     margin: Thickness,
     orientation: Orientation,
 ) {
-    let width = desired.width() + margin.left() + margin.right();
-    let height = desired.height() + margin.top() + margin.bottom();
+    let (width, height) = outer_size(desired.size(), margin);
 
     match orientation {
         Orientation::Horizontal => {
@@ -49,6 +49,7 @@ macro_rules! widget {
         pub struct $widget {
             attached_properties: HashMap<String, ComponentBox>,
             shared_attached_properties: HashMap<(String, String), SharedComponentBox>,
+            localized_properties: std::collections::BTreeMap<String, String>,
             event_handlers: Vec<Rc<dyn EventHandler>>,
             bounds: Rectangle,
             position: Point,
@@ -58,6 +59,11 @@ macro_rules! widget {
             max_height: Option<f64>,
             width: Option<f64>,
             height: Option<f64>,
+            preferred_width: Option<f64>,
+            preferred_height: Option<f64>,
+            width_percent: Option<f64>,
+            height_percent: Option<f64>,
+            layout_triggers: Vec<LayoutTrigger>,
             name: Option<String>,
             horizontal_alignment: Alignment,
             vertical_alignment: Alignment,
@@ -111,6 +117,11 @@ macro_rules! widget {
                 self
             }
 
+            // internal helper, returns `true` if the attached property is set or shared
+            fn has_property(&self, key: &str) -> bool {
+                self.attached_properties.contains_key(key) || self.shared_attached_properties.keys().any(|k| k.0 == key)
+            }
+
             /// Sets or shares the constraint property.
             pub fn position(self, position: impl IntoPropertySource<Point>) -> Self {
                 self.set_property("position", position)
@@ -146,7 +157,9 @@ macro_rules! widget {
                 self.set_property("enabled", enabled)
             }
 
-            /// Sets or shares the clip property.
+            /// Sets or shares the clip property. If it is set, the children that overflow the bounds
+            /// of the widget, e.g. because of negative margins, are clipped and receive mouse
+            /// events only inside of the bounds.
             pub fn clip(self, clip: impl IntoPropertySource<bool>) -> Self {
                 self.set_property("clip", clip)
             }
@@ -156,6 +169,68 @@ macro_rules! widget {
                 self.set_property("opacity", opacity)
             }
 
+            /// Sets or shares the z index property. Children with a higher z index are rendered on
+            /// top of their siblings and receive the mouse input first. The default is `0`.
+            pub fn z_index(self, z_index: impl IntoPropertySource<i32>) -> Self {
+                self.set_property("z_index", z_index)
+            }
+
+            /// Sets or shares the cache mode property. With `CacheMode::Texture` the widget and its
+            /// children are rendered once into a layer that is reused until it is invalidated.
+            pub fn cache_mode(self, cache_mode: impl IntoPropertySource<CacheMode>) -> Self {
+                self.set_property("cache_mode", cache_mode)
+            }
+
+            /// Sets or shares the name that is read by screen readers. If not set the text of the
+            /// widget is used.
+            pub fn accessible_name(self, accessible_name: impl IntoPropertySource<String>) -> Self {
+                if self.has_property("accessible_name") {
+                    return self;
+                }
+
+                self.set_property("accessible_name", accessible_name)
+            }
+
+            /// Sets or shares a stable id that is used by ui tests and automation tools to find the
+            /// widget, e.g. with `UiDriver::find`.
+            pub fn automation_id(self, automation_id: impl IntoPropertySource<String>) -> Self {
+                if self.has_property("automation_id") {
+                    return self;
+                }
+
+                self.set_property("automation_id", automation_id)
+            }
+
+            /// Sets or shares the role of the widget for assistive technologies. Only widgets with
+            /// a role are exposed in the accessibility tree.
+            pub fn accessible_role(self, accessible_role: impl IntoPropertySource<AccessibleRole>) -> Self {
+                if self.has_property("accessible_role") {
+                    return self;
+                }
+
+                self.set_property("accessible_role", accessible_role)
+            }
+
+            /// Sets or shares the flow direction of the widget. It is inherited by all children that
+            /// don't set an own flow direction.
+            pub fn flow_direction(self, flow_direction: impl IntoPropertySource<FlowDirection>) -> Self {
+                self.set_property("flow_direction", flow_direction)
+            }
+
+            /// Sets or shares the aspect ratio, that is kept while the widget is stretched.
+            pub fn aspect_ratio(self, aspect_ratio: impl IntoPropertySource<AspectRatio>) -> Self {
+                self.set_property("aspect_ratio", aspect_ratio)
+            }
+
+            /// Sets or shares the icon of the mouse cursor while it hovers the widget.
+            pub fn cursor(self, cursor: impl IntoPropertySource<CursorIcon>) -> Self {
+                if self.has_property("cursor") {
+                    return self;
+                }
+
+                self.set_property("cursor", cursor)
+            }
+
             /// Inserts a new width.
             pub fn width(mut self, width: f64) -> Self {
                 if !self.width.is_none() {
@@ -185,6 +260,53 @@ macro_rules! widget {
                 self
             }
 
+            /// Inserts a new preferred width.
+            pub fn preferred_width(mut self, preferred_width: f64) -> Self {
+                if !self.preferred_width.is_none() {
+                    return self;
+                }
+                self.preferred_width = Some(preferred_width);
+                self
+            }
+
+            /// Inserts a new preferred height.
+            pub fn preferred_height(mut self, preferred_height: f64) -> Self {
+                if !self.preferred_height.is_none() {
+                    return self;
+                }
+                self.preferred_height = Some(preferred_height);
+                self
+            }
+
+            /// Inserts a new preferred size.
+            pub fn preferred_size(mut self, preferred_width: f64, preferred_height: f64) -> Self {
+                if self.preferred_width.is_none() {
+                    self.preferred_width = Some(preferred_width);
+                }
+                if self.preferred_height.is_none() {
+                    self.preferred_height = Some(preferred_height);
+                }
+                self
+            }
+
+            /// Inserts a new width in percent of the available width of the parent.
+            pub fn width_percent(mut self, width_percent: f64) -> Self {
+                if !self.width_percent.is_none() {
+                    return self;
+                }
+                self.width_percent = Some(width_percent);
+                self
+            }
+
+            /// Inserts a new height in percent of the available height of the parent.
+            pub fn height_percent(mut self, height_percent: f64) -> Self {
+                if !self.height_percent.is_none() {
+                    return self;
+                }
+                self.height_percent = Some(height_percent);
+                self
+            }
+
             /// Inserts a new min_width.
             pub fn min_width(mut self, min_width: f64) -> Self {
                 if !self.min_width.is_none() {
@@ -243,6 +365,13 @@ macro_rules! widget {
                 self
             }
 
+            /// Adds a layout trigger that switches the properties of the widget if the width of
+            /// the window or the parent crosses its breakpoints.
+            pub fn layout_trigger(mut self, layout_trigger: LayoutTrigger) -> Self {
+                self.layout_triggers.push(layout_trigger);
+                self
+            }
+
             /// Sets the debug name of the widget.
             pub fn name<P: Into<String>>(mut self, name: P) -> Self {
                 self.name = Some(name.into());
@@ -264,6 +393,10 @@ macro_rules! widget {
                             return self;
                         }
 
+                        if let Some(key) = $property.localization_key() {
+                            self.localized_properties.insert(stringify!($property).to_string(), key);
+                        }
+
                         self.$property = Some($property.into_source());
                         self
                     }
@@ -288,6 +421,8 @@ macro_rules! widget {
             )*
         )*
 
+        impl SizeChangedHandler for $widget {}
+
         impl Widget for $widget {
             fn create() -> Self {
                 $widget {
@@ -318,7 +453,7 @@ macro_rules! widget {
             fn build(self, ctx: &mut BuildContext) -> Entity {
                 let entity = ctx.create_entity();
 
-                let this = self.template(entity, ctx);
+                let this = ctx.apply_template(self, entity);
 
                 ctx.register_render_object(entity, this.render_object());
                 ctx.register_layout(entity, this.layout());
@@ -348,6 +483,18 @@ macro_rules! widget {
                 if let Some(height) = this.height {
                     constraint.set_height(height);
                 }
+                if let Some(preferred_width) = this.preferred_width {
+                    constraint.set_preferred_width(preferred_width);
+                }
+                if let Some(preferred_height) = this.preferred_height {
+                    constraint.set_preferred_height(preferred_height);
+                }
+                if let Some(width_percent) = this.width_percent {
+                    constraint.set_width_percent(width_percent);
+                }
+                if let Some(height_percent) = this.height_percent {
+                    constraint.set_height_percent(height_percent);
+                }
                 if let Some(min_width) = this.min_width {
                     constraint.set_min_width(min_width);
                 }
@@ -362,6 +509,10 @@ macro_rules! widget {
                 }
                 ctx.register_property("constraint", entity, constraint);
 
+                if !this.layout_triggers.is_empty() {
+                    ctx.register_property("layout_triggers", entity, this.layout_triggers);
+                }
+
 
                 // register attached properties
                 for (key, property) in this.attached_properties {
@@ -394,6 +545,11 @@ macro_rules! widget {
                     )*
                 )*
 
+                // register the keys of localized properties, the translations are applied by the event state system
+                if !this.localized_properties.is_empty() {
+                    ctx.register_property("localized_properties", entity, LocalizedProperties::new(this.localized_properties));
+                }
+
                 // register event handlers
                 for handler in this.event_handlers {
                     ctx.register_handler(entity, handler);
@@ -413,3 +569,12 @@ macro_rules! widget {
         }
     };
 }
+
+/// Creates a `LocalizedText` with the given key, e.g. `.text(loc!("save_button"))`. The property
+/// is updated with the translation of the current language of the `Localization` service.
+#[macro_export]
+macro_rules! loc {
+    ($key:expr) => {
+        $crate::prelude::LocalizedText::new($key)
+    };
+}
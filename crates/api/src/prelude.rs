@@ -2,16 +2,21 @@ pub use dces::prelude::{Entity, EntityComponentManager, StringComponentStore};
 
 pub use crate::{
     application::*,
-    css_engine::{Selector, SelectorRelation, Theme as ThemeValue, ThemeBuilder},
+    css_engine::{
+        Selector, SelectorRelation, Symbol, Theme as ThemeValue, ThemeBuilder, ThemeExtension,
+        ThemeLayer, THEME_PROPERTIES,
+    },
     event::*,
     into_property_source,
     layout::*,
+    loc,
     macros::*,
     properties::*,
     render_object::*,
     services::*,
+    shell::{monitor_of, Monitor},
     systems::*,
-    tree::Tree,
+    tree::{ArenaMap, EntityMap, Tree},
     trigger_event, widget,
     widget::*,
 };
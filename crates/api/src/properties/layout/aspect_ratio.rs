@@ -0,0 +1,71 @@
+/// Describes a fixed width:height ratio of a widget. If it is set, the widget keeps the ratio
+/// while it is stretched, the limiting dimension of the available size is used. A width or
+/// height of `0.0` disables the aspect ratio.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct AspectRatio {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl AspectRatio {
+    /// Creates a new aspect ratio, e.g. `AspectRatio::new(16.0, 9.0)`.
+    pub fn new(width: f64, height: f64) -> Self {
+        AspectRatio { width, height }
+    }
+
+    /// Returns `true` if the aspect ratio is used.
+    pub fn is_enabled(&self) -> bool {
+        self.width > 0.0 && self.height > 0.0
+    }
+
+    /// Returns the largest size with the aspect ratio that fits into the given size.
+    pub fn fit(&self, size: (f64, f64)) -> (f64, f64) {
+        if !self.is_enabled() {
+            return size;
+        }
+
+        let ratio = self.width / self.height;
+
+        if size.0 / ratio <= size.1 {
+            (size.0, size.0 / ratio)
+        } else {
+            (size.1 * ratio, size.1)
+        }
+    }
+}
+
+impl From<f64> for AspectRatio {
+    fn from(ratio: f64) -> Self {
+        AspectRatio::new(ratio, 1.0)
+    }
+}
+
+impl From<(f64, f64)> for AspectRatio {
+    fn from(t: (f64, f64)) -> Self {
+        AspectRatio::new(t.0, t.1)
+    }
+}
+
+impl From<(i32, i32)> for AspectRatio {
+    fn from(t: (i32, i32)) -> Self {
+        AspectRatio::new(t.0 as f64, t.1 as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit() {
+        let aspect_ratio = AspectRatio::from((16, 9));
+
+        // limited by the width
+        assert_eq!(aspect_ratio.fit((160.0, 200.0)), (160.0, 90.0));
+
+        // limited by the height
+        assert_eq!(aspect_ratio.fit((400.0, 90.0)), (160.0, 90.0));
+
+        assert_eq!(AspectRatio::default().fit((400.0, 90.0)), (400.0, 90.0));
+    }
+}
@@ -196,6 +196,11 @@ impl Columns {
     pub fn iter_mut(&mut self) -> IterMut<Column> {
         self.0.iter_mut()
     }
+
+    /// Appends the given column.
+    pub fn push(&mut self, column: impl Into<Column>) {
+        self.0.push(column.into());
+    }
 }
 
 #[cfg(test)]
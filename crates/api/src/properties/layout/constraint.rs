@@ -5,10 +5,14 @@ use std::f64;
 pub struct ConstraintBuilder {
     width: f64,
     height: f64,
+    preferred_width: f64,
+    preferred_height: f64,
     min_width: f64,
     min_height: f64,
     max_width: f64,
     max_height: f64,
+    width_percent: f64,
+    height_percent: f64,
 }
 
 /// Used to build a constraint, specifying additional details.
@@ -37,6 +41,37 @@ impl ConstraintBuilder {
         self
     }
 
+    /// Inserts a new preferred width.
+    pub fn preferred_width(mut self, preferred_width: f64) -> Self {
+        self.preferred_width = preferred_width;
+        self
+    }
+
+    /// Inserts a new preferred height.
+    pub fn preferred_height(mut self, preferred_height: f64) -> Self {
+        self.preferred_height = preferred_height;
+        self
+    }
+
+    /// Inserts a new preferred size.
+    pub fn preferred_size(mut self, preferred_width: f64, preferred_height: f64) -> Self {
+        self.preferred_width = preferred_width;
+        self.preferred_height = preferred_height;
+        self
+    }
+
+    /// Inserts a new width in percent of the available width of the parent.
+    pub fn width_percent(mut self, width_percent: f64) -> Self {
+        self.width_percent = width_percent;
+        self
+    }
+
+    /// Inserts a new height in percent of the available height of the parent.
+    pub fn height_percent(mut self, height_percent: f64) -> Self {
+        self.height_percent = height_percent;
+        self
+    }
+
     /// Inserts a new min_width.
     pub fn min_width(mut self, min_width: f64) -> Self {
         self.min_width = min_width;
@@ -80,23 +115,39 @@ impl ConstraintBuilder {
         Constraint {
             width: self.width,
             height: self.height,
+            preferred_width: self.preferred_width,
+            preferred_height: self.preferred_height,
             min_width: self.min_width,
             min_height: self.min_height,
             max_width: self.max_width,
             max_height: self.max_height,
+            width_percent: self.width_percent,
+            height_percent: self.height_percent,
         }
     }
 }
 
 /// `Constraint` describes a box constraint.
+///
+/// A fixed `width` and `height` replace the measured size of the widget, also if it is stretched.
+/// The preferred size is used as desired size instead of the measured size, a stretched widget
+/// still fills its parent and a widget that does not fit into its parent shrinks down to the min
+/// size. The min size always wins over the max size.
+///
+/// The width and height could also be given in percent of the available size of the parent,
+/// they are resolved with `resolve` during the arrange pass of the layout.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Constraint {
     width: f64,
     height: f64,
+    preferred_width: f64,
+    preferred_height: f64,
     min_width: f64,
     min_height: f64,
     max_width: f64,
     max_height: f64,
+    width_percent: f64,
+    height_percent: f64,
 }
 
 impl Default for Constraint {
@@ -104,10 +155,14 @@ impl Default for Constraint {
         Constraint {
             width: 0.0,
             height: 0.0,
+            preferred_width: 0.0,
+            preferred_height: 0.0,
             min_width: 0.0,
             min_height: 0.0,
             max_width: f64::MAX,
             max_height: f64::MAX,
+            width_percent: 0.0,
+            height_percent: 0.0,
         }
     }
 }
@@ -149,6 +204,57 @@ impl Constraint {
         self.height = height;
     }
 
+    /// Gets the preferred width.
+    pub fn preferred_width(&self) -> f64 {
+        self.preferred_width
+    }
+
+    /// Sets the preferred width. `0.0` disables it.
+    pub fn set_preferred_width(&mut self, preferred_width: f64) {
+        self.preferred_width = preferred_width;
+    }
+
+    /// Gets the preferred height.
+    pub fn preferred_height(&self) -> f64 {
+        self.preferred_height
+    }
+
+    /// Sets the preferred height. `0.0` disables it.
+    pub fn set_preferred_height(&mut self, preferred_height: f64) {
+        self.preferred_height = preferred_height;
+    }
+
+    /// Gets the preferred size.
+    pub fn preferred_size(&self) -> (f64, f64) {
+        (self.preferred_width, self.preferred_height)
+    }
+
+    /// Sets the preferred size.
+    pub fn set_preferred_size(&mut self, preferred_width: f64, preferred_height: f64) {
+        self.preferred_width = preferred_width;
+        self.preferred_height = preferred_height;
+    }
+
+    /// Gets the width in percent of the available width of the parent.
+    pub fn width_percent(&self) -> f64 {
+        self.width_percent
+    }
+
+    /// Sets the width in percent of the available width of the parent. `0.0` disables it.
+    pub fn set_width_percent(&mut self, width_percent: f64) {
+        self.width_percent = width_percent;
+    }
+
+    /// Gets the height in percent of the available height of the parent.
+    pub fn height_percent(&self) -> f64 {
+        self.height_percent
+    }
+
+    /// Sets the height in percent of the available height of the parent. `0.0` disables it.
+    pub fn set_height_percent(&mut self, height_percent: f64) {
+        self.height_percent = height_percent;
+    }
+
     /// Gets min_width.
     pub fn min_width(&self) -> f64 {
         self.min_width
@@ -211,39 +317,93 @@ impl Constraint {
         self.max_height = max_height;
     }
 
+    /// Returns the constraint with the percentage width and height resolved to a fixed size
+    /// inside of the given available size of the parent.
+    pub fn resolve(&self, parent_size: (f64, f64)) -> Constraint {
+        let mut constraint = *self;
+
+        if self.width_percent > 0.0 {
+            constraint.width = (parent_size.0 * self.width_percent / 100.0).max(0.0);
+            constraint.width_percent = 0.0;
+        }
+
+        if self.height_percent > 0.0 {
+            constraint.height = (parent_size.1 * self.height_percent / 100.0).max(0.0);
+            constraint.height_percent = 0.0;
+        }
+
+        constraint
+    }
+
+    /// Returns the desired size of a widget with the given measured `size`. Used by the measure
+    /// pass of the layouts, so the parents take the constraints of their children into account.
+    pub fn measure(&self, size: (f64, f64)) -> (f64, f64) {
+        (
+            constrain(
+                prefer(size.0, self.width, self.preferred_width),
+                self.min_width,
+                self.max_width,
+            ),
+            constrain(
+                prefer(size.1, self.height, self.preferred_height),
+                self.min_height,
+                self.max_height,
+            ),
+        )
+    }
+
     /// Adjust the given `size` to match the constraint.
     pub fn perform(&self, size: (f64, f64)) -> (f64, f64) {
-        let size = {
-            let width = if self.width > 0.0 { self.width } else { size.0 };
-            let height = if self.height > 0.0 {
-                self.height
-            } else {
-                size.1
-            };
-
-            (width, height)
-        };
-
         (
-            constrain(size.0, self.min_width, self.max_width, self.width),
-            constrain(size.1, self.min_height, self.max_height, self.height),
+            constrain(
+                prefer(size.0, self.width, 0.0),
+                self.min_width,
+                self.max_width,
+            ),
+            constrain(
+                prefer(size.1, self.height, 0.0),
+                self.min_height,
+                self.max_height,
+            ),
         )
     }
+
+    /// Adjust the given `size` to match the constraint inside of the `available` size of the
+    /// parent. A preferred size that does not fit is shrunk to the available size.
+    pub fn fit(&self, size: (f64, f64), available: (f64, f64)) -> (f64, f64) {
+        let width = if self.width <= 0.0 && self.preferred_width > 0.0 {
+            size.0.min(available.0)
+        } else {
+            size.0
+        };
+
+        let height = if self.height <= 0.0 && self.preferred_height > 0.0 {
+            size.1.min(available.1)
+        } else {
+            size.1
+        };
+
+        self.perform((width, height))
+    }
 }
 
-// Check constraint for the given
-fn constrain(val: f64, min: f64, max: f64, size: f64) -> f64 {
-    if min == 0.0 && max == 0.0 && size > 0.0 {
+// Returns the fixed size if it is set, otherwise the preferred size or the given value.
+fn prefer(val: f64, size: f64, preferred: f64) -> f64 {
+    if size > 0.0 {
         size
-    } else if val < min && min > 0.0 {
-        min
-    } else if val > max && max > 0.0 {
-        max
+    } else if preferred > 0.0 {
+        preferred
     } else {
         val
     }
 }
 
+// Clamps the value between min and max, a max of `0.0` is ignored.
+fn constrain(val: f64, min: f64, max: f64) -> f64 {
+    let val = if max > 0.0 { val.min(max) } else { val };
+    val.max(min)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -409,4 +569,91 @@ mod tests {
         assert_eq!(constraint.perform((5.0, 40.0)), (10.0, 40.0));
         assert_eq!(constraint.perform((10.0, 70.0)), (10.0, 60.0));
     }
+
+    #[test]
+    fn test_builder_preferred_size() {
+        let constraint = Constraint::create().preferred_size(12.0, 14.0).build();
+
+        assert_eq!(constraint.preferred_size(), (12.0, 14.0));
+    }
+
+    #[test]
+    fn test_set_preferred_size() {
+        let mut constraint = Constraint::default();
+        constraint.set_preferred_width(12.0);
+        constraint.set_preferred_height(14.0);
+
+        assert_eq!(constraint.preferred_size(), (12.0, 14.0));
+    }
+
+    #[test]
+    fn test_measure() {
+        // at least 100 but prefer 200
+        let constraint = Constraint::create()
+            .min_width(100.0)
+            .preferred_width(200.0)
+            .build();
+
+        assert_eq!(constraint.measure((50.0, 20.0)), (200.0, 20.0));
+        assert_eq!(constraint.measure((300.0, 20.0)), (200.0, 20.0));
+
+        let constraint = Constraint::create()
+            .width(40.0)
+            .min_height(30.0)
+            .max_height(35.0)
+            .build();
+        assert_eq!(constraint.measure((80.0, 10.0)), (40.0, 30.0));
+        assert_eq!(constraint.measure((80.0, 50.0)), (40.0, 35.0));
+
+        // min wins over max
+        let constraint = Constraint::create().min_width(20.0).max_width(10.0).build();
+        assert_eq!(constraint.measure((15.0, 0.0)), (20.0, 0.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let constraint = Constraint::create()
+            .min_width(100.0)
+            .preferred_width(200.0)
+            .build();
+
+        // stretched
+        assert_eq!(constraint.fit((400.0, 10.0), (400.0, 10.0)), (400.0, 10.0));
+
+        // aligned
+        assert_eq!(constraint.fit((200.0, 10.0), (400.0, 10.0)), (200.0, 10.0));
+        assert_eq!(constraint.fit((200.0, 10.0), (150.0, 10.0)), (150.0, 10.0));
+        assert_eq!(constraint.fit((200.0, 10.0), (50.0, 10.0)), (100.0, 10.0));
+
+        // fixed sizes are not shrunk
+        let constraint = Constraint::create().width(200.0).build();
+        assert_eq!(constraint.fit((400.0, 10.0), (150.0, 10.0)), (200.0, 10.0));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let constraint = Constraint::create()
+            .width_percent(50.0)
+            .height(20.0)
+            .max_width(80.0)
+            .build();
+
+        let resolved = constraint.resolve((100.0, 300.0));
+        assert_eq!(resolved.size(), (50.0, 20.0));
+        assert_eq!(resolved.perform((10.0, 10.0)), (50.0, 20.0));
+        assert_eq!(
+            constraint.resolve((200.0, 300.0)).perform((10.0, 10.0)),
+            (80.0, 20.0)
+        );
+
+        let constraint = Constraint::create().height_percent(25.0).build();
+        assert_eq!(
+            constraint.resolve((100.0, 300.0)).perform((10.0, 10.0)),
+            (10.0, 75.0)
+        );
+        assert_eq!(
+            Constraint::default().resolve((100.0, 300.0)),
+            Constraint::default()
+        );
+    }
 }
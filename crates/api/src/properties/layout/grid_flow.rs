@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+/// `GridFlow` describes how a grid places the children without a column and a row.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GridFlow {
+    /// Children without a cell are placed in the first column and row.
+    None,
+
+    /// Children fill the columns of a row and wrap to the next row after the last column.
+    Row,
+
+    /// Children fill the rows of a column and wrap to the next column after the last row.
+    Column,
+}
+
+impl GridFlow {
+    /// Returns the cells `(column, row)` of the given children. A child with a column or a row
+    /// keeps it and occupies its cell, the children without both are assigned to the next free
+    /// cell in order. The flow wraps after the given number of columns or rows, spans are not
+    /// considered.
+    pub fn place(
+        self,
+        cells: &[(Option<usize>, Option<usize>)],
+        columns: usize,
+        rows: usize,
+    ) -> Vec<(Option<usize>, Option<usize>)> {
+        if self == GridFlow::None {
+            return cells.to_vec();
+        }
+
+        let occupied: HashSet<(usize, usize)> = cells
+            .iter()
+            .filter(|(column, row)| column.is_some() || row.is_some())
+            .map(|(column, row)| (column.unwrap_or(0), row.unwrap_or(0)))
+            .collect();
+
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+        let mut index = 0;
+
+        cells
+            .iter()
+            .map(|cell| {
+                if cell.0.is_some() || cell.1.is_some() {
+                    return *cell;
+                }
+
+                loop {
+                    let cell = match self {
+                        GridFlow::Column => (index / rows, index % rows),
+                        _ => (index % columns, index / columns),
+                    };
+                    index += 1;
+
+                    if !occupied.contains(&cell) {
+                        return (Some(cell.0), Some(cell.1));
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl Default for GridFlow {
+    fn default() -> Self {
+        GridFlow::None
+    }
+}
+
+impl From<&str> for GridFlow {
+    fn from(s: &str) -> GridFlow {
+        match s {
+            "Row" | "row" => GridFlow::Row,
+            "Column" | "column" => GridFlow::Column,
+            _ => GridFlow::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_place_row() {
+        let cells = vec![(None, None); 5];
+        assert_eq!(
+            GridFlow::Row.place(&cells, 2, 0),
+            vec![
+                (Some(0), Some(0)),
+                (Some(1), Some(0)),
+                (Some(0), Some(1)),
+                (Some(1), Some(1)),
+                (Some(0), Some(2)),
+            ]
+        );
+        assert_eq!(GridFlow::None.place(&cells, 2, 0), cells);
+    }
+
+    #[test]
+    fn test_place_column() {
+        let cells = vec![(None, None); 3];
+        assert_eq!(
+            GridFlow::Column.place(&cells, 0, 2),
+            vec![(Some(0), Some(0)), (Some(0), Some(1)), (Some(1), Some(0))]
+        );
+    }
+
+    #[test]
+    fn test_place_explicit() {
+        // explicit cells are kept and skipped by the flow
+        let cells = vec![(None, None), (Some(1), None), (None, None), (None, Some(1))];
+        assert_eq!(
+            GridFlow::Row.place(&cells, 2, 2),
+            vec![
+                (Some(0), Some(0)),
+                (Some(1), None),
+                (Some(1), Some(1)),
+                (None, Some(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(GridFlow::from("row"), GridFlow::Row);
+        assert_eq!(GridFlow::from("Column"), GridFlow::Column);
+        assert_eq!(GridFlow::from("none"), GridFlow::None);
+    }
+}
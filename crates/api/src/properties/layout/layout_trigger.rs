@@ -0,0 +1,160 @@
+use std::{f64, fmt, rc::Rc};
+
+use crate::prelude::*;
+
+/// Describes whose width is compared with the breakpoints of a `LayoutTrigger`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TriggerSource {
+    /// The width of the window.
+    Window,
+
+    /// The width of the parent of the widget after the last layout.
+    Parent,
+}
+
+impl Default for TriggerSource {
+    fn default() -> Self {
+        TriggerSource::Window
+    }
+}
+
+pub type TriggerSetter = dyn Fn(&mut WidgetContainer<'_>, bool) + 'static;
+
+/// A `LayoutTrigger` switches the properties of a widget if the width of the window or of the
+/// parent crosses its breakpoints, similar to a media query of css. The trigger is active while
+/// the width is at least `min_width` and less than `max_width`.
+///
+/// An active trigger adds its css class to the widget, so the theme could style a compact
+/// version of the widget. Other properties like the visibility of a template are switched by the
+/// `on_change` setter, it is called after the first layout and whenever the trigger switches.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Stack::create()
+///     .layout_trigger(LayoutTrigger::new().max_width(600.0).class("compact"))
+///     .layout_trigger(LayoutTrigger::new().max_width(600.0).on_change(|widget, active| {
+///         let orientation = if active { "vertical" } else { "horizontal" };
+///         widget.set("orientation", Orientation::from(orientation));
+///     }))
+/// ```
+#[derive(Clone, Default)]
+pub struct LayoutTrigger {
+    source: TriggerSource,
+    min_width: f64,
+    max_width: Option<f64>,
+    class: Option<String>,
+    setter: Option<Rc<TriggerSetter>>,
+    active: Option<bool>,
+}
+
+impl LayoutTrigger {
+    /// Creates a trigger that compares the width of the window and is always active.
+    pub fn new() -> Self {
+        LayoutTrigger::default()
+    }
+
+    /// Compares the width of the parent instead of the window.
+    pub fn parent(mut self) -> Self {
+        self.source = TriggerSource::Parent;
+        self
+    }
+
+    /// Sets the width that activates the trigger.
+    pub fn min_width(mut self, min_width: f64) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the width that deactivates the trigger.
+    pub fn max_width(mut self, max_width: f64) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the css class that is added to the widget while the trigger is active.
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Sets the setter that is called with `true` if the trigger is activated and with `false`
+    /// if it is deactivated.
+    pub fn on_change<F: Fn(&mut WidgetContainer<'_>, bool) + 'static>(mut self, setter: F) -> Self {
+        self.setter = Some(Rc::new(setter));
+        self
+    }
+
+    /// Gets the source of the compared width.
+    pub fn source(&self) -> TriggerSource {
+        self.source
+    }
+
+    /// Returns `true` if the trigger is active since the last layout.
+    pub fn is_active(&self) -> bool {
+        self.active == Some(true)
+    }
+
+    /// Returns `true` if the trigger is active for the given width.
+    pub fn matches(&self, width: f64) -> bool {
+        width >= self.min_width && width < self.max_width.unwrap_or(f64::MAX)
+    }
+
+    /// Updates the trigger with the given width and switches the properties of the widget if the
+    /// trigger is activated or deactivated. Returns `true` if it is switched.
+    pub fn update(&mut self, width: f64, widget: &mut WidgetContainer<'_>) -> bool {
+        let active = self.matches(width);
+
+        if self.active == Some(active) {
+            return false;
+        }
+
+        self.active = Some(active);
+
+        if let Some(class) = &self.class {
+            if active {
+                widget.add_class(class);
+            } else {
+                widget.remove_class(class);
+            }
+        }
+
+        if let Some(setter) = &self.setter {
+            setter(widget, active);
+        }
+
+        true
+    }
+}
+
+impl fmt::Debug for LayoutTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LayoutTrigger")
+            .field("source", &self.source)
+            .field("min_width", &self.min_width)
+            .field("max_width", &self.max_width)
+            .field("class", &self.class)
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let trigger = LayoutTrigger::new().min_width(300.0).max_width(600.0);
+        assert!(!trigger.matches(299.0));
+        assert!(trigger.matches(300.0));
+        assert!(trigger.matches(599.0));
+        assert!(!trigger.matches(600.0));
+
+        assert!(LayoutTrigger::new().matches(0.0));
+        assert_eq!(
+            LayoutTrigger::new().parent().source(),
+            TriggerSource::Parent
+        );
+    }
+}
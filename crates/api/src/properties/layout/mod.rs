@@ -1,11 +1,17 @@
 // Layout specific properties.
 
+pub use self::aspect_ratio::*;
 pub use self::column::*;
 pub use self::constraint::*;
+pub use self::grid_flow::*;
+pub use self::layout_trigger::*;
 pub use self::row::*;
 pub use self::scroll_viewer_mode::*;
 
+mod aspect_ratio;
 mod column;
 mod constraint;
+mod grid_flow;
+mod layout_trigger;
 mod row;
 mod scroll_viewer_mode;
@@ -195,6 +195,11 @@ impl Rows {
     pub fn iter_mut(&mut self) -> IterMut<Row> {
         self.0.iter_mut()
     }
+
+    /// Appends the given row.
+    pub fn push(&mut self, row: impl Into<Row>) {
+        self.0.push(row.into());
+    }
 }
 
 #[cfg(test)]
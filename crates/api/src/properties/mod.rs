@@ -52,6 +52,11 @@ impl<P: Component + Debug> From<Entity> for PropertySource<P> {
 /// Used to convert components / properties into a PropertySource object.
 pub trait IntoPropertySource<P: Component + Debug> {
     fn into_source(self) -> PropertySource<P>;
+
+    /// Returns the key of the localized string if the source is a `LocalizedText`.
+    fn localization_key(&self) -> Option<String> {
+        None
+    }
 }
 
 /// Used ot generate attached properties.
@@ -87,11 +92,22 @@ into_property_source!(i32);
 into_property_source!(i64);
 
 // Implementation of PropertySource for utils types
+into_property_source!(utils::AccessibleRole: &str);
 into_property_source!(utils::Alignment: &str);
 into_property_source!(utils::Brush: &str, utils::Color);
+into_property_source!(
+    utils::CornerRadius: i32,
+    f64,
+    (i32, i32, i32, i32),
+    (f64, f64, f64, f64)
+);
+into_property_source!(utils::CursorIcon: &str);
+into_property_source!(utils::Date: (i32, u32, u32));
+into_property_source!(utils::FlowDirection: &str);
 into_property_source!(utils::Orientation: &str);
 into_property_source!(utils::Point: f64, i32, (i32, i32), (f64, f64));
 into_property_source!(utils::Rectangle: (i32, i32, i32, i32), (f64, f64, f64, f64));
+into_property_source!(utils::RenderMode: &str);
 into_property_source!(
     utils::Thickness: i32,
     f64,
@@ -102,22 +118,62 @@ into_property_source!(
 );
 into_property_source!(utils::String16: &str, String);
 into_property_source!(utils::SelectionMode: &str);
+into_property_source!(utils::TextTrimming: &str);
+into_property_source!(utils::TextWrap: &str);
 into_property_source!(utils::Visibility: &str);
+into_property_source!(utils::WindowGrip: &str);
 into_property_source!(Vec<String>);
 
 // Implementation of css types
 into_property_source!(css_engine::Selector: &str, String);
 into_property_source!(css_engine::Theme);
+into_property_source!(css_engine::ThemeExtension);
 
 // Implementation of render property types
 into_property_source!(render::Image: &str);
+into_property_source!(render::RenderTarget);
+into_property_source!(render::Svg: &str, String);
 
 // Implementation of custom property types
+into_property_source!(AspectRatio: f64, (f64, f64), (i32, i32));
+into_property_source!(CacheMode: &str);
+into_property_source!(ChartSeries: Vec<f64>, Vec<(&str, f64)>);
 into_property_source!(Columns);
+into_property_source!(Command);
+into_property_source!(Commands: Vec<Command>);
 into_property_source!(Constraint);
+into_property_source!(GridFlow: &str);
+into_property_source!(InputFilter);
 into_property_source!(RenderPipeline);
 into_property_source!(Rows);
 into_property_source!(ScrollViewerMode: (&str, &str));
+into_property_source!(SelectedDate: utils::Date, Option<utils::Date>);
 into_property_source!(SelectedEntities: HashSet<Entity>);
+into_property_source!(SelectedIndex: usize);
 into_property_source!(SelectedIndices: HashSet<usize>);
+into_property_source!(TextComposition);
 into_property_source!(TextSelection: (usize, usize));
+into_property_source!(TextSpans: Vec<TextSpan>);
+into_property_source!(TreeNodes);
+into_property_source!(VirtualResolution: (f64, f64), (i32, i32));
+
+// Localized strings use the key until the translation is applied
+impl IntoPropertySource<String> for LocalizedText {
+    fn into_source(self) -> PropertySource<String> {
+        PropertySource::Value(self.0)
+    }
+
+    fn localization_key(&self) -> Option<String> {
+        Some(self.0.clone())
+    }
+}
+
+impl IntoPropertySource<utils::String16> for LocalizedText {
+    fn into_source(self) -> PropertySource<utils::String16> {
+        PropertySource::Value(self.0.into())
+    }
+
+    fn localization_key(&self) -> Option<String> {
+        Some(self.0.clone())
+    }
+}
@@ -0,0 +1,45 @@
+/// Describes if a widget and its children are rendered each frame or once into a cached layer.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CacheMode {
+    /// The widget and its children are rendered each frame.
+    None,
+
+    /// The widget and its children are rendered once into an offscreen layer with the size of
+    /// the widget. The layer is drawn until it is invalidated, e.g. by a changed property or
+    /// layout of the subtree. Makes complex but mostly static widgets cheap to render.
+    Texture,
+}
+
+impl Default for CacheMode {
+    fn default() -> Self {
+        CacheMode::None
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for CacheMode {
+    fn from(t: &str) -> Self {
+        match t {
+            "Texture" | "texture" => CacheMode::Texture,
+            _ => CacheMode::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let cache_mode: CacheMode = "texture".into();
+        assert_eq!(cache_mode, CacheMode::Texture);
+
+        let cache_mode: CacheMode = "Texture".into();
+        assert_eq!(cache_mode, CacheMode::Texture);
+
+        let cache_mode: CacheMode = "other".into();
+        assert_eq!(cache_mode, CacheMode::None);
+    }
+}
@@ -0,0 +1,403 @@
+use std::{f64::consts::PI, fmt};
+
+use crate::utils::{Point, Rectangle};
+
+/// Width of the space left of the plot that is used for the labels of the value axis.
+pub const CHART_AXIS_WIDTH: f64 = 40.0;
+
+/// Height of the space below the plot that is used for the labels of the points.
+pub const CHART_AXIS_HEIGHT: f64 = 20.0;
+
+// Part of the space of a point that is filled by its bar.
+const BAR_FILL: f64 = 0.6;
+
+// Distance between the pie and the bounds of the chart.
+const PIE_PADDING: f64 = 4.0;
+
+/// Describes a labeled value of a chart.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ChartPoint {
+    pub label: String,
+    pub value: f64,
+}
+
+impl ChartPoint {
+    /// Creates a new chart point.
+    pub fn new(label: impl Into<String>, value: f64) -> Self {
+        ChartPoint {
+            label: label.into(),
+            value,
+        }
+    }
+}
+
+impl fmt::Display for ChartPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            self.label,
+            ChartLayout::format_value(self.value)
+        )
+    }
+}
+
+/// Describes the data series that is displayed by a chart.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ChartSeries {
+    points: Vec<ChartPoint>,
+}
+
+impl ChartSeries {
+    /// Creates a new empty series.
+    pub fn new() -> Self {
+        ChartSeries::default()
+    }
+
+    /// Builder method that adds a point to the series.
+    pub fn point(mut self, label: impl Into<String>, value: f64) -> Self {
+        self.push(label, value);
+        self
+    }
+
+    /// Adds a point to the end of the series.
+    pub fn push(&mut self, label: impl Into<String>, value: f64) {
+        self.points.push(ChartPoint::new(label, value));
+    }
+
+    /// Returns the point of the given index.
+    pub fn get(&self, index: usize) -> Option<&ChartPoint> {
+        self.points.get(index)
+    }
+
+    /// Returns all points of the series.
+    pub fn points(&self) -> &[ChartPoint] {
+        &self.points
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if the series contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the smallest and the largest value, the range always includes `0.0`.
+    pub fn range(&self) -> (f64, f64) {
+        self.points.iter().fold((0.0, 0.0), |(min, max), point| {
+            (point.value.min(min), point.value.max(max))
+        })
+    }
+
+    /// Returns the sum of the positive values, used for the slices of a pie chart.
+    pub fn total(&self) -> f64 {
+        self.points.iter().map(|point| point.value.max(0.0)).sum()
+    }
+}
+
+impl From<Vec<f64>> for ChartSeries {
+    fn from(values: Vec<f64>) -> Self {
+        ChartSeries {
+            points: values
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| ChartPoint::new((i + 1).to_string(), value))
+                .collect(),
+        }
+    }
+}
+
+impl From<Vec<(&str, f64)>> for ChartSeries {
+    fn from(values: Vec<(&str, f64)>) -> Self {
+        ChartSeries {
+            points: values
+                .into_iter()
+                .map(|(label, value)| ChartPoint::new(label, value))
+                .collect(),
+        }
+    }
+}
+
+/// Describes how a series is drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ChartKind {
+    Line,
+    Bar,
+    Pie,
+}
+
+impl Default for ChartKind {
+    fn default() -> Self {
+        ChartKind::Line
+    }
+}
+
+/// Calculates the positions of the axes and the points of a chart, shared by the rendering and
+/// the hit testing of the chart widgets. All positions are relative to the chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartLayout {
+    kind: ChartKind,
+    plot: Rectangle,
+    min: f64,
+    max: f64,
+    len: usize,
+}
+
+impl ChartLayout {
+    /// Creates the layout of the given series for a chart with the given size.
+    pub fn new(kind: ChartKind, series: &ChartSeries, size: (f64, f64)) -> Self {
+        let plot = if kind == ChartKind::Pie {
+            Rectangle::new(0.0, 0.0, size.0, size.1)
+        } else {
+            Rectangle::new(
+                CHART_AXIS_WIDTH,
+                0.0,
+                (size.0 - CHART_AXIS_WIDTH).max(0.0),
+                (size.1 - CHART_AXIS_HEIGHT).max(0.0),
+            )
+        };
+
+        let (min, mut max) = series.range();
+
+        if max <= min {
+            max = min + 1.0;
+        }
+
+        ChartLayout {
+            kind,
+            plot,
+            min,
+            max,
+            len: series.len(),
+        }
+    }
+
+    /// Formats a value of the axis or the tooltip without needless decimals.
+    pub fn format_value(value: f64) -> String {
+        if value.fract() == 0.0 {
+            return value.to_string();
+        }
+
+        format!("{:.2}", value)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    }
+
+    /// Returns the area that contains the points of the chart.
+    pub fn plot(&self) -> Rectangle {
+        self.plot
+    }
+
+    /// Returns the vertical position of the given value.
+    pub fn y(&self, value: f64) -> f64 {
+        self.plot.y() + self.plot.height() * (1.0 - (value - self.min) / (self.max - self.min))
+    }
+
+    /// Returns the given number of evenly spaced values from the minimum to the maximum.
+    pub fn ticks(&self, count: usize) -> Vec<f64> {
+        if count < 2 {
+            return vec![self.min];
+        }
+
+        let step = (self.max - self.min) / (count - 1) as f64;
+        (0..count).map(|i| self.min + step * i as f64).collect()
+    }
+
+    /// Returns the horizontal center of the point with the given index.
+    pub fn x(&self, index: usize) -> f64 {
+        self.plot.x() + self.slot_width() * (index as f64 + 0.5)
+    }
+
+    /// Returns the position of the point with the given index of a line chart.
+    pub fn line_point(&self, series: &ChartSeries, index: usize) -> Option<Point> {
+        series
+            .get(index)
+            .map(|point| Point::new(self.x(index), self.y(point.value)))
+    }
+
+    /// Returns the bar of the point with the given index of a bar chart.
+    pub fn bar(&self, series: &ChartSeries, index: usize) -> Option<Rectangle> {
+        series.get(index).map(|point| {
+            let width = self.slot_width() * BAR_FILL;
+            let top = self.y(point.value.max(0.0));
+            let bottom = self.y(point.value.min(0.0));
+            Rectangle::new(self.x(index) - width / 2.0, top, width, bottom - top)
+        })
+    }
+
+    /// Returns the center of a pie chart.
+    pub fn pie_center(&self) -> Point {
+        Point::new(
+            self.plot.x() + self.plot.width() / 2.0,
+            self.plot.y() + self.plot.height() / 2.0,
+        )
+    }
+
+    /// Returns the radius of a pie chart.
+    pub fn pie_radius(&self) -> f64 {
+        (self.plot.width().min(self.plot.height()) / 2.0 - PIE_PADDING).max(0.0)
+    }
+
+    /// Returns the start and the end angle of the slice with the given index of a pie chart. The
+    /// first slice starts at the top, negative values have no slice.
+    pub fn slice(&self, series: &ChartSeries, index: usize) -> Option<(f64, f64)> {
+        let total = series.total();
+
+        if total <= 0.0 || index >= series.len() {
+            return None;
+        }
+
+        let start = series.points()[..index]
+            .iter()
+            .fold(-PI / 2.0, |angle, point| {
+                angle + point.value.max(0.0) / total * 2.0 * PI
+            });
+
+        Some((
+            start,
+            start + series.points()[index].value.max(0.0) / total * 2.0 * PI,
+        ))
+    }
+
+    /// Returns the index of the point under the given position.
+    pub fn hit_test(&self, series: &ChartSeries, position: Point) -> Option<usize> {
+        match self.kind {
+            ChartKind::Line => {
+                if self.len == 0 || !self.plot.contains((position.x, position.y)) {
+                    return None;
+                }
+
+                let index = ((position.x - self.plot.x()) / self.slot_width()) as usize;
+                Some(index.min(self.len - 1))
+            }
+            ChartKind::Bar => (0..series.len()).find(|i| {
+                self.bar(series, *i)
+                    .map_or(false, |bar| bar.contains((position.x, position.y)))
+            }),
+            ChartKind::Pie => {
+                let center = self.pie_center();
+                let (dx, dy) = (position.x - center.x, position.y - center.y);
+
+                if (dx * dx + dy * dy).sqrt() > self.pie_radius() {
+                    return None;
+                }
+
+                // angles of the slices go from -PI / 2 to 3 * PI / 2
+                let mut angle = dy.atan2(dx);
+                if angle < -PI / 2.0 {
+                    angle += 2.0 * PI;
+                }
+
+                (0..series.len()).find(|i| {
+                    self.slice(series, *i)
+                        .map_or(false, |(start, end)| angle >= start && angle < end)
+                })
+            }
+        }
+    }
+
+    fn slot_width(&self) -> f64 {
+        self.plot.width() / self.len.max(1) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series() -> ChartSeries {
+        ChartSeries::from(vec![("a", 10.0), ("b", -5.0), ("c", 20.0), ("d", 10.0)])
+    }
+
+    #[test]
+    fn test_series() {
+        let series = series();
+        assert_eq!(series.len(), 4);
+        assert_eq!(series.range(), (-5.0, 20.0));
+        assert_eq!(series.total(), 40.0);
+
+        let series = ChartSeries::from(vec![2.0, 3.0]);
+        assert_eq!(series.get(1), Some(&ChartPoint::new("2", 3.0)));
+        assert_eq!(series.range(), (0.0, 3.0));
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(ChartLayout::format_value(20.0), "20");
+        assert_eq!(ChartLayout::format_value(-2.5), "-2.5");
+        assert_eq!(ChartLayout::format_value(1.0 / 3.0), "0.33");
+        assert_eq!(ChartPoint::new("a", 1.5).to_string(), "a: 1.5");
+    }
+
+    #[test]
+    fn test_axis() {
+        let layout = ChartLayout::new(ChartKind::Bar, &series(), (140.0, 120.0));
+        assert_eq!(layout.plot(), Rectangle::new(40.0, 0.0, 100.0, 100.0));
+        assert_eq!(layout.y(20.0), 0.0);
+        assert_eq!(layout.y(-5.0), 100.0);
+        assert_eq!(layout.y(0.0), 80.0);
+        assert_eq!(layout.ticks(6), vec![-5.0, 0.0, 5.0, 10.0, 15.0, 20.0]);
+
+        // an empty series has a valid range
+        let layout = ChartLayout::new(ChartKind::Line, &ChartSeries::new(), (140.0, 120.0));
+        assert_eq!(layout.y(0.0), 100.0);
+    }
+
+    #[test]
+    fn test_bar() {
+        let series = series();
+        let layout = ChartLayout::new(ChartKind::Bar, &series, (140.0, 120.0));
+        assert_eq!(
+            layout.bar(&series, 0),
+            Some(Rectangle::new(45.0, 40.0, 15.0, 40.0))
+        );
+
+        // negative values are drawn below the zero line
+        assert_eq!(
+            layout.bar(&series, 1),
+            Some(Rectangle::new(70.0, 80.0, 15.0, 20.0))
+        );
+
+        assert_eq!(layout.hit_test(&series, Point::new(50.0, 60.0)), Some(0));
+        assert_eq!(layout.hit_test(&series, Point::new(50.0, 20.0)), None);
+        assert_eq!(layout.hit_test(&series, Point::new(80.0, 90.0)), Some(1));
+    }
+
+    #[test]
+    fn test_line() {
+        let series = series();
+        let layout = ChartLayout::new(ChartKind::Line, &series, (140.0, 120.0));
+        assert_eq!(layout.line_point(&series, 2), Some(Point::new(102.5, 0.0)));
+        assert_eq!(layout.line_point(&series, 4), None);
+
+        assert_eq!(layout.hit_test(&series, Point::new(41.0, 50.0)), Some(0));
+        assert_eq!(layout.hit_test(&series, Point::new(139.0, 50.0)), Some(3));
+        assert_eq!(layout.hit_test(&series, Point::new(20.0, 50.0)), None);
+    }
+
+    #[test]
+    fn test_pie() {
+        let series = series();
+        let layout = ChartLayout::new(ChartKind::Pie, &series, (108.0, 108.0));
+        assert_eq!(layout.pie_center(), Point::new(54.0, 54.0));
+        assert_eq!(layout.pie_radius(), 50.0);
+
+        assert_eq!(layout.slice(&series, 0), Some((-PI / 2.0, 0.0)));
+        let (start, end) = layout.slice(&series, 1).unwrap();
+        assert_eq!(start, end);
+        assert_eq!(layout.slice(&series, 2), Some((0.0, PI)));
+
+        // top right quarter
+        assert_eq!(layout.hit_test(&series, Point::new(74.0, 34.0)), Some(0));
+        // bottom half
+        assert_eq!(layout.hit_test(&series, Point::new(54.0, 84.0)), Some(2));
+        // top left quarter
+        assert_eq!(layout.hit_test(&series, Point::new(34.0, 34.0)), Some(3));
+        // outside of the pie
+        assert_eq!(layout.hit_test(&series, Point::new(2.0, 2.0)), None);
+    }
+}
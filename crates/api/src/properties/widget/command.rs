@@ -0,0 +1,132 @@
+use std::{fmt, rc::Rc};
+
+use crate::{
+    prelude::{Context, KeyboardState},
+    shell::Key,
+};
+
+use super::Shortcut;
+
+pub type ExecuteFn = dyn Fn(&mut Context<'_>) + 'static;
+
+pub type CanExecuteFn = dyn Fn(&mut Context<'_>) -> bool + 'static;
+
+/// A `Command` is an action of the application, e.g. save or undo, that is shared by the widgets
+/// that trigger it. `Button` and `MenuItem` execute their command if they are clicked and are
+/// disabled while the command could not be executed. The shortcut of a command is handled by the
+/// window it is registered with.
+///
+/// The `can_execute` callback is evaluated each time the states of the window are updated, e.g.
+/// after a property it reads is changed. Both callbacks are called with the context of the widget
+/// that triggers the command.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let save = Command::new(|ctx| ctx.resource::<Document>().unwrap().save())
+///     .can_execute(|ctx| ctx.resource::<Document>().unwrap().is_changed())
+///     .shortcut("ctrl+s");
+///
+/// Window::create().commands(vec![save.clone()])
+/// Button::create().text("Save").command(save.clone())
+/// MenuItem::create().text("Save").command(save)
+/// ```
+#[derive(Clone, Default)]
+pub struct Command {
+    execute: Option<Rc<ExecuteFn>>,
+    can_execute: Option<Rc<CanExecuteFn>>,
+    shortcut: Option<Shortcut>,
+}
+
+impl Command {
+    /// Creates a command with the given action.
+    pub fn new<F: Fn(&mut Context<'_>) + 'static>(execute: F) -> Self {
+        Command {
+            execute: Some(Rc::new(execute)),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the callback that decides if the command could be executed. Without the callback the
+    /// command could always be executed.
+    pub fn can_execute<F: Fn(&mut Context<'_>) -> bool + 'static>(
+        mut self,
+        can_execute: F,
+    ) -> Self {
+        self.can_execute = Some(Rc::new(can_execute));
+        self
+    }
+
+    /// Sets the shortcut of the command, e.g. `ctrl+s`.
+    pub fn shortcut(mut self, shortcut: impl Into<Shortcut>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Returns the text of the shortcut to display it beside the command, e.g. `Ctrl+S`.
+    pub fn accelerator(&self) -> Option<String> {
+        self.shortcut.map(|shortcut| shortcut.to_string())
+    }
+
+    /// Returns `true` if the command could be executed in the given context.
+    pub fn is_executable(&self, ctx: &mut Context<'_>) -> bool {
+        match &self.can_execute {
+            Some(can_execute) => self.execute.is_some() && can_execute(ctx),
+            None => self.execute.is_some(),
+        }
+    }
+
+    /// Executes the command if it could be executed. Returns `true` if it is executed.
+    pub fn execute(&self, ctx: &mut Context<'_>) -> bool {
+        if !self.is_executable(ctx) {
+            return false;
+        }
+
+        if let Some(execute) = &self.execute {
+            execute(ctx);
+        }
+
+        true
+    }
+
+    /// Returns `true` if the given pressed key and the modifiers of the keyboard state match the
+    /// shortcut of the command.
+    pub fn matches(&self, key: Key, keyboard_state: &KeyboardState) -> bool {
+        self.shortcut
+            .map_or(false, |shortcut| shortcut.matches(key, keyboard_state))
+    }
+
+    /// Enables or disables the current widget of the context, depending on whether the command
+    /// could be executed. Returns `true` if the command could be executed.
+    pub fn update_enabled(&self, ctx: &mut Context<'_>) -> bool {
+        let executable = self.is_executable(ctx);
+        let mut widget = ctx.widget();
+
+        if *widget.get::<bool>("enabled") != executable {
+            widget.set("enabled", executable);
+            widget.update_theme_by_state(false);
+        }
+
+        executable
+    }
+}
+
+impl fmt::Debug for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Command")
+            .field("execute", &self.execute.is_some())
+            .field("can_execute", &self.can_execute.is_some())
+            .field("shortcut", &self.shortcut)
+            .finish()
+    }
+}
+
+/// `Commands` describes the commands whose shortcuts are handled by a window.
+#[derive(Clone, Default, Debug)]
+pub struct Commands(pub Vec<Command>);
+
+impl From<Vec<Command>> for Commands {
+    fn from(commands: Vec<Command>) -> Self {
+        Commands(commands)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::utils::Brush;
+
+/// Describes a glyph of an icon font with its own font, size and brush. Unset values keep the
+/// defaults of the widget, e.g. of the theme.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Button::create()
+///     .text("Save")
+///     .font_icon(Icon::new(material_font_icons::SAVE_FONT_ICON).size(16.0))
+///     .build(ctx)
+/// ```
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Icon {
+    pub glyph: String,
+    pub font: Option<String>,
+    pub size: Option<f64>,
+    pub brush: Option<Brush>,
+}
+
+impl Icon {
+    /// Creates an icon with the given glyph.
+    pub fn new(glyph: impl Into<String>) -> Self {
+        Icon {
+            glyph: glyph.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Builder method that sets the family of the icon font, the font has to be registered, e.g.
+    /// with `Application::font`.
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Builder method that sets the font size of the icon.
+    pub fn size(mut self, size: f64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Builder method that sets the brush of the icon.
+    pub fn brush(mut self, brush: impl Into<Brush>) -> Self {
+        self.brush = Some(brush.into());
+        self
+    }
+}
+
+impl From<&str> for Icon {
+    fn from(glyph: &str) -> Self {
+        Icon::new(glyph)
+    }
+}
+
+impl From<String> for Icon {
+    fn from(glyph: String) -> Self {
+        Icon::new(glyph)
+    }
+}
@@ -0,0 +1,128 @@
+use std::{fmt, rc::Rc};
+
+use crate::utils::String16;
+
+pub type CharFilter = dyn Fn(char) -> bool + 'static;
+
+pub type TextFilter = dyn Fn(&str) -> bool + 'static;
+
+/// An `InputFilter` decides which typed or inserted characters enter the text of a text box.
+/// Rejected characters are dropped before the text is changed, so the text never holds input
+/// that is not allowed.
+///
+/// The filters are applied in order: the `chars` filter removes single characters, `max_length`
+/// cuts the input at the maximum number of characters and the `text` filter rejects the whole
+/// input if the resulting text does not match, e.g. a regular expression.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// TextBox::create().input_filter(InputFilter::digits().max_length(4))
+/// TextBox::create().input_filter(InputFilter::new().text(move |text| regex.is_match(text)))
+/// ```
+#[derive(Clone, Default)]
+pub struct InputFilter {
+    max_length: Option<usize>,
+    chars: Option<Rc<CharFilter>>,
+    text: Option<Rc<TextFilter>>,
+}
+
+impl InputFilter {
+    /// Creates a filter that accepts all input.
+    pub fn new() -> Self {
+        InputFilter::default()
+    }
+
+    /// Creates a filter that accepts only the digits `0` to `9`.
+    pub fn digits() -> Self {
+        InputFilter::new().chars(|c| c.is_ascii_digit())
+    }
+
+    /// Sets the maximum number of characters of the text.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the filter that is called for each inserted character and drops it if it returns
+    /// `false`.
+    pub fn chars<F: Fn(char) -> bool + 'static>(mut self, filter: F) -> Self {
+        self.chars = Some(Rc::new(filter));
+        self
+    }
+
+    /// Sets the filter that is called with the text after the input is inserted. The input is
+    /// rejected if it returns `false`.
+    pub fn text<F: Fn(&str) -> bool + 'static>(mut self, filter: F) -> Self {
+        self.text = Some(Rc::new(filter));
+        self
+    }
+
+    /// Returns the part of the input that is accepted if it is inserted into the text at the
+    /// given index. The index is measured in UTF-16 units like the text.
+    pub fn filter(&self, text: &String16, index: usize, input: &str) -> String {
+        let mut accepted: String = match &self.chars {
+            Some(chars) => input.chars().filter(|c| chars(*c)).collect(),
+            None => input.to_string(),
+        };
+
+        if let Some(max_length) = self.max_length {
+            let free = max_length.saturating_sub(text.as_string().chars().count());
+            accepted = accepted.chars().take(free).collect();
+        }
+
+        if let Some(filter) = &self.text {
+            let mut new_text = text.clone();
+            new_text.insert_str(index, &accepted);
+
+            if !filter(new_text.as_string().as_str()) {
+                accepted.clear();
+            }
+        }
+
+        accepted
+    }
+}
+
+impl fmt::Debug for InputFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputFilter")
+            .field("max_length", &self.max_length)
+            .field("chars", &self.chars.is_some())
+            .field("text", &self.text.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let text = String16::from("12");
+        assert_eq!(InputFilter::new().filter(&text, 2, "a3"), "a3");
+        assert_eq!(InputFilter::digits().filter(&text, 2, "a3b4"), "34");
+        assert_eq!(
+            InputFilter::digits().max_length(3).filter(&text, 0, "345"),
+            "3"
+        );
+        assert_eq!(InputFilter::new().max_length(2).filter(&text, 1, "3"), "");
+    }
+
+    #[test]
+    fn test_text_filter() {
+        // accepts a time like 12:30
+        let filter = InputFilter::new().text(|text| {
+            text.chars().enumerate().all(|(i, c)| match i {
+                2 => c == ':',
+                0 | 1 | 3 | 4 => c.is_ascii_digit(),
+                _ => false,
+            })
+        });
+
+        assert_eq!(filter.filter(&String16::from("12"), 2, ":"), ":");
+        assert_eq!(filter.filter(&String16::from("12"), 2, "3"), "");
+        assert_eq!(filter.filter(&String16::from("12:"), 0, "0"), "");
+    }
+}
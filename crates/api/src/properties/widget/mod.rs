@@ -1,10 +1,34 @@
 // Widget related properties.
+pub use self::cache_mode::*;
+pub use self::chart_series::*;
+pub use self::command::*;
+pub use self::icon::*;
+pub use self::input_filter::*;
 pub use self::render_pipeline::*;
+pub use self::selected_date::*;
 pub use self::selected_entities::*;
+pub use self::selected_index::*;
 pub use self::selected_indices::*;
+pub use self::shortcut::*;
+pub use self::text_composition::*;
 pub use self::text_selection::*;
+pub use self::text_spans::*;
+pub use self::tree_nodes::*;
+pub use self::virtual_resolution::*;
 
+mod cache_mode;
+mod chart_series;
+mod command;
+mod icon;
+mod input_filter;
 mod render_pipeline;
+mod selected_date;
 mod selected_entities;
+mod selected_index;
 mod selected_indices;
+mod shortcut;
+mod text_composition;
 mod text_selection;
+mod text_spans;
+mod tree_nodes;
+mod virtual_resolution;
@@ -0,0 +1,17 @@
+use crate::utils::Date;
+
+/// `SelectedDate` describes the selected date of a calendar. `None` if no date is selected.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct SelectedDate(pub Option<Date>);
+
+impl From<Date> for SelectedDate {
+    fn from(d: Date) -> Self {
+        SelectedDate(Some(d))
+    }
+}
+
+impl From<Option<Date>> for SelectedDate {
+    fn from(d: Option<Date>) -> Self {
+        SelectedDate(d)
+    }
+}
@@ -0,0 +1,10 @@
+/// `SelectedIndex` describes the index of the selected item of a widget that always shows one of
+/// its items, e.g. the page of a pager.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct SelectedIndex(pub usize);
+
+impl From<usize> for SelectedIndex {
+    fn from(index: usize) -> Self {
+        SelectedIndex(index)
+    }
+}
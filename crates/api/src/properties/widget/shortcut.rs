@@ -0,0 +1,194 @@
+use std::fmt;
+
+use crate::{prelude::KeyboardState, shell::Key};
+
+// Names of the keys that are not written as a single character.
+static KEY_NAMES: [(&str, Key); 14] = [
+    ("Backspace", Key::Backspace),
+    ("Delete", Key::Delete),
+    ("Down", Key::Down),
+    ("End", Key::End),
+    ("Enter", Key::Enter),
+    ("Escape", Key::Escape),
+    ("Esc", Key::Escape),
+    ("Home", Key::Home),
+    ("Left", Key::Left),
+    ("PageDown", Key::PageDown),
+    ("PageUp", Key::PageUp),
+    ("Right", Key::Right),
+    ("Space", Key::Space),
+    ("Up", Key::Up),
+];
+
+// Letters are compared without their case, the shift key is checked separately.
+fn normalize(key: Key) -> Key {
+    let text: &str = key.into();
+
+    match text.chars().next() {
+        Some(c) if c.is_ascii_uppercase() => Key::from(c.to_ascii_lowercase()),
+        _ => key,
+    }
+}
+
+/// A `Shortcut` describes the key combination that executes a command, e.g. `Ctrl+S`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Shortcut::from("ctrl+shift+z")
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Shortcut {
+    pub key: Key,
+
+    pub ctrl: bool,
+
+    pub shift: bool,
+
+    pub alt: bool,
+}
+
+impl Shortcut {
+    /// Creates a shortcut of the given key without modifiers.
+    pub fn new(key: Key) -> Self {
+        Shortcut {
+            key: normalize(key),
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    /// Parses a shortcut like `ctrl+s` or `Alt+Enter`. The modifiers `ctrl`, `shift` and `alt`
+    /// are followed by a single character or the name of a key. Returns `None` if the text is not
+    /// a valid shortcut.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut shortcut = Shortcut::new(Key::Unknown);
+        let parts: Vec<&str> = text.split('+').map(|part| part.trim()).collect();
+        let (key, modifiers) = parts.split_last()?;
+
+        for modifier in modifiers {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => shortcut.ctrl = true,
+                "shift" => shortcut.shift = true,
+                "alt" => shortcut.alt = true,
+                _ => return None,
+            }
+        }
+
+        let mut chars = key.chars();
+        shortcut.key = match (chars.next(), chars.next()) {
+            (Some(c), None) => normalize(Key::from(c)),
+            _ => KEY_NAMES
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, key)| *key)?,
+        };
+
+        if shortcut.key == Key::Unknown {
+            return None;
+        }
+
+        Some(shortcut)
+    }
+
+    /// Returns `true` if the given pressed key and the modifiers of the keyboard state match the
+    /// shortcut.
+    pub fn matches(&self, key: Key, keyboard_state: &KeyboardState) -> bool {
+        self.key != Key::Unknown
+            && normalize(key) == self.key
+            && keyboard_state.is_ctrl_down() == self.ctrl
+            && keyboard_state.is_shift_down() == self.shift
+            && keyboard_state.is_alt_down() == self.alt
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+
+        match KEY_NAMES.iter().find(|(_, key)| *key == self.key) {
+            Some((name, _)) => f.write_str(name),
+            None => f.write_str(&<&str>::from(self.key).to_uppercase()),
+        }
+    }
+}
+
+impl From<Key> for Shortcut {
+    fn from(key: Key) -> Self {
+        Shortcut::new(key)
+    }
+}
+
+impl From<&str> for Shortcut {
+    fn from(text: &str) -> Self {
+        // an invalid shortcut never matches
+        Shortcut::parse(text).unwrap_or_else(|| Shortcut::new(Key::Unknown))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Shortcut::parse("ctrl+S"),
+            Some(Shortcut {
+                key: Key::S(false),
+                ctrl: true,
+                shift: false,
+                alt: false,
+            })
+        );
+        assert_eq!(
+            Shortcut::parse("Alt + Shift + enter"),
+            Some(Shortcut {
+                key: Key::Enter,
+                ctrl: false,
+                shift: true,
+                alt: true,
+            })
+        );
+        assert_eq!(Shortcut::parse("esc"), Some(Shortcut::new(Key::Escape)));
+        assert_eq!(Shortcut::parse("ctrl+"), None);
+        assert_eq!(Shortcut::parse("super+s"), None);
+        assert_eq!(Shortcut::parse("ctrl+f12"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Shortcut::from("ctrl+shift+z").to_string(), "Ctrl+Shift+Z");
+        assert_eq!(Shortcut::from("alt+1").to_string(), "Alt+1");
+        assert_eq!(Shortcut::from("escape").to_string(), "Escape");
+    }
+
+    #[test]
+    fn test_matches() {
+        let shortcut = Shortcut::from("ctrl+s");
+        let mut keyboard_state = KeyboardState::default();
+        assert!(!shortcut.matches(Key::S(false), &keyboard_state));
+
+        keyboard_state.set_key_state(Key::Control, true);
+        assert!(shortcut.matches(Key::S(false), &keyboard_state));
+        assert!(!shortcut.matches(Key::A(false), &keyboard_state));
+
+        // the case of a letter depends on the shift key
+        keyboard_state.set_key_state(Key::ShiftL, true);
+        assert!(!shortcut.matches(Key::S(true), &keyboard_state));
+        assert!(Shortcut::from("ctrl+shift+s").matches(Key::S(true), &keyboard_state));
+
+        assert!(!Shortcut::from("ctrl+f12").matches(Key::Unknown, &keyboard_state));
+    }
+}
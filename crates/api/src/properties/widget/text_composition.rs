@@ -0,0 +1,24 @@
+/// Describes the composition string of an input method that is displayed inline in a text, but
+/// is not part of the text until it is committed.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TextComposition {
+    pub text: String,
+
+    /// Position in the text (utf-16 units) the composition string is displayed at.
+    pub index: usize,
+}
+
+impl TextComposition {
+    /// Creates a new composition.
+    pub fn new(text: impl Into<String>, index: usize) -> Self {
+        TextComposition {
+            text: text.into(),
+            index,
+        }
+    }
+
+    /// Returns `true` if there is no composition string.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::utils::Brush;
+
+/// Describes the brush of a range of characters of a line, e.g. of a highlighted keyword.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TextSpan {
+    /// Index of the first character of the span.
+    pub start: usize,
+
+    /// Index after the last character of the span.
+    pub end: usize,
+
+    /// Brush of the characters of the span.
+    pub brush: Brush,
+}
+
+impl TextSpan {
+    /// Creates a new span of the characters from `start` to `end` (exclusive).
+    pub fn new(start: usize, end: usize, brush: impl Into<Brush>) -> Self {
+        TextSpan {
+            start,
+            end,
+            brush: brush.into(),
+        }
+    }
+}
+
+/// Describes the styled spans of a line of text. The characters that are not part of a span
+/// are drawn with the foreground of the widget.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TextSpans(pub Vec<TextSpan>);
+
+impl TextSpans {
+    /// Splits a line with the given number of characters into runs `(start, end, brush)` of
+    /// characters with the same brush. A span overrides the spans before it, the parts outside
+    /// of the line are ignored.
+    pub fn runs(&self, len: usize, foreground: &Brush) -> Vec<(usize, usize, Brush)> {
+        let mut brushes = vec![foreground; len];
+
+        for span in &self.0 {
+            for brush in brushes.iter_mut().take(span.end.min(len)).skip(span.start) {
+                *brush = &span.brush;
+            }
+        }
+
+        let mut runs: Vec<(usize, usize, Brush)> = vec![];
+
+        for (index, brush) in brushes.into_iter().enumerate() {
+            match runs.last_mut() {
+                Some(run) if run.2 == *brush => run.1 = index + 1,
+                _ => runs.push((index, index + 1, brush.clone())),
+            }
+        }
+
+        runs
+    }
+}
+
+impl From<Vec<TextSpan>> for TextSpans {
+    fn from(spans: Vec<TextSpan>) -> Self {
+        TextSpans(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs() {
+        let foreground = Brush::from("#ffffff");
+        let keyword = Brush::from("#c678dd");
+        let spans = TextSpans(vec![TextSpan::new(0, 3, "#c678dd")]);
+
+        // let x = 1;
+        assert_eq!(
+            spans.runs(10, &foreground),
+            vec![(0, 3, keyword.clone()), (3, 10, foreground.clone())]
+        );
+
+        // later spans override the spans before and the end is clamped to the line
+        let spans = TextSpans(vec![
+            TextSpan::new(0, 4, "#c678dd"),
+            TextSpan::new(2, 8, "#ffffff"),
+        ]);
+        assert_eq!(
+            spans.runs(5, &foreground),
+            vec![(0, 2, keyword), (2, 5, foreground.clone())]
+        );
+        assert_eq!(TextSpans::default().runs(0, &foreground), vec![]);
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+
+/// Describes where a dragged node is dropped relative to the target node.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DropPosition {
+    /// Inserts the node as sibling before the target.
+    Before,
+
+    /// Inserts the node as sibling after the target.
+    After,
+
+    /// Inserts the node as last child of the target.
+    Into,
+}
+
+impl Default for DropPosition {
+    fn default() -> DropPosition {
+        DropPosition::Into
+    }
+}
+
+/// `TreeNodes` describes the hierarchy of the nodes of a tree. A node is referenced by its index,
+/// that could be used to read the data of the node from an external list.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TreeNodes {
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    roots: Vec<usize>,
+}
+
+impl TreeNodes {
+    /// Creates a new empty tree.
+    pub fn new() -> Self {
+        TreeNodes::default()
+    }
+
+    /// Appends a new node to the children of `parent` or to the roots if `parent` is `None` and
+    /// returns the index of the new node.
+    pub fn push(&mut self, parent: Option<usize>) -> usize {
+        let node = self.parents.len();
+        self.parents.push(parent);
+        self.children.push(vec![]);
+        self.children_mut(parent).push(node);
+        node
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns `true` if the tree has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the parent of the given node.
+    pub fn parent(&self, node: usize) -> Option<usize> {
+        self.parents.get(node).copied().flatten()
+    }
+
+    /// Returns the children of `node` or the roots if `node` is `None`.
+    pub fn children(&self, node: Option<usize>) -> &[usize] {
+        match node {
+            Some(node) => &self.children[node],
+            None => &self.roots,
+        }
+    }
+
+    /// Checks if `ancestor` is a (grand) parent of `node`.
+    pub fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        let mut current = self.parent(node);
+
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+
+            current = self.parent(parent);
+        }
+
+        false
+    }
+
+    /// Checks if `node` could be moved next to or into `target`. A node could not be moved into
+    /// itself or one of its descendants.
+    pub fn can_move(&self, node: usize, target: usize) -> bool {
+        node < self.len()
+            && target < self.len()
+            && node != target
+            && !self.is_ancestor(node, target)
+    }
+
+    /// Moves `node` with all its descendants to the given position relative to `target`.
+    pub fn move_node(
+        &mut self,
+        node: usize,
+        target: usize,
+        position: DropPosition,
+    ) -> Result<(), String> {
+        if !self.can_move(node, target) {
+            return Err(format!(
+                "TreeNodes.move_node: node {} could not be moved to node {}.",
+                node, target
+            ));
+        }
+
+        let old_parent = self.parents[node];
+        self.children_mut(old_parent).retain(|n| *n != node);
+
+        let (parent, index) = match position {
+            DropPosition::Into => (Some(target), self.children[target].len()),
+            _ => {
+                let parent = self.parents[target];
+                let index = self
+                    .children(parent)
+                    .iter()
+                    .position(|n| *n == target)
+                    .unwrap_or(0);
+
+                if position == DropPosition::After {
+                    (parent, index + 1)
+                } else {
+                    (parent, index)
+                }
+            }
+        };
+
+        self.parents[node] = parent;
+        self.children_mut(parent).insert(index, node);
+
+        Ok(())
+    }
+
+    /// Returns the nodes that are visible if only the children of the `expanded` nodes are shown,
+    /// in display order with their depth.
+    pub fn visible_nodes(&self, expanded: &HashSet<usize>) -> Vec<(usize, usize)> {
+        let mut nodes = vec![];
+        let mut stack: Vec<(usize, usize)> = self.roots.iter().rev().map(|n| (*n, 0)).collect();
+
+        while let Some((node, depth)) = stack.pop() {
+            nodes.push((node, depth));
+
+            if expanded.contains(&node) {
+                stack.extend(self.children[node].iter().rev().map(|n| (*n, depth + 1)));
+            }
+        }
+
+        nodes
+    }
+
+    fn children_mut(&mut self, node: Option<usize>) -> &mut Vec<usize> {
+        match node {
+            Some(node) => &mut self.children[node],
+            None => &mut self.roots,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0
+    // - 1
+    //   - 2
+    // 3
+    fn tree() -> TreeNodes {
+        let mut tree = TreeNodes::new();
+        let root = tree.push(None);
+        let child = tree.push(Some(root));
+        tree.push(Some(child));
+        tree.push(None);
+        tree
+    }
+
+    #[test]
+    fn test_push() {
+        let tree = tree();
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.children(None), &[0, 3]);
+        assert_eq!(tree.children(Some(0)), &[1]);
+        assert_eq!(tree.parent(2), Some(1));
+        assert_eq!(tree.parent(3), None);
+    }
+
+    #[test]
+    fn test_is_ancestor() {
+        let tree = tree();
+        assert!(tree.is_ancestor(0, 2));
+        assert!(tree.is_ancestor(1, 2));
+        assert!(!tree.is_ancestor(2, 0));
+        assert!(!tree.is_ancestor(3, 2));
+    }
+
+    #[test]
+    fn test_move_node() {
+        let mut tree = tree();
+
+        assert!(tree.move_node(0, 2, DropPosition::Into).is_err());
+        assert!(tree.move_node(1, 1, DropPosition::After).is_err());
+
+        assert!(tree.move_node(2, 3, DropPosition::Into).is_ok());
+        assert_eq!(tree.parent(2), Some(3));
+        assert!(tree.children(Some(1)).is_empty());
+
+        assert!(tree.move_node(3, 0, DropPosition::Before).is_ok());
+        assert_eq!(tree.children(None), &[3, 0]);
+
+        assert!(tree.move_node(1, 0, DropPosition::After).is_ok());
+        assert_eq!(tree.children(None), &[3, 0, 1]);
+        assert_eq!(tree.parent(1), None);
+    }
+
+    #[test]
+    fn test_visible_nodes() {
+        let tree = tree();
+        let mut expanded = HashSet::new();
+        assert_eq!(tree.visible_nodes(&expanded), vec![(0, 0), (3, 0)]);
+
+        expanded.insert(0);
+        expanded.insert(1);
+        assert_eq!(
+            tree.visible_nodes(&expanded),
+            vec![(0, 0), (1, 1), (2, 2), (3, 0)]
+        );
+    }
+}
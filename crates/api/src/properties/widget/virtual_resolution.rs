@@ -0,0 +1,93 @@
+/// Describes a fixed logical resolution of a window. If it is set, the widgets are laid out at
+/// this resolution and scaled to the window, the remaining space is filled with letterbox bars.
+/// A width or height of `0.0` disables the virtual resolution.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct VirtualResolution {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl VirtualResolution {
+    /// Creates a new virtual resolution.
+    pub fn new(width: f64, height: f64) -> Self {
+        VirtualResolution { width, height }
+    }
+
+    /// Returns `true` if the virtual resolution is used.
+    pub fn is_enabled(&self) -> bool {
+        self.width > 0.0 && self.height > 0.0
+    }
+
+    /// Returns the scale factor and the offset of the scaled content in a window with the given
+    /// size.
+    pub fn transform(&self, window_width: f64, window_height: f64) -> (f64, f64, f64) {
+        if !self.is_enabled() {
+            return (1.0, 0.0, 0.0);
+        }
+
+        let scale = (window_width / self.width).min(window_height / self.height);
+
+        (
+            scale,
+            ((window_width - self.width * scale) / 2.0).floor(),
+            ((window_height - self.height * scale) / 2.0).floor(),
+        )
+    }
+
+    /// Maps a position in a window with the given size to the virtual resolution.
+    pub fn to_virtual(&self, window_width: f64, window_height: f64, x: f64, y: f64) -> (f64, f64) {
+        let (scale, offset_x, offset_y) = self.transform(window_width, window_height);
+
+        if scale <= 0.0 {
+            return (x, y);
+        }
+
+        ((x - offset_x) / scale, (y - offset_y) / scale)
+    }
+}
+
+impl From<(f64, f64)> for VirtualResolution {
+    fn from(t: (f64, f64)) -> Self {
+        VirtualResolution::new(t.0, t.1)
+    }
+}
+
+impl From<(i32, i32)> for VirtualResolution {
+    fn from(t: (i32, i32)) -> Self {
+        VirtualResolution::new(t.0 as f64, t.1 as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform() {
+        let resolution = VirtualResolution::new(400.0, 300.0);
+
+        // pillarbox
+        let (scale, x, y) = resolution.transform(1000.0, 600.0);
+        assert!((scale - 2.0).abs() < std::f64::EPSILON);
+        assert!((x - 100.0).abs() < std::f64::EPSILON);
+        assert!(y.abs() < std::f64::EPSILON);
+
+        // letterbox
+        let (scale, x, y) = resolution.transform(200.0, 400.0);
+        assert!((scale - 0.5).abs() < std::f64::EPSILON);
+        assert!(x.abs() < std::f64::EPSILON);
+        assert!((y - 125.0).abs() < std::f64::EPSILON);
+
+        let (scale, _, _) = VirtualResolution::default().transform(200.0, 400.0);
+        assert!((scale - 1.0).abs() < std::f64::EPSILON);
+    }
+
+    #[test]
+    fn test_to_virtual() {
+        let resolution = VirtualResolution::from((400, 300));
+
+        let (x, y) = resolution.to_virtual(1000.0, 600.0, 300.0, 100.0);
+        assert!((x - 100.0).abs() < std::f64::EPSILON);
+        assert!((y - 50.0).abs() < std::f64::EPSILON);
+    }
+}
@@ -0,0 +1,292 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::render::RenderContext2D;
+use crate::{prelude::*, utils::*};
+
+#[cfg(not(target_arch = "wasm32"))]
+use super::paint_order;
+
+/// Key of the `RenderCache` component of a widget with `CacheMode::Texture`.
+pub static RENDER_CACHE: &str = "render_cache";
+
+/// Describes the cached layer of a widget. The cache is stored as component of the widget entity.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderCache {
+    /// `false` if the layer has to be rendered again.
+    pub valid: bool,
+
+    /// Position of the layer in window coordinates.
+    pub origin: Point,
+
+    /// Layout generation of the window when the layout of the subtree was last checked.
+    pub layout_generation: usize,
+
+    /// Hash of the bounds and visibility of the widget and its descendants when the layer was
+    /// rendered.
+    pub layout_hash: u64,
+}
+
+// Keys of the components of the window root that count the widgets with a render cache and the
+// runs of the layout system, so writes and unchanged layouts skip the checks of the caches.
+static RENDER_CACHE_COUNT: &str = "render_cache_count";
+static LAYOUT_GENERATION: &str = "layout_generation";
+
+fn root_counter(ecm: &EntityComponentManager<Tree, StringComponentStore>, key: &str) -> usize {
+    let root = ecm.entity_store().root();
+
+    ecm.component_store()
+        .get::<usize>(key, root)
+        .map_or(0, |counter| *counter)
+}
+
+fn set_root_counter(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    key: &str,
+    value: usize,
+) {
+    let root = ecm.entity_store().root();
+
+    if let Ok(counter) = ecm.component_store_mut().get_mut::<usize>(key, root) {
+        *counter = value;
+    } else {
+        ecm.component_store_mut().register(key, root, value);
+    }
+}
+
+/// Starts a new layout generation of the window, the render caches compare the layout of their
+/// subtree again in the next frame. Is called by the layout system on each run.
+pub fn next_layout_generation(ecm: &mut EntityComponentManager<Tree, StringComponentStore>) {
+    let generation = root_counter(ecm, LAYOUT_GENERATION).wrapping_add(1);
+    set_root_counter(ecm, LAYOUT_GENERATION, generation);
+}
+
+// Describes how the render object of a widget with a cache mode renders the widget. Layers are
+// not supported on the web, there all widgets are uncached.
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+pub(crate) enum CacheState {
+    // The widget is rendered without a layer.
+    Uncached,
+
+    // The cached layer is drawn, the widget and its children are skipped.
+    Drawn,
+
+    // The widget and its children are rendered into a new layer with the given origin.
+    Layer(Point),
+}
+
+/// Marks the render caches of the given widget and its ancestors as invalid, so the layers are
+/// rendered again in the next frame.
+pub fn invalidate_render_cache(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) {
+    // without cached widgets there is nothing to invalidate
+    if root_counter(ecm, RENDER_CACHE_COUNT) == 0 {
+        return;
+    }
+
+    let mut current = Some(entity);
+
+    while let Some(widget) = current {
+        if let Ok(cache) = ecm
+            .component_store_mut()
+            .get_mut::<RenderCache>(RENDER_CACHE, widget)
+        {
+            cache.valid = false;
+        }
+
+        current = ecm.entity_store().parent.get(&widget).copied().flatten();
+    }
+}
+
+// Returns the hash of the bounds and visibility of the widget and its descendants. Only the size
+// of the widget is used, a moved layer is drawn at its new position.
+#[cfg(not(target_arch = "wasm32"))]
+fn subtree_layout_hash(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    let mut widgets = vec![entity];
+
+    while let Some(widget) = widgets.pop() {
+        if let Some(children) = ecm.entity_store().children.get(&widget) {
+            widgets.extend(children);
+        }
+
+        let bounds = ecm
+            .component_store()
+            .get::<Rectangle>("bounds", widget)
+            .map(|bounds| *bounds)
+            .unwrap_or_default();
+        let visibility = ecm
+            .component_store()
+            .get::<Visibility>("visibility", widget)
+            .map(|visibility| *visibility)
+            .unwrap_or(Visibility::Collapsed);
+
+        if widget != entity {
+            bounds.x().to_bits().hash(&mut hasher);
+            bounds.y().to_bits().hash(&mut hasher);
+        }
+
+        bounds.width().to_bits().hash(&mut hasher);
+        bounds.height().to_bits().hash(&mut hasher);
+        mem::discriminant(&visibility).hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+// Moves the global positions of the widget and its descendants by the given offset.
+#[cfg(not(target_arch = "wasm32"))]
+fn move_positions(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    offset: Point,
+) {
+    for widget in paint_order(ecm, entity) {
+        if let Ok(position) = ecm
+            .component_store_mut()
+            .get_mut::<Point>("position", widget)
+        {
+            position.x += offset.x;
+            position.y += offset.y;
+        }
+    }
+}
+
+// Draws the cached layer of the widget if it is still valid, otherwise begins a new layer.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn begin_cache(
+    render_context_2_d: &mut RenderContext2D,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    global_position: Point,
+    opacity: f32,
+) -> CacheState {
+    let cache_mode = ecm
+        .component_store()
+        .get::<CacheMode>("cache_mode", entity)
+        .map(|cache_mode| *cache_mode)
+        .unwrap_or_default();
+    let bounds = ecm
+        .component_store()
+        .get::<Rectangle>("bounds", entity)
+        .map(|bounds| *bounds)
+        .unwrap_or_default();
+
+    if cache_mode != CacheMode::Texture || bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+        return CacheState::Uncached;
+    }
+
+    let origin = Point::new(
+        global_position.x + bounds.x(),
+        global_position.y + bounds.y(),
+    );
+    let layout_generation = root_counter(ecm, LAYOUT_GENERATION);
+    let cached = ecm
+        .component_store()
+        .get::<RenderCache>(RENDER_CACHE, entity)
+        .map(|cache| (cache.layout_generation, cache.layout_hash))
+        .ok();
+
+    // the layout of the subtree is only hashed again after the layout system has run
+    let layout_hash = match cached {
+        Some((generation, hash)) if generation == layout_generation => hash,
+        _ => subtree_layout_hash(ecm, entity),
+    };
+
+    if let Ok(cache) = ecm
+        .component_store_mut()
+        .get_mut::<RenderCache>(RENDER_CACHE, entity)
+    {
+        cache.layout_generation = layout_generation;
+
+        if cache.valid && cache.layout_hash == layout_hash {
+            let offset = Point::new(origin.x - cache.origin.x, origin.y - cache.origin.y);
+            cache.origin = origin;
+
+            if offset.x != 0.0 || offset.y != 0.0 {
+                move_positions(ecm, entity, offset);
+            }
+
+            render_context_2_d.set_alpha(opacity);
+            render_context_2_d.draw_layer(u64::from(entity.0), origin.x, origin.y);
+            return CacheState::Drawn;
+        }
+
+        cache.valid = true;
+        cache.origin = origin;
+        cache.layout_hash = layout_hash;
+    } else {
+        ecm.component_store_mut().register(
+            RENDER_CACHE,
+            entity,
+            RenderCache {
+                valid: true,
+                origin,
+                layout_generation,
+                layout_hash,
+            },
+        );
+
+        let count = root_counter(ecm, RENDER_CACHE_COUNT) + 1;
+        set_root_counter(ecm, RENDER_CACHE_COUNT, count);
+    }
+
+    render_context_2_d.begin_layer(
+        u64::from(entity.0),
+        origin.x,
+        origin.y,
+        bounds.width(),
+        bounds.height(),
+    );
+
+    CacheState::Layer(origin)
+}
+
+// Finishes the layer of the widget and moves the global positions of the subtree from layer to
+// window coordinates.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn end_cache(
+    render_context_2_d: &mut RenderContext2D,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    origin: Point,
+    opacity: f32,
+) {
+    move_positions(ecm, entity, origin);
+    render_context_2_d.set_alpha(opacity);
+    render_context_2_d.end_layer();
+}
+
+/// Removes the cached layers of the given widget and its descendants from the render context,
+/// e.g. before the widget is removed from the tree.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn remove_render_caches(
+    render_context_2_d: &mut RenderContext2D,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) {
+    let mut count = root_counter(ecm, RENDER_CACHE_COUNT);
+
+    for widget in paint_order(ecm, entity) {
+        if ecm
+            .component_store()
+            .get::<RenderCache>(RENDER_CACHE, widget)
+            .is_ok()
+        {
+            render_context_2_d.remove_layer(u64::from(widget.0));
+            count = count.saturating_sub(1);
+        }
+    }
+
+    set_root_counter(ecm, RENDER_CACHE_COUNT, count);
+}
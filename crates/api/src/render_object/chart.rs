@@ -0,0 +1,277 @@
+use std::f64::consts::PI;
+
+use crate::{prelude::*, render::RenderContext2D, utils::*};
+
+// Number of labels of the value axis.
+const TICK_COUNT: usize = 5;
+
+// Radius of the markers of a line chart.
+const MARKER_RADIUS: f64 = 3.0;
+
+// Space between the labels and the axes, also used inside of the tooltip.
+const LABEL_PADDING: f64 = 4.0;
+
+// Brush and font of the axes, the labels and the tooltip.
+struct LabelStyle {
+    foreground: Brush,
+    font: String,
+    font_size: f64,
+}
+
+/// Used to render the `series` of a line, bar or pie chart with its axes and the `tooltip` of
+/// the hovered point.
+pub struct ChartRenderObject {
+    kind: ChartKind,
+}
+
+impl ChartRenderObject {
+    /// Creates a render object that draws the series as the given kind of chart.
+    pub fn new(kind: ChartKind) -> Self {
+        ChartRenderObject { kind }
+    }
+
+    fn render_axes(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        series: &ChartSeries,
+        layout: &ChartLayout,
+        origin: Point,
+        style: &LabelStyle,
+    ) {
+        let plot = layout.plot();
+        let (left, bottom) = (origin.x + plot.x(), origin.y + plot.y() + plot.height());
+
+        render_context_2_d.begin_path();
+        render_context_2_d.set_stroke_style(style.foreground.clone());
+        render_context_2_d.set_line_width(1.0);
+        render_context_2_d.move_to(left, origin.y + plot.y());
+        render_context_2_d.line_to(left, bottom);
+        render_context_2_d.line_to(left + plot.width(), bottom);
+        render_context_2_d.stroke();
+        render_context_2_d.close_path();
+
+        if style.font_size == 0.0 {
+            return;
+        }
+
+        render_context_2_d.begin_path();
+        render_context_2_d.set_font_family(style.font.as_str());
+        render_context_2_d.set_font_size(style.font_size);
+        render_context_2_d.set_fill_style(style.foreground.clone());
+
+        for tick in layout.ticks(TICK_COUNT) {
+            let text = ChartLayout::format_value(tick);
+            let metrics =
+                render_context_2_d.measure(text.as_str(), style.font_size, style.font.as_str());
+
+            // keeps the labels of the minimum and the maximum beside the axis
+            let y = (layout.y(tick) - metrics.height / 2.0)
+                .min(plot.y() + plot.height() - metrics.height)
+                .max(plot.y());
+
+            render_context_2_d.fill_text(
+                text.as_str(),
+                left - metrics.width - LABEL_PADDING,
+                origin.y + y,
+            );
+        }
+
+        for (index, point) in series.points().iter().enumerate() {
+            let width = render_context_2_d
+                .measure(point.label.as_str(), style.font_size, style.font.as_str())
+                .width;
+
+            render_context_2_d.fill_text(
+                point.label.as_str(),
+                origin.x + layout.x(index) - width / 2.0,
+                bottom + LABEL_PADDING,
+            );
+        }
+
+        render_context_2_d.close_path();
+    }
+
+    fn render_series(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        series: &ChartSeries,
+        layout: &ChartLayout,
+        origin: Point,
+        brush: &Brush,
+    ) {
+        match self.kind {
+            ChartKind::Line => {
+                render_context_2_d.begin_path();
+                render_context_2_d.set_stroke_style(brush.clone());
+                render_context_2_d.set_line_width(2.0);
+
+                for index in 0..series.len() {
+                    if let Some(point) = layout.line_point(series, index) {
+                        if index == 0 {
+                            render_context_2_d.move_to(origin.x + point.x, origin.y + point.y);
+                        } else {
+                            render_context_2_d.line_to(origin.x + point.x, origin.y + point.y);
+                        }
+                    }
+                }
+
+                render_context_2_d.stroke();
+                render_context_2_d.close_path();
+                render_context_2_d.set_fill_style(brush.clone());
+
+                for index in 0..series.len() {
+                    if let Some(point) = layout.line_point(series, index) {
+                        render_context_2_d.begin_path();
+                        render_context_2_d.arc(
+                            origin.x + point.x,
+                            origin.y + point.y,
+                            MARKER_RADIUS,
+                            0.0,
+                            2.0 * PI,
+                        );
+                        render_context_2_d.fill();
+                        render_context_2_d.close_path();
+                    }
+                }
+            }
+            ChartKind::Bar => {
+                render_context_2_d.set_fill_style(brush.clone());
+
+                for index in 0..series.len() {
+                    if let Some(bar) = layout.bar(series, index) {
+                        render_context_2_d.fill_rect(
+                            origin.x + bar.x(),
+                            origin.y + bar.y(),
+                            bar.width(),
+                            bar.height(),
+                        );
+                    }
+                }
+            }
+            ChartKind::Pie => {
+                let center = Point::new(
+                    origin.x + layout.pie_center().x,
+                    origin.y + layout.pie_center().y,
+                );
+
+                for index in 0..series.len() {
+                    let (start, end) = match layout.slice(series, index) {
+                        Some(slice) if slice.1 > slice.0 => slice,
+                        _ => continue,
+                    };
+
+                    render_context_2_d.begin_path();
+                    render_context_2_d.set_fill_style(slice_brush(brush, index));
+                    render_context_2_d.move_to(center.x, center.y);
+                    render_context_2_d.arc(center.x, center.y, layout.pie_radius(), start, end);
+                    render_context_2_d.line_to(center.x, center.y);
+                    render_context_2_d.fill();
+                    render_context_2_d.close_path();
+                }
+            }
+        }
+    }
+
+    fn render_tooltip(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        bounds: &Rectangle,
+        origin: Point,
+        tooltip: (&str, Point, Brush),
+        style: &LabelStyle,
+    ) {
+        let (text, position, background) = tooltip;
+        let metrics = render_context_2_d.measure(text, style.font_size, style.font.as_str());
+        let width = metrics.width + 2.0 * LABEL_PADDING;
+        let height = metrics.height + 2.0 * LABEL_PADDING;
+
+        // the tooltip is placed above the pointer and kept inside of the chart
+        let x = (position.x - width / 2.0)
+            .min(bounds.width() - width)
+            .max(0.0);
+        let y = (position.y - height - LABEL_PADDING).max(0.0);
+
+        render_context_2_d.begin_path();
+        render_context_2_d.set_fill_style(background);
+        render_context_2_d.fill_rect(origin.x + x, origin.y + y, width, height);
+        render_context_2_d.set_font_family(style.font.as_str());
+        render_context_2_d.set_font_size(style.font_size);
+        render_context_2_d.set_fill_style(style.foreground.clone());
+        render_context_2_d.fill_text(
+            text,
+            origin.x + x + LABEL_PADDING,
+            origin.y + y + LABEL_PADDING,
+        );
+        render_context_2_d.close_path();
+    }
+}
+
+impl Into<Box<dyn RenderObject>> for ChartRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+// Returns the brush of the slice with the given index, a solid color is rotated on the color
+// wheel to tell the slices apart.
+fn slice_brush(brush: &Brush, index: usize) -> Brush {
+    match brush {
+        Brush::SolidColor(color) => {
+            let (h, s, l) = color.to_hsl();
+            Brush::SolidColor(Color::hsl((h + index as f64 * 137.5) % 360.0, s, l))
+        }
+        _ => brush.clone(),
+    }
+}
+
+impl RenderObject for ChartRenderObject {
+    fn render_self(&self, ctx: &mut Context<'_>, global_position: &Point) {
+        let (bounds, series, series_brush, style, tooltip) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.clone_or_default::<ChartSeries>("series"),
+                widget.clone::<Brush>("series_brush"),
+                LabelStyle {
+                    foreground: widget.clone::<Brush>("foreground"),
+                    font: widget.clone::<String>("font"),
+                    font_size: *widget.get::<f64>("font_size"),
+                },
+                (
+                    widget.clone_or_default::<String>("tooltip"),
+                    widget.clone_or_default::<Point>("tooltip_position"),
+                    widget.clone::<Brush>("tooltip_background"),
+                ),
+            )
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 {
+            return;
+        }
+
+        let origin = Point::new(
+            global_position.x + bounds.x(),
+            global_position.y + bounds.y(),
+        );
+        let layout = ChartLayout::new(self.kind, &series, bounds.size());
+        let render_context_2_d = ctx.render_context_2_d();
+
+        if self.kind != ChartKind::Pie {
+            self.render_axes(render_context_2_d, &series, &layout, origin, &style);
+        }
+
+        self.render_series(render_context_2_d, &series, &layout, origin, &series_brush);
+
+        let (text, position, background) = tooltip;
+
+        if !text.is_empty() && style.font_size > 0.0 {
+            self.render_tooltip(
+                render_context_2_d,
+                &bounds,
+                origin,
+                (text.as_str(), position, background),
+                &style,
+            );
+        }
+    }
+}
@@ -15,6 +15,20 @@ impl RenderObject for ClearRenderObject {
     fn render_self(&self, ctx: &mut Context<'_>, _: &Point) {
         let background = ctx.widget().get::<Brush>("background").clone();
 
+        // with a virtual resolution only the scaled area is cleared, the rest shows letterbox bars
+        let resolution = ctx
+            .widget()
+            .try_get::<VirtualResolution>("virtual_resolution")
+            .filter(|r| r.is_enabled())
+            .copied();
+
+        if let Some(resolution) = resolution {
+            ctx.render_context_2_d().set_fill_style(background);
+            ctx.render_context_2_d()
+                .fill_rect(0.0, 0.0, resolution.width, resolution.height);
+            return;
+        }
+
         ctx.render_context_2_d().clear(&background);
     }
 }
@@ -0,0 +1,119 @@
+use crate::{
+    prelude::*,
+    utils::{Brush, Point, Rectangle},
+};
+
+/// Space between the line numbers and the edges of the gutter of a code line.
+pub const CODE_GUTTER_PADDING: f64 = 8.0;
+
+/// Returns the width of the gutter that fits the line numbers of the given number of lines with
+/// monospaced characters of the given width.
+pub fn code_gutter_width(line_count: usize, char_width: f64) -> f64 {
+    let digits = line_count.max(1).to_string().len();
+
+    digits as f64 * char_width + 2.0 * CODE_GUTTER_PADDING
+}
+
+/// Used to render a line of code with its `spans` on a grid of monospaced characters with the
+/// width `char_width`. The `line_number` is drawn right aligned in the gutter at the left side
+/// if the `gutter_width` is greater than `0`.
+pub struct CodeLineRenderObject;
+
+impl Into<Box<dyn RenderObject>> for CodeLineRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for CodeLineRenderObject {
+    fn render_self(&self, ctx: &mut Context<'_>, global_position: &Point) {
+        let (bounds, text, spans, foreground, font, font_size, char_width) = {
+            let widget = ctx.widget();
+
+            (
+                *widget.get::<Rectangle>("bounds"),
+                widget.clone::<String>("text"),
+                widget.clone_or_default::<TextSpans>("spans"),
+                widget.clone::<Brush>("foreground"),
+                widget.clone::<String>("font"),
+                *widget.get::<f64>("font_size"),
+                *widget.get::<f64>("char_width"),
+            )
+        };
+        let (line_number, gutter_width, gutter_foreground) = {
+            let widget = ctx.widget();
+
+            (
+                *widget.get::<usize>("line_number"),
+                *widget.get::<f64>("gutter_width"),
+                widget.clone::<Brush>("gutter_foreground"),
+            )
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 || font_size == 0.0 || char_width == 0.0
+        {
+            return;
+        }
+
+        let x = global_position.x + bounds.x();
+        let y = global_position.y + bounds.y();
+        let render_context_2_d = ctx.render_context_2_d();
+
+        render_context_2_d.begin_path();
+        render_context_2_d.set_font_family(font);
+        render_context_2_d.set_font_size(font_size);
+
+        if gutter_width > 0.0 {
+            let number = line_number.to_string();
+
+            render_context_2_d.set_fill_style(gutter_foreground);
+            render_context_2_d.fill_text(
+                number.as_str(),
+                x + gutter_width - CODE_GUTTER_PADDING - number.len() as f64 * char_width,
+                y,
+            );
+        }
+
+        // each character is drawn in its cell, so the columns line up with any font
+        let chars: Vec<char> = text.chars().collect();
+
+        for (start, end, brush) in spans.runs(chars.len(), &foreground) {
+            if brush.is_transparent() {
+                continue;
+            }
+
+            render_context_2_d.set_fill_style(brush);
+
+            for (column, c) in chars.iter().enumerate().take(end).skip(start) {
+                if c.is_whitespace() {
+                    continue;
+                }
+
+                render_context_2_d.fill_text(
+                    c.to_string().as_str(),
+                    x + gutter_width + column as f64 * char_width,
+                    y,
+                );
+            }
+        }
+
+        render_context_2_d.close_path();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_gutter_width() {
+        assert_eq!(code_gutter_width(9, 8.0), 8.0 + 2.0 * CODE_GUTTER_PADDING);
+        assert_eq!(
+            code_gutter_width(120, 8.0),
+            24.0 + 2.0 * CODE_GUTTER_PADDING
+        );
+
+        // an empty text has one line
+        assert_eq!(code_gutter_width(0, 8.0), 8.0 + 2.0 * CODE_GUTTER_PADDING);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    css_engine::*,
+    render::RenderContext2D,
+    utils::{Brush, CornerRadius, Rectangle},
+};
+
+use super::rectangle::render_rounded_rect_path;
+
+/// Css element of the focus adorner.
+pub static FOCUS_ADORNER: &str = "focus-adorner";
+
+/// The `FocusAdorner` draws a ring around the focused widget of a window. The render system
+/// draws it after all widgets and outside of the bounds of the focused widget, so it is never
+/// clipped by the widget or its parents and follows the focus without any code in the widgets.
+///
+/// The ring is styled by the `focus-adorner` css element with `border-color`, `border-width`,
+/// `border-radius` and `padding` as the gap between the widget and the ring. The element of the
+/// focused widget is added as class, e.g. `focus-adorner.text-box { border-width: 0; }` hides the
+/// ring of text boxes that show their focus with their own border.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FocusAdorner {
+    /// Brush of the ring.
+    pub brush: Brush,
+
+    /// Width of the ring.
+    pub width: f64,
+
+    /// Radius of the corners of the ring.
+    pub radius: CornerRadius,
+
+    /// Gap between the focused widget and the ring.
+    pub spacing: f64,
+}
+
+impl FocusAdorner {
+    /// Returns the adorner of a focused widget with the given selector. Returns `None` if the
+    /// theme has no visible ring for the widget.
+    pub fn from_theme(theme: &Theme, widget: &Selector) -> Option<Self> {
+        let mut selector = Selector::from(FOCUS_ADORNER);
+
+        if let Some(element) = widget.element {
+            selector.classes.insert(element);
+        }
+
+        let brush = theme.brush("border-color", &selector)?;
+        let width = theme.uint("border-width", &selector).unwrap_or(1) as f64;
+
+        if brush.is_transparent() || width <= 0.0 {
+            return None;
+        }
+
+        Some(FocusAdorner {
+            brush,
+            width,
+            radius: theme
+                .corner_radius("border-radius", &selector)
+                .unwrap_or_default(),
+            spacing: theme.uint("padding", &selector).unwrap_or(0) as f64,
+        })
+    }
+
+    /// Returns the bounds of the ring around a widget with the given bounds. The stroke of the
+    /// ring is centered on the returned bounds.
+    pub fn bounds(&self, widget: Rectangle) -> Rectangle {
+        let inset = self.spacing + self.width / 2.0;
+
+        Rectangle::new(
+            widget.x() - inset,
+            widget.y() - inset,
+            widget.width() + 2.0 * inset,
+            widget.height() + 2.0 * inset,
+        )
+    }
+
+    /// Draws the ring around a widget with the given bounds in window coordinates.
+    pub fn render(&self, render_context_2_d: &mut RenderContext2D, widget: Rectangle) {
+        let bounds = self.bounds(widget);
+        let radius = self.radius.fit(bounds.width(), bounds.height());
+
+        render_context_2_d.begin_path();
+
+        if radius.is_zero() {
+            render_context_2_d.rect(bounds.x(), bounds.y(), bounds.width(), bounds.height());
+        } else {
+            render_rounded_rect_path(
+                render_context_2_d,
+                bounds.x(),
+                bounds.y(),
+                bounds.width(),
+                bounds.height(),
+                radius,
+            );
+        }
+
+        render_context_2_d.set_line_width(self.width);
+        render_context_2_d.set_stroke_style(self.brush.clone());
+        render_context_2_d.stroke();
+        render_context_2_d.close_path();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_theme() {
+        let theme = Theme::create_from_css(
+            "focus-adorner { border-color: #f8de4c; border-width: 2; padding: 2; } \
+             focus-adorner.text-box { border-width: 0; }",
+        )
+        .build();
+
+        let adorner = FocusAdorner::from_theme(&theme, &Selector::from("button")).unwrap();
+        assert_eq!(adorner.width, 2.0);
+        assert_eq!(adorner.spacing, 2.0);
+        assert_eq!(adorner.radius, CornerRadius::default());
+
+        // the ring is hidden for the element of the widget
+        assert_eq!(
+            FocusAdorner::from_theme(&theme, &Selector::from("text-box")),
+            None
+        );
+        assert_eq!(
+            FocusAdorner::from_theme(&Theme::create_from_css("").build(), &Selector::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let adorner = FocusAdorner {
+            brush: Brush::from("#f8de4c"),
+            width: 2.0,
+            radius: CornerRadius::default(),
+            spacing: 2.0,
+        };
+
+        assert_eq!(
+            adorner.bounds(Rectangle::new(10.0, 10.0, 20.0, 10.0)),
+            Rectangle::new(7.0, 7.0, 26.0, 16.0)
+        );
+    }
+}
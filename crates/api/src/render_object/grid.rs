@@ -0,0 +1,114 @@
+use crate::{css_engine::Selector, prelude::*, utils::*};
+
+// Brush of the debug overlay if the theme has no `debug-border` style.
+static DEBUG_COLOR: &str = "#0000ff";
+
+// Font size of the sizes of the columns and rows.
+const DEBUG_FONT_SIZE: f64 = 10.0;
+
+/// Used to render the background of a grid. If the `debug` property of the grid is set, the
+/// boundaries and the computed sizes of its columns and rows are drawn on top of its children.
+pub struct GridRenderObject;
+
+impl Into<Box<dyn RenderObject>> for GridRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+// Returns the offset and the size of each cell of the given sizes of columns or rows.
+fn cells(sizes: impl Iterator<Item = f64>) -> Vec<(f64, f64)> {
+    let mut offset = 0.0;
+
+    sizes
+        .map(|size| {
+            let cell = (offset, size);
+            offset += size;
+            cell
+        })
+        .collect()
+}
+
+impl RenderObject for GridRenderObject {
+    fn render_self(&self, ctx: &mut Context<'_>, global_position: &Point) {
+        RectangleRenderObject.render_self(ctx, global_position);
+    }
+
+    fn render_overlay(&self, ctx: &mut Context<'_>, global_position: &Point) {
+        let (debug, bounds, columns, rows) = {
+            let widget = ctx.widget();
+            (
+                widget.clone_or_default::<bool>("debug"),
+                widget.clone::<Rectangle>("bounds"),
+                widget.clone_or_default::<Columns>("columns"),
+                widget.clone_or_default::<Rows>("rows"),
+            )
+        };
+
+        if !debug || bounds.width() == 0.0 || bounds.height() == 0.0 {
+            return;
+        }
+
+        let brush = ctx
+            .theme
+            .brush("border-color", &Selector::from("debug-border"))
+            .unwrap_or_else(|| Brush::from(DEBUG_COLOR));
+        let x = global_position.x + bounds.x();
+        let y = global_position.y + bounds.y();
+        let columns = cells(columns.iter().map(|column| column.current_width()));
+        let rows = cells(rows.iter().map(|row| row.current_height()));
+
+        let render_context_2_d = ctx.render_context_2_d();
+        render_context_2_d.begin_path();
+        render_context_2_d.set_line_width(1.0);
+        render_context_2_d.set_stroke_style(brush.clone());
+        render_context_2_d.stroke_rect(x, y, bounds.width(), bounds.height());
+
+        for (offset, _) in columns.iter().skip(1) {
+            render_context_2_d.move_to(x + offset, y);
+            render_context_2_d.line_to(x + offset, y + bounds.height());
+        }
+
+        for (offset, _) in rows.iter().skip(1) {
+            render_context_2_d.move_to(x, y + offset);
+            render_context_2_d.line_to(x + bounds.width(), y + offset);
+        }
+
+        render_context_2_d.stroke();
+        render_context_2_d.close_path();
+
+        // the width of a column is written at its top, the height of a row at its left side
+        render_context_2_d.begin_path();
+        render_context_2_d.set_font_family("Roboto Regular");
+        render_context_2_d.set_font_size(DEBUG_FONT_SIZE);
+        render_context_2_d.set_fill_style(brush);
+
+        for (offset, width) in &columns {
+            render_context_2_d.fill_text(&format!("{}", width), x + offset + 2.0, y + 2.0);
+        }
+
+        for (offset, height) in &rows {
+            render_context_2_d.fill_text(
+                &format!("{}", height),
+                x + 2.0,
+                y + offset + DEBUG_FONT_SIZE + 4.0,
+            );
+        }
+
+        render_context_2_d.close_path();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells() {
+        assert!(cells(vec![].into_iter()).is_empty());
+        assert_eq!(
+            cells(vec![10.0, 20.5, 5.0].into_iter()),
+            vec![(0.0, 10.0), (10.0, 20.5), (30.5, 5.0)]
+        );
+    }
+}
@@ -4,21 +4,120 @@ use std::{any::Any, cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use crate::{css_engine::*, prelude::*, shell::WindowShell, utils::*};
 
+pub use self::cache::*;
+pub use self::chart::*;
 pub use self::clear::*;
+pub use self::code_line::*;
 pub use self::default::*;
+pub use self::focus_adorner::*;
 pub use self::font_icon::*;
+pub use self::grid::*;
 pub use self::image::*;
 pub use self::pipeline::*;
 pub use self::rectangle::*;
+pub use self::svg::*;
 pub use self::text::*;
+pub use self::texture::*;
 
+mod cache;
+mod chart;
 mod clear;
+mod code_line;
 mod default;
+mod focus_adorner;
 mod font_icon;
+mod grid;
 mod image;
 mod pipeline;
 mod rectangle;
+mod svg;
 mod text;
+mod texture;
+
+/// Returns the children of the widget in the order they are rendered. The children are sorted by
+/// their `z_index` property, children with the same z index keep their order of the tree.
+pub fn children_in_paint_order(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> Vec<Entity> {
+    let mut children = ecm
+        .entity_store()
+        .children
+        .get(&entity)
+        .cloned()
+        .unwrap_or_default();
+
+    children.sort_by_key(|child| {
+        ecm.component_store()
+            .get::<i32>("z_index", *child)
+            .map_or(0, |z_index| *z_index)
+    });
+
+    children
+}
+
+/// Returns the widget and all of its descendants in the order they are rendered, the last widget
+/// is on top.
+pub fn paint_order(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> Vec<Entity> {
+    let mut order = vec![];
+    let mut stack = vec![entity];
+
+    while let Some(current) = stack.pop() {
+        order.push(current);
+        stack.extend(children_in_paint_order(ecm, current).into_iter().rev());
+    }
+
+    order
+}
+
+/// Stores the render object of the given widget. The built-in render objects are stored unboxed in
+/// an arena per render object type, other render objects are stored boxed.
+pub fn insert_render_object(
+    render_objects: &mut ArenaMap<dyn RenderObject>,
+    entity: Entity,
+    render_object: Box<dyn RenderObject>,
+) {
+    let render_object = Some(render_object)
+        .and_then(|object| insert_as::<ChartRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<ClearRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<CodeLineRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<DefaultRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<FontIconRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<GridRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<ImageRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<PipelineRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<RectangleRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<SvgRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<TextRenderObject>(render_objects, entity, object))
+        .and_then(|object| insert_as::<TextureRenderObject>(render_objects, entity, object));
+
+    if let Some(render_object) = render_object {
+        render_objects.insert_boxed(entity, render_object);
+    }
+}
+
+// Inserts the render object into the arena of `R` if it is a `R`, otherwise it is returned.
+fn insert_as<R: RenderObject>(
+    render_objects: &mut ArenaMap<dyn RenderObject>,
+    entity: Entity,
+    render_object: Box<dyn RenderObject>,
+) -> Option<Box<dyn RenderObject>> {
+    if !(&*render_object as &dyn Any).is::<R>() {
+        return Some(render_object);
+    }
+
+    let render_object: Box<dyn Any> = render_object;
+    render_objects.insert(
+        entity,
+        *render_object.downcast::<R>().unwrap(),
+        |render_object| render_object,
+        |render_object| render_object,
+    );
+    None
+}
 
 pub trait RenderObject: Any {
     fn render(
@@ -26,8 +125,8 @@ pub trait RenderObject: Any {
         shell: &mut WindowShell<WindowAdapter>,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_objects: &RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>,
-        layouts: &Rc<RefCell<BTreeMap<Entity, Box<dyn Layout>>>>,
+        render_objects: &RefCell<ArenaMap<dyn RenderObject>>,
+        layouts: &Rc<RefCell<ArenaMap<dyn Layout>>>,
         handlers: &Rc<RefCell<EventHandlerMap>>,
         states: &Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
         theme: &ThemeValue,
@@ -53,12 +152,37 @@ pub trait RenderObject: Any {
             return;
         }
 
-        shell.render_context_2_d().begin_path();
-        shell.render_context_2_d().set_alpha(
-            *ecm.component_store()
-                .get::<f32>("opacity", entity)
-                .unwrap_or(&1.0),
+        let opacity = *ecm
+            .component_store()
+            .get::<f32>("opacity", entity)
+            .unwrap_or(&1.0);
+
+        // a cached widget is rendered into its layer at the origin of the layer
+        #[cfg(not(target_arch = "wasm32"))]
+        let cache_state = cache::begin_cache(
+            shell.render_context_2_d(),
+            ecm,
+            entity,
+            global_position,
+            opacity,
         );
+        #[cfg(target_arch = "wasm32")]
+        let cache_state = cache::CacheState::Uncached;
+
+        let layer_origin = match cache_state {
+            cache::CacheState::Uncached => None,
+            cache::CacheState::Drawn => return,
+            cache::CacheState::Layer(origin) => {
+                global_position =
+                    Point::new(global_position.x - origin.x, global_position.y - origin.y);
+                Some(origin)
+            }
+        };
+
+        shell.render_context_2_d().begin_path();
+        shell
+            .render_context_2_d()
+            .set_alpha(if layer_origin.is_some() { 1.0 } else { opacity });
 
         // Could be unwrap because every widget has the clip property
         let clip = *ecm.component_store().get::<bool>("clip", entity).unwrap();
@@ -120,6 +244,20 @@ pub trait RenderObject: Any {
             debug,
         );
 
+        self.render_overlay(
+            &mut Context::new(
+                (entity, ecm),
+                shell,
+                &theme,
+                render_objects,
+                &mut layouts.borrow_mut(),
+                &mut handlers.borrow_mut(),
+                &states,
+                &mut BTreeMap::new(),
+            ),
+            &global_position,
+        );
+
         shell.render_context_2_d().close_path();
 
         if clip {
@@ -142,26 +280,34 @@ pub trait RenderObject: Any {
                 shell.render_context_2_d().close_path();
             }
         }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(origin) = layer_origin {
+                cache::end_cache(shell.render_context_2_d(), ecm, entity, origin, opacity);
+            }
+        }
     }
 
     fn render_self(&self, _: &mut Context<'_>, _: &Point) {}
 
+    /// Renders on top of the children of the widget, e.g. debug information.
+    fn render_overlay(&self, _: &mut Context<'_>, _: &Point) {}
+
     fn render_children(
         &self,
         shell: &mut WindowShell<WindowAdapter>,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_objects: &RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>,
-        layouts: &Rc<RefCell<BTreeMap<Entity, Box<dyn Layout>>>>,
+        render_objects: &RefCell<ArenaMap<dyn RenderObject>>,
+        layouts: &Rc<RefCell<ArenaMap<dyn Layout>>>,
         handlers: &Rc<RefCell<EventHandlerMap>>,
         states: &Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
         theme: &ThemeValue,
         offsets: &mut BTreeMap<Entity, (f64, f64)>,
         debug: bool,
     ) {
-        for index in 0..ecm.entity_store().children[&entity].len() {
-            let child = ecm.entity_store().children[&entity][index];
-
+        for child in children_in_paint_order(ecm, entity) {
             if let Some(render_object) = render_objects.borrow().get(&child) {
                 render_object.render(
                     shell,
@@ -4,9 +4,32 @@ use crate::{
     prelude::*,
     render::RenderContext2D,
     utils,
-    utils::{Brush, Point, Rectangle, Thickness},
+    utils::{Brush, CornerRadius, Point, Rectangle, Thickness},
 };
 
+// Builds rectangle path with a radius for each corner and without border.
+pub(crate) fn render_rounded_rect_path(
+    render_context_2_d: &mut RenderContext2D,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    radius: CornerRadius,
+) {
+    let r = x + width;
+    let b = y + height;
+    render_context_2_d.move_to(x + radius.top_left, y);
+    render_context_2_d.line_to(r - radius.top_right, y);
+    render_context_2_d.quadratic_curve_to(r, y, r, y + radius.top_right);
+    render_context_2_d.line_to(r, b - radius.bottom_right);
+    render_context_2_d.quadratic_curve_to(r, b, r - radius.bottom_right, b);
+    render_context_2_d.line_to(x + radius.bottom_left, b);
+    render_context_2_d.quadratic_curve_to(x, b, x, b - radius.bottom_left);
+    render_context_2_d.line_to(x, y + radius.top_left);
+    render_context_2_d.quadratic_curve_to(x, y, x + radius.top_left, y);
+    render_context_2_d.close_path();
+}
+
 pub struct RectangleRenderObject;
 
 impl RectangleRenderObject {
@@ -33,30 +56,6 @@ impl RectangleRenderObject {
         }
     }
 
-    // Builds rectangle path with radius and without border.
-    fn render_rounded_rect_path(
-        &self,
-        render_context_2_d: &mut RenderContext2D,
-        x: f64,
-        y: f64,
-        width: f64,
-        height: f64,
-        radius: f64,
-    ) {
-        let r = x + width;
-        let b = y + height;
-        render_context_2_d.move_to(x + radius, y);
-        render_context_2_d.line_to(r - radius, y);
-        render_context_2_d.quadratic_curve_to(r, y, r, y + radius);
-        render_context_2_d.line_to(r, y + height - radius);
-        render_context_2_d.quadratic_curve_to(r, b, r - radius, b);
-        render_context_2_d.line_to(x + radius, b);
-        render_context_2_d.quadratic_curve_to(x, b, x, b - radius);
-        render_context_2_d.line_to(x, y + radius);
-        render_context_2_d.quadratic_curve_to(x, y, x + radius, y);
-        render_context_2_d.close_path();
-    }
-
     fn render_circle(
         &self,
         render_context_2_d: &mut RenderContext2D,
@@ -102,12 +101,12 @@ impl RectangleRenderObject {
         &self,
         render_context_2_d: &mut RenderContext2D,
         rect: Rectangle,
-        radius: f64,
+        radius: CornerRadius,
         brush: utils::Brush,
         border_brush: utils::Brush,
         border_thickness: Thickness,
     ) {
-        self.render_rounded_rect_path(
+        render_rounded_rect_path(
             render_context_2_d,
             rect.x,
             rect.y,
@@ -142,7 +141,7 @@ impl RenderObject for RectangleRenderObject {
             (
                 widget.clone::<Rectangle>("bounds"),
                 widget.get::<Brush>("background").clone(),
-                widget.clone_or_default::<f64>("border_radius"),
+                widget.clone_or_default::<CornerRadius>("border_radius"),
                 widget.clone_or_default::<Thickness>("border_width"),
                 widget.clone_or_default::<Brush>("border_brush"),
             )
@@ -164,9 +163,15 @@ impl RenderObject for RectangleRenderObject {
             || border_thickness.right > 0.0
             || border_thickness.bottom > 0.0;
 
+        // like in css, the radii of the corners are scaled down until they fit
+        let border_radius = border_radius.fit(bounds.width(), bounds.height());
+
         ctx.render_context_2_d().begin_path();
 
-        if bounds.width() == bounds.height() && border_radius >= bounds.width() / 2.0 {
+        if bounds.width() == bounds.height()
+            && border_radius.is_uniform()
+            && border_radius.top_left >= bounds.width() / 2.0
+        {
             if !has_thickness {
                 self.render_circle(
                     ctx.render_context_2_d(),
@@ -174,7 +179,7 @@ impl RenderObject for RectangleRenderObject {
                     global_position.y + bounds.y(),
                     bounds.width(),
                     bounds.height(),
-                    border_radius,
+                    border_radius.top_left,
                 );
                 ctx.render_context_2_d().set_fill_style(background);
                 ctx.render_context_2_d().fill();
@@ -185,14 +190,14 @@ impl RenderObject for RectangleRenderObject {
                     global_position.y + bounds.y(),
                     bounds.width(),
                     bounds.height(),
-                    border_radius,
+                    border_radius.top_left,
                     background,
                     border_brush,
                     border_thickness,
                 );
             }
             return;
-        } else if border_radius > 0. && has_thickness {
+        } else if !border_radius.is_zero() && has_thickness {
             self.render_rounded_bordered_rect_path(
                 ctx.render_context_2_d(),
                 Rectangle::new(
@@ -206,8 +211,8 @@ impl RenderObject for RectangleRenderObject {
                 border_brush,
                 border_thickness,
             );
-        } else if border_radius > 0. {
-            self.render_rounded_rect_path(
+        } else if !border_radius.is_zero() {
+            render_rounded_rect_path(
                 ctx.render_context_2_d(),
                 global_position.x + bounds.x(),
                 global_position.y + bounds.y(),
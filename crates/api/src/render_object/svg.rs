@@ -0,0 +1,35 @@
+use crate::{prelude::*, render::Svg, utils::*};
+
+/// Used to render the `svg` property scaled to the bounds of the widget. Shapes that are painted
+/// with `currentColor` use the `icon_brush`.
+pub struct SvgRenderObject;
+
+impl Into<Box<dyn RenderObject>> for SvgRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for SvgRenderObject {
+    fn render_self(&self, ctx: &mut Context<'_>, global_position: &Point) {
+        let (bounds, svg, icon_brush) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.try_clone::<Svg>("svg"),
+                widget.try_clone::<Brush>("icon_brush").unwrap_or_default(),
+            )
+        };
+
+        if let Some(svg) = svg {
+            svg.draw(
+                ctx.render_context_2_d(),
+                global_position.x + bounds.x(),
+                global_position.y + bounds.y(),
+                bounds.width(),
+                bounds.height(),
+                &icon_brush,
+            );
+        }
+    }
+}
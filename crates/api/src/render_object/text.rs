@@ -1,6 +1,6 @@
 use crate::{
     prelude::*,
-    utils::{Brush, Point, Rectangle, String16},
+    utils::{Brush, Point, Rectangle, String16, TextTrimming, TextWrap},
 };
 
 /// Used to render a text.
@@ -14,23 +14,40 @@ impl Into<Box<dyn RenderObject>> for TextRenderObject {
 
 impl RenderObject for TextRenderObject {
     fn render_self(&self, ctx: &mut Context<'_>, global_position: &Point) {
-        let (bounds, text, foreground, font, font_size) = {
+        let (bounds, text, composition, foreground, font, font_size, text_trimming, text_wrap) = {
             let widget = ctx.widget();
             let text = widget.clone::<String16>("text");
+            let composition = widget.clone_or_default::<TextComposition>("composition");
 
-            let txt = {
-                if !text.is_empty() {
+            // the composition string of an input method is displayed inline at its index
+            let (txt, composition) = if composition.is_empty() {
+                let txt = if !text.is_empty() {
                     text
                 } else {
                     widget.clone_or_default::<String16>("water_mark")
-                }
+                };
+
+                (txt.to_string(), None)
+            } else {
+                let index = composition.index.min(text.len());
+                let before = text.get_string(0, index).unwrap_or_default();
+                let after = text.get_string(index, text.len()).unwrap_or_default();
+
+                (
+                    format!("{}{}{}", before, composition.text, after),
+                    Some((before, composition.text)),
+                )
             };
+
             (
                 *widget.get::<Rectangle>("bounds"),
-                txt.to_string(),
+                txt,
+                composition,
                 widget.get::<Brush>("foreground").clone(),
                 widget.get::<String>("font").clone(),
                 *widget.get::<f64>("font_size"),
+                widget.clone_or_default::<TextTrimming>("text_trimming"),
+                widget.clone_or_default::<TextWrap>("text_wrap"),
             )
         };
 
@@ -43,18 +60,81 @@ impl RenderObject for TextRenderObject {
             return;
         }
 
+        // right to left texts are aligned at the right side of the bounds
+        let right_to_left = ctx.flow_direction().is_right_to_left();
+
         if !text.is_empty() {
-            ctx.render_context_2_d().begin_path();
-            ctx.render_context_2_d().set_font_family(font);
-            ctx.render_context_2_d().set_font_size(font_size);
-            ctx.render_context_2_d().set_fill_style(foreground);
-
-            ctx.render_context_2_d().fill_text(
-                &text,
-                global_position.x + bounds.x,
-                global_position.y + bounds.y,
-            );
-            ctx.render_context_2_d().close_path();
+            let render_context_2_d = ctx.render_context_2_d();
+
+            let line_height = render_context_2_d
+                .measure(text.as_str(), font_size, font.as_str())
+                .height;
+
+            let lines: Vec<String> = text_wrap
+                .wrap(&text, bounds.width, |line| {
+                    render_context_2_d
+                        .measure(line, font_size, font.as_str())
+                        .width
+                })
+                .iter()
+                .map(|line| {
+                    text_trimming.trim(line, bounds.width, |text| {
+                        render_context_2_d
+                            .measure(text, font_size, font.as_str())
+                            .width
+                    })
+                })
+                .collect();
+
+            render_context_2_d.begin_path();
+            render_context_2_d.set_font_family(font.clone());
+            render_context_2_d.set_font_size(font_size);
+            render_context_2_d.set_fill_style(foreground);
+
+            let offsets: Vec<f64> = lines
+                .iter()
+                .map(|line| {
+                    if right_to_left {
+                        bounds.width
+                            - render_context_2_d
+                                .measure(line, font_size, font.as_str())
+                                .width
+                    } else {
+                        0.0
+                    }
+                })
+                .collect();
+
+            for (index, line) in lines.iter().enumerate() {
+                render_context_2_d.fill_text(
+                    line,
+                    global_position.x + bounds.x + offsets[index],
+                    global_position.y + bounds.y + index as f64 * line_height,
+                );
+            }
+
+            render_context_2_d.close_path();
+
+            // underlines the composition string
+            if let (Some((before, composition)), TextWrap::NoWrap) = (composition, text_wrap) {
+                let x = offsets.first().copied().unwrap_or_default()
+                    + render_context_2_d
+                        .measure(before.as_str(), font_size, font.as_str())
+                        .width;
+                let width = render_context_2_d
+                    .measure(composition.as_str(), font_size, font.as_str())
+                    .width
+                    .min(bounds.width - x);
+
+                if width > 0.0 {
+                    render_context_2_d.fill_rect(
+                        global_position.x + bounds.x + x,
+                        global_position.y + bounds.y + line_height - 1.0,
+                        width,
+                        1.0,
+                    );
+                }
+            }
         }
     }
 }
@@ -0,0 +1,67 @@
+use crate::{prelude::*, render::RenderTarget, utils::*};
+
+/// Used to render the pixels of the `texture` property, e.g. the frames of a render surface. A
+/// texture with an other size than the widget is scaled to the bounds of the widget, with the
+/// `letterbox` property it keeps its aspect ratio and is centered on the `background`.
+pub struct TextureRenderObject;
+
+impl Into<Box<dyn RenderObject>> for TextureRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for TextureRenderObject {
+    fn render_self(&self, ctx: &mut Context<'_>, global_position: &Point) {
+        let (bounds, texture, letterbox, background) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.try_clone::<RenderTarget>("texture"),
+                widget.try_clone::<bool>("letterbox").unwrap_or(false),
+                widget.try_clone::<Brush>("background"),
+            )
+        };
+
+        if let Some(background) = background {
+            ctx.render_context_2_d().set_fill_style(background);
+            ctx.render_context_2_d().fill_rect(
+                global_position.x,
+                global_position.y,
+                bounds.width(),
+                bounds.height(),
+            );
+        }
+
+        let texture = match texture {
+            Some(texture) if texture.width() > 0.0 && texture.height() > 0.0 => texture,
+            _ => return,
+        };
+
+        if texture.width() == bounds.width().floor() && texture.height() == bounds.height().floor()
+        {
+            ctx.render_context_2_d().draw_render_target(
+                &texture,
+                global_position.x,
+                global_position.y,
+            );
+            return;
+        }
+
+        let (x, y, width, height) = if letterbox {
+            let (scale, x, y) = VirtualResolution::new(texture.width(), texture.height())
+                .transform(bounds.width(), bounds.height());
+            (x, y, texture.width() * scale, texture.height() * scale)
+        } else {
+            (0.0, 0.0, bounds.width(), bounds.height())
+        };
+
+        ctx.render_context_2_d().draw_render_target_with_size(
+            &texture,
+            global_position.x + x,
+            global_position.y + y,
+            width,
+            height,
+        );
+    }
+}
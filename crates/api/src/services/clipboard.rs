@@ -0,0 +1,59 @@
+use std::{cell::RefCell, rc::Rc};
+
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+use clipboard::{ClipboardContext, ClipboardProvider};
+
+#[cfg(target_arch = "wasm32")]
+use stdweb::js;
+
+/// `Clipboard` represents a global service to copy text to and paste text from the clipboard.
+/// The text is written to the clipboard of the operating system or the browser if it is
+/// available, otherwise it is only shared between the windows of the application.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Clipboard {
+    text: Rc<RefCell<Option<String>>>,
+}
+
+impl Clipboard {
+    /// Creates a new empty clipboard.
+    pub fn new() -> Self {
+        Clipboard::default()
+    }
+
+    /// Copies the given text to the clipboard.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        let text = text.into();
+
+        #[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+        {
+            let context: Result<ClipboardContext, _> = ClipboardProvider::new();
+
+            if let Ok(mut context) = context {
+                let _ = context.set_contents(text.clone());
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        js! {
+            if (navigator.clipboard) {
+                navigator.clipboard.writeText(@{&text});
+            }
+        }
+
+        *self.text.borrow_mut() = Some(text);
+    }
+
+    /// Returns the current text of the clipboard.
+    pub fn text(&self) -> Option<String> {
+        #[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+        {
+            let context: Result<ClipboardContext, _> = ClipboardProvider::new();
+
+            if let Ok(text) = context.and_then(|mut context| context.get_contents()) {
+                return Some(text);
+            }
+        }
+
+        self.text.borrow().clone()
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+thread_local! {
+    static CURRENT_CLOCK: RefCell<Clock> = RefCell::new(Clock::default());
+}
+
+/// `Clock` represents a global service that provides the current time to states and event
+/// handlers, e.g. to detect double clicks and long presses.
+///
+/// The system time is used by default. A manual clock is only advanced with `advance`, so tests
+/// of time dependent states are deterministic, see `Application::run_stepped`. The clock of the
+/// application that runs on the current thread is returned by `Clock::current`.
+#[derive(Clone, Debug, Default)]
+pub struct Clock {
+    manual: Option<Rc<Cell<Duration>>>,
+}
+
+impl Clock {
+    /// Creates a clock that returns the system time.
+    pub fn system() -> Self {
+        Clock::default()
+    }
+
+    /// Creates a manual clock that starts at zero.
+    pub fn manual() -> Self {
+        Clock {
+            manual: Some(Rc::new(Cell::new(Duration::default()))),
+        }
+    }
+
+    /// Returns the clock of the application that runs on the current thread.
+    pub fn current() -> Self {
+        CURRENT_CLOCK.with(|clock| clock.borrow().clone())
+    }
+
+    // Sets the clock that is returned by `current` on this thread.
+    pub(crate) fn make_current(&self) {
+        CURRENT_CLOCK.with(|clock| *clock.borrow_mut() = self.clone());
+    }
+
+    /// Returns `true` if the clock is a manual clock.
+    pub fn is_manual(&self) -> bool {
+        self.manual.is_some()
+    }
+
+    /// Returns the time since the unix epoch or since the start of a manual clock.
+    pub fn now(&self) -> Duration {
+        if let Some(manual) = &self.manual {
+            return manual.get();
+        }
+
+        system_time()
+    }
+
+    /// Advances a manual clock by the given duration. A system clock is not changed.
+    pub fn advance(&self, duration: Duration) {
+        if let Some(manual) = &self.manual {
+            manual.set(manual.get() + duration);
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn system_time() -> Duration {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn system_time() -> Duration {
+    Duration::from_secs_f64(stdweb::web::Date::now() / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual() {
+        let clock = Clock::manual();
+        assert!(clock.is_manual());
+        assert_eq!(clock.now(), Duration::default());
+
+        // clones share the time
+        clock.clone().advance(Duration::from_millis(250));
+        assert_eq!(clock.now(), Duration::from_millis(250));
+
+        let system = Clock::system();
+        system.advance(Duration::from_secs(60));
+        assert!(!system.is_manual());
+        assert!(system.now() > Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_current() {
+        assert!(!Clock::current().is_manual());
+
+        let clock = Clock::manual();
+        clock.make_current();
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(Clock::current().now(), Duration::from_secs(1));
+    }
+}
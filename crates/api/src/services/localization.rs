@@ -0,0 +1,245 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, path::Path};
+
+use ron::de::from_str;
+
+/// `Localization` represents a global service that provides the translated strings of an
+/// application. Widgets reference the strings by key with the `loc!` macro, e.g.
+/// `.text(loc!("save_button"))`. If the language is changed with `set_language`, all localized
+/// properties are updated on the next run.
+///
+/// Translations could be loaded from `ron` files with a map of keys and strings or from simple
+/// Fluent (`ftl`) files with one `key = value` message per line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Localization {
+    language: String,
+    dictionaries: HashMap<String, HashMap<String, String>>,
+}
+
+impl Localization {
+    /// Creates a new `Localization` service with the given current language.
+    pub fn new(language: impl Into<String>) -> Self {
+        Localization {
+            language: language.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Gets the current language.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Sets the current language.
+    pub fn set_language(&mut self, language: impl Into<String>) {
+        self.language = language.into();
+    }
+
+    /// Returns the sorted list of all languages with translations.
+    pub fn languages(&self) -> Vec<&str> {
+        let mut languages: Vec<&str> = self.dictionaries.keys().map(|l| l.as_str()).collect();
+        languages.sort();
+        languages
+    }
+
+    /// Inserts a translated string for the given language.
+    pub fn insert(
+        &mut self,
+        language: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) {
+        self.dictionaries
+            .entry(language.into())
+            .or_default()
+            .insert(key.into(), value.into());
+    }
+
+    /// Loads the translations of the given language from a `ron` map of keys and strings.
+    pub fn load_ron(&mut self, language: impl Into<String>, content: &str) -> Result<(), String> {
+        let dictionary: HashMap<String, String> = from_str(content)
+            .map_err(|e| format!("Localization.load_ron: Could not read translations: {}", e))?;
+
+        self.dictionaries
+            .entry(language.into())
+            .or_default()
+            .extend(dictionary);
+
+        Ok(())
+    }
+
+    /// Loads the translations of the given language from a Fluent file. Only simple messages
+    /// are supported, indented lines continue the value of the previous message.
+    pub fn load_ftl(&mut self, language: impl Into<String>, content: &str) -> Result<(), String> {
+        let mut dictionary: Vec<(String, String)> = vec![];
+
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with(char::is_whitespace) {
+                if let Some((_, value)) = dictionary.last_mut() {
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+                    value.push_str(line.trim());
+                    continue;
+                }
+            }
+
+            let mut parts = line.splitn(2, '=');
+
+            match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) if !key.trim().is_empty() => {
+                    dictionary.push((key.trim().to_string(), value.trim().to_string()));
+                }
+                _ => {
+                    return Err(format!(
+                        "Localization.load_ftl: Invalid message in line {}: {}",
+                        index + 1,
+                        line
+                    ));
+                }
+            }
+        }
+
+        self.dictionaries
+            .entry(language.into())
+            .or_default()
+            .extend(dictionary);
+
+        Ok(())
+    }
+
+    /// Loads the translations of the given language from a `.ron` or `.ftl` file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_file(
+        &mut self,
+        language: impl Into<String>,
+        path: impl AsRef<Path>,
+    ) -> Result<(), String> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|_| format!("Localization.load_file: Could not read file {:?}", path))?;
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ron") => self.load_ron(language, &content),
+            Some("ftl") => self.load_ftl(language, &content),
+            _ => Err(format!(
+                "Localization.load_file: Unsupported file format {:?}",
+                path
+            )),
+        }
+    }
+
+    /// Returns the translation of the key in the current language if there is one.
+    pub fn try_text(&self, key: &str) -> Option<&str> {
+        self.dictionaries
+            .get(&self.language)
+            .and_then(|dictionary| dictionary.get(key))
+            .map(|value| value.as_str())
+    }
+
+    /// Returns the translation of the key in the current language or the key itself if there
+    /// is no translation.
+    pub fn text(&self, key: &str) -> String {
+        self.try_text(key).unwrap_or(key).to_string()
+    }
+}
+
+/// Key of a localized string, use the `loc!` macro to create it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalizedText(pub String);
+
+impl LocalizedText {
+    /// Creates a new localized text with the given key.
+    pub fn new(key: impl Into<String>) -> Self {
+        LocalizedText(key.into())
+    }
+
+    /// Gets the key of the string.
+    pub fn key(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Stores the localization keys of the properties of a widget and the language that is applied.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalizedProperties {
+    keys: BTreeMap<String, String>,
+    language: Option<String>,
+}
+
+impl LocalizedProperties {
+    /// Creates new localized properties from a map of property names and keys.
+    pub fn new(keys: BTreeMap<String, String>) -> Self {
+        LocalizedProperties {
+            keys,
+            language: None,
+        }
+    }
+
+    /// Gets the map of property names and keys.
+    pub fn keys(&self) -> &BTreeMap<String, String> {
+        &self.keys
+    }
+
+    /// Gets the language that is applied on the properties.
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Sets the language that is applied on the properties.
+    pub fn set_language(&mut self, language: impl Into<String>) {
+        self.language = Some(language.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text() {
+        let mut localization = Localization::new("de");
+        localization.insert("de", "save_button", "Speichern");
+        localization.insert("en", "save_button", "Save");
+
+        assert_eq!(localization.text("save_button"), "Speichern");
+        assert_eq!(localization.text("open_button"), "open_button");
+
+        localization.set_language("en");
+        assert_eq!(localization.text("save_button"), "Save");
+        assert_eq!(localization.try_text("open_button"), None);
+        assert_eq!(localization.languages(), vec!["de", "en"]);
+    }
+
+    #[test]
+    fn test_load_ron() {
+        let mut localization = Localization::new("en");
+
+        assert!(localization
+            .load_ron(
+                "en",
+                "{ \"save_button\": \"Save\", \"open_button\": \"Open\" }"
+            )
+            .is_ok());
+        assert_eq!(localization.text("open_button"), "Open");
+        assert!(localization.load_ron("en", "save_button = Save").is_err());
+    }
+
+    #[test]
+    fn test_load_ftl() {
+        let mut localization = Localization::new("en");
+
+        let content = "# Buttons\nsave_button = Save\n\nabout = OrbTk\n    a Rust UI toolkit\n";
+        assert!(localization.load_ftl("en", content).is_ok());
+        assert_eq!(localization.text("save_button"), "Save");
+        assert_eq!(localization.text("about"), "OrbTk\na Rust UI toolkit");
+
+        assert!(localization.load_ftl("en", "save_button Save").is_err());
+    }
+}
@@ -1,5 +1,15 @@
 //! This module contains global services.
 //!
+pub use self::clipboard::*;
+pub use self::clock::*;
+pub use self::localization::*;
+pub use self::resources::*;
 pub use self::settings::*;
+pub use self::state_recorder::*;
 
+mod clipboard;
+mod clock;
+mod localization;
+mod resources;
 mod settings;
+mod state_recorder;
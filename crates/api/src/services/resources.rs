@@ -0,0 +1,111 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+
+/// `Resources` represents a typed container of the shared state of an application, e.g. a
+/// database handle, the configuration or an event bus. A resource is registered once per type
+/// with `Application::resource` and is read by the states of all windows with
+/// `Context::resource`, without passing it through properties.
+///
+/// Resources are shared by reference, a resource that is changed by the states should use
+/// interior mutability, e.g. a `RefCell`.
+#[derive(Clone, Default)]
+pub struct Resources {
+    resources: Rc<RefCell<HashMap<TypeId, Rc<dyn Any>>>>,
+}
+
+impl Resources {
+    /// Creates an empty resource container.
+    pub fn new() -> Self {
+        Resources::default()
+    }
+
+    /// Inserts the resource of the type `T`. A previous resource of the type is replaced.
+    pub fn insert<T: Any>(&self, resource: T) {
+        self.resources
+            .borrow_mut()
+            .insert(TypeId::of::<T>(), Rc::new(resource));
+    }
+
+    /// Returns the resource of the type `T`.
+    pub fn get<T: Any>(&self) -> Option<Rc<T>> {
+        self.resources
+            .borrow()
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|resource| resource.downcast::<T>().ok())
+    }
+
+    /// Returns `true` if a resource of the type `T` is registered.
+    pub fn contains<T: Any>(&self) -> bool {
+        self.resources.borrow().contains_key(&TypeId::of::<T>())
+    }
+
+    /// Removes the resource of the type `T` and returns it.
+    pub fn remove<T: Any>(&self) -> Option<Rc<T>> {
+        self.resources
+            .borrow_mut()
+            .remove(&TypeId::of::<T>())
+            .and_then(|resource| resource.downcast::<T>().ok())
+    }
+
+    /// Returns the number of registered resources.
+    pub fn len(&self) -> usize {
+        self.resources.borrow().len()
+    }
+
+    /// Returns `true` if no resource is registered.
+    pub fn is_empty(&self) -> bool {
+        self.resources.borrow().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Config {
+        name: &'static str,
+    }
+
+    #[test]
+    fn test_insert_get() {
+        let resources = Resources::new();
+        assert!(resources.is_empty());
+        assert_eq!(resources.get::<Config>(), None);
+
+        resources.insert(Config { name: "one" });
+        resources.insert(RefCell::new(vec![1, 2]));
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources.get::<Config>().unwrap().name, "one");
+
+        // clones share the resources
+        let shared = resources.clone();
+        shared.insert(Config { name: "two" });
+        shared
+            .get::<RefCell<Vec<i32>>>()
+            .unwrap()
+            .borrow_mut()
+            .push(3);
+        assert_eq!(resources.get::<Config>().unwrap().name, "two");
+        assert_eq!(
+            *resources.get::<RefCell<Vec<i32>>>().unwrap().borrow(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let resources = Resources::new();
+        resources.insert(Config { name: "one" });
+        assert!(resources.contains::<Config>());
+
+        assert_eq!(resources.remove::<Config>().unwrap().name, "one");
+        assert!(!resources.contains::<Config>());
+        assert_eq!(resources.remove::<Config>(), None);
+    }
+}
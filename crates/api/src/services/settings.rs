@@ -1,37 +1,240 @@
+use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
+
 #[cfg(not(target_arch = "wasm32"))]
 use std::{
-    fs::{create_dir_all, File},
+    fs::{create_dir_all, read_to_string, remove_file, File},
     io::Write,
+    path::PathBuf,
 };
 
 #[cfg(target_arch = "wasm32")]
 use stdweb::web::window;
 
-#[cfg(target_arch = "wasm32")]
-use ron::de::from_str;
+use ron::{
+    de::from_str,
+    ser::{to_string_pretty, PrettyConfig},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Describes the storage backend of the `Settings` service, that persists the serialized settings
+/// of an application by key.
+pub trait SettingsStorage {
+    /// Reads the content that is stored under the given key of the application.
+    fn read(&self, app_name: &str, key: &str) -> Result<String, String>;
 
+    /// Writes the content under the given key of the application.
+    fn write(&self, app_name: &str, key: &str, content: &str) -> Result<(), String>;
+
+    /// Removes the content that is stored under the given key of the application.
+    fn remove(&self, app_name: &str, key: &str) -> Result<(), String>;
+}
+
+/// Stores the settings as `ron` files in a folder with the application name inside of the user
+/// config directory (depending on the operating system, also on Redox) or a custom directory.
 #[cfg(not(target_arch = "wasm32"))]
-use dirs;
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileStorage {
+    dir: Option<PathBuf>,
+}
 
 #[cfg(not(target_arch = "wasm32"))]
-use ron::de::from_reader;
+impl FileStorage {
+    /// Creates a new file storage in the user config directory.
+    pub fn new() -> Self {
+        FileStorage::default()
+    }
 
-use ron::ser::{to_string_pretty, PrettyConfig};
+    /// Creates a new file storage in the given directory.
+    pub fn from_dir(dir: impl Into<PathBuf>) -> Self {
+        FileStorage {
+            dir: Some(dir.into()),
+        }
+    }
 
-use serde::{de::DeserializeOwned, Serialize};
+    // Returns the path of the file with the given key.
+    fn path(&self, app_name: &str, key: &str) -> Option<PathBuf> {
+        let mut path = self.dir.clone().or_else(dirs::config_dir)?;
+        path.push(app_name);
+        path.push(format!("{}.ron", key));
+        Some(path)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SettingsStorage for FileStorage {
+    fn read(&self, app_name: &str, key: &str) -> Result<String, String> {
+        let path = self.path(app_name, key).ok_or_else(|| {
+            format!(
+                "FileStorage.read: Could not load settings with key: {}",
+                key
+            )
+        })?;
+
+        read_to_string(&path)
+            .map_err(|_| format!("FileStorage.read: Could not open config file {:?}", path))
+    }
+
+    fn write(&self, app_name: &str, key: &str, content: &str) -> Result<(), String> {
+        let path = match self.path(app_name, key) {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        if let Some(dir) = path.parent() {
+            if !dir.exists() && create_dir_all(dir).is_err() {
+                return Err(format!(
+                    "FileStorage.write: Could not create settings dir {:?}",
+                    dir
+                ));
+            }
+        }
+
+        let mut file = File::create(&path)
+            .map_err(|_| format!("FileStorage.write: Could not create config file {:?}", path))?;
+
+        file.write_all(content.as_bytes()).map_err(|_| {
+            format!(
+                "FileStorage.write: Could not write to config file {:?}",
+                path
+            )
+        })
+    }
+
+    fn remove(&self, app_name: &str, key: &str) -> Result<(), String> {
+        if let Some(path) = self.path(app_name, key) {
+            if path.exists() && remove_file(&path).is_err() {
+                return Err(format!(
+                    "FileStorage.remove: Could not remove config file {:?}",
+                    path
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Stores the settings in the local storage of the browser.
+#[cfg(target_arch = "wasm32")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorage {
+    /// Creates a new local storage.
+    pub fn new() -> Self {
+        LocalStorage
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl SettingsStorage for LocalStorage {
+    fn read(&self, _: &str, key: &str) -> Result<String, String> {
+        window().local_storage().get(key).ok_or_else(|| {
+            format!(
+                "LocalStorage.read: Could not read data from local browser storage with key: {}",
+                key
+            )
+        })
+    }
+
+    fn write(&self, _: &str, key: &str, content: &str) -> Result<(), String> {
+        window()
+            .local_storage()
+            .insert(key, content)
+            .map_err(|_| {
+                format!(
+                    "LocalStorage.write: Could not write settings with key {} to local browser storage.",
+                    key
+                )
+            })
+    }
+
+    fn remove(&self, _: &str, key: &str) -> Result<(), String> {
+        window().local_storage().remove(key);
+        Ok(())
+    }
+}
+
+/// Keeps the settings only in memory, e.g. for tests or applications without persistence. The
+/// clones of a memory storage share their content.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryStorage {
+    content: Rc<RefCell<HashMap<(String, String), String>>>,
+}
+
+impl MemoryStorage {
+    /// Creates a new empty memory storage.
+    pub fn new() -> Self {
+        MemoryStorage::default()
+    }
+}
+
+impl SettingsStorage for MemoryStorage {
+    fn read(&self, app_name: &str, key: &str) -> Result<String, String> {
+        self.content
+            .borrow()
+            .get(&(app_name.to_string(), key.to_string()))
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "MemoryStorage.read: Could not load settings with key: {}",
+                    key
+                )
+            })
+    }
+
+    fn write(&self, app_name: &str, key: &str, content: &str) -> Result<(), String> {
+        self.content
+            .borrow_mut()
+            .insert((app_name.to_string(), key.to_string()), content.to_string());
+        Ok(())
+    }
+
+    fn remove(&self, app_name: &str, key: &str) -> Result<(), String> {
+        self.content
+            .borrow_mut()
+            .remove(&(app_name.to_string(), key.to_string()));
+        Ok(())
+    }
+}
+
+// Returns the default storage of the platform.
+#[cfg(not(target_arch = "wasm32"))]
+fn default_storage() -> Rc<dyn SettingsStorage> {
+    Rc::new(FileStorage::new())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn default_storage() -> Rc<dyn SettingsStorage> {
+    Rc::new(LocalStorage::new())
+}
 
 /// `Settings` represents a global settings service that could be use to serialize and deserialize
-/// data in the `ron` file format. Settings are stored in the user settings directory (depending on the operation system)
-/// under the a folder with the given application name.
+/// data in the `ron` file format. By default settings are stored in the user settings directory (depending on the operation system)
+/// under the a folder with the given application name. On the web the browser storage is used.
+/// Another backend could be set with `Settings::with_storage`.
+///
+/// The service is registered in the registry under the key `settings` and could also be accessed
+/// from each state with `ctx.settings()`.
+#[derive(Clone)]
 pub struct Settings {
     app_name: Box<str>,
+    storage: Rc<dyn SettingsStorage>,
 }
 
 impl Default for Settings {
     fn default() -> Self {
-        Settings {
-            app_name: "orbtk-app".into(),
-        }
+        Settings::new("orbtk-app")
+    }
+}
+
+impl fmt::Debug for Settings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Settings")
+            .field("app_name", &self.app_name)
+            .finish()
     }
 }
 
@@ -40,6 +243,25 @@ impl Settings {
     pub fn new(app_name: impl Into<Box<str>>) -> Self {
         Settings {
             app_name: app_name.into(),
+            storage: default_storage(),
+        }
+    }
+
+    /// Creates a new `Settings` service with the given app name and storage backend.
+    pub fn with_storage(
+        app_name: impl Into<Box<str>>,
+        storage: impl SettingsStorage + 'static,
+    ) -> Self {
+        Settings::from_shared_storage(app_name, Rc::new(storage))
+    }
+
+    pub(crate) fn from_shared_storage(
+        app_name: impl Into<Box<str>>,
+        storage: Rc<dyn SettingsStorage>,
+    ) -> Self {
+        Settings {
+            app_name: app_name.into(),
+            storage,
         }
     }
 
@@ -48,111 +270,57 @@ impl Settings {
         &*self.app_name
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
-    /// Serialize the given data object from user's config dir.
-    pub fn save<S: Serialize>(&self, key: &str, data: &S) -> Result<(), String> {
-        let content = to_string_pretty(data, PrettyConfig::default());
-
-        if let Some(config_path) = &mut dirs::config_dir() {
-            config_path.push(&*self.app_name);
-
-            if !config_path.exists() {
-                let result = create_dir_all(&config_path);
-
-                if result.is_err() {
-                    return Err(format!(
-                        "Settings.save: Could not create settings dir {:?}",
-                        config_path
-                    ));
-                }
-            }
+    /// Loads and deserialize data with the given key or returns the default value if the data
+    /// could not be loaded, e.g. on the first start of the application.
+    pub fn load_or_default<D: DeserializeOwned + Default>(&self, key: &str) -> D {
+        self.load(key).unwrap_or_default()
+    }
 
-            config_path.push(format!("{}.ron", key));
-
-            if let Ok(file) = &mut File::create(&config_path) {
-                let result = file.write_all(content.unwrap().as_bytes());
-                if result.is_err() {
-                    return Err(format!(
-                        "Settings.save: Could not write to config file {:?}",
-                        config_path
-                    ));
-                }
-            } else {
-                return Err(format!(
-                    "Settings.save: Could not create config file {:?}",
-                    config_path
-                ));
-            }
-        }
+    /// Serialize the given data object to the storage.
+    pub fn save<S: Serialize>(&self, key: &str, data: &S) -> Result<(), String> {
+        let content = to_string_pretty(data, PrettyConfig::default())
+            .map_err(|e| format!("Settings.save: Could not serialize data: {}", e))?;
 
-        Ok(())
+        self.storage.write(&*self.app_name, key, content.as_str())
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
-    /// Loads and deserialize data from user's config dir.
+    /// Loads and deserialize data from the storage.
     pub fn load<D: DeserializeOwned>(&self, key: &str) -> Result<D, String> {
-        if let Some(config_path) = &mut dirs::config_dir() {
-            config_path.push(&*self.app_name);
-            config_path.push(format!("{}.ron", key));
-
-            if let Ok(file) = &mut File::open(&config_path) {
-                if let Ok(data) = from_reader(file) {
-                    return Ok(data);
-                } else {
-                    return Err(format!(
-                        "Settings.load: Could not read data from config file {:?}",
-                        config_path
-                    ));
-                }
-            } else {
-                return Err(format!(
-                    "Settings.load: Could not open config file {:?}",
-                    config_path
-                ));
-            }
-        }
+        let content = self.storage.read(&*self.app_name, key)?;
 
-        Err(format!(
-            "Settings.load: Could not load settings with key: {}",
-            key
-        ))
+        from_str(content.as_str())
+            .map_err(|_| format!("Settings.load: Could not read data with key: {}", key))
     }
 
-    #[cfg(target_arch = "wasm32")]
-    /// Serialize the given data object from the browser storage.
-    pub fn save<S: Serialize>(&self, key: &str, data: &S) -> Result<(), String> {
-        let content = to_string_pretty(data, PrettyConfig::default());
-        if window()
-            .local_storage()
-            .insert(key, content.unwrap().as_str())
-            .is_ok()
-        {
-            return Ok(());
-        }
-
-        Err(format!(
-            "Settings.save: Could not write settings with key {} to local browser storage.",
-            key
-        ))
+    /// Removes the data with the given key from the storage.
+    pub fn remove(&self, key: &str) -> Result<(), String> {
+        self.storage.remove(&*self.app_name, key)
     }
+}
 
-    #[cfg(target_arch = "wasm32")]
-    /// Loads and deserialize data from the browser storage.
-    pub fn load<D: DeserializeOwned>(&self, key: &str) -> Result<D, String> {
-        if let Some(data) = window().local_storage().get(key) {
-            if let Ok(data) = from_str(data.as_str()) {
-                return Ok(data);
-            } else {
-                return Err(format!(
-                    "Settings.load: Could not read data from local browser storage with key: {}",
-                    key
-                ));
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_storage() {
+        let storage = MemoryStorage::new();
+        let settings = Settings::with_storage("app", storage.clone());
+        let other_settings = Settings::with_storage("other-app", storage);
+
+        assert!(settings.load::<Vec<String>>("list").is_err());
+        assert_eq!(
+            settings.load_or_default::<Vec<String>>("list"),
+            Vec::<String>::new()
+        );
+
+        let list = vec!["a".to_string(), "b".to_string()];
+        assert!(settings.save("list", &list).is_ok());
+        assert_eq!(settings.load::<Vec<String>>("list"), Ok(list));
+        assert!(other_settings.load::<Vec<String>>("list").is_err());
+        assert!(settings.load::<usize>("list").is_err());
 
-        Err(format!(
-            "Settings.load: Could not read data from local browser storage with key: {}",
-            key
-        ))
+        assert!(settings.remove("list").is_ok());
+        assert!(settings.load::<Vec<String>>("list").is_err());
     }
 }
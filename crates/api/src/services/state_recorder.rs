@@ -0,0 +1,367 @@
+use std::{any::Any, collections::BTreeMap, fmt::Debug, marker::PhantomData};
+
+use dces::prelude::{Component, Entity, EntityComponentManager, StringComponentStore};
+
+use crate::tree::Tree;
+
+// Reads, compares and writes the values of a tracked property type.
+trait PropertyTracker {
+    fn snapshot(
+        &self,
+        key: &str,
+        entity: Entity,
+        ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Option<Box<dyn Any>>;
+
+    fn equals(&self, a: &dyn Any, b: &dyn Any) -> bool;
+
+    fn clone_value(&self, value: &dyn Any) -> Box<dyn Any>;
+
+    fn apply(
+        &self,
+        key: &str,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        value: &dyn Any,
+    );
+
+    fn describe(&self, value: &dyn Any) -> String;
+}
+
+struct TypedTracker<P>(PhantomData<P>);
+
+impl<P> PropertyTracker for TypedTracker<P>
+where
+    P: Component + Clone + PartialEq + Debug,
+{
+    fn snapshot(
+        &self,
+        key: &str,
+        entity: Entity,
+        ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Option<Box<dyn Any>> {
+        ecm.component_store()
+            .get::<P>(key, entity)
+            .ok()
+            .map(|value| Box::new(value.clone()) as Box<dyn Any>)
+    }
+
+    fn equals(&self, a: &dyn Any, b: &dyn Any) -> bool {
+        a.downcast_ref::<P>() == b.downcast_ref::<P>()
+    }
+
+    fn clone_value(&self, value: &dyn Any) -> Box<dyn Any> {
+        Box::new(value.downcast_ref::<P>().unwrap().clone())
+    }
+
+    fn apply(
+        &self,
+        key: &str,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        value: &dyn Any,
+    ) {
+        if let (Ok(property), Some(value)) = (
+            ecm.component_store_mut().get_mut::<P>(key, entity),
+            value.downcast_ref::<P>(),
+        ) {
+            *property = value.clone();
+        }
+    }
+
+    fn describe(&self, value: &dyn Any) -> String {
+        format!("{:?}", value.downcast_ref::<P>().unwrap())
+    }
+}
+
+/// Describes the change of a property value in a recorded frame.
+pub struct PropertyChange {
+    entity: Entity,
+    tracker: usize,
+    old_value: Box<dyn Any>,
+    new_value: Box<dyn Any>,
+}
+
+impl PropertyChange {
+    /// Gets the entity of the changed widget.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Step {
+    Backward,
+    Forward,
+}
+
+/// The `StateRecorder` is an opt-in debug service that records the changes of the tracked
+/// properties of all widgets frame by frame. The recorded frames could be stepped backward and
+/// forward to inspect how the ui got into its current state.
+///
+/// Register the recorder with the key `state_recorder` to enable it:
+///
+/// ```rust,ignore
+/// registry.register(
+///     "state_recorder",
+///     StateRecorder::new().track::<String16>("text").track::<bool>("selected"),
+/// );
+/// ```
+///
+/// If a new change is recorded after stepping backward, the frames after the current position
+/// are discarded.
+pub struct StateRecorder {
+    trackers: Vec<(String, Box<dyn PropertyTracker>)>,
+    values: BTreeMap<(Entity, usize), Box<dyn Any>>,
+    frames: Vec<Vec<PropertyChange>>,
+    position: usize,
+    max_frames: usize,
+    steps: Vec<Step>,
+}
+
+impl Default for StateRecorder {
+    fn default() -> Self {
+        StateRecorder {
+            trackers: vec![],
+            values: BTreeMap::new(),
+            frames: vec![],
+            position: 0,
+            max_frames: 1000,
+            steps: vec![],
+        }
+    }
+}
+
+impl StateRecorder {
+    /// Creates a new recorder without tracked properties.
+    pub fn new() -> Self {
+        StateRecorder::default()
+    }
+
+    /// Tracks the property with the given key and type on all widgets.
+    pub fn track<P>(mut self, key: impl Into<String>) -> Self
+    where
+        P: Component + Clone + PartialEq + Debug,
+    {
+        self.trackers
+            .push((key.into(), Box::new(TypedTracker::<P>(PhantomData))));
+        self
+    }
+
+    /// Sets the maximum number of recorded frames. The oldest frames are discarded.
+    pub fn max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = max_frames.max(1);
+        self
+    }
+
+    /// Returns the number of recorded frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if no frame is recorded.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the number of frames that are applied. It is smaller than `len` after stepping
+    /// backward.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Discards all recorded frames.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.position = 0;
+    }
+
+    /// Requests to revert the changes of the last applied frame. It is executed after the next
+    /// update.
+    pub fn step_backward(&mut self) {
+        self.steps.push(Step::Backward);
+    }
+
+    /// Requests to apply the changes of the next frame again. It is executed after the next
+    /// update.
+    pub fn step_forward(&mut self) {
+        self.steps.push(Step::Forward);
+    }
+
+    /// Returns the recorded changes of the frame with the given index.
+    pub fn frame(&self, index: usize) -> Option<&[PropertyChange]> {
+        self.frames.get(index).map(|frame| frame.as_slice())
+    }
+
+    /// Describes a change in the format `key: old -> new`.
+    pub fn describe(&self, change: &PropertyChange) -> String {
+        let (key, tracker) = &self.trackers[change.tracker];
+
+        format!(
+            "{}: {} -> {}",
+            key,
+            tracker.describe(&*change.old_value),
+            tracker.describe(&*change.new_value)
+        )
+    }
+
+    /// Executes the requested steps and records the changes of the current frame. Returns true
+    /// if values are changed by a step.
+    pub fn run(&mut self, ecm: &mut EntityComponentManager<Tree, StringComponentStore>) -> bool {
+        let steps: Vec<Step> = self.steps.drain(..).collect();
+        let stepped = !steps.is_empty();
+
+        // changes since the last frame are recorded before the steps to not lose them
+        self.record(ecm);
+
+        for step in steps {
+            match step {
+                Step::Backward if self.position > 0 => {
+                    self.position -= 1;
+                    self.apply(self.position, false, ecm);
+                }
+                Step::Forward if self.position < self.frames.len() => {
+                    self.apply(self.position, true, ecm);
+                    self.position += 1;
+                }
+                _ => {}
+            }
+        }
+
+        stepped
+    }
+
+    fn record(&mut self, ecm: &EntityComponentManager<Tree, StringComponentStore>) {
+        let mut changes = vec![];
+        let entities: Vec<Entity> = ecm.entity_store().children.keys().cloned().collect();
+
+        for entity in entities {
+            for (index, (key, tracker)) in self.trackers.iter().enumerate() {
+                let value = match tracker.snapshot(key, entity, ecm) {
+                    Some(value) => value,
+                    None => continue,
+                };
+
+                if let Some(old_value) = self.values.get(&(entity, index)) {
+                    if tracker.equals(&**old_value, &*value) {
+                        continue;
+                    }
+
+                    changes.push(PropertyChange {
+                        entity,
+                        tracker: index,
+                        old_value: tracker.clone_value(&**old_value),
+                        new_value: tracker.clone_value(&*value),
+                    });
+                }
+
+                self.values.insert((entity, index), value);
+            }
+        }
+
+        if changes.is_empty() {
+            return;
+        }
+
+        self.frames.truncate(self.position);
+        self.frames.push(changes);
+
+        if self.frames.len() > self.max_frames {
+            self.frames.remove(0);
+        }
+
+        self.position = self.frames.len();
+    }
+
+    fn apply(
+        &mut self,
+        frame: usize,
+        forward: bool,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let changes: Box<dyn Iterator<Item = &PropertyChange>> = if forward {
+            Box::new(self.frames[frame].iter())
+        } else {
+            Box::new(self.frames[frame].iter().rev())
+        };
+
+        for change in changes {
+            let (key, tracker) = &self.trackers[change.tracker];
+            let value = if forward {
+                &change.new_value
+            } else {
+                &change.old_value
+            };
+
+            tracker.apply(key, change.entity, ecm, &**value);
+
+            // the applied value is not recorded as new change
+            self.values.insert(
+                (change.entity, change.tracker),
+                tracker.clone_value(&**value),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dces::prelude::World;
+
+    use super::*;
+
+    #[test]
+    fn test_record_and_step() {
+        let mut world = World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+        let entity = ecm.create_entity().build();
+        ecm.component_store_mut().register("count", entity, 0_usize);
+
+        let mut recorder = StateRecorder::new().track::<usize>("count");
+        recorder.run(ecm);
+        assert!(recorder.is_empty());
+
+        *ecm.component_store_mut()
+            .get_mut::<usize>("count", entity)
+            .unwrap() = 1;
+        recorder.run(ecm);
+        *ecm.component_store_mut()
+            .get_mut::<usize>("count", entity)
+            .unwrap() = 2;
+        recorder.run(ecm);
+        assert_eq!(recorder.len(), 2);
+        assert_eq!(recorder.position(), 2);
+        assert_eq!(
+            recorder.describe(&recorder.frame(1).unwrap()[0]),
+            "count: 1 -> 2"
+        );
+
+        recorder.step_backward();
+        recorder.step_backward();
+        assert!(recorder.run(ecm));
+        assert_eq!(recorder.position(), 0);
+        assert_eq!(
+            *ecm.component_store().get::<usize>("count", entity).unwrap(),
+            0
+        );
+
+        recorder.step_forward();
+        recorder.run(ecm);
+        assert_eq!(recorder.position(), 1);
+        assert_eq!(
+            *ecm.component_store().get::<usize>("count", entity).unwrap(),
+            1
+        );
+
+        // a new change discards the frames after the position
+        *ecm.component_store_mut()
+            .get_mut::<usize>("count", entity)
+            .unwrap() = 5;
+        recorder.run(ecm);
+        assert_eq!(recorder.len(), 2);
+        assert_eq!(
+            recorder.describe(&recorder.frame(1).unwrap()[0]),
+            "count: 1 -> 5"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use dces::prelude::{Entity, EntityComponentManager, System};
+
+use crate::{
+    prelude::*,
+    shell::{AccessibilityNode, WindowShell},
+    tree::Tree,
+    utils::*,
+};
+
+/// The `AccessibilitySystem` collects the widgets with an accessible role and exposes them to the
+/// platform accessibility apis over the window shell. Runs after the render system, because the
+/// absolute positions of the widgets are calculated there.
+pub struct AccessibilitySystem {
+    pub shell: Rc<RefCell<WindowShell<WindowAdapter>>>,
+}
+
+impl System<Tree, StringComponentStore> for AccessibilitySystem {
+    fn run(&self, ecm: &mut EntityComponentManager<Tree, StringComponentStore>) {
+        if !self.shell.borrow().update() || ecm.entity_store().parent.is_empty() {
+            return;
+        }
+
+        let root = ecm.entity_store().root();
+        let store = ecm.component_store();
+
+        let focused_widget = store
+            .get::<Global>("global", root)
+            .ok()
+            .and_then(|global| global.focused_widget);
+
+        let mut nodes = vec![];
+
+        // accessible ancestor node of the visible widgets
+        let mut parent_nodes: BTreeMap<Entity, Option<u64>> = BTreeMap::new();
+
+        for entity in ecm.entity_store().start_node(root).into_iter() {
+            let parent = ecm.entity_store().parent[&entity];
+
+            // children of hidden widgets and leaf nodes are not exposed
+            if let Some(parent) = parent {
+                if !parent_nodes.contains_key(&parent) {
+                    continue;
+                }
+            }
+
+            if let Ok(visibility) = store.get::<Visibility>("visibility", entity) {
+                if *visibility != Visibility::Visible {
+                    continue;
+                }
+            }
+
+            let parent_node = parent.and_then(|parent| parent_nodes[&parent]);
+
+            let role = store
+                .get::<AccessibleRole>("accessible_role", entity)
+                .map(|role| *role)
+                .unwrap_or_default();
+
+            if role == AccessibleRole::None {
+                parent_nodes.insert(entity, parent_node);
+                continue;
+            }
+
+            // the text of text inputs is not used as name to not expose passwords
+            let name = match store.get::<String>("accessible_name", entity) {
+                Ok(name) if !name.is_empty() => name.clone(),
+                _ if role != AccessibleRole::TextInput => store
+                    .get::<String16>("text", entity)
+                    .map(|text| text.to_string())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            };
+
+            let position = store
+                .get::<Point>("position", entity)
+                .map(|position| *position)
+                .unwrap_or_default();
+
+            let bounds = store
+                .get::<Rectangle>("bounds", entity)
+                .map(|bounds| *bounds)
+                .unwrap_or_default();
+
+            let id = entity.0 as u64;
+            let index = |key: &str| store.get::<usize>(key, entity).map(|index| *index).ok();
+            let relation = |key: &str| {
+                store
+                    .get::<Entity>(key, entity)
+                    .map(|related| related.0 as u64)
+                    .ok()
+            };
+
+            nodes.push(AccessibilityNode {
+                id,
+                parent: parent_node,
+                role,
+                name,
+                automation_id: store
+                    .get::<String>("automation_id", entity)
+                    .map(|automation_id| automation_id.clone())
+                    .unwrap_or_default(),
+                bounds: Rectangle::new(position.x, position.y, bounds.width(), bounds.height()),
+                enabled: store
+                    .get::<bool>("enabled", entity)
+                    .map(|enabled| *enabled)
+                    .unwrap_or(true),
+                focused: focused_widget == Some(entity),
+                selected: store
+                    .get::<bool>("selected", entity)
+                    .map(|selected| *selected)
+                    .unwrap_or(false),
+                row_index: index("accessible_row_index"),
+                column_index: index("accessible_column_index"),
+                row_count: index("accessible_row_count"),
+                column_count: index("accessible_column_count"),
+                header: relation("accessible_header"),
+                active_descendant: relation("accessible_active_descendant"),
+            });
+
+            if !role.is_leaf() {
+                parent_nodes.insert(entity, Some(id));
+            }
+        }
+
+        // relations to removed or hidden widgets are dropped
+        let ids: Vec<u64> = nodes.iter().map(|node| node.id).collect();
+
+        for node in &mut nodes {
+            node.header = node.header.filter(|header| ids.contains(header));
+            node.active_descendant = node
+                .active_descendant
+                .filter(|descendant| ids.contains(descendant));
+        }
+
+        let mut shell = self.shell.borrow_mut();
+
+        if shell.accessibility_tree() != nodes.as_slice() {
+            shell.set_accessibility_tree(nodes);
+        }
+    }
+}
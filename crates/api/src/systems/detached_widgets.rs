@@ -0,0 +1,68 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use dces::prelude::{Entity, EntityComponentManager};
+
+use crate::{prelude::*, shell::WindowShell, tree::Tree};
+
+// Calls the `detached` hooks of the widgets that are removed from the tree and removes their
+// entities, states, handlers, layouts and render objects. Widgets that are removed by a hook are
+// removed in the same run.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn remove_detached_widgets(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    shell: &mut WindowShell<WindowAdapter>,
+    theme: &ThemeValue,
+    render_objects: &RefCell<ArenaMap<dyn RenderObject>>,
+    layouts: &RefCell<ArenaMap<dyn Layout>>,
+    handlers: &RefCell<EventHandlerMap>,
+    states: &RefCell<BTreeMap<Entity, Box<dyn State>>>,
+    registry: &RefCell<Registry>,
+) {
+    loop {
+        let detached: Vec<Entity> = shell.adapter().detached_widgets.drain(..).collect();
+
+        if detached.is_empty() {
+            return;
+        }
+
+        for widget in detached {
+            if !ecm.entity_store().children.contains_key(&widget) {
+                continue;
+            }
+
+            // children are detached before their parents
+            let subtree: Vec<Entity> = paint_order(ecm, widget).into_iter().rev().collect();
+
+            for entity in &subtree {
+                // the state is taken out of the map, so the hook could access other states
+                let state = states.borrow_mut().remove(entity);
+
+                if let Some(mut state) = state {
+                    let layouts = &mut layouts.borrow_mut();
+                    let handlers = &mut handlers.borrow_mut();
+                    let new_states = &mut BTreeMap::new();
+
+                    let mut ctx = Context::new(
+                        (*entity, ecm),
+                        shell,
+                        theme,
+                        render_objects,
+                        layouts,
+                        handlers,
+                        states,
+                        new_states,
+                    );
+
+                    state.detached(&mut *registry.borrow_mut(), &mut ctx);
+                }
+            }
+
+            for entity in subtree {
+                render_objects.borrow_mut().remove(&entity);
+                layouts.borrow_mut().remove(&entity);
+                handlers.borrow_mut().remove(&entity);
+                ecm.remove_entity(entity);
+            }
+        }
+    }
+}
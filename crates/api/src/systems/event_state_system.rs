@@ -2,16 +2,23 @@ use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use dces::prelude::{Entity, EntityComponentManager, System};
 
-use crate::{css_engine::*, prelude::*, shell::WindowShell, tree::Tree, utils::*};
+use crate::{
+    css_engine::*,
+    prelude::*,
+    shell::{TouchPhase, WindowShell},
+    tree::Tree,
+    utils::*,
+};
 
 /// The `EventStateSystem` pops events from the event queue and delegates the events to the corresponding event handlers of the widgets and updates the states.
 pub struct EventStateSystem {
     pub shell: Rc<RefCell<WindowShell<WindowAdapter>>>,
     pub handlers: Rc<RefCell<EventHandlerMap>>,
     pub mouse_down_nodes: RefCell<Vec<Entity>>,
+    pub touch_down_nodes: RefCell<BTreeMap<u64, Vec<Entity>>>,
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
-    pub render_objects: Rc<RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>>,
-    pub layouts: Rc<RefCell<BTreeMap<Entity, Box<dyn Layout>>>>,
+    pub render_objects: Rc<RefCell<ArenaMap<dyn RenderObject>>>,
+    pub layouts: Rc<RefCell<ArenaMap<dyn Layout>>>,
     pub registry: Rc<RefCell<Registry>>,
 }
 
@@ -23,6 +30,35 @@ impl EventStateSystem {
     // ) {
     // }
 
+    // Asks all close requested handlers of the window if it could be closed.
+    fn close_allowed(&self, root: Entity) -> bool {
+        let event = EventBox::new(CloseRequestedEvent, EventStrategy::Direct, root);
+        let mut allowed = true;
+
+        if let Some(handlers) = self.handlers.borrow().get(&root) {
+            for handler in handlers.iter().filter(|h| h.handles_event(&event)) {
+                // a vetoing handler handles the event
+                if handler.handle_event(
+                    &mut StatesContext::new(&mut *self.states.borrow_mut()),
+                    &event,
+                ) {
+                    allowed = false;
+                }
+            }
+        }
+
+        allowed
+    }
+
+    // Removes the handlers that should not be called anymore, e.g. once handlers.
+    fn remove_expired_handlers(&self) {
+        let mut handlers = self.handlers.borrow_mut();
+
+        for widget_handlers in handlers.values_mut() {
+            widget_handlers.retain(|handler| !handler.is_expired());
+        }
+    }
+
     fn process_direct(&self, event: &EventBox) -> bool {
         if event.strategy == EventStrategy::Direct {
             if let Some(handlers) = self.handlers.borrow().get(&event.source) {
@@ -91,8 +127,11 @@ impl EventStateSystem {
                 }
             }
 
-            // key down event
-            if event.downcast_ref::<KeyDownEvent>().is_ok() {
+            // key down, text input and text composition event
+            if event.downcast_ref::<KeyDownEvent>().is_ok()
+                || event.downcast_ref::<TextInputEvent>().is_ok()
+                || event.downcast_ref::<TextCompositionEvent>().is_ok()
+            {
                 if let Some(focused) = ecm
                     .component_store()
                     .get::<Global>("global", root)
@@ -104,6 +143,16 @@ impl EventStateSystem {
                     }
                 }
 
+                // the window handles the key down events that are not handled by the focused
+                // widget, e.g. the shortcuts of its commands
+                if current_node == root
+                    && has_handler
+                    && event.downcast_ref::<KeyDownEvent>().is_ok()
+                    && !matching_nodes.contains(&root)
+                {
+                    matching_nodes.push(root);
+                }
+
                 unknown_event = false;
             }
 
@@ -228,6 +277,35 @@ impl EventStateSystem {
                 unknown_event = false;
             }
 
+            // touch handling, the touch points are delivered to the widgets they are started on
+            if let Ok(event) = event.downcast_ref::<TouchInputEvent>() {
+                let event = event.event;
+
+                if event.phase == TouchPhase::Down {
+                    if check_mouse_condition(
+                        Point::new(event.x, event.y),
+                        &WidgetContainer::new(current_node, ecm, &theme),
+                    ) && has_handler
+                    {
+                        matching_nodes.push(current_node);
+                        self.touch_down_nodes
+                            .borrow_mut()
+                            .entry(event.id)
+                            .or_insert_with(Vec::new)
+                            .push(current_node);
+                    }
+                } else if self
+                    .touch_down_nodes
+                    .borrow()
+                    .get(&event.id)
+                    .map_or(false, |nodes| nodes.contains(&current_node))
+                {
+                    matching_nodes.push(current_node);
+                }
+
+                unknown_event = false;
+            }
+
             if unknown_event
                 && *WidgetContainer::new(current_node, ecm, &theme).get::<bool>("enabled")
             {
@@ -253,6 +331,26 @@ impl EventStateSystem {
             }
         }
 
+        if let Ok(event) = event.downcast_ref::<TouchInputEvent>() {
+            match event.event.phase {
+                TouchPhase::Up | TouchPhase::Cancel => {
+                    self.touch_down_nodes.borrow_mut().remove(&event.event.id);
+                }
+                _ => {}
+            }
+        }
+
+        // children with a higher z index are rendered on top and handle the event first
+        if matching_nodes.len() > 1 {
+            let order: BTreeMap<Entity, usize> = paint_order(ecm, root)
+                .into_iter()
+                .enumerate()
+                .map(|(index, node)| (node, index))
+                .collect();
+
+            matching_nodes.sort_by_key(|node| order.get(node).copied().unwrap_or_default());
+        }
+
         let mut handled = false;
         let mut disabled_parent = None;
 
@@ -291,6 +389,86 @@ impl EventStateSystem {
 
         update
     }
+
+    // Returns the cursor icon of the top most visible widget with a cursor property under the mouse.
+    fn cursor_icon(
+        &self,
+        mouse_position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> CursorIcon {
+        let mut cursor_icon = CursorIcon::default();
+        let root = ecm.entity_store().root();
+
+        for node in paint_order(ecm, root) {
+            let store = ecm.component_store();
+
+            let node_cursor_icon = match store.get::<CursorIcon>("cursor", node) {
+                Ok(node_cursor_icon) => node_cursor_icon,
+                Err(_) => continue,
+            };
+
+            if store.get::<Visibility>("visibility", node).ok() != Some(&Visibility::Visible) {
+                continue;
+            }
+
+            if let (Ok(position), Ok(bounds)) = (
+                store.get::<Point>("position", node),
+                store.get::<Rectangle>("bounds", node),
+            ) {
+                if Rectangle::new(position.x, position.y, bounds.width(), bounds.height())
+                    .contains((mouse_position.x, mouse_position.y))
+                {
+                    cursor_icon = node_cursor_icon.clone();
+                }
+            }
+        }
+
+        cursor_icon
+    }
+
+    // Applies the translations of the current language on the localized properties of the widget.
+    // Returns `true` if a property is changed.
+    fn localize(
+        &self,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let registry = self.registry.borrow();
+
+        let localization = match registry.try_get::<Localization>("localization") {
+            Some(localization) => localization,
+            None => return false,
+        };
+
+        let keys = match ecm
+            .component_store_mut()
+            .get_mut::<LocalizedProperties>("localized_properties", entity)
+        {
+            Ok(properties) if properties.language() != Some(localization.language()) => {
+                properties.set_language(localization.language());
+                properties.keys().clone()
+            }
+            _ => return false,
+        };
+
+        for (property, key) in keys {
+            let text = localization.text(&key);
+
+            if let Ok(value) = ecm
+                .component_store_mut()
+                .get_mut::<String16>(property.as_str(), entity)
+            {
+                *value = String16::from(text);
+            } else if let Ok(value) = ecm
+                .component_store_mut()
+                .get_mut::<String>(property.as_str(), entity)
+            {
+                *value = text;
+            }
+        }
+
+        true
+    }
 }
 
 impl System<Tree, StringComponentStore> for EventStateSystem {
@@ -301,13 +479,26 @@ impl System<Tree, StringComponentStore> for EventStateSystem {
         loop {
             {
                 let adapter = shell.adapter();
+                let window = adapter.root;
                 let mouse_position = adapter.mouse_position;
+                let mut mouse_moved = false;
+
+                // moves the events that are sent from other threads into the event queue
+                adapter.sent_events.dequeue_into(&mut adapter.event_queue);
+
                 for event in adapter.event_queue.into_iter() {
+                    mouse_moved = mouse_moved || event.downcast_ref::<MouseMoveEvent>().is_ok();
+
                     if let Ok(event) = event.downcast_ref::<SystemEvent>() {
                         match event {
                             SystemEvent::Quit => {
-                                shell.set_running(false);
-                                return;
+                                if self.close_allowed(window) {
+                                    shell.set_running(false);
+                                    return;
+                                }
+
+                                // the states that vetoed the request are updated
+                                update = true;
                             }
                         }
                     }
@@ -328,6 +519,15 @@ impl System<Tree, StringComponentStore> for EventStateSystem {
                         }
                     }
                 }
+
+                if update {
+                    self.remove_expired_handlers();
+                }
+
+                if mouse_moved {
+                    let cursor_icon = self.cursor_icon(mouse_position, ecm);
+                    shell.set_cursor_icon(cursor_icon);
+                }
             }
 
             shell.set_update(update);
@@ -341,6 +541,60 @@ impl System<Tree, StringComponentStore> for EventStateSystem {
                 .get::<Theme>("theme", root)
                 .unwrap()
                 .clone();
+
+            // run the closures that are enqueued by ui handles
+            let tasks = shell.adapter().ui_tasks.dequeue();
+
+            if !tasks.is_empty() {
+                shell.set_update(true);
+            }
+
+            for (entity, task) in tasks {
+                if !ecm.entity_store().children.contains_key(&entity) {
+                    continue;
+                }
+
+                let render_objects = &self.render_objects;
+                let layouts = &mut self.layouts.borrow_mut();
+                let handlers = &mut self.handlers.borrow_mut();
+                let registry = &mut self.registry.borrow_mut();
+                let new_states = &mut BTreeMap::new();
+
+                let mut ctx = Context::new(
+                    (entity, ecm),
+                    &mut shell,
+                    &theme,
+                    render_objects,
+                    layouts,
+                    handlers,
+                    &self.states,
+                    new_states,
+                );
+
+                task(&mut ctx);
+
+                let keys = ctx.new_states_keys();
+                drop(ctx);
+
+                for key in keys {
+                    let new_states = &mut BTreeMap::new();
+                    let mut ctx = Context::new(
+                        (key, ecm),
+                        &mut shell,
+                        &theme,
+                        render_objects,
+                        layouts,
+                        handlers,
+                        &self.states,
+                        new_states,
+                    );
+                    if let Some(state) = self.states.borrow_mut().get_mut(&key) {
+                        state.init(registry, &mut ctx);
+                        state.attached(registry, &mut ctx);
+                    }
+                }
+            }
+
             let mut current_node = root;
 
             loop {
@@ -393,12 +647,17 @@ impl System<Tree, StringComponentStore> for EventStateSystem {
                             );
                             if let Some(state) = self.states.borrow_mut().get_mut(&key) {
                                 state.init(registry, &mut ctx);
+                                state.attached(registry, &mut ctx);
                             }
 
                             drop(ctx);
                         }
                     }
                 }
+                if self.localize(current_node, ecm) {
+                    shell.set_update(true);
+                }
+
                 let mut it = ecm.entity_store().start_node(current_node).into_iter();
                 it.next();
 
@@ -409,6 +668,17 @@ impl System<Tree, StringComponentStore> for EventStateSystem {
                 }
             }
 
+            super::detached_widgets::remove_detached_widgets(
+                ecm,
+                &mut shell,
+                &theme,
+                &self.render_objects,
+                &self.layouts,
+                &self.handlers,
+                &self.states,
+                &self.registry,
+            );
+
             if shell.adapter().event_queue.is_empty() {
                 break;
             }
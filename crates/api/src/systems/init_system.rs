@@ -8,8 +8,8 @@ use crate::{css_engine::*, prelude::*, shell::WindowShell, tree::Tree};
 pub struct InitSystem {
     pub shell: Rc<RefCell<WindowShell<WindowAdapter>>>,
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
-    pub render_objects: Rc<RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>>,
-    pub layouts: Rc<RefCell<BTreeMap<Entity, Box<dyn Layout>>>>,
+    pub render_objects: Rc<RefCell<ArenaMap<dyn RenderObject>>>,
+    pub layouts: Rc<RefCell<ArenaMap<dyn Layout>>>,
     pub handlers: Rc<RefCell<EventHandlerMap>>,
     pub registry: Rc<RefCell<Registry>>,
 }
@@ -20,7 +20,7 @@ impl InitSystem {
         // Add css id to global id map.
         let id = if let Ok(selector) = store.get::<Selector>("selector", node) {
             if let Some(id) = &selector.id {
-                Some((node, id.clone()))
+                Some((node, id.to_string()))
             } else {
                 None
             }
@@ -90,6 +90,7 @@ impl System<Tree, StringComponentStore> for InitSystem {
 
                 if let Some(state) = self.states.borrow_mut().get_mut(&current_node) {
                     state.init(&mut *self.registry.borrow_mut(), &mut ctx);
+                    state.attached(&mut *self.registry.borrow_mut(), &mut ctx);
                 }
 
                 self.read_init_from_theme(&mut ctx);
@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
 use dces::prelude::{Entity, EntityComponentManager, System};
 
@@ -6,7 +6,7 @@ use crate::{css_engine::*, prelude::*, shell::WindowShell, tree::Tree, utils::*}
 
 /// The `LayoutSystem` builds per iteration the layout of the current ui. The layout parts are calulated by the layout objects of layout widgets.
 pub struct LayoutSystem {
-    pub layouts: Rc<RefCell<BTreeMap<Entity, Box<dyn Layout>>>>,
+    pub layouts: Rc<RefCell<ArenaMap<dyn Layout>>>,
     pub shell: Rc<RefCell<WindowShell<WindowAdapter>>>,
 }
 
@@ -20,6 +20,8 @@ impl System<Tree, StringComponentStore> for LayoutSystem {
         //     shell::log("\n------ Start layout update  ------\n".to_string());
         // }
 
+        next_layout_generation(ecm);
+
         let mut window_size = (0.0, 0.0);
         let root = ecm.entity_store().root();
 
@@ -51,8 +53,91 @@ impl System<Tree, StringComponentStore> for LayoutSystem {
             &theme,
         );
 
+        // the layout triggers are switched with the widths of this layout, switched properties and
+        // wrapped texts are measured with the width that is available after the arrange, if their
+        // size changes the parents are arranged again.
+        let switched = update_layout_triggers(ecm, root, window_size.0, &theme);
+
+        let dirty = self.layouts.borrow()[&root]
+            .measure(
+                self.shell.borrow_mut().render_context_2_d(),
+                root,
+                ecm,
+                &self.layouts.borrow(),
+                &theme,
+            )
+            .dirty();
+
+        if dirty || switched {
+            self.layouts.borrow()[&root].arrange(
+                self.shell.borrow_mut().render_context_2_d(),
+                window_size,
+                root,
+                ecm,
+                &self.layouts.borrow(),
+                &theme,
+            );
+        }
+
         // if self.debug_flag.get() {
         //     println!("\n------ End layout update   ------\n");
         // }
     }
 }
+
+// Updates the layout triggers of the given widget and its descendants with the width of the
+// window or of their parent. Returns `true` if a trigger is switched.
+fn update_layout_triggers(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    root: Entity,
+    window_width: f64,
+    theme: &ThemeValue,
+) -> bool {
+    let mut switched = false;
+    let mut widgets = vec![root];
+
+    while let Some(widget) = widgets.pop() {
+        widgets.extend(ecm.entity_store().children[&widget].iter().rev());
+
+        let mut layout_triggers = match ecm
+            .component_store()
+            .get::<Vec<LayoutTrigger>>("layout_triggers", widget)
+        {
+            Ok(layout_triggers) => layout_triggers.clone(),
+            Err(_) => continue,
+        };
+
+        let parent_width = ecm
+            .entity_store()
+            .parent
+            .get(&widget)
+            .copied()
+            .flatten()
+            .and_then(|parent| {
+                ecm.component_store()
+                    .get::<Rectangle>("bounds", parent)
+                    .ok()
+                    .map(|bounds| bounds.width())
+            })
+            .unwrap_or(window_width);
+
+        let mut container = WidgetContainer::new(widget, ecm, theme);
+        let mut widget_switched = false;
+
+        for layout_trigger in &mut layout_triggers {
+            let width = match layout_trigger.source() {
+                TriggerSource::Window => window_width,
+                TriggerSource::Parent => parent_width,
+            };
+
+            widget_switched = layout_trigger.update(width, &mut container) || widget_switched;
+        }
+
+        if widget_switched {
+            container.set("layout_triggers", layout_triggers);
+            switched = true;
+        }
+    }
+
+    switched
+}
@@ -1,12 +1,15 @@
 //! Contains all system used in OrbTk. Systems are meant as systems in OrbTks Entity Component System.
 //! The are used for event handling, layouting and drawing.
 
+pub use self::accessibility_system::*;
 pub use self::event_state_system::*;
 pub use self::init_system::*;
 pub use self::layout_system::*;
 pub use self::post_layout_state_system::*;
 pub use self::render_system::*;
 
+mod accessibility_system;
+mod detached_widgets;
 mod event_state_system;
 mod init_system;
 mod layout_system;
@@ -2,14 +2,19 @@ use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use dces::prelude::{Entity, EntityComponentManager, System};
 
-use crate::{css_engine::*, prelude::*, shell::WindowShell, tree::Tree};
+use crate::{
+    css_engine::*,
+    prelude::*,
+    shell::{ShellRequest, WindowShell},
+    tree::Tree,
+};
 
 /// The `PostLayoutStateSystem` calls the update_post_layout methods of widget states.
 pub struct PostLayoutStateSystem {
     pub shell: Rc<RefCell<WindowShell<WindowAdapter>>>,
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
-    pub render_objects: Rc<RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>>,
-    pub layouts: Rc<RefCell<BTreeMap<Entity, Box<dyn Layout>>>>,
+    pub render_objects: Rc<RefCell<ArenaMap<dyn RenderObject>>>,
+    pub layouts: Rc<RefCell<ArenaMap<dyn Layout>>>,
     pub handlers: Rc<RefCell<EventHandlerMap>>,
     pub registry: Rc<RefCell<Registry>>,
 }
@@ -29,36 +34,80 @@ impl System<Tree, StringComponentStore> for PostLayoutStateSystem {
             .unwrap()
             .clone();
 
-        let render_objects = &self.render_objects;
-        let layouts = &mut self.layouts.borrow_mut();
-        let handlers = &mut self.handlers.borrow_mut();
-        let new_states = &mut BTreeMap::new();
+        {
+            let render_objects = &self.render_objects;
+            let layouts = &mut self.layouts.borrow_mut();
+            let handlers = &mut self.handlers.borrow_mut();
+            let new_states = &mut BTreeMap::new();
 
-        let mut ctx = Context::new(
-            (root, ecm),
+            let mut ctx = Context::new(
+                (root, ecm),
+                window_shell,
+                &theme,
+                render_objects,
+                layouts,
+                handlers,
+                &self.states,
+                new_states,
+            );
+
+            for (node, state) in &mut *self.states.borrow_mut() {
+                ctx.entity = *node;
+
+                state.update_post_layout(&mut *self.registry.borrow_mut(), &mut ctx);
+
+                // Handle messages.
+                {
+                    // todo fix messages.
+                    // for (entity, messages) in ctx.messages().iter() {
+                    //     if let Some(state) = self.states.borrow().get(&entity) {
+                    //         ctx.entity = *entity;
+                    //         state.receive_messages(&mut ctx, &messages);
+                    //     }
+                    // }
+                }
+            }
+
+            drop(ctx);
+        }
+
+        super::detached_widgets::remove_detached_widgets(
+            ecm,
             window_shell,
             &theme,
-            render_objects,
-            layouts,
-            handlers,
+            &self.render_objects,
+            &self.layouts,
+            &self.handlers,
             &self.states,
-            new_states,
+            &self.registry,
         );
 
-        for (node, state) in &mut *self.states.borrow_mut() {
-            ctx.entity = *node;
+        // records the property changes of this frame if a state recorder is registered
+        if let Some(recorder) = self
+            .registry
+            .borrow_mut()
+            .try_get_mut::<StateRecorder>("state_recorder")
+        {
+            if recorder.run(ecm) {
+                let _ = window_shell.request_sender().send(ShellRequest::Update);
+            }
+        }
 
-            state.update_post_layout(&mut *self.registry.borrow_mut(), &mut ctx);
+        // widgets whose size is changed by the layout are notified in the next frame
+        window_shell
+            .adapter()
+            .event_queue
+            .append(&mut size_changed_events(ecm, &self.handlers.borrow()));
+
+        // events that are pushed after the event handling are handled in the next frame
+        if !window_shell.adapter().event_queue.is_empty() {
+            let _ = window_shell.request_sender().send(ShellRequest::Update);
+        }
 
-            // Handle messages.
-            {
-                // todo fix messages.
-                // for (entity, messages) in ctx.messages().iter() {
-                //     if let Some(state) = self.states.borrow().get(&entity) {
-                //         ctx.entity = *entity;
-                //         state.receive_messages(&mut ctx, &messages);
-                //     }
-                // }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(crash_reporter) = &window_shell.adapter().crash_reporter {
+                crash_reporter.record_frame(ecm);
             }
         }
     }
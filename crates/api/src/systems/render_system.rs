@@ -2,23 +2,206 @@ use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use dces::prelude::{Entity, EntityComponentManager, System};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::render::encode_png;
 use crate::{
     css_engine::*,
     prelude::*,
     shell::{WindowShell, CONSOLE},
     tree::Tree,
-    utils::Brush,
+    utils::{Brush, Color, Point, Rectangle},
 };
 
+// Draws the focus adorner around the focused widget on top of all widgets, if the widget and its
+// parents are visible.
+fn render_focus_adorner(
+    shell: &mut WindowShell<WindowAdapter>,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    theme: &Theme,
+) {
+    let store = ecm.component_store();
+    let root = ecm.entity_store().root();
+
+    let focused = match store
+        .get::<Global>("global", root)
+        .ok()
+        .and_then(|global| global.focused_widget)
+    {
+        Some(focused) => focused,
+        None => return,
+    };
+
+    let mut current = Some(focused);
+
+    while let Some(entity) = current {
+        if store.get::<Visibility>("visibility", entity).ok() != Some(&Visibility::Visible) {
+            return;
+        }
+
+        current = ecm.entity_store().parent.get(&entity).copied().flatten();
+    }
+
+    // the position is the rendered position of the widget in window coordinates
+    let (position, bounds) = match (
+        store.get::<Point>("position", focused),
+        store.get::<Rectangle>("bounds", focused),
+    ) {
+        (Ok(position), Ok(bounds)) => (*position, *bounds),
+        _ => return,
+    };
+
+    let selector = store
+        .get::<Selector>("selector", focused)
+        .map(|selector| selector.clone())
+        .unwrap_or_default();
+
+    if let Some(adorner) = FocusAdorner::from_theme(theme, &selector) {
+        adorner.render(
+            shell.render_context_2_d(),
+            Rectangle::new(position.x, position.y, bounds.width(), bounds.height()),
+        );
+    }
+}
+
 /// The `RenderSystem` iterates over all visual widgets and used its render objects to draw them on the screen.
 pub struct RenderSystem {
-    pub render_objects: Rc<RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>>,
+    pub render_objects: Rc<RefCell<ArenaMap<dyn RenderObject>>>,
     pub shell: Rc<RefCell<WindowShell<WindowAdapter>>>,
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
-    pub layouts: Rc<RefCell<BTreeMap<Entity, Box<dyn Layout>>>>,
+    pub layouts: Rc<RefCell<ArenaMap<dyn Layout>>>,
     pub handlers: Rc<RefCell<EventHandlerMap>>,
 }
 
+impl RenderSystem {
+    // Renders the widget of the export request offscreen and writes it into the file of the
+    // request.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn export(
+        &self,
+        request: &ExportRequest,
+        shell: &mut WindowShell<WindowAdapter>,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        theme: &Theme,
+    ) -> Result<(), String> {
+        let entity = request.entity.unwrap_or_else(|| ecm.entity_store().root());
+        let bounds = ecm
+            .component_store()
+            .get::<Rectangle>("bounds", entity)
+            .map(|bounds| *bounds)
+            .map_err(|_| format!("Could not export widget {}, it is not found.", entity.0))?;
+
+        // the widget is rendered at its position in the window, that is moved into the image
+        let parent = ecm.entity_store().parent.get(&entity).copied().flatten();
+        let parent_position = parent
+            .and_then(|parent| {
+                ecm.component_store()
+                    .get::<Point>("position", parent)
+                    .ok()
+                    .copied()
+            })
+            .unwrap_or_default();
+        let ((width, height), scale, offset) = request.transform(Rectangle::new(
+            parent_position.x + bounds.x(),
+            parent_position.y + bounds.y(),
+            bounds.width(),
+            bounds.height(),
+        ))?;
+
+        // layers ignore the transformation of the export, so cached widgets are rendered
+        // without their layers
+        let cached: Vec<Entity> = paint_order(ecm, entity)
+            .into_iter()
+            .filter(|widget| {
+                ecm.component_store()
+                    .get::<CacheMode>("cache_mode", *widget)
+                    .ok()
+                    == Some(&CacheMode::Texture)
+            })
+            .collect();
+
+        for widget in &cached {
+            if let Ok(cache_mode) = ecm
+                .component_store_mut()
+                .get_mut::<CacheMode>("cache_mode", *widget)
+            {
+                *cache_mode = CacheMode::None;
+            }
+        }
+
+        let mut render_context_2_d = shell
+            .render_context_2_d()
+            .offscreen(width as f64, height as f64);
+        std::mem::swap(shell.render_context_2_d(), &mut render_context_2_d);
+
+        shell.render_context_2_d().start();
+
+        if request.format == ExportFormat::Pdf {
+            shell.render_context_2_d().start_recording();
+        }
+
+        shell
+            .render_context_2_d()
+            .set_transform(scale, 0.0, 0.0, scale, offset.0, offset.1);
+        shell.render_context_2_d().begin_path();
+
+        let mut offsets = BTreeMap::new();
+
+        if let Some(parent) = parent {
+            offsets.insert(parent, (parent_position.x, parent_position.y));
+        }
+
+        if let Some(render_object) = self.render_objects.borrow().get(&entity) {
+            render_object.render(
+                shell,
+                entity,
+                ecm,
+                &self.render_objects,
+                &self.layouts,
+                &self.handlers,
+                &self.states,
+                theme,
+                &mut offsets,
+                false,
+            );
+        }
+
+        shell.render_context_2_d().finish();
+
+        let file = match request.format {
+            ExportFormat::Png => match shell.render_context_2_d().wait_data() {
+                Some(data) => encode_png(data, width, height),
+                None => Err("Could not render the export.".to_string()),
+            },
+            ExportFormat::Pdf => Ok(shell.render_context_2_d().finish_recording_pdf(
+                width as f64,
+                height as f64,
+                request.points_per_pixel(),
+            )),
+        };
+
+        std::mem::swap(shell.render_context_2_d(), &mut render_context_2_d);
+
+        for widget in cached {
+            if let Ok(cache_mode) = ecm
+                .component_store_mut()
+                .get_mut::<CacheMode>("cache_mode", widget)
+            {
+                *cache_mode = CacheMode::Texture;
+            }
+
+            invalidate_render_cache(ecm, widget);
+        }
+
+        std::fs::write(&request.path, file?).map_err(|error| {
+            format!(
+                "Could not write export {}: {}",
+                request.path.display(),
+                error
+            )
+        })
+    }
+}
+
 impl System<Tree, StringComponentStore> for RenderSystem {
     fn run(&self, ecm: &mut EntityComponentManager<Tree, StringComponentStore>) {
         if !self.shell.borrow().update()
@@ -55,7 +238,28 @@ impl System<Tree, StringComponentStore> for RenderSystem {
 
         CONSOLE.time("render");
 
+        // a window with a virtual resolution is scaled to the window size with letterbox bars
+        let virtual_resolution = ecm
+            .component_store()
+            .get::<VirtualResolution>("virtual_resolution", root)
+            .map(|r| *r)
+            .unwrap_or_default();
+        shell.adapter().virtual_resolution = virtual_resolution;
+
         shell.render_context_2_d().start();
+
+        if virtual_resolution.is_enabled() {
+            let (width, height) = shell.adapter().window_size;
+            let (scale, x, y) = virtual_resolution.transform(width, height);
+
+            shell
+                .render_context_2_d()
+                .clear(&Brush::from(Color::rgb(0, 0, 0)));
+            shell
+                .render_context_2_d()
+                .set_transform(scale, 0.0, 0.0, scale, x, y);
+        }
+
         shell.render_context_2_d().begin_path();
         self.render_objects.borrow()[&root].render(
             &mut shell,
@@ -69,8 +273,28 @@ impl System<Tree, StringComponentStore> for RenderSystem {
             &mut offsets,
             debug,
         );
+
+        render_focus_adorner(&mut shell, ecm, &theme);
+
+        if virtual_resolution.is_enabled() {
+            shell
+                .render_context_2_d()
+                .set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+        }
+
         shell.render_context_2_d().finish();
 
+        // the exports are rendered after the frame with the same layout
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let exports: Vec<ExportRequest> = shell.adapter().exports.drain(..).collect();
+
+            for request in exports {
+                let result = self.export(&request, &mut shell, ecm, &theme);
+                request.finish(result);
+            }
+        }
+
         //  print_tree(root, 0, ecm);
     }
 }
@@ -12,14 +12,32 @@ pub type WidgetBuildContext = Option<Box<dyn Fn(&mut BuildContext, usize) -> Ent
 #[derive(Constructor)]
 pub struct BuildContext<'a> {
     ecm: &'a mut EntityComponentManager<Tree, StringComponentStore>,
-    render_objects: &'a RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>,
-    layouts: &'a mut BTreeMap<Entity, Box<dyn Layout>>,
+    render_objects: &'a RefCell<ArenaMap<dyn RenderObject>>,
+    layouts: &'a mut ArenaMap<dyn Layout>,
     handlers: &'a mut EventHandlerMap,
     states: &'a mut BTreeMap<Entity, Box<dyn State>>,
     theme: &'a ThemeValue,
+    templates: Rc<TemplateOverrides>,
 }
 
 impl<'a> BuildContext<'a> {
+    /// Applies the template of the given widget. The template registered for the widget type with
+    /// `Application::template` is used instead of the default template if it exists.
+    pub fn apply_template<W: Template + 'static>(&mut self, widget: W, id: Entity) -> W {
+        if let Some(template) = self.templates.get::<W>() {
+            if let Some(template) = template.downcast_ref::<Box<TemplateFn<W>>>() {
+                return template(widget, id, self);
+            }
+        }
+
+        widget.template(id, self)
+    }
+
+    /// Returns the template overrides of the application.
+    pub fn templates(&self) -> Rc<TemplateOverrides> {
+        self.templates.clone()
+    }
+
     /// Returns a specific widget.
     pub fn get_widget(&mut self, entity: Entity) -> WidgetContainer<'_> {
         WidgetContainer::new(entity, self.ecm, self.theme)
@@ -117,9 +135,7 @@ impl<'a> BuildContext<'a> {
 
     /// Registers a render object with a widget.
     pub fn register_render_object(&mut self, widget: Entity, render_object: Box<dyn RenderObject>) {
-        self.render_objects
-            .borrow_mut()
-            .insert(widget, render_object);
+        insert_render_object(&mut self.render_objects.borrow_mut(), widget, render_object);
     }
 
     /// Registers a event handler with a widget.
@@ -133,6 +149,6 @@ impl<'a> BuildContext<'a> {
 
     /// Registers a layout object with a widget.
     pub fn register_layout(&mut self, widget: Entity, layout: Box<dyn Layout>) {
-        self.layouts.insert(widget, layout);
+        insert_layout(self.layouts, widget, layout);
     }
 }
@@ -1,7 +1,9 @@
-use std::{cell::RefCell, collections::BTreeMap, sync::mpsc::Sender};
+use std::{
+    any::Any, cell::RefCell, collections::BTreeMap, rc::Rc, sync::mpsc::Sender, time::Duration,
+};
 
 #[cfg(not(target_os = "redox"))]
-use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+use raw_window_handle::RawWindowHandle;
 
 use dces::prelude::{Entity, EntityComponentManager};
 
@@ -9,8 +11,9 @@ use crate::{
     css_engine::*,
     prelude::*,
     render::*,
-    shell::{ShellRequest, WindowShell},
+    shell::{monitor_of, Monitor, ShellRequest, WindowShell},
     tree::Tree,
+    utils::{FlowDirection, Point, Rectangle},
 };
 
 use super::{MessageBox, WidgetContainer};
@@ -21,8 +24,8 @@ pub struct Context<'a> {
     window_shell: &'a mut WindowShell<WindowAdapter>,
     pub entity: Entity,
     pub theme: &'a ThemeValue,
-    render_objects: &'a RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>,
-    layouts: &'a mut BTreeMap<Entity, Box<dyn Layout>>,
+    render_objects: &'a RefCell<ArenaMap<dyn RenderObject>>,
+    layouts: &'a mut ArenaMap<dyn Layout>,
     handlers: &'a mut EventHandlerMap,
     states: &'a RefCell<BTreeMap<Entity, Box<dyn State>>>,
     new_states: &'a mut BTreeMap<Entity, Box<dyn State>>,
@@ -34,13 +37,6 @@ impl<'a> Drop for Context<'a> {
     }
 }
 
-#[cfg(not(target_os = "redox"))]
-unsafe impl<'a> HasRawWindowHandle for Context<'a> {
-    fn raw_window_handle(&self) -> RawWindowHandle {
-        self.window_shell.raw_window_handle()
-    }
-}
-
 impl<'a> Context<'a> {
     /// Creates a new container.
     pub fn new(
@@ -50,8 +46,8 @@ impl<'a> Context<'a> {
         ),
         window_shell: &'a mut WindowShell<WindowAdapter>,
         theme: &'a ThemeValue,
-        render_objects: &'a RefCell<BTreeMap<Entity, Box<dyn RenderObject>>>,
-        layouts: &'a mut BTreeMap<Entity, Box<dyn Layout>>,
+        render_objects: &'a RefCell<ArenaMap<dyn RenderObject>>,
+        layouts: &'a mut ArenaMap<dyn Layout>,
         handlers: &'a mut EventHandlerMap,
         states: &'a RefCell<BTreeMap<Entity, Box<dyn State>>>,
         new_states: &'a mut BTreeMap<Entity, Box<dyn State>>,
@@ -69,6 +65,13 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// Returns the handle of the native window or `None` if the window backend has no native
+    /// window, e.g. an embedded backend.
+    #[cfg(not(target_os = "redox"))]
+    pub fn try_raw_window_handle(&self) -> Option<RawWindowHandle> {
+        self.window_shell.try_raw_window_handle()
+    }
+
     // -- Widgets --
 
     /// Returns a specific widget.
@@ -87,6 +90,16 @@ impl<'a> Context<'a> {
         self.get_widget(root)
     }
 
+    /// Returns the settings service to save and load data, e.g. the window size or the selected
+    /// theme.
+    pub fn settings(&self) -> &Settings {
+        let root = self.ecm.entity_store().root();
+        self.ecm
+            .component_store()
+            .get::<Settings>("settings", root)
+            .expect("Context.settings: Settings service is not registered.")
+    }
+
     /// Returns a child of the widget of the current state referenced by css `id`.
     /// If the no id is defined its panics.
     pub fn child<'b>(&mut self, id: impl Into<&'b str>) -> WidgetContainer<'_> {
@@ -209,6 +222,7 @@ impl<'a> Context<'a> {
             self.handlers,
             self.new_states,
             self.theme,
+            self.window_shell.adapter().templates.clone(),
         )
     }
 
@@ -241,7 +255,7 @@ impl<'a> Context<'a> {
     /// will be returned.
     pub fn append_child_entity_to_overlay(&mut self, child: Entity) -> Result<(), String> {
         if let Some(overlay) = self.ecm.entity_store().overlay {
-            self.append_child_entity_to(overlay.into(), child);
+            self.append_child_entity_to(child, overlay.into());
             return Ok(());
         }
 
@@ -255,7 +269,29 @@ impl<'a> Context<'a> {
 
     /// Appends a child widget by entity to the current widget.
     pub fn append_child_entity(&mut self, child: Entity) {
-        self.append_child_entity_to(self.entity, child);
+        self.append_child_entity_to(child, self.entity);
+    }
+
+    /// Moves a child entity with its children from its current parent to the given parent at the
+    /// given index. The child is not destroyed. The style of the moved widgets is resolved again,
+    /// because it could depend on their new ancestors.
+    pub fn move_child_entity_to(
+        &mut self,
+        child: Entity,
+        parent: Entity,
+        index: usize,
+    ) -> Result<(), String> {
+        self.ecm
+            .entity_store_mut()
+            .move_child(parent, child, index)
+            .map_err(|_| {
+                "Context.move_child_entity_to: Could not find child or parent.".to_string()
+            })?;
+
+        let theme = self.theme;
+        self.update_style_of(child, theme);
+
+        Ok(())
     }
 
     /// Removes a child from the current widget. If the given entity is not a child
@@ -279,7 +315,7 @@ impl<'a> Context<'a> {
     /// of the given parent nothing will happen.
     pub fn remove_child_from(&mut self, child: Entity, parent: Entity) {
         if self.ecm.entity_store().children[&parent].contains(&child) {
-            self.ecm.remove_entity(child);
+            self.detach_child(child);
         }
     }
 
@@ -292,16 +328,71 @@ impl<'a> Context<'a> {
     pub fn clear_children_of(&mut self, parent: Entity) {
         while !self.ecm.entity_store().children[&parent].is_empty() {
             let child = self.ecm.entity_store().children[&parent][0];
-
-            self.ecm.remove_entity(child);
+            self.detach_child(child);
         }
     }
 
+    // Takes the child out of the tree. The `detached` hooks of the states of the child and its
+    // descendants are called and the entities are removed after the current state is updated.
+    fn detach_child(&mut self, child: Entity) {
+        #[cfg(not(target_arch = "wasm32"))]
+        remove_render_caches(self.window_shell.render_context_2_d(), self.ecm, child);
+        let _ = self.ecm.entity_store_mut().detach_child(child);
+        self.window_shell.adapter().detached_widgets.push(child);
+    }
+
     // -- Manipulation --
 
+    // -- Event handlers --
+
+    /// Registers an event handler with the given widget and returns it, so it could be removed
+    /// later with `remove_handler`.
+    pub fn register_handler(
+        &mut self,
+        widget: Entity,
+        handler: impl Into<Rc<dyn EventHandler>>,
+    ) -> Rc<dyn EventHandler> {
+        let handler = handler.into();
+        self.handlers
+            .entry(widget)
+            .or_insert_with(Vec::new)
+            .push(handler.clone());
+        handler
+    }
+
+    /// Registers an event handler with the given widget that is removed after it has handled its
+    /// first event.
+    pub fn register_handler_once(
+        &mut self,
+        widget: Entity,
+        handler: impl Into<Rc<dyn EventHandler>>,
+    ) -> Rc<dyn EventHandler> {
+        self.register_handler(widget, OnceHandler::new(handler))
+    }
+
+    /// Removes the given event handler from the given widget. Returns `false` if the handler is
+    /// not registered with the widget.
+    pub fn remove_handler(&mut self, widget: Entity, handler: &Rc<dyn EventHandler>) -> bool {
+        if let Some(handlers) = self.handlers.get_mut(&widget) {
+            let len = handlers.len();
+            handlers.retain(|h| !is_same_handler(h, handler));
+            return handlers.len() != len;
+        }
+
+        false
+    }
+
+    /// Removes all event handlers of the given widget.
+    pub fn clear_handlers(&mut self, widget: Entity) {
+        self.handlers.remove(&widget);
+    }
+
+    // -- Event handlers --
+
     /// Returns the entity id of an child by the given name.
     pub fn entity_of_child<'b>(&mut self, id: impl Into<&'b str>) -> Option<Entity> {
-        let id = id.into();
+        // an id that is not interned is not used by any widget
+        let id = Symbol::lookup(id.into())?;
 
         let mut current_node = self.entity;
 
@@ -311,10 +402,8 @@ impl<'a> Context<'a> {
                 .component_store()
                 .get::<Selector>("selector", current_node)
             {
-                if let Some(child_id) = &selector.id {
-                    if child_id == id {
-                        return Some(current_node);
-                    }
+                if selector.id == Some(id) {
+                    return Some(current_node);
                 }
             }
 
@@ -331,6 +420,44 @@ impl<'a> Context<'a> {
         None
     }
 
+    /// Returns the entity of the widget with the given automation id. The search starts at the
+    /// current widget and walks the tree like `entity_of_child`.
+    pub fn entity_of_automation_id(&mut self, automation_id: &str) -> Option<Entity> {
+        let store = self.ecm.component_store();
+
+        self.ecm
+            .entity_store()
+            .start_node(self.entity)
+            .into_iter()
+            .find(|entity| {
+                store
+                    .get::<String>("automation_id", *entity)
+                    .map_or(false, |id| id == automation_id)
+            })
+    }
+
+    /// Returns the widgets in the subtree of the given widget, e.g. a form, whose `is_valid`
+    /// property is `false`. Widgets that share the property of another widget are skipped.
+    pub fn invalid_widgets(&mut self, form: Entity) -> Vec<Entity> {
+        let store = self.ecm.component_store();
+
+        self.ecm
+            .entity_store()
+            .start_node(form)
+            .into_iter()
+            .filter(|entity| {
+                store.is_origin::<bool>("is_valid", *entity)
+                    && store.get::<bool>("is_valid", *entity).map_or(false, |v| !v)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if all widgets in the subtree of the given widget are valid, see
+    /// `invalid_widgets`.
+    pub fn is_valid(&mut self, form: Entity) -> bool {
+        self.invalid_widgets(form).is_empty()
+    }
+
     /// Returns the entity of the parent referenced by css `element`.
     /// If the no id is defined None will returned.
     pub fn parent_entity_by_element<'b>(&mut self, element: impl Into<&'b str>) -> Option<Entity> {
@@ -366,6 +493,32 @@ impl<'a> Context<'a> {
         self.ecm.entity_store().parent[&self.entity]
     }
 
+    /// Returns the bounds of the given widget after the last layout pass, its position is in
+    /// window coordinates, e.g. to place a popup next to the widget. Returns `None` if the widget
+    /// does not exist.
+    pub fn bounds_of(&self, entity: Entity) -> Option<Rectangle> {
+        crate::layout::window_bounds(self.ecm, entity)
+    }
+
+    /// Returns the widgets under the given point in window coordinates, the top most widget
+    /// first, e.g. to find the target of a drag and drop operation.
+    pub fn hit_test(&self, point: impl Into<Point>) -> Vec<Entity> {
+        crate::event::hit_test(self.ecm, point.into())
+    }
+
+    /// Renders the layer of the given widget with `CacheMode::Texture` and of its cached parents
+    /// again in the next frame. Needed if a property of the subtree is changed without the
+    /// widget container, e.g. by a shared property of another widget.
+    pub fn invalidate_cache(&mut self, entity: Entity) {
+        invalidate_render_cache(self.ecm, entity);
+    }
+
+    /// Returns the flow direction of the current widget, that is inherited from its parents if
+    /// it is not set.
+    pub fn flow_direction(&self) -> FlowDirection {
+        crate::layout::flow_direction(self.ecm, self.entity)
+    }
+
     /// Returns the child index of the current entity.
     pub fn index_as_child(&mut self, entity: Entity) -> Option<usize> {
         if let Some(parent) = self.ecm.entity_store().parent[&entity] {
@@ -438,6 +591,57 @@ impl<'a> Context<'a> {
             .register_event_with_strategy(event, strategy, entity);
     }
 
+    // -- Theme --
+
+    // Marks the selectors of the given widget and its descendants as dirty and resolves their
+    // style with the given theme.
+    fn update_style_of(&mut self, entity: Entity, theme: &ThemeValue) {
+        let entities: Vec<Entity> = self
+            .ecm
+            .entity_store()
+            .start_node(entity)
+            .into_iter()
+            .collect();
+
+        for entity in entities {
+            if let Ok(selector) = self
+                .ecm
+                .component_store_mut()
+                .get_mut::<Selector>("selector", entity)
+            {
+                selector.set_dirty(true);
+            } else {
+                continue;
+            }
+
+            WidgetContainer::new(entity, self.ecm, theme).update_properties_by_theme();
+        }
+    }
+
+    /// Replaces the theme of the window and updates the properties of all widgets with the new
+    /// theme, e.g. to apply the changes of a theme editor live. The `theme` field of the current
+    /// context still references the previous theme.
+    pub fn switch_theme(&mut self, theme: ThemeValue) {
+        let root = self.ecm.entity_store().root();
+
+        self.update_style_of(root, &theme);
+
+        if let Ok(window_theme) = self
+            .ecm
+            .component_store_mut()
+            .get_mut::<ThemeValue>("theme", root)
+        {
+            *window_theme = theme;
+        }
+
+        let _ = self
+            .window_shell
+            .request_sender()
+            .send(ShellRequest::Update);
+    }
+
+    // -- Theme --
+
     /// Returns a mutable reference of the 2d render ctx.
     pub fn render_context_2_d(&mut self) -> &mut RenderContext2D {
         self.window_shell.render_context_2_d()
@@ -448,6 +652,234 @@ impl<'a> Context<'a> {
         self.window_shell.request_sender()
     }
 
+    /// Requests a new frame of the window, e.g. for the next step of an animation. Needed by
+    /// states that change the ui without an input event if the window renders on demand.
+    pub fn request_update(&self) {
+        let _ = self
+            .window_shell
+            .request_sender()
+            .send(ShellRequest::Update);
+    }
+
+    /// Shows a toast notification with the given text in the corner of the window, that is
+    /// removed after the given duration. The toasts are shown by the `ToastHost` of the window.
+    pub fn show_toast(&mut self, text: impl Into<String>, duration: Duration) {
+        self.window()
+            .get_mut::<Global>("global")
+            .toasts
+            .push(ToastMessage::new(text, duration));
+        self.request_update();
+    }
+
+    /// Pushes the screen with the given name onto the navigation stack of the `NavigationView`
+    /// of the window. Unknown screens are ignored.
+    pub fn navigate(&mut self, screen: impl Into<String>) {
+        self.window()
+            .get_mut::<Global>("global")
+            .navigation_requests
+            .push(NavigationRequest::Navigate(screen.into()));
+        self.request_update();
+    }
+
+    /// Pops the current screen of the `NavigationView` of the window and shows the screen below
+    /// it. Nothing happens on the first screen.
+    pub fn navigate_back(&mut self) {
+        self.window()
+            .get_mut::<Global>("global")
+            .navigation_requests
+            .push(NavigationRequest::Back);
+        self.request_update();
+    }
+
+    /// Shows the widget that is built by the given builder modally over the window. The widgets
+    /// below the dialog do not get any input until it is closed and the focus is restored
+    /// afterwards. The callback is called on the ui thread with the context of the dialog host
+    /// and the result of `close_dialog`, or `None` if the dialog is canceled, e.g. by `Escape`,
+    /// or the result has an other type.
+    pub fn show_dialog<R, B, F>(&mut self, builder: B, on_close: F)
+    where
+        R: Any,
+        B: FnOnce(&mut BuildContext) -> Entity + 'static,
+        F: FnOnce(&mut Context<'_>, Option<R>) + 'static,
+    {
+        let dialog = DialogRequest::new(builder, move |ctx, result| {
+            on_close(
+                ctx,
+                result
+                    .and_then(|result| result.downcast::<R>().ok())
+                    .map(|result| *result),
+            )
+        });
+
+        self.window()
+            .get_mut::<Global>("global")
+            .dialogs
+            .push(Rc::new(dialog));
+        self.request_update();
+    }
+
+    /// Shows a modal dialog like `show_dialog` and returns a future that resolves with its
+    /// result after the dialog is closed. The future could be awaited e.g. inside of an async
+    /// task.
+    pub fn show_dialog_async<R, B>(&mut self, builder: B) -> DialogFuture<R>
+    where
+        R: Any + Send,
+        B: FnOnce(&mut BuildContext) -> Entity + 'static,
+    {
+        let (future, completer) = DialogFuture::new();
+        self.show_dialog(builder, move |_, result| completer.complete(result));
+        future
+    }
+
+    /// Closes the top most dialog with the given result.
+    pub fn close_dialog<R: Any>(&mut self, result: R) {
+        self.close_top_dialog(Some(Box::new(result)));
+    }
+
+    /// Closes the top most dialog without result.
+    pub fn cancel_dialog(&mut self) {
+        self.close_top_dialog(None);
+    }
+
+    fn close_top_dialog(&mut self, result: Option<Box<dyn Any>>) {
+        let dialog = self
+            .window()
+            .get::<Global>("global")
+            .dialogs
+            .iter()
+            .rev()
+            .find(|dialog| !dialog.is_closed())
+            .cloned();
+
+        if let Some(dialog) = dialog {
+            dialog.close(result);
+            self.request_update();
+        }
+    }
+
+    /// Exports the rendered widgets of the window or of the widget of the request into a PNG
+    /// image or PDF document. The widgets are rendered offscreen after the next frame, the result
+    /// is passed to the callback of the request.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export(&mut self, request: ExportRequest) {
+        self.window_shell.adapter().exports.push(request);
+        self.request_update();
+    }
+
+    /// Returns the last position of the mouse inside of the window.
+    pub fn mouse_position(&mut self) -> Point {
+        self.window_shell.adapter().mouse_position
+    }
+
+    /// Closes the window without asking the close requested handlers, e.g. after the user
+    /// confirmed to discard unsaved work.
+    pub fn close_window(&mut self) {
+        self.window_shell.set_running(false);
+    }
+
+    /// Shows or hides the window, e.g. of an application that lives in the system tray. The
+    /// desktop backend supports it on X11 and Windows.
+    pub fn set_window_visible(&mut self, visible: bool) {
+        self.window_shell.set_visible(visible);
+    }
+
+    /// Sets the icon of the window. The desktop backend supports it on X11 and Windows.
+    pub fn set_window_icon(&mut self, icon: &Image) {
+        self.window_shell.set_icon(icon);
+    }
+
+    /// Sets a badge of the window, e.g. an unread count, or removes it with `None`. Only the web
+    /// backend shows the badge, in front of the title of the page.
+    pub fn set_window_badge(&mut self, badge: Option<&str>) {
+        self.window_shell.set_badge(badge);
+    }
+
+    /// Keeps the window above all other windows, e.g. a tool palette or a notification. Only
+    /// supported by some platforms.
+    pub fn set_window_always_on_top(&mut self, always_on_top: bool) {
+        self.window_shell.set_always_on_top(always_on_top);
+    }
+
+    /// Sets the opacity of the whole window from `0.0` to `1.0`, e.g. of an overlay. The desktop
+    /// backend supports it on Windows and on X11 with a compositor.
+    pub fn set_window_opacity(&mut self, opacity: f64) {
+        self.window_shell.set_opacity(opacity.clamp(0.0, 1.0));
+    }
+
+    /// Moves the window by the given offset on the screen, e.g. while a custom title bar of a
+    /// borderless window is dragged.
+    pub fn move_window_by(&mut self, delta_x: f64, delta_y: f64) {
+        self.window_shell.move_by(delta_x, delta_y);
+    }
+
+    /// Resizes the window to the given size, e.g. with the resize grip of a borderless window.
+    /// Does nothing if `can_resize_window` returns `false`.
+    pub fn resize_window_to(&mut self, width: f64, height: f64) {
+        self.window_shell.resize_to(width, height);
+    }
+
+    /// Returns `true` if the backend could resize the window. The desktop backend supports it on
+    /// X11 and Windows.
+    pub fn can_resize_window(&self) -> bool {
+        self.window_shell.can_resize()
+    }
+
+    /// Moves the window to the given position on the screen, e.g. to center a dialog with
+    /// `Monitor::center`. Does nothing if the backend doesn't know the window position.
+    pub fn move_window_to(&mut self, x: f64, y: f64) {
+        if let Some((window_x, window_y)) = self.window_shell.position() {
+            self.window_shell.move_by(x - window_x, y - window_y);
+        }
+    }
+
+    /// Returns the monitors of the system with their geometry, work area and scale factor. Is
+    /// empty if the backend could not query the monitors, the desktop backend supports it on X11
+    /// and Windows.
+    pub fn monitors(&self) -> Vec<Monitor> {
+        self.window_shell.monitors()
+    }
+
+    /// Returns the monitor that contains the largest part of the window, e.g. to center a dialog
+    /// on the monitor of its parent window or to restore a saved position with `Monitor::fit`.
+    pub fn window_monitor(&mut self) -> Option<Monitor> {
+        let monitors = self.monitors();
+        let (x, y) = self.window_shell.position().unwrap_or_default();
+        let size = self.window().get::<Rectangle>("bounds").size();
+
+        monitor_of(&monitors, &Rectangle::new(x, y, size.0, size.1)).cloned()
+    }
+
+    /// Returns the shared resource of the type `T` that is registered with
+    /// `Application::resource` or `insert_resource`.
+    pub fn resource<T: Any>(&mut self) -> Option<Rc<T>> {
+        self.window_shell.adapter().resources.get::<T>()
+    }
+
+    /// Registers a resource that is shared by the states of all windows of the application. A
+    /// previous resource of the same type is replaced.
+    pub fn insert_resource<T: Any>(&mut self, resource: T) {
+        self.window_shell.adapter().resources.insert(resource);
+    }
+
+    /// Returns a handle that could be used from other threads to run closures with the context of
+    /// the current widget on the ui thread.
+    pub fn ui_handle(&mut self) -> UiHandle {
+        UiHandle::new(
+            self.entity,
+            self.window_shell.adapter().ui_tasks.sender(),
+            self.window_shell.request_sender(),
+        )
+    }
+
+    /// Returns a sender that could be moved to other threads to send events to the current widget.
+    pub fn event_sender(&mut self) -> EventSender {
+        EventSender::new(
+            self.entity,
+            self.window_shell.adapter().sent_events.sender(),
+            self.window_shell.request_sender(),
+        )
+    }
+
     /// Returns a keys collection of new added states.
     pub fn new_states_keys(&self) -> Vec<Entity> {
         self.new_states.keys().cloned().collect()
@@ -2,7 +2,10 @@ use std::rc::Rc;
 
 use dces::prelude::Entity;
 
-use crate::{css_engine::*, event::EventHandler};
+use crate::{
+    css_engine::*,
+    event::{EventHandler, OnceHandler},
+};
 
 pub use self::build_context::*;
 pub use self::context::*;
@@ -25,7 +28,7 @@ mod widget_container;
 /// Adds the given `pseudo_class` to the css selector of the given `widget`.
 pub fn add_selector_to_widget(pseudo_class: &str, widget: &mut WidgetContainer<'_>) {
     if let Some(selector) = widget.try_get_mut::<Selector>("selector") {
-        selector.pseudo_classes.insert(String::from(pseudo_class));
+        selector.add_pseudo_class(pseudo_class);
         selector.set_dirty(true);
     }
 }
@@ -33,7 +36,7 @@ pub fn add_selector_to_widget(pseudo_class: &str, widget: &mut WidgetContainer<'
 /// Removes the given `pseudo_class` from the css selector of the given `widget`.
 pub fn remove_selector_from_widget(pseudo_class: &str, widget: &mut WidgetContainer<'_>) {
     if let Some(selector) = widget.try_get_mut::<Selector>("selector") {
-        selector.pseudo_classes.remove(pseudo_class);
+        selector.remove_pseudo_class(pseudo_class);
         selector.set_dirty(true);
     }
 }
@@ -61,6 +64,11 @@ pub trait Widget: Template {
     /// Inerts a new event handler.
     fn insert_handler(self, handler: impl Into<Rc<dyn EventHandler>>) -> Self;
 
+    /// Inserts an event handler that is removed after it has handled its first event.
+    fn insert_handler_once(self, handler: impl Into<Rc<dyn EventHandler>>) -> Self {
+        self.insert_handler(OnceHandler::new(handler))
+    }
+
     /// Appends a child ot the widget.
     fn child(self, child: Entity) -> Self;
 }
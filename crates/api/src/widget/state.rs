@@ -14,6 +14,16 @@ pub trait State: AsAny {
     /// Init is used for initial setup.
     fn init(&mut self, _: &mut Registry, _: &mut Context<'_>) {}
 
+    /// Called after `init` when the widget is attached to the live tree, e.g. to subscribe to
+    /// services of the registry.
+    fn attached(&mut self, _: &mut Registry, _: &mut Context<'_>) {}
+
+    /// Called when the widget or one of its ancestors is removed from the tree, before the widget
+    /// and its state are dropped. Used to release the resources that are acquired in `attached`.
+    ///
+    /// The children of the widget are detached before the widget itself.
+    fn detached(&mut self, _: &mut Registry, _: &mut Context<'_>) {}
+
     /// Updates the state for the given `ctx`.
     ///
     /// This update method is called before layout is calculated.
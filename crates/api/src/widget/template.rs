@@ -1,9 +1,18 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    rc::Rc,
+};
+
 use dces::prelude::Entity;
 
 use crate::prelude::*;
 
 use super::BuildContext;
 
+/// Function that replaces the template of a widget type.
+pub type TemplateFn<W> = dyn Fn(W, Entity, &mut BuildContext) -> W;
+
 /// The `Template` trait provides the method for the widget template creation.
 pub trait Template: Sized {
     /// Creates the template of the widget and returns it.
@@ -19,3 +28,75 @@ pub trait Template: Sized {
         Box::new(GridLayout::new())
     }
 }
+
+/// Stores the templates that replace the default templates of widget types, e.g. to give every
+/// `Button` of an application a custom visual tree. Templates are registered with
+/// `Application::template`.
+#[derive(Clone, Default)]
+pub struct TemplateOverrides {
+    templates: HashMap<TypeId, Rc<dyn Any>>,
+}
+
+impl TemplateOverrides {
+    /// Creates an empty set of template overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the template of the widget type `W`. A previous template of the type is replaced.
+    pub fn register<W: Template + 'static>(
+        &mut self,
+        template: impl Fn(W, Entity, &mut BuildContext) -> W + 'static,
+    ) {
+        let template: Box<TemplateFn<W>> = Box::new(template);
+        self.templates
+            .insert(TypeId::of::<W>(), Rc::new(template) as Rc<dyn Any>);
+    }
+
+    /// Returns `true` if a template is registered for the widget type `W`.
+    pub fn contains<W: Template + 'static>(&self) -> bool {
+        self.templates.contains_key(&TypeId::of::<W>())
+    }
+
+    /// Returns the number of widget types with a registered template.
+    pub fn len(&self) -> usize {
+        self.templates.len()
+    }
+
+    /// Returns `true` if no template is registered.
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+
+    // Returns the registered template of the widget type `W`.
+    pub(crate) fn get<W: Template + 'static>(&self) -> Option<Rc<dyn Any>> {
+        self.templates.get(&TypeId::of::<W>()).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct First;
+
+    impl Template for First {}
+
+    struct Second;
+
+    impl Template for Second {}
+
+    #[test]
+    fn test_register() {
+        let mut templates = TemplateOverrides::new();
+        assert!(templates.is_empty());
+
+        templates.register(|first: First, _, _| first);
+        assert!(templates.contains::<First>());
+        assert!(!templates.contains::<Second>());
+
+        templates.register(|first: First, _, _| first);
+        assert_eq!(templates.len(), 1);
+        assert!(templates.get::<First>().is_some());
+    }
+}
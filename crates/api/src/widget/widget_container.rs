@@ -3,7 +3,7 @@ use std::any::TypeId;
 use crate::{
     css_engine::*,
     prelude::*,
-    utils::{Brush, String16, Thickness},
+    utils::{Brush, CornerRadius, String16, Thickness},
 };
 
 use dces::prelude::{Component, Entity, EntityComponentManager};
@@ -29,6 +29,11 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
+    /// Returns the entity of the widget.
+    pub fn entity(&self) -> Entity {
+        self.current_node
+    }
+
     /// Gets the property.
     ///
     /// # Panics
@@ -50,7 +55,8 @@ impl<'a> WidgetContainer<'a> {
         );
     }
 
-    /// Gets a mutable reference of the property of type `P`.
+    /// Gets a mutable reference of the property of type `P`. Invalidates the render caches of
+    /// the widget and its parents.
     ///
     /// # Panics
     ///
@@ -59,6 +65,8 @@ impl<'a> WidgetContainer<'a> {
     where
         P: Clone + Component,
     {
+        invalidate_render_cache(self.ecm, self.current_node);
+
         if let Ok(property) = self
             .ecm
             .component_store_mut()
@@ -122,7 +130,8 @@ impl<'a> WidgetContainer<'a> {
         None
     }
 
-    /// Sets the property of type `P`.
+    /// Sets the property of type `P`. Invalidates the render caches of the widget and its
+    /// parents.
     ///
     /// # Panics
     ///
@@ -131,6 +140,8 @@ impl<'a> WidgetContainer<'a> {
     where
         P: Component + Clone,
     {
+        invalidate_render_cache(self.ecm, self.current_node);
+
         if let Ok(property) = self
             .ecm
             .component_store_mut()
@@ -170,6 +181,8 @@ impl<'a> WidgetContainer<'a> {
     /// Returns a mutable reference of a property of type `P` from the given widget entity. If the entity does
     /// not exists or it doesn't have a component of type `P` `None` will be returned.
     pub fn try_get_mut<P: Component>(&mut self, key: &str) -> Option<&mut P> {
+        invalidate_render_cache(self.ecm, self.current_node);
+
         self.ecm
             .component_store_mut()
             .get_mut::<P>(key, self.current_node)
@@ -185,56 +198,98 @@ impl<'a> WidgetContainer<'a> {
         false
     }
 
+    /// Adds the given css class to the selector of the widget and updates the properties by the
+    /// theme, e.g. to style the widget with a `.warning` rule.
+    pub fn add_class(&mut self, class: &str) {
+        let added = match self.try_get_mut::<Selector>("selector") {
+            Some(selector) => selector.add_class(class),
+            None => false,
+        };
+
+        if added {
+            self.update_properties_by_theme();
+        }
+    }
+
+    /// Removes the given css class from the selector of the widget and updates the properties by
+    /// the theme.
+    pub fn remove_class(&mut self, class: &str) {
+        let removed = match self.try_get_mut::<Selector>("selector") {
+            Some(selector) => selector.remove_class(class),
+            None => false,
+        };
+
+        if removed {
+            self.update_properties_by_theme();
+        }
+    }
+
+    /// Returns `true` if the selector of the widget contains the given css class.
+    pub fn has_class(&self, class: &str) -> bool {
+        self.try_get::<Selector>("selector")
+            .map_or(false, |selector| selector.has_class(class))
+    }
+
     /// Updates the theme by the inner state e.g. `selected` or `pressed`.
     pub fn update_theme_by_state(&mut self, force: bool) {
         if let Some(selector) = self.try_clone::<Selector>("selector") {
             let mut update = false;
 
             if let Some(focus) = self.try_clone::<bool>("focused") {
-                if focus && !selector.pseudo_classes.contains("focus") {
+                if focus && !selector.has_pseudo_class("focus") {
                     add_selector_to_widget("focus", self);
                     update = true;
-                } else if !focus && selector.pseudo_classes.contains("focus") {
+                } else if !focus && selector.has_pseudo_class("focus") {
                     remove_selector_from_widget("focus", self);
                     update = true;
                 }
             }
 
             if let Some(selected) = self.try_clone::<bool>("selected") {
-                if selected && !selector.pseudo_classes.contains("selected") {
+                if selected && !selector.has_pseudo_class("selected") {
                     add_selector_to_widget("selected", self);
                     update = true;
-                } else if !selected && selector.pseudo_classes.contains("selected") {
+                } else if !selected && selector.has_pseudo_class("selected") {
                     remove_selector_from_widget("selected", self);
                     update = true;
                 }
             }
 
             if let Some(pressed) = self.try_clone::<bool>("pressed") {
-                if pressed && !selector.pseudo_classes.contains("active") {
+                if pressed && !selector.has_pseudo_class("active") {
                     add_selector_to_widget("active", self);
                     update = true;
-                } else if !pressed && selector.pseudo_classes.contains("active") {
+                } else if !pressed && selector.has_pseudo_class("active") {
                     remove_selector_from_widget("active", self);
                     update = true;
                 }
             }
 
             if let Some(enabled) = self.try_clone::<bool>("enabled") {
-                if !enabled && !selector.pseudo_classes.contains("disabled") {
+                if !enabled && !selector.has_pseudo_class("disabled") {
                     add_selector_to_widget("disabled", self);
                     update = true;
-                } else if enabled && selector.pseudo_classes.contains("disabled") {
+                } else if enabled && selector.has_pseudo_class("disabled") {
                     remove_selector_from_widget("disabled", self);
                     update = true;
                 }
             }
 
+            if let Some(valid) = self.try_clone::<bool>("is_valid") {
+                if !valid && !selector.has_pseudo_class("invalid") {
+                    add_selector_to_widget("invalid", self);
+                    update = true;
+                } else if valid && selector.has_pseudo_class("invalid") {
+                    remove_selector_from_widget("invalid", self);
+                    update = true;
+                }
+            }
+
             if let Some(text) = self.try_clone::<String16>("text") {
-                if text.is_empty() && !selector.pseudo_classes.contains("empty") {
+                if text.is_empty() && !selector.has_pseudo_class("empty") {
                     add_selector_to_widget("empty", self);
                     update = true;
-                } else if !text.is_empty() && selector.pseudo_classes.contains("empty") {
+                } else if !text.is_empty() && selector.has_pseudo_class("empty") {
                     remove_selector_from_widget("empty", self);
                     update = true;
                 }
@@ -246,18 +301,17 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
-    /// Update all properties for the theme.
+    /// Updates all properties for the theme if the selector of the widget is dirty, e.g. after
+    /// one of its classes or pseudo classes is changed. Unchanged widgets are skipped.
     pub fn update_properties_by_theme(&mut self) {
-        if !self.has::<Selector>("selector") {
-            return;
+        // the selector is only cloned if it has changed
+        match self.try_get::<Selector>("selector") {
+            Some(selector) if selector.dirty() => {}
+            _ => return,
         }
 
         let selector = self.clone::<Selector>("selector");
 
-        if !selector.dirty() {
-            return;
-        }
-
         if self.has::<Brush>("foreground") {
             if let Some(color) = self.theme.brush("color", &selector) {
                 self.set::<Brush>("foreground", color);
@@ -276,9 +330,15 @@ impl<'a> WidgetContainer<'a> {
             }
         }
 
-        if self.has::<f64>("border_radius") {
-            if let Some(radius) = self.theme.float("border-radius", &selector) {
-                self.set::<f64>("border_radius", f64::from(radius));
+        if self.has::<CornerRadius>("border_radius") {
+            if let Some(radius) = self.theme.corner_radius("border-radius", &selector) {
+                self.set::<CornerRadius>("border_radius", radius);
+            }
+        }
+
+        if self.has::<f64>("caret_width") {
+            if let Some(caret_width) = self.theme.float("caret-width", &selector) {
+                self.set::<f64>("caret_width", f64::from(caret_width));
             }
         }
 
@@ -321,7 +381,8 @@ impl<'a> WidgetContainer<'a> {
 
         // todo padding, icon_margin
 
-        self.get_mut::<Selector>("selector").set_dirty(true);
+        // the style is resolved again only after the selector is changed
+        self.get_mut::<Selector>("selector").set_dirty(false);
     }
 
     pub fn update_font_properties_by_theme(&mut self, selector: &Selector) {
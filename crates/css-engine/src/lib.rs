@@ -21,9 +21,20 @@ let background = theme.brush("background", &selector);
 
  */
 
+#[macro_use]
+extern crate lazy_static;
+
 pub use selector::*;
+pub use static_theme::*;
+pub use symbol::*;
 pub use theme::*;
+pub use theme_extension::*;
+pub use theme_layer::*;
 
 pub mod prelude;
 mod selector;
+mod static_theme;
+mod symbol;
 mod theme;
+mod theme_extension;
+mod theme_layer;
@@ -1,6 +1,8 @@
 use std::fmt;
 use std::{collections::HashSet, ops::Add};
 
+use crate::Symbol;
+
 #[derive(Clone, Debug)]
 pub enum SelectorRelation {
     Ancestor(Selector),
@@ -28,15 +30,16 @@ impl Add<Self> for Specificity {
     }
 }
 
-/// Describes a css selector.
+/// Describes a css selector. Its names are interned, so selectors are cheap to clone and match.
 #[derive(Debug, Default)]
 pub struct Selector {
-    pub id: Option<String>,
-    pub element: Option<String>,
-    pub classes: HashSet<String>,
-    pub pseudo_classes: HashSet<String>,
+    pub id: Option<Symbol>,
+    pub element: Option<Symbol>,
+    pub classes: HashSet<Symbol>,
+    pub pseudo_classes: HashSet<Symbol>,
     pub relation: Option<Box<SelectorRelation>>,
     pub dirty: bool,
+    generation: u32,
 }
 
 /// Inner selector value.
@@ -49,6 +52,7 @@ impl Selector {
             pseudo_classes: HashSet::new(),
             relation: None,
             dirty: true,
+            generation: 0,
         }
     }
 
@@ -63,10 +67,22 @@ impl Selector {
         self.dirty
     }
 
+    /// Marks the selector as dirty, so the style of its widget is resolved again. Each change to
+    /// dirty increments the generation of the selector.
     pub fn set_dirty(&mut self, dirty: bool) {
+        if dirty {
+            self.generation = self.generation.wrapping_add(1);
+        }
+
         self.dirty = dirty;
     }
 
+    /// Returns the style generation of the selector, it changes each time the selector is marked
+    /// as dirty, e.g. to check if the style of a widget was changed since a previous frame.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
     pub fn specificity(&self) -> Specificity {
         let s = Specificity([
             if self.id.is_some() { 1 } else { 0 },
@@ -105,35 +121,97 @@ impl Selector {
         true
     }
 
-    pub fn with<S: Into<String>>(mut self, element: S) -> Self {
+    pub fn with<S: Into<Symbol>>(mut self, element: S) -> Self {
         self.element = Some(element.into());
         self
     }
 
-    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+    pub fn id<S: Into<Symbol>>(mut self, id: S) -> Self {
         self.id = Some(id.into());
         self
     }
 
-    pub fn class<S: Into<String>>(mut self, class: S) -> Self {
+    pub fn class<S: Into<Symbol>>(mut self, class: S) -> Self {
         self.classes.insert(class.into());
         self
     }
 
-    pub fn without_class<S: Into<String>>(mut self, class: S) -> Self {
+    pub fn without_class<S: Into<Symbol>>(mut self, class: S) -> Self {
         self.classes.remove(&class.into());
         self
     }
 
-    pub fn pseudo_class<S: Into<String>>(mut self, pseudo_class: S) -> Self {
+    /// Adds the given class and marks the selector as dirty. Returns `false` if the class was
+    /// already set.
+    pub fn add_class<S: Into<Symbol>>(&mut self, class: S) -> bool {
+        let added = self.classes.insert(class.into());
+
+        if added {
+            self.set_dirty(true);
+        }
+
+        added
+    }
+
+    /// Removes the given class and marks the selector as dirty. Returns `false` if the class was
+    /// not set.
+    pub fn remove_class(&mut self, class: &str) -> bool {
+        let removed = Symbol::lookup(class).map_or(false, |class| self.classes.remove(&class));
+
+        if removed {
+            self.set_dirty(true);
+        }
+
+        removed
+    }
+
+    /// Returns `true` if the selector contains the given class.
+    pub fn has_class(&self, class: &str) -> bool {
+        Symbol::lookup(class).map_or(false, |class| self.classes.contains(&class))
+    }
+
+    pub fn pseudo_class<S: Into<Symbol>>(mut self, pseudo_class: S) -> Self {
         self.pseudo_classes.insert(pseudo_class.into());
         self
     }
 
-    pub fn without_pseudo_class<S: Into<String>>(mut self, pseudo_class: S) -> Self {
+    pub fn without_pseudo_class<S: Into<Symbol>>(mut self, pseudo_class: S) -> Self {
         self.pseudo_classes.remove(&pseudo_class.into());
         self
     }
+
+    /// Adds the given pseudo class, e.g. `focus`, and marks the selector as dirty. Returns `false`
+    /// if the pseudo class was already set.
+    pub fn add_pseudo_class<S: Into<Symbol>>(&mut self, pseudo_class: S) -> bool {
+        let added = self.pseudo_classes.insert(pseudo_class.into());
+
+        if added {
+            self.set_dirty(true);
+        }
+
+        added
+    }
+
+    /// Removes the given pseudo class and marks the selector as dirty. Returns `false` if the
+    /// pseudo class was not set.
+    pub fn remove_pseudo_class(&mut self, pseudo_class: &str) -> bool {
+        let removed = Symbol::lookup(pseudo_class).map_or(false, |pseudo_class| {
+            self.pseudo_classes.remove(&pseudo_class)
+        });
+
+        if removed {
+            self.set_dirty(true);
+        }
+
+        removed
+    }
+
+    /// Returns `true` if the selector contains the given pseudo class.
+    pub fn has_pseudo_class(&self, pseudo_class: &str) -> bool {
+        Symbol::lookup(pseudo_class).map_or(false, |pseudo_class| {
+            self.pseudo_classes.contains(&pseudo_class)
+        })
+    }
 }
 
 impl PartialEq for Selector {
@@ -145,12 +223,13 @@ impl PartialEq for Selector {
 impl Clone for Selector {
     fn clone(&self) -> Self {
         Selector {
-            id: self.id.clone(),
-            element: self.element.clone(),
+            id: self.id,
+            element: self.element,
             classes: self.classes.clone(),
             pseudo_classes: self.pseudo_classes.clone(),
             relation: self.relation.clone(),
             dirty: self.dirty,
+            generation: self.generation,
         }
     }
 }
@@ -167,14 +246,112 @@ impl fmt::Display for Selector {
 
 // --- Conversions ---
 
+// Parses an element with optional classes, e.g. `button.primary.warning`.
+impl From<&str> for Selector {
+    fn from(s: &str) -> Selector {
+        let mut parts = s.split('.');
+        let mut selector = Selector::new();
+
+        if let Some(element) = parts.next() {
+            if !element.is_empty() {
+                selector.element = Some(Symbol::new(element));
+            }
+        }
+
+        for class in parts.filter(|class| !class.is_empty()) {
+            selector.classes.insert(Symbol::new(class));
+        }
+
+        selector
+    }
+}
+
 impl From<String> for Selector {
     fn from(s: String) -> Selector {
-        Selector::new().with(s)
+        Selector::from(s.as_str())
     }
 }
 
-impl From<&str> for Selector {
-    fn from(s: &str) -> Selector {
-        Selector::new().with(s.to_string())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let selector = Selector::from("button");
+        assert_eq!(selector.element, Some(Symbol::new("button")));
+        assert!(selector.classes.is_empty());
+
+        let selector = Selector::from("button.primary.warning");
+        assert_eq!(selector.element, Some(Symbol::new("button")));
+        assert!(selector.has_class("primary"));
+        assert!(selector.has_class("warning"));
+
+        let selector = Selector::from(".warning");
+        assert_eq!(selector.element, None);
+        assert!(selector.has_class("warning"));
+    }
+
+    #[test]
+    fn test_add_remove_class() {
+        let mut selector = Selector::from("button");
+        selector.set_dirty(false);
+
+        assert!(selector.add_class("warning"));
+        assert!(selector.dirty());
+        assert!(!selector.add_class("warning"));
+
+        selector.set_dirty(false);
+        assert!(!selector.remove_class("error"));
+        assert!(!selector.dirty());
+        assert!(selector.remove_class("warning"));
+        assert!(selector.dirty());
+        assert!(!selector.has_class("warning"));
+    }
+
+    #[test]
+    fn test_generation() {
+        let mut selector = Selector::from("button");
+        let generation = selector.generation();
+
+        selector.add_class("warning");
+        assert_eq!(selector.generation(), generation + 1);
+
+        // unchanged classes do not increment the generation
+        selector.add_class("warning");
+        selector.remove_class("error");
+        selector.set_dirty(false);
+        assert_eq!(selector.generation(), generation + 1);
+        assert_eq!(selector.clone().generation(), generation + 1);
+    }
+
+    #[test]
+    fn test_add_remove_pseudo_class() {
+        let mut selector = Selector::from("button");
+        selector.set_dirty(false);
+
+        assert!(selector.add_pseudo_class("focus"));
+        assert!(selector.dirty());
+        assert!(selector.has_pseudo_class("focus"));
+        assert!(Selector::default().pseudo_class("focus").matches(&selector));
+
+        selector.set_dirty(false);
+        assert!(!selector.remove_pseudo_class("selector-test-unknown"));
+        assert!(!selector.dirty());
+        assert!(selector.remove_pseudo_class("focus"));
+        assert!(selector.dirty());
+        assert!(!selector.has_pseudo_class("focus"));
+    }
+
+    #[test]
+    fn test_matches_class() {
+        let rule = Selector::default().class("warning");
+        let mut widget = Selector::from("button");
+        assert!(!rule.matches(&widget));
+
+        widget.add_class("warning");
+        assert!(rule.matches(&widget));
+        assert!(Selector::from("button.warning").matches(&widget));
+        assert!(!Selector::from("text-box.warning").matches(&widget));
     }
 }
@@ -0,0 +1,197 @@
+use orbtk_utils::prelude::*;
+
+use crate::prelude::*;
+
+/// Value of a `StaticDeclaration`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StaticValue {
+    UInt(u32),
+    Float(f32),
+
+    /// A solid color with the data of a `Color`.
+    Color(u32),
+    Str(&'static str),
+
+    /// The radii of a `CornerRadius`, clockwise from the top left corner.
+    CornerRadius(f64, f64, f64, f64),
+}
+
+/// Relation of a `StaticSelector` to the selector of an ancestor or parent.
+#[derive(Copy, Clone, Debug)]
+pub enum StaticRelation {
+    Ancestor(&'static StaticSelector),
+    Parent(&'static StaticSelector),
+}
+
+/// Selector of a `StaticRule`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StaticSelector {
+    pub id: Option<&'static str>,
+    pub element: Option<&'static str>,
+    pub classes: &'static [&'static str],
+    pub pseudo_classes: &'static [&'static str],
+    pub relation: Option<StaticRelation>,
+}
+
+/// Declaration of a `StaticRule`.
+#[derive(Copy, Clone, Debug)]
+pub struct StaticDeclaration {
+    pub property: &'static str,
+    pub value: StaticValue,
+    pub important: bool,
+}
+
+/// A css rule that is parsed at build time and embedded in the binary, see the `compile_theme`
+/// macro of the `orbtk_proc_macros` crate. Use `Theme::create_from_static` to build a theme from
+/// a table of rules without parsing css.
+#[derive(Copy, Clone, Debug)]
+pub struct StaticRule {
+    pub selectors: &'static [StaticSelector],
+    pub declarations: &'static [StaticDeclaration],
+}
+
+// --- Conversions ---
+
+impl From<&StaticValue> for Value {
+    fn from(value: &StaticValue) -> Self {
+        match *value {
+            StaticValue::UInt(x) => Value::UInt(x),
+            StaticValue::Float(x) => Value::Float(x),
+            StaticValue::Color(data) => Value::Brush(Brush::SolidColor(Color { data })),
+            StaticValue::Str(x) => Value::Str(x.to_string()),
+            StaticValue::CornerRadius(top_left, top_right, bottom_right, bottom_left) => {
+                Value::CornerRadius(CornerRadius::new(
+                    top_left,
+                    top_right,
+                    bottom_right,
+                    bottom_left,
+                ))
+            }
+        }
+    }
+}
+
+impl From<&StaticSelector> for Selector {
+    fn from(selector: &StaticSelector) -> Self {
+        let mut result = Selector::new();
+        result.id = selector.id.map(Symbol::new);
+        result.element = selector.element.map(Symbol::new);
+        result.classes = selector.classes.iter().map(|c| Symbol::new(c)).collect();
+        result.pseudo_classes = selector
+            .pseudo_classes
+            .iter()
+            .map(|c| Symbol::new(c))
+            .collect();
+        result.relation = selector.relation.map(|relation| {
+            Box::new(match relation {
+                StaticRelation::Ancestor(x) => SelectorRelation::Ancestor(x.into()),
+                StaticRelation::Parent(x) => SelectorRelation::Parent(x.into()),
+            })
+        });
+
+        result
+    }
+}
+
+impl From<&StaticRule> for Rule {
+    fn from(rule: &StaticRule) -> Self {
+        Rule {
+            selectors: rule.selectors.iter().map(Selector::from).collect(),
+            declarations: rule
+                .declarations
+                .iter()
+                .map(|declaration| Declaration {
+                    property: Symbol::new(declaration.property),
+                    value: Value::from(&declaration.value),
+                    important: declaration.important,
+                })
+                .collect(),
+            layer: ThemeLayer::Base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static RULES: &[StaticRule] = &[StaticRule {
+        selectors: &[
+            StaticSelector {
+                id: None,
+                element: Some("button"),
+                classes: &["primary"],
+                pseudo_classes: &[],
+                relation: None,
+            },
+            StaticSelector {
+                id: Some("ok"),
+                element: None,
+                classes: &[],
+                pseudo_classes: &["active"],
+                relation: None,
+            },
+        ],
+        declarations: &[
+            StaticDeclaration {
+                property: "background",
+                value: StaticValue::Color(0xFF21_6BE1),
+                important: false,
+            },
+            StaticDeclaration {
+                property: "font-size",
+                value: StaticValue::UInt(14),
+                important: true,
+            },
+        ],
+    }];
+
+    #[test]
+    fn test_create_from_static() {
+        let theme = Theme::create_from_static(RULES).build();
+        let css = Theme::create_from_css(
+            "button.primary, #ok:active { background: #216be1; font-size: 14 !important; }",
+        )
+        .build();
+
+        for selector in &[
+            Selector::from("button").class("primary"),
+            Selector::default().id("ok").pseudo_class("active"),
+        ] {
+            assert_eq!(
+                theme.brush("background", selector),
+                Some(Brush::from("#216be1"))
+            );
+            assert_eq!(theme.uint("font-size", selector), Some(14));
+            assert_eq!(
+                theme.get("background", selector),
+                css.get("background", selector)
+            );
+        }
+
+        assert_eq!(theme.uint("font-size", &Selector::from("button")), None);
+    }
+
+    #[test]
+    fn test_try_parse() {
+        assert!(try_parse("button { background: #216be1; }").is_ok());
+        assert!(try_parse("button { background: nocolor; }").is_err());
+        assert!(try_parse("button { unknown: 1; }").is_err());
+        assert!(try_parse("button { background: #ggg; }").is_err());
+
+        let theme = Theme::create_from_css(
+            "button { background: rgba(94, 121, 166, 50%); color: #abc; icon-color: hsl(120, 100%, 25%); }",
+        )
+        .build();
+        let button = Selector::from("button");
+        assert_eq!(
+            theme.brush("background", &button),
+            Some(Brush::from(Color::rgba(94, 121, 166, 128)))
+        );
+        assert_eq!(theme.brush("color", &button), Some(Brush::from("#aabbcc")));
+        assert_eq!(
+            theme.brush("icon-color", &button),
+            Some(Brush::from(Color::rgb(0, 128, 0)))
+        );
+    }
+}
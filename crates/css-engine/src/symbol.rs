@@ -0,0 +1,224 @@
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    ptr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+lazy_static! {
+    static ref INTERNER: Mutex<Interner> = Mutex::new(Interner::default());
+}
+
+// Number of interned texts, a thread compares it with its cache to sync the cache without lock.
+static INTERNED: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static CACHE: RefCell<Cache> = RefCell::new(Cache::default());
+}
+
+// Stores the text of each symbol, the texts live until the end of the program and are only
+// appended.
+#[derive(Default)]
+struct Interner {
+    symbols: HashSet<&'static str>,
+    texts: Vec<&'static str>,
+}
+
+impl Interner {
+    fn intern(&mut self, text: &str) -> Symbol {
+        if let Some(text) = self.symbols.get(text).copied() {
+            return Symbol(text);
+        }
+
+        let text: &'static str = Box::leak(text.to_string().into_boxed_str());
+        self.texts.push(text);
+        self.symbols.insert(text);
+        INTERNED.store(self.texts.len(), Ordering::Release);
+
+        Symbol(text)
+    }
+}
+
+// Copy of the interned texts of a thread, the lock is only taken to copy newly interned texts.
+#[derive(Default)]
+struct Cache {
+    symbols: HashSet<&'static str>,
+    len: usize,
+}
+
+impl Cache {
+    fn lookup(&mut self, text: &str) -> Option<Symbol> {
+        if self.len != INTERNED.load(Ordering::Acquire) {
+            let interner = INTERNER.lock().unwrap();
+            self.symbols.extend(&interner.texts[self.len..]);
+            self.len = interner.texts.len();
+        }
+
+        self.symbols.get(text).copied().map(Symbol)
+    }
+}
+
+/// `Symbol` is an interned string that is used for the ids, elements, classes and property names
+/// of selectors and themes. Symbols with the same text are equal, copying, comparing and hashing
+/// a symbol does not touch its text and reading its text takes no lock.
+///
+/// The text of a symbol is stored once for the whole program, so symbols should only be created
+/// for a limited set of names and not for user input.
+#[derive(Copy, Clone)]
+pub struct Symbol(&'static str);
+
+impl Symbol {
+    /// Returns the symbol of the given text, the text is interned if it is new.
+    pub fn new(text: &str) -> Self {
+        Symbol::lookup(text).unwrap_or_else(|| INTERNER.lock().unwrap().intern(text))
+    }
+
+    /// Returns the symbol of the given text if it is already interned. Used to look up names
+    /// without interning them.
+    pub fn lookup(text: &str) -> Option<Self> {
+        CACHE.with(|cache| cache.borrow_mut().lookup(text))
+    }
+
+    /// Returns the text of the symbol.
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+// each text is interned once, so symbols are compared and hashed by the address of their text
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Symbol) -> bool {
+        ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ptr::hash(self.0.as_ptr(), state);
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for Symbol {
+    fn from(text: &str) -> Self {
+        Symbol::new(text)
+    }
+}
+
+impl From<&String> for Symbol {
+    fn from(text: &String) -> Self {
+        Symbol::new(text)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(text: String) -> Self {
+        Symbol::new(&text)
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(symbol: Symbol) -> Self {
+        symbol.as_str().to_string()
+    }
+}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Symbol {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<Symbol> for str {
+    fn eq(&self, other: &Symbol) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Symbol> for &str {
+    fn eq(&self, other: &Symbol) -> bool {
+        *self == other.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let button = Symbol::new("button");
+        assert_eq!(button, Symbol::from(String::from("button")));
+        assert_ne!(button, Symbol::new("text-box"));
+        assert_eq!(button.as_str(), "button");
+        assert_eq!(button, "button");
+        assert_eq!(format!("{}", button), "button");
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(Symbol::lookup("symbol-test-lookup"), None);
+
+        let symbol = Symbol::new("symbol-test-lookup");
+        assert_eq!(Symbol::lookup("symbol-test-lookup"), Some(symbol));
+    }
+
+    #[test]
+    fn test_lookup_other_thread() {
+        assert_eq!(Symbol::lookup("symbol-test-thread"), None);
+
+        let symbol = std::thread::spawn(|| Symbol::new("symbol-test-thread"))
+            .join()
+            .unwrap();
+
+        assert_eq!(Symbol::lookup("symbol-test-thread"), Some(symbol));
+        assert_eq!(symbol.as_str(), "symbol-test-thread");
+    }
+}
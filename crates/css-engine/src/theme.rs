@@ -1,6 +1,8 @@
 //! This module contains all css theming related resources.
 
-use std::{fs::File, io::BufReader, io::Read, mem, path::Path, sync::Arc};
+use std::{
+    cell::RefCell, fmt, fs::File, io::BufReader, io::Read, mem, path::Path, rc::Rc, sync::Arc,
+};
 
 use cssparser::{
     self, BasicParseError, CompactCowStr, DeclarationListParser, ParseError, Parser, ParserInput,
@@ -15,6 +17,7 @@ use crate::prelude::*;
 pub struct ThemeBuilder {
     theme_css: Option<String>,
     theme_path: Option<String>,
+    theme_rules: &'static [StaticRule],
     theme_extensions: Vec<String>,
     theme_extension_paths: Vec<String>,
 }
@@ -24,6 +27,7 @@ impl Default for ThemeBuilder {
         ThemeBuilder {
             theme_css: None,
             theme_path: None,
+            theme_rules: &[],
             theme_extensions: Vec::new(),
             theme_extension_paths: Vec::new(),
         }
@@ -75,7 +79,13 @@ impl ThemeBuilder {
             }
         };
 
-        Theme::parse(&theme)
+        let mut theme = Theme::parse(&theme);
+
+        // the rules of a compiled theme are not parsed, they follow the parsed rules like the
+        // css of the theme follows its extensions
+        theme.rules.extend(self.theme_rules.iter().map(Rule::from));
+
+        theme
     }
 }
 
@@ -103,6 +113,15 @@ impl Theme {
         }
     }
 
+    /// Creates a new `ThemeBuilder` with the given rules as base, e.g. a theme that is parsed at
+    /// build time with the `compile_theme` macro.
+    pub fn create_from_static(rules: &'static [StaticRule]) -> ThemeBuilder {
+        ThemeBuilder {
+            theme_rules: rules,
+            ..Default::default()
+        }
+    }
+
     /// Creates a new `ThemeBuilder` with the given css path as base.
     pub fn create_from_path(path: impl Into<String>) -> ThemeBuilder {
         ThemeBuilder {
@@ -131,22 +150,18 @@ impl Theme {
         }
     }
 
-    fn all_rules(&self) -> Vec<Rule> {
-        if let Some(ref parent) = self.parent {
-            self.rules
-                .iter()
-                .chain(parent.rules.iter())
-                .cloned()
-                .collect()
-        } else {
-            self.rules.clone()
-        }
+    fn all_rules(&self) -> impl DoubleEndedIterator<Item = &Rule> {
+        self.rules
+            .iter()
+            .chain(self.parent.iter().flat_map(|parent| parent.rules.iter()))
     }
 
     pub fn get(&self, property: &str, query: &Selector) -> Option<Value> {
-        let mut matches: Vec<(bool, Specificity, Value)> = Vec::new();
+        // a property that is not interned is not declared by any rule
+        let property = Symbol::lookup(property)?;
+        let mut matches: Vec<(bool, ThemeLayer, Specificity, Value)> = Vec::new();
 
-        for rule in self.all_rules().iter().rev() {
+        for rule in self.all_rules().rev() {
             let matching_selectors = rule
                 .selectors
                 .iter()
@@ -164,13 +179,38 @@ impl Theme {
                         .map(|sel| sel.specificity())
                         .max()
                         .unwrap();
-                    matches.push((decl.important, highest_specifity, decl.value.clone()));
+                    matches.push((
+                        decl.important,
+                        rule.layer,
+                        highest_specifity,
+                        decl.value.clone(),
+                    ));
                 }
             }
         }
 
-        matches.sort_by_key(|x| (x.0, x.1));
-        matches.last().map(|x| x.2.clone())
+        matches.sort_by_key(|x| (x.0, x.1, x.2));
+        matches.last().map(|x| x.3.clone())
+    }
+
+    /// Merges the rules of the given theme as the given layer into this theme, e.g. the
+    /// overrides of an application into the default theme. The rules of a later merge win over
+    /// the rules of an earlier merge of the same layer.
+    pub fn merge(mut self, layer: ThemeLayer, theme: Theme) -> Theme {
+        let mut rules: Vec<Rule> = theme
+            .all_rules()
+            .cloned()
+            .map(|mut rule| {
+                rule.layer = layer;
+                rule
+            })
+            .collect();
+
+        // on equal precedence the first rule wins
+        rules.append(&mut self.rules);
+        self.rules = rules;
+
+        self
     }
 
     pub fn brush(&self, property: &str, query: &Selector) -> Option<Brush> {
@@ -188,17 +228,24 @@ impl Theme {
     pub fn string(&self, property: &str, query: &Selector) -> Option<String> {
         self.get(property, query).and_then(|v| v.string())
     }
+
+    pub fn corner_radius(&self, property: &str, query: &Selector) -> Option<CornerRadius> {
+        self.get(property, query).and_then(|v| v.corner_radius())
+    }
 }
 
 #[derive(Clone, Default, PartialEq, Debug)]
 pub struct Rule {
     pub selectors: Vec<Selector>,
     pub declarations: Vec<Declaration>,
+
+    /// Layer of the rule if the theme is merged from several themes.
+    pub layer: ThemeLayer,
 }
 
 #[derive(Clone, Default, PartialEq, Debug)]
 pub struct Declaration {
-    pub property: String,
+    pub property: Symbol,
     pub value: Value,
     pub important: bool,
 }
@@ -209,6 +256,7 @@ pub enum Value {
     Float(f32),
     Brush(Brush),
     Str(String),
+    CornerRadius(CornerRadius),
 }
 
 impl Default for Value {
@@ -245,6 +293,34 @@ impl Value {
             _ => None,
         }
     }
+
+    /// Returns the corner radius of the value, a single number rounds all corners.
+    pub fn corner_radius(&self) -> Option<CornerRadius> {
+        match *self {
+            Value::CornerRadius(x) => Some(x),
+            Value::UInt(x) => Some(CornerRadius::from(x as f64)),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the value as css.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::UInt(x) => write!(f, "{}", x),
+            Value::Float(x) => write!(f, "{}", x),
+            Value::Brush(Brush::SolidColor(color)) => write!(f, "{}", color.to_string()),
+            Value::Brush(_) => write!(f, "transparent"),
+            Value::Str(x) => write!(f, "\"{}\"", x),
+            Value::CornerRadius(x) if x.is_uniform() => write!(f, "{}", x.top_left),
+            Value::CornerRadius(x) => write!(
+                f,
+                "{} {} {} {}",
+                x.top_left, x.top_right, x.bottom_right, x.bottom_left
+            ),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -260,11 +336,21 @@ impl<'t> From<CustomParseError> for ParseError<'t, CustomParseError> {
     }
 }
 
-struct RuleParser;
+// Describes an invalid rule or declaration with the css that could not be parsed.
+fn parse_error_message(error: &ParseError<'_, CustomParseError>, css: &str) -> String {
+    match error {
+        ParseError::Basic(e) => format!("{:?} in `{}`", e, css),
+        ParseError::Custom(e) => format!("{:?} in `{}`", e, css),
+    }
+}
+
+struct RuleParser {
+    errors: Rc<RefCell<Vec<String>>>,
+}
 
 impl RuleParser {
-    fn new() -> Self {
-        RuleParser {}
+    fn new(errors: Rc<RefCell<Vec<String>>>) -> Self {
+        RuleParser { errors }
     }
 }
 
@@ -291,15 +377,10 @@ impl<'i> cssparser::QualifiedRuleParser<'i> for RuleParser {
         let decls = DeclarationListParser::new(input, decl_parser).collect::<Vec<_>>();
 
         for decl in &decls {
-            match *decl {
-                Ok(_) => {}
-                Err(ref e) => {
-                    match e.error {
-                        ParseError::Basic(ref e) => eprintln!("{:?}", e),
-                        ParseError::Custom(ref e) => eprintln!("{:?}", e),
-                    }
-                    println!("Error occured in `{}`", input.slice(e.span.clone()));
-                }
+            if let Err(ref e) = *decl {
+                self.errors
+                    .borrow_mut()
+                    .push(parse_error_message(&e.error, input.slice(e.span.clone())));
             }
         }
 
@@ -308,6 +389,7 @@ impl<'i> cssparser::QualifiedRuleParser<'i> for RuleParser {
         Ok(Rule {
             selectors,
             declarations: decls,
+            layer: ThemeLayer::Base,
         })
     }
 }
@@ -331,23 +413,23 @@ fn parse_selectors<'i, 't>(
             // Element
             Token::Ident(ref element_name) => {
                 if first_token_in_selector {
-                    selector.element = Some(element_name.to_string())
+                    selector.element = Some(Symbol::new(element_name))
                 } else {
-                    let mut old_selector = Selector::new().with(element_name.to_string());
+                    let mut old_selector = Selector::new().with(&**element_name);
                     mem::swap(&mut old_selector, &mut selector);
                     selector.relation = Some(Box::new(SelectorRelation::Ancestor(old_selector)));
                 }
             }
 
             Token::Delim('>') => {
-                let mut old_selector = Selector::new().with(input.expect_ident()?.to_string());
+                let mut old_selector = Selector::new().with(&*input.expect_ident()?);
                 mem::swap(&mut old_selector, &mut selector);
                 selector.relation = Some(Box::new(SelectorRelation::Parent(old_selector)));
             }
 
             // Id
             Token::IDHash(ref id_name) => {
-                selector.id = Some(id_name.to_string());
+                selector.id = Some(Symbol::new(id_name));
             }
 
             // Any element
@@ -355,14 +437,14 @@ fn parse_selectors<'i, 't>(
 
             // Class
             Token::Delim('.') => {
-                selector.classes.insert(input.expect_ident()?.into_owned());
+                selector.classes.insert(Symbol::new(&input.expect_ident()?));
             }
 
             // Pseudo-class
             Token::Colon => {
                 selector
                     .pseudo_classes
-                    .insert(input.expect_ident()?.into_owned());
+                    .insert(Symbol::new(&input.expect_ident()?));
             }
 
             // This selector is done, on to the next one
@@ -409,19 +491,26 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
 
             "font-family" | "icon-family" => Value::Str(parse_string(input)?),
 
-            "border-radius" | "border-width" | "font-size" | "icon-size" | "icon-margin"
-            | "padding" | "padding-left" | "padding-top" | "padding-right" | "padding-bottom" => {
-                match input.next()? {
-                    Token::Number {
-                        int_value: Some(x),
-                        has_sign,
-                        ..
-                    } if !has_sign && x >= 0 => Value::UInt(x as u32),
-                    t => return Err(BasicParseError::UnexpectedToken(t).into()),
+            "border-radius" => {
+                let mut radii = vec![parse_uint(input)? as f64];
+
+                while radii.len() < 4 {
+                    match input.r#try(|input| parse_uint(input)) {
+                        Ok(radius) => radii.push(radius as f64),
+                        Err(_) => break,
+                    }
                 }
+
+                // one to four radii are always valid
+                Value::CornerRadius(CornerRadius::from_values(&radii).unwrap_or_default())
+            }
+
+            "border-width" | "font-size" | "icon-size" | "icon-margin" | "padding"
+            | "padding-left" | "padding-top" | "padding-right" | "padding-bottom" => {
+                Value::UInt(parse_uint(input)?)
             }
 
-            "opacity" => match input.next()? {
+            "opacity" | "caret-width" => match input.next()? {
                 Token::Number { value: x, .. } => Value::Float(x as f32),
                 t => return Err(BasicParseError::UnexpectedToken(t).into()),
             },
@@ -430,7 +519,7 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
         };
 
         Ok(Declaration {
-            property: name.into_owned(),
+            property: Symbol::new(&name),
             value,
             important: input.r#try(cssparser::parse_important).is_ok(),
         })
@@ -471,6 +560,17 @@ fn css_string(name: &str) -> Option<String> {
     Some(String::from(name))
 }
 
+fn parse_uint<'i, 't>(input: &mut Parser<'i, 't>) -> Result<u32, ParseError<'i, CustomParseError>> {
+    match input.next()? {
+        Token::Number {
+            int_value: Some(x),
+            has_sign,
+            ..
+        } if !has_sign && x >= 0 => Ok(x as u32),
+        t => Err(BasicParseError::UnexpectedToken(t).into()),
+    }
+}
+
 fn parse_string<'i, 't>(
     input: &mut Parser<'i, 't>,
 ) -> Result<String, ParseError<'i, CustomParseError>> {
@@ -496,7 +596,41 @@ fn parse_basic_color<'i, 't>(
             None => return Err(CustomParseError::InvalidColorName(s.into_owned()).into()),
         },
 
-        Token::IDHash(hash) | Token::Hash(hash) => Brush::from(hash.into_owned()),
+        Token::IDHash(hash) | Token::Hash(hash) => match Color::parse(&hash) {
+            Some(color) => Brush::from(color),
+            None => return Err(CustomParseError::InvalidColorHex(hash.into_owned()).into()),
+        },
+
+        // e.g. rgba(94, 121, 166, 0.5) or hsl(120, 100%, 25%)
+        Token::Function(name) => {
+            let name = name.into_owned();
+            let arguments = input.parse_nested_block(
+                |input| -> Result<String, ParseError<'i, CustomParseError>> {
+                    let mut arguments = vec![];
+
+                    while let Ok(token) = input.next() {
+                        match token {
+                            Token::Number { value, .. } | Token::Dimension { value, .. } => {
+                                arguments.push(value.to_string())
+                            }
+                            Token::Percentage { unit_value, .. } => {
+                                arguments.push(format!("{}%", unit_value * 100.0))
+                            }
+                            Token::Comma | Token::Delim('/') => {}
+                            t => return Err(BasicParseError::UnexpectedToken(t).into()),
+                        }
+                    }
+
+                    Ok(arguments.join(","))
+                },
+            )?;
+            let function = format!("{}({})", name, arguments);
+
+            match Color::parse(&function) {
+                Some(color) => Brush::from(color),
+                None => return Err(CustomParseError::InvalidColorName(function).into()),
+            }
+        }
 
         t => {
             let basic_error = BasicParseError::UnexpectedToken(t);
@@ -505,10 +639,12 @@ fn parse_basic_color<'i, 't>(
     })
 }
 
-pub fn parse(s: &str) -> Vec<Rule> {
+// Returns the valid rules of the css and the errors of the invalid rules and declarations.
+fn parse_rules(s: &str) -> (Vec<Rule>, Vec<String>) {
     let mut input = ParserInput::new(s);
     let mut parser = Parser::new(&mut input);
-    let rule_parser = RuleParser::new();
+    let errors = Rc::new(RefCell::new(vec![]));
+    let rule_parser = RuleParser::new(errors.clone());
 
     let rules = {
         let rule_list_parser =
@@ -517,17 +653,69 @@ pub fn parse(s: &str) -> Vec<Rule> {
     };
 
     for rule in &rules {
-        match *rule {
-            Ok(_) => {}
-            Err(ref e) => {
-                match e.error {
-                    ParseError::Basic(ref e) => eprintln!("{:?}", e),
-                    ParseError::Custom(ref e) => eprintln!("{:?}", e),
-                }
-                println!("Error occured in `{}`", parser.slice(e.span.clone()));
-            }
+        if let Err(ref e) = *rule {
+            errors
+                .borrow_mut()
+                .push(parse_error_message(&e.error, parser.slice(e.span.clone())));
         }
     }
 
-    rules.into_iter().filter_map(|rule| rule.ok()).collect()
+    let errors = errors.borrow().clone();
+
+    (
+        rules.into_iter().filter_map(|rule| rule.ok()).collect(),
+        errors,
+    )
+}
+
+/// Parses the css, invalid rules and declarations are skipped and reported on stderr.
+pub fn parse(s: &str) -> Vec<Rule> {
+    let (rules, errors) = parse_rules(s);
+
+    for error in errors {
+        eprintln!("Error occured: {}", error);
+    }
+
+    rules
+}
+
+/// Parses the css and fails with the errors of all invalid rules and declarations, e.g. to check
+/// a theme at build time.
+pub fn try_parse(s: &str) -> Result<Vec<Rule>, String> {
+    let (rules, errors) = parse_rules(s);
+
+    if !errors.is_empty() {
+        return Err(format!("Theme.try_parse: {}", errors.join(", ")));
+    }
+
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_border_radius() {
+        let theme = Theme::create_from_css(
+            "button { border-radius: 4; } .first { border-radius: 4 0 0 4; } .top { border-radius: 4 2; }",
+        )
+        .build();
+        let radius =
+            |selector: &str| theme.corner_radius("border-radius", &Selector::from(selector));
+
+        assert_eq!(radius("button"), Some(CornerRadius::from(4.0)));
+        assert_eq!(
+            radius("button.first"),
+            Some(CornerRadius::new(4.0, 0.0, 0.0, 4.0))
+        );
+        assert_eq!(
+            radius("button.top"),
+            Some(CornerRadius::new(4.0, 2.0, 4.0, 2.0))
+        );
+        assert_eq!(
+            Value::CornerRadius(CornerRadius::new(4.0, 0.0, 0.0, 4.0)).to_string(),
+            "4 0 0 4"
+        );
+    }
 }
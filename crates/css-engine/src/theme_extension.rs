@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+
+/// Names of the css properties that are supported by the theme.
+pub const THEME_PROPERTIES: [&str; 15] = [
+    "background",
+    "border-color",
+    "border-radius",
+    "border-width",
+    "caret-width",
+    "color",
+    "font-family",
+    "font-size",
+    "foreground",
+    "icon-color",
+    "icon-family",
+    "icon-margin",
+    "icon-size",
+    "opacity",
+    "padding",
+];
+
+/// Collects css declarations by selector, e.g. the changes of a theme editor, and writes them as
+/// theme extension.
+///
+/// ```rust
+/// # use orbtk_css_engine::prelude::*;
+/// let mut extension = ThemeExtension::new();
+/// extension.set("button", "background", "#efd035");
+///
+/// let theme = Theme::create_from_css("button { background: #647b91; }")
+///     .extension_css(extension.to_css())
+///     .build();
+/// ```
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct ThemeExtension {
+    rules: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl ThemeExtension {
+    /// Creates a new empty extension.
+    pub fn new() -> Self {
+        ThemeExtension::default()
+    }
+
+    /// Sets the value of a property for the given selector.
+    pub fn set(
+        &mut self,
+        selector: impl Into<String>,
+        property: impl Into<String>,
+        value: impl Into<String>,
+    ) {
+        self.rules
+            .entry(selector.into())
+            .or_insert_with(BTreeMap::new)
+            .insert(property.into(), value.into());
+    }
+
+    /// Returns the value of a property for the given selector.
+    pub fn get(&self, selector: &str, property: &str) -> Option<&str> {
+        self.rules
+            .get(selector)
+            .and_then(|declarations| declarations.get(property))
+            .map(|value| value.as_str())
+    }
+
+    /// Removes the value of a property for the given selector.
+    pub fn remove(&mut self, selector: &str, property: &str) -> Option<String> {
+        let declarations = self.rules.get_mut(selector)?;
+        let value = declarations.remove(property);
+
+        if declarations.is_empty() {
+            self.rules.remove(selector);
+        }
+
+        value
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.rules.clear();
+    }
+
+    /// Returns `true` if no value is set.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Writes the values as css.
+    pub fn to_css(&self) -> String {
+        let mut css = String::new();
+
+        for (selector, declarations) in &self.rules {
+            css.push_str(selector);
+            css.push_str(" {\n");
+
+            for (property, value) in declarations {
+                css.push_str(&format!("    {}: {};\n", property, value));
+            }
+
+            css.push_str("}\n\n");
+        }
+
+        css
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_remove() {
+        let mut extension = ThemeExtension::new();
+        assert!(extension.is_empty());
+
+        extension.set("button", "background", "#efd035");
+        extension.set("button", "border-radius", "4");
+        assert_eq!(extension.get("button", "background"), Some("#efd035"));
+        assert_eq!(extension.get("button", "color"), None);
+
+        assert_eq!(
+            extension.remove("button", "background"),
+            Some(String::from("#efd035"))
+        );
+        assert_eq!(
+            extension.remove("button", "border-radius"),
+            Some(String::from("4"))
+        );
+        assert!(extension.is_empty());
+        assert_eq!(extension.remove("button", "color"), None);
+    }
+
+    #[test]
+    fn test_to_css() {
+        let mut extension = ThemeExtension::new();
+        extension.set("text-box", "color", "#dfebf5");
+        extension.set("button.primary", "background", "#efd035");
+        extension.set("button.primary", "border-radius", "4");
+
+        assert_eq!(
+            extension.to_css(),
+            "button.primary {\n    background: #efd035;\n    border-radius: 4;\n}\n\n\
+             text-box {\n    color: #dfebf5;\n}\n\n"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+/// Describes the layer of the rules of a theme that is composed of several stylesheets with
+/// `Theme::merge`.
+///
+/// A declaration of a higher layer wins over the declarations of the lower layers regardless of
+/// the specificity of their selectors, `!important` declarations still win over all others.
+/// Inside of a layer the declaration with the most specific selector wins.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ThemeLayer {
+    /// The base theme, e.g. the default theme of the toolkit.
+    Base,
+
+    /// Overrides of the application.
+    Application,
+
+    /// Overrides of a single window.
+    Window,
+}
+
+impl Default for ThemeLayer {
+    fn default() -> Self {
+        ThemeLayer::Base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use orbtk_utils::prelude::*;
+
+    #[test]
+    fn test_merge() {
+        let theme = Theme::create_from_css(
+            "button { background: #000000; color: #000000; } button:active { background: #111111; }",
+        )
+        .build()
+        .merge(
+            ThemeLayer::Window,
+            Theme::create_from_css("button { color: #333333; }").build(),
+        )
+        .merge(
+            ThemeLayer::Application,
+            Theme::create_from_css("button { background: #222222; color: #222222; }").build(),
+        );
+
+        let button = Selector::from("button");
+        let active = Selector::from("button").pseudo_class("active");
+
+        // the application layer wins over the more specific selector of the base layer
+        assert_eq!(
+            theme.brush("background", &active),
+            Some(Brush::from("#222222"))
+        );
+        assert_eq!(
+            theme.brush("background", &button),
+            Some(Brush::from("#222222"))
+        );
+        assert_eq!(theme.brush("color", &button), Some(Brush::from("#333333")));
+
+        // a later merge of the same layer wins
+        let theme = theme.merge(
+            ThemeLayer::Window,
+            Theme::create_from_css("button { color: #444444; }").build(),
+        );
+        assert_eq!(theme.brush("color", &button), Some(Brush::from("#444444")));
+    }
+
+    #[test]
+    fn test_important() {
+        let theme = Theme::create_from_css("button { background: #000000 !important; }")
+            .build()
+            .merge(
+                ThemeLayer::Window,
+                Theme::create_from_css("button { background: #111111; }").build(),
+            );
+
+        assert_eq!(
+            theme.brush("background", &Selector::from("button")),
+            Some(Brush::from("#000000"))
+        );
+    }
+}
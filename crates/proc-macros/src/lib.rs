@@ -1,8 +1,13 @@
 extern crate proc_macro;
 
+use std::{collections::HashSet, env, fs, path::PathBuf};
+
+use orbtk_css_engine::prelude::*;
+use orbtk_utils::prelude::Color;
 use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, punctuated::Punctuated, DeriveInput, LitStr, Token};
 
 #[proc_macro_derive(Pipeline)]
 pub fn derive_pipeline(input: TokenStream) -> TokenStream {
@@ -47,3 +52,162 @@ pub fn derive_as_any(input: TokenStream) -> TokenStream {
 
     TokenStream::from(gen)
 }
+
+// Returns the tokens of an optional name of a `StaticSelector`.
+fn name_tokens(name: Option<Symbol>) -> TokenStream2 {
+    match name {
+        Some(name) => {
+            let name = name.as_str();
+            quote!(Some(#name))
+        }
+        None => quote!(None),
+    }
+}
+
+// Returns the tokens of a set of names of a `StaticSelector`, sorted to get the same output for
+// each build.
+fn names_tokens(names: &HashSet<Symbol>) -> TokenStream2 {
+    let mut names: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+    names.sort();
+
+    quote!(&[#(#names),*])
+}
+
+fn selector_tokens(selector: &Selector) -> TokenStream2 {
+    let id = name_tokens(selector.id);
+    let element = name_tokens(selector.element);
+    let classes = names_tokens(&selector.classes);
+    let pseudo_classes = names_tokens(&selector.pseudo_classes);
+    let relation = match selector.relation.as_ref().map(|relation| &**relation) {
+        Some(SelectorRelation::Ancestor(ancestor)) => {
+            let ancestor = selector_tokens(ancestor);
+            quote!(Some(::orbtk::css_engine::StaticRelation::Ancestor(&#ancestor)))
+        }
+        Some(SelectorRelation::Parent(parent)) => {
+            let parent = selector_tokens(parent);
+            quote!(Some(::orbtk::css_engine::StaticRelation::Parent(&#parent)))
+        }
+        None => quote!(None),
+    };
+
+    quote! {
+        ::orbtk::css_engine::StaticSelector {
+            id: #id,
+            element: #element,
+            classes: #classes,
+            pseudo_classes: #pseudo_classes,
+            relation: #relation,
+        }
+    }
+}
+
+fn value_tokens(value: &Value) -> TokenStream2 {
+    match value {
+        Value::UInt(x) => quote!(::orbtk::css_engine::StaticValue::UInt(#x)),
+        Value::Float(x) => quote!(::orbtk::css_engine::StaticValue::Float(#x)),
+        Value::Brush(brush) => {
+            let data = Color::from(brush.clone()).data;
+            quote!(::orbtk::css_engine::StaticValue::Color(#data))
+        }
+        Value::Str(x) => quote!(::orbtk::css_engine::StaticValue::Str(#x)),
+        Value::CornerRadius(radius) => {
+            let (top_left, top_right, bottom_right, bottom_left) = (
+                radius.top_left,
+                radius.top_right,
+                radius.bottom_right,
+                radius.bottom_left,
+            );
+            quote!(::orbtk::css_engine::StaticValue::CornerRadius(
+                #top_left,
+                #top_right,
+                #bottom_right,
+                #bottom_left
+            ))
+        }
+    }
+}
+
+fn rule_tokens(rule: &Rule) -> TokenStream2 {
+    let selectors = rule.selectors.iter().map(selector_tokens);
+    let declarations = rule.declarations.iter().map(|declaration| {
+        let property = declaration.property.as_str();
+        let value = value_tokens(&declaration.value);
+        let important = declaration.important;
+
+        quote! {
+            ::orbtk::css_engine::StaticDeclaration {
+                property: #property,
+                value: #value,
+                important: #important,
+            }
+        }
+    });
+
+    quote! {
+        ::orbtk::css_engine::StaticRule {
+            selectors: &[#(#selectors),*],
+            declarations: &[#(#declarations),*],
+        }
+    }
+}
+
+/// Parses css theme files at build time and returns a `ThemeBuilder` with a static table of their
+/// rules, so invalid css fails the build and the theme is not parsed at startup. The paths are
+/// relative to the root of the crate. Later files extend the earlier ones, like the extensions of
+/// a `ThemeBuilder`.
+///
+/// ```rust,ignore
+/// let theme = compile_theme!("res/theme.css", "res/theme-extension.css").build();
+/// ```
+#[proc_macro]
+pub fn compile_theme(input: TokenStream) -> TokenStream {
+    let paths = parse_macro_input!(input with Punctuated::<LitStr, Token![,]>::parse_terminated);
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+    let mut css = String::new();
+    let mut files = vec![];
+
+    // the rules of an extension are placed before the rules it extends
+    for path in paths.iter().rev() {
+        let file = root.join(path.value());
+
+        match fs::read_to_string(&file) {
+            Ok(text) => css.push_str(&text),
+            Err(e) => {
+                return syn::Error::new(
+                    path.span(),
+                    format!(
+                        "compile_theme: Could not read file {}: {}",
+                        file.display(),
+                        e
+                    ),
+                )
+                .to_compile_error()
+                .into();
+            }
+        }
+
+        files.push(file.display().to_string());
+    }
+
+    let rules = match try_parse(&css) {
+        Ok(rules) => rules,
+        Err(e) => {
+            let span = paths
+                .first()
+                .map_or_else(Span::call_site, |path| path.span());
+            return syn::Error::new(span, e).to_compile_error().into();
+        }
+    };
+    let rules = rules.iter().map(rule_tokens);
+
+    let gen = quote! {
+        {
+            // the files are included to build the theme again if they are changed
+            #(const _: &str = include_str!(#files);)*
+            static RULES: &[::orbtk::css_engine::StaticRule] = &[#(#rules),*];
+            ::orbtk::css_engine::Theme::create_from_static(RULES)
+        }
+    };
+
+    TokenStream::from(gen)
+}
@@ -0,0 +1,587 @@
+use std::{collections::BTreeSet, f64::consts::PI, fmt::Write};
+
+use image::{png::PNGEncoder, ColorType};
+
+use super::RenderTask;
+use crate::utils::*;
+
+/// Encodes the given pixels of a render ctx as PNG image.
+pub fn encode_png(data: &[u32], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    if data.len() != width as usize * height as usize {
+        return Err(format!(
+            "Could not encode png, the data does not fit into {}x{} pixels.",
+            width, height
+        ));
+    }
+
+    let mut pixels = Vec::with_capacity(data.len() * 4);
+
+    for pixel in data {
+        pixels.extend_from_slice(&unpremultiply(*pixel));
+    }
+
+    let mut png = vec![];
+    PNGEncoder::new(&mut png)
+        .encode(&pixels, width, height, ColorType::RGBA(8))
+        .map_err(|error| format!("Could not encode png: {}", error))?;
+
+    Ok(png)
+}
+
+// Converts a premultiplied ARGB pixel of the render ctx to RGBA.
+fn unpremultiply(pixel: u32) -> [u8; 4] {
+    let alpha = pixel >> 24;
+
+    if alpha == 0 {
+        return [0; 4];
+    }
+
+    let channel =
+        |shift: u32| ((((pixel >> shift) & 0xff) * 255 + alpha / 2) / alpha).min(255) as u8;
+
+    [channel(16), channel(8), channel(0), alpha as u8]
+}
+
+// Affine transformation in the order of `RenderContext2D::set_transform`.
+type Matrix = [f64; 6];
+
+const IDENTITY: Matrix = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+// Returns the transformation that applies `first` and then `second`.
+fn multiply(first: &Matrix, second: &Matrix) -> Matrix {
+    [
+        first[0] * second[0] + first[1] * second[2],
+        first[0] * second[1] + first[1] * second[3],
+        first[2] * second[0] + first[3] * second[2],
+        first[2] * second[1] + first[3] * second[3],
+        first[4] * second[0] + first[5] * second[2] + second[4],
+        first[4] * second[1] + first[5] * second[3] + second[5],
+    ]
+}
+
+fn transform_point(matrix: &Matrix, x: f64, y: f64) -> (f64, f64) {
+    (
+        x * matrix[0] + y * matrix[2] + matrix[4],
+        x * matrix[1] + y * matrix[3] + matrix[5],
+    )
+}
+
+// Returns the control and end points of the cubic bezier curves that approximate the arc, at
+// most a quarter circle per curve.
+fn arc_to_beziers(
+    x: f64,
+    y: f64,
+    radius: f64,
+    start_angle: f64,
+    end_angle: f64,
+) -> Vec<[(f64, f64); 3]> {
+    let sweep = end_angle - start_angle;
+    let count = (sweep.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+    let step = sweep / count as f64;
+    let handle = 4.0 / 3.0 * (step / 4.0).tan() * radius;
+
+    (0..count)
+        .map(|index| {
+            let start = start_angle + step * index as f64;
+            let end = start + step;
+
+            [
+                (
+                    x + radius * start.cos() - handle * start.sin(),
+                    y + radius * start.sin() + handle * start.cos(),
+                ),
+                (
+                    x + radius * end.cos() + handle * end.sin(),
+                    y + radius * end.sin() - handle * end.cos(),
+                ),
+                (x + radius * end.cos(), y + radius * end.sin()),
+            ]
+        })
+        .collect()
+}
+
+// Writes a number with at most three decimals.
+fn number(value: f64) -> String {
+    let value = format!("{:.3}", value);
+    let value = value.trim_end_matches('0').trim_end_matches('.');
+
+    if value == "-0" {
+        return "0".to_string();
+    }
+
+    value.to_string()
+}
+
+// Writes the text as PDF string, characters that are not part of latin-1 are replaced by `?`.
+fn text_string(text: &str) -> String {
+    let mut string = String::from("(");
+
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                string.push('\\');
+                string.push(c);
+            }
+            ' '..='~' => string.push(c),
+            '\u{a0}'..='\u{ff}' => {
+                let _ = write!(string, "\\{:03o}", c as u32);
+            }
+            _ => string.push('?'),
+        }
+    }
+
+    string.push(')');
+    string
+}
+
+// Returns the color that is used for the brush, gradients are drawn with their first color.
+fn brush_color(brush: &Brush) -> Color {
+    match brush {
+        Brush::SolidColor(color) => *color,
+        Brush::LinearGradient { stops, .. } => stops
+            .first()
+            .map(|stop| stop.color)
+            .unwrap_or_else(|| Color::rgba(0, 0, 0, 0)),
+    }
+}
+
+// Drawing state that is saved by `Save` and restored by `Restore`.
+#[derive(Clone)]
+struct Style {
+    fill: Color,
+    stroke: Color,
+    alpha: f32,
+    line_width: f64,
+    font_size: f64,
+}
+
+// Converts the drawing commands of the render ctx to the content of a PDF page. The canvas
+// transformation is applied to the points, because the transformation of a PDF could only be
+// concatenated and not set.
+struct PdfWriter {
+    content: String,
+    // sub-paths of the current path, they are kept after a fill like in the render ctx.
+    path: String,
+    current: (f64, f64),
+    transform: Matrix,
+    style: Style,
+    saved: Vec<Style>,
+    opacities: BTreeSet<u8>,
+}
+
+impl PdfWriter {
+    fn new() -> Self {
+        PdfWriter {
+            content: String::new(),
+            path: String::new(),
+            current: (0.0, 0.0),
+            transform: IDENTITY,
+            style: Style {
+                fill: Color::rgb(0, 0, 0),
+                stroke: Color::rgb(0, 0, 0),
+                alpha: 1.0,
+                line_width: 1.0,
+                font_size: 12.0,
+            },
+            saved: vec![],
+            opacities: BTreeSet::new(),
+        }
+    }
+
+    fn point(&self, x: f64, y: f64) -> String {
+        let (x, y) = transform_point(&self.transform, x, y);
+        format!("{} {}", number(x), number(y))
+    }
+
+    fn move_to(&mut self, x: f64, y: f64) {
+        let point = self.point(x, y);
+        let _ = writeln!(self.path, "{} m", point);
+        self.current = (x, y);
+    }
+
+    fn line_to(&mut self, x: f64, y: f64) {
+        if self.path.is_empty() {
+            self.move_to(x, y);
+            return;
+        }
+
+        let point = self.point(x, y);
+        let _ = writeln!(self.path, "{} l", point);
+        self.current = (x, y);
+    }
+
+    fn bezier_curve_to(&mut self, cp1: (f64, f64), cp2: (f64, f64), end: (f64, f64)) {
+        if self.path.is_empty() {
+            self.move_to(cp1.0, cp1.1);
+        }
+
+        let points = [
+            self.point(cp1.0, cp1.1),
+            self.point(cp2.0, cp2.1),
+            self.point(end.0, end.1),
+        ];
+        let _ = writeln!(self.path, "{} {} {} c", points[0], points[1], points[2]);
+        self.current = end;
+    }
+
+    fn rect_path(&self, x: f64, y: f64, width: f64, height: f64) -> String {
+        format!(
+            "{} m\n{} l\n{} l\n{} l\nh\n",
+            self.point(x, y),
+            self.point(x + width, y),
+            self.point(x + width, y + height),
+            self.point(x, y + height)
+        )
+    }
+
+    // Sets the color and opacity for the next fill or stroke. Returns `false` if it is invisible.
+    fn paint_style(&mut self, color: Color, operator: &str) -> bool {
+        let opacity = (color.a() as f32 * self.style.alpha.max(0.0).min(1.0)).round() as u8;
+
+        if opacity == 0 {
+            return false;
+        }
+
+        self.opacities.insert(opacity);
+
+        let _ = writeln!(
+            self.content,
+            "/GS{} gs\n{} {} {} {}",
+            opacity,
+            number(color.r() as f64 / 255.0),
+            number(color.g() as f64 / 255.0),
+            number(color.b() as f64 / 255.0),
+            operator
+        );
+
+        true
+    }
+
+    fn fill(&mut self, path: &str) {
+        if path.is_empty() || !self.paint_style(self.style.fill, "rg") {
+            return;
+        }
+
+        let _ = writeln!(self.content, "{}f", path);
+    }
+
+    fn stroke(&mut self, path: &str) {
+        if path.is_empty() || !self.paint_style(self.style.stroke, "RG") {
+            return;
+        }
+
+        // the line width is scaled like the points
+        let scale = (self.transform[0] * self.transform[3] - self.transform[1] * self.transform[2])
+            .abs()
+            .sqrt();
+        let _ = writeln!(
+            self.content,
+            "{} w\n{}S",
+            number(self.style.line_width * scale),
+            path
+        );
+    }
+
+    fn fill_text(&mut self, text: &str, x: f64, y: f64) {
+        if text.is_empty() || !self.paint_style(self.style.fill, "rg") {
+            return;
+        }
+
+        // the render ctx positions the text by its top, the baseline is about one font size
+        // below, the y axis of the text is flipped back
+        let size = self.style.font_size;
+        let matrix = multiply(&[size, 0.0, 0.0, -size, x, y + size], &self.transform);
+        let matrix: Vec<String> = matrix.iter().map(|value| number(*value)).collect();
+
+        let _ = writeln!(
+            self.content,
+            "BT\n/F1 1 Tf\n{} Tm\n{} Tj\nET",
+            matrix.join(" "),
+            text_string(text)
+        );
+    }
+
+    fn write(&mut self, task: &RenderTask, width: f64, height: f64) {
+        match task {
+            RenderTask::FillRect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let path = self.rect_path(*x, *y, *width, *height);
+                self.fill(&path);
+            }
+            RenderTask::StrokeRect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let path = self.rect_path(*x, *y, *width, *height);
+                self.stroke(&path);
+            }
+            RenderTask::FillText { text, x, y } => self.fill_text(text, *x, *y),
+            RenderTask::Fill() => {
+                let path = self.path.clone();
+                self.fill(&path);
+            }
+            RenderTask::Stroke() => {
+                let path = self.path.clone();
+                self.stroke(&path);
+            }
+            RenderTask::BeginPath() => self.path.clear(),
+            RenderTask::ClosePath() => {
+                if !self.path.is_empty() {
+                    self.path.push_str("h\n");
+                }
+            }
+            RenderTask::Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let path = self.rect_path(*x, *y, *width, *height);
+                self.path.push_str(&path);
+                self.current = (*x, *y);
+            }
+            RenderTask::Arc {
+                x,
+                y,
+                radius,
+                start_angle,
+                end_angle,
+            } => {
+                // the arc is connected with a line to the current sub-path
+                self.line_to(
+                    x + radius * start_angle.cos(),
+                    y + radius * start_angle.sin(),
+                );
+
+                for [cp1, cp2, end] in arc_to_beziers(*x, *y, *radius, *start_angle, *end_angle) {
+                    self.bezier_curve_to(cp1, cp2, end);
+                }
+            }
+            RenderTask::MoveTo { x, y } => self.move_to(*x, *y),
+            RenderTask::LineTo { x, y } => self.line_to(*x, *y),
+            RenderTask::QuadraticCurveTo { cpx, cpy, x, y } => {
+                let start = self.current;
+                self.bezier_curve_to(
+                    (
+                        start.0 + 2.0 / 3.0 * (cpx - start.0),
+                        start.1 + 2.0 / 3.0 * (cpy - start.1),
+                    ),
+                    (x + 2.0 / 3.0 * (cpx - x), y + 2.0 / 3.0 * (cpy - y)),
+                    (*x, *y),
+                );
+            }
+            RenderTask::BesierCurveTo {
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            } => self.bezier_curve_to((*cp1x, *cp1y), (*cp2x, *cp2y), (*x, *y)),
+            RenderTask::Clip() => {
+                let path = if self.path.is_empty() {
+                    "0 0 m\nh\n".to_string()
+                } else {
+                    self.path.clone()
+                };
+                let _ = writeln!(self.content, "{}W n", path);
+            }
+            RenderTask::SetLineWidth { line_width } => self.style.line_width = *line_width,
+            RenderTask::SetAlpha { alpha } => self.style.alpha = *alpha,
+            RenderTask::SetFontSize { size } => self.style.font_size = *size,
+            RenderTask::SetFillStyle { fill_style } => self.style.fill = brush_color(fill_style),
+            RenderTask::SetStrokeStyle { stroke_style } => {
+                self.style.stroke = brush_color(stroke_style)
+            }
+            RenderTask::Save() => {
+                self.saved.push(self.style.clone());
+                self.content.push_str("q\n");
+            }
+            RenderTask::Restore() => {
+                if let Some(style) = self.saved.pop() {
+                    self.style = style;
+                    self.content.push_str("Q\n");
+                }
+            }
+            RenderTask::Clear { brush } => {
+                let transform = self.transform;
+                self.transform = IDENTITY;
+                let path = self.rect_path(0.0, 0.0, width, height);
+                let fill = self.style.fill;
+                self.style.fill = brush_color(brush);
+                self.fill(&path);
+                self.style.fill = fill;
+                self.transform = transform;
+            }
+            RenderTask::SetTransform {
+                h_scaling,
+                h_skewing,
+                v_skewing,
+                v_scaling,
+                h_moving,
+                v_moving,
+            } => {
+                self.transform = [
+                    *h_scaling, *h_skewing, *v_skewing, *v_scaling, *h_moving, *v_moving,
+                ];
+            }
+            _ => {}
+        }
+    }
+}
+
+// Writes the drawing commands as PDF document with one page. The page shows the area of the
+// given size in pixels at (0, 0).
+pub(super) fn write_pdf(
+    tasks: &[RenderTask],
+    width: f64,
+    height: f64,
+    points_per_pixel: f64,
+) -> Vec<u8> {
+    let mut writer = PdfWriter::new();
+
+    // maps the pixels with the y axis down to the points of the page
+    let _ = writeln!(
+        writer.content,
+        "{} 0 0 {} 0 {} cm",
+        number(points_per_pixel),
+        number(-points_per_pixel),
+        number(height * points_per_pixel)
+    );
+
+    for task in tasks {
+        writer.write(task, width, height);
+    }
+
+    for _ in 0..writer.saved.len() {
+        writer.content.push_str("Q\n");
+    }
+
+    let opacities: String = writer
+        .opacities
+        .iter()
+        .map(|opacity| {
+            format!(
+                " /GS{} << /Type /ExtGState /ca {} /CA {} >>",
+                opacity,
+                number(*opacity as f64 / 255.0),
+                number(*opacity as f64 / 255.0)
+            )
+        })
+        .collect();
+
+    let objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 4 0 R >> /ExtGState <<{} >> >> /Contents 5 0 R >>",
+            number(width * points_per_pixel),
+            number(height * points_per_pixel),
+            opacities
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            writer.content.len(),
+            writer.content
+        ),
+    ];
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = vec![];
+
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        let _ = writeln!(pdf, "{} 0 obj\n{}\nendobj", index + 1, object);
+    }
+
+    let xref = pdf.len();
+    let _ = writeln!(pdf, "xref\n0 {}\n0000000000 65535 f ", objects.len() + 1);
+
+    for offset in offsets {
+        let _ = writeln!(pdf, "{:010} 00000 n ", offset);
+    }
+
+    let _ = writeln!(
+        pdf,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF",
+        objects.len() + 1,
+        xref
+    );
+
+    pdf.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unpremultiply() {
+        assert_eq!(unpremultiply(0xff11_2233), [0x11, 0x22, 0x33, 0xff]);
+        assert_eq!(unpremultiply(0x8040_0000), [0x80, 0, 0, 0x80]);
+        assert_eq!(unpremultiply(0x0012_3456), [0; 4]);
+    }
+
+    #[test]
+    fn test_arc_to_beziers() {
+        let beziers = arc_to_beziers(0.0, 0.0, 10.0, 0.0, PI);
+        assert_eq!(beziers.len(), 2);
+
+        // the curves end on the circle
+        let end = beziers[0][2];
+        assert!(end.0.abs() < 1e-9 && (end.1 - 10.0).abs() < 1e-9);
+        let end = beziers[1][2];
+        assert!((end.0 + 10.0).abs() < 1e-9 && end.1.abs() < 1e-9);
+
+        // handle of a quarter circle
+        assert!((beziers[0][0].1 - 5.5228).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_write_pdf() {
+        let tasks = vec![
+            RenderTask::SetFillStyle {
+                fill_style: Brush::SolidColor(Color::rgb(255, 0, 0)),
+            },
+            RenderTask::SetTransform {
+                h_scaling: 2.0,
+                h_skewing: 0.0,
+                v_skewing: 0.0,
+                v_scaling: 2.0,
+                h_moving: 0.0,
+                v_moving: 0.0,
+            },
+            RenderTask::FillRect {
+                x: 1.0,
+                y: 2.0,
+                width: 3.0,
+                height: 4.0,
+            },
+            RenderTask::FillText {
+                text: "(a)".to_string(),
+                x: 0.0,
+                y: 0.0,
+            },
+        ];
+
+        let pdf = String::from_utf8(write_pdf(&tasks, 100.0, 50.0, 0.75)).unwrap();
+
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.contains("/MediaBox [0 0 75 37.5]"));
+        assert!(pdf.contains("/GS255 gs\n1 0 0 rg\n2 4 m\n8 4 l\n8 12 l\n2 12 l\nh\nf\n"));
+        assert!(pdf.contains("24 0 0 -24 0 24 Tm\n(\\(a\\)) Tj"));
+
+        // the cross reference table points to the objects
+        let offset = pdf.find("3 0 obj").unwrap();
+        assert!(pdf.contains(&format!("{:010} 00000 n \n", offset)));
+    }
+}
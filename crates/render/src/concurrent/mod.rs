@@ -6,6 +6,10 @@ use std::{
 use crate::{platform, utils::*, Pipeline, RenderTarget, TextMetrics};
 use platform::Image;
 
+pub use self::export::encode_png;
+
+mod export;
+
 #[derive(Clone)]
 struct PipelineWrapper(pub Box<dyn Pipeline>);
 
@@ -91,6 +95,13 @@ enum RenderTask {
         x: f64,
         y: f64,
     },
+    DrawRenderTargetWithSize {
+        render_target: RenderTarget,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
     DrawImage {
         image: Image,
         x: f64,
@@ -109,6 +120,22 @@ enum RenderTask {
         height: f64,
         pipeline: PipelineWrapper,
     },
+    BeginLayer {
+        id: u64,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    EndLayer(),
+    DrawLayer {
+        id: u64,
+        x: f64,
+        y: f64,
+    },
+    RemoveLayer {
+        id: u64,
+    },
     Clip(),
     SetLineWidth {
         line_width: f64,
@@ -161,6 +188,7 @@ fn is_single_tasks(task: &RenderTask) -> bool {
         RenderTask::Resize { .. } => true,
         RenderTask::RegisterFont { .. } => true,
         RenderTask::DrawRenderTarget { .. } => true,
+        RenderTask::DrawRenderTargetWithSize { .. } => true,
         RenderTask::DrawImage { .. } => true,
         RenderTask::DrawImageWithClip { .. } => true,
         RenderTask::DrawPipeline { .. } => true,
@@ -207,6 +235,21 @@ impl RenderWorker {
                         } => {
                             render_context_2_d.draw_render_target(&render_target, x, y);
                         }
+                        RenderTask::DrawRenderTargetWithSize {
+                            render_target,
+                            x,
+                            y,
+                            width,
+                            height,
+                        } => {
+                            render_context_2_d.draw_render_target_with_size(
+                                &render_target,
+                                x,
+                                y,
+                                width,
+                                height,
+                            );
+                        }
                         RenderTask::DrawImage { image, x, y } => {
                             render_context_2_d.draw_image(&image, x, y);
                         }
@@ -340,9 +383,39 @@ impl RenderWorker {
                             RenderTask::Restore() => {
                                 render_context_2_d.restore();
                             }
+                            RenderTask::SetTransform {
+                                h_scaling,
+                                h_skewing,
+                                v_skewing,
+                                v_scaling,
+                                h_moving,
+                                v_moving,
+                            } => {
+                                render_context_2_d.set_transform(
+                                    h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+                                );
+                            }
                             RenderTask::Clear { brush } => {
                                 render_context_2_d.clear(&brush);
                             }
+                            RenderTask::BeginLayer {
+                                id,
+                                x,
+                                y,
+                                width,
+                                height,
+                            } => {
+                                render_context_2_d.begin_layer(id, x, y, width, height);
+                            }
+                            RenderTask::EndLayer() => {
+                                render_context_2_d.end_layer();
+                            }
+                            RenderTask::DrawLayer { id, x, y } => {
+                                render_context_2_d.draw_layer(id, x, y);
+                            }
+                            RenderTask::RemoveLayer { id } => {
+                                render_context_2_d.remove_layer(id);
+                            }
                             RenderTask::Finish() => {
                                 sender
                                     .lock()
@@ -373,6 +446,9 @@ pub struct RenderContext2D {
     result_receiver: mpsc::Receiver<RenderResult>,
     tasks: Vec<RenderTask>,
     measure_context: platform::RenderContext2D,
+    fonts: Vec<(String, &'static [u8])>,
+    // drawing commands that are sent since `start_recording`.
+    recording: Option<Vec<RenderTask>>,
 }
 
 impl Drop for RenderContext2D {
@@ -405,24 +481,46 @@ impl RenderContext2D {
             result_receiver,
             tasks: vec![],
             measure_context: platform::RenderContext2D::new(width, height),
+            fonts: vec![],
+            recording: None,
         }
     }
 
+    /// Creates a new render ctx with the given size and the fonts of this ctx, e.g. to render
+    /// widgets offscreen.
+    pub fn offscreen(&self, width: f64, height: f64) -> Self {
+        let mut render_context_2_d = RenderContext2D::new(width, height);
+
+        for (family, font_file) in &self.fonts {
+            render_context_2_d.register_font(family, *font_file);
+        }
+
+        render_context_2_d
+    }
+
+    // Sends the render tasks to the render thread and records them if a recording is started.
+    fn send(&mut self, tasks: Vec<RenderTask>, message: &str) {
+        if let Some(recording) = &mut self.recording {
+            recording.extend(tasks.iter().cloned());
+        }
+
+        self.sender.send(tasks).expect(message);
+    }
+
     // Sends a render task to the render thread.
     fn send_tasks(&mut self) {
         if !self.tasks.is_empty() {
-            self.sender
-                .send(self.tasks.to_vec())
-                .expect("Could not send render task.");
-            self.tasks.clear();
+            let tasks = self.tasks.drain(..).collect();
+            self.send(tasks, "Could not send render task.");
         }
     }
 
     /// Starts a new render pipeline.
     pub fn start(&mut self) {
-        self.sender
-            .send(vec![RenderTask::Start()])
-            .expect("Could not send start ot render thread.");
+        self.send(
+            vec![RenderTask::Start()],
+            "Could not send start ot render thread.",
+        );
     }
 
     /// Finishes the current render pipeline.
@@ -433,20 +531,31 @@ impl RenderContext2D {
 
     /// Resizes the render ctx.
     pub fn resize(&mut self, width: f64, height: f64) {
-        self.sender
-            .send(vec![RenderTask::Resize { width, height }])
-            .expect("Could not send resize to render thread.");
+        self.send(
+            vec![RenderTask::Resize { width, height }],
+            "Could not send resize to render thread.",
+        );
     }
 
     /// Registers a new font file.
     pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
         self.measure_context.register_font(family, font_file);
-        self.sender
-            .send(vec![RenderTask::RegisterFont {
+
+        if !self
+            .fonts
+            .iter()
+            .any(|(registered, _)| registered == family)
+        {
+            self.fonts.push((family.to_string(), font_file));
+        }
+
+        self.send(
+            vec![RenderTask::RegisterFont {
                 family: family.to_string(),
                 font_file,
-            }])
-            .expect("Could not send register font to render thread.");
+            }],
+            "Could not send register font to render thread.",
+        );
     }
 
     // Rectangles
@@ -569,39 +678,92 @@ impl RenderContext2D {
         });
     }
 
+    // Layers
+
+    /// Starts to render into the offscreen layer with the given id and size, the origin of the
+    /// layer is at (0, 0). `end_layer` keeps the layer and draws it at the given position.
+    pub fn begin_layer(&mut self, id: u64, x: f64, y: f64, width: f64, height: f64) {
+        self.tasks.push(RenderTask::BeginLayer {
+            id,
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    /// Finishes the current layer, keeps it and draws it at its position.
+    pub fn end_layer(&mut self) {
+        self.tasks.push(RenderTask::EndLayer());
+    }
+
+    /// Draws the kept layer with the given id at the given position.
+    pub fn draw_layer(&mut self, id: u64, x: f64, y: f64) {
+        self.tasks.push(RenderTask::DrawLayer { id, x, y });
+    }
+
+    /// Removes the kept layer with the given id.
+    pub fn remove_layer(&mut self, id: u64) {
+        self.tasks.push(RenderTask::RemoveLayer { id });
+    }
+
     // Draw image
 
     pub fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {
-        self.sender
-            .send(vec![RenderTask::DrawRenderTarget {
+        self.send(
+            vec![RenderTask::DrawRenderTarget {
+                render_target: render_target.clone(),
+                x,
+                y,
+            }],
+            "Could not send render target to render thread.",
+        );
+    }
+
+    /// Draws the render target scaled to the given size.
+    pub fn draw_render_target_with_size(
+        &mut self,
+        render_target: &RenderTarget,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.send(
+            vec![RenderTask::DrawRenderTargetWithSize {
                 render_target: render_target.clone(),
                 x,
                 y,
-            }])
-            .expect("Could not send render target to render thread.");
+                width,
+                height,
+            }],
+            "Could not send render target to render thread.",
+        );
     }
 
     /// Draws the image.
     pub fn draw_image(&mut self, image: &mut Image, x: f64, y: f64) {
-        self.sender
-            .send(vec![RenderTask::DrawImage {
+        self.send(
+            vec![RenderTask::DrawImage {
                 image: image.clone(),
                 x,
                 y,
-            }])
-            .expect("Could not send image to render thread.");
+            }],
+            "Could not send image to render thread.",
+        );
     }
 
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &mut Image, clip: Rectangle, x: f64, y: f64) {
-        self.sender
-            .send(vec![RenderTask::DrawImageWithClip {
+        self.send(
+            vec![RenderTask::DrawImageWithClip {
                 image: image.clone(),
                 clip,
                 x,
                 y,
-            }])
-            .expect("Could not send clipped image to render thread.");
+            }],
+            "Could not send clipped image to render thread.",
+        );
     }
 
     pub fn draw_pipeline(
@@ -612,15 +774,16 @@ impl RenderContext2D {
         height: f64,
         pipeline: Box<dyn Pipeline>,
     ) {
-        self.sender
-            .send(vec![RenderTask::DrawPipeline {
+        self.send(
+            vec![RenderTask::DrawPipeline {
                 x,
                 y,
                 width,
                 height,
                 pipeline: PipelineWrapper(pipeline),
-            }])
-            .expect("Could not send draw_pipeline to render thread.");
+            }],
+            "Could not send draw_pipeline to render thread.",
+        );
     }
 
     /// Creates a clipping path from the current sub-paths. Everything drawn after clip() is called appears inside the clipping path only.
@@ -711,6 +874,43 @@ impl RenderContext2D {
         }
     }
 
+    /// Waits until the render thread has finished the current render pipeline and returns the
+    /// rendered pixels. Returns `None` if the render thread is stopped.
+    pub fn wait_data(&mut self) -> Option<&[u32]> {
+        if let Ok(RenderResult::Finish { data }) = self.result_receiver.recv() {
+            self.output = data;
+            Some(&self.output)
+        } else {
+            None
+        }
+    }
+
+    // Recording
+
+    /// Starts to record the drawing commands, e.g. to export them with `finish_recording_pdf`.
+    pub fn start_recording(&mut self) {
+        self.recording = Some(vec![]);
+    }
+
+    /// Stops the recording and returns the recorded drawing commands as PDF document. The page
+    /// shows the area of the given size in pixels at (0, 0), `points_per_pixel` defines the size
+    /// of the page. Images and pipelines are left out.
+    pub fn finish_recording_pdf(
+        &mut self,
+        width: f64,
+        height: f64,
+        points_per_pixel: f64,
+    ) -> Vec<u8> {
+        self.send_tasks();
+
+        export::write_pdf(
+            &self.recording.take().unwrap_or_default(),
+            width,
+            height,
+            points_per_pixel,
+        )
+    }
+
     pub fn data_mut(&mut self) -> &mut [u32] {
         &mut self.output
     }
@@ -24,8 +24,10 @@ pub use platform::RenderContext2D;
 pub mod platform;
 
 pub use self::render_target::*;
+pub use self::svg::*;
 
 mod render_target;
+mod svg;
 
 /// Defines the current configuration of the render ctx.
 #[derive(Debug, Clone)]
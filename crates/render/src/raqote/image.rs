@@ -56,14 +56,32 @@ impl Image {
 
     /// Load an image from file path. Supports BMP and PNG
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, String> {
-        let img = image::open(path);
+        let img = image::open(path.as_ref());
         if let Ok(img) = img {
-            return Self::from_rgba_image(img.to_rgba());
+            let mut image = Self::from_rgba_image(img.to_rgba())?;
+            image.source = path.as_ref().to_string_lossy().to_string();
+            return Ok(image);
         }
 
         Err("Could not load image.".to_string())
     }
 
+    /// Load an image from the bytes of an image file, e.g. embedded with `include_bytes!`.
+    /// Supports BMP and PNG
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let img = image::load_from_memory(bytes)
+            .map_err(|_| "Image.from_bytes: Could not load image.".to_string())?;
+
+        // the source identifies the image for comparisons
+        let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+        let mut image = Self::from_rgba_image(img.to_rgba())?;
+        image.source = format!("memory:{:x}", hash);
+        Ok(image)
+    }
+
     /// Gets the width.
     pub fn width(&self) -> f64 {
         self.render_target.width() as f64
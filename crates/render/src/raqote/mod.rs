@@ -10,6 +10,16 @@ pub use self::image::Image;
 mod font;
 mod image;
 
+// Draw target and clip state that are replaced by an unfinished layer.
+struct Layer {
+    id: u64,
+    x: f64,
+    y: f64,
+    draw_target: raqote::DrawTarget,
+    clip: bool,
+    clip_rect: Option<Rectangle>,
+}
+
 /// The RenderContext2D trait, provides the rendering ctx. It is used for drawing shapes, text, images, and other objects.
 pub struct RenderContext2D {
     draw_target: raqote::DrawTarget,
@@ -18,6 +28,11 @@ pub struct RenderContext2D {
     saved_config: Option<RenderConfig>,
     fonts: HashMap<String, Font>,
 
+    // offscreen layers, the targets of the unfinished layers are stacked with their ids and
+    // positions
+    layers: HashMap<u64, RenderTarget>,
+    layer_stack: Vec<Layer>,
+
     // hack / work around for faster text clipping
     clip: bool,
     last_rect: Rectangle,
@@ -36,6 +51,8 @@ impl RenderContext2D {
             config: RenderConfig::default(),
             saved_config: None,
             fonts: HashMap::new(),
+            layers: HashMap::new(),
+            layer_stack: vec![],
             clip: false,
             last_rect: Rectangle::new(0.0, 0.0, width, height),
             clip_rect: None,
@@ -88,6 +105,22 @@ impl RenderContext2D {
             return;
         }
 
+        // the text is drawn directly into the pixels, so it is moved and scaled by the
+        // transformation of the draw target here
+        let (m11, m12, m21, m22, m31, m32) = {
+            let transform = self.draw_target.get_transform();
+            (
+                transform.m11 as f64,
+                transform.m12 as f64,
+                transform.m21 as f64,
+                transform.m22 as f64,
+                transform.m31 as f64,
+                transform.m32 as f64,
+            )
+        };
+        let position = (x * m11 + y * m21 + m31, x * m12 + y * m22 + m32);
+        let font_size = self.config.font_config.font_size * (m11 * m22 - m12 * m21).abs().sqrt();
+
         if let Some(font) = self.fonts.get(&self.config.font_config.family) {
             let width = self.draw_target.width() as f64;
 
@@ -97,17 +130,22 @@ impl RenderContext2D {
                         text,
                         self.draw_target.get_data_mut(),
                         width,
-                        (self.config.font_config.font_size, color, self.config.alpha),
-                        (x, y),
-                        rect,
+                        (font_size, color, self.config.alpha),
+                        position,
+                        Rectangle::new(
+                            rect.x() * m11 + m31,
+                            rect.y() * m22 + m32,
+                            rect.width() * m11,
+                            rect.height() * m22,
+                        ),
                     );
                 } else {
                     font.render_text(
                         text,
                         self.draw_target.get_data_mut(),
                         width,
-                        (self.config.font_config.font_size, color, self.config.alpha),
-                        (x, y),
+                        (font_size, color, self.config.alpha),
+                        position,
                     );
                 }
             } else {
@@ -115,8 +153,8 @@ impl RenderContext2D {
                     text,
                     self.draw_target.get_data_mut(),
                     width,
-                    (self.config.font_config.font_size, color, self.config.alpha),
-                    (x, y),
+                    (font_size, color, self.config.alpha),
+                    position,
                 );
             }
         }
@@ -256,6 +294,94 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the render target scaled to the given size.
+    pub fn draw_render_target_with_size(
+        &mut self,
+        render_target: &RenderTarget,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.draw_target.draw_image_with_size_at(
+            width as f32,
+            height as f32,
+            x as f32,
+            y as f32,
+            &raqote::Image {
+                data: &render_target.data(),
+                width: render_target.width() as i32,
+                height: render_target.height() as i32,
+            },
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+    }
+
+    // Layers
+
+    /// Starts to render into the offscreen layer with the given id and size, the origin of the
+    /// layer is at (0, 0). `end_layer` keeps the layer and draws it at the given position.
+    pub fn begin_layer(&mut self, id: u64, x: f64, y: f64, width: f64, height: f64) {
+        let draw_target =
+            raqote::DrawTarget::new(width.ceil().max(1.0) as i32, height.ceil().max(1.0) as i32);
+
+        self.layer_stack.push(Layer {
+            id,
+            x,
+            y,
+            draw_target: std::mem::replace(&mut self.draw_target, draw_target),
+            clip: self.clip,
+            clip_rect: self.clip_rect.take(),
+        });
+        self.clip = false;
+    }
+
+    /// Finishes the current layer, keeps it and draws it at its position.
+    pub fn end_layer(&mut self) {
+        if let Some(layer) = self.layer_stack.pop() {
+            let draw_target = std::mem::replace(&mut self.draw_target, layer.draw_target);
+            self.clip = layer.clip;
+            self.clip_rect = layer.clip_rect;
+
+            let render_target = RenderTarget::from_data(
+                draw_target.width() as u32,
+                draw_target.height() as u32,
+                draw_target.get_data().to_vec(),
+            )
+            .unwrap_or_default();
+
+            self.layers.insert(layer.id, render_target);
+            self.draw_layer(layer.id, layer.x, layer.y);
+        }
+    }
+
+    /// Draws the kept layer with the given id at the given position.
+    pub fn draw_layer(&mut self, id: u64, x: f64, y: f64) {
+        if let Some(render_target) = self.layers.get(&id) {
+            self.draw_target.draw_image_at(
+                x as f32,
+                y as f32,
+                &raqote::Image {
+                    data: &render_target.data(),
+                    width: render_target.width() as i32,
+                    height: render_target.height() as i32,
+                },
+                &raqote::DrawOptions {
+                    alpha: self.config.alpha,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    /// Removes the kept layer with the given id.
+    pub fn remove_layer(&mut self, id: u64) {
+        self.layers.remove(&id);
+    }
+
     /// Draws the image.
     pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
         self.draw_target.draw_image_at(
@@ -0,0 +1,1169 @@
+use std::{f64::consts::PI, fmt, fs, path::Path};
+
+use crate::{utils::*, RenderContext2D};
+
+// Factor of the control points of a cubic bezier curve that approximates a quarter of a circle.
+const KAPPA: f64 = 0.552_284_749_8;
+
+// Elements whose content is not drawn.
+static SKIPPED_ELEMENTS: [&str; 12] = [
+    "clipPath",
+    "defs",
+    "desc",
+    "linearGradient",
+    "marker",
+    "mask",
+    "metadata",
+    "pattern",
+    "radialGradient",
+    "style",
+    "symbol",
+    "title",
+];
+
+/// Describes how a shape of a svg is filled or stroked.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SvgPaint {
+    /// Paints with a fixed color.
+    Color(Color),
+
+    /// Paints with the color given by the widget, e.g. to draw an icon in the color of the theme.
+    CurrentColor,
+}
+
+impl SvgPaint {
+    /// Returns the brush of the paint.
+    pub fn brush(&self, current_color: &Brush) -> Brush {
+        match self {
+            SvgPaint::Color(color) => Brush::SolidColor(*color),
+            SvgPaint::CurrentColor => current_color.clone(),
+        }
+    }
+}
+
+/// Describes a segment of the outline of a svg shape. All points are in the coordinates of the
+/// view box.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SvgCommand {
+    MoveTo(Point),
+    LineTo(Point),
+    QuadraticCurveTo(Point, Point),
+    BezierCurveTo(Point, Point, Point),
+    Close,
+}
+
+/// Describes a filled and / or stroked outline of a svg.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SvgShape {
+    pub commands: Vec<SvgCommand>,
+    pub fill: Option<SvgPaint>,
+    pub stroke: Option<SvgPaint>,
+    pub stroke_width: f64,
+}
+
+/// Scalable vector graphic that is parsed from svg markup. It supports the subset that is used
+/// by icons: paths, basic shapes, groups, fills, strokes and transforms. Gradients, clipping,
+/// masks and text are not drawn.
+#[derive(Clone, Default, PartialEq)]
+pub struct Svg {
+    width: f64,
+    height: f64,
+    view_box: Rectangle,
+    shapes: Vec<SvgShape>,
+}
+
+impl fmt::Debug for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Svg ( width: {}, height: {}, shapes: {})",
+            self.width,
+            self.height,
+            self.shapes.len()
+        )
+    }
+}
+
+impl Svg {
+    /// Parses the given svg markup, e.g. embedded with `include_str!`.
+    pub fn from_markup(markup: &str) -> Result<Self, String> {
+        let mut svg = None;
+        let mut styles: Vec<Style> = vec![];
+        let mut shapes = vec![];
+        let mut skip_depth = 0;
+        let mut rest = markup;
+
+        while let Some(start) = rest.find('<') {
+            rest = &rest[start..];
+
+            // comments, declarations and character data
+            let end_marker = if rest.starts_with("<!--") {
+                Some("-->")
+            } else if rest.starts_with("<![CDATA[") {
+                Some("]]>")
+            } else if rest.starts_with("<?") || rest.starts_with("<!") {
+                Some(">")
+            } else {
+                None
+            };
+
+            if let Some(end_marker) = end_marker {
+                rest = rest
+                    .find(end_marker)
+                    .map_or("", |end| &rest[end + end_marker.len()..]);
+                continue;
+            }
+
+            let end = rest
+                .find('>')
+                .ok_or_else(|| "Svg.from_markup: An element is not closed.".to_string())?;
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+
+            if tag.starts_with('/') {
+                if skip_depth > 0 {
+                    skip_depth -= 1;
+                } else {
+                    styles.pop();
+                }
+
+                continue;
+            }
+
+            let self_closing = tag.ends_with('/');
+            let (name, attributes) = parse_tag(tag.trim_end_matches('/'));
+
+            if skip_depth > 0 || SKIPPED_ELEMENTS.contains(&name) {
+                if !self_closing {
+                    skip_depth += 1;
+                }
+
+                continue;
+            }
+
+            let style = styles
+                .last()
+                .cloned()
+                .unwrap_or_default()
+                .apply(&attributes);
+
+            if name == "svg" && svg.is_none() {
+                svg = Some(Svg::from_attributes(&attributes)?);
+            } else if let Some(commands) = shape_commands(name, &attributes) {
+                shapes.push(style.shape(commands));
+            }
+
+            if !self_closing {
+                styles.push(style);
+            }
+        }
+
+        let mut svg =
+            svg.ok_or_else(|| "Svg.from_markup: The markup contains no svg element.".to_string())?;
+        svg.shapes = shapes;
+        Ok(svg)
+    }
+
+    /// Loads a svg from the given file path.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let markup = fs::read_to_string(path)
+            .map_err(|_| "Svg.from_path: Could not read the file.".to_string())?;
+        Svg::from_markup(markup.as_str())
+    }
+
+    // Reads the size and the view box of the root element.
+    fn from_attributes(attributes: &[(&str, &str)]) -> Result<Self, String> {
+        let view_box = attribute(attributes, "viewBox").map(|view_box| {
+            let mut parser = NumberParser::new(view_box);
+            (0..4).filter_map(|_| parser.number()).collect::<Vec<f64>>()
+        });
+
+        // sizes in percent are relative to the parent and replaced by the view box
+        let length = |name| {
+            attribute(attributes, name)
+                .filter(|value| !value.trim_end().ends_with('%'))
+                .and_then(|value| NumberParser::new(value).number())
+        };
+
+        let view_box = match (view_box, length("width"), length("height")) {
+            (Some(view_box), _, _) if view_box.len() == 4 => {
+                Rectangle::new(view_box[0], view_box[1], view_box[2], view_box[3])
+            }
+            (_, Some(width), Some(height)) => Rectangle::new(0.0, 0.0, width, height),
+            _ => {
+                return Err("Svg.from_markup: The svg element has no size.".to_string());
+            }
+        };
+
+        Ok(Svg {
+            width: length("width").unwrap_or_else(|| view_box.width()),
+            height: length("height").unwrap_or_else(|| view_box.height()),
+            view_box,
+            shapes: vec![],
+        })
+    }
+
+    /// Gets the width.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Gets the height.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Gets the area of the drawing that is mapped to the size of the svg.
+    pub fn view_box(&self) -> Rectangle {
+        self.view_box
+    }
+
+    /// Gets the shapes in drawing order.
+    pub fn shapes(&self) -> &[SvgShape] {
+        &self.shapes
+    }
+
+    /// Draws the svg scaled to the given size, it keeps its aspect ratio and is centered. The
+    /// `current_color` is used by shapes that are painted with `currentColor`.
+    pub fn draw(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        current_color: &Brush,
+    ) {
+        if self.view_box.width() <= 0.0 || self.view_box.height() <= 0.0 {
+            return;
+        }
+
+        let scale = (width / self.view_box.width()).min(height / self.view_box.height());
+        let offset_x =
+            x + (width - self.view_box.width() * scale) / 2.0 - self.view_box.x() * scale;
+        let offset_y =
+            y + (height - self.view_box.height() * scale) / 2.0 - self.view_box.y() * scale;
+        let map = |point: &Point| (offset_x + point.x * scale, offset_y + point.y * scale);
+
+        for shape in &self.shapes {
+            render_context_2_d.begin_path();
+
+            for command in &shape.commands {
+                match command {
+                    SvgCommand::MoveTo(point) => {
+                        let (x, y) = map(point);
+                        render_context_2_d.move_to(x, y);
+                    }
+                    SvgCommand::LineTo(point) => {
+                        let (x, y) = map(point);
+                        render_context_2_d.line_to(x, y);
+                    }
+                    SvgCommand::QuadraticCurveTo(control, point) => {
+                        let ((cpx, cpy), (x, y)) = (map(control), map(point));
+                        render_context_2_d.quadratic_curve_to(cpx, cpy, x, y);
+                    }
+                    SvgCommand::BezierCurveTo(control_1, control_2, point) => {
+                        let ((cp1x, cp1y), (cp2x, cp2y), (x, y)) =
+                            (map(control_1), map(control_2), map(point));
+                        render_context_2_d.bezier_curve_to(cp1x, cp1y, cp2x, cp2y, x, y);
+                    }
+                    SvgCommand::Close => render_context_2_d.close_path(),
+                }
+            }
+
+            if let Some(fill) = &shape.fill {
+                render_context_2_d.set_fill_style(fill.brush(current_color));
+                render_context_2_d.fill();
+            }
+
+            if let Some(stroke) = &shape.stroke {
+                render_context_2_d.set_stroke_style(stroke.brush(current_color));
+                render_context_2_d.set_line_width(shape.stroke_width * scale);
+                render_context_2_d.stroke();
+            }
+        }
+    }
+}
+
+impl From<&str> for Svg {
+    fn from(s: &str) -> Svg {
+        Svg::from_path(s).unwrap()
+    }
+}
+
+impl From<String> for Svg {
+    fn from(s: String) -> Svg {
+        Svg::from_path(s).unwrap()
+    }
+}
+
+// --- Styles ---
+
+// Affine transformation matrix [a c e; b d f; 0 0 1].
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Transform {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+}
+
+impl Transform {
+    fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Transform { a, b, c, d, e, f }
+    }
+
+    // Parses a transform list like `translate(10 20) rotate(45)`.
+    fn parse(s: &str) -> Self {
+        let mut transform = Transform::default();
+
+        for item in s.split(')') {
+            let mut parts = item.splitn(2, '(');
+            let (name, args) = match (parts.next(), parts.next()) {
+                (Some(name), Some(args)) => (
+                    name.trim_matches(|c: char| c == ',' || c.is_whitespace()),
+                    args,
+                ),
+                _ => continue,
+            };
+
+            let mut parser = NumberParser::new(args);
+            let args: Vec<f64> = std::iter::from_fn(|| parser.number()).collect();
+            let arg = |index: usize, default: f64| args.get(index).copied().unwrap_or(default);
+
+            let item = match name {
+                "matrix" if args.len() == 6 => {
+                    Transform::new(args[0], args[1], args[2], args[3], args[4], args[5])
+                }
+                "translate" => Transform::new(1.0, 0.0, 0.0, 1.0, arg(0, 0.0), arg(1, 0.0)),
+                "scale" => Transform::new(arg(0, 1.0), 0.0, 0.0, arg(1, arg(0, 1.0)), 0.0, 0.0),
+                "rotate" => {
+                    let (sin, cos) = arg(0, 0.0).to_radians().sin_cos();
+                    let (cx, cy) = (arg(1, 0.0), arg(2, 0.0));
+                    Transform::new(1.0, 0.0, 0.0, 1.0, cx, cy)
+                        .multiply(&Transform::new(cos, sin, -sin, cos, 0.0, 0.0))
+                        .multiply(&Transform::new(1.0, 0.0, 0.0, 1.0, -cx, -cy))
+                }
+                "skewX" => Transform::new(1.0, 0.0, arg(0, 0.0).to_radians().tan(), 1.0, 0.0, 0.0),
+                "skewY" => Transform::new(1.0, arg(0, 0.0).to_radians().tan(), 0.0, 1.0, 0.0, 0.0),
+                _ => continue,
+            };
+
+            transform = transform.multiply(&item);
+        }
+
+        transform
+    }
+
+    // Returns the transformation that applies the other transformation first.
+    fn multiply(&self, other: &Transform) -> Self {
+        Transform::new(
+            self.a * other.a + self.c * other.b,
+            self.b * other.a + self.d * other.b,
+            self.a * other.c + self.c * other.d,
+            self.b * other.c + self.d * other.d,
+            self.a * other.e + self.c * other.f + self.e,
+            self.b * other.e + self.d * other.f + self.f,
+        )
+    }
+
+    fn apply(&self, point: Point) -> Point {
+        Point::new(
+            self.a * point.x + self.c * point.y + self.e,
+            self.b * point.x + self.d * point.y + self.f,
+        )
+    }
+
+    // Average scale factor, used for the stroke width.
+    fn scale(&self) -> f64 {
+        (self.a * self.d - self.b * self.c).abs().sqrt()
+    }
+}
+
+// Presentation attributes that are inherited by the children of an element.
+#[derive(Clone, Debug, PartialEq)]
+struct Style {
+    fill: Option<SvgPaint>,
+    stroke: Option<SvgPaint>,
+    stroke_width: f64,
+    transform: Transform,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            fill: Some(SvgPaint::Color(Color::rgb(0, 0, 0))),
+            stroke: None,
+            stroke_width: 1.0,
+            transform: Transform::default(),
+        }
+    }
+}
+
+impl Style {
+    // Applies the attributes and the declarations of the style attribute of an element.
+    fn apply(mut self, attributes: &[(&str, &str)]) -> Self {
+        let declarations = attribute(attributes, "style")
+            .into_iter()
+            .flat_map(|style| style.split(';'))
+            .filter_map(|declaration| {
+                let mut parts = declaration.splitn(2, ':');
+                Some((parts.next()?.trim(), parts.next()?.trim()))
+            });
+
+        for (name, value) in attributes.iter().copied().chain(declarations) {
+            match name {
+                "fill" => self.fill = parse_paint(value),
+                "stroke" => self.stroke = parse_paint(value),
+                "stroke-width" => {
+                    if let Some(width) = NumberParser::new(value).number() {
+                        self.stroke_width = width;
+                    }
+                }
+                "transform" => {
+                    self.transform = self.transform.multiply(&Transform::parse(value));
+                }
+                _ => {}
+            }
+        }
+
+        self
+    }
+
+    // Creates a shape with the transformed commands.
+    fn shape(&self, commands: Vec<SvgCommand>) -> SvgShape {
+        let t = |point: Point| self.transform.apply(point);
+
+        SvgShape {
+            commands: commands
+                .into_iter()
+                .map(|command| match command {
+                    SvgCommand::MoveTo(p) => SvgCommand::MoveTo(t(p)),
+                    SvgCommand::LineTo(p) => SvgCommand::LineTo(t(p)),
+                    SvgCommand::QuadraticCurveTo(c, p) => SvgCommand::QuadraticCurveTo(t(c), t(p)),
+                    SvgCommand::BezierCurveTo(c1, c2, p) => {
+                        SvgCommand::BezierCurveTo(t(c1), t(c2), t(p))
+                    }
+                    SvgCommand::Close => SvgCommand::Close,
+                })
+                .collect(),
+            fill: self.fill,
+            stroke: self.stroke,
+            stroke_width: self.stroke_width * self.transform.scale(),
+        }
+    }
+}
+
+// Parses a color like `#fff`, `#ffffff`, `rgb(255, 255, 255)` or a basic color name. Unsupported
+// paints like gradients are not drawn.
+fn parse_paint(s: &str) -> Option<SvgPaint> {
+    let s = s.trim();
+
+    let color = match s {
+        "currentColor" => return Some(SvgPaint::CurrentColor),
+        "black" => Color::rgb(0, 0, 0),
+        "white" => Color::rgb(255, 255, 255),
+        "red" => Color::rgb(255, 0, 0),
+        "green" => Color::rgb(0, 128, 0),
+        "blue" => Color::rgb(0, 0, 255),
+        "gray" | "grey" => Color::rgb(128, 128, 128),
+        _ if s.starts_with('#') && s.len() == 4 => {
+            let value = u32::from_str_radix(&s[1..], 16).ok()?;
+            let channel = |shift: u32| ((value >> shift) & 0xf) as u8 * 17;
+            Color::rgb(channel(8), channel(4), channel(0))
+        }
+        _ if s.starts_with('#') && s.len() == 7 => {
+            u32::from_str_radix(&s[1..], 16).ok()?;
+            Color::from(s)
+        }
+        _ if s.starts_with("rgb(") => {
+            let mut parser = NumberParser::new(&s[4..]);
+            let mut channel = || parser.number().map(|value| value.clamp(0.0, 255.0) as u8);
+            Color::rgb(channel()?, channel()?, channel()?)
+        }
+        _ => return None,
+    };
+
+    Some(SvgPaint::Color(color))
+}
+
+// --- Parsing ---
+
+// Splits a start tag into its name and its attributes.
+fn parse_tag(tag: &str) -> (&str, Vec<(&str, &str)>) {
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let mut rest = &tag[name_end..];
+    let mut attributes = vec![];
+
+    while let Some(equals) = rest.find('=') {
+        let name = rest[..equals].trim();
+        let value = rest[equals + 1..].trim_start();
+
+        let quote = match value.chars().next() {
+            Some(quote) if quote == '"' || quote == '\'' => quote,
+            _ => break,
+        };
+
+        let end = match value[1..].find(quote) {
+            Some(end) => end + 1,
+            None => break,
+        };
+
+        attributes.push((name, &value[1..end]));
+        rest = &value[end + 1..];
+    }
+
+    (&tag[..name_end], attributes)
+}
+
+fn attribute<'a>(attributes: &[(&str, &'a str)], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(attribute_name, _)| *attribute_name == name)
+        .map(|(_, value)| *value)
+}
+
+// Reads the numbers of path data, lists and lengths.
+struct NumberParser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> NumberParser<'a> {
+    fn new(s: &'a str) -> Self {
+        NumberParser {
+            data: s.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.position < self.data.len()
+            && (self.data[self.position].is_ascii_whitespace() || self.data[self.position] == b',')
+        {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.data.get(self.position).copied()
+    }
+
+    fn next_byte(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        self.skip_separators();
+        let start = self.position;
+        let digits = |parser: &mut Self| {
+            while parser.position < parser.data.len()
+                && parser.data[parser.position].is_ascii_digit()
+            {
+                parser.position += 1;
+            }
+        };
+
+        if let Some(b'+') | Some(b'-') = self.data.get(self.position) {
+            self.position += 1;
+        }
+
+        digits(self);
+
+        if self.data.get(self.position) == Some(&b'.') {
+            self.position += 1;
+            digits(self);
+        }
+
+        // exponent, not the start of an other command like `e` of a length unit
+        if let Some(b'e') | Some(b'E') = self.data.get(self.position) {
+            let exponent = self.position;
+            self.position += 1;
+
+            if let Some(b'+') | Some(b'-') = self.data.get(self.position) {
+                self.position += 1;
+            }
+
+            if self
+                .data
+                .get(self.position)
+                .map_or(false, |byte| byte.is_ascii_digit())
+            {
+                digits(self);
+            } else {
+                self.position = exponent;
+            }
+        }
+
+        let number = std::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok());
+
+        if number.is_none() {
+            self.position = start;
+        }
+
+        number
+    }
+
+    // Reads a flag of an arc, flags could be written without separators.
+    fn flag(&mut self) -> Option<bool> {
+        match self.peek()? {
+            b'0' => {
+                self.position += 1;
+                Some(false)
+            }
+            b'1' => {
+                self.position += 1;
+                Some(true)
+            }
+            _ => None,
+        }
+    }
+
+    fn point(&mut self) -> Option<Point> {
+        Some(Point::new(self.number()?, self.number()?))
+    }
+}
+
+// Returns the outline of a path or a basic shape.
+fn shape_commands(name: &str, attributes: &[(&str, &str)]) -> Option<Vec<SvgCommand>> {
+    let number = |name| {
+        attribute(attributes, name)
+            .and_then(|value| NumberParser::new(value).number())
+            .unwrap_or(0.0)
+    };
+
+    let commands = match name {
+        "path" => parse_path(attribute(attributes, "d")?),
+        "rect" => {
+            let (rx, ry) = match (attribute(attributes, "rx"), attribute(attributes, "ry")) {
+                (None, None) => (0.0, 0.0),
+                (Some(_), None) => (number("rx"), number("rx")),
+                (None, Some(_)) => (number("ry"), number("ry")),
+                _ => (number("rx"), number("ry")),
+            };
+
+            rect_commands(
+                Rectangle::new(number("x"), number("y"), number("width"), number("height")),
+                rx,
+                ry,
+            )
+        }
+        "circle" => ellipse_commands(number("cx"), number("cy"), number("r"), number("r")),
+        "ellipse" => ellipse_commands(number("cx"), number("cy"), number("rx"), number("ry")),
+        "line" => vec![
+            SvgCommand::MoveTo(Point::new(number("x1"), number("y1"))),
+            SvgCommand::LineTo(Point::new(number("x2"), number("y2"))),
+        ],
+        "polyline" | "polygon" => {
+            let mut parser = NumberParser::new(attribute(attributes, "points")?);
+            let mut commands: Vec<SvgCommand> = std::iter::from_fn(|| parser.point())
+                .enumerate()
+                .map(|(i, point)| {
+                    if i == 0 {
+                        SvgCommand::MoveTo(point)
+                    } else {
+                        SvgCommand::LineTo(point)
+                    }
+                })
+                .collect();
+
+            if name == "polygon" && !commands.is_empty() {
+                commands.push(SvgCommand::Close);
+            }
+
+            commands
+        }
+        _ => return None,
+    };
+
+    Some(commands)
+}
+
+fn rect_commands(rect: Rectangle, rx: f64, ry: f64) -> Vec<SvgCommand> {
+    if rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return vec![];
+    }
+
+    let (x, y, width, height) = (rect.x(), rect.y(), rect.width(), rect.height());
+    let rx = rx.max(0.0).min(width / 2.0);
+    let ry = ry.max(0.0).min(height / 2.0);
+
+    if rx == 0.0 || ry == 0.0 {
+        return vec![
+            SvgCommand::MoveTo(Point::new(x, y)),
+            SvgCommand::LineTo(Point::new(x + width, y)),
+            SvgCommand::LineTo(Point::new(x + width, y + height)),
+            SvgCommand::LineTo(Point::new(x, y + height)),
+            SvgCommand::Close,
+        ];
+    }
+
+    let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+    let (right, bottom) = (x + width, y + height);
+
+    vec![
+        SvgCommand::MoveTo(Point::new(x + rx, y)),
+        SvgCommand::LineTo(Point::new(right - rx, y)),
+        SvgCommand::BezierCurveTo(
+            Point::new(right - rx + kx, y),
+            Point::new(right, y + ry - ky),
+            Point::new(right, y + ry),
+        ),
+        SvgCommand::LineTo(Point::new(right, bottom - ry)),
+        SvgCommand::BezierCurveTo(
+            Point::new(right, bottom - ry + ky),
+            Point::new(right - rx + kx, bottom),
+            Point::new(right - rx, bottom),
+        ),
+        SvgCommand::LineTo(Point::new(x + rx, bottom)),
+        SvgCommand::BezierCurveTo(
+            Point::new(x + rx - kx, bottom),
+            Point::new(x, bottom - ry + ky),
+            Point::new(x, bottom - ry),
+        ),
+        SvgCommand::LineTo(Point::new(x, y + ry)),
+        SvgCommand::BezierCurveTo(
+            Point::new(x, y + ry - ky),
+            Point::new(x + rx - kx, y),
+            Point::new(x + rx, y),
+        ),
+        SvgCommand::Close,
+    ]
+}
+
+fn ellipse_commands(cx: f64, cy: f64, rx: f64, ry: f64) -> Vec<SvgCommand> {
+    if rx <= 0.0 || ry <= 0.0 {
+        return vec![];
+    }
+
+    let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+
+    vec![
+        SvgCommand::MoveTo(Point::new(cx + rx, cy)),
+        SvgCommand::BezierCurveTo(
+            Point::new(cx + rx, cy + ky),
+            Point::new(cx + kx, cy + ry),
+            Point::new(cx, cy + ry),
+        ),
+        SvgCommand::BezierCurveTo(
+            Point::new(cx - kx, cy + ry),
+            Point::new(cx - rx, cy + ky),
+            Point::new(cx - rx, cy),
+        ),
+        SvgCommand::BezierCurveTo(
+            Point::new(cx - rx, cy - ky),
+            Point::new(cx - kx, cy - ry),
+            Point::new(cx, cy - ry),
+        ),
+        SvgCommand::BezierCurveTo(
+            Point::new(cx + kx, cy - ry),
+            Point::new(cx + rx, cy - ky),
+            Point::new(cx + rx, cy),
+        ),
+        SvgCommand::Close,
+    ]
+}
+
+// Parses path data to absolute commands. Like browsers it draws the path until the first error.
+fn parse_path(data: &str) -> Vec<SvgCommand> {
+    let mut parser = NumberParser::new(data);
+    let mut commands = vec![];
+    let mut command = None;
+    let (mut current, mut start) = (Point::default(), Point::default());
+    // reflected control point of smooth curves
+    let mut last_control: Option<(u8, Point)> = None;
+
+    while let Some(byte) = parser.peek() {
+        if byte.is_ascii_alphabetic() {
+            parser.next_byte();
+            command = Some(byte);
+        }
+
+        let name = match command {
+            Some(name) => name,
+            None => break,
+        };
+
+        let relative = name.is_ascii_lowercase();
+        let offset = |point: Point, current: Point| {
+            if relative {
+                Point::new(current.x + point.x, current.y + point.y)
+            } else {
+                point
+            }
+        };
+        let reflect =
+            |kind: u8, current: Point, last_control: Option<(u8, Point)>| match last_control {
+                Some((last_kind, control)) if last_kind == kind => {
+                    Point::new(2.0 * current.x - control.x, 2.0 * current.y - control.y)
+                }
+                _ => current,
+            };
+
+        let mut control = None;
+
+        let kind = name.to_ascii_uppercase();
+
+        match kind {
+            b'M' => {
+                let point = match parser.point() {
+                    Some(point) => offset(point, current),
+                    None => break,
+                };
+
+                commands.push(SvgCommand::MoveTo(point));
+                current = point;
+                start = point;
+
+                // following coordinate pairs are lines
+                command = Some(if relative { b'l' } else { b'L' });
+            }
+            b'L' => {
+                let point = match parser.point() {
+                    Some(point) => offset(point, current),
+                    None => break,
+                };
+
+                commands.push(SvgCommand::LineTo(point));
+                current = point;
+            }
+            b'H' => {
+                let x = match parser.number() {
+                    Some(x) if relative => current.x + x,
+                    Some(x) => x,
+                    None => break,
+                };
+
+                current = Point::new(x, current.y);
+                commands.push(SvgCommand::LineTo(current));
+            }
+            b'V' => {
+                let y = match parser.number() {
+                    Some(y) if relative => current.y + y,
+                    Some(y) => y,
+                    None => break,
+                };
+
+                current = Point::new(current.x, y);
+                commands.push(SvgCommand::LineTo(current));
+            }
+            b'C' | b'S' => {
+                let control_1 = if kind == b'C' {
+                    match parser.point() {
+                        Some(point) => offset(point, current),
+                        None => break,
+                    }
+                } else {
+                    reflect(b'C', current, last_control)
+                };
+
+                let (control_2, point) = match (parser.point(), parser.point()) {
+                    (Some(control_2), Some(point)) => {
+                        (offset(control_2, current), offset(point, current))
+                    }
+                    _ => break,
+                };
+
+                commands.push(SvgCommand::BezierCurveTo(control_1, control_2, point));
+                control = Some((b'C', control_2));
+                current = point;
+            }
+            b'Q' | b'T' => {
+                let control_1 = if kind == b'Q' {
+                    match parser.point() {
+                        Some(point) => offset(point, current),
+                        None => break,
+                    }
+                } else {
+                    reflect(b'Q', current, last_control)
+                };
+
+                let point = match parser.point() {
+                    Some(point) => offset(point, current),
+                    None => break,
+                };
+
+                commands.push(SvgCommand::QuadraticCurveTo(control_1, point));
+                control = Some((b'Q', control_1));
+                current = point;
+            }
+            b'A' => {
+                let (rx, ry, rotation, large_arc, sweep, point) = match (
+                    parser.number(),
+                    parser.number(),
+                    parser.number(),
+                    parser.flag(),
+                    parser.flag(),
+                    parser.point(),
+                ) {
+                    (Some(rx), Some(ry), Some(rotation), Some(large), Some(sweep), Some(point)) => {
+                        (rx, ry, rotation, large, sweep, offset(point, current))
+                    }
+                    _ => break,
+                };
+
+                commands.extend(arc_commands(
+                    current, rx, ry, rotation, large_arc, sweep, point,
+                ));
+                current = point;
+            }
+            b'Z' => {
+                commands.push(SvgCommand::Close);
+                current = start;
+
+                // a close command has no arguments
+                command = None;
+            }
+            _ => break,
+        }
+
+        last_control = control;
+    }
+
+    commands
+}
+
+// Converts an elliptical arc to cubic bezier curves, see
+// https://www.w3.org/TR/SVG/implnote.html#ArcImplementationNotes
+fn arc_commands(
+    from: Point,
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    to: Point,
+) -> Vec<SvgCommand> {
+    if from == to {
+        return vec![];
+    }
+
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+
+    if rx == 0.0 || ry == 0.0 {
+        return vec![SvgCommand::LineTo(to)];
+    }
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let (dx, dy) = ((from.x - to.x) / 2.0, (from.y - to.y) / 2.0);
+    let (x1, y1) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+
+    // scales up radii that are too small to reach the end point
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+    let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+    let (cx1, cy1) = (coefficient * rx * y1 / ry, -coefficient * ry * x1 / rx);
+    let center = Point::new(
+        cos * cx1 - sin * cy1 + (from.x + to.x) / 2.0,
+        sin * cx1 + cos * cy1 + (from.y + to.y) / 2.0,
+    );
+
+    let angle = |ux: f64, uy: f64, vx: f64, vy: f64| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+    let (ux, uy) = ((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let (vx, vy) = ((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+    let start_angle = angle(1.0, 0.0, ux, uy);
+    let mut delta = angle(ux, uy, vx, vy);
+
+    if !sweep && delta > 0.0 {
+        delta -= 2.0 * PI;
+    } else if sweep && delta < 0.0 {
+        delta += 2.0 * PI;
+    }
+
+    // each segment covers at most a quarter of the ellipse
+    let segments = (delta.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+    let step = delta / segments as f64;
+    let t = 4.0 / 3.0 * (step / 4.0).tan();
+    let map = |x: f64, y: f64| {
+        Point::new(
+            center.x + rx * x * cos - ry * y * sin,
+            center.y + rx * x * sin + ry * y * cos,
+        )
+    };
+
+    (0..segments)
+        .map(|i| {
+            let (sin_1, cos_1) = (start_angle + step * i as f64).sin_cos();
+            let (sin_2, cos_2) = (start_angle + step * (i + 1) as f64).sin_cos();
+            let point = if i + 1 == segments {
+                to
+            } else {
+                map(cos_2, sin_2)
+            };
+
+            SvgCommand::BezierCurveTo(
+                map(cos_1 - t * sin_1, sin_1 + t * cos_1),
+                map(cos_2 + t * sin_2, sin_2 - t * cos_2),
+                point,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(actual: Point, expected: (f64, f64)) {
+        assert!(
+            (actual.x - expected.0).abs() < 1e-9 && (actual.y - expected.1).abs() < 1e-9,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            parse_path("M10,10 l5-5h5V20zm1 1"),
+            vec![
+                SvgCommand::MoveTo(Point::new(10.0, 10.0)),
+                SvgCommand::LineTo(Point::new(15.0, 5.0)),
+                SvgCommand::LineTo(Point::new(20.0, 5.0)),
+                SvgCommand::LineTo(Point::new(20.0, 20.0)),
+                SvgCommand::Close,
+                SvgCommand::MoveTo(Point::new(11.0, 11.0)),
+            ]
+        );
+
+        // smooth curves reflect the last control point
+        assert_eq!(
+            parse_path("M0 0C0 1 1 1 1 0S2-1 2 0"),
+            vec![
+                SvgCommand::MoveTo(Point::new(0.0, 0.0)),
+                SvgCommand::BezierCurveTo(
+                    Point::new(0.0, 1.0),
+                    Point::new(1.0, 1.0),
+                    Point::new(1.0, 0.0)
+                ),
+                SvgCommand::BezierCurveTo(
+                    Point::new(1.0, -1.0),
+                    Point::new(2.0, -1.0),
+                    Point::new(2.0, 0.0)
+                ),
+            ]
+        );
+
+        // numbers with exponents and without separators, the path ends at the error
+        assert_eq!(
+            parse_path("M1e1.5L.5.5 L x"),
+            vec![
+                SvgCommand::MoveTo(Point::new(10.0, 0.5)),
+                SvgCommand::LineTo(Point::new(0.5, 0.5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_arc() {
+        // half circle from the left to the right of a circle at 10, 10 with radius 5
+        let commands = parse_path("M5 10A5 5 0 0 1 15 10");
+        assert_eq!(commands.len(), 3);
+
+        match commands[1] {
+            SvgCommand::BezierCurveTo(_, _, point) => assert_point(point, (10.0, 5.0)),
+            _ => panic!("arc is not converted to curves"),
+        }
+
+        // the last curve ends exactly at the end point
+        match commands[2] {
+            SvgCommand::BezierCurveTo(_, _, point) => assert_eq!(point, Point::new(15.0, 10.0)),
+            _ => panic!("arc is not converted to curves"),
+        }
+
+        // flags without separators
+        assert_eq!(parse_path("M5 10a5 5 0 1115 10").len(), 3);
+    }
+
+    #[test]
+    fn test_transform() {
+        let transform = Transform::parse("translate(10, 20) scale(2)");
+        assert_point(transform.apply(Point::new(1.0, 1.0)), (12.0, 22.0));
+        assert_eq!(transform.scale(), 2.0);
+
+        let transform = Transform::parse("rotate(90 5 5)");
+        assert_point(transform.apply(Point::new(10.0, 5.0)), (5.0, 10.0));
+    }
+
+    #[test]
+    fn test_parse_paint() {
+        assert_eq!(
+            parse_paint("#f00"),
+            Some(SvgPaint::Color(Color::rgb(255, 0, 0)))
+        );
+        assert_eq!(
+            parse_paint("rgb(0, 128, 255)"),
+            Some(SvgPaint::Color(Color::rgb(0, 128, 255)))
+        );
+        assert_eq!(parse_paint("currentColor"), Some(SvgPaint::CurrentColor));
+        assert_eq!(parse_paint("none"), None);
+        assert_eq!(parse_paint("url(#gradient)"), None);
+    }
+
+    #[test]
+    fn test_from_markup() {
+        let svg = Svg::from_markup(
+            r##"<?xml version="1.0"?>
+            <!-- icon -->
+            <svg xmlns="http://www.w3.org/2000/svg" width="48" height="48" viewBox="0 0 24 24">
+                <defs><rect width="4" height="4"/></defs>
+                <g fill="currentColor" transform="translate(2 2)">
+                    <rect x="1" y="1" width="4" height="2" />
+                    <circle cx="10" cy="10" r="2" style="fill: none; stroke: #ff0000; stroke-width: 2"/>
+                </g>
+                <path d="M0 0H4" fill='none' stroke="black"/>
+            </svg>"##,
+        )
+        .unwrap();
+
+        assert_eq!((svg.width(), svg.height()), (48.0, 48.0));
+        assert_eq!(svg.view_box(), Rectangle::new(0.0, 0.0, 24.0, 24.0));
+        assert_eq!(svg.shapes().len(), 3);
+
+        let rect = &svg.shapes()[0];
+        assert_eq!(rect.fill, Some(SvgPaint::CurrentColor));
+        assert_eq!(rect.commands[0], SvgCommand::MoveTo(Point::new(3.0, 3.0)));
+
+        let circle = &svg.shapes()[1];
+        assert_eq!(circle.fill, None);
+        assert_eq!(circle.stroke, Some(SvgPaint::Color(Color::rgb(255, 0, 0))));
+        assert_eq!(circle.stroke_width, 2.0);
+        assert_eq!(
+            circle.commands[0],
+            SvgCommand::MoveTo(Point::new(14.0, 12.0))
+        );
+
+        let path = &svg.shapes()[2];
+        assert_eq!(path.fill, None);
+        assert_eq!(path.stroke, Some(SvgPaint::Color(Color::rgb(0, 0, 0))));
+
+        // the size is taken from the view box
+        let svg = Svg::from_markup(r#"<svg viewBox="0 0 16 8"></svg>"#).unwrap();
+        assert_eq!((svg.width(), svg.height()), (16.0, 8.0));
+
+        assert!(Svg::from_markup("<svg></svg>").is_err());
+        assert!(Svg::from_markup("<html></html>").is_err());
+    }
+}
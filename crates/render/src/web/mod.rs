@@ -177,55 +177,30 @@ impl RenderContext2D {
 
     /// Draws a render target.
     pub fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {
-        // todo
-    }
-
-    /// Draws the image.
-    pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
-        js!(
-            var img = document.image_store.image(@{&image.source});
-
-            if(img == null) {
-                img = document.image_store.load_image(@{&image.source});
-                img.then(
-                    function(i) {
-                         @{&self.canvas_render_context_2_d}.drawImage(i, @{&x}, @{&y});
-                    }
-                )
-            } else {
-                //  @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y});
-            }
-        );
-    }
-
-    /// Draws the given part of the image.
-    pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
-        js!(
-            var img = document.image_store.image(@{&image.source});
-
-            if(img == null) {
-                img = document.image_store.load_image(@{&image.source});
-                img.then(
-                    function(i) {
-                         @{&self.canvas_render_context_2_d}.drawImage(img, @{&clip.x}, @{&clip.y}, @{&clip.width}, @{&clip.height}, @{&x}, @{&y}, @{&clip.width}, @{&clip.height});
-                    }
-                )
-            } else {
-                 @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y});
-            }
+        self.draw_render_target_with_size(
+            render_target,
+            x,
+            y,
+            render_target.width(),
+            render_target.height(),
         );
     }
 
-    pub fn draw_pipeline(
+    /// Draws the render target scaled to the given size.
+    pub fn draw_render_target_with_size(
         &mut self,
+        render_target: &RenderTarget,
         x: f64,
         y: f64,
-        width: f64,
-        height: f64,
-        pipeline: Box<dyn Pipeline>,
+        target_width: f64,
+        target_height: f64,
     ) {
-        let mut render_target = RenderTarget::new(width as u32, height as u32);
-        pipeline.draw_pipeline(&mut render_target);
+        let width = render_target.width();
+        let height = render_target.height();
+
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
 
         let image_data = self
             .canvas_render_context_2_d
@@ -259,7 +234,7 @@ impl RenderContext2D {
 
         let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
         ctx.put_image_data(image_data, 0.0, 0.0)
-            .expect("Could no draw pipeline.");
+            .expect("Could not draw render target.");
 
         // todo: use await after stdweb futures are stable
         js!(
@@ -267,13 +242,63 @@ impl RenderContext2D {
             var img = new Image();
 
             img.onload = function () {
-                @{&self.canvas_render_context_2_d}.drawImage(img,@{&x},@{&y});
+                @{&self.canvas_render_context_2_d}.drawImage(img,@{&x},@{&y},@{&target_width},@{&target_height});
             };
 
             img.src = @{&canvas}.toDataURL();
         );
     }
 
+    /// Draws the image.
+    pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
+        js!(
+            var img = document.image_store.image(@{&image.source});
+
+            if(img == null) {
+                img = document.image_store.load_image(@{&image.source});
+                img.then(
+                    function(i) {
+                         @{&self.canvas_render_context_2_d}.drawImage(i, @{&x}, @{&y});
+                    }
+                )
+            } else {
+                //  @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y});
+            }
+        );
+    }
+
+    /// Draws the given part of the image.
+    pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
+        js!(
+            var img = document.image_store.image(@{&image.source});
+
+            if(img == null) {
+                img = document.image_store.load_image(@{&image.source});
+                img.then(
+                    function(i) {
+                         @{&self.canvas_render_context_2_d}.drawImage(img, @{&clip.x}, @{&clip.y}, @{&clip.width}, @{&clip.height}, @{&x}, @{&y}, @{&clip.width}, @{&clip.height});
+                    }
+                )
+            } else {
+                 @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y});
+            }
+        );
+    }
+
+    pub fn draw_pipeline(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        pipeline: Box<dyn Pipeline>,
+    ) {
+        let mut render_target = RenderTarget::new(width as u32, height as u32);
+        pipeline.draw_pipeline(&mut render_target);
+
+        self.draw_render_target(&render_target, x, y);
+    }
+
     /// Creates a clipping path from the current sub-paths. Everything drawn after clip() is called appears inside the clipping path only.
     pub fn clip(&mut self) {
         self.canvas_render_context_2_d.clip(FillRule::EvenOdd);
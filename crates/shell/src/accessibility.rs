@@ -0,0 +1,50 @@
+//! This module contains the types to expose the widget tree to assistive technologies.
+
+use crate::utils::{AccessibleRole, Rectangle};
+
+/// Describes a widget for assistive technologies like screen readers.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessibilityNode {
+    /// Unique id of the node, e.g. the entity of the widget.
+    pub id: u64,
+
+    /// Id of the parent node, `None` for the root.
+    pub parent: Option<u64>,
+
+    pub role: AccessibleRole,
+
+    /// Name that is read by screen readers, e.g. the text of a button.
+    pub name: String,
+
+    /// Stable id of the widget for automation tools, empty if it is not set.
+    pub automation_id: String,
+
+    /// Absolute bounds of the widget in the window.
+    pub bounds: Rectangle,
+
+    pub enabled: bool,
+
+    pub focused: bool,
+
+    /// Checked state of check boxes, switches and toggle buttons or selection of items.
+    pub selected: bool,
+
+    /// Zero based row index of table rows, cells and column headers.
+    pub row_index: Option<usize>,
+
+    /// Zero based column index of table cells and column headers.
+    pub column_index: Option<usize>,
+
+    /// Number of rows of a table, including the header row.
+    pub row_count: Option<usize>,
+
+    /// Number of columns of a table.
+    pub column_count: Option<usize>,
+
+    /// Id of the column header node of a table cell.
+    pub header: Option<u64>,
+
+    /// Id of the descendant node that has the keyboard focus inside of a focused composite
+    /// widget, e.g. the current cell of a table.
+    pub active_descendant: Option<u64>,
+}
@@ -0,0 +1,261 @@
+//! This module contains the traits to plug a custom window backend into the window shell.
+//!
+//! The desktop shell uses a minifb based backend by default. Embedders, e.g. games or
+//! applications with an existing event loop, could provide their own backend or call
+//! `ShellRunner::tick` with their own events.
+
+#[cfg(not(target_os = "redox"))]
+use raw_window_handle::RawWindowHandle;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    event::*,
+    monitor::Monitor,
+    tray::TrayEvent,
+    utils::{CursorIcon, Rectangle},
+    window::WindowAdapter,
+};
+
+/// Platform independent input event that is passed from an event source to the window shell.
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub enum ShellEvent {
+    /// The window is resized to the given width and height.
+    Resize(f64, f64),
+
+    /// The mouse is moved to the given position.
+    Mouse(f64, f64),
+
+    MouseEvent(MouseEvent),
+
+    TouchEvent(TouchEvent),
+
+    /// Scroll with the given delta x and delta y.
+    Scroll(f64, f64),
+
+    KeyEvent(KeyEvent),
+
+    TextInput(String),
+
+    CompositionEvent(CompositionEvent),
+
+    /// The window is activated or deactivated.
+    Active(bool),
+
+    /// The window is minimized or restored.
+    Minimized(bool),
+
+    /// Event of the system tray icon of the application.
+    Tray(TrayEvent),
+
+    /// The user requested to close the window, the application could veto it.
+    Quit,
+}
+
+impl ShellEvent {
+    /// Passes the event to the corresponding method of the given adapter.
+    pub fn dispatch<A: WindowAdapter>(self, adapter: &mut A) {
+        match self {
+            ShellEvent::Resize(width, height) => adapter.resize(width, height),
+            ShellEvent::Mouse(x, y) => adapter.mouse(x, y),
+            ShellEvent::MouseEvent(event) => adapter.mouse_event(event),
+            ShellEvent::TouchEvent(event) => adapter.touch_event(event),
+            ShellEvent::Scroll(delta_x, delta_y) => adapter.scroll(delta_x, delta_y),
+            ShellEvent::KeyEvent(event) => adapter.key_event(event),
+            ShellEvent::TextInput(text) => adapter.text_input(text),
+            ShellEvent::CompositionEvent(event) => adapter.composition_event(event),
+            ShellEvent::Active(active) => adapter.active(active),
+            ShellEvent::Minimized(minimized) => adapter.minimized(minimized),
+            ShellEvent::Tray(event) => adapter.tray_event(event),
+            ShellEvent::Quit => adapter.quite_event(),
+        }
+    }
+}
+
+/// Settings that are used to create the window of a backend.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowSettings {
+    pub title: String,
+
+    pub resizeable: bool,
+
+    /// The window is created without border and title bar, the application draws its own
+    /// window chrome.
+    pub borderless: bool,
+
+    /// Position and size of the window.
+    pub bounds: Rectangle,
+
+    /// Backends with a swap chain should wait for the vertical blank before they present a frame.
+    /// Without vsync the frames are presented immediately.
+    pub vsync: bool,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        WindowSettings {
+            title: String::default(),
+            resizeable: false,
+            borderless: false,
+            bounds: Rectangle::default(),
+            vsync: true,
+        }
+    }
+}
+
+/// Source of the input events of a window.
+pub trait EventSource {
+    /// Removes and returns all events that are received since the last call.
+    fn poll_events(&mut self) -> Vec<ShellEvent>;
+
+    /// Returns `false` if the window is closed.
+    fn is_open(&self) -> bool {
+        true
+    }
+}
+
+/// Presents the rendered frames of a window.
+pub trait Presenter {
+    /// Presents the given pixel buffer in ARGB format.
+    fn present(&mut self, data: &[u32], width: usize, height: usize);
+
+    /// Returns the current size of the drawing area.
+    fn size(&self) -> (usize, usize);
+
+    /// Sets the color that is drawn outside of the presented frame.
+    fn set_background_color(&mut self, _red: u8, _green: u8, _blue: u8) {}
+
+    /// Sets the icon of the mouse cursor inside of the window.
+    fn set_cursor_icon(&mut self, _cursor_icon: &CursorIcon) {}
+
+    /// Shows or hides the window. Backends that could not hide their window ignore it.
+    fn set_visible(&mut self, _visible: bool) {}
+
+    /// Sets the icon of the window from a pixel buffer in ARGB format.
+    fn set_icon(&mut self, _data: &[u32], _width: usize, _height: usize) {}
+
+    /// Sets a badge of the window in the taskbar, e.g. an unread count, or removes it with
+    /// `None`. Only supported by some platforms.
+    fn set_badge(&mut self, _badge: Option<&str>) {}
+
+    /// Keeps the window above all other windows, e.g. a tool palette. Only supported by some
+    /// platforms.
+    fn set_always_on_top(&mut self, _always_on_top: bool) {}
+
+    /// Sets the opacity of the whole window from `0.0` (transparent) to `1.0` (opaque). Only
+    /// supported by some platforms.
+    fn set_opacity(&mut self, _opacity: f64) {}
+
+    /// Moves the window by the given offset on the screen, e.g. while its custom title bar is
+    /// dragged. Backends that could not move their window ignore it.
+    fn move_by(&mut self, _delta_x: f64, _delta_y: f64) {}
+
+    /// Resizes the window to the given size. The backend reports the new size with a
+    /// `ShellEvent::Resize`. Backends that could not resize their window ignore it.
+    fn resize_to(&mut self, _width: f64, _height: f64) {}
+
+    /// Returns `true` if the backend could resize its window with `resize_to`.
+    fn can_resize(&self) -> bool {
+        false
+    }
+
+    /// Returns the position of the window on the screen, `None` if it is not known.
+    fn position(&self) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Returns the monitors of the system. Backends that could not query the monitors return an
+    /// empty list.
+    fn monitors(&self) -> Vec<Monitor> {
+        vec![]
+    }
+
+    /// Returns the handle of the native window if there is one.
+    #[cfg(not(target_os = "redox"))]
+    fn try_raw_window_handle(&self) -> Option<RawWindowHandle> {
+        None
+    }
+}
+
+/// A backend provides the event source and the presenter of a window.
+pub trait WindowBackend: EventSource + Presenter {}
+
+impl<T: EventSource + Presenter> WindowBackend for T {}
+
+/// Creates the backend of a window shell.
+pub trait WindowFactory {
+    /// Creates a new backend with the given settings.
+    fn create(&mut self, settings: &WindowSettings) -> Result<Box<dyn WindowBackend>, String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Point;
+
+    #[derive(Default)]
+    struct TestAdapter {
+        resized: bool,
+        text: String,
+        active: bool,
+        minimized: bool,
+        quit: bool,
+        tray_events: Vec<TrayEvent>,
+    }
+
+    impl WindowAdapter for TestAdapter {
+        fn resize(&mut self, _width: f64, _height: f64) {
+            self.resized = true;
+        }
+
+        fn text_input(&mut self, text: String) {
+            self.text.push_str(&text);
+        }
+
+        fn quite_event(&mut self) {
+            self.quit = true;
+        }
+
+        fn mouse_position(&self) -> Point {
+            Point::default()
+        }
+
+        fn active(&mut self, active: bool) {
+            self.active = active;
+        }
+
+        fn minimized(&mut self, minimized: bool) {
+            self.minimized = minimized;
+        }
+
+        fn tray_event(&mut self, event: TrayEvent) {
+            self.tray_events.push(event);
+        }
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let mut adapter = TestAdapter::default();
+
+        ShellEvent::Resize(100.0, 50.0).dispatch(&mut adapter);
+        ShellEvent::TextInput("a".to_string()).dispatch(&mut adapter);
+        ShellEvent::TextInput("b".to_string()).dispatch(&mut adapter);
+        ShellEvent::Active(true).dispatch(&mut adapter);
+
+        assert!(adapter.resized);
+        assert_eq!(adapter.text, "ab");
+        assert!(adapter.active);
+        assert!(!adapter.quit);
+
+        ShellEvent::Minimized(true).dispatch(&mut adapter);
+        assert!(adapter.minimized);
+
+        ShellEvent::Quit.dispatch(&mut adapter);
+        assert!(adapter.quit);
+
+        ShellEvent::Tray(TrayEvent::MenuItem("show".to_string())).dispatch(&mut adapter);
+        assert_eq!(
+            adapter.tray_events,
+            vec![TrayEvent::MenuItem("show".to_string())]
+        );
+    }
+}
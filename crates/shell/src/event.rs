@@ -1,7 +1,9 @@
 //! This module contains elements to work with window events.
 
+use serde_derive::{Deserialize, Serialize};
+
 /// Represents a keyboard key.
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Key {
     Unknown,
     Backspace,
@@ -18,6 +20,9 @@ pub enum Key {
     Alt,
     Escape,
     Home,
+    End,
+    PageUp,
+    PageDown,
     A(bool),
     B(bool),
     C(bool),
@@ -295,7 +300,7 @@ impl From<char> for Key {
 }
 
 /// Describes a specific mouse button.
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum MouseButton {
     Left,
     Middle,
@@ -303,7 +308,7 @@ pub enum MouseButton {
 }
 
 /// Describes the position / state of a button.
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum ButtonState {
     Down,
 
@@ -311,7 +316,7 @@ pub enum ButtonState {
 }
 
 /// Represents a mouse event.
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct MouseEvent {
     pub x: f64,
 
@@ -322,12 +327,52 @@ pub struct MouseEvent {
     pub state: ButtonState,
 }
 
-/// Represents a keyboard key event.
-#[derive(PartialEq, Eq, Clone, Debug)]
+/// Describes the phase of a touch point.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum TouchPhase {
+    Down,
+
+    Move,
+
+    Up,
+
+    /// The touch is canceled by the system, e.g. if too many touch points are active.
+    Cancel,
+}
+
+/// Represents an event of a single touch point. Touch points that are down at the same time have
+/// different ids.
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct TouchEvent {
+    pub id: u64,
+
+    pub x: f64,
+
+    pub y: f64,
+
+    pub phase: TouchPhase,
+}
+
+/// Represents a keyboard key event. Typed characters are reported separately as text input.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct KeyEvent {
     pub key: Key,
 
     pub state: ButtonState,
 
-    pub text: String,
+    /// `true` if the event is a key repeat of the operating system while the key is hold down.
+    pub repeat: bool,
+}
+
+/// Represents an input method (IME) composition event, e.g. of CJK input.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub enum CompositionEvent {
+    /// The composition string is changed. It is not part of the text until it is committed.
+    Update(String),
+
+    /// The composition string is confirmed and should be inserted into the text.
+    Commit(String),
+
+    /// The composition is aborted.
+    Cancel,
 }
@@ -21,14 +21,39 @@ let runner = ShellRunner {
 };
 
 runner.run()
+```
+
+On desktop the window is created by a `WindowFactory` (minifb by default). To drive the shell from
+an existing event loop, pass a custom factory to the builder and call `ShellRunner::tick` once per
+frame with the events of the loop:
+
+```rust,no_run
+
+use orbtk_shell::prelude::*;
+
+let shell = WindowBuilder::new(MyCustomWindowAdapter::new())
+                        .factory(MyCustomWindowFactory::new())
+                        .build();
+
+let mut runner = ShellRunner {
+    shell,
+    updater: Box::new(MyCustomUpdater::new())
+};
+
+runner.tick(vec![ShellEvent::Resize(100.0, 100.0)]);
 ```
 
  */
 #[macro_use]
 extern crate lazy_static;
 
+pub mod accessibility;
+pub mod backend;
 pub mod event;
+pub mod monitor;
 pub mod prelude;
+pub mod scheduler;
+pub mod tray;
 pub mod window;
 
 pub use orbtk_utils::prelude as utils;
@@ -7,6 +7,7 @@ use std::{
     rc::Rc,
     sync::mpsc::{channel, Receiver, Sender},
     sync::Mutex,
+    thread,
     time::Duration,
 };
 
@@ -17,77 +18,108 @@ use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 
 use crate::{prelude::*, render::*, utils::*};
 
+use self::native::NativeWindow;
+
+#[cfg(windows)]
+pub use self::native::Win32Tray;
+
+mod native;
+
 pub fn initialize() {}
 
-fn key_event_helper<A>(key: &mut KeyHelper, adapter: &mut A, window: &minifb::Window)
-where
-    A: WindowAdapter,
-{
-    if !key.0 && window.is_key_pressed(key.1, minifb::KeyRepeat::Yes) {
-        adapter.key_event(KeyEvent {
+fn key_event_helper(key: &mut KeyHelper, events: &mut Vec<ShellEvent>, window: &minifb::Window) {
+    let down = window.is_key_down(key.1);
+
+    if down && window.is_key_pressed(key.1, minifb::KeyRepeat::Yes) {
+        // if the key is already down the event is a key repeat
+        events.push(ShellEvent::KeyEvent(KeyEvent {
             key: key.2,
             state: ButtonState::Down,
-            text: String::new(),
-        });
+            repeat: key.0,
+        }));
 
         key.0 = true;
-    } else {
-        key.0 = false;
-    }
-}
-
-fn unicode_to_key_event(uni_char: u32) -> Option<KeyEvent> {
-    let mut text = String::new();
-
-    let key = if let Some(character) = char::from_u32(uni_char) {
-        text = character.to_string();
-        Key::from(character)
-    } else {
-        Key::Unknown
-    };
+    } else if !down && key.0 {
+        events.push(ShellEvent::KeyEvent(KeyEvent {
+            key: key.2,
+            state: ButtonState::Up,
+            repeat: false,
+        }));
 
-    if key == Key::Up
-        || key == Key::Down
-        || key == Key::Left
-        || key == Key::Right
-        || key == Key::Backspace
-    {
-        return None;
+        key.0 = false;
     }
-
-    Some(KeyEvent {
-        key,
-        state: ButtonState::Down,
-        text,
-    })
 }
 
 struct KeyInputCallBack {
     key_events: Rc<RefCell<Vec<KeyEvent>>>,
+    text_input: Rc<RefCell<Vec<String>>>,
 }
 
 impl minifb::InputCallback for KeyInputCallBack {
     fn add_char(&mut self, uni_char: u32) {
-        if let Some(key_event) = unicode_to_key_event(uni_char) {
-            self.key_events.borrow_mut().push(key_event);
-        }
+        // control characters like backspace or enter are reported by the key helpers
+        let character = match char::from_u32(uni_char) {
+            Some(character) if !character.is_control() => character,
+            _ => return,
+        };
+
+        self.key_events.borrow_mut().push(KeyEvent {
+            key: Key::from(character),
+            state: ButtonState::Down,
+            repeat: false,
+        });
+        self.text_input.borrow_mut().push(character.to_string());
     }
 }
 
 struct KeyHelper(bool, minifb::Key, Key);
 
-/// Concrete implementation of the window shell.
-pub struct WindowShell<A>
-where
-    A: WindowAdapter,
-{
+/// Default factory of the desktop shell, creates minifb based windows.
+#[derive(Default)]
+pub struct MinifbWindowFactory;
+
+impl WindowFactory for MinifbWindowFactory {
+    fn create(&mut self, settings: &WindowSettings) -> Result<Box<dyn WindowBackend>, String> {
+        let window_options = minifb::WindowOptions {
+            // a borderless window is resized by its own grips
+            resize: settings.resizeable || settings.borderless,
+            borderless: settings.borderless,
+            title: !settings.borderless,
+            scale_mode: minifb::ScaleMode::UpperLeft,
+            ..Default::default()
+        };
+
+        let mut window = minifb::Window::new(
+            settings.title.as_str(),
+            settings.bounds.width as usize,
+            settings.bounds.height as usize,
+            window_options,
+        )
+        .map_err(|e| format!("MinifbWindowFactory.create: {}", e))?;
+
+        // the frame rate is limited by the loop of the shell runner
+        window.limit_update_rate(None);
+        window.set_position(settings.bounds.x as isize, settings.bounds.y as isize);
+
+        let mut backend = MinifbBackend::new(window);
+        backend.position = (settings.bounds.x, settings.bounds.y);
+
+        Ok(Box::new(backend))
+    }
+}
+
+/// Event source and presenter of a minifb window.
+pub struct MinifbBackend {
     window: minifb::Window,
-    render_context_2_d: RenderContext2D,
-    adapter: A,
+    // native window for the functions that minifb doesn't provide
+    native: Option<NativeWindow>,
+    // last set position of the window, it is used if the native window could not be queried
+    position: (f64, f64),
     mouse_pos: (f32, f32),
     button_down: (bool, bool, bool),
     window_size: (usize, usize),
     key_events: Rc<RefCell<Vec<KeyEvent>>>,
+    text_input: Rc<RefCell<Vec<String>>>,
     // todo: temp solution
     key_backspace: KeyHelper,
     key_delete: KeyHelper,
@@ -104,48 +136,34 @@ where
     key_alt_r: KeyHelper,
     key_escape: KeyHelper,
     key_home: KeyHelper,
-    update: bool,
-    running: bool,
+    key_end: KeyHelper,
+    key_page_up: KeyHelper,
+    key_page_down: KeyHelper,
     active: bool,
-    request_receiver: Receiver<ShellRequest>,
-    request_sender: Sender<ShellRequest>,
+    minimized: bool,
+    presented: bool,
 }
 
-#[cfg(not(target_os = "redox"))]
-unsafe impl<A> HasRawWindowHandle for WindowShell<A>
-where
-    A: WindowAdapter,
-{
-    fn raw_window_handle(&self) -> RawWindowHandle {
-        self.window.raw_window_handle()
-    }
-}
+impl MinifbBackend {
+    /// Creates a new backend for the given window.
+    pub fn new(mut window: minifb::Window) -> Self {
+        let key_events = Rc::new(RefCell::new(vec![]));
+        let text_input = Rc::new(RefCell::new(vec![]));
 
-impl<A> WindowShell<A>
-where
-    A: WindowAdapter,
-{
-    /// Creates a new window shell with an adapter.
-    pub fn new(
-        window: minifb::Window,
-        adapter: A,
-        key_events: Rc<RefCell<Vec<KeyEvent>>>,
-    ) -> WindowShell<A> {
-        let size = window.get_size();
-        let render_context_2_d = RenderContext2D::new(size.0 as f64, size.1 as f64);
-        let (request_sender, request_receiver) = channel();
+        window.set_input_callback(Box::new(KeyInputCallBack {
+            key_events: key_events.clone(),
+            text_input: text_input.clone(),
+        }));
 
-        WindowShell {
+        MinifbBackend {
+            window_size: window.get_size(),
+            native: NativeWindow::new(&window),
             window,
-            // window_size: window_size,
-            // mouse_buttons: (false, false, false),
-            // mouse_position: Point::default(),
-            render_context_2_d,
-            adapter,
+            position: (0.0, 0.0),
             mouse_pos: (0.0, 0.0),
-            window_size: size,
             button_down: (false, false, false),
             key_events,
+            text_input,
             key_backspace: KeyHelper(false, minifb::Key::Backspace, Key::Backspace),
             key_left: KeyHelper(false, minifb::Key::Left, Key::Left),
             key_right: KeyHelper(false, minifb::Key::Right, Key::Right),
@@ -161,14 +179,303 @@ where
             key_alt_r: KeyHelper(false, minifb::Key::RightAlt, Key::Alt),
             key_escape: KeyHelper(false, minifb::Key::Escape, Key::Escape),
             key_home: KeyHelper(false, minifb::Key::Home, Key::Home),
+            key_end: KeyHelper(false, minifb::Key::End, Key::End),
+            key_page_up: KeyHelper(false, minifb::Key::PageUp, Key::PageUp),
+            key_page_down: KeyHelper(false, minifb::Key::PageDown, Key::PageDown),
+            active: false,
+            minimized: false,
+            presented: false,
+        }
+    }
+
+    fn push_mouse_event(&self, events: &mut Vec<ShellEvent>, pressed: bool, button: MouseButton) {
+        let state = if pressed {
+            ButtonState::Down
+        } else {
+            ButtonState::Up
+        };
+
+        events.push(ShellEvent::MouseEvent(MouseEvent {
+            x: self.mouse_pos.0 as f64,
+            y: self.mouse_pos.1 as f64,
+            button,
+            state,
+        }));
+    }
+}
+
+impl EventSource for MinifbBackend {
+    fn poll_events(&mut self) -> Vec<ShellEvent> {
+        // minifb receives the window events only on update
+        if !self.presented {
+            self.window.update();
+        }
+        self.presented = false;
+
+        let mut events = vec![];
+
+        // mouse move
+        if let Some(pos) = self.window.get_mouse_pos(minifb::MouseMode::Discard) {
+            if (pos.0.floor(), pos.1.floor()) != self.mouse_pos {
+                events.push(ShellEvent::Mouse(pos.0 as f64, pos.1 as f64));
+                self.mouse_pos = (pos.0.floor(), pos.1.floor());
+            }
+        }
+
+        // mouse
+        let left_button_down = self.window.get_mouse_down(minifb::MouseButton::Left);
+        let middle_button_down = self.window.get_mouse_down(minifb::MouseButton::Middle);
+        let right_button_down = self.window.get_mouse_down(minifb::MouseButton::Right);
+
+        if self.active != self.window.is_active() {
+            self.active = self.window.is_active();
+            events.push(ShellEvent::Active(self.active));
+        }
+
+        if left_button_down != self.button_down.0 {
+            self.push_mouse_event(&mut events, left_button_down, MouseButton::Left);
+            self.button_down.0 = left_button_down;
+        }
+
+        if middle_button_down != self.button_down.1 {
+            self.push_mouse_event(&mut events, middle_button_down, MouseButton::Middle);
+            self.button_down.1 = middle_button_down;
+        }
+
+        if right_button_down != self.button_down.2 {
+            self.push_mouse_event(&mut events, right_button_down, MouseButton::Right);
+            self.button_down.2 = right_button_down;
+        }
+
+        // scroll
+        if let Some(delta) = self.window.get_scroll_wheel() {
+            events.push(ShellEvent::Scroll(delta.0 as f64, delta.1 as f64));
+        }
+
+        // key
+        for event in self.key_events.borrow_mut().drain(..) {
+            events.push(ShellEvent::KeyEvent(event));
+        }
+
+        for text in self.text_input.borrow_mut().drain(..) {
+            events.push(ShellEvent::TextInput(text));
+        }
+
+        key_event_helper(&mut self.key_backspace, &mut events, &self.window);
+        key_event_helper(&mut self.key_delete, &mut events, &self.window);
+        key_event_helper(&mut self.key_left, &mut events, &self.window);
+        key_event_helper(&mut self.key_right, &mut events, &self.window);
+        key_event_helper(&mut self.key_up, &mut events, &self.window);
+        key_event_helper(&mut self.key_down, &mut events, &self.window);
+        key_event_helper(&mut self.key_enter, &mut events, &self.window);
+        key_event_helper(&mut self.key_control, &mut events, &self.window);
+        key_event_helper(&mut self.key_control_right, &mut events, &self.window);
+        key_event_helper(&mut self.key_shift_l, &mut events, &self.window);
+        key_event_helper(&mut self.key_shift_r, &mut events, &self.window);
+        key_event_helper(&mut self.key_alt, &mut events, &self.window);
+        key_event_helper(&mut self.key_alt_r, &mut events, &self.window);
+        key_event_helper(&mut self.key_escape, &mut events, &self.window);
+        key_event_helper(&mut self.key_home, &mut events, &self.window);
+        key_event_helper(&mut self.key_end, &mut events, &self.window);
+        key_event_helper(&mut self.key_page_up, &mut events, &self.window);
+        key_event_helper(&mut self.key_page_down, &mut events, &self.window);
+
+        // resize
+        if self.window_size != self.window.get_size() {
+            self.window_size = self.window.get_size();
+
+            // minifb reports an empty size for a minimized window
+            let minimized = self.window_size.0 == 0 || self.window_size.1 == 0;
+
+            if minimized != self.minimized {
+                self.minimized = minimized;
+                events.push(ShellEvent::Minimized(minimized));
+            }
+
+            if !minimized {
+                events.push(ShellEvent::Resize(
+                    self.window_size.0 as f64,
+                    self.window_size.1 as f64,
+                ));
+            }
+        }
+
+        events
+    }
+
+    fn is_open(&self) -> bool {
+        self.window.is_open()
+    }
+}
+
+impl Presenter for MinifbBackend {
+    fn present(&mut self, data: &[u32], width: usize, height: usize) {
+        let _ = self.window.update_with_buffer(data, width, height);
+        self.presented = true;
+    }
+
+    fn size(&self) -> (usize, usize) {
+        self.window.get_size()
+    }
+
+    fn set_background_color(&mut self, red: u8, green: u8, blue: u8) {
+        self.window
+            .set_background_color(red as usize, green as usize, blue as usize);
+    }
+
+    fn set_cursor_icon(&mut self, cursor_icon: &CursorIcon) {
+        // minifb could neither hide the cursor nor load custom cursors
+        let style = match cursor_icon {
+            CursorIcon::Hand => minifb::CursorStyle::OpenHand,
+            CursorIcon::Text => minifb::CursorStyle::Ibeam,
+            CursorIcon::Crosshair => minifb::CursorStyle::Crosshair,
+            CursorIcon::ResizeHorizontal => minifb::CursorStyle::ResizeLeftRight,
+            CursorIcon::ResizeVertical => minifb::CursorStyle::ResizeUpDown,
+            CursorIcon::ResizeAll => minifb::CursorStyle::ResizeAll,
+            _ => minifb::CursorStyle::Arrow,
+        };
+
+        self.window.set_cursor_style(style);
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        if let Some(native) = &mut self.native {
+            native.set_visible(visible);
+        }
+    }
+
+    // the taskbar badge is not supported, `set_badge` is ignored
+    fn set_icon(&mut self, data: &[u32], width: usize, height: usize) {
+        if let Some(native) = &mut self.native {
+            native.set_icon(data, width, height);
+        }
+    }
+
+    fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.window.topmost(always_on_top);
+    }
+
+    fn set_opacity(&mut self, opacity: f64) {
+        if let Some(native) = &mut self.native {
+            native.set_opacity(opacity);
+        }
+    }
+
+    fn move_by(&mut self, delta_x: f64, delta_y: f64) {
+        let (x, y) = self.position().unwrap_or(self.position);
+        self.position = (x + delta_x, y + delta_y);
+        self.window
+            .set_position(self.position.0 as isize, self.position.1 as isize);
+    }
+
+    fn resize_to(&mut self, width: f64, height: f64) {
+        if let Some(native) = &mut self.native {
+            native.resize_to(width, height);
+        }
+    }
+
+    fn can_resize(&self) -> bool {
+        self.native.is_some()
+    }
+
+    fn monitors(&self) -> Vec<Monitor> {
+        self.native
+            .as_ref()
+            .map_or_else(Vec::new, |native| native.monitors())
+    }
+
+    // without a native window the position is only changed with `move_by`, a window moved by the
+    // user is not tracked
+    fn position(&self) -> Option<(f64, f64)> {
+        self.native
+            .as_ref()
+            .and_then(|native| native.position())
+            .or(Some(self.position))
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    fn try_raw_window_handle(&self) -> Option<RawWindowHandle> {
+        Some(self.window.raw_window_handle())
+    }
+}
+
+// the minifb backend always has a native window
+#[cfg(not(target_os = "redox"))]
+unsafe impl HasRawWindowHandle for MinifbBackend {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.window.raw_window_handle()
+    }
+}
+
+/// Concrete implementation of the window shell.
+pub struct WindowShell<A>
+where
+    A: WindowAdapter,
+{
+    backend: Box<dyn WindowBackend>,
+    render_context_2_d: RenderContext2D,
+    adapter: A,
+    window_size: (usize, usize),
+    update: bool,
+    running: bool,
+    request_receiver: Receiver<ShellRequest>,
+    request_sender: Sender<ShellRequest>,
+    accessibility_tree: Vec<AccessibilityNode>,
+    cursor_icon: CursorIcon,
+    frame_scheduler: FrameScheduler,
+    event_observer: Option<Box<dyn FnMut(&[ShellEvent])>>,
+}
+
+impl<A> WindowShell<A>
+where
+    A: WindowAdapter,
+{
+    /// Returns the handle of the native window or `None` if the backend has no native window.
+    /// The handle of a `MinifbBackend` is also available with `HasRawWindowHandle`.
+    #[cfg(not(target_os = "redox"))]
+    pub fn try_raw_window_handle(&self) -> Option<RawWindowHandle> {
+        self.backend.try_raw_window_handle()
+    }
+
+    /// Creates a new window shell with a backend and an adapter.
+    pub fn new(backend: Box<dyn WindowBackend>, adapter: A) -> WindowShell<A> {
+        let size = backend.size();
+        let render_context_2_d = RenderContext2D::new(size.0 as f64, size.1 as f64);
+        let (request_sender, request_receiver) = channel();
+
+        WindowShell {
+            backend,
+            render_context_2_d,
+            adapter,
+            window_size: size,
             running: true,
             update: true,
-            active: false,
             request_receiver,
             request_sender,
+            accessibility_tree: vec![],
+            cursor_icon: CursorIcon::default(),
+            frame_scheduler: FrameScheduler::default(),
+            event_observer: None,
         }
     }
 
+    /// Sets the accessibility tree of the window. The desktop backend has no connection to the
+    /// platform accessibility apis yet, the tree could be read with `accessibility_tree`.
+    pub fn set_accessibility_tree(&mut self, nodes: Vec<AccessibilityNode>) {
+        self.accessibility_tree = nodes;
+    }
+
+    /// Sets an observer that is called once per frame with the events of the frame before they
+    /// are handled, e.g. to record the input of the window.
+    pub fn set_event_observer(&mut self, event_observer: impl FnMut(&[ShellEvent]) + 'static) {
+        self.event_observer = Some(Box::new(event_observer));
+    }
+
+    /// Returns the current accessibility tree of the window.
+    pub fn accessibility_tree(&self) -> &[AccessibilityNode] {
+        &self.accessibility_tree
+    }
+
     /// Gets if the shell is running.
     pub fn running(&self) -> bool {
         self.running
@@ -181,8 +488,91 @@ where
 
     /// Sets the background color of the window.
     pub fn set_background_color(&mut self, red: u8, green: u8, blue: u8) {
-        self.window
-            .set_background_color(red as usize, green as usize, blue as usize);
+        self.backend.set_background_color(red, green, blue);
+    }
+
+    /// Sets the icon of the mouse cursor inside of the window.
+    pub fn set_cursor_icon(&mut self, cursor_icon: CursorIcon) {
+        if cursor_icon != self.cursor_icon {
+            self.backend.set_cursor_icon(&cursor_icon);
+            self.cursor_icon = cursor_icon;
+        }
+    }
+
+    /// Shows or hides the window, e.g. of an application that lives in the system tray.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.backend.set_visible(visible);
+    }
+
+    /// Sets the icon of the window.
+    pub fn set_icon(&mut self, icon: &Image) {
+        self.backend
+            .set_icon(icon.data(), icon.width() as usize, icon.height() as usize);
+    }
+
+    /// Sets a badge of the window in the taskbar or removes it with `None`.
+    pub fn set_badge(&mut self, badge: Option<&str>) {
+        self.backend.set_badge(badge);
+    }
+
+    /// Keeps the window above all other windows, if the backend supports it.
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.backend.set_always_on_top(always_on_top);
+    }
+
+    /// Sets the opacity of the whole window, if the backend supports it.
+    pub fn set_opacity(&mut self, opacity: f64) {
+        self.backend.set_opacity(opacity);
+    }
+
+    /// Moves the window by the given offset on the screen.
+    pub fn move_by(&mut self, delta_x: f64, delta_y: f64) {
+        self.backend.move_by(delta_x, delta_y);
+    }
+
+    /// Resizes the window, if the backend supports it.
+    pub fn resize_to(&mut self, width: f64, height: f64) {
+        self.backend.resize_to(width, height);
+    }
+
+    /// Returns `true` if the backend could resize the window with `resize_to`.
+    pub fn can_resize(&self) -> bool {
+        self.backend.can_resize()
+    }
+
+    /// Returns the position of the window on the screen, if the backend knows it.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        self.backend.position()
+    }
+
+    /// Returns the monitors of the system, if the backend could query them.
+    pub fn monitors(&self) -> Vec<Monitor> {
+        self.backend.monitors()
+    }
+
+    /// Gets the scheduler that decides when the window renders a new frame.
+    pub fn frame_scheduler(&self) -> &FrameScheduler {
+        &self.frame_scheduler
+    }
+
+    /// Sets the render mode of the window.
+    pub fn set_render_mode(&mut self, render_mode: RenderMode) {
+        self.frame_scheduler.set_render_mode(render_mode);
+    }
+
+    /// Sets the max frames per second of the window, `0` removes the own frame interval.
+    pub fn set_max_fps(&mut self, max_fps: u32) {
+        self.frame_scheduler.set_max_fps(max_fps);
+    }
+
+    /// Sets the target interval between two frames of the window.
+    pub fn set_frame_interval(&mut self, frame_interval: impl Into<Option<Duration>>) {
+        self.frame_scheduler.set_frame_interval(frame_interval);
+    }
+
+    /// Sets if the frames of the window are paced by vsync.
+    pub fn set_vsync(&mut self, vsync: bool) {
+        self.frame_scheduler.set_vsync(vsync);
     }
 
     /// Sets running.
@@ -210,95 +600,36 @@ where
         &mut self.render_context_2_d
     }
 
-    fn drain_events(&mut self) {
-        // mouse move
-        if let Some(pos) = self.window.get_mouse_pos(minifb::MouseMode::Discard) {
-            if (pos.0.floor(), pos.1.floor()) != self.mouse_pos {
-                self.adapter.mouse(pos.0 as f64, pos.1 as f64);
-                self.mouse_pos = (pos.0.floor(), pos.1.floor());
-            }
-        }
-
-        // mouse
-        let left_button_down = self.window.get_mouse_down(minifb::MouseButton::Left);
-        let middle_button_down = self.window.get_mouse_down(minifb::MouseButton::Middle);
-        let right_button_down = self.window.get_mouse_down(minifb::MouseButton::Right);
-
-        if self.active != self.window.is_active() {
-            self.adapter.active(self.window.is_active());
-            self.active = self.window.is_active();
-        }
+    /// Returns the events that are received by the backend since the last call.
+    pub fn poll_events(&mut self) -> Vec<ShellEvent> {
+        self.backend.poll_events()
+    }
 
-        if left_button_down != self.button_down.0 {
-            if left_button_down {
-                self.push_mouse_event(true, MouseButton::Left);
-            } else {
-                self.push_mouse_event(false, MouseButton::Left);
-            }
-            self.button_down.0 = left_button_down;
-        }
+    /// Passes the given events to the adapter and receives the pending requests.
+    pub fn handle_events(&mut self, events: impl IntoIterator<Item = ShellEvent>) {
+        let events: Vec<ShellEvent> = events.into_iter().collect();
 
-        if middle_button_down != self.button_down.1 {
-            if middle_button_down {
-                self.push_mouse_event(true, MouseButton::Middle);
-            } else {
-                self.push_mouse_event(false, MouseButton::Middle);
-            }
-            self.button_down.1 = middle_button_down;
+        if let Some(event_observer) = &mut self.event_observer {
+            event_observer(&events);
         }
 
-        if right_button_down != self.button_down.2 {
-            if right_button_down {
-                self.push_mouse_event(true, MouseButton::Right);
-            } else {
-                self.push_mouse_event(false, MouseButton::Right);
+        for event in events {
+            if let ShellEvent::Resize(width, height) = event {
+                self.window_size = (width as usize, height as usize);
+                self.render_context_2_d.resize(width, height);
             }
-            self.button_down.2 = right_button_down;
-        }
 
-        // scroll
-        if let Some(delta) = self.window.get_scroll_wheel() {
-            self.adapter.scroll(delta.0 as f64, delta.1 as f64);
-        }
-
-        // key
-        while let Some(event) = self.key_events.borrow_mut().pop() {
-            self.adapter.key_event(event);
+            event.dispatch(&mut self.adapter);
         }
 
-        key_event_helper(&mut self.key_backspace, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_delete, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_left, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_right, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_up, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_down, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_enter, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_control, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_control_right, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_shift_l, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_shift_r, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_alt, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_alt_r, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_escape, &mut self.adapter, &self.window);
-        key_event_helper(&mut self.key_home, &mut self.adapter, &self.window);
-
-        // resize
-        if self.window_size != self.window.get_size() {
-            self.window_size = self.window.get_size();
-            self.render_context_2_d
-                .resize(self.window_size.0 as f64, self.window_size.1 as f64);
-            self.adapter
-                .resize(self.window_size.0 as f64, self.window_size.1 as f64);
-        }
+        self.receive_requests();
+    }
 
-        // receive request
+    /// Receives the pending requests and returns `true` if the content should be updated.
+    pub fn receive_requests(&mut self) -> bool {
         let mut update = self.update();
 
         for request in self.request_receiver.try_iter() {
-            if update {
-                break;
-            }
-
             match request {
                 ShellRequest::Update => {
                     update = true;
@@ -307,28 +638,14 @@ where
         }
 
         self.set_update(update);
+        update
     }
 
-    fn push_mouse_event(&mut self, pressed: bool, button: MouseButton) {
-        let state = if pressed {
-            ButtonState::Down
-        } else {
-            ButtonState::Up
-        };
-
-        self.adapter.mouse_event(MouseEvent {
-            x: self.mouse_pos.0 as f64,
-            y: self.mouse_pos.1 as f64,
-            button,
-            state,
-        });
-    }
-
+    /// Presents the current frame. Returns `false` if there is no frame to present.
     pub fn flip(&mut self) -> bool {
         if let Some(data) = self.render_context_2_d.data() {
-            let _ = self
-                .window
-                .update_with_buffer(data, self.window_size.0, self.window_size.1);
+            self.backend
+                .present(data, self.window_size.0, self.window_size.1);
             CONSOLE.time_end("render");
             return true;
         }
@@ -357,30 +674,78 @@ impl<A> ShellRunner<A>
 where
     A: WindowAdapter,
 {
+    /// Runs the loop of the window until it is closed.
     pub fn run(&mut self) {
+        self.run_with(Vec::new);
+    }
+
+    /// Runs the loop of the window until it is closed and passes the events of the given source,
+    /// e.g. of a system tray icon, in addition to the events of the window. The frames are
+    /// limited and scheduled by the frame scheduler of the window.
+    pub fn run_with(&mut self, mut event_source: impl FnMut() -> Vec<ShellEvent>) {
+        let mut close_requested = false;
+
         loop {
-            if !self.window_shell.borrow().running() || !self.window_shell.borrow().window.is_open()
-            {
+            if !self.window_shell.borrow().running() {
                 break;
             }
 
             // CONSOLE.time("complete run");
 
-            self.updater.update();
+            let frame_start = Instant::now();
 
-            if self.window_shell.borrow_mut().update() {
-                self.window_shell.borrow_mut().set_update(false);
+            let mut events = self.window_shell.borrow_mut().poll_events();
+            events.append(&mut event_source());
+
+            // the user closed the window, the application could veto it e.g. to save its work. A
+            // vetoed window stays open until the application closes it, minifb does not report
+            // further close requests of the window.
+            if !close_requested && !self.window_shell.borrow().backend.is_open() {
+                close_requested = true;
+                events.push(ShellEvent::Quit);
             }
 
-            if !self.window_shell.borrow_mut().flip() {
-                self.window_shell.borrow_mut().window.update();
+            let render = {
+                let mut window_shell = self.window_shell.borrow_mut();
+                let dirty = !events.is_empty() || window_shell.receive_requests();
+                window_shell.frame_scheduler().should_render(dirty)
+            };
+
+            if render {
+                self.tick(events);
             }
 
-            self.window_shell.borrow_mut().drain_events();
+            let wait_duration = self
+                .window_shell
+                .borrow()
+                .frame_scheduler()
+                .wait_duration(frame_start.elapsed(), render);
+            thread::sleep(wait_duration);
 
             // CONSOLE.time_end("complete run");
         }
     }
+
+    /// Runs one frame with the given events: passes the events to the adapter, updates the
+    /// content and presents it. Could be used to drive the window from an external event loop.
+    /// Returns `false` if the shell is not running anymore.
+    pub fn tick(&mut self, events: impl IntoIterator<Item = ShellEvent>) -> bool {
+        if !self.window_shell.borrow().running() {
+            return false;
+        }
+
+        self.window_shell.borrow_mut().handle_events(events);
+
+        self.updater.update();
+
+        if self.window_shell.borrow_mut().update() {
+            self.window_shell.borrow_mut().set_update(false);
+        }
+
+        self.window_shell.borrow_mut().flip();
+
+        self.window_shell.borrow().running()
+    }
 }
 
 /// Constructs the window shell
@@ -388,11 +753,11 @@ pub struct WindowBuilder<A>
 where
     A: WindowAdapter,
 {
-    title: String,
+    settings: WindowSettings,
 
-    resizeable: bool,
+    factory: Box<dyn WindowFactory>,
 
-    bounds: Rectangle,
+    frame_scheduler: FrameScheduler,
 
     adapter: A,
 }
@@ -405,60 +770,79 @@ where
     pub fn new(adapter: A) -> Self {
         WindowBuilder {
             adapter,
-            title: String::default(),
-            resizeable: false,
-            bounds: Rectangle::default(),
+            settings: WindowSettings::default(),
+            factory: Box::new(MinifbWindowFactory),
+            frame_scheduler: FrameScheduler::default(),
         }
     }
 
     /// Sets the title.
     pub fn title(mut self, title: impl Into<String>) -> Self {
-        self.title = title.into();
+        self.settings.title = title.into();
         self
     }
 
     /// Sets resizeable.
     pub fn resizeable(mut self, resizeable: bool) -> Self {
-        self.resizeable = resizeable;
+        self.settings.resizeable = resizeable;
+        self
+    }
+
+    /// Creates the window without border and title bar, default is `false`.
+    pub fn borderless(mut self, borderless: bool) -> Self {
+        self.settings.borderless = borderless;
         self
     }
 
     /// Sets the bounds.
     pub fn bounds(mut self, bounds: impl Into<Rectangle>) -> Self {
-        self.bounds = bounds.into();
+        self.settings.bounds = bounds.into();
         self
     }
 
-    /// Builds the window shell.
-    pub fn build(self) -> WindowShell<A> {
-        let window_options = minifb::WindowOptions {
-            resize: self.resizeable,
-            scale_mode: minifb::ScaleMode::UpperLeft,
-            ..Default::default()
-        };
+    /// Sets the render mode, default is `RenderMode::Continuous`.
+    pub fn render_mode(mut self, render_mode: RenderMode) -> Self {
+        self.frame_scheduler.set_render_mode(render_mode);
+        self
+    }
 
-        let mut window = minifb::Window::new(
-            self.title.as_str(),
-            self.bounds.width as usize,
-            self.bounds.height as usize,
-            window_options,
-        )
-        .unwrap_or_else(|e| {
-            panic!("{}", e);
-        });
+    /// Sets the target frame interval by max frames per second. `0` removes it, default is `0`.
+    pub fn max_fps(mut self, max_fps: u32) -> Self {
+        self.frame_scheduler.set_max_fps(max_fps);
+        self
+    }
 
-        // Limit to max ~60 fps update rate
-        window.limit_update_rate(Some(Duration::from_micros(16600)));
+    /// Sets the target interval between two frames, it has priority over vsync. Default is
+    /// `None`.
+    pub fn frame_interval(mut self, frame_interval: impl Into<Option<Duration>>) -> Self {
+        self.frame_scheduler.set_frame_interval(frame_interval);
+        self
+    }
 
-        let key_events = Rc::new(RefCell::new(vec![]));
+    /// Sets vsync, default is `true`. Without vsync and frame interval the frame rate is not
+    /// limited, e.g. for games that are embedded in the ui. minifb has no access to the
+    /// vertical blank, its frames are paced with the `REFRESH_INTERVAL`.
+    pub fn vsync(mut self, vsync: bool) -> Self {
+        self.settings.vsync = vsync;
+        self.frame_scheduler.set_vsync(vsync);
+        self
+    }
 
-        window.set_input_callback(Box::new(KeyInputCallBack {
-            key_events: key_events.clone(),
-        }));
+    /// Sets the factory that creates the backend of the window. Default is `MinifbWindowFactory`.
+    pub fn factory(mut self, factory: impl WindowFactory + 'static) -> Self {
+        self.factory = Box::new(factory);
+        self
+    }
 
-        window.set_position(self.bounds.x as isize, self.bounds.y as isize);
+    /// Builds the window shell.
+    pub fn build(mut self) -> WindowShell<A> {
+        let backend = self.factory.create(&self.settings).unwrap_or_else(|e| {
+            panic!("{}", e);
+        });
 
-        WindowShell::new(window, self.adapter, key_events)
+        let mut window_shell = WindowShell::new(backend, self.adapter);
+        window_shell.frame_scheduler = self.frame_scheduler;
+        window_shell
     }
 }
 
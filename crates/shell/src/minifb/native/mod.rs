@@ -0,0 +1,27 @@
+//! This module contains the functions of the native window that minifb doesn't provide. They are
+//! implemented for X11 and Windows, on other platforms the window is not changed. On Windows it
+//! also contains the `Win32Tray` backend of the system tray.
+
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "redox"))))]
+pub use self::x11::NativeWindow;
+
+#[cfg(windows)]
+pub use self::win32::NativeWindow;
+
+#[cfg(windows)]
+pub use self::win32_tray::Win32Tray;
+
+#[cfg(not(any(windows, all(unix, not(any(target_os = "macos", target_os = "redox"))))))]
+pub use self::unsupported::NativeWindow;
+
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "redox"))))]
+mod x11;
+
+#[cfg(windows)]
+mod win32;
+
+#[cfg(windows)]
+mod win32_tray;
+
+#[cfg(not(any(windows, all(unix, not(any(target_os = "macos", target_os = "redox"))))))]
+mod unsupported;
@@ -0,0 +1,35 @@
+use crate::monitor::Monitor;
+
+/// Native window of a platform without an implementation, it could not be created.
+pub enum NativeWindow {}
+
+impl NativeWindow {
+    /// Returns always `None`.
+    pub fn new(_window: &minifb::Window) -> Option<Self> {
+        None
+    }
+
+    pub fn set_visible(&mut self, _visible: bool) {
+        match *self {}
+    }
+
+    pub fn set_icon(&mut self, _data: &[u32], _width: usize, _height: usize) {
+        match *self {}
+    }
+
+    pub fn set_opacity(&mut self, _opacity: f64) {
+        match *self {}
+    }
+
+    pub fn position(&self) -> Option<(f64, f64)> {
+        match *self {}
+    }
+
+    pub fn resize_to(&mut self, _width: f64, _height: f64) {
+        match *self {}
+    }
+
+    pub fn monitors(&self) -> Vec<Monitor> {
+        match *self {}
+    }
+}
@@ -0,0 +1,241 @@
+use std::{mem, os::raw::c_int, ptr};
+
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+use winapi::{
+    shared::{
+        minwindef::{BOOL, DWORD, LPARAM, TRUE, WPARAM},
+        windef::{HDC, HICON, HMONITOR, HWND, LPRECT, RECT},
+    },
+    um::winuser,
+};
+
+use crate::{monitor::Monitor, utils::Rectangle};
+
+// Returns the rectangle with the bounds of the given windows rect.
+fn rectangle(rect: &RECT) -> Rectangle {
+    Rectangle::new(
+        rect.left as f64,
+        rect.top as f64,
+        (rect.right - rect.left) as f64,
+        (rect.bottom - rect.top) as f64,
+    )
+}
+
+// Adds the monitor with the given handle to the list of monitors that is passed as `data`.
+unsafe extern "system" fn add_monitor(handle: HMONITOR, _: HDC, _: LPRECT, data: LPARAM) -> BOOL {
+    let monitors = &mut *(data as *mut Vec<Monitor>);
+    let mut info: winuser::MONITORINFOEXW = mem::zeroed();
+    info.cbSize = mem::size_of::<winuser::MONITORINFOEXW>() as DWORD;
+
+    if winuser::GetMonitorInfoW(handle, &mut info as *mut _ as winuser::LPMONITORINFO) != 0 {
+        let name_len = info
+            .szDevice
+            .iter()
+            .position(|c| *c == 0)
+            .unwrap_or(winuser::CCHDEVICENAME);
+
+        let mut monitor = Monitor::new(
+            String::from_utf16_lossy(&info.szDevice[..name_len]),
+            rectangle(&info.rcMonitor),
+        );
+        monitor.work_area = rectangle(&info.rcWork);
+        monitor.primary = info.dwFlags & winuser::MONITORINFOF_PRIMARY != 0;
+        monitors.push(monitor);
+    }
+
+    TRUE
+}
+
+// Creates an icon from a pixel buffer in ARGB format, returns a null handle if it fails. The icon
+// has to be destroyed with `DestroyIcon`.
+pub(super) fn create_icon(data: &[u32], width: usize, height: usize) -> HICON {
+    if data.is_empty() || data.len() != width * height {
+        return ptr::null_mut();
+    }
+
+    // the pixels are BGRA in memory and their alpha is used instead of the empty mask, the rows
+    // of the mask are word aligned
+    let mask = vec![0_u8; width.div_ceil(16) * 2 * height];
+
+    unsafe {
+        winuser::CreateIcon(
+            ptr::null_mut(),
+            width as c_int,
+            height as c_int,
+            1,
+            32,
+            mask.as_ptr(),
+            data.as_ptr() as *const u8,
+        )
+    }
+}
+
+/// Win32 window of a minifb window.
+pub struct NativeWindow {
+    hwnd: HWND,
+    // icon that is set by `set_icon`, it is destroyed when it is replaced
+    icon: HICON,
+}
+
+impl NativeWindow {
+    /// Returns the Win32 window of the given minifb window.
+    pub fn new(window: &minifb::Window) -> Option<Self> {
+        match window.raw_window_handle() {
+            RawWindowHandle::Windows(handle) if !handle.hwnd.is_null() => Some(NativeWindow {
+                hwnd: handle.hwnd as HWND,
+                icon: ptr::null_mut(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Shows or hides the window.
+    pub fn set_visible(&mut self, visible: bool) {
+        let command = if visible {
+            winuser::SW_SHOW
+        } else {
+            winuser::SW_HIDE
+        };
+
+        unsafe {
+            winuser::ShowWindow(self.hwnd, command);
+        }
+    }
+
+    /// Sets the small and the big icon of the window from a pixel buffer in ARGB format.
+    pub fn set_icon(&mut self, data: &[u32], width: usize, height: usize) {
+        let icon = create_icon(data, width, height);
+
+        if icon.is_null() {
+            return;
+        }
+
+        unsafe {
+            for size in &[winuser::ICON_SMALL, winuser::ICON_BIG] {
+                winuser::SendMessageW(
+                    self.hwnd,
+                    winuser::WM_SETICON,
+                    *size as WPARAM,
+                    icon as LPARAM,
+                );
+            }
+
+            if !self.icon.is_null() {
+                winuser::DestroyIcon(self.icon);
+            }
+
+            self.icon = icon;
+        }
+    }
+
+    /// Sets the opacity of the window, an opaque window is not layered.
+    pub fn set_opacity(&mut self, opacity: f64) {
+        unsafe {
+            let style = winuser::GetWindowLongPtrW(self.hwnd, winuser::GWL_EXSTYLE) as DWORD;
+
+            if opacity >= 1.0 {
+                winuser::SetWindowLongPtrW(
+                    self.hwnd,
+                    winuser::GWL_EXSTYLE,
+                    (style & !winuser::WS_EX_LAYERED) as _,
+                );
+                return;
+            }
+
+            winuser::SetWindowLongPtrW(
+                self.hwnd,
+                winuser::GWL_EXSTYLE,
+                (style | winuser::WS_EX_LAYERED) as _,
+            );
+            winuser::SetLayeredWindowAttributes(
+                self.hwnd,
+                0,
+                (opacity.clamp(0.0, 1.0) * 255.0).round() as u8,
+                winuser::LWA_ALPHA,
+            );
+        }
+    }
+
+    /// Returns the position of the window frame on the screen.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        let rect = self.window_rect()?;
+
+        Some((rect.left as f64, rect.top as f64))
+    }
+
+    /// Resizes the client area of the window, the frame is added to the given size.
+    pub fn resize_to(&mut self, width: f64, height: f64) {
+        let (window, client) = match (self.window_rect(), self.client_rect()) {
+            (Some(window), Some(client)) => (window, client),
+            _ => return,
+        };
+
+        let frame_width = (window.right - window.left) - (client.right - client.left);
+        let frame_height = (window.bottom - window.top) - (client.bottom - client.top);
+
+        unsafe {
+            winuser::SetWindowPos(
+                self.hwnd,
+                ptr::null_mut(),
+                0,
+                0,
+                width.max(1.0) as c_int + frame_width,
+                height.max(1.0) as c_int + frame_height,
+                winuser::SWP_NOMOVE | winuser::SWP_NOZORDER | winuser::SWP_NOACTIVATE,
+            );
+        }
+    }
+
+    /// Returns the display monitors. minifb windows are not DPI aware, the monitors are described
+    /// in the scaled pixels of the window.
+    pub fn monitors(&self) -> Vec<Monitor> {
+        let mut monitors: Vec<Monitor> = vec![];
+
+        unsafe {
+            winuser::EnumDisplayMonitors(
+                ptr::null_mut(),
+                ptr::null(),
+                Some(add_monitor),
+                &mut monitors as *mut Vec<Monitor> as LPARAM,
+            );
+        }
+
+        monitors
+    }
+
+    // Returns the bounds of the window with its frame in screen coordinates.
+    fn window_rect(&self) -> Option<RECT> {
+        unsafe {
+            let mut rect: RECT = mem::zeroed();
+
+            if winuser::GetWindowRect(self.hwnd, &mut rect) == 0 {
+                return None;
+            }
+
+            Some(rect)
+        }
+    }
+
+    // Returns the bounds of the client area, its position is always zero.
+    fn client_rect(&self) -> Option<RECT> {
+        unsafe {
+            let mut rect: RECT = mem::zeroed();
+
+            if winuser::GetClientRect(self.hwnd, &mut rect) == 0 {
+                return None;
+            }
+
+            Some(rect)
+        }
+    }
+}
+
+impl Drop for NativeWindow {
+    fn drop(&mut self) {
+        if !self.icon.is_null() {
+            unsafe {
+                winuser::DestroyIcon(self.icon);
+            }
+        }
+    }
+}
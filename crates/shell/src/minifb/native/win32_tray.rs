@@ -0,0 +1,314 @@
+use std::{mem, ptr};
+
+use winapi::{
+    shared::{
+        minwindef::{LPARAM, LRESULT, UINT, WPARAM},
+        windef::{HICON, HMENU, HWND, POINT},
+    },
+    um::{libloaderapi, shellapi, winuser},
+};
+
+use super::win32::create_icon;
+use crate::{
+    render::Image,
+    tray::{TrayBackend, TrayEvent, TrayMenuItem, TraySettings},
+};
+
+// Message that the shell sends to the tray window on input on the icon.
+const WM_TRAY: UINT = winuser::WM_APP + 1;
+
+// Returns the given text as nul terminated utf-16.
+fn wide(text: &str) -> Vec<u16> {
+    text.encode_utf16().chain(Some(0)).collect()
+}
+
+// State of the tray window that is shared with its window procedure by the user data of the
+// window.
+struct TrayState {
+    menu: HMENU,
+    ids: Vec<String>,
+    events: Vec<TrayEvent>,
+}
+
+impl TrayState {
+    // Replaces the menu with a menu of the given items. The command of an item is its index plus
+    // one, `TrackPopupMenu` returns zero if no item is selected.
+    unsafe fn set_menu(&mut self, items: &[TrayMenuItem]) {
+        if !self.menu.is_null() {
+            winuser::DestroyMenu(self.menu);
+            self.menu = ptr::null_mut();
+        }
+
+        self.ids = items.iter().map(|item| item.id.clone()).collect();
+
+        if items.is_empty() {
+            return;
+        }
+
+        self.menu = winuser::CreatePopupMenu();
+
+        for (index, item) in items.iter().enumerate() {
+            let flags = if item.enabled {
+                winuser::MF_STRING
+            } else {
+                winuser::MF_STRING | winuser::MF_GRAYED
+            };
+
+            winuser::AppendMenuW(self.menu, flags, index + 1, wide(&item.text).as_ptr());
+        }
+    }
+
+    // Shows the menu at the cursor and adds the event of the selected item.
+    unsafe fn show_menu(&mut self, hwnd: HWND) {
+        if self.menu.is_null() {
+            return;
+        }
+
+        let mut cursor = POINT { x: 0, y: 0 };
+        winuser::GetCursorPos(&mut cursor);
+
+        // the menu is only closed by a click outside of it if its window is in the foreground
+        winuser::SetForegroundWindow(hwnd);
+        let command = winuser::TrackPopupMenu(
+            self.menu,
+            winuser::TPM_RETURNCMD | winuser::TPM_NONOTIFY | winuser::TPM_RIGHTBUTTON,
+            cursor.x,
+            cursor.y,
+            0,
+            hwnd,
+            ptr::null(),
+        );
+        winuser::PostMessageW(hwnd, winuser::WM_NULL, 0, 0);
+
+        if let Some(id) = (command as usize)
+            .checked_sub(1)
+            .and_then(|index| self.ids.get(index))
+        {
+            self.events.push(TrayEvent::MenuItem(id.clone()));
+        }
+    }
+}
+
+// Window procedure of the tray window, it turns the input on the icon into tray events.
+unsafe extern "system" fn window_proc(
+    hwnd: HWND,
+    message: UINT,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    let state = winuser::GetWindowLongPtrW(hwnd, winuser::GWLP_USERDATA) as *mut TrayState;
+
+    if message != WM_TRAY || state.is_null() {
+        return winuser::DefWindowProcW(hwnd, message, wparam, lparam);
+    }
+
+    // the mouse message on the icon is passed as `lparam`
+    match lparam as UINT {
+        winuser::WM_LBUTTONUP => (*state).events.push(TrayEvent::Click),
+        winuser::WM_LBUTTONDBLCLK => (*state).events.push(TrayEvent::DoubleClick),
+        winuser::WM_RBUTTONUP => (*state).show_menu(hwnd),
+        _ => {}
+    }
+
+    0
+}
+
+/// Tray icon of Windows that is shown with `Shell_NotifyIconW`. The icon image is loaded from the
+/// path of the settings and the menu is shown on a right click. The input on the icon is received
+/// by a hidden message window on the thread of the application.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Application::new()
+///     .system_tray(
+///         SystemTray::new(Win32Tray::new())
+///             .icon("res/icon.png")
+///             .tooltip("OrbTk")
+///             .menu_item("quit", "Quit"),
+///     )
+/// ```
+pub struct Win32Tray {
+    hwnd: HWND,
+    icon: HICON,
+    visible: bool,
+    state: *mut TrayState,
+}
+
+impl Default for Win32Tray {
+    fn default() -> Self {
+        Win32Tray {
+            hwnd: ptr::null_mut(),
+            icon: ptr::null_mut(),
+            visible: false,
+            state: Box::into_raw(Box::new(TrayState {
+                menu: ptr::null_mut(),
+                ids: vec![],
+                events: vec![],
+            })),
+        }
+    }
+}
+
+impl Win32Tray {
+    /// Creates a new tray, its window is created when the icon is shown.
+    pub fn new() -> Self {
+        Win32Tray::default()
+    }
+
+    // Creates the message window that receives the input on the icon.
+    unsafe fn create_window(&mut self) -> Result<(), String> {
+        let class_name = wide("OrbTkTray");
+        let instance = libloaderapi::GetModuleHandleW(ptr::null());
+
+        let mut class: winuser::WNDCLASSW = mem::zeroed();
+        class.lpfnWndProc = Some(window_proc);
+        class.hInstance = instance;
+        class.lpszClassName = class_name.as_ptr();
+
+        // fails if the class is already registered by another tray, that is fine
+        winuser::RegisterClassW(&class);
+
+        self.hwnd = winuser::CreateWindowExW(
+            0,
+            class_name.as_ptr(),
+            class_name.as_ptr(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            winuser::HWND_MESSAGE,
+            ptr::null_mut(),
+            instance,
+            ptr::null_mut(),
+        );
+
+        if self.hwnd.is_null() {
+            return Err("Could not create the tray window.".to_string());
+        }
+
+        winuser::SetWindowLongPtrW(self.hwnd, winuser::GWLP_USERDATA, self.state as _);
+
+        Ok(())
+    }
+
+    // Returns the description of the icon of the tray window.
+    fn icon_data(&self) -> shellapi::NOTIFYICONDATAW {
+        let mut data: shellapi::NOTIFYICONDATAW = unsafe { mem::zeroed() };
+        data.cbSize = mem::size_of::<shellapi::NOTIFYICONDATAW>() as u32;
+        data.hWnd = self.hwnd;
+        data.uID = 1;
+        data
+    }
+}
+
+impl TrayBackend for Win32Tray {
+    fn show(&mut self, settings: &TraySettings) -> Result<(), String> {
+        let image = Image::from_path(&settings.icon)
+            .map_err(|e| format!("Could not load the icon {}: {}", settings.icon, e))?;
+
+        unsafe {
+            if self.hwnd.is_null() {
+                self.create_window()?;
+            }
+
+            let icon = create_icon(
+                image.data(),
+                image.width() as usize,
+                image.height() as usize,
+            );
+
+            if icon.is_null() {
+                return Err(format!("Could not create the icon {}.", settings.icon));
+            }
+
+            (*self.state).set_menu(&settings.menu);
+
+            let mut data = self.icon_data();
+            data.uFlags = shellapi::NIF_ICON | shellapi::NIF_MESSAGE | shellapi::NIF_TIP;
+            data.uCallbackMessage = WM_TRAY;
+            data.hIcon = icon;
+
+            // the tooltip is cut to the size of the buffer, the last character stays nul
+            for (target, c) in data.szTip[..127]
+                .iter_mut()
+                .zip(settings.tooltip.encode_utf16())
+            {
+                *target = c;
+            }
+
+            let command = if self.visible {
+                shellapi::NIM_MODIFY
+            } else {
+                shellapi::NIM_ADD
+            };
+
+            if shellapi::Shell_NotifyIconW(command, &mut data) == 0 {
+                winuser::DestroyIcon(icon);
+                return Err("Could not show the tray icon.".to_string());
+            }
+
+            if !self.icon.is_null() {
+                winuser::DestroyIcon(self.icon);
+            }
+
+            self.icon = icon;
+            self.visible = true;
+        }
+
+        Ok(())
+    }
+
+    fn hide(&mut self) {
+        if !self.visible {
+            return;
+        }
+
+        let mut data = self.icon_data();
+
+        unsafe {
+            shellapi::Shell_NotifyIconW(shellapi::NIM_DELETE, &mut data);
+        }
+
+        self.visible = false;
+    }
+
+    fn poll_events(&mut self) -> Vec<TrayEvent> {
+        if !self.hwnd.is_null() {
+            unsafe {
+                let mut message: winuser::MSG = mem::zeroed();
+
+                while winuser::PeekMessageW(&mut message, self.hwnd, 0, 0, winuser::PM_REMOVE) != 0
+                {
+                    winuser::TranslateMessage(&message);
+                    winuser::DispatchMessageW(&message);
+                }
+            }
+        }
+
+        unsafe { mem::take(&mut (*self.state).events) }
+    }
+}
+
+impl Drop for Win32Tray {
+    fn drop(&mut self) {
+        self.hide();
+
+        unsafe {
+            if !self.hwnd.is_null() {
+                winuser::DestroyWindow(self.hwnd);
+            }
+
+            if !self.icon.is_null() {
+                winuser::DestroyIcon(self.icon);
+            }
+
+            let state = Box::from_raw(self.state);
+
+            if !state.menu.is_null() {
+                winuser::DestroyMenu(state.menu);
+            }
+        }
+    }
+}
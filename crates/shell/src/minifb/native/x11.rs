@@ -0,0 +1,427 @@
+use std::{
+    os::raw::{c_char, c_int, c_long, c_uchar, c_uint, c_ulong},
+    ptr, slice,
+};
+
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+use x11_dl::{xinerama, xlib, xrandr};
+
+use crate::{monitor::Monitor, utils::Rectangle};
+
+// Returns the value of the `_NET_WM_ICON` property, the width and height followed by the ARGB
+// pixels. Properties with a format of 32 bit are passed as longs.
+fn icon_property(data: &[u32], width: usize, height: usize) -> Vec<c_ulong> {
+    [width as c_ulong, height as c_ulong]
+        .iter()
+        .cloned()
+        .chain(data.iter().map(|pixel| c_ulong::from(*pixel)))
+        .collect()
+}
+
+// Returns the value of the `_NET_WM_WINDOW_OPACITY` property, `0xffffffff` is opaque.
+fn opacity_property(opacity: f64) -> c_ulong {
+    (opacity.clamp(0.0, 1.0) * f64::from(u32::MAX)).round() as c_ulong
+}
+
+// Returns the part of the given work area that is on the monitor, the whole monitor if they
+// don't intersect.
+fn monitor_work_area(bounds: Rectangle, work_area: Rectangle) -> Rectangle {
+    let x = bounds.x().max(work_area.x());
+    let y = bounds.y().max(work_area.y());
+    let width = (bounds.x() + bounds.width()).min(work_area.x() + work_area.width()) - x;
+    let height = (bounds.y() + bounds.height()).min(work_area.y() + work_area.height()) - y;
+
+    if width <= 0.0 || height <= 0.0 {
+        return bounds;
+    }
+
+    Rectangle::new(x, y, width, height)
+}
+
+// Returns the index of the primary screen, the screen at the origin of the primary RandR output.
+// Without a primary output the first screen is used, most servers list the primary screen first.
+fn primary_screen(screens: &[Rectangle], primary: Option<Rectangle>) -> usize {
+    primary
+        .and_then(|primary| {
+            screens
+                .iter()
+                .position(|screen| screen.x() == primary.x() && screen.y() == primary.y())
+        })
+        .unwrap_or(0)
+}
+
+/// Xlib window of a minifb window.
+pub struct NativeWindow {
+    xlib: xlib::Xlib,
+    // Xinerama describes the monitors, it is `None` if libXinerama could not be loaded
+    xinerama: Option<xinerama::Xlib>,
+    // RandR tells the primary monitor, it is `None` if libXrandr could not be loaded
+    xrandr: Option<xrandr::Xrandr>,
+    display: *mut xlib::Display,
+    window: xlib::Window,
+}
+
+impl NativeWindow {
+    /// Returns the Xlib window of the given minifb window, `None` if libX11 could not be loaded.
+    pub fn new(window: &minifb::Window) -> Option<Self> {
+        let handle = match window.raw_window_handle() {
+            RawWindowHandle::Xlib(handle) => handle,
+            _ => return None,
+        };
+
+        if handle.display.is_null() {
+            return None;
+        }
+
+        Some(NativeWindow {
+            xlib: xlib::Xlib::open().ok()?,
+            xinerama: xinerama::Xlib::open().ok(),
+            xrandr: xrandr::Xrandr::open().ok(),
+            display: handle.display as *mut xlib::Display,
+            window: handle.window,
+        })
+    }
+
+    /// Maps or unmaps the window.
+    pub fn set_visible(&mut self, visible: bool) {
+        unsafe {
+            if visible {
+                (self.xlib.XMapRaised)(self.display, self.window);
+            } else {
+                (self.xlib.XUnmapWindow)(self.display, self.window);
+            }
+
+            (self.xlib.XFlush)(self.display);
+        }
+    }
+
+    /// Sets the `_NET_WM_ICON` of the window from a pixel buffer in ARGB format.
+    pub fn set_icon(&mut self, data: &[u32], width: usize, height: usize) {
+        if data.is_empty() || data.len() != width * height {
+            return;
+        }
+
+        let property = icon_property(data, width, height);
+
+        unsafe {
+            (self.xlib.XChangeProperty)(
+                self.display,
+                self.window,
+                self.atom(b"_NET_WM_ICON\0"),
+                xlib::XA_CARDINAL,
+                32,
+                xlib::PropModeReplace,
+                property.as_ptr() as *const c_uchar,
+                property.len() as c_int,
+            );
+            (self.xlib.XFlush)(self.display);
+        }
+    }
+
+    /// Sets the `_NET_WM_WINDOW_OPACITY` of the window, that is applied by the compositor.
+    pub fn set_opacity(&mut self, opacity: f64) {
+        let atom = self.atom(b"_NET_WM_WINDOW_OPACITY\0");
+
+        unsafe {
+            // an opaque window has no opacity property
+            if opacity >= 1.0 {
+                (self.xlib.XDeleteProperty)(self.display, self.window, atom);
+            } else {
+                let value = opacity_property(opacity);
+
+                (self.xlib.XChangeProperty)(
+                    self.display,
+                    self.window,
+                    atom,
+                    xlib::XA_CARDINAL,
+                    32,
+                    xlib::PropModeReplace,
+                    &value as *const c_ulong as *const c_uchar,
+                    1,
+                );
+            }
+
+            (self.xlib.XFlush)(self.display);
+        }
+    }
+
+    /// Returns the position of the window frame on the screen.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        let (mut x, mut y, mut child) = (0, 0, 0);
+
+        let translated = unsafe {
+            (self.xlib.XTranslateCoordinates)(
+                self.display,
+                self.window,
+                (self.xlib.XDefaultRootWindow)(self.display),
+                0,
+                0,
+                &mut x,
+                &mut y,
+                &mut child,
+            )
+        };
+
+        if translated == xlib::False {
+            return None;
+        }
+
+        // the window is placed inside of the frame of the window manager, left and top border
+        let extents = self.cardinals(self.window, b"_NET_FRAME_EXTENTS\0");
+        let (left, top) = match extents.as_slice() {
+            [left, _, top, _] => (*left as f64, *top as f64),
+            _ => (0.0, 0.0),
+        };
+
+        Some((x as f64 - left, y as f64 - top))
+    }
+
+    /// Resizes the drawing area of the window.
+    pub fn resize_to(&mut self, width: f64, height: f64) {
+        unsafe {
+            (self.xlib.XResizeWindow)(
+                self.display,
+                self.window,
+                width.max(1.0) as c_uint,
+                height.max(1.0) as c_uint,
+            );
+            (self.xlib.XFlush)(self.display);
+        }
+    }
+
+    /// Returns the Xinerama screens or the whole screen if Xinerama is not active. The work area
+    /// is the part of the `_NET_WORKAREA` of the current desktop that is on the monitor.
+    pub fn monitors(&self) -> Vec<Monitor> {
+        let root = unsafe { (self.xlib.XDefaultRootWindow)(self.display) };
+        let desktop = self
+            .cardinals(root, b"_NET_CURRENT_DESKTOP\0")
+            .first()
+            .map_or(0, |desktop| *desktop as usize);
+        let work_area = self
+            .cardinals(root, b"_NET_WORKAREA\0")
+            .chunks(4)
+            .nth(desktop)
+            .and_then(|area| match area {
+                [x, y, width, height] => Some(Rectangle::new(
+                    *x as f64,
+                    *y as f64,
+                    *width as f64,
+                    *height as f64,
+                )),
+                _ => None,
+            });
+
+        let mut screens = self.screens();
+
+        if screens.is_empty() {
+            let (width, height) = unsafe {
+                let screen = (self.xlib.XDefaultScreen)(self.display);
+                (
+                    (self.xlib.XDisplayWidth)(self.display, screen),
+                    (self.xlib.XDisplayHeight)(self.display, screen),
+                )
+            };
+
+            screens.push(Rectangle::new(0.0, 0.0, width as f64, height as f64));
+        }
+
+        let primary = primary_screen(&screens, self.primary_bounds(root));
+
+        // minifb doesn't scale its windows, the monitors are described in pixels
+        screens
+            .into_iter()
+            .enumerate()
+            .map(|(index, bounds)| {
+                let mut monitor = Monitor::new(format!("screen {}", index), bounds);
+
+                if let Some(work_area) = work_area {
+                    monitor.work_area = monitor_work_area(bounds, work_area);
+                }
+
+                monitor.primary = index == primary;
+                monitor
+            })
+            .collect()
+    }
+
+    // Returns the bounds of the Xinerama screens.
+    fn screens(&self) -> Vec<Rectangle> {
+        let xinerama = match &self.xinerama {
+            Some(xinerama) => xinerama,
+            None => return vec![],
+        };
+
+        unsafe {
+            if (xinerama.XineramaIsActive)(self.display) == xlib::False {
+                return vec![];
+            }
+
+            let mut count = 0;
+            let screens = (xinerama.XineramaQueryScreens)(self.display, &mut count);
+
+            if screens.is_null() {
+                return vec![];
+            }
+
+            let bounds = slice::from_raw_parts(screens, count as usize)
+                .iter()
+                .map(|screen| {
+                    Rectangle::new(
+                        f64::from(screen.x_org),
+                        f64::from(screen.y_org),
+                        f64::from(screen.width),
+                        f64::from(screen.height),
+                    )
+                })
+                .collect();
+
+            (self.xlib.XFree)(screens as *mut _);
+
+            bounds
+        }
+    }
+
+    // Returns the bounds of the crtc of the primary RandR output, `None` if no output is primary
+    // or libXrandr could not be loaded.
+    fn primary_bounds(&self, root: xlib::Window) -> Option<Rectangle> {
+        let xrandr = self.xrandr.as_ref()?;
+
+        unsafe {
+            let output = (xrandr.XRRGetOutputPrimary)(self.display, root);
+
+            if output == 0 {
+                return None;
+            }
+
+            let resources = (xrandr.XRRGetScreenResourcesCurrent)(self.display, root);
+
+            if resources.is_null() {
+                return None;
+            }
+
+            let mut bounds = None;
+            let output_info = (xrandr.XRRGetOutputInfo)(self.display, resources, output);
+
+            if !output_info.is_null() {
+                if (*output_info).crtc != 0 {
+                    let crtc_info =
+                        (xrandr.XRRGetCrtcInfo)(self.display, resources, (*output_info).crtc);
+
+                    if !crtc_info.is_null() {
+                        bounds = Some(Rectangle::new(
+                            f64::from((*crtc_info).x),
+                            f64::from((*crtc_info).y),
+                            f64::from((*crtc_info).width),
+                            f64::from((*crtc_info).height),
+                        ));
+                        (xrandr.XRRFreeCrtcInfo)(crtc_info);
+                    }
+                }
+
+                (xrandr.XRRFreeOutputInfo)(output_info);
+            }
+
+            (xrandr.XRRFreeScreenResources)(resources);
+
+            bounds
+        }
+    }
+
+    // Returns the values of the given cardinal property of a window, empty if it is not set.
+    fn cardinals(&self, window: xlib::Window, name: &[u8]) -> Vec<c_ulong> {
+        let (mut actual_type, mut format, mut count, mut remaining) = (0, 0, 0, 0);
+        let mut data = ptr::null_mut();
+
+        unsafe {
+            let status = (self.xlib.XGetWindowProperty)(
+                self.display,
+                window,
+                self.atom(name),
+                0,
+                c_long::MAX,
+                xlib::False,
+                xlib::XA_CARDINAL,
+                &mut actual_type,
+                &mut format,
+                &mut count,
+                &mut remaining,
+                &mut data,
+            );
+
+            if data.is_null() {
+                return vec![];
+            }
+
+            // properties with a format of 32 bit are returned as longs
+            let values = if status == xlib::Success as c_int && format == 32 {
+                slice::from_raw_parts(data as *const c_ulong, count as usize).to_vec()
+            } else {
+                vec![]
+            };
+
+            (self.xlib.XFree)(data as *mut _);
+
+            values
+        }
+    }
+
+    // Returns the atom of the given nul terminated name.
+    fn atom(&self, name: &[u8]) -> xlib::Atom {
+        unsafe {
+            (self.xlib.XInternAtom)(self.display, name.as_ptr() as *const c_char, xlib::False)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_property() {
+        assert_eq!(
+            icon_property(&[0xff00_0000, 0x80ff_ffff], 2, 1),
+            vec![2, 1, 0xff00_0000, 0x80ff_ffff]
+        );
+    }
+
+    #[test]
+    fn test_monitor_work_area() {
+        let bounds = Rectangle::new(1920.0, 0.0, 1280.0, 1024.0);
+
+        // the work area spans both monitors and leaves out a panel at the top
+        assert_eq!(
+            monitor_work_area(bounds, Rectangle::new(0.0, 32.0, 3200.0, 1048.0)),
+            Rectangle::new(1920.0, 32.0, 1280.0, 992.0)
+        );
+        assert_eq!(
+            monitor_work_area(bounds, Rectangle::new(0.0, 0.0, 1920.0, 1080.0)),
+            bounds
+        );
+    }
+
+    #[test]
+    fn test_opacity_property() {
+        assert_eq!(opacity_property(0.0), 0);
+        assert_eq!(opacity_property(0.5), 0x8000_0000);
+        assert_eq!(opacity_property(2.0), 0xffff_ffff);
+    }
+
+    #[test]
+    fn test_primary_screen() {
+        let screens = [
+            Rectangle::new(0.0, 0.0, 1920.0, 1080.0),
+            Rectangle::new(1920.0, 0.0, 1280.0, 1024.0),
+        ];
+
+        assert_eq!(
+            primary_screen(&screens, Some(Rectangle::new(1920.0, 0.0, 1280.0, 1024.0))),
+            1
+        );
+        assert_eq!(primary_screen(&screens, None), 0);
+        assert_eq!(
+            primary_screen(&screens, Some(Rectangle::new(0.0, 1080.0, 800.0, 600.0))),
+            0
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! This module contains the description of the monitors of the system, e.g. to center a dialog
+//! on the monitor of its parent window.
+
+use crate::utils::Rectangle;
+
+/// Describes a monitor with its geometry in screen coordinates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Monitor {
+    pub name: String,
+
+    /// Position and size of the monitor.
+    pub bounds: Rectangle,
+
+    /// Part of the monitor that is not covered by task bars or docks.
+    pub work_area: Rectangle,
+
+    /// Ratio of physical pixels to logical pixels.
+    pub scale_factor: f64,
+
+    /// `true` for the primary monitor of the system.
+    pub primary: bool,
+}
+
+impl Monitor {
+    /// Creates a monitor with the given bounds, its work area covers the whole monitor.
+    pub fn new(name: impl Into<String>, bounds: impl Into<Rectangle>) -> Self {
+        let bounds = bounds.into();
+
+        Monitor {
+            name: name.into(),
+            bounds,
+            work_area: bounds,
+            scale_factor: 1.0,
+            primary: false,
+        }
+    }
+
+    /// Returns the position that centers a window of the given size in the work area. A window
+    /// that is larger than the work area starts at its top left corner.
+    pub fn center(&self, width: f64, height: f64) -> (f64, f64) {
+        let area = &self.work_area;
+
+        (
+            area.x() + ((area.width() - width) / 2.0).max(0.0),
+            area.y() + ((area.height() - height) / 2.0).max(0.0),
+        )
+    }
+
+    /// Moves and shrinks the given window bounds into the work area, e.g. to restore a saved
+    /// window position after the monitors are changed.
+    pub fn fit(&self, bounds: &Rectangle) -> Rectangle {
+        let area = &self.work_area;
+        let width = bounds.width().min(area.width());
+        let height = bounds.height().min(area.height());
+
+        Rectangle::new(
+            bounds
+                .x()
+                .max(area.x())
+                .min(area.x() + area.width() - width),
+            bounds
+                .y()
+                .max(area.y())
+                .min(area.y() + area.height() - height),
+            width,
+            height,
+        )
+    }
+}
+
+// Returns the area of the intersection of the given rectangles.
+fn overlap(a: &Rectangle, b: &Rectangle) -> f64 {
+    let width = (a.x() + a.width()).min(b.x() + b.width()) - a.x().max(b.x());
+    let height = (a.y() + a.height()).min(b.y() + b.height()) - a.y().max(b.y());
+
+    width.max(0.0) * height.max(0.0)
+}
+
+/// Returns the monitor that contains the largest part of the given window bounds. If the window
+/// is on no monitor the primary monitor is returned.
+pub fn monitor_of<'a>(monitors: &'a [Monitor], bounds: &Rectangle) -> Option<&'a Monitor> {
+    let mut best: Option<(&Monitor, f64)> = None;
+
+    for monitor in monitors {
+        let area = overlap(&monitor.bounds, bounds);
+
+        if area > best.map_or(0.0, |(_, best_area)| best_area) {
+            best = Some((monitor, area));
+        }
+    }
+
+    best.map(|(monitor, _)| monitor)
+        .or_else(|| monitors.iter().find(|monitor| monitor.primary))
+        .or_else(|| monitors.first())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitors() -> Vec<Monitor> {
+        let mut primary = Monitor::new("primary", (0.0, 0.0, 1920.0, 1080.0));
+        primary.work_area = Rectangle::new(0.0, 0.0, 1920.0, 1040.0);
+        primary.primary = true;
+
+        vec![
+            primary,
+            Monitor::new("secondary", (1920.0, 0.0, 1280.0, 1024.0)),
+        ]
+    }
+
+    #[test]
+    fn test_monitor_of() {
+        let monitors = monitors();
+
+        let monitor = monitor_of(&monitors, &Rectangle::new(1800.0, 10.0, 400.0, 300.0));
+        assert_eq!(monitor.map(|m| m.name.as_str()), Some("secondary"));
+
+        let monitor = monitor_of(&monitors, &Rectangle::new(-900.0, 10.0, 400.0, 300.0));
+        assert_eq!(monitor.map(|m| m.name.as_str()), Some("primary"));
+
+        assert_eq!(monitor_of(&[], &Rectangle::new(0.0, 0.0, 10.0, 10.0)), None);
+    }
+
+    #[test]
+    fn test_center_and_fit() {
+        let monitor = &monitors()[0];
+        assert_eq!(monitor.center(400.0, 200.0), (760.0, 420.0));
+        assert_eq!(monitor.center(4000.0, 200.0), (0.0, 420.0));
+
+        assert_eq!(
+            monitor.fit(&Rectangle::new(1800.0, -20.0, 400.0, 2000.0)),
+            Rectangle::new(1520.0, 0.0, 400.0, 1040.0)
+        );
+    }
+}
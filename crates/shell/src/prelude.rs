@@ -1 +1,4 @@
-pub use crate::{event::*, platform::*, window::*, ShellRequest};
+pub use crate::{
+    accessibility::*, backend::*, event::*, monitor::*, platform::*, scheduler::*, tray::*,
+    window::*, ShellRequest,
+};
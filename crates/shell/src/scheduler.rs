@@ -0,0 +1,204 @@
+//! This module contains the frame scheduling of the window shell.
+
+use std::time::Duration;
+
+use crate::utils::RenderMode;
+
+/// Duration that an idle loop of the `OnDemand` render mode without max fps waits before it polls
+/// the events again.
+pub const IDLE_FRAME_DURATION: Duration = Duration::from_micros(16_600);
+
+/// Frame interval of a loop with vsync and without an own frame interval. Backends without access
+/// to the vertical blank, e.g. minifb, are paced with the refresh interval of a common 60 Hz
+/// display.
+pub const REFRESH_INTERVAL: Duration = Duration::from_micros(16_600);
+
+// A frame is due slightly before its exact start to compensate the jitter of the platform timers.
+const FRAME_TOLERANCE: Duration = Duration::from_millis(1);
+
+/// Decides if the loop of a window renders a frame and how long it sleeps between two frames.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FrameScheduler {
+    render_mode: RenderMode,
+    frame_interval: Option<Duration>,
+    vsync: bool,
+}
+
+impl Default for FrameScheduler {
+    fn default() -> Self {
+        let mut frame_scheduler = FrameScheduler::new(RenderMode::Continuous, 0);
+        frame_scheduler.set_vsync(true);
+        frame_scheduler
+    }
+}
+
+impl FrameScheduler {
+    /// Creates a new scheduler without vsync. A `max_fps` of `0` doesn't limit the frame rate.
+    pub fn new(render_mode: RenderMode, max_fps: u32) -> Self {
+        let mut frame_scheduler = FrameScheduler {
+            render_mode,
+            frame_interval: None,
+            vsync: false,
+        };
+        frame_scheduler.set_max_fps(max_fps);
+        frame_scheduler
+    }
+
+    /// Gets the render mode.
+    pub fn render_mode(&self) -> RenderMode {
+        self.render_mode
+    }
+
+    /// Sets the render mode.
+    pub fn set_render_mode(&mut self, render_mode: RenderMode) {
+        self.render_mode = render_mode;
+    }
+
+    /// Gets the max frames per second of the own frame interval, `0` means there is none.
+    pub fn max_fps(&self) -> u32 {
+        self.frame_interval.map_or(0, |interval| {
+            (1_000_000_000 / interval.as_nanos().max(1)) as u32
+        })
+    }
+
+    /// Sets the frame interval by max frames per second, `0` removes the own frame interval.
+    pub fn set_max_fps(&mut self, max_fps: u32) {
+        self.frame_interval = if max_fps == 0 {
+            None
+        } else {
+            Some(Duration::from_nanos(1_000_000_000 / max_fps as u64))
+        };
+    }
+
+    /// Gets the own target interval between two frames.
+    pub fn frame_interval(&self) -> Option<Duration> {
+        self.frame_interval
+    }
+
+    /// Sets the own target interval between two frames. It has priority over vsync, with `None`
+    /// the frames are paced by vsync or are not limited without vsync.
+    pub fn set_frame_interval(&mut self, frame_interval: impl Into<Option<Duration>>) {
+        self.frame_interval = frame_interval.into();
+    }
+
+    /// Gets vsync.
+    pub fn vsync(&self) -> bool {
+        self.vsync
+    }
+
+    /// Sets vsync. Without vsync and an own frame interval the frame rate is not limited, e.g.
+    /// for games that are embedded in the ui.
+    pub fn set_vsync(&mut self, vsync: bool) {
+        self.vsync = vsync;
+    }
+
+    /// Returns the min duration of a frame or `None` if the frame rate is not limited.
+    pub fn frame_duration(&self) -> Option<Duration> {
+        match self.frame_interval {
+            Some(frame_interval) => Some(frame_interval),
+            None if self.vsync => Some(REFRESH_INTERVAL),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if a frame should be rendered. `dirty` describes if there are pending events
+    /// or update requests.
+    pub fn should_render(&self, dirty: bool) -> bool {
+        self.render_mode == RenderMode::Continuous || dirty
+    }
+
+    /// Returns `true` if the given time since the last rendered frame reaches the min frame
+    /// duration.
+    pub fn is_frame_due(&self, elapsed: Duration) -> bool {
+        self.frame_duration().map_or(true, |frame_duration| {
+            elapsed + FRAME_TOLERANCE >= frame_duration
+        })
+    }
+
+    /// Returns how long the loop should sleep after an iteration that took `elapsed` and did or
+    /// did not render a frame.
+    pub fn wait_duration(&self, elapsed: Duration, rendered: bool) -> Duration {
+        let frame_duration = match self.frame_duration() {
+            Some(frame_duration) => frame_duration,
+            None if rendered => return Duration::default(),
+            None => IDLE_FRAME_DURATION,
+        };
+
+        frame_duration.checked_sub(elapsed).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_render() {
+        let scheduler = FrameScheduler::new(RenderMode::Continuous, 60);
+        assert!(scheduler.should_render(false));
+        assert!(scheduler.should_render(true));
+
+        let scheduler = FrameScheduler::new(RenderMode::OnDemand, 60);
+        assert!(!scheduler.should_render(false));
+        assert!(scheduler.should_render(true));
+    }
+
+    #[test]
+    fn test_frame_duration() {
+        let mut scheduler = FrameScheduler::default();
+        assert!(scheduler.vsync());
+        assert_eq!(scheduler.frame_duration(), Some(REFRESH_INTERVAL));
+
+        scheduler.set_frame_interval(Duration::from_millis(5));
+        assert_eq!(scheduler.frame_duration(), Some(Duration::from_millis(5)));
+        assert_eq!(scheduler.max_fps(), 200);
+
+        scheduler.set_frame_interval(None);
+        scheduler.set_vsync(false);
+        assert_eq!(scheduler.frame_duration(), None);
+        assert_eq!(scheduler.max_fps(), 0);
+
+        scheduler.set_max_fps(40);
+        assert_eq!(scheduler.frame_interval(), Some(Duration::from_millis(25)));
+    }
+
+    #[test]
+    fn test_is_frame_due() {
+        let scheduler = FrameScheduler::new(RenderMode::Continuous, 50);
+        assert!(!scheduler.is_frame_due(Duration::from_millis(10)));
+        assert!(scheduler.is_frame_due(Duration::from_millis(20)));
+        assert!(scheduler.is_frame_due(Duration::from_micros(19_500)));
+
+        let scheduler = FrameScheduler::new(RenderMode::Continuous, 0);
+        assert!(scheduler.is_frame_due(Duration::default()));
+    }
+
+    #[test]
+    fn test_wait_duration() {
+        let scheduler = FrameScheduler::new(RenderMode::OnDemand, 50);
+        assert_eq!(
+            scheduler.wait_duration(Duration::from_millis(5), true),
+            Duration::from_millis(15)
+        );
+        assert_eq!(
+            scheduler.wait_duration(Duration::from_millis(30), true),
+            Duration::default()
+        );
+
+        let scheduler = FrameScheduler::new(RenderMode::OnDemand, 0);
+        assert_eq!(
+            scheduler.wait_duration(Duration::from_millis(5), true),
+            Duration::default()
+        );
+        assert_eq!(
+            scheduler.wait_duration(Duration::from_millis(5), false),
+            IDLE_FRAME_DURATION - Duration::from_millis(5)
+        );
+
+        let scheduler = FrameScheduler::new(RenderMode::Continuous, 0);
+        assert_eq!(
+            scheduler.wait_duration(Duration::from_millis(5), true),
+            Duration::default()
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! This module contains the types to plug a system tray icon into the shell.
+//!
+//! On Windows the shell ships the `Win32Tray` backend, on other platforms a `TrayBackend` could be
+//! implemented with the tray api of the platform or a tray crate. The backend is polled by the
+//! loop of the window.
+
+use serde_derive::{Deserialize, Serialize};
+
+/// Describes an entry of the menu of a tray icon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrayMenuItem {
+    /// Id that is passed with the `TrayEvent::MenuItem` event.
+    pub id: String,
+
+    pub text: String,
+
+    pub enabled: bool,
+}
+
+impl TrayMenuItem {
+    /// Creates a new enabled menu item.
+    pub fn new(id: impl Into<String>, text: impl Into<String>) -> Self {
+        TrayMenuItem {
+            id: id.into(),
+            text: text.into(),
+            enabled: true,
+        }
+    }
+}
+
+/// Input event of a tray icon.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TrayEvent {
+    /// The tray icon is clicked.
+    Click,
+
+    /// The tray icon is double clicked.
+    DoubleClick,
+
+    /// The menu item with the given id is selected.
+    MenuItem(String),
+}
+
+/// Settings that are used to show a tray icon.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TraySettings {
+    /// Path of the icon image.
+    pub icon: String,
+
+    pub tooltip: String,
+
+    pub menu: Vec<TrayMenuItem>,
+}
+
+/// Platform backend of a system tray icon.
+pub trait TrayBackend {
+    /// Shows the tray icon with the given settings or updates the shown icon.
+    fn show(&mut self, settings: &TraySettings) -> Result<(), String>;
+
+    /// Removes the tray icon.
+    fn hide(&mut self) {}
+
+    /// Removes and returns all events that are received since the last call.
+    fn poll_events(&mut self) -> Vec<TrayEvent>;
+}
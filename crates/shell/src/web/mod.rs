@@ -4,6 +4,7 @@ use std::{
     cell::RefCell,
     rc::Rc,
     sync::mpsc::{channel, Receiver, Sender},
+    time::Duration,
 };
 
 use stdweb::{
@@ -31,10 +32,8 @@ fn get_mouse_button(button: event::MouseButton) -> MouseButton {
     }
 }
 
-fn get_key(code: &str, key: String) -> (Key, String) {
-    let mut text = String::from("");
-
-    let code = match code {
+fn get_key(code: &str, key: &str) -> Key {
+    match code {
         "Backspace" => Key::Backspace,
         "Delete" => Key::Delete,
         "ControlLeft" => Key::Control,
@@ -46,14 +45,41 @@ fn get_key(code: &str, key: String) -> (Key, String) {
         "ArrowRight" => Key::Right,
         "ArrowDown" => Key::Down,
         "Escape" => Key::Escape,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
         "Enter" => Key::Enter,
-        _ => {
-            text = key.clone();
-            Key::from(key.chars().next().unwrap())
-        }
-    };
+        _ => key.chars().next().map(Key::from).unwrap_or(Key::Unknown),
+    }
+}
+
+// Returns the typed character of a key down event. Named keys like `Tab` and shortcuts are not
+// reported as text.
+fn get_text(event: &event::KeyDownEvent) -> Option<String> {
+    let key = event.key();
 
-    (code, text)
+    if key.chars().count() != 1 || event.ctrl_key() || event.meta_key() {
+        return None;
+    }
+
+    Some(key)
+}
+
+// Converts the changed touch points of a web touch event.
+fn push_touch_events(
+    events: &Rc<RefCell<Vec<TouchEvent>>>,
+    touches: Vec<stdweb::web::Touch>,
+    phase: TouchPhase,
+) {
+    for touch in touches {
+        events.borrow_mut().push(TouchEvent {
+            id: touch.identifier() as u64,
+            x: touch.client_x(),
+            y: touch.client_y(),
+            phase,
+        });
+    }
 }
 
 /// Concrete implementation of the window shell.
@@ -64,13 +90,13 @@ where
     render_context_2_d: RenderContext2D,
     pub mouse_move_events: Rc<RefCell<Vec<event::MouseMoveEvent>>>,
     pub mouse_up_events: Rc<RefCell<Vec<event::MouseUpEvent>>>,
-    pub touch_start_events: Rc<RefCell<Vec<event::TouchStart>>>,
-    pub touch_end_events: Rc<RefCell<Vec<event::TouchEnd>>>,
+    pub touch_events: Rc<RefCell<Vec<TouchEvent>>>,
     pub mouse_down_events: Rc<RefCell<Vec<event::MouseDownEvent>>>,
     pub scroll_events: Rc<RefCell<Vec<event::MouseWheelEvent>>>,
     pub key_up_events: Rc<RefCell<Vec<event::KeyUpEvent>>>,
     pub key_down_events: Rc<RefCell<Vec<event::KeyDownEvent>>>,
     pub resize_events: Rc<RefCell<Vec<event::ResizeEvent>>>,
+    pub composition_events: Rc<RefCell<Vec<CompositionEvent>>>,
     canvas: CanvasElement,
     pub old_canvas: Option<CanvasElement>,
     pub flip: bool,
@@ -79,6 +105,11 @@ where
     running: bool,
     request_receiver: Receiver<ShellRequest>,
     request_sender: Sender<ShellRequest>,
+    accessibility_tree: Vec<AccessibilityNode>,
+    cursor_icon: CursorIcon,
+    frame_scheduler: FrameScheduler,
+    dirty: bool,
+    last_frame: f64,
 }
 
 unsafe impl<A> HasRawWindowHandle for WindowShell<A>
@@ -99,6 +130,108 @@ impl<A> WindowShell<A>
 where
     A: WindowAdapter,
 {
+    /// Returns the handle of the canvas of the window, the web window always has one.
+    pub fn try_raw_window_handle(&self) -> Option<RawWindowHandle> {
+        Some(self.raw_window_handle())
+    }
+
+    /// Sets the accessibility tree of the window. The nodes are mirrored as hidden html elements
+    /// with aria roles and attributes, the name of a newly focused node or of the newly active
+    /// descendant of the focused node is announced.
+    pub fn set_accessibility_tree(&mut self, nodes: Vec<AccessibilityNode>) {
+        let focused = |nodes: &[AccessibilityNode]| {
+            nodes
+                .iter()
+                .find(|n| n.focused)
+                .map(|n| n.active_descendant.unwrap_or(n.id))
+        };
+        let announced = focused(&nodes);
+        let announce = announced != focused(&self.accessibility_tree);
+
+        js! {
+            document.getElementById("orbtk-accessibility").innerHTML = "";
+        }
+
+        for node in &nodes {
+            let parent = node
+                .parent
+                .map(|parent| format!("orbtk-accessibility-{}", parent))
+                .unwrap_or_else(|| String::from("orbtk-accessibility"));
+
+            // the elements are positioned relative to their parent
+            let offset = nodes
+                .iter()
+                .find(|n| Some(n.id) == node.parent)
+                .map(|n| (n.bounds.x(), n.bounds.y()))
+                .unwrap_or((0.0, 0.0));
+
+            js! {
+                var element = document.createElement("div");
+                element.id = "orbtk-accessibility-" + @{node.id.to_string()};
+                element.setAttribute("role", @{node.role.aria_role()});
+                element.setAttribute("aria-label", @{&node.name});
+                element.setAttribute("aria-disabled", @{!node.enabled});
+                element.setAttribute("aria-checked", @{node.selected});
+                element.setAttribute("aria-selected", @{node.selected});
+                element.style.position = "absolute";
+                element.style.left = @{node.bounds.x() - offset.0} + "px";
+                element.style.top = @{node.bounds.y() - offset.1} + "px";
+                element.style.width = @{node.bounds.width()} + "px";
+                element.style.height = @{node.bounds.height()} + "px";
+                var parent = document.getElementById(@{parent})
+                    || document.getElementById("orbtk-accessibility");
+                parent.appendChild(element);
+            }
+
+            let element = format!("orbtk-accessibility-{}", node.id);
+
+            let indices = [
+                ("aria-rowindex", node.row_index.map(|index| index + 1)),
+                ("aria-colindex", node.column_index.map(|index| index + 1)),
+                ("aria-rowcount", node.row_count),
+                ("aria-colcount", node.column_count),
+            ];
+
+            for (attribute, value) in indices.iter() {
+                if let Some(value) = value {
+                    js! {
+                        document.getElementById(@{&element})
+                            .setAttribute(@{*attribute}, @{value.to_string()});
+                    }
+                }
+            }
+
+            let relations = [
+                ("aria-describedby", node.header),
+                ("aria-activedescendant", node.active_descendant),
+            ];
+
+            for (attribute, related) in relations.iter() {
+                if let Some(related) = related {
+                    js! {
+                        document.getElementById(@{&element}).setAttribute(
+                            @{*attribute},
+                            "orbtk-accessibility-" + @{related.to_string()}
+                        );
+                    }
+                }
+            }
+
+            if announce && announced == Some(node.id) {
+                js! {
+                    document.getElementById("orbtk-accessibility-live").textContent = @{&node.name};
+                }
+            }
+        }
+
+        self.accessibility_tree = nodes;
+    }
+
+    /// Returns the current accessibility tree of the window.
+    pub fn accessibility_tree(&self) -> &[AccessibilityNode] {
+        &self.accessibility_tree
+    }
+
     /// Gets if the shell is running.
     pub fn running(&self) -> bool {
         self.running
@@ -109,6 +242,48 @@ where
         self.request_sender.clone()
     }
 
+    /// Gets the scheduler that decides when the window renders a new frame.
+    pub fn frame_scheduler(&self) -> &FrameScheduler {
+        &self.frame_scheduler
+    }
+
+    /// Sets the render mode of the window.
+    pub fn set_render_mode(&mut self, render_mode: RenderMode) {
+        self.frame_scheduler.set_render_mode(render_mode);
+    }
+
+    /// Sets the max frames per second of the window, `0` removes the own frame interval.
+    pub fn set_max_fps(&mut self, max_fps: u32) {
+        self.frame_scheduler.set_max_fps(max_fps);
+    }
+
+    /// Sets the target interval between two frames of the window.
+    pub fn set_frame_interval(&mut self, frame_interval: impl Into<Option<Duration>>) {
+        self.frame_scheduler.set_frame_interval(frame_interval);
+    }
+
+    /// Sets if the frames of the window are paced by vsync.
+    pub fn set_vsync(&mut self, vsync: bool) {
+        self.frame_scheduler.set_vsync(vsync);
+    }
+
+    // Checks if the animation frame with the given time in ms renders a new frame.
+    fn should_render(&mut self, time: f64) -> bool {
+        let elapsed = Duration::from_micros(((time - self.last_frame).max(0.0) * 1000.0) as u64);
+
+        if !self
+            .frame_scheduler
+            .should_render(self.dirty || self.update)
+            || !self.frame_scheduler.is_frame_due(elapsed)
+        {
+            return false;
+        }
+
+        self.dirty = false;
+        self.last_frame = time;
+        true
+    }
+
     /// Sets running.
     pub fn set_running(&mut self, running: bool) {
         self.running = running;
@@ -135,6 +310,122 @@ where
             .fill_rect(0.0, 0.0, width.into(), height.into());
     }
 
+    /// Sets the icon of the mouse cursor over the canvas.
+    pub fn set_cursor_icon(&mut self, cursor_icon: CursorIcon) {
+        if cursor_icon == self.cursor_icon {
+            return;
+        }
+
+        let cursor = match &cursor_icon {
+            CursorIcon::Arrow => "default".to_string(),
+            CursorIcon::Hand => "pointer".to_string(),
+            CursorIcon::Text => "text".to_string(),
+            CursorIcon::Crosshair => "crosshair".to_string(),
+            CursorIcon::ResizeHorizontal => "ew-resize".to_string(),
+            CursorIcon::ResizeVertical => "ns-resize".to_string(),
+            CursorIcon::ResizeAll => "move".to_string(),
+            CursorIcon::Hidden => "none".to_string(),
+            CursorIcon::Custom(path) => format!("url({}), auto", path),
+        };
+
+        js! {
+            @{&self.canvas}.style.cursor = @{cursor};
+        }
+
+        self.cursor_icon = cursor_icon;
+    }
+
+    /// Sets the favicon of the page to the source of the given icon.
+    pub fn set_icon(&mut self, icon: &Image) {
+        js! {
+            var link = document.querySelector("link[rel~='icon']");
+
+            if (!link) {
+                link = document.createElement("link");
+                link.rel = "icon";
+                document.head.appendChild(link);
+            }
+
+            link.href = @{&icon.source};
+        }
+    }
+
+    /// Shows the badge, e.g. an unread count, in front of the title of the page or removes it
+    /// with `None`.
+    pub fn set_badge(&mut self, badge: Option<&str>) {
+        let badge = badge
+            .map(|badge| format!("({}) ", badge))
+            .unwrap_or_default();
+
+        js! {
+            if (!document.hasOwnProperty("orbtk_title")) {
+                document.orbtk_title = document.title;
+            }
+
+            document.title = @{badge} + document.orbtk_title;
+        }
+    }
+
+    /// The canvas is always part of the page, it is ignored.
+    pub fn set_always_on_top(&mut self, _always_on_top: bool) {}
+
+    /// Sets the opacity of the canvas.
+    pub fn set_opacity(&mut self, opacity: f64) {
+        js! {
+            @{&self.canvas}.style.opacity = @{opacity};
+        }
+    }
+
+    /// The canvas could not be moved, the offset is ignored.
+    pub fn move_by(&mut self, _delta_x: f64, _delta_y: f64) {}
+
+    /// The size of the canvas is given by the page, the size is ignored.
+    pub fn resize_to(&mut self, _width: f64, _height: f64) {}
+
+    /// Returns always `false`, the size of the canvas is given by the page.
+    pub fn can_resize(&self) -> bool {
+        false
+    }
+
+    /// Returns the position of the browser window on the screen.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        let x: f64 = js! { return window.screenX; }.try_into().ok()?;
+        let y: f64 = js! { return window.screenY; }.try_into().ok()?;
+
+        Some((x, y))
+    }
+
+    /// Returns the screen of the browser, other monitors could not be queried.
+    pub fn monitors(&self) -> Vec<Monitor> {
+        let screen = js! {
+            var screen = window.screen;
+            return [
+                screen.left || 0, screen.top || 0, screen.width, screen.height,
+                screen.availLeft || 0, screen.availTop || 0, screen.availWidth, screen.availHeight
+            ];
+        };
+        let values: Vec<f64> = match screen.try_into() {
+            Ok(values) => values,
+            Err(_) => return vec![],
+        };
+
+        let mut monitor = Monitor::new("screen", (values[0], values[1], values[2], values[3]));
+        monitor.work_area = Rectangle::new(values[4], values[5], values[6], values[7]);
+        monitor.scale_factor = window().device_pixel_ratio();
+        monitor.primary = true;
+
+        vec![monitor]
+    }
+
+    /// Shows or hides the canvas of the window.
+    pub fn set_visible(&mut self, visible: bool) {
+        let display = if visible { "block" } else { "none" };
+
+        js! {
+            @{&self.canvas}.style.display = @{display};
+        }
+    }
+
     /// Gets the shell adapter.
     pub fn adapter(&mut self) -> &mut A {
         &mut self.adapter
@@ -146,6 +437,18 @@ where
     }
 
     fn drain_events(&mut self) {
+        // events that are passed to the adapter are handled with the next rendered frame
+        self.dirty = self.dirty
+            || !self.mouse_move_events.borrow().is_empty()
+            || !self.mouse_up_events.borrow().is_empty()
+            || !self.touch_events.borrow().is_empty()
+            || !self.mouse_down_events.borrow().is_empty()
+            || !self.scroll_events.borrow().is_empty()
+            || !self.key_up_events.borrow().is_empty()
+            || !self.key_down_events.borrow().is_empty()
+            || !self.resize_events.borrow().is_empty()
+            || !self.composition_events.borrow().is_empty();
+
         while let Some(event) = self.mouse_move_events.borrow_mut().pop() {
             self.adapter
                 .mouse(event.client_x() as f64, event.client_y() as f64);
@@ -187,33 +490,33 @@ where
             self.adapter.scroll(event.delta_x(), event.delta_y());
         }
 
-        // todo tmp solution to map touch events to mouse vent
-        while let Some(event) = self.touch_start_events.borrow_mut().pop() {
-            self.adapter.mouse_event(MouseEvent {
-                x: event.target_touches()[0].client_x() as f64,
-                y: event.target_touches()[0].client_y() as f64,
-                button: MouseButton::Left,
+        for event in self.touch_events.borrow_mut().drain(..) {
+            self.adapter.touch_event(event);
+        }
+
+        for event in self.key_down_events.borrow_mut().drain(..) {
+            self.adapter.key_event(KeyEvent {
+                key: get_key(event.code().as_str(), event.key().as_str()),
                 state: ButtonState::Down,
+                repeat: event.repeat(),
             });
+
+            if let Some(text) = get_text(&event) {
+                self.adapter.text_input(text);
+            }
         }
 
-        while let Some(event) = self.touch_end_events.borrow_mut().pop() {
-            self.adapter.mouse_event(MouseEvent {
-                x: event.target_touches()[0].client_x() as f64,
-                y: event.target_touches()[0].client_y() as f64,
-                button: MouseButton::Left,
+        for event in self.key_up_events.borrow_mut().drain(..) {
+            self.adapter.key_event(KeyEvent {
+                key: get_key(event.code().as_str(), event.key().as_str()),
                 state: ButtonState::Up,
+                repeat: false,
             });
         }
 
-        while let Some(event) = self.key_down_events.borrow_mut().pop() {
-            let key = get_key(event.code().as_str(), event.key());
-
-            self.adapter.key_event(KeyEvent {
-                key: key.0,
-                state: ButtonState::Down,
-                text: key.1,
-            });
+        // composition events are handled in the order they occur
+        for event in self.composition_events.borrow_mut().drain(..) {
+            self.adapter.composition_event(event);
         }
 
         while let Some(_) = self.resize_events.borrow_mut().pop() {
@@ -281,10 +584,6 @@ where
         let mut update = self.update();
 
         for request in self.request_receiver.try_iter() {
-            if update {
-                break;
-            }
-
             match request {
                 ShellRequest::Update => {
                     update = true;
@@ -325,10 +624,15 @@ where
     A: WindowAdapter,
 {
     pub fn run(mut self) {
-        window().request_animation_frame(move |_| {
-            self.updater.update();
-            self.window_shell.borrow_mut().set_update(false);
-            self.window_shell.borrow_mut().flip();
+        window().request_animation_frame(move |time| {
+            let render = self.window_shell.borrow_mut().should_render(time);
+
+            if render {
+                self.updater.update();
+                self.window_shell.borrow_mut().set_update(false);
+                self.window_shell.borrow_mut().flip();
+            }
+
             self.window_shell.borrow_mut().drain_events();
             self.run();
         });
@@ -346,6 +650,8 @@ where
 
     bounds: Rectangle,
 
+    frame_scheduler: FrameScheduler,
+
     adapter: A,
 }
 
@@ -360,6 +666,7 @@ where
             title: String::default(),
             resizeable: false,
             bounds: Rectangle::default(),
+            frame_scheduler: FrameScheduler::default(),
         }
     }
 
@@ -375,12 +682,44 @@ where
         self
     }
 
+    /// The canvas has no border, it is ignored.
+    pub fn borderless(self, _borderless: bool) -> Self {
+        self
+    }
+
     /// Sets the bounds.
     pub fn bounds(mut self, bounds: impl Into<Rectangle>) -> Self {
         self.bounds = bounds.into();
         self
     }
 
+    /// Sets the render mode, default is `RenderMode::Continuous`.
+    pub fn render_mode(mut self, render_mode: RenderMode) -> Self {
+        self.frame_scheduler.set_render_mode(render_mode);
+        self
+    }
+
+    /// Sets the target frame interval by max frames per second. `0` removes it, default is `0`.
+    pub fn max_fps(mut self, max_fps: u32) -> Self {
+        self.frame_scheduler.set_max_fps(max_fps);
+        self
+    }
+
+    /// Sets the target interval between two frames, it has priority over vsync. Default is
+    /// `None`.
+    pub fn frame_interval(mut self, frame_interval: impl Into<Option<Duration>>) -> Self {
+        self.frame_scheduler.set_frame_interval(frame_interval);
+        self
+    }
+
+    /// Sets vsync, default is `true`. The browser always renders with the animation frames of
+    /// the display, without vsync the frames are not throttled to the `REFRESH_INTERVAL`, e.g.
+    /// on displays with a higher refresh rate.
+    pub fn vsync(mut self, vsync: bool) -> Self {
+        self.frame_scheduler.set_vsync(vsync);
+        self
+    }
+
     /// Builds the window shell.
     pub fn build(mut self) -> WindowShell<A> {
         // console_error_panic_hook::set_once();
@@ -411,13 +750,13 @@ where
         // web event queues
         let mouse_move = Rc::new(RefCell::new(vec![]));
         let mouse_up = Rc::new(RefCell::new(vec![]));
-        let touch_start = Rc::new(RefCell::new(vec![]));
-        let touch_end = Rc::new(RefCell::new(vec![]));
+        let touch = Rc::new(RefCell::new(vec![]));
         let mouse_down = Rc::new(RefCell::new(vec![]));
         let scroll = Rc::new(RefCell::new(vec![]));
         let key_down = Rc::new(RefCell::new(vec![]));
         let key_up = Rc::new(RefCell::new(vec![]));
         let resize = Rc::new(RefCell::new(vec![]));
+        let composition = Rc::new(RefCell::new(vec![]));
 
         let mouse_down_c = mouse_down.clone();
         document()
@@ -435,22 +774,39 @@ where
                 mouse_up_c.borrow_mut().push(e);
             });
 
-        let touch_start_c = touch_start.clone();
+        let touch_c = touch.clone();
         document()
             .body()
             .unwrap()
             .add_event_listener(move |e: event::TouchStart| {
                 e.prevent_default();
-                // touch_start_c.borrow_mut().push(e);
+                push_touch_events(&touch_c, e.changed_touches(), TouchPhase::Down);
+            });
+
+        let touch_c = touch.clone();
+        document()
+            .body()
+            .unwrap()
+            .add_event_listener(move |e: event::TouchMove| {
+                e.prevent_default();
+                push_touch_events(&touch_c, e.changed_touches(), TouchPhase::Move);
             });
 
-        let touch_end_c = touch_end.clone();
+        let touch_c = touch.clone();
         document()
             .body()
             .unwrap()
             .add_event_listener(move |e: event::TouchEnd| {
                 e.prevent_default();
-                // touch_end_c.borrow_mut().push(e);
+                push_touch_events(&touch_c, e.changed_touches(), TouchPhase::Up);
+            });
+
+        let touch_c = touch.clone();
+        document()
+            .body()
+            .unwrap()
+            .add_event_listener(move |e: event::TouchCancel| {
+                push_touch_events(&touch_c, e.changed_touches(), TouchPhase::Cancel);
             });
 
         let mouse_move_c = mouse_move.clone();
@@ -474,6 +830,11 @@ where
             .body()
             .unwrap()
             .add_event_listener(move |e: event::KeyDownEvent| {
+                // keys that are processed by the input method are reported by composition events
+                if e.key() == "Process" {
+                    return;
+                }
+
                 e.prevent_default();
                 key_down_c.borrow_mut().push(e);
             });
@@ -490,6 +851,56 @@ where
             resize_c.borrow_mut().push(e);
         });
 
+        // the browser only starts an input method composition on an editable element, therefore
+        // a hidden text area keeps the focus and reports the composition events.
+        let composition_c = composition.clone();
+        let push_composition = move |kind: String, text: String| {
+            composition_c.borrow_mut().push(match kind.as_str() {
+                "update" if !text.is_empty() => CompositionEvent::Update(text),
+                "commit" if !text.is_empty() => CompositionEvent::Commit(text),
+                _ => CompositionEvent::Cancel,
+            });
+        };
+
+        js! {
+            var push_composition = @{push_composition};
+            var input = document.createElement("textarea");
+            input.style.position = "absolute";
+            input.style.left = "-1000px";
+            input.style.opacity = 0;
+            document.body.appendChild(input);
+            input.addEventListener("compositionupdate", function(e) {
+                push_composition("update", e.data || "");
+            });
+            input.addEventListener("compositionend", function(e) {
+                push_composition("commit", e.data || "");
+                input.value = "";
+            });
+            document.body.addEventListener("mousedown", function() {
+                setTimeout(function() { input.focus(); }, 0);
+            });
+            input.focus();
+        }
+
+        // hidden elements that mirror the accessibility tree for screen readers
+        js! {
+            var root = document.createElement("div");
+            root.id = "orbtk-accessibility";
+            root.style.position = "absolute";
+            root.style.left = "0";
+            root.style.top = "0";
+            root.style.opacity = 0;
+            root.style.pointerEvents = "none";
+            document.body.appendChild(root);
+
+            var live = document.createElement("div");
+            live.id = "orbtk-accessibility-live";
+            live.setAttribute("aria-live", "polite");
+            live.style.position = "absolute";
+            live.style.left = "-1000px";
+            document.body.appendChild(live);
+        }
+
         document().body().unwrap().append_child(&canvas);
         let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
 
@@ -537,13 +948,13 @@ where
             adapter: self.adapter,
             mouse_move_events: mouse_move,
             mouse_up_events: mouse_up,
-            touch_start_events: touch_start,
-            touch_end_events: touch_end,
+            touch_events: touch,
             mouse_down_events: mouse_down,
             scroll_events: scroll,
             key_down_events: key_down,
             key_up_events: key_up,
             resize_events: resize,
+            composition_events: composition,
             flip: false,
             canvas,
             old_canvas: None,
@@ -551,6 +962,11 @@ where
             running: true,
             request_receiver,
             request_sender,
+            accessibility_tree: vec![],
+            cursor_icon: CursorIcon::default(),
+            frame_scheduler: self.frame_scheduler,
+            dirty: true,
+            last_frame: 0.0,
         }
     }
 }
@@ -1,6 +1,6 @@
 //! This module contains traits to inject custom logic into the window shell.
 
-use crate::{event::*, utils::Point};
+use crate::{event::*, tray::TrayEvent, utils::Point};
 
 /// The window adapter is used to work with the window shell.ButtonState
 ///
@@ -22,13 +22,25 @@ pub trait WindowAdapter {
     /// Is called after the state of a mouse button is changed.
     fn mouse_event(&mut self, _event: MouseEvent) {}
 
+    /// Is called after a touch point is pressed, moved or released. Only called by backends
+    /// with touch support.
+    fn touch_event(&mut self, _event: TouchEvent) {}
+
     /// Is called if mouse wheel or trackpad detect scroll event.
     fn scroll(&mut self, _delta_x: f64, _delta_y: f64) {}
 
     /// Is called after the state of a keyboard key is changed.
     fn key_event(&mut self, _event: KeyEvent) {}
 
-    /// Is called after the quite event of the window is called.
+    /// Is called after a character is received from the keyboard, e.g. a shifted or AltGr
+    /// character.
+    fn text_input(&mut self, _text: String) {}
+
+    /// Is called after the composition string of an input method is changed, committed or canceled.
+    /// Only called by backends with input method support.
+    fn composition_event(&mut self, _event: CompositionEvent) {}
+
+    /// Is called after the user requested to close the window.
     fn quite_event(&mut self) {}
 
     /// Gets the current mouse position.
@@ -36,6 +48,13 @@ pub trait WindowAdapter {
 
     /// Is called if active state of the window is changed.
     fn active(&mut self, active: bool);
+
+    /// Is called after the window is minimized or restored.
+    fn minimized(&mut self, _minimized: bool) {}
+
+    /// Is called after the system tray icon of the application is clicked or an item of its
+    /// menu is selected.
+    fn tray_event(&mut self, _event: TrayEvent) {}
 }
 
 /// Used to define an additional updater for the window shell.
@@ -11,6 +11,7 @@ extern crate lazy_static;
 use std::sync::Arc;
 
 use orbtk_css_engine::prelude::*;
+use orbtk_utils::Palette;
 
 pub mod colors;
 pub mod fonts;
@@ -37,3 +38,54 @@ pub fn default_theme() -> Theme {
 pub fn light_theme() -> Theme {
     Theme::create_from_css(&LIGHT_THEME_CSS[..]).build()
 }
+
+/// Returns a theme extension that replaces the accent color of the default themes with the colors
+/// of the given palette.
+///
+/// ```rust,no_run
+/// # use orbtk_css_engine::prelude::*;
+/// # use orbtk_theme::prelude::*;
+/// # use orbtk_utils::Palette;
+/// let theme = Theme::create_from_css(DEFAULT_THEME_CSS)
+///     .extension_css(accent_css(&Palette::from("#216be1")))
+///     .build();
+/// ```
+pub fn accent_css(palette: &Palette) -> String {
+    format!(
+        "button.primary {{
+    background: {accent};
+    color: {on_accent};
+    icon-color: {on_accent};
+}}
+
+button.primary:active {{
+    background: {accent_pressed};
+    color: {on_accent};
+    icon-color: {on_accent};
+}}
+
+button.primary:disabled {{
+    background: {accent_disabled};
+    color: {on_accent_disabled};
+    icon-color: {on_accent_disabled};
+}}
+
+menuitem:selected, calendar-day:selected, list-view-item:selected {{
+    background: {accent};
+    color: {on_accent};
+    icon-color: {on_accent};
+}}
+
+menuitem:active, list-view-item:active {{
+    background: {accent_pressed};
+    color: {on_accent};
+    icon-color: {on_accent};
+}}
+",
+        accent = palette.accent.to_string(),
+        accent_pressed = palette.accent_pressed.to_string(),
+        accent_disabled = palette.accent_disabled.to_string(),
+        on_accent = palette.on_accent.to_string(),
+        on_accent_disabled = palette.on_accent_disabled.to_string(),
+    )
+}
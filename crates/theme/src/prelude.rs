@@ -1,4 +1,4 @@
 pub use crate::{
-    colors, default_theme, fonts, light_theme, vector_graphics, DEFAULT_THEME_CSS,
+    accent_css, colors, default_theme, fonts, light_theme, vector_graphics, DEFAULT_THEME_CSS,
     LIGHT_THEME_EXTENSION_CSS,
 };
@@ -7,4 +7,11 @@ pub const LOAD_FONT_ICON: &str = "";
 pub const SAVE_FONT_ICON: &str = "";
 pub const KEYBOARD_ARROW_LEFT_FONT_ICON: &str = "";
 pub const KEYBOARD_ARROW_RIGHT_FONT_ICON: &str = "";
+pub const KEYBOARD_ARROW_DOWN_FONT_ICON: &str = "";
 pub const REMOVE_ICON: &str = "";
+pub const ARROW_DROP_DOWN_FONT_ICON: &str = "";
+pub const ARROW_DROP_UP_FONT_ICON: &str = "";
+pub const FOLDER_FONT_ICON: &str = "";
+pub const FILE_FONT_ICON: &str = "";
+pub const MORE_HORIZ_FONT_ICON: &str = "";
+pub const EVENT_FONT_ICON: &str = "";
@@ -0,0 +1,242 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+
+use dces::prelude::Entity;
+
+use crate::EntityMap;
+
+/// `ArenaMap` stores one value of the trait object type `T` per entity, e.g. the layout or the
+/// render object of a widget. Values of the same concrete type are stored unboxed in their own
+/// arena, the entity only maps to the arena and the slot of its value. Values that are only known
+/// as `Box<T>` are stored in a common arena of boxes.
+pub struct ArenaMap<T: ?Sized + 'static> {
+    slots: EntityMap<(usize, usize)>,
+    arenas: Vec<Box<dyn ErasedArena<T>>>,
+    types: HashMap<TypeId, usize>,
+}
+
+impl<T: ?Sized + 'static> Default for ArenaMap<T> {
+    fn default() -> Self {
+        ArenaMap {
+            slots: EntityMap::new(),
+            arenas: vec![],
+            types: HashMap::new(),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static> ArenaMap<T> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        ArenaMap::default()
+    }
+
+    /// Returns the number of entities with a value.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if no entity has a value.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns `true` if the given entity has a value.
+    pub fn contains_key(&self, entity: &Entity) -> bool {
+        self.slots.contains_key(entity)
+    }
+
+    /// Returns a reference of the value of the given entity.
+    pub fn get(&self, entity: &Entity) -> Option<&T> {
+        self.slots
+            .get(entity)
+            .map(|(arena, slot)| self.arenas[*arena].get(*slot))
+    }
+
+    /// Returns a mutable reference of the value of the given entity.
+    pub fn get_mut(&mut self, entity: &Entity) -> Option<&mut T> {
+        let (arena, slot) = *self.slots.get(entity)?;
+        Some(self.arenas[arena].get_mut(slot))
+    }
+
+    /// Inserts the value of the given entity into the arena of its type `C`. The `upcast`
+    /// functions convert a reference of the value to a reference of `T`, e.g. `|value| value`.
+    pub fn insert<C: 'static>(
+        &mut self,
+        entity: Entity,
+        value: C,
+        upcast: fn(&C) -> &T,
+        upcast_mut: fn(&mut C) -> &mut T,
+    ) {
+        self.remove(&entity);
+
+        let arena = if let Some(arena) = self.types.get(&TypeId::of::<C>()) {
+            *arena
+        } else {
+            self.arenas.push(Box::new(Arena {
+                values: vec![],
+                free: vec![],
+                upcast,
+                upcast_mut,
+            }));
+            self.types.insert(TypeId::of::<C>(), self.arenas.len() - 1);
+            self.arenas.len() - 1
+        };
+
+        let slot = self.arenas[arena]
+            .as_any_mut()
+            .downcast_mut::<Arena<C, T>>()
+            .unwrap()
+            .insert(value);
+
+        self.slots.insert(entity, (arena, slot));
+    }
+
+    /// Inserts the boxed value of the given entity into the common arena of boxes.
+    pub fn insert_boxed(&mut self, entity: Entity, value: Box<T>) {
+        self.insert(entity, value, |value| &**value, |value| &mut **value);
+    }
+
+    /// Removes the value of the given entity. Returns `true` if the entity had a value.
+    pub fn remove(&mut self, entity: &Entity) -> bool {
+        if let Some((arena, slot)) = self.slots.remove(entity) {
+            self.arenas[arena].remove(slot);
+            return true;
+        }
+
+        false
+    }
+
+    /// Iterates linearly over all values of the concrete type `C`.
+    pub fn values_of<C: 'static>(&self) -> impl Iterator<Item = &C> {
+        self.types
+            .get(&TypeId::of::<C>())
+            .and_then(|arena| self.arenas[*arena].as_any().downcast_ref::<Arena<C, T>>())
+            .into_iter()
+            .flat_map(|arena| arena.values.iter().filter_map(|value| value.as_ref()))
+    }
+}
+
+impl<T: ?Sized + 'static> Index<&Entity> for ArenaMap<T> {
+    type Output = T;
+
+    fn index(&self, entity: &Entity) -> &T {
+        self.get(entity)
+            .unwrap_or_else(|| panic!("ArenaMap: entity {} has no value.", entity.0))
+    }
+}
+
+impl<T: ?Sized + 'static> IndexMut<&Entity> for ArenaMap<T> {
+    fn index_mut(&mut self, entity: &Entity) -> &mut T {
+        let index = entity.0;
+
+        self.get_mut(entity)
+            .unwrap_or_else(|| panic!("ArenaMap: entity {} has no value.", index))
+    }
+}
+
+// Arena of the values of one concrete type `C`. Removed slots are reused by the next insert.
+struct Arena<C, T: ?Sized> {
+    values: Vec<Option<C>>,
+    free: Vec<usize>,
+    upcast: fn(&C) -> &T,
+    upcast_mut: fn(&mut C) -> &mut T,
+}
+
+impl<C, T: ?Sized> Arena<C, T> {
+    fn insert(&mut self, value: C) -> usize {
+        if let Some(slot) = self.free.pop() {
+            self.values[slot] = Some(value);
+            return slot;
+        }
+
+        self.values.push(Some(value));
+        self.values.len() - 1
+    }
+}
+
+// Arena with its concrete type erased, so the arenas of all types are stored in one vector.
+trait ErasedArena<T: ?Sized> {
+    fn get(&self, slot: usize) -> &T;
+
+    fn get_mut(&mut self, slot: usize) -> &mut T;
+
+    fn remove(&mut self, slot: usize);
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<C: 'static, T: ?Sized + 'static> ErasedArena<T> for Arena<C, T> {
+    fn get(&self, slot: usize) -> &T {
+        (self.upcast)(self.values[slot].as_ref().unwrap())
+    }
+
+    fn get_mut(&mut self, slot: usize) -> &mut T {
+        (self.upcast_mut)(self.values[slot].as_mut().unwrap())
+    }
+
+    fn remove(&mut self, slot: usize) {
+        self.values[slot] = None;
+        self.free.push(slot);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Small(u8);
+
+    #[test]
+    fn test_insert_remove() {
+        let mut map: ArenaMap<dyn Debug> = ArenaMap::new();
+        assert!(map.is_empty());
+
+        map.insert(Entity(3), Small(3), |value| value, |value| value);
+        map.insert(Entity(1), 1_i32, |value| value, |value| value);
+        map.insert_boxed(Entity(2), Box::new("two"));
+        map.insert(Entity(3), Small(4), |value| value, |value| value);
+        assert_eq!(map.len(), 3);
+        assert_eq!(format!("{:?}", &map[&Entity(3)]), "Small(4)");
+        assert_eq!(format!("{:?}", &map[&Entity(2)]), "\"two\"");
+        assert!(map.get(&Entity(10)).is_none());
+
+        assert!(map.remove(&Entity(1)));
+        assert!(!map.remove(&Entity(1)));
+        assert!(!map.contains_key(&Entity(1)));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_values_of() {
+        let mut map: ArenaMap<dyn Debug> = ArenaMap::new();
+        map.insert(Entity(0), Small(0), |value| value, |value| value);
+        map.insert(Entity(1), 1_i32, |value| value, |value| value);
+        map.insert(Entity(2), Small(2), |value| value, |value| value);
+        map.remove(&Entity(0));
+        map.insert(Entity(4), Small(4), |value| value, |value| value);
+
+        assert_eq!(
+            map.values_of::<Small>().collect::<Vec<_>>(),
+            vec![&Small(4), &Small(2)]
+        );
+        assert_eq!(map.values_of::<i32>().count(), 1);
+        assert_eq!(map.values_of::<u64>().count(), 0);
+    }
+}
@@ -0,0 +1,163 @@
+use std::ops::{Index, IndexMut};
+
+use dces::prelude::Entity;
+
+/// `EntityMap` stores one value per entity, e.g. the arena slot of a widget's layout. The
+/// values are stored in a dense vector that is indexed by the entity, so a lookup is a single
+/// index operation and the values are iterated linearly in entity order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityMap<V> {
+    values: Vec<Option<V>>,
+    len: usize,
+}
+
+impl<V> Default for EntityMap<V> {
+    fn default() -> Self {
+        EntityMap {
+            values: vec![],
+            len: 0,
+        }
+    }
+}
+
+impl<V> EntityMap<V> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        EntityMap::default()
+    }
+
+    /// Returns the number of entities with a value.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no entity has a value.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the given entity has a value.
+    pub fn contains_key(&self, entity: &Entity) -> bool {
+        self.get(entity).is_some()
+    }
+
+    /// Returns a reference of the value of the given entity.
+    pub fn get(&self, entity: &Entity) -> Option<&V> {
+        self.values
+            .get(entity.0 as usize)
+            .and_then(|value| value.as_ref())
+    }
+
+    /// Returns a mutable reference of the value of the given entity.
+    pub fn get_mut(&mut self, entity: &Entity) -> Option<&mut V> {
+        self.values
+            .get_mut(entity.0 as usize)
+            .and_then(|value| value.as_mut())
+    }
+
+    /// Inserts the value of the given entity and returns the replaced value.
+    pub fn insert(&mut self, entity: Entity, value: V) -> Option<V> {
+        let index = entity.0 as usize;
+
+        if index >= self.values.len() {
+            self.values.resize_with(index + 1, || None);
+        }
+
+        let old_value = self.values[index].replace(value);
+
+        if old_value.is_none() {
+            self.len += 1;
+        }
+
+        old_value
+    }
+
+    /// Removes the value of the given entity and returns it.
+    pub fn remove(&mut self, entity: &Entity) -> Option<V> {
+        let old_value = self
+            .values
+            .get_mut(entity.0 as usize)
+            .and_then(|value| value.take());
+
+        if old_value.is_some() {
+            self.len -= 1;
+        }
+
+        old_value
+    }
+
+    /// Iterates over the entities and their values in entity order.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &V)> {
+        self.values
+            .iter()
+            .enumerate()
+            .filter_map(|(index, value)| value.as_ref().map(|value| (Entity(index as u32), value)))
+    }
+
+    /// Iterates over the entities with a value in entity order.
+    pub fn keys(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.iter().map(|(entity, _)| entity)
+    }
+
+    /// Iterates over the values in entity order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.values.iter().filter_map(|value| value.as_ref())
+    }
+}
+
+impl<V> Index<&Entity> for EntityMap<V> {
+    type Output = V;
+
+    fn index(&self, entity: &Entity) -> &V {
+        self.get(entity)
+            .unwrap_or_else(|| panic!("EntityMap: entity {} has no value.", entity.0))
+    }
+}
+
+impl<V> IndexMut<&Entity> for EntityMap<V> {
+    fn index_mut(&mut self, entity: &Entity) -> &mut V {
+        let index = entity.0;
+
+        self.get_mut(entity)
+            .unwrap_or_else(|| panic!("EntityMap: entity {} has no value.", index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut map = EntityMap::new();
+        assert!(map.is_empty());
+
+        assert_eq!(map.insert(Entity(3), "three"), None);
+        assert_eq!(map.insert(Entity(1), "one"), None);
+        assert_eq!(map.insert(Entity(3), "drei"), Some("three"));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&Entity(3)], "drei");
+        assert!(!map.contains_key(&Entity(2)));
+        assert_eq!(map.get(&Entity(10)), None);
+
+        assert_eq!(map.remove(&Entity(1)), Some("one"));
+        assert_eq!(map.remove(&Entity(1)), None);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut map = EntityMap::new();
+        map.insert(Entity(4), 4);
+        map.insert(Entity(0), 0);
+        map.insert(Entity(2), 2);
+        *map.get_mut(&Entity(2)).unwrap() += 1;
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(Entity(0), &0), (Entity(2), &3), (Entity(4), &4)]
+        );
+        assert_eq!(map.keys().count(), 3);
+        assert_eq!(map.values().sum::<i32>(), 7);
+    }
+}
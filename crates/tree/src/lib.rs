@@ -23,6 +23,11 @@ use std::{cell::Cell, collections::BTreeMap};
 
 use dces::{entity::EntityStore, prelude::Entity};
 
+pub use self::arena_map::*;
+pub use self::entity_map::*;
+
+mod arena_map;
+mod entity_map;
 pub mod prelude;
 
 /// Used as return type if a requested entity is not found on the tree.
@@ -130,6 +135,67 @@ impl Tree {
         Ok(child)
     }
 
+    /// Moves the `child` entity with all its children from its current parent to the given `parent`
+    /// entity at the given `index`. If `index` is greater than the number of children of `parent`
+    /// the child is appended.
+    /// Raised `NotFound` error if the parent or the child is not part of the tree.
+    pub fn move_child(
+        &mut self,
+        parent: impl Into<Entity>,
+        child: impl Into<Entity>,
+        index: usize,
+    ) -> Result<Entity, NotFound> {
+        let parent = parent.into();
+        let child = child.into();
+
+        if !self.children.contains_key(&parent) {
+            return Err(NotFound::Parent(parent));
+        }
+
+        let old_parent = if let Some(old_parent) = self.parent.get(&child) {
+            *old_parent
+        } else {
+            return Err(NotFound::Child(child));
+        };
+
+        if let Some(old_parent) = old_parent {
+            if let Some(siblings) = self.children.get_mut(&old_parent) {
+                siblings.retain(|c| *c != child);
+            }
+        }
+
+        if let Some(p) = self.children.get_mut(&parent) {
+            p.insert(index.min(p.len()), child);
+        }
+
+        self.parent.insert(child, Some(parent));
+
+        Ok(child)
+    }
+
+    /// Removes the `child` entity with all its children from its current parent. The entities stay
+    /// registered, but are no longer reachable from the parent.
+    /// Raised `NotFound` error if the child is not part of the tree.
+    pub fn detach_child(&mut self, child: impl Into<Entity>) -> Result<Entity, NotFound> {
+        let child = child.into();
+
+        let parent = if let Some(parent) = self.parent.get(&child) {
+            *parent
+        } else {
+            return Err(NotFound::Child(child));
+        };
+
+        if let Some(parent) = parent {
+            if let Some(siblings) = self.children.get_mut(&parent) {
+                siblings.retain(|c| *c != child);
+            }
+        }
+
+        self.parent.insert(child, None);
+
+        Ok(child)
+    }
+
     /// Returns the number of all entities in the tree.
     pub fn len(&self) -> usize {
         self.children.len()
@@ -260,6 +326,49 @@ mod tests {
         assert_eq!(tree.parent.get(&child).unwrap().unwrap(), parent);
     }
 
+    #[test]
+    fn test_move_child() {
+        let mut tree = Tree::new();
+        tree.register_node(0);
+        tree.register_node(1);
+        tree.register_node(2);
+        tree.register_node(3);
+
+        tree.append_child(0, 1).unwrap();
+        tree.append_child(0, 2).unwrap();
+        tree.append_child(1, 3).unwrap();
+
+        tree.move_child(2, 3, 0).unwrap();
+        assert!(tree.children[&Entity(1)].is_empty());
+        assert_eq!(tree.children[&Entity(2)], vec![Entity(3)]);
+        assert_eq!(tree.parent[&Entity(3)], Some(Entity(2)));
+
+        tree.move_child(0, 2, 0).unwrap();
+        assert_eq!(tree.children[&Entity(0)], vec![Entity(2), Entity(1)]);
+
+        assert_eq!(tree.move_child(4, 1, 0), Err(NotFound::Parent(Entity(4))));
+        assert_eq!(tree.move_child(0, 4, 0), Err(NotFound::Child(Entity(4))));
+    }
+
+    #[test]
+    fn test_detach_child() {
+        let mut tree = Tree::new();
+        tree.register_node(0);
+        tree.register_node(1);
+        tree.register_node(2);
+
+        tree.append_child(0, 1).unwrap();
+        tree.append_child(1, 2).unwrap();
+
+        tree.detach_child(1).unwrap();
+        assert!(tree.children[&Entity(0)].is_empty());
+        assert_eq!(tree.parent[&Entity(1)], None);
+        assert_eq!(tree.children[&Entity(1)], vec![Entity(2)]);
+        assert_eq!(tree.len(), 3);
+
+        assert_eq!(tree.detach_child(3), Err(NotFound::Child(Entity(3))));
+    }
+
     #[test]
     fn test_len() {
         let mut tree = Tree::new();
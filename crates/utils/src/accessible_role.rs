@@ -0,0 +1,151 @@
+/// Describes the role of a widget for assistive technologies like screen readers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AccessibleRole {
+    /// The widget is not exposed to assistive technologies, its children are.
+    None,
+    Button,
+    /// Cell of a table, the coordinates of the cell are exposed with the node.
+    Cell,
+    CheckBox,
+    /// Header of a table column.
+    ColumnHeader,
+    Group,
+    Image,
+    List,
+    ListItem,
+    Menu,
+    MenuBar,
+    MenuItem,
+    /// Row of a table that contains cells or column headers.
+    Row,
+    Slider,
+    Switch,
+    Table,
+    Text,
+    TextInput,
+    ToggleButton,
+    Tree,
+    TreeItem,
+    Window,
+}
+
+impl Default for AccessibleRole {
+    fn default() -> AccessibleRole {
+        AccessibleRole::None
+    }
+}
+
+impl AccessibleRole {
+    /// Returns the name of the corresponding WAI-ARIA role.
+    pub fn aria_role(self) -> &'static str {
+        match self {
+            AccessibleRole::None => "none",
+            AccessibleRole::Button | AccessibleRole::ToggleButton => "button",
+            AccessibleRole::Cell => "gridcell",
+            AccessibleRole::CheckBox => "checkbox",
+            AccessibleRole::ColumnHeader => "columnheader",
+            AccessibleRole::Group => "group",
+            AccessibleRole::Image => "img",
+            AccessibleRole::List => "list",
+            AccessibleRole::ListItem => "listitem",
+            AccessibleRole::Menu => "menu",
+            AccessibleRole::MenuBar => "menubar",
+            AccessibleRole::MenuItem => "menuitem",
+            AccessibleRole::Row => "row",
+            AccessibleRole::Slider => "slider",
+            AccessibleRole::Switch => "switch",
+            AccessibleRole::Table => "table",
+            AccessibleRole::Text => "text",
+            AccessibleRole::TextInput => "textbox",
+            AccessibleRole::Tree => "tree",
+            AccessibleRole::TreeItem => "treeitem",
+            AccessibleRole::Window => "application",
+        }
+    }
+
+    /// Returns `true` if the children of widgets with this role are not exposed, e.g. the text
+    /// block inside of a button.
+    pub fn is_leaf(self) -> bool {
+        match self {
+            AccessibleRole::Button
+            | AccessibleRole::Cell
+            | AccessibleRole::CheckBox
+            | AccessibleRole::ColumnHeader
+            | AccessibleRole::Image
+            | AccessibleRole::MenuItem
+            | AccessibleRole::Slider
+            | AccessibleRole::Switch
+            | AccessibleRole::Text
+            | AccessibleRole::TextInput
+            | AccessibleRole::ToggleButton => true,
+            _ => false,
+        }
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for AccessibleRole {
+    fn from(t: &str) -> Self {
+        match t {
+            "Button" | "button" => AccessibleRole::Button,
+            "Cell" | "cell" => AccessibleRole::Cell,
+            "CheckBox" | "check-box" => AccessibleRole::CheckBox,
+            "ColumnHeader" | "column-header" => AccessibleRole::ColumnHeader,
+            "Group" | "group" => AccessibleRole::Group,
+            "Image" | "image" => AccessibleRole::Image,
+            "List" | "list" => AccessibleRole::List,
+            "ListItem" | "list-item" => AccessibleRole::ListItem,
+            "Menu" | "menu" => AccessibleRole::Menu,
+            "MenuBar" | "menu-bar" => AccessibleRole::MenuBar,
+            "MenuItem" | "menu-item" => AccessibleRole::MenuItem,
+            "Row" | "row" => AccessibleRole::Row,
+            "Slider" | "slider" => AccessibleRole::Slider,
+            "Switch" | "switch" => AccessibleRole::Switch,
+            "Table" | "table" => AccessibleRole::Table,
+            "Text" | "text" => AccessibleRole::Text,
+            "TextInput" | "text-input" => AccessibleRole::TextInput,
+            "ToggleButton" | "toggle-button" => AccessibleRole::ToggleButton,
+            "Tree" | "tree" => AccessibleRole::Tree,
+            "TreeItem" | "tree-item" => AccessibleRole::TreeItem,
+            "Window" | "window" => AccessibleRole::Window,
+            _ => AccessibleRole::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let role: AccessibleRole = "Button".into();
+        assert_eq!(role, AccessibleRole::Button);
+
+        let role: AccessibleRole = "text-input".into();
+        assert_eq!(role, AccessibleRole::TextInput);
+
+        let role: AccessibleRole = "column-header".into();
+        assert_eq!(role, AccessibleRole::ColumnHeader);
+
+        let role: AccessibleRole = "other".into();
+        assert_eq!(role, AccessibleRole::None);
+    }
+
+    #[test]
+    fn test_aria_role() {
+        assert_eq!(AccessibleRole::CheckBox.aria_role(), "checkbox");
+        assert_eq!(AccessibleRole::ToggleButton.aria_role(), "button");
+        assert_eq!(AccessibleRole::Cell.aria_role(), "gridcell");
+    }
+
+    #[test]
+    fn test_is_leaf() {
+        assert!(AccessibleRole::Button.is_leaf());
+        assert!(AccessibleRole::Cell.is_leaf());
+        assert!(!AccessibleRole::List.is_leaf());
+        assert!(!AccessibleRole::Row.is_leaf());
+        assert!(!AccessibleRole::None.is_leaf());
+    }
+}
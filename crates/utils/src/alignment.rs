@@ -25,7 +25,9 @@ impl Alignment {
     ) -> f64 {
         match self {
             Alignment::End => available_measure - measure - margin_end,
-            Alignment::Center => (available_measure - measure) / 2.0,
+            Alignment::Center => {
+                margin_start + (available_measure - measure - margin_start - margin_end) / 2.0
+            }
             _ => margin_start,
         }
     }
@@ -40,7 +42,7 @@ impl Alignment {
         margin_end: f64,
     ) -> f64 {
         match self {
-            Alignment::Stretch => available_measure - margin_start - margin_end,
+            Alignment::Stretch => (available_measure - margin_start - margin_end).max(0.0),
             _ => measure,
         }
     }
@@ -150,4 +152,33 @@ mod tests {
         let alignment: Alignment = "other".into();
         assert_eq!(alignment, Alignment::Stretch);
     }
+
+    #[test]
+    fn test_align_margin() {
+        // negative margins move the widget over the edges of the available space
+        assert_eq!(
+            Alignment::Start.align_position(100.0, 50.0, -10.0, 0.0),
+            -10.0
+        );
+        assert_eq!(
+            Alignment::Center.align_position(100.0, 50.0, 10.0, 30.0),
+            15.0
+        );
+        assert_eq!(Alignment::End.align_position(100.0, 50.0, 0.0, -10.0), 60.0);
+        assert_eq!(
+            Alignment::Stretch.align_measure(100.0, 50.0, -10.0, -10.0),
+            120.0
+        );
+        assert_eq!(
+            Alignment::Stretch.align_measure(100.0, 50.0, 60.0, 60.0),
+            0.0
+        );
+
+        // a widget that is larger than the available space overflows it
+        assert_eq!(
+            Alignment::Center.align_position(100.0, 150.0, 0.0, 0.0),
+            -25.0
+        );
+        assert_eq!(Alignment::End.align_position(100.0, 150.0, 0.0, 0.0), -50.0);
+    }
 }
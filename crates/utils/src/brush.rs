@@ -28,6 +28,40 @@ impl Brush {
             _ => false,
         }
     }
+
+    /// Returns the brush with each of its colors changed by the given function, e.g. all stops
+    /// of a gradient.
+    pub fn map_colors(&self, f: impl Fn(Color) -> Color) -> Brush {
+        match self {
+            Brush::SolidColor(color) => Brush::SolidColor(f(*color)),
+            Brush::LinearGradient { start, end, stops } => Brush::LinearGradient {
+                start: *start,
+                end: *end,
+                stops: stops
+                    .iter()
+                    .map(|stop| LinearGradientStop {
+                        position: stop.position,
+                        color: f(stop.color),
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    /// Returns the brush with the given alpha value (0.0 - 1.0) for all of its colors.
+    pub fn with_alpha(&self, alpha: f64) -> Brush {
+        self.map_colors(|color| color.with_alpha(alpha))
+    }
+
+    /// Returns the brush with the lightness of its colors increased by the given amount.
+    pub fn lighten(&self, amount: f64) -> Brush {
+        self.map_colors(|color| color.lighten(amount))
+    }
+
+    /// Returns the brush with the lightness of its colors decreased by the given amount.
+    pub fn darken(&self, amount: f64) -> Brush {
+        self.map_colors(|color| color.darken(amount))
+    }
 }
 
 impl From<Brush> for Color {
@@ -71,6 +105,35 @@ impl From<String> for Brush {
 
 #[cfg(test)]
 mod tests {
-    //  use crate::prelude::*;
-    // todo: tbd after brush struct is finished
+    use crate::prelude::*;
+
+    #[test]
+    fn test_map_colors() {
+        assert_eq!(
+            Brush::from("#000").lighten(0.2),
+            Brush::from(Color::rgb(51, 51, 51))
+        );
+
+        let gradient = Brush::LinearGradient {
+            start: Point::new(0.0, 0.0),
+            end: Point::new(1.0, 0.0),
+            stops: vec![
+                LinearGradientStop {
+                    position: 0.0,
+                    color: Color::rgb(255, 0, 0),
+                },
+                LinearGradientStop {
+                    position: 1.0,
+                    color: Color::rgb(0, 0, 255),
+                },
+            ],
+        };
+
+        match gradient.with_alpha(0.5) {
+            Brush::LinearGradient { stops, .. } => {
+                assert!(stops.iter().all(|stop| stop.color.a() == 128));
+            }
+            _ => panic!("gradient expected"),
+        }
+    }
 }
@@ -55,6 +55,253 @@ impl Color {
     fn interp(start_color: u8, end_color: u8, scale: f64) -> u8 {
         (end_color as f64 - start_color as f64).mul_add(scale, start_color as f64) as u8
     }
+
+    /// Create a new color from hue (0.0 - 360.0), saturation and lightness (0.0 - 1.0).
+    pub fn hsl(h: f64, s: f64, l: f64) -> Self {
+        Color::hsla(h, s, l, 1.0)
+    }
+
+    /// Create a new color from hue (0.0 - 360.0), saturation, lightness and alpha (0.0 - 1.0).
+    pub fn hsla(h: f64, s: f64, l: f64, a: f64) -> Self {
+        let s = clamp_unit(s);
+        let l = clamp_unit(l);
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+
+        Color::from_chroma(h, c, l - c / 2.0, a)
+    }
+
+    /// Create a new color from hue (0.0 - 360.0), saturation and value (0.0 - 1.0).
+    pub fn hsv(h: f64, s: f64, v: f64) -> Self {
+        Color::hsva(h, s, v, 1.0)
+    }
+
+    /// Create a new color from hue (0.0 - 360.0), saturation, value and alpha (0.0 - 1.0).
+    pub fn hsva(h: f64, s: f64, v: f64, a: f64) -> Self {
+        let c = clamp_unit(v) * clamp_unit(s);
+
+        Color::from_chroma(h, c, clamp_unit(v) - c, a)
+    }
+
+    // Builds a color from hue, chroma and the value that is added to each channel.
+    fn from_chroma(h: f64, c: f64, m: f64, a: f64) -> Self {
+        let h = ((h % 360.0) + 360.0) % 360.0 / 60.0;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Color::rgba(
+            to_channel(r + m),
+            to_channel(g + m),
+            to_channel(b + m),
+            to_channel(a),
+        )
+    }
+
+    // Returns hue (0.0 - 360.0), the max and the min channel (0.0 - 1.0).
+    fn hue_max_min(self) -> (f64, f64, f64) {
+        let r = self.r() as f64 / 255.0;
+        let g = self.g() as f64 / 255.0;
+        let b = self.b() as f64 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let h = if delta <= 0.0 {
+            0.0
+        } else if r >= g && r >= b {
+            60.0 * (((g - b) / delta) % 6.0)
+        } else if g >= b {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (if h < 0.0 { h + 360.0 } else { h }, max, min)
+    }
+
+    /// Returns hue (0.0 - 360.0), saturation and lightness (0.0 - 1.0) of the color.
+    pub fn to_hsl(self) -> (f64, f64, f64) {
+        let (h, max, min) = self.hue_max_min();
+        let l = (max + min) / 2.0;
+
+        let s = if max - min <= 0.0 {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+
+        (h, s, l)
+    }
+
+    /// Returns hue (0.0 - 360.0), saturation and value (0.0 - 1.0) of the color.
+    pub fn to_hsv(self) -> (f64, f64, f64) {
+        let (h, max, min) = self.hue_max_min();
+        let s = if max <= 0.0 { 0.0 } else { (max - min) / max };
+
+        (h, s, max)
+    }
+
+    /// Returns the color with the lightness increased by the given amount (0.0 - 1.0), e.g.
+    /// `primary.lighten(0.1)` for a hover color.
+    pub fn lighten(self, amount: f64) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::hsla(h, s, l + amount, self.alpha())
+    }
+
+    /// Returns the color with the lightness decreased by the given amount (0.0 - 1.0).
+    pub fn darken(self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Mixes the color with an other color. A weight of 0.0 returns this color, 1.0 returns the
+    /// other color.
+    pub fn mix(self, other: Color, weight: f64) -> Color {
+        Color::interpolate(self, other, clamp_unit(weight))
+    }
+
+    /// Returns the relative luminance (0.0 - 1.0) of the color as defined by WCAG.
+    pub fn luminance(self) -> f64 {
+        let channel = |value: u8| {
+            let value = value as f64 / 255.0;
+
+            if value <= 0.03928 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * channel(self.r()) + 0.7152 * channel(self.g()) + 0.0722 * channel(self.b())
+    }
+
+    /// Returns the contrast ratio (1.0 - 21.0) between this and an other color as defined by WCAG.
+    pub fn contrast(self, other: Color) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns the alpha value as 0.0 - 1.0.
+    pub fn alpha(self) -> f64 {
+        self.a() as f64 / 255.0
+    }
+
+    /// Returns the color with the given alpha value (0.0 - 1.0).
+    pub fn with_alpha(self, alpha: f64) -> Color {
+        Color::rgba(self.r(), self.g(), self.b(), to_channel(alpha))
+    }
+
+    /// Parses a color from hex with 3, 4, 6 or 8 digits (`#rgb`, `#argb`, `#rrggbb`,
+    /// `#aarrggbb`) or from the css functions `rgb()`, `rgba()`, `hsl()` and `hsla()`. Returns
+    /// `None` if the text is not a valid color.
+    pub fn parse(text: &str) -> Option<Color> {
+        let text = text.trim();
+
+        if let Some(start) = text.find('(') {
+            if !text.ends_with(')') {
+                return None;
+            }
+
+            return parse_function(&text[..start], &text[start + 1..text.len() - 1]);
+        }
+
+        parse_hex(text.trim_start_matches('#'))
+    }
+}
+
+fn clamp_unit(value: f64) -> f64 {
+    value.clamp(0.0, 1.0)
+}
+
+fn to_channel(value: f64) -> u8 {
+    (clamp_unit(value) * 255.0).round() as u8
+}
+
+// Parses hex digits, the short forms repeat each digit. The alpha is the first component.
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let hex = match hex.len() {
+        3 | 4 => hex.chars().flat_map(|c| vec![c, c]).collect(),
+        6 | 8 => hex.to_string(),
+        _ => return None,
+    };
+
+    let mut data = u32::from_str_radix(&hex, 16).ok()?;
+
+    if hex.len() == 6 {
+        data |= 0xFF00_0000;
+    }
+
+    Some(Color { data })
+}
+
+// Parses an argument of a css color function, a percentage is returned as 0.0 - 1.0 and
+// `percentage` is set.
+fn parse_argument(argument: &str) -> Option<(f64, bool)> {
+    if let Some(argument) = argument.strip_suffix('%') {
+        return argument
+            .parse::<f64>()
+            .ok()
+            .map(|value| (value / 100.0, true));
+    }
+
+    argument
+        .trim_end_matches("deg")
+        .parse::<f64>()
+        .ok()
+        .map(|value| (value, false))
+}
+
+// Parses the arguments of `rgb()`, `rgba()`, `hsl()` or `hsla()`, separated by commas or by
+// spaces with the alpha after a slash.
+fn parse_function(name: &str, arguments: &str) -> Option<Color> {
+    let arguments = arguments
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|argument| !argument.is_empty())
+        .map(parse_argument)
+        .collect::<Option<Vec<_>>>()?;
+
+    if arguments.len() != 3 && arguments.len() != 4 {
+        return None;
+    }
+
+    let alpha = arguments.get(3).map_or(1.0, |(alpha, _)| *alpha);
+
+    match name.trim() {
+        "rgb" | "rgba" => {
+            let channel = |(value, percentage): (f64, bool)| {
+                if percentage {
+                    to_channel(value)
+                } else {
+                    value.clamp(0.0, 255.0).round() as u8
+                }
+            };
+
+            Some(Color::rgba(
+                channel(arguments[0]),
+                channel(arguments[1]),
+                channel(arguments[2]),
+                to_channel(alpha),
+            ))
+        }
+        "hsl" | "hsla" => Some(Color::hsla(
+            arguments[0].0,
+            arguments[1].0,
+            arguments[2].0,
+            alpha,
+        )),
+        _ => None,
+    }
 }
 
 impl ToString for Color {
@@ -73,23 +320,9 @@ impl ToString for Color {
 }
 
 impl From<&str> for Color {
+    /// Parses the color with `Color::parse`, an invalid color is transparent.
     fn from(s: &str) -> Color {
-        let clean_hex = s.trim_start_matches('#');
-        match clean_hex.len() {
-            6 | 8 => {
-                let mut x = match u32::from_str_radix(&clean_hex, 16) {
-                    Ok(x) => x,
-                    Err(_) => 0,
-                };
-
-                if clean_hex.len() == 6 {
-                    x |= 0xFF_000_000;
-                }
-
-                Color { data: x }
-            }
-            _ => Color { data: 0 },
-        }
+        Color::parse(s).unwrap_or(Color { data: 0 })
     }
 }
 
@@ -122,4 +355,95 @@ mod tests {
         assert_eq!(false, Color::rgb(1, 2, 3) == Color::rgba(11, 2, 3, 200));
         assert_eq!(true, Color::rgba(1, 2, 3, 200) == Color::rgba(1, 2, 3, 200));
     }
+
+    #[test]
+    fn hsl() {
+        assert_eq!(Color::rgb(255, 0, 0), Color::hsl(0.0, 1.0, 0.5));
+        assert_eq!(Color::rgb(0, 128, 0), Color::hsl(120.0, 1.0, 0.25));
+        assert_eq!(Color::rgb(0, 0, 255), Color::hsl(-120.0, 1.0, 0.5));
+
+        let (h, s, l) = Color::rgb(0, 128, 0).to_hsl();
+        assert_eq!(
+            (120, 100, 25),
+            (
+                (h.round() as i32),
+                (s * 100.0).round() as i32,
+                (l * 100.0).round() as i32
+            )
+        );
+
+        let color = Color::rgb(94, 121, 166);
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(color, Color::hsl(h, s, l));
+    }
+
+    #[test]
+    fn hsv() {
+        assert_eq!(Color::rgb(255, 255, 0), Color::hsv(60.0, 1.0, 1.0));
+        assert_eq!(Color::rgb(128, 128, 128), Color::hsv(200.0, 0.0, 0.5));
+
+        let color = Color::rgb(94, 121, 166);
+        let (h, s, v) = color.to_hsv();
+        assert_eq!(color, Color::hsv(h, s, v));
+    }
+
+    #[test]
+    fn contrast() {
+        assert_eq!(
+            21.0,
+            Color::rgb(0, 0, 0).contrast(Color::rgb(255, 255, 255))
+        );
+        assert_eq!(
+            1.0,
+            Color::rgb(94, 121, 166).contrast(Color::rgb(94, 121, 166))
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Color::parse("#abc"), Some(Color::rgb(0xAA, 0xBB, 0xCC)));
+        assert_eq!(Color::parse("#8abc").map(|c| c.a()), Some(0x88));
+        assert_eq!(Color::parse("5e79a6"), Some(Color::rgb(94, 121, 166)));
+        assert_eq!(Color::parse("#805e79a6").map(|c| c.a()), Some(0x80));
+        assert_eq!(
+            Color::parse("rgba(94, 121, 166, 0.5)").map(|c| (c, c.a())),
+            Some((Color::rgb(94, 121, 166), 128))
+        );
+        assert_eq!(
+            Color::parse("rgb(100% 0% 0% / 25%)").map(|c| (c, c.a())),
+            Some((Color::rgb(255, 0, 0), 64))
+        );
+        assert_eq!(
+            Color::parse("hsl(120deg, 100%, 25%)"),
+            Some(Color::rgb(0, 128, 0))
+        );
+        assert_eq!(
+            Color::parse("hsla(0, 100%, 50%, 0)").map(|c| c.a()),
+            Some(0)
+        );
+
+        assert_eq!(Color::parse("#abcde"), None);
+        assert_eq!(Color::parse("#ggg"), None);
+        assert_eq!(Color::parse("rgb(1, 2)"), None);
+        assert_eq!(Color::parse("cmyk(1, 2, 3, 4)"), None);
+        assert_eq!(Color::from("nocolor").a(), 0);
+    }
+
+    #[test]
+    fn lighten_darken_mix() {
+        assert_eq!(Color::rgb(255, 255, 255), Color::rgb(0, 0, 0).lighten(1.0));
+        assert_eq!(Color::rgb(51, 51, 51), Color::rgb(0, 0, 0).lighten(0.2));
+        assert_eq!(Color::rgb(0, 0, 0), Color::rgb(51, 51, 51).darken(0.5));
+        assert_eq!(
+            Color::rgb(127, 127, 127),
+            Color::rgb(0, 0, 0).mix(Color::rgb(255, 255, 255), 0.5)
+        );
+        assert_eq!(
+            100,
+            Color::rgba(1, 2, 3, 200)
+                .lighten(0.1)
+                .with_alpha(100.0 / 255.0)
+                .a()
+        );
+    }
 }
@@ -0,0 +1,152 @@
+/// Describes the radii of the four corners of a rounded rectangle, e.g. a border radius.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct CornerRadius {
+    /// Radius of the top left corner.
+    pub top_left: f64,
+
+    /// Radius of the top right corner.
+    pub top_right: f64,
+
+    /// Radius of the bottom right corner.
+    pub bottom_right: f64,
+
+    /// Radius of the bottom left corner.
+    pub bottom_left: f64,
+}
+
+impl CornerRadius {
+    /// Creates a new corner radius with the given radii, clockwise from the top left corner.
+    pub fn new(top_left: f64, top_right: f64, bottom_right: f64, bottom_left: f64) -> Self {
+        CornerRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Creates a corner radius from one to four values like the css `border-radius` property.
+    /// One value sets all corners, two values set the top left and bottom right and then the
+    /// top right and bottom left corners, three values set the top left, the top right and
+    /// bottom left and the bottom right corners and four values set the corners clockwise from
+    /// the top left corner. Returns `None` for any other number of values.
+    pub fn from_values(values: &[f64]) -> Option<Self> {
+        match *values {
+            [radius] => Some(CornerRadius::from(radius)),
+            [first, second] => Some(CornerRadius::new(first, second, first, second)),
+            [top_left, second, bottom_right] => {
+                Some(CornerRadius::new(top_left, second, bottom_right, second))
+            }
+            [top_left, top_right, bottom_right, bottom_left] => Some(CornerRadius::new(
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if all corners have the same radius.
+    pub fn is_uniform(&self) -> bool {
+        self.top_left == self.top_right
+            && self.top_left == self.bottom_right
+            && self.top_left == self.bottom_left
+    }
+
+    /// Returns `true` if no corner is rounded.
+    pub fn is_zero(&self) -> bool {
+        self.top_left <= 0.0
+            && self.top_right <= 0.0
+            && self.bottom_right <= 0.0
+            && self.bottom_left <= 0.0
+    }
+
+    /// Returns the radii that fit into a rectangle of the given size. Like in css, all radii are
+    /// scaled down by the same factor if the radii of two corners on one side overlap.
+    pub fn fit(&self, width: f64, height: f64) -> CornerRadius {
+        let scale = |side: f64, radii: f64| if radii > side { side / radii } else { 1.0 };
+        let factor = scale(width, self.top_left + self.top_right)
+            .min(scale(width, self.bottom_left + self.bottom_right))
+            .min(scale(height, self.top_left + self.bottom_left))
+            .min(scale(height, self.top_right + self.bottom_right));
+
+        CornerRadius::new(
+            self.top_left.max(0.0) * factor,
+            self.top_right.max(0.0) * factor,
+            self.bottom_right.max(0.0) * factor,
+            self.bottom_left.max(0.0) * factor,
+        )
+    }
+}
+
+// --- Trait implementations ---
+
+impl From<f64> for CornerRadius {
+    fn from(radius: f64) -> Self {
+        CornerRadius::new(radius, radius, radius, radius)
+    }
+}
+
+impl From<i32> for CornerRadius {
+    fn from(radius: i32) -> Self {
+        CornerRadius::from(radius as f64)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for CornerRadius {
+    fn from(r: (f64, f64, f64, f64)) -> Self {
+        CornerRadius::new(r.0, r.1, r.2, r.3)
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for CornerRadius {
+    fn from(r: (i32, i32, i32, i32)) -> Self {
+        CornerRadius::new(r.0 as f64, r.1 as f64, r.2 as f64, r.3 as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_values() {
+        assert_eq!(
+            CornerRadius::from_values(&[4.0]),
+            Some(CornerRadius::new(4.0, 4.0, 4.0, 4.0))
+        );
+        assert_eq!(
+            CornerRadius::from_values(&[4.0, 2.0]),
+            Some(CornerRadius::new(4.0, 2.0, 4.0, 2.0))
+        );
+        assert_eq!(
+            CornerRadius::from_values(&[4.0, 2.0, 0.0]),
+            Some(CornerRadius::new(4.0, 2.0, 0.0, 2.0))
+        );
+        assert_eq!(
+            CornerRadius::from_values(&[4.0, 4.0, 0.0, 0.0]),
+            Some(CornerRadius::new(4.0, 4.0, 0.0, 0.0))
+        );
+        assert_eq!(CornerRadius::from_values(&[]), None);
+    }
+
+    #[test]
+    fn test_fit() {
+        let radius = CornerRadius::new(20.0, 20.0, 0.0, 0.0);
+        assert_eq!(radius.fit(100.0, 100.0), radius);
+
+        // the top corners overlap on a width of 20
+        assert_eq!(
+            radius.fit(20.0, 100.0),
+            CornerRadius::new(10.0, 10.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_is_uniform() {
+        assert!(CornerRadius::from(2.0).is_uniform());
+        assert!(!CornerRadius::new(2.0, 2.0, 0.0, 2.0).is_uniform());
+        assert!(CornerRadius::default().is_zero());
+    }
+}
@@ -0,0 +1,80 @@
+/// Describes the icon of the mouse cursor while it hovers a widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CursorIcon {
+    /// The default arrow.
+    Arrow,
+
+    /// A hand that indicates a link or a clickable element.
+    Hand,
+
+    /// A text cursor (I-beam) that indicates selectable or editable text.
+    Text,
+
+    /// A crosshair, e.g. to pick a position or a color.
+    Crosshair,
+
+    /// Indicates that the element could be resized horizontally, e.g. a column divider.
+    ResizeHorizontal,
+
+    /// Indicates that the element could be resized vertically, e.g. a row divider.
+    ResizeVertical,
+
+    /// Indicates that the element could be moved or resized in all directions.
+    ResizeAll,
+
+    /// The cursor is not shown.
+    Hidden,
+
+    /// A cursor from the image with the given path. Backends that don't support custom cursors
+    /// show the arrow instead.
+    Custom(String),
+}
+
+impl Default for CursorIcon {
+    fn default() -> Self {
+        CursorIcon::Arrow
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for CursorIcon {
+    fn from(t: &str) -> Self {
+        match t {
+            "Hand" | "hand" | "pointer" => CursorIcon::Hand,
+            "Text" | "text" => CursorIcon::Text,
+            "Crosshair" | "crosshair" => CursorIcon::Crosshair,
+            "ResizeHorizontal" | "resize-horizontal" | "ew-resize" => CursorIcon::ResizeHorizontal,
+            "ResizeVertical" | "resize-vertical" | "ns-resize" => CursorIcon::ResizeVertical,
+            "ResizeAll" | "resize-all" | "move" => CursorIcon::ResizeAll,
+            "Hidden" | "hidden" | "none" => CursorIcon::Hidden,
+            _ => CursorIcon::Arrow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let cursor_icon: CursorIcon = "hand".into();
+        assert_eq!(cursor_icon, CursorIcon::Hand);
+
+        let cursor_icon: CursorIcon = "Text".into();
+        assert_eq!(cursor_icon, CursorIcon::Text);
+
+        let cursor_icon: CursorIcon = "ew-resize".into();
+        assert_eq!(cursor_icon, CursorIcon::ResizeHorizontal);
+
+        let cursor_icon: CursorIcon = "resize-vertical".into();
+        assert_eq!(cursor_icon, CursorIcon::ResizeVertical);
+
+        let cursor_icon: CursorIcon = "none".into();
+        assert_eq!(cursor_icon, CursorIcon::Hidden);
+
+        let cursor_icon: CursorIcon = "other".into();
+        assert_eq!(cursor_icon, CursorIcon::Arrow);
+    }
+}
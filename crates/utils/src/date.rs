@@ -0,0 +1,212 @@
+use std::{fmt, str::FromStr};
+
+/// Describes a calendar date of the gregorian calendar.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new date. Month and day are clamped to valid values.
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = month.clamp(1, 12);
+        let day = day.clamp(1, Date::days_in_month(year, month));
+
+        Date { year, month, day }
+    }
+
+    /// Gets the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Gets the month from 1 to 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Gets the day of the month from 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Checks if the given year is a leap year.
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Returns the number of days of the given month.
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if Date::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns the day of the week from 0 (monday) to 6 (sunday).
+    pub fn weekday(&self) -> u32 {
+        (self.days_since_epoch() + 3).rem_euclid(7) as u32
+    }
+
+    /// Returns the first day of the month of the date.
+    pub fn first_of_month(&self) -> Date {
+        Date::new(self.year, self.month, 1)
+    }
+
+    /// Adds the given number of months. The day is clamped to the days of the new month.
+    pub fn add_months(&self, months: i32) -> Date {
+        let month = self.year * 12 + self.month as i32 - 1 + months;
+        Date::new(
+            month.div_euclid(12),
+            month.rem_euclid(12) as u32 + 1,
+            self.day,
+        )
+    }
+
+    /// Adds the given number of days.
+    pub fn add_days(&self, days: i64) -> Date {
+        Date::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Returns the current date (UTC).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn today() -> Date {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Date::from_days_since_epoch((seconds / 86_400) as i64)
+    }
+
+    // Number of days since 1970-01-01.
+    fn days_since_epoch(&self) -> i64 {
+        let year = if self.month <= 2 {
+            self.year as i64 - 1
+        } else {
+            self.year as i64
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days_since_epoch(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        Date::new(1970, 1, 1)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// Parses a date of the format `yyyy-mm-dd`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.trim().split('-').collect();
+
+        if parts.len() != 3 {
+            return Err(format!(
+                "Date.from_str: {} is not a date of format yyyy-mm-dd.",
+                s
+            ));
+        }
+
+        let parse_error = |_| format!("Date.from_str: {} is not a date of format yyyy-mm-dd.", s);
+        let year = parts[0].parse::<i32>().map_err(parse_error)?;
+        let month = parts[1].parse::<u32>().map_err(parse_error)?;
+        let day = parts[2].parse::<u32>().map_err(parse_error)?;
+
+        if !(1..=12).contains(&month) || !(1..=Date::days_in_month(year, month)).contains(&day) {
+            return Err(format!("Date.from_str: {} is not a valid date.", s));
+        }
+
+        Ok(Date { year, month, day })
+    }
+}
+
+impl From<(i32, u32, u32)> for Date {
+    fn from(t: (i32, u32, u32)) -> Self {
+        Date::new(t.0, t.1, t.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Date::new(2020, 2, 30), Date::new(2020, 2, 29));
+        assert_eq!(Date::new(2019, 2, 30), Date::new(2019, 2, 28));
+        assert_eq!(Date::new(2020, 13, 0), Date::new(2020, 12, 1));
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(Date::new(1970, 1, 1).weekday(), 3);
+        assert_eq!(Date::new(2020, 3, 1).weekday(), 6);
+        assert_eq!(Date::new(2000, 2, 29).weekday(), 1);
+    }
+
+    #[test]
+    fn test_add_months() {
+        assert_eq!(Date::new(2020, 1, 31).add_months(1), Date::new(2020, 2, 29));
+        assert_eq!(
+            Date::new(2020, 1, 15).add_months(-1),
+            Date::new(2019, 12, 15)
+        );
+        assert_eq!(Date::new(2020, 12, 1).add_months(13), Date::new(2022, 1, 1));
+    }
+
+    #[test]
+    fn test_add_days() {
+        assert_eq!(Date::new(2020, 2, 28).add_days(1), Date::new(2020, 2, 29));
+        assert_eq!(Date::new(2020, 12, 31).add_days(1), Date::new(2021, 1, 1));
+        assert_eq!(Date::new(2020, 1, 1).add_days(-1), Date::new(2019, 12, 31));
+        assert_eq!(Date::new(1969, 12, 31).add_days(366), Date::new(1971, 1, 1));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("2020-03-01".parse::<Date>(), Ok(Date::new(2020, 3, 1)));
+        assert!("2019-02-29".parse::<Date>().is_err());
+        assert!("2020-03".parse::<Date>().is_err());
+        assert!("a-b-c".parse::<Date>().is_err());
+        assert_eq!(Date::new(2020, 3, 1).to_string(), "2020-03-01");
+    }
+}
@@ -0,0 +1,54 @@
+/// Describes the direction in which the content of a widget flows. Right to left mirrors the
+/// layout of the children, e.g. for Arabic or Hebrew user interfaces.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FlowDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl FlowDirection {
+    /// Returns `true` if the direction is right to left.
+    pub fn is_right_to_left(self) -> bool {
+        self == FlowDirection::RightToLeft
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for FlowDirection {
+    fn from(t: &str) -> Self {
+        match t {
+            "RightToLeft" | "right-to-left" | "rtl" => FlowDirection::RightToLeft,
+            _ => FlowDirection::LeftToRight,
+        }
+    }
+}
+
+impl Default for FlowDirection {
+    fn default() -> FlowDirection {
+        FlowDirection::LeftToRight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let flow_direction: FlowDirection = "RightToLeft".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "right-to-left".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "rtl".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "LeftToRight".into();
+        assert_eq!(flow_direction, FlowDirection::LeftToRight);
+
+        let flow_direction: FlowDirection = "other".into();
+        assert_eq!(flow_direction, FlowDirection::LeftToRight);
+    }
+}
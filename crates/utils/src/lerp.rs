@@ -0,0 +1,175 @@
+use crate::prelude::*;
+
+/// Describes a value that could be interpolated linearly, e.g. to tween a property of a widget
+/// in an animation.
+pub trait Lerp {
+    /// Returns the value between this and the given value, a scale of 0.0 returns this value and
+    /// 1.0 returns the other value.
+    fn lerp(&self, to: &Self, scale: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, to: &Self, scale: f64) -> Self {
+        self + (to - self) * scale
+    }
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, to: &Self, scale: f64) -> Self {
+        self + (to - self) * scale as f32
+    }
+}
+
+impl Lerp for Point {
+    fn lerp(&self, to: &Self, scale: f64) -> Self {
+        Point::new(self.x.lerp(&to.x, scale), self.y.lerp(&to.y, scale))
+    }
+}
+
+impl Lerp for Thickness {
+    fn lerp(&self, to: &Self, scale: f64) -> Self {
+        Thickness::new(
+            self.left.lerp(&to.left, scale),
+            self.top.lerp(&to.top, scale),
+            self.right.lerp(&to.right, scale),
+            self.bottom.lerp(&to.bottom, scale),
+        )
+    }
+}
+
+impl Lerp for CornerRadius {
+    fn lerp(&self, to: &Self, scale: f64) -> Self {
+        CornerRadius::new(
+            self.top_left.lerp(&to.top_left, scale),
+            self.top_right.lerp(&to.top_right, scale),
+            self.bottom_right.lerp(&to.bottom_right, scale),
+            self.bottom_left.lerp(&to.bottom_left, scale),
+        )
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(&self, to: &Self, scale: f64) -> Self {
+        Color::interpolate(*self, *to, scale)
+    }
+}
+
+// Returns a gradient with the geometry and stop positions of the given gradient, that is filled
+// with the color, so a solid color could be tweened to a gradient.
+fn solid_gradient(color: Color, gradient: &Brush) -> Brush {
+    gradient.map_colors(|_| color)
+}
+
+impl Lerp for Brush {
+    /// Interpolates the colors of the brushes. Gradients are interpolated stop by stop, if they
+    /// have a different number of stops the brush switches at the half of the animation.
+    fn lerp(&self, to: &Self, scale: f64) -> Self {
+        match (self, to) {
+            (Brush::SolidColor(from), Brush::SolidColor(to)) => {
+                Brush::SolidColor(from.lerp(to, scale))
+            }
+            (Brush::SolidColor(from), gradient @ Brush::LinearGradient { .. }) => {
+                solid_gradient(*from, gradient).lerp(gradient, scale)
+            }
+            (gradient @ Brush::LinearGradient { .. }, Brush::SolidColor(to)) => {
+                gradient.lerp(&solid_gradient(*to, gradient), scale)
+            }
+            (
+                Brush::LinearGradient {
+                    start: from_start,
+                    end: from_end,
+                    stops: from_stops,
+                },
+                Brush::LinearGradient {
+                    start: to_start,
+                    end: to_end,
+                    stops: to_stops,
+                },
+            ) if from_stops.len() == to_stops.len() => Brush::LinearGradient {
+                start: from_start.lerp(to_start, scale),
+                end: from_end.lerp(to_end, scale),
+                stops: from_stops
+                    .iter()
+                    .zip(to_stops)
+                    .map(|(from, to)| LinearGradientStop {
+                        position: from.position.lerp(&to.position, scale),
+                        color: from.color.lerp(&to.color, scale),
+                    })
+                    .collect(),
+            },
+            _ => {
+                if scale < 0.5 {
+                    self.clone()
+                } else {
+                    to.clone()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(stops: &[(f64, Color)]) -> Brush {
+        Brush::LinearGradient {
+            start: Point::new(0.0, 0.0),
+            end: Point::new(0.0, 10.0),
+            stops: stops
+                .iter()
+                .map(|(position, color)| LinearGradientStop {
+                    position: *position,
+                    color: *color,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_lerp_values() {
+        assert_eq!(2.0.lerp(&4.0, 0.5), 3.0);
+        assert_eq!(
+            Point::new(0.0, 10.0).lerp(&Point::new(10.0, 0.0), 0.25),
+            Point::new(2.5, 7.5)
+        );
+        assert_eq!(
+            Thickness::new(0.0, 0.0, 0.0, 0.0).lerp(&Thickness::new(4.0, 8.0, 4.0, 8.0), 0.5),
+            Thickness::new(2.0, 4.0, 2.0, 4.0)
+        );
+        assert_eq!(
+            CornerRadius::from(4.0).lerp(&CornerRadius::new(8.0, 8.0, 0.0, 0.0), 0.5),
+            CornerRadius::new(6.0, 6.0, 2.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_lerp_brush() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
+        let gray = Color::rgb(127, 127, 127);
+
+        assert_eq!(
+            Brush::from(black).lerp(&Brush::from(white), 0.5),
+            Brush::from(gray)
+        );
+
+        // gradients with the same number of stops are interpolated stop by stop
+        assert_eq!(
+            gradient(&[(0.0, black), (1.0, black)])
+                .lerp(&gradient(&[(0.5, white), (1.0, black)]), 0.5),
+            gradient(&[(0.25, gray), (1.0, black)])
+        );
+
+        // a solid color is tweened to each stop
+        assert_eq!(
+            Brush::from(black).lerp(&gradient(&[(0.0, white), (1.0, black)]), 0.5),
+            gradient(&[(0.0, gray), (1.0, black)])
+        );
+
+        let three_stops = gradient(&[(0.0, white), (0.5, black), (1.0, white)]);
+        let two_stops = gradient(&[(0.0, black), (1.0, white)]);
+        assert_eq!(three_stops.lerp(&two_stops, 0.4), three_stops);
+        assert_eq!(three_stops.lerp(&two_stops, 0.6), two_stops);
+    }
+}
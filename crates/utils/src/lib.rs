@@ -1,31 +1,59 @@
+pub use self::accessible_role::*;
 pub use self::alignment::*;
 pub use self::border::*;
 pub use self::brush::*;
 pub use self::color::*;
+pub use self::corner_radius::*;
+pub use self::cursor_icon::*;
+pub use self::date::*;
 pub use self::dirty_size::*;
+pub use self::flow_direction::*;
+pub use self::lerp::*;
 pub use self::orientation::*;
+pub use self::palette::*;
 pub use self::point::*;
 pub use self::rectangle::*;
+pub use self::render_mode::*;
 pub use self::selection_mode::*;
+pub use self::sort_direction::*;
 pub use self::string16::*;
 pub use self::text_alignment::*;
 pub use self::text_baseline::*;
+pub use self::text_edit::*;
+pub use self::text_trimming::*;
+pub use self::text_wrap::*;
 pub use self::thickness::*;
+pub use self::undo_stack::*;
 pub use self::visibility::*;
+pub use self::window_grip::*;
 
+mod accessible_role;
 mod alignment;
 mod border;
 mod brush;
 mod color;
+mod corner_radius;
+mod cursor_icon;
+mod date;
 mod dirty_size;
+mod flow_direction;
+mod lerp;
 mod orientation;
+mod palette;
 mod point;
 pub mod prelude;
 mod rectangle;
+mod render_mode;
 mod selection_mode;
+mod sort_direction;
 mod spacer;
 mod string16;
 mod text_alignment;
 mod text_baseline;
+mod text_edit;
+mod text_trimming;
+mod text_wrap;
 mod thickness;
+mod undo_stack;
 mod visibility;
+mod window_grip;
@@ -0,0 +1,97 @@
+use crate::Color;
+
+/// Semantic colors that are derived from one accent color, e.g. to offer user-chosen accent colors
+/// with a consistent theming.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Palette {
+    /// The accent color, e.g. the background of primary buttons and selected items.
+    pub accent: Color,
+
+    /// The accent color of hovered elements.
+    pub accent_hover: Color,
+
+    /// The accent color of pressed elements.
+    pub accent_pressed: Color,
+
+    /// The accent color of disabled elements.
+    pub accent_disabled: Color,
+
+    /// The color of text and icons on the accent color.
+    pub on_accent: Color,
+
+    /// The color of text and icons on the disabled accent color.
+    pub on_accent_disabled: Color,
+}
+
+impl Palette {
+    /// Derives the palette from the given seed color.
+    pub fn from_seed(seed: Color) -> Self {
+        let seed = seed.with_alpha(1.0);
+        let (h, s, l) = seed.to_hsl();
+
+        // very light colors are darkened on hover to keep a visible difference
+        let accent_hover = if l > 0.9 {
+            seed.darken(0.05)
+        } else {
+            seed.lighten(0.1)
+        };
+
+        let accent_disabled = Color::hsl(h, s * 0.3, l);
+
+        Palette {
+            accent: seed,
+            accent_hover,
+            accent_pressed: seed.darken(0.1),
+            accent_disabled,
+            on_accent: on_color(seed),
+            on_accent_disabled: on_color(accent_disabled).mix(accent_disabled, 0.4),
+        }
+    }
+}
+
+impl From<Color> for Palette {
+    fn from(seed: Color) -> Self {
+        Palette::from_seed(seed)
+    }
+}
+
+impl From<&str> for Palette {
+    fn from(seed: &str) -> Self {
+        Palette::from_seed(Color::from(seed))
+    }
+}
+
+// Returns black or white, whichever has the higher contrast to the given background.
+fn on_color(background: Color) -> Color {
+    let black = Color::rgb(0, 0, 0);
+    let white = Color::rgb(255, 255, 255);
+
+    if background.contrast(black) >= background.contrast(white) {
+        black
+    } else {
+        white
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_seed() {
+        let palette = Palette::from_seed(Color::rgb(0x21, 0x6b, 0xe1));
+
+        assert_eq!(Color::rgb(0x21, 0x6b, 0xe1), palette.accent);
+        assert!(palette.accent_hover.to_hsl().2 > palette.accent.to_hsl().2);
+        assert!(palette.accent_pressed.to_hsl().2 < palette.accent.to_hsl().2);
+        assert!(palette.accent_disabled.to_hsl().1 < palette.accent.to_hsl().1);
+        assert_eq!(Color::rgb(255, 255, 255), palette.on_accent);
+
+        let palette = Palette::from("#efd035");
+        assert_eq!(Color::rgb(0, 0, 0), palette.on_accent);
+        assert!(palette.accent_hover.to_hsl().2 > palette.accent.to_hsl().2);
+
+        let palette = Palette::from_seed(Color::rgb(250, 250, 250));
+        assert!(palette.accent_hover.to_hsl().2 < palette.accent.to_hsl().2);
+    }
+}
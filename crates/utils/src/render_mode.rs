@@ -0,0 +1,47 @@
+/// Describes when the loop of a window renders a new frame.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RenderMode {
+    /// The content is updated and presented in each frame.
+    Continuous,
+
+    /// The loop sleeps until an input event or an update request, e.g. of a state or an
+    /// animation, is received. Keeps the cpu usage of idle tool style applications low.
+    OnDemand,
+}
+
+impl Default for RenderMode {
+    fn default() -> Self {
+        RenderMode::Continuous
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for RenderMode {
+    fn from(t: &str) -> Self {
+        match t {
+            "OnDemand" | "on-demand" | "on_demand" => RenderMode::OnDemand,
+            _ => RenderMode::Continuous,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let render_mode: RenderMode = "on-demand".into();
+        assert_eq!(render_mode, RenderMode::OnDemand);
+
+        let render_mode: RenderMode = "OnDemand".into();
+        assert_eq!(render_mode, RenderMode::OnDemand);
+
+        let render_mode: RenderMode = "continuous".into();
+        assert_eq!(render_mode, RenderMode::Continuous);
+
+        let render_mode: RenderMode = "other".into();
+        assert_eq!(render_mode, RenderMode::Continuous);
+    }
+}
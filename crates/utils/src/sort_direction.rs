@@ -0,0 +1,62 @@
+/// Describes the direction of a sorted list, e.g. a sorted table column.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SortDirection {
+    /// From the smallest to the largest value.
+    Ascending,
+
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction.
+    pub fn toggle(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+impl Default for SortDirection {
+    fn default() -> SortDirection {
+        SortDirection::Ascending
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for SortDirection {
+    fn from(t: &str) -> Self {
+        match t {
+            "Descending" | "descending" => SortDirection::Descending,
+            _ => SortDirection::Ascending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle() {
+        assert_eq!(SortDirection::Ascending.toggle(), SortDirection::Descending);
+        assert_eq!(SortDirection::Descending.toggle(), SortDirection::Ascending);
+    }
+
+    #[test]
+    fn test_into() {
+        let sort_direction: SortDirection = "Descending".into();
+        assert_eq!(sort_direction, SortDirection::Descending);
+
+        let sort_direction: SortDirection = "descending".into();
+        assert_eq!(sort_direction, SortDirection::Descending);
+
+        let sort_direction: SortDirection = "Ascending".into();
+        assert_eq!(sort_direction, SortDirection::Ascending);
+
+        let sort_direction: SortDirection = "other".into();
+        assert_eq!(sort_direction, SortDirection::Ascending);
+    }
+}
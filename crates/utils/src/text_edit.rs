@@ -0,0 +1,220 @@
+use crate::{Edit, String16};
+
+/// Describes an edit of the text of a text widget, used for undo and redo. Indices and lengths are
+/// measured in UTF-16 units like the `String16` text.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextEdit {
+    /// The text is inserted at the index.
+    Insert { index: usize, text: String },
+
+    /// The text at the index is deleted.
+    Delete { index: usize, text: String },
+
+    /// The old text at the index is replaced by the new text.
+    Replace {
+        index: usize,
+        old: String,
+        new: String,
+    },
+}
+
+// Returns the length of the text in UTF-16 units.
+fn len16(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+// Removes the given number of UTF-16 units at the index.
+fn remove(target: &mut String16, index: usize, len: usize) {
+    for _ in 0..len.min(target.len().saturating_sub(index)) {
+        target.remove(index);
+    }
+}
+
+// Returns `true` if the text is a single character, only typed characters are merged.
+fn is_char(text: &str) -> bool {
+    text.chars().count() == 1
+}
+
+impl TextEdit {
+    /// Returns the position of the caret after the edit is applied.
+    pub fn caret_after_apply(&self) -> usize {
+        match self {
+            TextEdit::Insert { index, text } => index + len16(text),
+            TextEdit::Delete { index, .. } => *index,
+            TextEdit::Replace { index, new, .. } => index + len16(new),
+        }
+    }
+
+    /// Returns the position of the caret after the edit is reverted.
+    pub fn caret_after_revert(&self) -> usize {
+        match self {
+            TextEdit::Insert { index, .. } => *index,
+            TextEdit::Delete { index, text } => index + len16(text),
+            TextEdit::Replace { index, old, .. } => index + len16(old),
+        }
+    }
+}
+
+impl Edit for TextEdit {
+    type Target = String16;
+
+    fn apply(&self, target: &mut String16) {
+        match self {
+            TextEdit::Insert { index, text } => target.insert_str(*index, text),
+            TextEdit::Delete { index, text } => remove(target, *index, len16(text)),
+            TextEdit::Replace { index, old, new } => {
+                remove(target, *index, len16(old));
+                target.insert_str(*index, new);
+            }
+        }
+    }
+
+    fn revert(&self, target: &mut String16) {
+        match self {
+            TextEdit::Insert { index, text } => remove(target, *index, len16(text)),
+            TextEdit::Delete { index, text } => target.insert_str(*index, text),
+            TextEdit::Replace { index, old, new } => {
+                remove(target, *index, len16(new));
+                target.insert_str(*index, old);
+            }
+        }
+    }
+
+    // Typed characters are merged until a new word starts, deleted characters are merged while
+    // backspace or delete is pressed at the same position.
+    fn merge(&mut self, next: &Self) -> bool {
+        match (self, next) {
+            (
+                TextEdit::Insert { index, text },
+                TextEdit::Insert {
+                    index: next_index,
+                    text: next_text,
+                },
+            ) => {
+                let word_start = text.ends_with(char::is_whitespace)
+                    && !next_text.starts_with(char::is_whitespace);
+
+                if !is_char(next_text) || *next_index != *index + len16(text) || word_start {
+                    return false;
+                }
+
+                text.push_str(next_text);
+                true
+            }
+            (
+                TextEdit::Delete { index, text },
+                TextEdit::Delete {
+                    index: next_index,
+                    text: next_text,
+                },
+            ) => {
+                if !is_char(next_text) {
+                    return false;
+                }
+
+                if *next_index + len16(next_text) == *index {
+                    // backspace
+                    text.insert_str(0, next_text);
+                    *index = *next_index;
+                    true
+                } else if *next_index == *index {
+                    // delete
+                    text.push_str(next_text);
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UndoStack;
+
+    fn insert(index: usize, text: &str) -> TextEdit {
+        TextEdit::Insert {
+            index,
+            text: text.to_string(),
+        }
+    }
+
+    fn delete(index: usize, text: &str) -> TextEdit {
+        TextEdit::Delete {
+            index,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_revert() {
+        let mut text = String16::from("Hello");
+
+        let replace = TextEdit::Replace {
+            index: 1,
+            old: "ell".to_string(),
+            new: "äää".to_string(),
+        };
+        replace.apply(&mut text);
+        assert_eq!(text.as_string(), "Häääo");
+        assert_eq!(replace.caret_after_apply(), 4);
+        replace.revert(&mut text);
+        assert_eq!(text.as_string(), "Hello");
+
+        let delete = delete(0, "He");
+        delete.apply(&mut text);
+        assert_eq!(text.as_string(), "llo");
+        delete.revert(&mut text);
+        assert_eq!(text.as_string(), "Hello");
+        assert_eq!(delete.caret_after_revert(), 2);
+    }
+
+    #[test]
+    fn test_merge_insert() {
+        let mut edit = insert(0, "a");
+        assert!(edit.merge(&insert(1, "b")));
+        assert!(edit.merge(&insert(2, " ")));
+        assert!(!edit.merge(&insert(3, "c")));
+        assert!(!edit.merge(&insert(0, "c")));
+        assert!(!edit.merge(&insert(3, " paste")));
+        assert_eq!(edit, insert(0, "ab "));
+    }
+
+    #[test]
+    fn test_merge_delete() {
+        let mut backspace = delete(4, "d");
+        assert!(backspace.merge(&delete(3, "c")));
+        assert!(!backspace.merge(&delete(0, "ab")));
+        assert_eq!(backspace, delete(3, "cd"));
+
+        let mut forward = delete(1, "b");
+        assert!(forward.merge(&delete(1, "c")));
+        assert_eq!(forward, delete(1, "bc"));
+        assert!(!forward.merge(&insert(1, "b")));
+    }
+
+    #[test]
+    fn test_undo_typing() {
+        let mut text = String16::new();
+        let mut undo_stack = UndoStack::new();
+
+        for (index, ch) in "ab cd".chars().enumerate() {
+            let edit = insert(index, &ch.to_string());
+            edit.apply(&mut text);
+            undo_stack.push(edit);
+        }
+
+        assert_eq!(
+            undo_stack.undo(&mut text).map(|e| e.caret_after_revert()),
+            Some(3)
+        );
+        assert_eq!(text.as_string(), "ab ");
+        undo_stack.undo(&mut text);
+        assert!(text.is_empty());
+        undo_stack.redo(&mut text);
+        assert_eq!(text.as_string(), "ab ");
+    }
+}
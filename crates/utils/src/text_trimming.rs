@@ -0,0 +1,116 @@
+/// The text that replaces the elided part of a trimmed text.
+pub const ELLIPSIS: &str = "...";
+
+/// Describes how a text is trimmed if it does not fit into the available width.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextTrimming {
+    /// The text is not trimmed.
+    None,
+
+    /// The end of the text is replaced by an ellipsis (`very long te...`).
+    End,
+
+    /// The middle of the text is replaced by an ellipsis (`C:\very\...\file.txt`).
+    Middle,
+}
+
+impl Default for TextTrimming {
+    fn default() -> TextTrimming {
+        TextTrimming::None
+    }
+}
+
+impl TextTrimming {
+    /// Trims the given `text` until its width fits into `max_width`. The width of a text
+    /// is calculated by the given `measure` function.
+    pub fn trim<F: FnMut(&str) -> f64>(self, text: &str, max_width: f64, mut measure: F) -> String {
+        if self == TextTrimming::None || max_width <= 0.0 || measure(text) <= max_width {
+            return text.to_string();
+        }
+
+        let chars: Vec<char> = text.chars().collect();
+        let mut keep = chars.len();
+
+        while keep > 0 {
+            keep -= 1;
+            let trimmed = self.elide(&chars, keep);
+
+            if measure(trimmed.as_str()) <= max_width {
+                return trimmed;
+            }
+        }
+
+        ELLIPSIS.to_string()
+    }
+
+    // Keeps `keep` characters of the given text and replaces the rest by an ellipsis.
+    fn elide(self, chars: &[char], keep: usize) -> String {
+        let (head, tail) = match self {
+            TextTrimming::Middle => {
+                let tail = keep / 2;
+                (keep - tail, tail)
+            }
+            _ => (keep, 0),
+        };
+
+        let mut trimmed: String = chars[..head].iter().collect();
+        trimmed.push_str(ELLIPSIS);
+        trimmed.extend(chars[chars.len() - tail..].iter());
+        trimmed
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for TextTrimming {
+    fn from(t: &str) -> Self {
+        match t {
+            "End" | "end" => TextTrimming::End,
+            "Middle" | "middle" => TextTrimming::Middle,
+            _ => TextTrimming::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measure(text: &str) -> f64 {
+        text.chars().count() as f64
+    }
+
+    #[test]
+    fn test_trim() {
+        let path = "C:\\very\\long\\path\\file.txt";
+
+        assert_eq!(TextTrimming::None.trim(path, 10.0, measure), path);
+        assert_eq!(TextTrimming::Middle.trim(path, 100.0, measure), path);
+        assert_eq!(TextTrimming::Middle.trim(path, 0.0, measure), path);
+
+        assert_eq!(
+            TextTrimming::Middle.trim(path, 19.0, measure),
+            "C:\\very\\...file.txt"
+        );
+        assert_eq!(TextTrimming::End.trim(path, 10.0, measure), "C:\\very...");
+        assert_eq!(TextTrimming::Middle.trim(path, 2.0, measure), "...");
+    }
+
+    #[test]
+    fn test_into() {
+        let text_trimming: TextTrimming = "End".into();
+        assert_eq!(text_trimming, TextTrimming::End);
+
+        let text_trimming: TextTrimming = "end".into();
+        assert_eq!(text_trimming, TextTrimming::End);
+
+        let text_trimming: TextTrimming = "Middle".into();
+        assert_eq!(text_trimming, TextTrimming::Middle);
+
+        let text_trimming: TextTrimming = "middle".into();
+        assert_eq!(text_trimming, TextTrimming::Middle);
+
+        let text_trimming: TextTrimming = "other".into();
+        assert_eq!(text_trimming, TextTrimming::None);
+    }
+}
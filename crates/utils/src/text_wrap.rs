@@ -0,0 +1,124 @@
+/// Describes if a text is wrapped into multiple lines if it does not fit into the available width.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextWrap {
+    /// The text is displayed in a single line.
+    NoWrap,
+
+    /// The text is wrapped at word boundaries. Words that are wider than the available width are
+    /// broken.
+    Wrap,
+}
+
+impl Default for TextWrap {
+    fn default() -> TextWrap {
+        TextWrap::NoWrap
+    }
+}
+
+impl TextWrap {
+    /// Splits the given `text` into lines that fit into `max_width`. The width of a text
+    /// is calculated by the given `measure` function.
+    pub fn wrap<F: FnMut(&str) -> f64>(
+        self,
+        text: &str,
+        max_width: f64,
+        mut measure: F,
+    ) -> Vec<String> {
+        if self == TextWrap::NoWrap || max_width <= 0.0 {
+            return vec![text.to_string()];
+        }
+
+        let mut lines = vec![];
+
+        for paragraph in text.split('\n') {
+            let mut line = String::new();
+
+            for word in paragraph.split(' ') {
+                let candidate = if line.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{} {}", line, word)
+                };
+
+                if measure(candidate.as_str()) <= max_width {
+                    line = candidate;
+                    continue;
+                }
+
+                if !line.is_empty() {
+                    lines.push(line);
+                }
+
+                line = String::new();
+
+                // breaks words that doesn't fit into a line
+                for c in word.chars() {
+                    line.push(c);
+
+                    if measure(line.as_str()) > max_width && line.chars().count() > 1 {
+                        line.pop();
+                        lines.push(line);
+                        line = c.to_string();
+                    }
+                }
+            }
+
+            lines.push(line);
+        }
+
+        lines
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for TextWrap {
+    fn from(t: &str) -> Self {
+        match t {
+            "Wrap" | "wrap" => TextWrap::Wrap,
+            _ => TextWrap::NoWrap,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measure(text: &str) -> f64 {
+        text.chars().count() as f64
+    }
+
+    #[test]
+    fn test_wrap() {
+        let text = "The quick brown fox";
+
+        assert_eq!(TextWrap::NoWrap.wrap(text, 5.0, measure), vec![text]);
+        assert_eq!(TextWrap::Wrap.wrap(text, 0.0, measure), vec![text]);
+        assert_eq!(TextWrap::Wrap.wrap(text, 100.0, measure), vec![text]);
+        assert_eq!(
+            TextWrap::Wrap.wrap(text, 10.0, measure),
+            vec!["The quick", "brown fox"]
+        );
+        assert_eq!(
+            TextWrap::Wrap.wrap("a verylongword b", 4.0, measure),
+            vec!["a", "very", "long", "word", "b"]
+        );
+        assert_eq!(
+            TextWrap::Wrap.wrap("line one\nline two", 100.0, measure),
+            vec!["line one", "line two"]
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let text_wrap: TextWrap = "Wrap".into();
+        assert_eq!(text_wrap, TextWrap::Wrap);
+
+        let text_wrap: TextWrap = "wrap".into();
+        assert_eq!(text_wrap, TextWrap::Wrap);
+
+        let text_wrap: TextWrap = "other".into();
+        assert_eq!(text_wrap, TextWrap::NoWrap);
+    }
+}
@@ -0,0 +1,197 @@
+/// Default number of undo steps of an `UndoStack`.
+pub const DEFAULT_UNDO_LIMIT: usize = 100;
+
+/// An `Edit` is a reversible change of a target, e.g. an insert into a text. Custom editors
+/// implement it for their own operations to use them with an `UndoStack`.
+pub trait Edit {
+    /// The type of the edited value.
+    type Target;
+
+    /// Applies the edit to the target.
+    fn apply(&self, target: &mut Self::Target);
+
+    /// Reverts the edit that was applied to the target.
+    fn revert(&self, target: &mut Self::Target);
+
+    /// Merges the given edit that follows this edit into this edit and returns `true` if it is
+    /// merged. Used to undo e.g. typed characters as one step, by default edits are not merged.
+    fn merge(&mut self, _next: &Self) -> bool {
+        false
+    }
+}
+
+/// The `UndoStack` records the applied edits of an editor, so they could be undone and redone.
+///
+/// Edits that are pushed after each other are merged if the edit allows it, `seal` starts a new
+/// undo step, e.g. after the caret is moved. Pushing an edit clears the edits that could be
+/// redone.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UndoStack<E> {
+    undo: Vec<E>,
+    redo: Vec<E>,
+    limit: usize,
+    sealed: bool,
+}
+
+impl<E> Default for UndoStack<E> {
+    fn default() -> Self {
+        UndoStack::with_limit(DEFAULT_UNDO_LIMIT)
+    }
+}
+
+impl<E> UndoStack<E> {
+    /// Creates an undo stack with the default limit of undo steps.
+    pub fn new() -> Self {
+        UndoStack::default()
+    }
+
+    /// Creates an undo stack that keeps the given number of undo steps, older steps are dropped.
+    pub fn with_limit(limit: usize) -> Self {
+        UndoStack {
+            undo: vec![],
+            redo: vec![],
+            limit,
+            sealed: false,
+        }
+    }
+
+    /// Returns `true` if there is an edit that could be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an edit that could be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Starts a new undo step, the next pushed edit is not merged with the last one.
+    pub fn seal(&mut self) {
+        self.sealed = true;
+    }
+
+    /// Removes all edits.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.sealed = false;
+    }
+}
+
+impl<E: Edit + Clone> UndoStack<E> {
+    /// Records an edit that is already applied to the target.
+    pub fn push(&mut self, edit: E) {
+        self.redo.clear();
+
+        if !self.sealed {
+            if let Some(last) = self.undo.last_mut() {
+                if last.merge(&edit) {
+                    return;
+                }
+            }
+        }
+
+        self.undo.push(edit);
+        self.sealed = false;
+
+        if self.undo.len() > self.limit {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Reverts the last edit on the given target and returns it.
+    pub fn undo(&mut self, target: &mut E::Target) -> Option<E> {
+        let edit = self.undo.pop()?;
+        edit.revert(target);
+        self.redo.push(edit.clone());
+        self.sealed = true;
+
+        Some(edit)
+    }
+
+    /// Applies the last undone edit again on the given target and returns it.
+    pub fn redo(&mut self, target: &mut E::Target) -> Option<E> {
+        let edit = self.redo.pop()?;
+        edit.apply(target);
+        self.undo.push(edit.clone());
+        self.sealed = true;
+
+        Some(edit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Adds a value to a number, values with the same sign are merged.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Add(i32);
+
+    impl Edit for Add {
+        type Target = i32;
+
+        fn apply(&self, target: &mut i32) {
+            *target += self.0;
+        }
+
+        fn revert(&self, target: &mut i32) {
+            *target -= self.0;
+        }
+
+        fn merge(&mut self, next: &Self) -> bool {
+            if self.0.signum() != next.0.signum() {
+                return false;
+            }
+
+            self.0 += next.0;
+            true
+        }
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut value = 0;
+        let mut undo_stack = UndoStack::new();
+        assert!(!undo_stack.can_undo());
+
+        for edit in &[Add(1), Add(2), Add(-4)] {
+            edit.apply(&mut value);
+            undo_stack.push(edit.clone());
+        }
+
+        assert_eq!(value, -1);
+        assert_eq!(undo_stack.undo(&mut value), Some(Add(-4)));
+        assert_eq!(value, 3);
+        assert_eq!(undo_stack.undo(&mut value), Some(Add(3)));
+        assert_eq!(value, 0);
+        assert_eq!(undo_stack.undo(&mut value), None);
+
+        assert_eq!(undo_stack.redo(&mut value), Some(Add(3)));
+        assert_eq!(value, 3);
+        assert!(undo_stack.can_redo());
+
+        // a new edit clears the redo edits and is not merged with the redone edit
+        Add(1).apply(&mut value);
+        undo_stack.push(Add(1));
+        assert!(!undo_stack.can_redo());
+        assert_eq!(undo_stack.undo(&mut value), Some(Add(1)));
+        assert_eq!(value, 3);
+    }
+
+    #[test]
+    fn test_seal_and_limit() {
+        let mut undo_stack = UndoStack::with_limit(2);
+        undo_stack.push(Add(1));
+        undo_stack.seal();
+        undo_stack.push(Add(2));
+        undo_stack.seal();
+        undo_stack.push(Add(3));
+
+        let mut value = 6;
+        assert_eq!(undo_stack.undo(&mut value), Some(Add(3)));
+        assert_eq!(undo_stack.undo(&mut value), Some(Add(2)));
+        assert_eq!(undo_stack.undo(&mut value), None);
+        assert_eq!(value, 1);
+    }
+}
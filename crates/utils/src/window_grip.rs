@@ -0,0 +1,192 @@
+/// Describes how dragging a `WindowHandle` changes the window, e.g. a custom title bar moves the
+/// window and a corner resizes it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WindowGrip {
+    /// Moves the window, e.g. a title bar.
+    Move,
+
+    /// Resizes the window at its left edge.
+    Left,
+
+    /// Resizes the window at its top edge.
+    Top,
+
+    /// Resizes the window at its right edge.
+    Right,
+
+    /// Resizes the window at its bottom edge.
+    Bottom,
+
+    /// Resizes the window at its top left corner.
+    TopLeft,
+
+    /// Resizes the window at its top right corner.
+    TopRight,
+
+    /// Resizes the window at its bottom left corner.
+    BottomLeft,
+
+    /// Resizes the window at its bottom right corner.
+    BottomRight,
+}
+
+/// The change of the window after a `WindowGrip` is dragged.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GripDrag {
+    /// Offset the window is moved by.
+    pub offset: (f64, f64),
+
+    /// New size of the window.
+    pub size: (f64, f64),
+
+    /// Position of the pointer in window coordinates that the next drag is measured from.
+    pub anchor: (f64, f64),
+}
+
+// How a grip changes one axis of the window.
+#[derive(Copy, Clone, PartialEq)]
+enum GripAxis {
+    Fixed,
+    Move,
+    Start,
+    End,
+}
+
+// Returns the offset, size and anchor of one axis. A moved window keeps the anchor under the
+// pointer, a moved end edge takes the anchor with it.
+fn drag_axis(axis: GripAxis, anchor: f64, pointer: f64, size: f64, min_size: f64) -> [f64; 3] {
+    let delta = pointer - anchor;
+
+    match axis {
+        GripAxis::Fixed => [0.0, size, anchor],
+        GripAxis::Move => [delta, size, anchor],
+        GripAxis::Start => {
+            let delta = delta.min(size - min_size);
+            [delta, size - delta, anchor]
+        }
+        GripAxis::End => {
+            let new_size = (size + delta).max(min_size);
+            [0.0, new_size, anchor + new_size - size]
+        }
+    }
+}
+
+impl WindowGrip {
+    fn axes(self) -> (GripAxis, GripAxis) {
+        match self {
+            WindowGrip::Move => (GripAxis::Move, GripAxis::Move),
+            WindowGrip::Left => (GripAxis::Start, GripAxis::Fixed),
+            WindowGrip::Top => (GripAxis::Fixed, GripAxis::Start),
+            WindowGrip::Right => (GripAxis::End, GripAxis::Fixed),
+            WindowGrip::Bottom => (GripAxis::Fixed, GripAxis::End),
+            WindowGrip::TopLeft => (GripAxis::Start, GripAxis::Start),
+            WindowGrip::TopRight => (GripAxis::End, GripAxis::Start),
+            WindowGrip::BottomLeft => (GripAxis::Start, GripAxis::End),
+            WindowGrip::BottomRight => (GripAxis::End, GripAxis::End),
+        }
+    }
+
+    /// Returns `true` if the grip resizes the window.
+    pub fn is_resize(self) -> bool {
+        self != WindowGrip::Move
+    }
+
+    /// Returns how the window changes if the pointer is dragged from the anchor to the given
+    /// position, both in window coordinates. The size is kept at least at the minimum size.
+    pub fn drag(
+        self,
+        anchor: (f64, f64),
+        pointer: (f64, f64),
+        size: (f64, f64),
+        min_size: (f64, f64),
+    ) -> GripDrag {
+        let (axis_x, axis_y) = self.axes();
+        let [offset_x, width, anchor_x] =
+            drag_axis(axis_x, anchor.0, pointer.0, size.0, min_size.0);
+        let [offset_y, height, anchor_y] =
+            drag_axis(axis_y, anchor.1, pointer.1, size.1, min_size.1);
+
+        GripDrag {
+            offset: (offset_x, offset_y),
+            size: (width, height),
+            anchor: (anchor_x, anchor_y),
+        }
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for WindowGrip {
+    fn from(t: &str) -> Self {
+        match t {
+            "Left" | "left" => WindowGrip::Left,
+            "Top" | "top" => WindowGrip::Top,
+            "Right" | "right" => WindowGrip::Right,
+            "Bottom" | "bottom" => WindowGrip::Bottom,
+            "TopLeft" | "top-left" => WindowGrip::TopLeft,
+            "TopRight" | "top-right" => WindowGrip::TopRight,
+            "BottomLeft" | "bottom-left" => WindowGrip::BottomLeft,
+            "BottomRight" | "bottom-right" => WindowGrip::BottomRight,
+            _ => WindowGrip::Move,
+        }
+    }
+}
+
+impl Default for WindowGrip {
+    fn default() -> WindowGrip {
+        WindowGrip::Move
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let grip: WindowGrip = "bottom-right".into();
+        assert_eq!(grip, WindowGrip::BottomRight);
+
+        let grip: WindowGrip = "Left".into();
+        assert_eq!(grip, WindowGrip::Left);
+
+        let grip: WindowGrip = "other".into();
+        assert_eq!(grip, WindowGrip::Move);
+    }
+
+    #[test]
+    fn test_drag() {
+        let size = (400.0, 300.0);
+        let min_size = (100.0, 100.0);
+
+        // the moved window keeps the anchor under the pointer
+        assert_eq!(
+            WindowGrip::Move.drag((10.0, 10.0), (15.0, 8.0), size, min_size),
+            GripDrag {
+                offset: (5.0, -2.0),
+                size,
+                anchor: (10.0, 10.0),
+            }
+        );
+
+        assert_eq!(
+            WindowGrip::BottomRight.drag((398.0, 298.0), (408.0, 288.0), size, min_size),
+            GripDrag {
+                offset: (0.0, 0.0),
+                size: (410.0, 290.0),
+                anchor: (408.0, 288.0),
+            }
+        );
+
+        // the left edge stops at the minimum width
+        assert_eq!(
+            WindowGrip::TopLeft.drag((2.0, 2.0), (352.0, -8.0), size, min_size),
+            GripDrag {
+                offset: (300.0, -10.0),
+                size: (100.0, 310.0),
+                anchor: (2.0, 2.0),
+            }
+        );
+        assert!(!WindowGrip::Move.is_resize());
+    }
+}
@@ -0,0 +1,425 @@
+use std::{cell::Cell, rc::Rc};
+
+use crate::{prelude::*, shell::Key};
+
+// Height of a suggestion in the popup.
+const SUGGESTION_HEIGHT: f64 = 24.0;
+
+// Returns the suggestions that contain the text, ignoring the case. Suggestions that start with
+// the text come first, a suggestion equal to the text is left out, because there is nothing to
+// complete. An empty text has no suggestions.
+fn filter_suggestions(suggestions: &[String], text: &str, max_suggestions: usize) -> Vec<String> {
+    if text.is_empty() {
+        return vec![];
+    }
+
+    let text = text.to_lowercase();
+    let mut prefix_matches = vec![];
+    let mut matches = vec![];
+
+    for suggestion in suggestions {
+        let lower = suggestion.to_lowercase();
+
+        if lower == text {
+            continue;
+        }
+
+        if lower.starts_with(text.as_str()) {
+            prefix_matches.push(suggestion.clone());
+        } else if lower.contains(text.as_str()) {
+            matches.push(suggestion.clone());
+        }
+    }
+
+    prefix_matches
+        .into_iter()
+        .chain(matches)
+        .take(max_suggestions)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum AutoCompleteAction {
+    Open,
+    Close,
+    Commit,
+    Select(Option<usize>),
+}
+
+/// The `AutoCompleteState` filters the suggestions by the text of the text box and commits the
+/// chosen suggestion.
+#[derive(Default, AsAny)]
+pub struct AutoCompleteState {
+    actions: RefCell<Vec<AutoCompleteAction>>,
+    // suggestions that are shown in the popup, shared with the items builder of the list.
+    matches: Rc<RefCell<Vec<String>>>,
+    text: String16,
+    input: Option<Entity>,
+    popup: Option<Entity>,
+    list: Option<Entity>,
+    open: Cell<bool>,
+    selected: Cell<Option<usize>>,
+}
+
+impl AutoCompleteState {
+    fn action(&self, action: AutoCompleteAction) {
+        self.actions.borrow_mut().push(action);
+    }
+
+    fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    fn has_selection(&self) -> bool {
+        self.open.get() && self.selected.get().is_some()
+    }
+
+    fn is_input_focused(&self, ctx: &mut Context<'_>) -> bool {
+        self.input.is_some() && ctx.window().get::<Global>("global").focused_widget == self.input
+    }
+
+    // Shows the suggestions that match the text in the popup below the text box or closes the
+    // popup if there are none.
+    fn filter(&mut self, ctx: &mut Context<'_>) {
+        let (popup, list) = match (self.popup, self.list) {
+            (Some(popup), Some(list)) => (popup, list),
+            _ => return,
+        };
+
+        let max_suggestions = *ctx.widget().get::<usize>("max_suggestions");
+        let matches = filter_suggestions(
+            ctx.widget().get::<Vec<String>>("suggestions"),
+            self.text.as_string().as_str(),
+            max_suggestions,
+        );
+        let count = matches.len();
+        *self.matches.borrow_mut() = matches;
+        self.selected.set(None);
+
+        {
+            let mut list = ctx.get_widget(list);
+            list.set("count", count);
+            list.set("request_update", true);
+        }
+
+        if count == 0 {
+            self.close_popup(ctx);
+            return;
+        }
+
+        let (position, bounds) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Point>("position"),
+                *widget.get::<Rectangle>("bounds"),
+            )
+        };
+        let frame = {
+            let list = ctx.get_widget(list);
+            let padding = *list.get::<Thickness>("padding");
+            let border_width = *list.get::<Thickness>("border_width");
+
+            padding.top() + padding.bottom() + border_width.top() + border_width.bottom()
+        };
+
+        let mut popup = ctx.get_widget(popup);
+        popup
+            .get_mut::<Constraint>("constraint")
+            .set_size(bounds.width(), count as f64 * SUGGESTION_HEIGHT + frame);
+        popup.set(
+            "margin",
+            Thickness::new(position.x, position.y + bounds.height(), 0.0, 0.0),
+        );
+        popup.set("visibility", Visibility::Visible);
+        self.open.set(true);
+    }
+
+    fn close_popup(&self, ctx: &mut Context<'_>) {
+        if let Some(popup) = self.popup {
+            ctx.get_widget(popup)
+                .set("visibility", Visibility::Collapsed);
+        }
+
+        self.open.set(false);
+        self.selected.set(None);
+    }
+
+    // Writes the selected suggestion into the text and moves the caret behind it.
+    fn commit(&mut self, ctx: &mut Context<'_>) {
+        let suggestion = match self.selected.get() {
+            Some(index) => self.matches.borrow().get(index).cloned(),
+            None => None,
+        };
+
+        self.close_popup(ctx);
+
+        let suggestion = match suggestion {
+            Some(suggestion) => String16::from(suggestion),
+            None => return,
+        };
+
+        if let Some(input) = self.input {
+            ctx.get_widget(input)
+                .set("text_selection", TextSelection::from((suggestion.len(), 0)));
+        }
+
+        self.text = suggestion.clone();
+        ctx.widget().set("text", suggestion);
+        self.focus_input(ctx);
+        ctx.push_event_strategy_by_entity(
+            ChangedEvent(ctx.entity),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    // Gives the focus back to the text box, e.g. after a suggestion is clicked.
+    fn focus_input(&self, ctx: &mut Context<'_>) {
+        let input = match self.input {
+            Some(input) => input,
+            None => return,
+        };
+
+        let focused_widget = ctx.window().get::<Global>("global").focused_widget;
+
+        if focused_widget == Some(input) {
+            return;
+        }
+
+        if let Some(old_focused_element) = focused_widget {
+            let mut old_focused_element = ctx.get_widget(old_focused_element);
+            old_focused_element.set("focused", false);
+            old_focused_element.update_theme_by_state(false);
+        }
+
+        ctx.window().get_mut::<Global>("global").focused_widget = Some(input);
+
+        let mut input = ctx.get_widget(input);
+        input.set("focused", true);
+        input.update_theme_by_state(false);
+    }
+}
+
+impl State for AutoCompleteState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.text = ctx.widget().clone::<String16>("text");
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let actions: Vec<AutoCompleteAction> = self.actions.borrow_mut().drain(..).collect();
+
+        for action in actions {
+            match action {
+                AutoCompleteAction::Open => self.filter(ctx),
+                AutoCompleteAction::Close => self.close_popup(ctx),
+                AutoCompleteAction::Commit => self.commit(ctx),
+                AutoCompleteAction::Select(index) => {
+                    self.selected.set(index);
+
+                    // a suggestion that is clicked moves the focus to the list
+                    if index.is_some() && !self.is_input_focused(ctx) {
+                        self.commit(ctx);
+                    }
+                }
+            }
+        }
+
+        // the suggestions are only shown while the user types, not if the text is set from outside
+        let text = ctx.widget().clone::<String16>("text");
+
+        if text != self.text {
+            self.text = text;
+
+            if self.is_input_focused(ctx) {
+                self.filter(ctx);
+            }
+        }
+
+        if self.open.get() {
+            let focused_widget = ctx.window().get::<Global>("global").focused_widget;
+
+            if focused_widget != self.input && focused_widget != self.list {
+                self.close_popup(ctx);
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `AutoComplete` widget is a text box that shows the `suggestions` that contain the
+    /// typed text in a popup below the text box.
+    ///
+    /// The arrow keys up and down select a suggestion, `Enter` writes it into the text and
+    /// `Escape` closes the popup. A click on a suggestion writes it into the text, too. Each
+    /// committed suggestion raises a changed event.
+    ///
+    /// **CSS element:** `auto-complete`
+    AutoComplete<AutoCompleteState>: ChangedHandler {
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the suggestions that are filtered by the text.
+        suggestions: Vec<String>,
+
+        /// Sets or shares the maximum number of suggestions that are shown in the popup.
+        max_suggestions: usize,
+
+        /// Sets or shares the water_mark text property.
+        water_mark: String16,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for AutoComplete {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let matches = self.state_mut().matches.clone();
+
+        let list = ListView::create()
+            .items_builder(move |bc, index| {
+                let text = matches.borrow().get(index).cloned().unwrap_or_default();
+
+                TextBlock::create()
+                    .vertical_alignment("center")
+                    .text(text)
+                    .build(bc)
+            })
+            .on_selection_changed(move |states, indices| {
+                states
+                    .get::<AutoCompleteState>(id)
+                    .action(AutoCompleteAction::Select(indices.first().copied()));
+            })
+            .build(ctx);
+
+        let popup = Container::create()
+            .selector("auto-complete-popup")
+            .horizontal_alignment("start")
+            .vertical_alignment("start")
+            .visibility("collapsed")
+            .child(list)
+            .build(ctx);
+
+        if ctx.append_child_to_overlay(popup).is_err() {
+            ctx.append_child(id, popup);
+        }
+
+        let input = TextBox::create()
+            .selector(Selector::from("text-box").id("input"))
+            .min_width(0.0)
+            .lost_focus_on_activation(false)
+            .text(id)
+            .water_mark(id)
+            .foreground(id)
+            .font_size(id)
+            .font(id)
+            .background(id)
+            .border_radius(id)
+            .border_width(id)
+            .border_brush(id)
+            .padding(id)
+            // handled before the keys of the text box, so enter commits the suggestion
+            .on_key_down(move |states, event| {
+                let state = states.get::<AutoCompleteState>(id);
+
+                match event.key {
+                    Key::Up | Key::Down if state.is_open() => {
+                        states.get::<ListViewState>(list).key(event.key);
+                        true
+                    }
+                    Key::Down => {
+                        state.action(AutoCompleteAction::Open);
+                        true
+                    }
+                    Key::Enter if state.has_selection() => {
+                        state.action(AutoCompleteAction::Commit);
+                        true
+                    }
+                    Key::Escape if state.is_open() => {
+                        state.action(AutoCompleteAction::Close);
+                        true
+                    }
+                    _ => false,
+                }
+            })
+            .build(ctx);
+
+        self.state_mut().input = Some(input);
+        self.state_mut().popup = Some(popup);
+        self.state_mut().list = Some(list);
+
+        self.name("AutoComplete")
+            .selector("auto-complete")
+            .text("")
+            .suggestions(vec![])
+            .max_suggestions(8)
+            .water_mark("")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto Regular")
+            .background(colors::LYNCH_COLOR)
+            .border_brush("transparent")
+            .border_width(0.0)
+            .border_radius(2.0)
+            .padding(4.0)
+            .min_width(128.0)
+            .height(32.0)
+            .child(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_suggestions() {
+        let suggestions: Vec<String> = vec!["Banana", "Apple", "Pineapple", "apricot", "Cherry"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        // prefix matches first, the case is ignored
+        assert_eq!(
+            filter_suggestions(&suggestions, "ap", 8),
+            vec!["Apple", "apricot", "Pineapple"]
+        );
+        assert_eq!(
+            filter_suggestions(&suggestions, "ap", 2),
+            vec!["Apple", "apricot"]
+        );
+
+        // the text itself is not suggested
+        assert_eq!(
+            filter_suggestions(&suggestions, "apple", 8),
+            vec!["Pineapple"]
+        );
+
+        assert!(filter_suggestions(&suggestions, "", 8).is_empty());
+        assert!(filter_suggestions(&suggestions, "kiwi", 8).is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::prelude::*;
+
+// Returns the text of the bubble for the given count, counts above the max count are shown as
+// `max+`. A count of `0` has no text, so the bubble is hidden. A max count of `0` shows all counts.
+fn badge_text(count: usize, max_count: usize) -> String {
+    if count == 0 {
+        String::new()
+    } else if max_count > 0 && count > max_count {
+        format!("{}+", max_count)
+    } else {
+        count.to_string()
+    }
+}
+
+/// The `BadgeState` shows the count in the bubble and hides the bubble if the count is `0`.
+#[derive(Default, AsAny)]
+pub struct BadgeState {
+    bubble: Option<Entity>,
+    label: Option<Entity>,
+    text: Option<String>,
+}
+
+impl State for BadgeState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let (bubble, label) = match (self.bubble, self.label) {
+            (Some(bubble), Some(label)) => (bubble, label),
+            _ => return,
+        };
+
+        let text = badge_text(
+            *ctx.widget().get::<usize>("count"),
+            *ctx.widget().get::<usize>("max_count"),
+        );
+
+        if self.text.as_ref() == Some(&text) {
+            return;
+        }
+
+        let visibility = if text.is_empty() {
+            Visibility::Collapsed
+        } else {
+            Visibility::Visible
+        };
+
+        ctx.get_widget(label)
+            .set("text", String16::from(text.as_str()));
+        ctx.get_widget(bubble).set("visibility", visibility);
+        self.text = Some(text);
+    }
+}
+
+widget!(
+    /// The `Badge` widget attaches a small bubble with a count to the top right corner of its
+    /// child, e.g. the number of unread messages of a button. The bubble is hidden if the
+    /// `count` is `0`, counts above the `max_count` are shown as `max_count+`, e.g. `99+`.
+    ///
+    /// **CSS element:** `badge`
+    Badge<BadgeState> {
+        /// Sets or shares the count that is shown in the bubble.
+        count: usize,
+
+        /// Sets or shares the highest count that is shown as number, `0` shows all counts.
+        max_count: usize,
+
+        /// Sets or shares the background of the bubble.
+        background: Brush,
+
+        /// Sets or shares the border radius of the bubble.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding of the bubble.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for Badge {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let label = TextBlock::create()
+            .horizontal_alignment("center")
+            .vertical_alignment("center")
+            .foreground(id)
+            .font(id)
+            .font_size(id)
+            .build(ctx);
+
+        // the bubble overlaps the corner of the child and is drawn on top of it
+        let bubble = Container::create()
+            .horizontal_alignment("end")
+            .vertical_alignment("start")
+            .margin((0.0, -8.0, -8.0, 0.0))
+            .min_width(16.0)
+            .height(16.0)
+            .visibility("collapsed")
+            .background(id)
+            .border_radius(id)
+            .padding(id)
+            .child(label)
+            .build(ctx);
+
+        self.state_mut().bubble = Some(bubble);
+        self.state_mut().label = Some(label);
+
+        self.name("Badge")
+            .selector("badge")
+            .count(0)
+            .max_count(99)
+            .background("#ef5350")
+            .border_radius(8.0)
+            .foreground("#ffffff")
+            .font_size(10.0)
+            .font("Roboto Regular")
+            .padding((4.0, 0.0, 4.0, 0.0))
+            .child(bubble)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge_text() {
+        assert_eq!(badge_text(0, 99), "");
+        assert_eq!(badge_text(7, 99), "7");
+        assert_eq!(badge_text(99, 99), "99");
+        assert_eq!(badge_text(100, 99), "99+");
+        assert_eq!(badge_text(100, 0), "100");
+    }
+}
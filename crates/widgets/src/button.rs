@@ -1,16 +1,36 @@
+use std::cell::Cell;
+
 use super::behaviors::MouseBehavior;
 use crate::prelude::*;
 
+// The `ButtonState` executes the command of the button.
+#[derive(Default, AsAny)]
+struct ButtonState {
+    clicked: Cell<bool>,
+}
+
+impl State for ButtonState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let clicked = self.clicked.replace(false);
+
+        if let Some(command) = ctx.widget().try_clone::<Command>("command") {
+            if command.update_enabled(ctx) && clicked {
+                command.execute(ctx);
+            }
+        }
+    }
+}
+
 widget!(
     /// The `Button` widget can be clicked by user. It's used to perform an action.
     /// 
     /// **CSS element:** `button`
-    Button: MouseHandler {
+    Button<ButtonState>: MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -49,13 +69,40 @@ widget!(
         selector: Selector,
 
         /// Sets or shares the pressed property. 
-        pressed: bool
+        pressed: bool,
+
+        /// Sets or shares the command that is executed if the button is clicked. The button is
+        /// disabled while the command could not be executed.
+        command: Command
     }
 );
 
+impl Button {
+    /// Sets the glyph, font, size and brush of the icon at once.
+    pub fn font_icon(self, icon: impl Into<Icon>) -> Self {
+        let icon = icon.into();
+        let mut this = self.icon(icon.glyph);
+
+        if let Some(font) = icon.font {
+            this = this.icon_font(font);
+        }
+
+        if let Some(size) = icon.size {
+            this = this.icon_size(size);
+        }
+
+        if let Some(brush) = icon.brush {
+            this = this.icon_brush(brush);
+        }
+
+        this
+    }
+}
+
 impl Template for Button {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("Button")
+            .accessible_role("button")
             .selector("button")
             .height(32.0)
             .min_width(80.0)
@@ -73,6 +120,10 @@ impl Template for Button {
             .icon_size(fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
+            .on_click(move |states, _| {
+                states.get::<ButtonState>(id).clicked.set(true);
+                false
+            })
             .child(
                 MouseBehavior::create()
                     .pressed(id)
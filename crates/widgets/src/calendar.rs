@@ -0,0 +1,312 @@
+use std::cell::Cell;
+
+use crate::prelude::*;
+
+// Size of a day cell.
+const DAY_SIZE: f64 = 32.0;
+
+// A month is displayed in six weeks.
+const DAY_COUNT: usize = 42;
+
+static MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+static WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+#[cfg(not(target_arch = "wasm32"))]
+fn today() -> Date {
+    Date::today()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn today() -> Date {
+    Date::default()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum CalendarAction {
+    PreviousMonth,
+    NextMonth,
+    Select(usize),
+}
+
+/// The `CalendarState` fills the day grid of the displayed month and handles the selection.
+#[derive(Default, AsAny)]
+pub struct CalendarState {
+    action: Cell<Option<CalendarAction>>,
+    days: Vec<Entity>,
+    display_date: Date,
+    selected_date: SelectedDate,
+}
+
+impl CalendarState {
+    fn action(&self, action: CalendarAction) {
+        self.action.set(Some(action));
+    }
+
+    // Returns the date of the day cell with the given index.
+    fn date_of(&self, index: usize) -> Date {
+        let first = self.display_date.first_of_month();
+        first.add_days(index as i64 - first.weekday() as i64)
+    }
+
+    fn refresh(&self, ctx: &mut Context<'_>) {
+        ctx.child("title").set(
+            "text",
+            String16::from(format!(
+                "{} {}",
+                MONTH_NAMES[self.display_date.month() as usize - 1],
+                self.display_date.year()
+            )),
+        );
+
+        for (index, day) in self.days.iter().enumerate() {
+            let date = self.date_of(index);
+            let mut day = ctx.get_widget(*day);
+
+            if date.month() != self.display_date.month() {
+                day.set("visibility", Visibility::Hidden);
+                day.set("selected", false);
+                continue;
+            }
+
+            day.set("visibility", Visibility::Visible);
+            day.set("text", String16::from(date.day().to_string()));
+            day.set("selected", self.selected_date.0 == Some(date));
+            day.update_theme_by_state(false);
+        }
+    }
+}
+
+impl State for CalendarState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.selected_date = *ctx.widget().get::<SelectedDate>("selected_date");
+
+        // shows the month of the selected date or of today if no month is set
+        let mut display_date = *ctx.widget().get::<Date>("display_date");
+
+        if display_date == Date::default() {
+            display_date = self.selected_date.0.unwrap_or_else(today);
+        }
+
+        self.display_date = display_date.first_of_month();
+        ctx.widget().set("display_date", self.display_date);
+        self.refresh(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(action) = self.action.take() {
+            match action {
+                CalendarAction::PreviousMonth => {
+                    ctx.widget()
+                        .set("display_date", self.display_date.add_months(-1));
+                }
+                CalendarAction::NextMonth => {
+                    ctx.widget()
+                        .set("display_date", self.display_date.add_months(1));
+                }
+                CalendarAction::Select(index) => {
+                    let date = self.date_of(index);
+                    ctx.widget().set("selected_date", SelectedDate(Some(date)));
+                    ctx.push_event_strategy_by_entity(
+                        ChangedEvent(ctx.entity),
+                        ctx.entity,
+                        EventStrategy::Direct,
+                    );
+                }
+            }
+        }
+
+        // refreshes the day grid if the month or the selection is changed, also from outside
+        let display_date = ctx.widget().get::<Date>("display_date").first_of_month();
+        let selected_date = *ctx.widget().get::<SelectedDate>("selected_date");
+
+        if display_date != self.display_date || selected_date != self.selected_date {
+            self.display_date = display_date;
+            self.selected_date = selected_date;
+            self.refresh(ctx);
+        }
+    }
+}
+
+widget!(
+    /// The `Calendar` widget displays the days of a month in a grid. A day could be selected by
+    /// clicking on it, the buttons of the header navigates to the previous and next month.
+    ///
+    /// **CSS element:** `calendar`
+    Calendar<CalendarState>: ChangedHandler {
+        /// Sets or shares the selected date.
+        selected_date: SelectedDate,
+
+        /// Sets or shares the date of the displayed month.
+        display_date: Date,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for Calendar {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut columns = Columns::create();
+
+        for _ in 0..WEEKDAY_NAMES.len() {
+            columns = columns.column(DAY_SIZE);
+        }
+
+        let mut rows = Rows::create().row("auto");
+
+        for _ in 0..DAY_COUNT / WEEKDAY_NAMES.len() {
+            rows = rows.row(DAY_SIZE);
+        }
+
+        let mut days_grid = Grid::create().columns(columns.build()).rows(rows.build());
+
+        for (column, name) in WEEKDAY_NAMES.iter().enumerate() {
+            days_grid = days_grid.child(
+                TextBlock::create()
+                    .selector("calendar-weekday")
+                    .attach(Grid::column(column))
+                    .horizontal_alignment("center")
+                    .margin((0.0, 0.0, 0.0, 4.0))
+                    .text(*name)
+                    .build(ctx),
+            );
+        }
+
+        let mut days = vec![];
+
+        for index in 0..DAY_COUNT {
+            let day = Button::create()
+                .selector("calendar-day")
+                .attach(Grid::column(index % WEEKDAY_NAMES.len()))
+                .attach(Grid::row(index / WEEKDAY_NAMES.len() + 1))
+                .min_width(0.0)
+                .width(DAY_SIZE)
+                .height(DAY_SIZE)
+                .padding(0.0)
+                .visibility("hidden")
+                .on_click(move |states, _| {
+                    states
+                        .get::<CalendarState>(id)
+                        .action(CalendarAction::Select(index));
+                    true
+                })
+                .build(ctx);
+
+            ctx.register_property("selected", day, false);
+            days_grid = days_grid.child(day);
+            days.push(day);
+        }
+
+        self.state_mut().days = days;
+
+        self.name("Calendar")
+            .accessible_role("group")
+            .selector("calendar")
+            .selected_date(SelectedDate::default())
+            .display_date(Date::default())
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(4.0)
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(
+                        Stack::create()
+                            .child(
+                                Grid::create()
+                                    .margin((0.0, 0.0, 0.0, 4.0))
+                                    .columns(
+                                        Columns::create()
+                                            .column(DAY_SIZE)
+                                            .column("*")
+                                            .column(DAY_SIZE)
+                                            .build(),
+                                    )
+                                    .child(
+                                        Button::create()
+                                            .selector("calendar-navigation")
+                                            .min_width(0.0)
+                                            .width(DAY_SIZE)
+                                            .padding(0.0)
+                                            .icon(
+                                                material_font_icons::KEYBOARD_ARROW_LEFT_FONT_ICON,
+                                            )
+                                            .on_click(move |states, _| {
+                                                states
+                                                    .get::<CalendarState>(id)
+                                                    .action(CalendarAction::PreviousMonth);
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::create()
+                                            .selector(Selector::from("calendar-title").id("title"))
+                                            .attach(Grid::column(1))
+                                            .horizontal_alignment("center")
+                                            .vertical_alignment("center")
+                                            .text("")
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        Button::create()
+                                            .selector("calendar-navigation")
+                                            .attach(Grid::column(2))
+                                            .min_width(0.0)
+                                            .width(DAY_SIZE)
+                                            .padding(0.0)
+                                            .icon(
+                                                material_font_icons::KEYBOARD_ARROW_RIGHT_FONT_ICON,
+                                            )
+                                            .on_click(move |states, _| {
+                                                states
+                                                    .get::<CalendarState>(id)
+                                                    .action(CalendarAction::NextMonth);
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .child(days_grid.build(ctx))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
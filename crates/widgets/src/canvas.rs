@@ -4,7 +4,7 @@ use crate::prelude::*;
 
 widget!(
     /// Canvas is used to render 3D graphics.
-    Canvas {
+    Canvas: GestureHandler, MouseHandler, TouchHandler {
         /// Sets or shares the three render pipeline.
         render_pipeline: RenderPipeline,
 
@@ -0,0 +1,207 @@
+use crate::prelude::*;
+
+// Default brush of the series.
+static SERIES_COLOR: &str = "#efd035";
+
+// The `ChartState` shows the tooltip of the point under the mouse.
+#[derive(Default, AsAny)]
+struct ChartState {
+    kind: ChartKind,
+}
+
+impl State for ChartState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let mouse_position = ctx.mouse_position();
+        let mut widget = ctx.widget();
+
+        let (position, bounds) = (
+            *widget.get::<Point>("position"),
+            *widget.get::<Rectangle>("bounds"),
+        );
+        let local = Point::new(mouse_position.x - position.x, mouse_position.y - position.y);
+
+        let tooltip = {
+            let series = widget.get::<ChartSeries>("series");
+
+            ChartLayout::new(self.kind, series, bounds.size())
+                .hit_test(series, local)
+                .and_then(|index| series.get(index))
+                .map(|point| point.to_string())
+                .unwrap_or_default()
+        };
+
+        if *widget.get::<String>("tooltip") != tooltip
+            || (!tooltip.is_empty() && *widget.get::<Point>("tooltip_position") != local)
+        {
+            widget.set("tooltip", tooltip);
+            widget.set("tooltip_position", local);
+        }
+    }
+}
+
+widget!(
+    /// The `LineChart` widget draws the values of a series as connected points with a value axis
+    /// and the labels of the points. The value under the mouse is shown in a tooltip.
+    ///
+    /// **CSS element:** `line-chart`
+    LineChart<ChartState>: MouseHandler {
+        /// Sets or shares the data series.
+        series: ChartSeries,
+
+        /// Sets or shares the brush of the series.
+        series_brush: Brush,
+
+        /// Sets or shares the brush of the axes and the labels.
+        foreground: Brush,
+
+        /// Sets or shares the font of the labels.
+        font: String,
+
+        /// Sets or shares the font size of the labels.
+        font_size: f64,
+
+        /// Sets or shares the text of the tooltip, it is empty if no point is hovered.
+        tooltip: String,
+
+        /// Sets or shares the position of the tooltip relative to the chart.
+        tooltip_position: Point,
+
+        /// Sets or shares the background of the tooltip.
+        tooltip_background: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for LineChart {
+    fn template(mut self, _: Entity, _: &mut BuildContext) -> Self {
+        self.state_mut().kind = ChartKind::Line;
+
+        self.name("LineChart")
+            .accessible_role("image")
+            .selector("line-chart")
+            .series_brush(SERIES_COLOR)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font("Roboto Regular")
+            .font_size(fonts::FONT_SIZE_12)
+            .tooltip_background(colors::LYNCH_COLOR)
+            // the tooltip is updated while the mouse moves over the chart
+            .on_mouse_move(|_, _| false)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(ChartRenderObject::new(ChartKind::Line))
+    }
+}
+
+widget!(
+    /// The `BarChart` widget draws the values of a series as bars with a value axis and the
+    /// labels of the points. The value under the mouse is shown in a tooltip.
+    ///
+    /// **CSS element:** `bar-chart`
+    BarChart<ChartState>: MouseHandler {
+        /// Sets or shares the data series.
+        series: ChartSeries,
+
+        /// Sets or shares the brush of the bars.
+        series_brush: Brush,
+
+        /// Sets or shares the brush of the axes and the labels.
+        foreground: Brush,
+
+        /// Sets or shares the font of the labels.
+        font: String,
+
+        /// Sets or shares the font size of the labels.
+        font_size: f64,
+
+        /// Sets or shares the text of the tooltip, it is empty if no bar is hovered.
+        tooltip: String,
+
+        /// Sets or shares the position of the tooltip relative to the chart.
+        tooltip_position: Point,
+
+        /// Sets or shares the background of the tooltip.
+        tooltip_background: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for BarChart {
+    fn template(mut self, _: Entity, _: &mut BuildContext) -> Self {
+        self.state_mut().kind = ChartKind::Bar;
+
+        self.name("BarChart")
+            .accessible_role("image")
+            .selector("bar-chart")
+            .series_brush(SERIES_COLOR)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font("Roboto Regular")
+            .font_size(fonts::FONT_SIZE_12)
+            .tooltip_background(colors::LYNCH_COLOR)
+            .on_mouse_move(|_, _| false)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(ChartRenderObject::new(ChartKind::Bar))
+    }
+}
+
+widget!(
+    /// The `PieChart` widget draws the positive values of a series as slices of a pie. The
+    /// slices get different hues of the series brush. The value under the mouse is shown in a
+    /// tooltip.
+    ///
+    /// **CSS element:** `pie-chart`
+    PieChart<ChartState>: MouseHandler {
+        /// Sets or shares the data series.
+        series: ChartSeries,
+
+        /// Sets or shares the brush of the first slice.
+        series_brush: Brush,
+
+        /// Sets or shares the brush of the tooltip text.
+        foreground: Brush,
+
+        /// Sets or shares the font of the tooltip.
+        font: String,
+
+        /// Sets or shares the font size of the tooltip.
+        font_size: f64,
+
+        /// Sets or shares the text of the tooltip, it is empty if no slice is hovered.
+        tooltip: String,
+
+        /// Sets or shares the position of the tooltip relative to the chart.
+        tooltip_position: Point,
+
+        /// Sets or shares the background of the tooltip.
+        tooltip_background: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for PieChart {
+    fn template(mut self, _: Entity, _: &mut BuildContext) -> Self {
+        self.state_mut().kind = ChartKind::Pie;
+
+        self.name("PieChart")
+            .accessible_role("image")
+            .selector("pie-chart")
+            .series_brush(SERIES_COLOR)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font("Roboto Regular")
+            .font_size(fonts::FONT_SIZE_12)
+            .tooltip_background(colors::LYNCH_COLOR)
+            .on_mouse_move(|_, _| false)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(ChartRenderObject::new(ChartKind::Pie))
+    }
+}
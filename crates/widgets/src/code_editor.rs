@@ -0,0 +1,820 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    prelude::*,
+    shell::{Key, KeyEvent},
+};
+
+// Height of a line relative to the font size.
+const LINE_SPACING: f64 = 1.4;
+
+// Number of spaces that are inserted for a typed tab.
+const TAB_WIDTH: usize = 4;
+
+type Highlighter = Option<Box<dyn Fn(&str) -> Vec<TextSpan>>>;
+
+#[derive(Clone)]
+enum CodeEditorAction {
+    Key(KeyEvent),
+    TextInput(String),
+    Mouse(Point),
+}
+
+// Returns the byte index of the character at the given column of a line.
+fn byte_index(line: &str, column: usize) -> usize {
+    line.char_indices()
+        .nth(column)
+        .map_or(line.len(), |(index, _)| index)
+}
+
+// Returns the offset of a scroll viewer that moves the range from `start` with the given extent
+// into the visible area. The offset is only changed if the range is outside of the area.
+fn scroll_into_view(offset: f64, start: f64, extent: f64, viewport: f64) -> f64 {
+    if start + offset < 0.0 {
+        -start
+    } else if start + extent + offset > viewport {
+        viewport - start - extent
+    } else {
+        offset
+    }
+}
+
+// The lines of the edited text. The caret is described by `(line, column)` in characters.
+#[derive(Clone, Debug, PartialEq)]
+struct CodeText {
+    lines: Vec<String>,
+}
+
+impl CodeText {
+    fn new(text: &str) -> Self {
+        CodeText {
+            lines: text
+                .split('\n')
+                .map(|line| line.trim_end_matches('\r').to_string())
+                .collect(),
+        }
+    }
+
+    fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.lines.get(line).map_or(0, |line| line.chars().count())
+    }
+
+    // Returns the leading whitespace of a line.
+    fn indentation(&self, line: usize) -> &str {
+        let line = self.lines[line].as_str();
+
+        &line[..line.len() - line.trim_start().len()]
+    }
+
+    fn clamp(&self, (line, column): (usize, usize)) -> (usize, usize) {
+        let line = line.min(self.lines.len() - 1);
+
+        (line, column.min(self.line_len(line)))
+    }
+
+    // Inserts the text at the caret and returns the caret behind it. Line breaks in the text
+    // split the line.
+    fn insert(&mut self, (line, column): (usize, usize), text: &str) -> (usize, usize) {
+        let index = byte_index(&self.lines[line], column);
+        let tail = self.lines[line].split_off(index);
+        let mut parts = text.split('\n');
+        let mut current = line;
+
+        self.lines[line].push_str(parts.next().unwrap_or_default());
+
+        for part in parts {
+            current += 1;
+            self.lines.insert(current, part.to_string());
+        }
+
+        let column = self.line_len(current);
+        self.lines[current].push_str(&tail);
+
+        (current, column)
+    }
+
+    // Removes the character before the caret, at the start of a line the line is joined with
+    // the previous one.
+    fn backspace(&mut self, (line, column): (usize, usize)) -> (usize, usize) {
+        if column > 0 {
+            let index = byte_index(&self.lines[line], column - 1);
+            self.lines[line].remove(index);
+            return (line, column - 1);
+        }
+
+        if line == 0 {
+            return (line, column);
+        }
+
+        let removed = self.lines.remove(line);
+        let column = self.line_len(line - 1);
+        self.lines[line - 1].push_str(&removed);
+
+        (line - 1, column)
+    }
+
+    // Removes the character at the caret, at the end of a line the next line is joined.
+    fn delete(&mut self, (line, column): (usize, usize)) {
+        if column < self.line_len(line) {
+            let index = byte_index(&self.lines[line], column);
+            self.lines[line].remove(index);
+        } else if line + 1 < self.lines.len() {
+            let removed = self.lines.remove(line + 1);
+            self.lines[line].push_str(&removed);
+        }
+    }
+
+    // Returns the caret that is moved by the given key or `None` if the key does not move the
+    // caret. The lines are moved in the `column` that is kept while the caret moves up and down.
+    fn move_caret(
+        &self,
+        key: Key,
+        (line, column): (usize, usize),
+        preferred_column: usize,
+        page_size: usize,
+    ) -> Option<(usize, usize)> {
+        let last_line = self.lines.len() - 1;
+        let vertical = |line: usize| (line, preferred_column.min(self.line_len(line)));
+
+        let caret = match key {
+            Key::Left if column > 0 => (line, column - 1),
+            Key::Left if line > 0 => (line - 1, self.line_len(line - 1)),
+            Key::Right if column < self.line_len(line) => (line, column + 1),
+            Key::Right if line < last_line => (line + 1, 0),
+            Key::Left | Key::Right => (line, column),
+            Key::Up => vertical(line.saturating_sub(1)),
+            Key::Down => vertical((line + 1).min(last_line)),
+            Key::PageUp => vertical(line.saturating_sub(page_size.max(1))),
+            Key::PageDown => vertical((line + page_size.max(1)).min(last_line)),
+            // moves to the first character after the indentation, then to the start of the line
+            Key::Home if column == self.indentation(line).chars().count() => (line, 0),
+            Key::Home => (line, self.indentation(line).chars().count()),
+            Key::End => (line, self.line_len(line)),
+            _ => return None,
+        };
+
+        Some(caret)
+    }
+}
+
+impl Default for CodeText {
+    fn default() -> Self {
+        CodeText::new("")
+    }
+}
+
+// Font, brushes and metrics of the lines, all lines are updated if it is changed.
+#[derive(Clone, Default, PartialEq)]
+struct LineStyle {
+    foreground: Brush,
+    gutter_foreground: Brush,
+    font: String,
+    font_size: f64,
+    char_width: f64,
+    gutter_width: f64,
+}
+
+impl LineStyle {
+    fn line_height(&self) -> f64 {
+        (self.font_size * LINE_SPACING).ceil()
+    }
+}
+
+/// The `CodeEditorState` handles the editing of the `CodeEditor` and keeps its line widgets in
+/// sync with the text.
+#[derive(Default, AsAny)]
+pub struct CodeEditorState {
+    actions: RefCell<Vec<CodeEditorAction>>,
+    highlighter: Highlighter,
+    // text of the `text` property the lines are built from.
+    text: String,
+    code: CodeText,
+    caret: (usize, usize),
+    // column the caret keeps while it moves up and down.
+    preferred_column: Option<usize>,
+    // highlighted spans by the text of the line, a line is only highlighted if it is changed.
+    spans: HashMap<String, TextSpans>,
+    line_widgets: Vec<Entity>,
+    style: LineStyle,
+    lines_panel: Option<Entity>,
+    caret_widget: Option<Entity>,
+    scroll_viewer: Option<Entity>,
+    // number of lines that fit into the visible area.
+    page_size: usize,
+    scroll_to_caret: bool,
+}
+
+impl CodeEditorState {
+    /// Returns the caret as `(line, column)` in characters, both start at `0`.
+    pub fn caret(&self) -> (usize, usize) {
+        self.caret
+    }
+
+    fn action(&self, action: CodeEditorAction) {
+        self.actions.borrow_mut().push(action);
+    }
+
+    fn request_focus(&self, ctx: &mut Context<'_>) {
+        let focused_widget = ctx.window().get::<Global>("global").focused_widget;
+
+        if focused_widget == Some(ctx.entity) || !ctx.widget().get::<bool>("enabled") {
+            return;
+        }
+
+        if let Some(old_focused_element) = focused_widget {
+            let mut old_focused_element = ctx.get_widget(old_focused_element);
+            old_focused_element.set("focused", false);
+            old_focused_element.update_theme_by_state(false);
+        }
+
+        ctx.window().get_mut::<Global>("global").focused_widget = Some(ctx.entity);
+        ctx.widget().set("focused", true);
+        ctx.widget().update_theme_by_state(false);
+    }
+
+    fn handle_key(&mut self, key: Key, ctx: &mut Context<'_>) {
+        match key {
+            Key::Backspace => {
+                let caret = self.code.backspace(self.caret);
+                self.edit(caret, ctx);
+            }
+            Key::Delete => {
+                self.code.delete(self.caret);
+                self.edit(self.caret, ctx);
+            }
+            // the new line keeps the indentation of the current line
+            Key::Enter => {
+                let text = format!("\n{}", self.code.indentation(self.caret.0));
+                let caret = self.code.insert(self.caret, text.as_str());
+                self.edit(caret, ctx);
+            }
+            _ => {
+                let preferred_column = self.preferred_column.unwrap_or(self.caret.1);
+
+                if let Some(caret) =
+                    self.code
+                        .move_caret(key, self.caret, preferred_column, self.page_size)
+                {
+                    self.preferred_column = match key {
+                        Key::Up | Key::Down | Key::PageUp | Key::PageDown => Some(preferred_column),
+                        _ => None,
+                    };
+                    self.caret = caret;
+                    self.scroll_to_caret = true;
+                }
+            }
+        }
+    }
+
+    // Inserts the typed text at the caret. Control characters, e.g. of shortcuts, are not
+    // inserted, a tab is inserted as spaces.
+    fn insert_text(&mut self, text: &str, ctx: &mut Context<'_>) {
+        if text.chars().all(char::is_control) && text != "\t" {
+            return;
+        }
+
+        let text = text.replace('\t', &" ".repeat(TAB_WIDTH));
+        let caret = self.code.insert(self.caret, text.as_str());
+        self.edit(caret, ctx);
+    }
+
+    // Writes the edited lines back to the text and moves the caret.
+    fn edit(&mut self, caret: (usize, usize), ctx: &mut Context<'_>) {
+        self.caret = caret;
+        self.preferred_column = None;
+        self.scroll_to_caret = true;
+        self.text = self.code.text();
+        ctx.widget().set("text", self.text.clone());
+
+        ctx.push_event_strategy_by_entity(
+            ChangedEvent(ctx.entity),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    // Moves the caret to the character cell under the given position in window coordinates.
+    fn click(&mut self, position: Point, ctx: &mut Context<'_>) {
+        let line_height = self.style.line_height();
+
+        let bounds = match self.lines_panel.and_then(|panel| ctx.bounds_of(panel)) {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        if line_height <= 0.0 || self.style.char_width <= 0.0 {
+            return;
+        }
+
+        let line = ((position.y - bounds.y()) / line_height).max(0.0) as usize;
+        let column = ((position.x - bounds.x() - self.style.gutter_width) / self.style.char_width)
+            .round()
+            .max(0.0) as usize;
+
+        self.caret = self.code.clamp((line, column));
+        self.preferred_column = None;
+    }
+
+    // Reads the style of the lines from the editor, the width of a character is measured if the
+    // font is changed. Returns `true` if the style is changed.
+    fn update_style(&mut self, ctx: &mut Context<'_>) -> bool {
+        let mut style = {
+            let widget = ctx.widget();
+
+            LineStyle {
+                foreground: widget.clone("foreground"),
+                gutter_foreground: widget.clone("gutter_foreground"),
+                font: widget.clone("font"),
+                font_size: *widget.get::<f64>("font_size"),
+                char_width: self.style.char_width,
+                gutter_width: 0.0,
+            }
+        };
+
+        if style.font != self.style.font || style.font_size != self.style.font_size {
+            style.char_width = ctx
+                .render_context_2_d()
+                .measure("0", style.font_size, style.font.as_str())
+                .width;
+        }
+
+        if *ctx.widget().get::<bool>("line_numbers") {
+            style.gutter_width = code_gutter_width(self.code.lines.len(), style.char_width);
+        }
+
+        if style == self.style {
+            return false;
+        }
+
+        self.style = style;
+        true
+    }
+
+    // Updates the line widgets whose line is changed, builds the missing ones and removes the
+    // widgets of removed lines. Unchanged lines keep their cached layer.
+    fn update_lines(&mut self, style_changed: bool, ctx: &mut Context<'_>) {
+        let lines_panel = match self.lines_panel {
+            Some(lines_panel) => lines_panel,
+            None => return,
+        };
+
+        let line_height = self.style.line_height();
+
+        for index in 0..self.code.lines.len() {
+            let new_line = index >= self.line_widgets.len();
+
+            if new_line {
+                let line = CodeLine::create().build(&mut ctx.build_context());
+                ctx.append_child_entity_to(line, lines_panel);
+                self.line_widgets.push(line);
+            }
+
+            let text = &self.code.lines[index];
+            let spans = if let Some(spans) = self.spans.get(text) {
+                spans.clone()
+            } else if let Some(highlighter) = &self.highlighter {
+                let spans = TextSpans(highlighter(text));
+                self.spans.insert(text.clone(), spans.clone());
+                spans
+            } else {
+                TextSpans::default()
+            };
+
+            let style = &self.style;
+            let mut widget = ctx.get_widget(self.line_widgets[index]);
+
+            if style_changed || new_line {
+                widget.set("foreground", style.foreground.clone());
+                widget.set("gutter_foreground", style.gutter_foreground.clone());
+                widget.set("font", style.font.clone());
+                widget.set("font_size", style.font_size);
+                widget.set("char_width", style.char_width);
+                widget.set("gutter_width", style.gutter_width);
+                widget
+                    .get_mut::<Constraint>("constraint")
+                    .set_height(line_height);
+            }
+
+            if widget.get::<String>("text") != text {
+                widget.set("text", text.clone());
+            }
+
+            if *widget.get::<TextSpans>("spans") != spans {
+                widget.set("spans", spans);
+            }
+
+            if *widget.get::<usize>("line_number") != index + 1 {
+                widget.set("line_number", index + 1);
+            }
+
+            // one more cell for the caret behind the last character
+            let width = style.gutter_width + (text.chars().count() + 1) as f64 * style.char_width;
+
+            if (widget.get::<Constraint>("constraint").width() - width).abs() > std::f64::EPSILON {
+                widget.get_mut::<Constraint>("constraint").set_width(width);
+            }
+        }
+
+        for line in self.line_widgets.split_off(self.code.lines.len()) {
+            ctx.remove_child_from(line, lines_panel);
+        }
+
+        // the spans of removed lines are dropped
+        let lines: HashSet<&String> = self.code.lines.iter().collect();
+        self.spans.retain(|text, _| lines.contains(text));
+    }
+
+    // Places the caret widget in the cell of the caret, it is only visible if the editor is
+    // focused.
+    fn update_caret(&mut self, style_changed: bool, ctx: &mut Context<'_>) {
+        let caret_widget = match self.caret_widget {
+            Some(caret_widget) => caret_widget,
+            None => return,
+        };
+
+        let visibility = if *ctx.widget().get::<bool>("focused") {
+            Visibility::Visible
+        } else {
+            Visibility::Collapsed
+        };
+        let line_height = self.style.line_height();
+        let margin = Thickness::new(
+            self.style.gutter_width + self.caret.1 as f64 * self.style.char_width,
+            self.caret.0 as f64 * line_height,
+            0.0,
+            0.0,
+        );
+
+        let mut widget = ctx.get_widget(caret_widget);
+
+        if style_changed {
+            widget.set("background", self.style.foreground.clone());
+            widget
+                .get_mut::<Constraint>("constraint")
+                .set_height(line_height);
+        }
+
+        if *widget.get::<Thickness>("margin") != margin {
+            widget.set("margin", margin);
+        }
+
+        if *widget.get::<Visibility>("visibility") != visibility {
+            widget.set("visibility", visibility);
+        }
+    }
+}
+
+impl State for CodeEditorState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        // the text is changed from outside
+        if *ctx.widget().get::<String>("text") != self.text {
+            self.text = ctx.widget().clone("text");
+            self.code = CodeText::new(self.text.as_str());
+            self.caret = self.code.clamp(self.caret);
+        }
+
+        // all actions since the last update are handled in order, e.g. fast typed characters
+        let actions: Vec<CodeEditorAction> = self.actions.borrow_mut().drain(..).collect();
+
+        for action in actions {
+            match action {
+                CodeEditorAction::Mouse(position) => {
+                    self.request_focus(ctx);
+                    self.click(position, ctx);
+                }
+                _ if !ctx.widget().get::<bool>("focused") => {}
+                CodeEditorAction::Key(event) => self.handle_key(event.key, ctx),
+                CodeEditorAction::TextInput(text) => self.insert_text(text.as_str(), ctx),
+            }
+        }
+
+        let style_changed = self.update_style(ctx);
+        self.update_lines(style_changed, ctx);
+        self.update_caret(style_changed, ctx);
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let scroll_viewer = match self.scroll_viewer {
+            Some(scroll_viewer) => scroll_viewer,
+            None => return,
+        };
+
+        let viewport = *ctx.get_widget(scroll_viewer).get::<Rectangle>("bounds");
+        let line_height = self.style.line_height();
+
+        if line_height > 0.0 {
+            self.page_size = (viewport.height() / line_height).floor() as usize;
+        }
+
+        if !self.scroll_to_caret {
+            return;
+        }
+        self.scroll_to_caret = false;
+
+        let offset = *ctx.get_widget(scroll_viewer).get::<Point>("scroll_offset");
+        let new_offset = Point::new(
+            scroll_into_view(
+                offset.x,
+                self.style.gutter_width + self.caret.1 as f64 * self.style.char_width,
+                self.style.char_width,
+                viewport.width(),
+            ),
+            scroll_into_view(
+                offset.y,
+                self.caret.0 as f64 * line_height,
+                line_height,
+                viewport.height(),
+            ),
+        );
+
+        if new_offset != offset {
+            ctx.get_widget(scroll_viewer)
+                .set("scroll_offset", new_offset);
+            let _ = ctx.request_sender().send(shell::ShellRequest::Update);
+        }
+    }
+}
+
+widget!(
+    /// The `CodeLine` widget draws a line of a `CodeEditor` with its line number and its
+    /// highlighted spans. It is rendered into a cached layer, so only changed lines are drawn
+    /// again.
+    ///
+    /// **CSS element:** `code-line`
+    CodeLine {
+        /// Sets or shares the text of the line.
+        text: String,
+
+        /// Sets or shares the highlighted spans of the line.
+        spans: TextSpans,
+
+        /// Sets or shares the line number that is drawn in the gutter.
+        line_number: usize,
+
+        /// Sets or shares the foreground of the characters outside of the spans.
+        foreground: Brush,
+
+        /// Sets or shares the foreground of the line number.
+        gutter_foreground: Brush,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the width of a character cell.
+        char_width: f64,
+
+        /// Sets or shares the width of the gutter, `0` hides the line number.
+        gutter_width: f64,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for CodeLine {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("CodeLine")
+            .selector("code-line")
+            .horizontal_alignment("start")
+            .text("")
+            .spans(TextSpans::default())
+            .line_number(1)
+            .foreground(colors::LINK_WATER_COLOR)
+            .gutter_foreground(colors::BOMBAY_COLOR)
+            .font("Roboto Regular")
+            .font_size(fonts::FONT_SIZE_12)
+            .char_width(0.0)
+            .gutter_width(0.0)
+            .cache_mode("texture")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(CodeLineRenderObject)
+    }
+}
+
+widget!(
+    /// The `CodeEditor` widget is a multi-line text input for code. The characters are placed
+    /// on a grid of monospaced cells that have the width of the `0` of the font, a font with
+    /// fixed width characters should be registered and set with `font`.
+    ///
+    /// The line numbers are drawn in a gutter at the left side if `line_numbers` is set. A
+    /// `highlighter` returns the styled spans of a line, it is only called for new and changed
+    /// lines. Each line is a `CodeLine` widget with a cached layer, so only the changed lines
+    /// are drawn again while typing.
+    ///
+    /// The caret is moved with the arrow keys, `Home`, `End`, `PageUp`, `PageDown` and a click
+    /// and it is scrolled into view. `Enter` keeps the indentation of the current line.
+    ///
+    /// **CSS element:** `code-editor`
+    CodeEditor<CodeEditorState>: ChangedHandler, KeyDownHandler, MouseHandler, TextInputHandler {
+        /// Sets or shares the text property, lines are separated by `\n`.
+        text: String,
+
+        /// Sets or shares the foreground of the characters outside of the highlighted spans.
+        foreground: Brush,
+
+        /// Sets or shares the foreground of the line numbers.
+        gutter_foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the value that describes if the line numbers are shown.
+        line_numbers: bool,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the (wheel, scroll) delta property.
+        delta: Point,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl CodeEditor {
+    /// Inserts a callback that returns the highlighted spans of a line. The spans describe
+    /// character indices of the line.
+    pub fn highlighter<F: Fn(&str) -> Vec<TextSpan> + 'static>(mut self, highlighter: F) -> Self {
+        self.state_mut().highlighter = Some(Box::new(highlighter));
+        self
+    }
+}
+
+impl Template for CodeEditor {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let lines_panel = Stack::create()
+            .vertical_alignment("start")
+            .horizontal_alignment("start")
+            .build(ctx);
+
+        let caret = Container::create()
+            .horizontal_alignment("start")
+            .vertical_alignment("start")
+            .width(1.0)
+            .visibility("collapsed")
+            .build(ctx);
+
+        let scroll_viewer = ScrollViewer::create()
+            .scroll_viewer_mode(("auto", "auto"))
+            .delta(id)
+            .child(
+                Grid::create()
+                    .vertical_alignment("start")
+                    .horizontal_alignment("start")
+                    .child(lines_panel)
+                    .child(caret)
+                    .build(ctx),
+            )
+            .build(ctx);
+
+        self.state_mut().lines_panel = Some(lines_panel);
+        self.state_mut().caret_widget = Some(caret);
+        self.state_mut().scroll_viewer = Some(scroll_viewer);
+
+        self.name("CodeEditor")
+            .accessible_role("text-input")
+            .selector("code-editor")
+            .cursor("text")
+            .text("")
+            .foreground(colors::LINK_WATER_COLOR)
+            .gutter_foreground(colors::BOMBAY_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto Regular")
+            .line_numbers(true)
+            .background(colors::LYNCH_COLOR)
+            .border_brush("transparent")
+            .border_width(0.0)
+            .border_radius(2.0)
+            .padding(4.0)
+            .min_width(128.0)
+            .min_height(64.0)
+            .delta(0.0)
+            .focused(false)
+            .on_mouse_down(move |states, position| {
+                states
+                    .get::<CodeEditorState>(id)
+                    .action(CodeEditorAction::Mouse(position));
+                false
+            })
+            .on_key_down(move |states, event| {
+                states
+                    .get::<CodeEditorState>(id)
+                    .action(CodeEditorAction::Key(event));
+                false
+            })
+            .on_text_input(move |states, text| {
+                states
+                    .get::<CodeEditorState>(id)
+                    .action(CodeEditorAction::TextInput(text));
+                false
+            })
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(scroll_viewer)
+                    .build(ctx),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut code = CodeText::new("fn main() {}");
+
+        assert_eq!(code.insert((0, 11), "\n    let a = 1;\n"), (2, 0));
+        assert_eq!(code.text(), "fn main() {\n    let a = 1;\n}");
+        assert_eq!(code.insert((1, 13), "23"), (1, 15));
+        assert_eq!(code.lines[1], "    let a = 123;");
+    }
+
+    #[test]
+    fn test_backspace_delete() {
+        let mut code = CodeText::new("ab\ncd");
+
+        // joins the lines at the start of a line
+        assert_eq!(code.backspace((1, 0)), (0, 2));
+        assert_eq!(code.text(), "abcd");
+        assert_eq!(code.backspace((0, 1)), (0, 0));
+        assert_eq!(code.backspace((0, 0)), (0, 0));
+        assert_eq!(code.text(), "bcd");
+
+        let mut code = CodeText::new("ab\ncd");
+        code.delete((0, 2));
+        assert_eq!(code.text(), "abcd");
+        code.delete((0, 0));
+        code.delete((0, 3));
+        assert_eq!(code.text(), "bcd");
+    }
+
+    #[test]
+    fn test_move_caret() {
+        let code = CodeText::new("fn main() {\n    a\n}");
+
+        assert_eq!(code.move_caret(Key::Right, (0, 11), 11, 10), Some((1, 0)));
+        assert_eq!(code.move_caret(Key::Left, (1, 0), 0, 10), Some((0, 11)));
+
+        // the column is kept while moving over shorter lines
+        assert_eq!(code.move_caret(Key::Down, (0, 9), 9, 10), Some((1, 5)));
+        assert_eq!(code.move_caret(Key::Up, (1, 5), 9, 10), Some((0, 9)));
+        assert_eq!(code.move_caret(Key::PageDown, (0, 9), 9, 10), Some((2, 1)));
+
+        // home moves to the indentation first
+        assert_eq!(code.move_caret(Key::Home, (1, 5), 5, 10), Some((1, 4)));
+        assert_eq!(code.move_caret(Key::Home, (1, 4), 4, 10), Some((1, 0)));
+        assert_eq!(code.move_caret(Key::End, (1, 0), 0, 10), Some((1, 5)));
+        assert_eq!(code.move_caret(Key::Enter, (1, 0), 0, 10), None);
+    }
+
+    #[test]
+    fn test_multi_byte() {
+        let mut code = CodeText::new("äö");
+
+        assert_eq!(code.insert((0, 1), "ü"), (0, 2));
+        assert_eq!(code.text(), "äüö");
+        assert_eq!(code.backspace((0, 3)), (0, 2));
+        assert_eq!(code.text(), "äü");
+    }
+
+    #[test]
+    fn test_scroll_into_view() {
+        assert_eq!(scroll_into_view(0.0, 20.0, 10.0, 100.0), 0.0);
+        assert_eq!(scroll_into_view(0.0, 120.0, 10.0, 100.0), -30.0);
+        assert_eq!(scroll_into_view(-50.0, 20.0, 10.0, 100.0), -20.0);
+    }
+}
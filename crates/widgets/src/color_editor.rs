@@ -0,0 +1,233 @@
+use crate::{gradient_editor::parse_color, prelude::*};
+
+#[derive(Copy, Clone)]
+enum ColorEditorAction {
+    SetColor,
+    SliderChanged,
+}
+
+/// The `ColorEditorState` keeps the hex input and the hue, saturation and lightness sliders in
+/// sync with the edited color.
+#[derive(Default, AsAny)]
+pub struct ColorEditorState {
+    actions: Vec<ColorEditorAction>,
+    brush: Brush,
+    hsl: (f64, f64, f64),
+}
+
+impl ColorEditorState {
+    fn action(&mut self, action: ColorEditorAction) {
+        self.actions.push(action);
+    }
+
+    // Writes the color to the brush property and raises the changed event.
+    fn apply(&mut self, ctx: &mut Context<'_>, color: Color) {
+        self.brush = Brush::from(color);
+        ctx.widget().set("brush", self.brush.clone());
+        ctx.push_event_strategy_by_entity(
+            ChangedEvent(ctx.entity),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    // Updates the input and the sliders if the brush is changed from outside.
+    fn load(&mut self, ctx: &mut Context<'_>) {
+        let brush = ctx.widget().clone::<Brush>("brush");
+
+        if brush == self.brush {
+            return;
+        }
+
+        self.brush = brush;
+        self.update_children(ctx, true);
+    }
+
+    fn update_children(&mut self, ctx: &mut Context<'_>, sliders: bool) {
+        let color = match &self.brush {
+            Brush::SolidColor(color) => *color,
+            _ => return,
+        };
+
+        ctx.child("preview").set("background", self.brush.clone());
+        ctx.child("color_input")
+            .set("text", String16::from(color.to_string()));
+
+        if !sliders {
+            return;
+        }
+
+        let (h, s, l) = color.to_hsl();
+        self.hsl = (h, s * 100.0, l * 100.0);
+
+        ctx.child("hue").set("value", self.hsl.0);
+        ctx.child("saturation").set("value", self.hsl.1);
+        ctx.child("lightness").set("value", self.hsl.2);
+    }
+
+    fn set_color(&mut self, ctx: &mut Context<'_>) {
+        let text = ctx.child("color_input").get::<String16>("text").to_string();
+
+        match parse_color(text.as_str()) {
+            Some(color) => {
+                self.apply(ctx, color);
+                self.update_children(ctx, true);
+            }
+            // reverts invalid input
+            None => self.update_children(ctx, false),
+        }
+    }
+
+    fn slider_changed(&mut self, ctx: &mut Context<'_>) {
+        let hsl = (
+            *ctx.child("hue").get::<f64>("value"),
+            *ctx.child("saturation").get::<f64>("value"),
+            *ctx.child("lightness").get::<f64>("value"),
+        );
+
+        // ignores the changes that are caused by update_children
+        if (hsl.0 - self.hsl.0).abs() < std::f64::EPSILON
+            && (hsl.1 - self.hsl.1).abs() < std::f64::EPSILON
+            && (hsl.2 - self.hsl.2).abs() < std::f64::EPSILON
+        {
+            return;
+        }
+
+        self.hsl = hsl;
+        self.apply(ctx, Color::hsl(hsl.0, hsl.1 / 100.0, hsl.2 / 100.0));
+        self.update_children(ctx, false);
+    }
+}
+
+impl State for ColorEditorState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.load(ctx);
+
+        let actions = std::mem::replace(&mut self.actions, vec![]);
+
+        for action in actions {
+            match action {
+                ColorEditorAction::SetColor => self.set_color(ctx),
+                ColorEditorAction::SliderChanged => self.slider_changed(ctx),
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `ColorEditor` is used to edit a solid color. The color could be entered as hex value,
+    /// e.g. `#efd035`, or adjusted by the hue, saturation and lightness sliders.
+    ///
+    /// **CSS element:** `color-editor`
+    ColorEditor<ColorEditorState>: ChangedHandler {
+        /// Sets or shares the edited color brush.
+        brush: Brush,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+// Builds a labeled slider for one of the hsl components.
+fn slider(id: Entity, label: &str, name: &str, maximum: f64, ctx: &mut BuildContext) -> Entity {
+    Grid::create()
+        .columns(Columns::create().column(24.0).column("*").build())
+        .child(
+            TextBlock::create()
+                .text(label)
+                .vertical_alignment("center")
+                .build(ctx),
+        )
+        .child(
+            Slider::create()
+                .selector(Selector::from("slider").id(name))
+                .attach(Grid::column(1))
+                .maximum(maximum)
+                .on_changed(move |states, _| {
+                    states
+                        .get_mut::<ColorEditorState>(id)
+                        .action(ColorEditorAction::SliderChanged);
+                })
+                .build(ctx),
+        )
+        .build(ctx)
+}
+
+impl Template for ColorEditor {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("ColorEditor")
+            .selector("color-editor")
+            .brush("#000000")
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(4.0)
+            .min_width(200.0)
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(
+                        Stack::create()
+                            .spacing(4.0)
+                            .child(
+                                Grid::create()
+                                    .columns(
+                                        Columns::create()
+                                            .column(32.0)
+                                            .column(8.0)
+                                            .column("*")
+                                            .build(),
+                                    )
+                                    .child(
+                                        Container::create()
+                                            .selector(
+                                                Selector::from("color-editor-preview")
+                                                    .id("preview"),
+                                            )
+                                            .border_width(1.0)
+                                            .border_brush(colors::BOMBAY_COLOR)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBox::create()
+                                            .selector(Selector::from("text-box").id("color_input"))
+                                            .attach(Grid::column(2))
+                                            .min_width(0.0)
+                                            .on_activate(move |states, _| {
+                                                states
+                                                    .get_mut::<ColorEditorState>(id)
+                                                    .action(ColorEditorAction::SetColor);
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .child(slider(id, "H", "hue", 360.0, ctx))
+                            .child(slider(id, "S", "saturation", 100.0, ctx))
+                            .child(slider(id, "L", "lightness", 100.0, ctx))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -2,12 +2,12 @@ use crate::prelude::*;
 
 widget!(
     /// The `Container` layout widget surrounds its child with a padding. Draws a box around the child.
-    Container {
+    Container: GestureHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
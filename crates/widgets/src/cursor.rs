@@ -1,32 +1,80 @@
 use crate::prelude::*;
 
-// Default state of the `Cursor` widget.
+/// Default seconds the caret is shown and hidden while it blinks.
+pub const DEFAULT_CARET_BLINK_RATE: f64 = 0.5;
+
+// Returns `true` if the caret is shown the given seconds after the blinking started. The caret is
+// shown and hidden for `blink_rate` seconds each, a rate of `0` turns the blinking off.
+fn is_caret_visible(elapsed: f64, blink_rate: f64) -> bool {
+    blink_rate <= 0.0 || (elapsed / blink_rate) as u64 % 2 == 0
+}
+
+/// The `CursorState` shows the cursor of a focused text widget and lets the caret blink. The
+/// blinking starts again whenever the caret is moved, so it is visible while typing.
 #[derive(Default, AsAny)]
-pub struct CursorState;
+pub struct CursorState {
+    blink_start: f64,
+    selection: Option<TextSelection>,
+}
 
 impl State for CursorState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let mut widget = ctx.widget();
+        let caret_width = *widget.get::<f64>("caret_width");
+        let constraint = widget.get_mut::<Constraint>("constraint");
+
+        if (constraint.width() - caret_width).abs() > std::f64::EPSILON {
+            constraint.set_width(caret_width);
+        }
+    }
+
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let now = Clock::current().now().as_secs_f64();
         let mut widget = ctx.widget();
 
-        let selection_length = widget.get::<TextSelection>("text_selection").length;
+        let selection = *widget.get::<TextSelection>("text_selection");
+        let focused = *widget.get::<bool>("focused");
+        let blink_rate = *widget.get::<f64>("caret_blink_rate");
 
-        if selection_length > 0 {
+        if selection.length > 0 {
             add_selector_to_widget("expanded", &mut widget);
         } else {
             remove_selector_from_widget("expanded", &mut widget)
         }
 
-        if *widget.get::<bool>("focused") {
-            widget.set("visibility", Visibility::from("visible"));
+        if !focused || self.selection != Some(selection) {
+            self.blink_start = now;
+            self.selection = Some(selection).filter(|_| focused);
+        }
+
+        let blinking = focused && selection.length == 0 && blink_rate > 0.0;
+
+        let visibility = if !focused {
+            Visibility::Collapsed
+        } else if blinking && !is_caret_visible(now - self.blink_start, blink_rate) {
+            Visibility::Hidden
         } else {
-            widget.set("visibility", Visibility::from("collapsed"));
+            Visibility::Visible
+        };
+
+        if *widget.get::<Visibility>("visibility") != visibility {
+            widget.set("visibility", visibility);
+        }
+
+        // the next phase of the blinking needs a new frame without input
+        if blinking {
+            ctx.request_update();
         }
     }
 }
 
 widget!(
-    /// The `Cursor` widget represents a text cursor used to mark text.
-    /// 
+    /// The `Cursor` widget represents a text cursor used to mark text. The caret of a focused
+    /// cursor blinks with the `caret_blink_rate`.
+    ///
+    /// The color of the caret is the `background` and its width is the `caret-width` of the
+    /// theme.
+    ///
     /// **CSS element:** `cursor`
     Cursor<CursorState> {
         /// Sets or shares the text property.
@@ -50,6 +98,12 @@ widget!(
         /// Sets or shares the focused property.
         focused: bool,
 
+        /// Sets or shares the width of the caret.
+        caret_width: f64,
+
+        /// Sets or shares the seconds the caret is shown and hidden while it blinks, `0` turns the blinking off.
+        caret_blink_rate: f64,
+
         /// Sets or shares the css selector property.
         selector: Selector
     }
@@ -66,6 +120,8 @@ impl Template for Cursor {
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto Regular")
             .focused(false)
+            .caret_width(1.0)
+            .caret_blink_rate(DEFAULT_CARET_BLINK_RATE)
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
@@ -76,3 +132,18 @@ impl Template for Cursor {
         Box::new(TextSelectionLayout::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_caret_visible() {
+        assert!(is_caret_visible(0.0, 0.5));
+        assert!(is_caret_visible(0.49, 0.5));
+        assert!(!is_caret_visible(0.5, 0.5));
+        assert!(!is_caret_visible(0.99, 0.5));
+        assert!(is_caret_visible(1.0, 0.5));
+        assert!(is_caret_visible(0.75, 0.0));
+    }
+}
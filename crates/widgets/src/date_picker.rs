@@ -0,0 +1,264 @@
+use std::cell::Cell;
+
+use crate::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DatePickerAction {
+    TogglePopup,
+    Commit,
+}
+
+/// The `DatePickerState` synchronizes the text field with the selected date and opens the
+/// calendar popup.
+#[derive(Default, AsAny)]
+pub struct DatePickerState {
+    action: Cell<Option<DatePickerAction>>,
+    popup: Option<Entity>,
+    calendar: Option<Entity>,
+    selected_date: SelectedDate,
+    focused: bool,
+}
+
+impl DatePickerState {
+    fn action(&self, action: DatePickerAction) {
+        self.action.set(Some(action));
+    }
+
+    fn is_popup_open(&self, ctx: &mut Context<'_>) -> bool {
+        if let Some(popup) = self.popup {
+            return *ctx.get_widget(popup).get::<Visibility>("visibility") == Visibility::Visible;
+        }
+
+        false
+    }
+
+    fn open_popup(&self, ctx: &mut Context<'_>) {
+        let (popup, calendar) = match (self.popup, self.calendar) {
+            (Some(popup), Some(calendar)) => (popup, calendar),
+            _ => return,
+        };
+
+        // the calendar shows the month of the selected date
+        if let Some(date) = self.selected_date.0 {
+            ctx.get_widget(calendar).set("display_date", date);
+        }
+
+        let (position, bounds) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Point>("position"),
+                *widget.get::<Rectangle>("bounds"),
+            )
+        };
+
+        let mut popup = ctx.get_widget(popup);
+        popup.set(
+            "margin",
+            Thickness::new(position.x, position.y + bounds.height(), 0.0, 0.0),
+        );
+        popup.set("visibility", Visibility::Visible);
+    }
+
+    fn close_popup(&self, ctx: &mut Context<'_>) {
+        if let Some(popup) = self.popup {
+            ctx.get_widget(popup)
+                .set("visibility", Visibility::Collapsed);
+        }
+    }
+
+    fn update_text(&self, ctx: &mut Context<'_>) {
+        let text = self
+            .selected_date
+            .0
+            .map(|date| date.to_string())
+            .unwrap_or_default();
+
+        ctx.widget().set("text", String16::from(text));
+    }
+
+    // Parses the text and selects the date. Invalid text is reverted to the selected date,
+    // empty text clears the selection.
+    fn commit(&mut self, ctx: &mut Context<'_>) {
+        let text = ctx.widget().get::<String16>("text").to_string();
+
+        if text.trim().is_empty() {
+            ctx.widget().set("selected_date", SelectedDate(None));
+            return;
+        }
+
+        match text.parse::<Date>() {
+            Ok(date) => ctx.widget().set("selected_date", SelectedDate(Some(date))),
+            Err(_) => self.update_text(ctx),
+        }
+    }
+}
+
+impl State for DatePickerState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.selected_date = *ctx.widget().get::<SelectedDate>("selected_date");
+        self.update_text(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        // commits the input if the text field lost the focus
+        let focused = *ctx.child("input").get::<bool>("focused");
+
+        if self.focused && !focused {
+            self.commit(ctx);
+        }
+
+        self.focused = focused;
+
+        if let Some(action) = self.action.take() {
+            match action {
+                DatePickerAction::TogglePopup => {
+                    if self.is_popup_open(ctx) {
+                        self.close_popup(ctx);
+                    } else {
+                        self.open_popup(ctx);
+                    }
+                }
+                DatePickerAction::Commit => self.commit(ctx),
+            }
+        }
+
+        // the date is selected by the calendar, the text field or from outside
+        let selected_date = *ctx.widget().get::<SelectedDate>("selected_date");
+
+        if selected_date != self.selected_date {
+            self.selected_date = selected_date;
+            self.update_text(ctx);
+            self.close_popup(ctx);
+            ctx.push_event_strategy_by_entity(
+                ChangedEvent(ctx.entity),
+                ctx.entity,
+                EventStrategy::Direct,
+            );
+        }
+    }
+}
+
+widget!(
+    /// The `DatePicker` widget is a text field to enter a date of the format `yyyy-mm-dd`. The
+    /// button next to the text field opens a `Calendar` popup to select the date.
+    ///
+    /// **CSS element:** `date-picker`
+    DatePicker<DatePickerState>: ChangedHandler {
+        /// Sets or shares the selected date.
+        selected_date: SelectedDate,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the water_mark text property.
+        water_mark: String16,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for DatePicker {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let calendar = Calendar::create().selected_date(id).build(ctx);
+
+        let popup = Container::create()
+            .selector("date-picker-popup")
+            .horizontal_alignment("start")
+            .vertical_alignment("start")
+            .visibility("collapsed")
+            .background(colors::LYNCH_COLOR)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .child(calendar)
+            .build(ctx);
+
+        if ctx.append_child_to_overlay(popup).is_err() {
+            ctx.append_child(id, popup);
+        }
+
+        self.state_mut().popup = Some(popup);
+        self.state_mut().calendar = Some(calendar);
+
+        self.name("DatePicker")
+            .selector("date-picker")
+            .selected_date(SelectedDate::default())
+            .text("")
+            .water_mark("yyyy-mm-dd")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto Regular")
+            .background(colors::LYNCH_COLOR)
+            .border_brush("transparent")
+            .border_width(0.0)
+            .border_radius(2.0)
+            .padding(4.0)
+            .min_width(128.0)
+            .height(32.0)
+            .child(
+                Grid::create()
+                    .columns(Columns::create().column("*").column(32.0).build())
+                    .child(
+                        TextBox::create()
+                            .selector(Selector::from("text-box").id("input"))
+                            .min_width(0.0)
+                            .text(id)
+                            .water_mark(id)
+                            .foreground(id)
+                            .font_size(id)
+                            .font(id)
+                            .background(id)
+                            .border_radius(id)
+                            .border_width(id)
+                            .border_brush(id)
+                            .padding(id)
+                            .on_activate(move |states, _| {
+                                states
+                                    .get::<DatePickerState>(id)
+                                    .action(DatePickerAction::Commit);
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::create()
+                            .selector("date-picker-button")
+                            .attach(Grid::column(1))
+                            .min_width(0.0)
+                            .padding(0.0)
+                            .icon(material_font_icons::EVENT_FONT_ICON)
+                            .on_click(move |states, _| {
+                                states
+                                    .get::<DatePickerState>(id)
+                                    .action(DatePickerAction::TogglePopup);
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
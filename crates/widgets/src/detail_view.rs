@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// The `DetailViewState` builds the detail of the selected item and keeps the details of the
+/// other items if they are preserved.
+#[derive(Default, AsAny)]
+pub struct DetailViewState {
+    builder: WidgetBuildContext,
+    selected: Option<usize>,
+    current: Option<Entity>,
+    // index and root entity of the preserved details.
+    details: HashMap<usize, Entity>,
+}
+
+impl DetailViewState {
+    // Shows the detail of the given item and hides the current one.
+    fn show(&mut self, index: Option<usize>, preserve_details: bool, ctx: &mut Context<'_>) {
+        if let Some(current) = self.current.take() {
+            if preserve_details {
+                ctx.get_widget(current)
+                    .set("visibility", Visibility::Collapsed);
+            } else {
+                ctx.clear_children();
+            }
+        }
+
+        let index = match index {
+            Some(index) => index,
+            None => return,
+        };
+
+        if let Some(detail) = self.details.get(&index) {
+            ctx.get_widget(*detail)
+                .set("visibility", Visibility::Visible);
+            self.current = Some(*detail);
+            return;
+        }
+
+        if let Some(builder) = &self.builder {
+            let entity = ctx.entity;
+            let detail = {
+                let build_context = &mut ctx.build_context();
+                let detail = builder(build_context, index);
+                build_context.append_child(entity, detail);
+                detail
+            };
+            ctx.get_widget(detail).update_properties_by_theme();
+
+            if preserve_details {
+                self.details.insert(index, detail);
+            }
+
+            self.current = Some(detail);
+        }
+    }
+}
+
+impl State for DetailViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        // the detail of the first selected item is shown
+        let selected = ctx
+            .widget()
+            .try_get::<SelectedIndices>("selected_indices")
+            .and_then(|selected_indices| selected_indices.0.iter().min().copied());
+        let preserve_details = ctx.widget().clone_or_default::<bool>("preserve_details");
+        let request_update = *ctx.widget().get::<bool>("request_update");
+
+        if request_update {
+            ctx.clear_children();
+            self.details.clear();
+            self.current = None;
+            ctx.widget().set("request_update", false);
+        } else if selected == self.selected {
+            return;
+        }
+
+        self.selected = selected;
+        self.show(selected, preserve_details, ctx);
+    }
+}
+
+widget!(
+    /// The `DetailView` shows the detail of the selected item of a list, e.g. of a `ListView`.
+    /// Share the `selected_indices` property with the list and set a `detail_builder`, the detail
+    /// is rebuilt each time the selection changes.
+    ///
+    /// If `preserve_details` is set, the details of the items are kept with their state, e.g.
+    /// the text of a text box, while another item is selected.
+    ///
+    /// **CSS element:** `detail-view`
+    DetailView<DetailViewState> {
+        /// Sets or shares the selected indices of the list.
+        selected_indices: SelectedIndices,
+
+        /// Sets or shares a value that indicates if the details of the items are kept while
+        /// another item is selected.
+        preserve_details: bool,
+
+        /// Sets or shares a value to request a rebuild of the details, e.g. after the data is
+        /// changed.
+        request_update: bool,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl DetailView {
+    /// Sets the builder of the detail of the item with the given index.
+    pub fn detail_builder<F: Fn(&mut BuildContext, usize) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.state_mut().builder = Some(Box::new(builder));
+        self
+    }
+}
+
+impl Template for DetailView {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("DetailView")
+            .selector("detail-view")
+            .preserve_details(false)
+            .request_update(false)
+    }
+}
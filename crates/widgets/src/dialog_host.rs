@@ -0,0 +1,185 @@
+use crate::prelude::*;
+
+// A dialog that is shown by the host.
+struct ShownDialog {
+    request: Rc<DialogRequest>,
+    scrim: Entity,
+    // widget that had the focus before the dialog is shown.
+    focused_widget: Option<Entity>,
+}
+
+/// The `DialogHostState` shows the dialogs that are requested by `Context::show_dialog` and
+/// removes each dialog after it is closed.
+#[derive(Default, AsAny)]
+pub struct DialogHostState {
+    dialogs: Vec<ShownDialog>,
+}
+
+impl DialogHostState {
+    fn show_dialogs(&mut self, ctx: &mut Context<'_>) {
+        let requests = ctx.window().get::<Global>("global").dialogs.clone();
+
+        for request in requests {
+            if request.is_closed()
+                || self
+                    .dialogs
+                    .iter()
+                    .any(|dialog| Rc::ptr_eq(&dialog.request, &request))
+            {
+                continue;
+            }
+
+            let content = match request.build(&mut ctx.build_context()) {
+                Some(content) => content,
+                None => continue,
+            };
+
+            let scrim = {
+                let build_context = &mut ctx.build_context();
+                let frame = Container::create()
+                    .selector("dialog")
+                    .accessible_role("dialog")
+                    .background(colors::LYNCH_COLOR)
+                    .border_brush(colors::BOMBAY_COLOR)
+                    .border_width(1.0)
+                    .border_radius(4.0)
+                    .padding(16.0)
+                    .horizontal_alignment("center")
+                    .vertical_alignment("center")
+                    .child(content)
+                    .build(build_context);
+
+                DialogScrim::create().child(frame).build(build_context)
+            };
+
+            ctx.append_child_entity_to(scrim, ctx.entity);
+            ctx.get_widget(scrim).update_properties_by_theme();
+
+            let focused_widget = self.take_focus(ctx);
+
+            self.dialogs.push(ShownDialog {
+                request,
+                scrim,
+                focused_widget,
+            });
+        }
+    }
+
+    // Removes the focus from the widgets below the dialog, so they get no key events.
+    fn take_focus(&self, ctx: &mut Context<'_>) -> Option<Entity> {
+        let focused_widget = ctx
+            .window()
+            .get_mut::<Global>("global")
+            .focused_widget
+            .take()?;
+
+        let mut widget = ctx.get_widget(focused_widget);
+        widget.set("focused", false);
+        widget.update_theme_by_state(false);
+
+        Some(focused_widget)
+    }
+
+    // Gives the focus back to the widget that had it before the dialog is shown.
+    fn restore_focus(&self, focused_widget: Option<Entity>, ctx: &mut Context<'_>) {
+        if let Some(old_focused_widget) = ctx.window().get::<Global>("global").focused_widget {
+            let mut widget = ctx.get_widget(old_focused_widget);
+            widget.set("focused", false);
+            widget.update_theme_by_state(false);
+        }
+
+        // the widget could be removed while the dialog is shown
+        let focused_widget = focused_widget.filter(|widget| ctx.bounds_of(*widget).is_some());
+        ctx.window().get_mut::<Global>("global").focused_widget = focused_widget;
+
+        if let Some(focused_widget) = focused_widget {
+            let mut widget = ctx.get_widget(focused_widget);
+            widget.set("focused", true);
+            widget.update_theme_by_state(false);
+        }
+    }
+
+    // Removes the closed dialogs and passes their results to the callbacks.
+    fn remove_closed_dialogs(&mut self, ctx: &mut Context<'_>) {
+        let (closed, dialogs): (Vec<_>, Vec<_>) = self
+            .dialogs
+            .drain(..)
+            .partition(|dialog| dialog.request.is_closed());
+        self.dialogs = dialogs;
+
+        if closed.is_empty() {
+            return;
+        }
+
+        ctx.window()
+            .get_mut::<Global>("global")
+            .dialogs
+            .retain(|dialog| !dialog.is_closed());
+
+        for dialog in closed {
+            ctx.remove_child(dialog.scrim);
+            self.restore_focus(dialog.focused_widget, ctx);
+            dialog.request.finish(ctx);
+        }
+    }
+}
+
+impl State for DialogHostState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.show_dialogs(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.remove_closed_dialogs(ctx);
+        self.show_dialogs(ctx);
+    }
+}
+
+widget!(
+    /// The `DialogHost` shows the modal dialogs of a window on top of each other. It is added to
+    /// the overlay of the window with the first call of `Context::show_dialog`.
+    ///
+    /// **CSS element:** `dialog-host`
+    DialogHost<DialogHostState> {
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for DialogHost {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("DialogHost").selector("dialog-host")
+    }
+}
+
+widget!(
+    /// The `DialogScrim` covers the window below a modal dialog and blocks the mouse and touch
+    /// input of the widgets below.
+    ///
+    /// **CSS element:** `dialog-scrim`
+    DialogScrim: MouseHandler, TouchHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for DialogScrim {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("DialogScrim")
+            .selector("dialog-scrim")
+            .background("#80000000")
+            .on_mouse_down(|_, _| true)
+            .on_mouse_up(|_, _| true)
+            .on_mouse_move(|_, _| true)
+            .on_click(|_, _| true)
+            .on_scroll(|_, _| true)
+            .on_touch(|_, _| true)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+}
@@ -0,0 +1,389 @@
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+};
+
+use crate::prelude::*;
+
+/// Describes a file or a directory listed by the `FileBrowser`.
+#[derive(Clone, Debug, PartialEq)]
+struct DirectoryEntry {
+    /// The file name of the entry.
+    name: String,
+
+    /// Is `true` if the entry is a directory.
+    is_dir: bool,
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+struct DirectoryEntries(Vec<DirectoryEntry>);
+
+#[derive(Clone, Default, Debug, PartialEq)]
+struct DirectoryPaths(Vec<PathBuf>);
+
+type ReadResult = (PathBuf, io::Result<Vec<DirectoryEntry>>);
+
+// Reads the entries of the given directory, directories first.
+fn read_entries(path: &Path) -> io::Result<Vec<DirectoryEntry>> {
+    let mut entries = vec![];
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        entries.push(DirectoryEntry {
+            name: entry.file_name().to_string_lossy().to_string(),
+            is_dir: entry.file_type().map(|t| t.is_dir()).unwrap_or(false),
+        });
+    }
+
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    Ok(entries)
+}
+
+/// The `FileBrowserState` reads the directories in the background and keeps the directory tree,
+/// the file list and the breadcrumb in sync with the current path.
+#[derive(Default, AsAny)]
+pub struct FileBrowserState {
+    path: PathBuf,
+    request_path: RefCell<Option<PathBuf>>,
+    receiver: Option<mpsc::Receiver<ReadResult>>,
+    loaded: HashSet<usize>,
+    selected_index: Option<usize>,
+}
+
+impl FileBrowserState {
+    fn navigate(&self, path: impl Into<PathBuf>) {
+        *self.request_path.borrow_mut() = Some(path.into());
+    }
+
+    // Starts reading the given directory on a background thread.
+    fn read_directory(&mut self, ctx: &mut Context<'_>, path: PathBuf) {
+        let (sender, receiver) = mpsc::channel();
+        let request_sender = ctx.request_sender();
+
+        thread::spawn(move || {
+            let entries = read_entries(&path);
+
+            // the receiver is dropped if an other directory is requested in the meantime
+            if sender.send((path, entries)).is_ok() {
+                let _ = request_sender.send(shell::ShellRequest::Update);
+            }
+        });
+
+        self.receiver = Some(receiver);
+    }
+
+    fn update_entries(&mut self, ctx: &mut Context<'_>, path: &Path, entries: Vec<DirectoryEntry>) {
+        // adds the sub directories to the directory tree if the directory is a node of the tree
+        let node = ctx
+            .widget()
+            .get::<DirectoryPaths>("directory_paths")
+            .0
+            .iter()
+            .position(|p| p == path);
+
+        if let Some(node) = node {
+            if self.loaded.insert(node) {
+                for entry in entries.iter().filter(|e| e.is_dir) {
+                    ctx.widget()
+                        .get_mut::<TreeNodes>("directory_nodes")
+                        .push(Some(node));
+                    ctx.widget()
+                        .get_mut::<DirectoryPaths>("directory_paths")
+                        .0
+                        .push(path.join(&entry.name));
+                }
+            }
+        }
+
+        ctx.widget().set("entries_count", entries.len());
+        ctx.widget().set("entries", DirectoryEntries(entries));
+        ctx.child("file_list").set("request_update", true);
+        self.selected_index = None;
+    }
+
+    // Rebuilds the breadcrumb with a button for each ancestor of the current path.
+    fn update_breadcrumb(&self, ctx: &mut Context<'_>) {
+        let breadcrumb = if let Some(breadcrumb) = ctx.entity_of_child("breadcrumb") {
+            breadcrumb
+        } else {
+            return;
+        };
+
+        ctx.clear_children_of(breadcrumb);
+
+        let id = ctx.entity;
+        let mut crumbs = vec![];
+        let mut ancestors: Vec<&Path> = self.path.ancestors().collect();
+        ancestors.reverse();
+
+        for ancestor in ancestors {
+            let text = ancestor
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| ancestor.to_string_lossy().to_string());
+            let path = ancestor.to_path_buf();
+
+            let build_context = &mut ctx.build_context();
+            let crumb = Button::create()
+                .selector("file-browser-crumb")
+                .min_width(0.0)
+                .text(text)
+                .on_click(move |states, _| {
+                    states.get::<FileBrowserState>(id).navigate(path.clone());
+                    true
+                })
+                .build(build_context);
+
+            build_context.append_child(breadcrumb, crumb);
+            crumbs.push(crumb);
+        }
+
+        for crumb in crumbs {
+            ctx.get_widget(crumb).update_properties_by_theme();
+        }
+    }
+
+    // Navigates into a selected directory or selects a file.
+    fn update_selection(&mut self, ctx: &mut Context<'_>) {
+        let selected_index = ctx
+            .child("file_list")
+            .get::<SelectedIndices>("selected_indices")
+            .0
+            .iter()
+            .next()
+            .copied();
+
+        if selected_index == self.selected_index {
+            return;
+        }
+
+        self.selected_index = selected_index;
+
+        let entry = selected_index.and_then(|index| {
+            ctx.widget()
+                .get::<DirectoryEntries>("entries")
+                .0
+                .get(index)
+                .cloned()
+        });
+
+        if let Some(entry) = entry {
+            let path = self.path.join(&entry.name);
+
+            if entry.is_dir {
+                self.navigate(path);
+            } else {
+                ctx.widget()
+                    .set("selected_path", path.to_string_lossy().to_string());
+            }
+        }
+    }
+}
+
+impl State for FileBrowserState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let path = PathBuf::from(ctx.widget().get::<String>("path"));
+        ctx.widget()
+            .get_mut::<TreeNodes>("directory_nodes")
+            .push(None);
+        ctx.widget()
+            .get_mut::<DirectoryPaths>("directory_paths")
+            .0
+            .push(path);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let path = PathBuf::from(ctx.widget().get::<String>("path"));
+        let request_path = self.request_path.borrow_mut().take();
+
+        if let Some(request_path) = request_path {
+            ctx.widget()
+                .set("path", request_path.to_string_lossy().to_string());
+            self.path = request_path.clone();
+            self.read_directory(ctx, request_path);
+            self.update_breadcrumb(ctx);
+        } else if path != self.path {
+            self.path = path.clone();
+            self.read_directory(ctx, path);
+            self.update_breadcrumb(ctx);
+        }
+
+        let result = self.receiver.as_ref().and_then(|r| r.try_recv().ok());
+
+        if let Some((path, entries)) = result {
+            self.receiver = None;
+            self.update_entries(ctx, &path, entries.unwrap_or_default());
+
+            // the selection of the file list is cleared after the list is rebuild
+            return;
+        }
+
+        self.update_selection(ctx);
+    }
+}
+
+widget!(
+    /// The `FileBrowser` shows a directory tree, the files of the current directory and a
+    /// breadcrumb of the current path. The directories are read in the background. Selecting a
+    /// directory in the file list navigates into it, selecting a file sets the selected path.
+    ///
+    /// **CSS element:** `file-browser`
+    FileBrowser<FileBrowserState> {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the path of the current directory.
+        path: String,
+
+        /// Sets or shares the path of the selected file.
+        selected_path: String,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for FileBrowser {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        ctx.register_property("directory_nodes", id, TreeNodes::new());
+        ctx.register_property("directory_paths", id, DirectoryPaths::default());
+        ctx.register_property("entries", id, DirectoryEntries::default());
+        ctx.register_property("entries_count", id, 0_usize);
+
+        let current_dir = std::env::current_dir()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        self.name("FileBrowser")
+            .selector("file-browser")
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding(4.0)
+            .path(current_dir)
+            .selected_path("")
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .opacity(id)
+                    .child(
+                        Grid::create()
+                            .rows(Rows::create().row("auto").row(4.0).row("*").build())
+                            .columns(
+                                Columns::create()
+                                    .column(200.0)
+                                    .column(4.0)
+                                    .column("*")
+                                    .build(),
+                            )
+                            .child(
+                                Stack::create()
+                                    .selector(Selector::default().id("breadcrumb"))
+                                    .orientation("horizontal")
+                                    .attach(Grid::row(0))
+                                    .attach(Grid::column_span(3))
+                                    .build(ctx),
+                            )
+                            .child(
+                                TreeView::create()
+                                    .attach(Grid::row(2))
+                                    .nodes(("directory_nodes", id))
+                                    .items_builder(move |bc, node| {
+                                        let path = bc
+                                            .get_widget(id)
+                                            .get::<DirectoryPaths>("directory_paths")
+                                            .0[node]
+                                            .clone();
+                                        let text = path
+                                            .file_name()
+                                            .map(|n| n.to_string_lossy().to_string())
+                                            .unwrap_or_else(|| path.to_string_lossy().to_string());
+
+                                        Button::create()
+                                            .selector("file-browser-directory")
+                                            .min_width(0.0)
+                                            .height(20.0)
+                                            .vertical_alignment("center")
+                                            .icon(material_font_icons::FOLDER_FONT_ICON)
+                                            .text(text)
+                                            .on_click(move |states, _| {
+                                                states
+                                                    .get::<FileBrowserState>(id)
+                                                    .navigate(path.clone());
+                                                true
+                                            })
+                                            .build(bc)
+                                    })
+                                    // the directory tree reflects the file system and could not be rearranged
+                                    .drop_validator(|_, _, _, _| false)
+                                    .build(ctx),
+                            )
+                            .child(
+                                ListView::create()
+                                    .selector(Selector::from("list-view").id("file_list"))
+                                    .attach(Grid::row(2))
+                                    .attach(Grid::column(2))
+                                    .count(("entries_count", id))
+                                    .items_builder(move |bc, index| {
+                                        let entry =
+                                            bc.get_widget(id).get::<DirectoryEntries>("entries").0
+                                                [index]
+                                                .clone();
+                                        let icon = if entry.is_dir {
+                                            material_font_icons::FOLDER_FONT_ICON
+                                        } else {
+                                            material_font_icons::FILE_FONT_ICON
+                                        };
+
+                                        Stack::create()
+                                            .orientation("horizontal")
+                                            .margin((4.0, 0.0, 0.0, 0.0))
+                                            .child(
+                                                FontIconBlock::create()
+                                                    .vertical_alignment("center")
+                                                    .margin((0.0, 0.0, 4.0, 0.0))
+                                                    .icon(icon)
+                                                    .build(bc),
+                                            )
+                                            .child(
+                                                TextBlock::create()
+                                                    .vertical_alignment("center")
+                                                    .text(entry.name)
+                                                    .build(bc),
+                                            )
+                                            .build(bc)
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -22,6 +22,28 @@ widget!(
     }
 );
 
+impl FontIconBlock {
+    /// Sets the glyph, font, size and brush of the icon at once.
+    pub fn font_icon(self, icon: impl Into<Icon>) -> Self {
+        let icon = icon.into();
+        let mut this = self.icon(icon.glyph);
+
+        if let Some(font) = icon.font {
+            this = this.icon_font(font);
+        }
+
+        if let Some(size) = icon.size {
+            this = this.icon_size(size);
+        }
+
+        if let Some(brush) = icon.brush {
+            this = this.icon_brush(brush);
+        }
+
+        this
+    }
+}
+
 impl Template for FontIconBlock {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
         self.name("FontIconBlock")
@@ -0,0 +1,532 @@
+use std::cmp::Ordering;
+
+use crate::prelude::*;
+
+// Width of the markers of the gradient stops.
+const MARKER_WIDTH: f64 = 10.0;
+
+#[derive(Copy, Clone)]
+enum GradientEditorAction {
+    MouseDown(Point),
+    MouseMove(Point),
+    MouseUp,
+    Remove,
+    SetColor,
+}
+
+/// The `GradientEditorState` adds, moves and removes the stops of the edited gradient.
+#[derive(Default, AsAny)]
+pub struct GradientEditorState {
+    actions: Vec<GradientEditorAction>,
+    stops: Vec<LinearGradientStop>,
+    selected: usize,
+    dragging: bool,
+    brush: Brush,
+    markers: Vec<Entity>,
+    preview_rect: Rectangle,
+    dirty: bool,
+}
+
+impl GradientEditorState {
+    fn action(&mut self, action: GradientEditorAction) {
+        self.actions.push(action);
+    }
+
+    // Returns the absolute bounds of the child with the given id.
+    fn child_rect(&self, ctx: &mut Context<'_>, id: &str) -> Rectangle {
+        let child = ctx.child(id);
+        let position = child.get::<Point>("position");
+        let bounds = child.get::<Rectangle>("bounds");
+
+        Rectangle::new(position.x, position.y, bounds.width(), bounds.height())
+    }
+
+    // Loads the stops if the brush is changed from outside.
+    fn load(&mut self, ctx: &mut Context<'_>) {
+        let brush = ctx.widget().clone::<Brush>("brush");
+
+        if brush == self.brush {
+            return;
+        }
+
+        self.stops = match &brush {
+            Brush::LinearGradient { stops, .. } => stops.clone(),
+            Brush::SolidColor(color) => vec![
+                LinearGradientStop {
+                    position: 0.0,
+                    color: *color,
+                },
+                LinearGradientStop {
+                    position: 1.0,
+                    color: *color,
+                },
+            ],
+        };
+
+        self.stops.sort_by(|a, b| {
+            a.position
+                .partial_cmp(&b.position)
+                .unwrap_or(Ordering::Equal)
+        });
+        self.selected = self.selected.min(self.stops.len().saturating_sub(1));
+        self.brush = brush;
+        self.dirty = true;
+    }
+
+    // Writes the stops to the brush property and raises the changed event.
+    fn apply(&mut self, ctx: &mut Context<'_>) {
+        let (start, end) = match ctx.widget().get::<Brush>("brush") {
+            Brush::LinearGradient { start, end, .. } => (*start, *end),
+            _ => (Point::new(0.0, 0.0), Point::new(100.0, 0.0)),
+        };
+
+        self.brush = Brush::LinearGradient {
+            start,
+            end,
+            stops: self.stops.clone(),
+        };
+        self.dirty = true;
+
+        ctx.widget().set("brush", self.brush.clone());
+        ctx.push_event_strategy_by_entity(
+            ChangedEvent(ctx.entity),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    fn mouse_down(&mut self, ctx: &mut Context<'_>, position: Point) {
+        let preview = self.child_rect(ctx, "preview");
+        let markers = self.child_rect(ctx, "markers");
+
+        if preview.width() <= 0.0 {
+            return;
+        }
+
+        // selects the marker under the mouse
+        if markers.contains((position.x, position.y)) {
+            let nearest = self
+                .stops
+                .iter()
+                .map(|stop| (preview.x() + stop.position * preview.width() - position.x).abs())
+                .enumerate()
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+
+            if let Some((index, distance)) = nearest {
+                if distance <= MARKER_WIDTH {
+                    self.selected = index;
+                    self.dragging = true;
+                    self.dirty = true;
+                }
+            }
+
+            return;
+        }
+
+        // adds a new stop with the color of the gradient at the clicked position
+        if preview.contains((position.x, position.y)) {
+            let stop_position = (position.x - preview.x()) / preview.width();
+            self.selected = insert_stop(&mut self.stops, stop_position);
+            self.dragging = true;
+            self.apply(ctx);
+        }
+    }
+
+    fn mouse_move(&mut self, ctx: &mut Context<'_>, position: Point) {
+        if !self.dragging || self.selected >= self.stops.len() {
+            return;
+        }
+
+        let preview = self.child_rect(ctx, "preview");
+
+        if preview.width() <= 0.0 {
+            return;
+        }
+
+        self.stops[self.selected].position = ((position.x - preview.x()) / preview.width())
+            .max(0.0)
+            .min(1.0);
+        self.selected = sort_stops(&mut self.stops, self.selected);
+        self.apply(ctx);
+    }
+
+    fn remove(&mut self, ctx: &mut Context<'_>) {
+        // a gradient needs at least two stops
+        if self.stops.len() <= 2 {
+            return;
+        }
+
+        self.stops.remove(self.selected);
+        self.selected = self.selected.min(self.stops.len() - 1);
+        self.apply(ctx);
+    }
+
+    fn set_color(&mut self, ctx: &mut Context<'_>) {
+        let text = ctx.child("color_input").get::<String16>("text").to_string();
+
+        match (
+            parse_color(text.as_str()),
+            self.stops.get_mut(self.selected),
+        ) {
+            (Some(color), Some(stop)) => {
+                stop.color = color;
+                self.apply(ctx);
+            }
+            // reverts invalid input
+            _ => self.dirty = true,
+        }
+    }
+
+    // Rebuilds the markers if the number of stops is changed and moves them to their positions.
+    fn update_markers(&mut self, ctx: &mut Context<'_>) {
+        let markers_panel = if let Some(markers_panel) = ctx.entity_of_child("markers") {
+            markers_panel
+        } else {
+            return;
+        };
+
+        if self.markers.len() != self.stops.len() {
+            ctx.clear_children_of(markers_panel);
+            self.markers.clear();
+
+            for _ in 0..self.stops.len() {
+                let bctx = &mut ctx.build_context();
+                let marker = Container::create()
+                    .selector("gradient-editor-stop")
+                    .horizontal_alignment("start")
+                    .width(MARKER_WIDTH)
+                    .border_width(2.0)
+                    .border_radius(2.0)
+                    .build(bctx);
+                bctx.append_child(markers_panel, marker);
+                self.markers.push(marker);
+            }
+        }
+
+        let width = ctx
+            .get_widget(markers_panel)
+            .get::<Rectangle>("bounds")
+            .width();
+
+        for (index, (stop, marker)) in self.stops.iter().zip(self.markers.iter()).enumerate() {
+            let left = (stop.position * width - MARKER_WIDTH / 2.0)
+                .max(0.0)
+                .min((width - MARKER_WIDTH).max(0.0));
+            let border_brush = if index == self.selected {
+                colors::LINK_WATER_COLOR
+            } else {
+                colors::BRIGHT_GRAY_COLOR
+            };
+
+            let mut marker = ctx.get_widget(*marker);
+            marker.set("margin", Thickness::new(left, 0.0, 0.0, 0.0));
+            marker.set("background", Brush::from(stop.color));
+            marker.set("border_brush", Brush::from(border_brush));
+        }
+
+        if let Some(stop) = self.stops.get(self.selected) {
+            ctx.child("color_input")
+                .set("text", String16::from(stop.color.to_string()));
+        }
+    }
+}
+
+impl State for GradientEditorState {
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.load(ctx);
+
+        let actions = std::mem::replace(&mut self.actions, vec![]);
+
+        for action in actions {
+            match action {
+                GradientEditorAction::MouseDown(position) => self.mouse_down(ctx, position),
+                GradientEditorAction::MouseMove(position) => self.mouse_move(ctx, position),
+                GradientEditorAction::MouseUp => self.dragging = false,
+                GradientEditorAction::Remove => self.remove(ctx),
+                GradientEditorAction::SetColor => self.set_color(ctx),
+            }
+        }
+
+        // the gradient of the preview is drawn in absolute coordinates
+        let preview_rect = self.child_rect(ctx, "preview");
+
+        if preview_rect != self.preview_rect {
+            self.preview_rect = preview_rect;
+            self.dirty = true;
+        }
+
+        if !self.dirty {
+            return;
+        }
+
+        self.dirty = false;
+
+        ctx.child("preview").set(
+            "background",
+            Brush::LinearGradient {
+                start: Point::new(preview_rect.x(), preview_rect.y()),
+                end: Point::new(preview_rect.x() + preview_rect.width(), preview_rect.y()),
+                stops: self.stops.clone(),
+            },
+        );
+
+        self.update_markers(ctx);
+
+        let _ = ctx.request_sender().send(shell::ShellRequest::Update);
+    }
+}
+
+widget!(
+    /// The `GradientEditor` is used to edit the stops of a linear gradient. Clicking the preview
+    /// adds a stop, the markers below the preview could be dragged to move a stop. The color of
+    /// the selected stop is entered as hex value, e.g. `#efd035`.
+    ///
+    /// Only the stops of the brush are edited, its start and end point are kept.
+    ///
+    /// **CSS element:** `gradient-editor`
+    GradientEditor<GradientEditorState>: ChangedHandler, MouseHandler {
+        /// Sets or shares the edited gradient brush.
+        brush: Brush,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for GradientEditor {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("GradientEditor")
+            .selector("gradient-editor")
+            .brush(Brush::LinearGradient {
+                start: Point::new(0.0, 0.0),
+                end: Point::new(100.0, 0.0),
+                stops: vec![
+                    LinearGradientStop {
+                        position: 0.0,
+                        color: Color::rgb(0, 0, 0),
+                    },
+                    LinearGradientStop {
+                        position: 1.0,
+                        color: Color::rgb(255, 255, 255),
+                    },
+                ],
+            })
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(4.0)
+            .min_width(200.0)
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(
+                        Stack::create()
+                            .spacing(4.0)
+                            .child(
+                                Container::create()
+                                    .selector(
+                                        Selector::from("gradient-editor-preview").id("preview"),
+                                    )
+                                    .height(24.0)
+                                    .border_width(1.0)
+                                    .border_brush(colors::BOMBAY_COLOR)
+                                    .build(ctx),
+                            )
+                            .child(
+                                Grid::create()
+                                    .selector(Selector::default().id("markers"))
+                                    .height(16.0)
+                                    .build(ctx),
+                            )
+                            .child(
+                                Grid::create()
+                                    .columns(
+                                        Columns::create()
+                                            .column("*")
+                                            .column(8.0)
+                                            .column("auto")
+                                            .build(),
+                                    )
+                                    .child(
+                                        TextBox::create()
+                                            .selector(Selector::from("text-box").id("color_input"))
+                                            .min_width(0.0)
+                                            .on_activate(move |states, _| {
+                                                states
+                                                    .get_mut::<GradientEditorState>(id)
+                                                    .action(GradientEditorAction::SetColor);
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        Button::create()
+                                            .attach(Grid::column(2))
+                                            .text("Remove")
+                                            .on_click(move |states, _| {
+                                                states
+                                                    .get_mut::<GradientEditorState>(id)
+                                                    .action(GradientEditorAction::Remove);
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_mouse_down(move |states, p| {
+                states
+                    .get_mut::<GradientEditorState>(id)
+                    .action(GradientEditorAction::MouseDown(p));
+                false
+            })
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<GradientEditorState>(id)
+                    .action(GradientEditorAction::MouseMove(p));
+                false
+            })
+            .on_mouse_up(move |states, _| {
+                states
+                    .get_mut::<GradientEditorState>(id)
+                    .action(GradientEditorAction::MouseUp);
+                false
+            })
+    }
+}
+
+// --- Helpers --
+
+// Returns the color of the gradient at the given position.
+fn color_at(stops: &[LinearGradientStop], position: f64) -> Color {
+    match stops.iter().position(|stop| stop.position >= position) {
+        Some(0) => stops[0].color,
+        Some(index) => {
+            let (start, end) = (&stops[index - 1], &stops[index]);
+            let span = end.position - start.position;
+            let scale = if span <= 0.0 {
+                0.0
+            } else {
+                (position - start.position) / span
+            };
+
+            Color::interpolate(start.color, end.color, scale)
+        }
+        None => stops
+            .last()
+            .map(|stop| stop.color)
+            .unwrap_or_else(|| Color::rgb(0, 0, 0)),
+    }
+}
+
+// Inserts a stop with the color of the gradient at the given position and returns its index.
+fn insert_stop(stops: &mut Vec<LinearGradientStop>, position: f64) -> usize {
+    let color = color_at(stops, position);
+    let index = stops
+        .iter()
+        .position(|stop| stop.position > position)
+        .unwrap_or_else(|| stops.len());
+
+    stops.insert(index, LinearGradientStop { position, color });
+    index
+}
+
+// Sorts the stops by position and returns the new index of the stop with the given index.
+fn sort_stops(stops: &mut Vec<LinearGradientStop>, index: usize) -> usize {
+    let stop = stops[index].clone();
+
+    stops.sort_by(|a, b| {
+        a.position
+            .partial_cmp(&b.position)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    stops.iter().position(|s| *s == stop).unwrap_or(0)
+}
+
+// Parses a hex color like `#efd035`, returns `None` if the text is not a valid color.
+pub(crate) fn parse_color(text: &str) -> Option<Color> {
+    let hex = text.trim().trim_start_matches('#');
+
+    if (hex.len() == 6 || hex.len() == 8) && u32::from_str_radix(hex, 16).is_ok() {
+        return Some(Color::from(hex));
+    }
+
+    None
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stops() -> Vec<LinearGradientStop> {
+        vec![
+            LinearGradientStop {
+                position: 0.0,
+                color: Color::rgb(0, 0, 0),
+            },
+            LinearGradientStop {
+                position: 1.0,
+                color: Color::rgb(200, 100, 0),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_color_at() {
+        assert_eq!(Color::rgb(0, 0, 0), color_at(&stops(), 0.0));
+        assert_eq!(Color::rgb(100, 50, 0), color_at(&stops(), 0.5));
+        assert_eq!(Color::rgb(200, 100, 0), color_at(&stops(), 2.0));
+    }
+
+    #[test]
+    fn test_insert_stop() {
+        let mut stops = stops();
+
+        assert_eq!(1, insert_stop(&mut stops, 0.5));
+        assert_eq!(3, stops.len());
+        assert_eq!(Color::rgb(100, 50, 0), stops[1].color);
+    }
+
+    #[test]
+    fn test_sort_stops() {
+        let mut stops = stops();
+        stops[0].position = 1.2;
+
+        assert_eq!(1, sort_stops(&mut stops, 0));
+        assert_eq!(Color::rgb(200, 100, 0), stops[0].color);
+        assert_eq!(0, sort_stops(&mut stops, 0));
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(Some(Color::rgb(0xef, 0xd0, 0x35)), parse_color("#efd035"));
+        assert_eq!(Some(Color::rgb(0xef, 0xd0, 0x35)), parse_color("efd035"));
+        assert_eq!(None, parse_color("#efd03"));
+        assert_eq!(None, parse_color("#xxxxxx"));
+    }
+}
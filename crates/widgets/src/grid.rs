@@ -14,8 +14,17 @@ widget!(
         /// Sets or shares the rows property.
         rows: Rows,
 
+        /// Sets or shares the auto flow property. If it is set to `row` or `column`, the children
+        /// without a column and a row are placed in order in the next free cell, additional rows
+        /// or columns are added as `auto`.
+        auto_flow: GridFlow,
+
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
+
+        /// Sets or shares the debug property. If it is set, the boundaries and the computed sizes
+        /// of the columns and rows are drawn on top of the children.
+        debug: bool,
 
         /// Sets or shares the css selector property.
         selector: Selector
@@ -41,13 +50,15 @@ impl Template for Grid {
         self.name("Grid")
             .selector("grid")
             .border_radius(0.0)
+            .debug(false)
             .background("transparent")
             .rows(Rows::default())
             .columns(Columns::default())
+            .auto_flow("none")
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
-        Box::new(RectangleRenderObject)
+        Box::new(GridRenderObject)
     }
 
     fn layout(&self) -> Box<dyn Layout> {
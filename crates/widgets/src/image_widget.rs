@@ -15,7 +15,10 @@ widget!(
 
 impl Template for ImageWidget {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
-        self.name("ImageWidget").selector("image-widget").image("")
+        self.name("ImageWidget")
+            .accessible_role("image")
+            .selector("image-widget")
+            .image("")
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
@@ -1,9 +1,59 @@
+use std::collections::HashMap;
+
 use crate::prelude::*;
 
+/// Returns the key of the item with the given index, see `ItemsWidget::items_key`.
+pub type ItemsKeyBuilder = Option<Box<dyn Fn(&mut BuildContext, usize) -> String + 'static>>;
+
 #[derive(Default, AsAny)]
 pub struct ItemsWidgetState {
     builder: WidgetBuildContext,
+    key_builder: ItemsKeyBuilder,
     count: usize,
+
+    // keys and entities of the items in their order, only used with a key builder
+    items: Vec<(String, Entity)>,
+}
+
+impl ItemsWidgetState {
+    // Reuses the entities of the items with an unchanged key, builds the items with a new key and
+    // removes the items whose key is gone.
+    fn update_keyed_items(&mut self, ctx: &mut Context<'_>, items_panel: Entity, count: usize) {
+        let (builder, key_builder) = match (&self.builder, &self.key_builder) {
+            (Some(builder), Some(key_builder)) => (builder, key_builder),
+            _ => return,
+        };
+
+        let mut old_items: HashMap<String, Entity> = self.items.drain(..).collect();
+        let mut items = Vec::with_capacity(count);
+
+        for index in 0..count {
+            let bctx = &mut ctx.build_context();
+            let key = key_builder(bctx, index);
+
+            let child = if let Some(child) = old_items.remove(&key) {
+                child
+            } else {
+                let child = builder(bctx, index);
+                bctx.append_child(items_panel, child);
+                child
+            };
+
+            items.push((key, child));
+        }
+
+        for child in old_items.values() {
+            ctx.remove_child_from(*child, items_panel);
+        }
+
+        for (index, (_, child)) in items.iter().enumerate() {
+            if ctx.index_as_child(*child) != Some(index) {
+                let _ = ctx.move_child_entity_to(*child, items_panel, index);
+            }
+        }
+
+        self.items = items;
+    }
 }
 
 impl State for ItemsWidgetState {
@@ -12,8 +62,10 @@ impl State for ItemsWidgetState {
         let request_update: bool = *ctx.widget().get("request_update");
 
         if count != self.count || request_update {
-            if let Some(builder) = &self.builder {
-                if let Some(items_panel) = ctx.entity_of_child("items_panel") {
+            if let Some(items_panel) = ctx.entity_of_child("items_panel") {
+                if self.key_builder.is_some() {
+                    self.update_keyed_items(ctx, items_panel, count);
+                } else if let Some(builder) = &self.builder {
                     ctx.clear_children_of(items_panel);
 
                     for i in 0..count {
@@ -40,7 +92,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -73,6 +125,17 @@ impl ItemsWidget {
         self.state_mut().builder = Some(Box::new(builder));
         self
     }
+
+    /// Sets a function that returns a unique key of the item with the given index. If the items
+    /// are updated, the entities of items with an unchanged key are kept with their focus, scroll
+    /// position and state. Only items with a new key are built by the items builder.
+    pub fn items_key<F: Fn(&mut BuildContext, usize) -> String + 'static>(
+        mut self,
+        key: F,
+    ) -> Self {
+        self.state_mut().key_builder = Some(Box::new(key));
+        self
+    }
 }
 
 impl Template for ItemsWidget {
@@ -13,45 +13,99 @@ pub use orbtk_shell::prelude as shell;
 pub use orbtk_theme::prelude as theme;
 pub use orbtk_utils::prelude as utils;
 
+pub use self::auto_complete::*;
+pub use self::badge::*;
 pub use self::button::*;
+pub use self::calendar::*;
 pub use self::canvas::*;
+pub use self::chart::*;
 pub use self::check_box::*;
+pub use self::code_editor::*;
+pub use self::color_editor::*;
 pub use self::container::*;
 pub use self::cursor::*;
+pub use self::date_picker::*;
+pub use self::detail_view::*;
+pub use self::dialog_host::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::file_browser::*;
 pub use self::font_icon_block::*;
+pub use self::gradient_editor::*;
 pub use self::grid::*;
 pub use self::image_widget::*;
 pub use self::items_widget::*;
 pub use self::list_view::*;
+pub use self::menu::*;
+pub use self::navigation_view::*;
+pub use self::numeric_box::*;
+pub use self::pager::*;
+pub use self::password_box::*;
+pub use self::property_inspector::*;
+pub use self::render_surface::*;
 pub use self::scroll_bar::*;
 pub use self::scroll_indicator::*;
 pub use self::scroll_viewer::*;
 pub use self::slider::*;
 pub use self::stack::*;
+pub use self::svg_image::*;
 pub use self::switch::*;
+pub use self::table_view::*;
 pub use self::text_block::*;
 pub use self::text_box::*;
+pub use self::toast::*;
 pub use self::toggle_button::*;
+pub use self::tool_bar::*;
+pub use self::tree_view::*;
+pub use self::ui_loader::*;
+pub use self::video_view::*;
 pub use self::window::*;
+pub use self::window_handle::*;
 
+mod auto_complete;
+mod badge;
 pub mod behaviors;
 mod button;
+mod calendar;
 mod canvas;
+mod chart;
 mod check_box;
+mod code_editor;
+mod color_editor;
 mod container;
 mod cursor;
+mod date_picker;
+mod detail_view;
+mod dialog_host;
+#[cfg(not(target_arch = "wasm32"))]
+mod file_browser;
 mod font_icon_block;
+mod gradient_editor;
 mod grid;
 mod image_widget;
 mod items_widget;
 mod list_view;
+mod menu;
+mod navigation_view;
+mod numeric_box;
+mod pager;
+mod password_box;
+mod property_inspector;
+mod render_surface;
 mod scroll_bar;
 mod scroll_indicator;
 mod scroll_viewer;
 mod slider;
 mod stack;
+mod svg_image;
 mod switch;
+mod table_view;
 mod text_block;
 mod text_box;
+mod toast;
 mod toggle_button;
+mod tool_bar;
+mod tree_view;
+mod ui_loader;
+mod video_view;
 mod window;
+mod window_handle;
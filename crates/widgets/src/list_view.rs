@@ -4,25 +4,252 @@ use std::{
 };
 
 use super::behaviors::MouseBehavior;
-use crate::{prelude::*, utils::SelectionMode as SelMode};
+use crate::{prelude::*, shell::Key, utils::SelectionMode as SelMode};
+
+// Returns the index of the item that is selected by the given key or `None` if the key does not
+// navigate. The keys move to the previous and next item, to the first and last item or by a page
+// of items. Without a current item the first item is selected.
+fn navigate_index(
+    key: Key,
+    (previous, next): (Key, Key),
+    current: Option<usize>,
+    count: usize,
+    page_size: usize,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    let page_size = page_size.max(1);
+    let index = match key {
+        Key::Home => 0,
+        Key::End => count - 1,
+        Key::PageUp => current.map_or(0, |i| i.saturating_sub(page_size)),
+        Key::PageDown => current.map_or(0, |i| i + page_size),
+        k if k == previous => current.map_or(0, |i| i.saturating_sub(1)),
+        k if k == next => current.map_or(0, |i| i + 1),
+        _ => return None,
+    };
+
+    Some(index.min(count - 1))
+}
 
 #[derive(Default, AsAny)]
 pub struct ListViewState {
     builder: WidgetBuildContext,
     count: usize,
     selected_entities: RefCell<HashSet<Entity>>,
+    selected_indices: HashSet<usize>,
+    // entities of the built items.
+    items: Vec<Entity>,
+    scroll_viewer: Option<Entity>,
+    // item that is moved by the keyboard and start of the range that is selected with Shift.
+    current: Option<usize>,
+    anchor: Option<usize>,
+    // number of items that fit into the visible area.
+    page_size: usize,
+    scroll_into_view: bool,
+    request_focus: Cell<bool>,
+    request_key: Cell<Option<Key>>,
+}
+
+impl ListViewState {
+    fn focus(&self) {
+        self.request_focus.set(true);
+    }
+
+    pub(crate) fn key(&self, key: Key) {
+        self.request_key.set(Some(key));
+    }
+
+    fn request_focus(&self, ctx: &mut Context<'_>) {
+        let focused_widget = ctx.window().get::<Global>("global").focused_widget;
+
+        if focused_widget == Some(ctx.entity) || !ctx.widget().get::<bool>("enabled") {
+            return;
+        }
+
+        if let Some(old_focused_element) = focused_widget {
+            let mut old_focused_element = ctx.get_widget(old_focused_element);
+            old_focused_element.set("focused", false);
+            old_focused_element.update_theme_by_state(false);
+        }
+
+        ctx.window().get_mut::<Global>("global").focused_widget = Some(ctx.entity);
+        ctx.widget().set("focused", true);
+        ctx.widget().update_theme_by_state(false);
+    }
+
+    // Selects the item of the navigation key and scrolls it into the visible area after the
+    // next layout pass. With Shift the range from the anchor is selected in multiple mode.
+    fn navigate(&mut self, key: Key, ctx: &mut Context<'_>) {
+        let selection_mode = *ctx.widget().get::<SelectionMode>("selection_mode");
+
+        if selection_mode == SelMode::None || !ctx.widget().get::<bool>("enabled") {
+            return;
+        }
+
+        let keys = match *ctx.widget().get::<Orientation>("orientation") {
+            Orientation::Horizontal if ctx.flow_direction().is_right_to_left() => {
+                (Key::Right, Key::Left)
+            }
+            Orientation::Horizontal => (Key::Left, Key::Right),
+            Orientation::Vertical => (Key::Up, Key::Down),
+        };
+
+        let count = self.items.len();
+        let index = match navigate_index(key, keys, self.current, count, self.page_size) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let extend_selection = selection_mode == SelMode::Multiple
+            && ctx
+                .window()
+                .get::<Global>("global")
+                .keyboard_state
+                .is_shift_down();
+        let anchor = if extend_selection {
+            self.anchor.or(self.current).unwrap_or(index)
+        } else {
+            index
+        };
+
+        self.anchor = Some(anchor);
+        self.current = Some(index);
+        self.scroll_into_view = true;
+
+        let range = anchor.min(index)..=anchor.max(index);
+        let entities = range.clone().map(|i| self.items[i]).collect();
+        ctx.widget()
+            .set("selected_indices", SelectedIndices(range.collect()));
+        ctx.widget()
+            .set("selected_entities", SelectedEntities(entities));
+    }
+
+    // Raises a selection changed event if the selected indices are changed since the last call.
+    // An item selected by a click becomes the current item of the keyboard navigation.
+    fn update_selection(&mut self, ctx: &mut Context<'_>) {
+        let indices = ctx
+            .widget()
+            .get::<SelectedIndices>("selected_indices")
+            .0
+            .clone();
+
+        if indices == self.selected_indices {
+            return;
+        }
+
+        // the selection of the keyboard navigation is scrolled into view, other changes are
+        // made by a click
+        if !self.scroll_into_view {
+            if let Some(added) = indices.difference(&self.selected_indices).min() {
+                self.current = Some(*added);
+                self.anchor = self.current;
+            }
+        }
+
+        let mut sorted: Vec<usize> = indices.iter().cloned().collect();
+        sorted.sort_unstable();
+        self.selected_indices = indices;
+
+        ctx.push_event_strategy_by_entity(
+            SelectionChangedEvent(sorted),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    // Updates the page size and scrolls the current item into the visible area if it is
+    // requested by the keyboard navigation.
+    fn update_scroll(&mut self, ctx: &mut Context<'_>) {
+        let scroll_viewer = match self.scroll_viewer {
+            Some(scroll_viewer) => scroll_viewer,
+            None => return,
+        };
+
+        let vertical = *ctx.widget().get::<Orientation>("orientation") == Orientation::Vertical;
+        let extent = |bounds: Rectangle| {
+            if vertical {
+                (bounds.y(), bounds.height())
+            } else {
+                (bounds.x(), bounds.width())
+            }
+        };
+
+        let (_, viewport) = extent(*ctx.get_widget(scroll_viewer).get::<Rectangle>("bounds"));
+
+        if let Some(item) = self.items.first() {
+            let (_, item_extent) = extent(*ctx.get_widget(*item).get::<Rectangle>("bounds"));
+
+            if item_extent > 0.0 {
+                self.page_size = (viewport / item_extent).floor() as usize;
+            }
+        }
+
+        if !self.scroll_into_view {
+            return;
+        }
+        self.scroll_into_view = false;
+
+        let item = match self.current.and_then(|current| self.items.get(current)) {
+            Some(item) => *item,
+            None => return,
+        };
+
+        let (start, item_extent) = extent(*ctx.get_widget(item).get::<Rectangle>("bounds"));
+        let mut scroll_offset = *ctx.get_widget(scroll_viewer).get::<Point>("scroll_offset");
+        let offset = if vertical {
+            &mut scroll_offset.y
+        } else {
+            &mut scroll_offset.x
+        };
+
+        let new_offset = if start + *offset < 0.0 {
+            -start
+        } else if start + item_extent + *offset > viewport {
+            viewport - start - item_extent
+        } else {
+            return;
+        };
+
+        *offset = new_offset;
+        ctx.get_widget(scroll_viewer)
+            .set("scroll_offset", scroll_offset);
+        let _ = ctx.request_sender().send(shell::ShellRequest::Update);
+    }
 }
 
 impl State for ListViewState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if self.request_focus.take() {
+            self.request_focus(ctx);
+        }
+
         let count = ctx.widget().clone_or_default::<usize>("count");
+        let request_update = *ctx.widget().get::<bool>("request_update");
         let entity = ctx.entity;
 
-        if count != self.count {
+        if count != self.count || request_update {
             if let Some(builder) = &self.builder {
                 if let Some(items_panel) = ctx.entity_of_child("items_panel") {
                     ctx.clear_children_of(items_panel);
 
+                    // the selection refers to the removed items
+                    ctx.widget()
+                        .get_mut::<SelectedEntities>("selected_entities")
+                        .0
+                        .clear();
+                    ctx.widget()
+                        .get_mut::<SelectedIndices>("selected_indices")
+                        .0
+                        .clear();
+                    self.selected_entities.borrow_mut().clear();
+                    self.selected_indices.clear();
+                    self.items.clear();
+                    self.current = None;
+                    self.anchor = None;
+
                     for i in 0..count {
                         let item = {
                             let build_context = &mut ctx.build_context();
@@ -56,11 +283,17 @@ impl State for ListViewState {
                             item
                         };
                         ctx.get_widget(item).update_properties_by_theme();
+                        self.items.push(item);
                     }
                 }
             }
 
             self.count = count;
+            ctx.widget().set("request_update", false);
+        }
+
+        if let Some(key) = self.request_key.take() {
+            self.navigate(key, ctx);
         }
     }
 
@@ -83,6 +316,9 @@ impl State for ListViewState {
             .get::<SelectedEntities>("selected_entities")
             .0
             .clone();
+
+        self.update_selection(ctx);
+        self.update_scroll(ctx);
     }
 }
 
@@ -162,7 +398,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -196,6 +432,7 @@ widget!(
 impl Template for ListViewItem {
     fn template(self, id: Entity, _: &mut BuildContext) -> Self {
         self.name("ListViewItem")
+            .accessible_role("list-item")
             .min_width(64.0)
             .height(24.0)
             .selected(false)
@@ -227,13 +464,19 @@ impl Template for ListViewItem {
 widget!(
     /// The `ListView` is an items drawer widget with selectable items.
     ///
+    /// If the list is focused, the arrow keys in the direction of the orientation select the
+    /// previous or next item, `Home` and `End` the first or last item and `PageUp` and `PageDown`
+    /// move the selection by the number of visible items. The selected item is scrolled into
+    /// view. In multiple selection mode `Shift` extends the selection. Each change of the
+    /// selection raises a selection changed event with the selected indices.
+    ///
     /// **CSS element:** `items-widget`
-    ListView<ListViewState> {
+    ListView<ListViewState>: KeyDownHandler, MouseHandler, SelectionChangedHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -250,6 +493,9 @@ widget!(
         /// Sets or shared the count.
         count: usize,
 
+        /// Sets or shares a value to request an update of the items.
+        request_update: bool,
+
         /// Sets or shares the css selector property.
         selector: Selector,
 
@@ -263,7 +509,10 @@ widget!(
         selected_entities: SelectedEntities,
 
         /// Sets or shares the (wheel, scroll) delta property.
-        delta: Point
+        delta: Point,
+
+        /// Sets or shares the focused property.
+        focused: bool
     }
 );
 
@@ -278,7 +527,7 @@ impl ListView {
 }
 
 impl Template for ListView {
-    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
         let items_panel = Stack::create()
             .vertical_alignment("start")
             .selector(Selector::default().id("items_panel"))
@@ -291,7 +540,10 @@ impl Template for ListView {
             .child(items_panel)
             .build(ctx);
 
+        self.state_mut().scroll_viewer = Some(scroll_viewer);
+
         self.name("ListView")
+            .accessible_role("list")
             .selector(Selector::from("list-view").id("ListView"))
             .background(colors::LYNCH_COLOR)
             .border_radius(2.0)
@@ -302,7 +554,17 @@ impl Template for ListView {
             .selected_indices(HashSet::new())
             .selected_entities(HashSet::new())
             .delta(0.0)
+            .request_update(false)
             .orientation("vertical")
+            .focused(false)
+            .on_mouse_down(move |states, _| {
+                states.get::<ListViewState>(id).focus();
+                false
+            })
+            .on_key_down(move |states, event| {
+                states.get::<ListViewState>(id).key(event.key);
+                false
+            })
             .child(
                 Container::create()
                     .background(id)
@@ -324,3 +586,25 @@ impl Template for ListView {
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigate_index() {
+        let keys = (Key::Up, Key::Down);
+
+        assert_eq!(navigate_index(Key::Down, keys, None, 10, 4), Some(0));
+        assert_eq!(navigate_index(Key::Down, keys, Some(2), 10, 4), Some(3));
+        assert_eq!(navigate_index(Key::Up, keys, Some(0), 10, 4), Some(0));
+        assert_eq!(navigate_index(Key::End, keys, Some(2), 10, 4), Some(9));
+        assert_eq!(navigate_index(Key::Home, keys, Some(2), 10, 4), Some(0));
+        assert_eq!(navigate_index(Key::PageDown, keys, Some(8), 10, 4), Some(9));
+        assert_eq!(navigate_index(Key::PageUp, keys, Some(5), 10, 4), Some(1));
+
+        // keys of the other orientation and empty lists are not handled
+        assert_eq!(navigate_index(Key::Left, keys, Some(2), 10, 4), None);
+        assert_eq!(navigate_index(Key::Down, keys, None, 0, 4), None);
+    }
+}
@@ -0,0 +1,769 @@
+use std::cell::{Cell, RefCell};
+
+use super::behaviors::MouseBehavior;
+use crate::{
+    prelude::*,
+    shell::{Key, KeyEvent},
+};
+
+// --- Helpers ---
+
+// Builds the popup that displays the given items and appends it collapsed to the overlay.
+fn build_popup(owner: Entity, items: &[Entity], ctx: &mut BuildContext) -> Entity {
+    let mut items_panel = Stack::create().orientation("vertical");
+
+    for item in items {
+        items_panel = items_panel.child(*item);
+    }
+
+    let popup = Container::create()
+        .selector("menu-popup")
+        .horizontal_alignment("start")
+        .vertical_alignment("start")
+        .visibility("collapsed")
+        .min_width(160.0)
+        .padding((0.0, 4.0, 0.0, 4.0))
+        .background(colors::LYNCH_COLOR)
+        .border_width(1.0)
+        .border_brush(colors::BOMBAY_COLOR)
+        .child(items_panel.build(ctx))
+        .build(ctx);
+
+    ctx.register_property("owner", popup, owner);
+    ctx.register_property("popup", owner, popup);
+    ctx.register_property("items", owner, items.to_vec());
+
+    if ctx.append_child_to_overlay(popup).is_err() {
+        ctx.append_child(owner, popup);
+    }
+
+    popup
+}
+
+// Opens the popup of the given owner at the given global position.
+fn open_popup(ctx: &mut Context<'_>, owner: Entity, position: Point) {
+    if let Some(popup) = ctx.get_widget(owner).try_clone::<Entity>("popup") {
+        let mut popup = ctx.get_widget(popup);
+        popup.set("margin", Thickness::new(position.x, position.y, 0.0, 0.0));
+        popup.set("visibility", Visibility::Visible);
+    }
+
+    let _ = ctx.request_sender().send(shell::ShellRequest::Update);
+}
+
+// Closes the popup of the given owner and all popups of its sub menus.
+fn close_popup(ctx: &mut Context<'_>, owner: Entity) {
+    let (popup, items) = {
+        let widget = ctx.get_widget(owner);
+        (
+            widget.try_clone::<Entity>("popup"),
+            widget.clone_or_default::<Vec<Entity>>("items"),
+        )
+    };
+
+    if let Some(popup) = popup {
+        ctx.get_widget(popup)
+            .set("visibility", Visibility::Collapsed);
+    }
+
+    for item in items {
+        select_item(ctx, item, false);
+        close_popup(ctx, item);
+    }
+
+    let _ = ctx.request_sender().send(shell::ShellRequest::Update);
+}
+
+// Checks if the popup of the given owner is open.
+fn is_popup_open(ctx: &mut Context<'_>, owner: Entity) -> bool {
+    if let Some(popup) = ctx.get_widget(owner).try_clone::<Entity>("popup") {
+        return *ctx.get_widget(popup).get::<Visibility>("visibility") == Visibility::Visible;
+    }
+
+    false
+}
+
+// Returns the top level menu of the current menu item.
+fn root_menu(ctx: &mut Context<'_>) -> Entity {
+    let entity = ctx.entity;
+    let mut root = entity;
+
+    while let Some(popup) = ctx.parent_entity_by_element("menu-popup") {
+        if let Some(owner) = ctx.get_widget(popup).try_clone::<Entity>("owner") {
+            root = owner;
+            ctx.entity = owner;
+        } else {
+            break;
+        }
+    }
+
+    ctx.entity = entity;
+    root
+}
+
+fn select_item(ctx: &mut Context<'_>, item: Entity, selected: bool) {
+    let mut widget = ctx.get_widget(item);
+
+    if widget.has::<bool>("selected") {
+        widget.set("selected", selected);
+        widget.update_theme_by_state(false);
+    }
+}
+
+// Only enabled menu items can be selected by keyboard, separators are skipped.
+fn is_selectable(ctx: &mut Context<'_>, item: Entity) -> bool {
+    let widget = ctx.get_widget(item);
+    widget.has::<String16>("text") && *widget.get::<bool>("enabled")
+}
+
+fn has_popup(ctx: &mut Context<'_>, item: Entity) -> bool {
+    ctx.get_widget(item).has::<Entity>("popup")
+}
+
+// Global position of the bottom left corner of a top level menu.
+fn position_below(ctx: &mut Context<'_>, entity: Entity) -> Point {
+    let widget = ctx.get_widget(entity);
+    let position = *widget.get::<Point>("position");
+    Point::new(
+        position.x,
+        position.y + widget.get::<Rectangle>("bounds").height(),
+    )
+}
+
+// Global position of the top right corner of a menu item.
+fn position_right(ctx: &mut Context<'_>, entity: Entity) -> Point {
+    let widget = ctx.get_widget(entity);
+    let position = *widget.get::<Point>("position");
+    Point::new(
+        position.x + widget.get::<Rectangle>("bounds").width(),
+        position.y,
+    )
+}
+
+// --- MenuBar ---
+
+widget!(
+    /// The `MenuBar` arranges `Menu` widgets horizontally. Use the arrow keys left and right
+    /// to move between the menus of an open menu bar.
+    ///
+    /// **CSS element:** `menu`
+    MenuBar {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for MenuBar {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let menus = std::mem::replace(&mut self.children, vec![]);
+        let mut menus_panel = Stack::create().orientation("horizontal");
+
+        for menu in &menus {
+            menus_panel = menus_panel.child(*menu);
+        }
+
+        ctx.register_property("menus", id, menus);
+
+        self.name("MenuBar")
+            .accessible_role("menu-bar")
+            .selector("menu")
+            .background(colors::LYNCH_COLOR)
+            .padding(0.0)
+            .height(28.0)
+            .child(
+                Container::create()
+                    .background(id)
+                    .padding(id)
+                    .child(menus_panel.build(ctx))
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Menu ---
+
+#[derive(Clone)]
+enum MenuAction {
+    Toggle,
+    Key(KeyEvent),
+}
+
+/// The `MenuState` handles the popup and the keyboard navigation of a `Menu` widget.
+#[derive(Default, AsAny)]
+pub struct MenuState {
+    action: RefCell<Option<MenuAction>>,
+    open: bool,
+
+    // owner of each open popup with the index of its selected item.
+    levels: Vec<(Entity, Option<usize>)>,
+}
+
+impl MenuState {
+    fn action(&self, action: MenuAction) {
+        *self.action.borrow_mut() = Some(action);
+    }
+
+    fn open(&mut self, ctx: &mut Context<'_>) {
+        let entity = ctx.entity;
+        let position = position_below(ctx, entity);
+        open_popup(ctx, entity, position);
+
+        if let Some(old_focused_element) = ctx.window().get::<Global>("global").focused_widget {
+            let mut old_focused_element = ctx.get_widget(old_focused_element);
+            old_focused_element.set("focused", false);
+            old_focused_element.update_theme_by_state(false);
+        }
+
+        ctx.window().get_mut::<Global>("global").focused_widget = Some(entity);
+        ctx.widget().set("selected", true);
+        ctx.widget().update_theme_by_state(false);
+
+        self.levels = vec![(entity, None)];
+        self.open = true;
+    }
+
+    fn close(&mut self, ctx: &mut Context<'_>) {
+        let entity = ctx.entity;
+        close_popup(ctx, entity);
+
+        if ctx.window().get::<Global>("global").focused_widget == Some(entity) {
+            ctx.window().get_mut::<Global>("global").focused_widget = None;
+        }
+
+        ctx.widget().set("selected", false);
+        ctx.widget().update_theme_by_state(false);
+
+        self.levels.clear();
+        self.open = false;
+    }
+
+    // Moves the selection of the current level by the given offset.
+    fn move_selection(&mut self, ctx: &mut Context<'_>, offset: i32) {
+        let (owner, selected) = match self.levels.last() {
+            Some(level) => *level,
+            None => return,
+        };
+
+        let items = ctx
+            .get_widget(owner)
+            .clone_or_default::<Vec<Entity>>("items");
+
+        if items.is_empty() {
+            return;
+        }
+
+        let len = items.len() as i32;
+        let mut index = match selected {
+            Some(selected) => selected as i32,
+            None if offset > 0 => -1,
+            None => len,
+        };
+
+        for _ in 0..len {
+            index = (index + offset + len) % len;
+
+            if is_selectable(ctx, items[index as usize]) {
+                if let Some(selected) = selected {
+                    select_item(ctx, items[selected], false);
+                }
+
+                select_item(ctx, items[index as usize], true);
+
+                if let Some(level) = self.levels.last_mut() {
+                    level.1 = Some(index as usize);
+                }
+
+                return;
+            }
+        }
+    }
+
+    fn selected_item(&self, ctx: &mut Context<'_>) -> Option<Entity> {
+        if let Some((owner, Some(selected))) = self.levels.last() {
+            return ctx
+                .get_widget(*owner)
+                .clone_or_default::<Vec<Entity>>("items")
+                .get(*selected)
+                .copied();
+        }
+
+        None
+    }
+
+    // Opens the sub menu of the selected item.
+    fn open_sub_menu(&mut self, ctx: &mut Context<'_>) -> bool {
+        if let Some(item) = self.selected_item(ctx) {
+            if has_popup(ctx, item) {
+                let position = position_right(ctx, item);
+                open_popup(ctx, item, position);
+                self.levels.push((item, None));
+                self.move_selection(ctx, 1);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // Closes the current menu and opens the menu beside it in the menu bar.
+    fn open_sibling(&mut self, ctx: &mut Context<'_>, offset: i32) {
+        let menu_bar = match ctx.parent_entity_by_element("menu") {
+            Some(menu_bar) => menu_bar,
+            None => return,
+        };
+
+        let menus = ctx
+            .get_widget(menu_bar)
+            .clone_or_default::<Vec<Entity>>("menus");
+
+        if let Some(index) = menus.iter().position(|m| *m == ctx.entity) {
+            let len = menus.len() as i32;
+            let sibling = menus[((index as i32 + offset + len) % len) as usize];
+
+            if sibling != ctx.entity {
+                self.close(ctx);
+                ctx.get_widget(sibling).set("selected", true);
+                let _ = ctx.request_sender().send(shell::ShellRequest::Update);
+            }
+        }
+    }
+
+    fn handle_key_event(&mut self, ctx: &mut Context<'_>, key_event: KeyEvent) {
+        match key_event.key {
+            Key::Up => self.move_selection(ctx, -1),
+            Key::Down => self.move_selection(ctx, 1),
+            Key::Right => {
+                if !self.open_sub_menu(ctx) {
+                    self.open_sibling(ctx, 1);
+                }
+            }
+            Key::Left => {
+                if self.levels.len() > 1 {
+                    if let Some((owner, _)) = self.levels.pop() {
+                        close_popup(ctx, owner);
+                    }
+                } else {
+                    self.open_sibling(ctx, -1);
+                }
+            }
+            Key::Enter => {
+                if !self.open_sub_menu(ctx) {
+                    if let Some(item) = self.selected_item(ctx) {
+                        let position = *ctx.get_widget(item).get::<Point>("position");
+                        ctx.push_event_strategy_by_entity(
+                            ClickEvent { position },
+                            item,
+                            EventStrategy::Direct,
+                        );
+                        self.close(ctx);
+                    }
+                }
+            }
+            Key::Escape => {
+                if self.levels.len() > 1 {
+                    if let Some((owner, _)) = self.levels.pop() {
+                        close_popup(ctx, owner);
+                    }
+                } else {
+                    self.close(ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl State for MenuState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(action) = self.action.borrow_mut().take() {
+            match action {
+                MenuAction::Toggle => {
+                    if self.open {
+                        self.close(ctx);
+                    } else {
+                        self.open(ctx);
+                    }
+                }
+                MenuAction::Key(key_event) => {
+                    if self.open {
+                        self.handle_key_event(ctx, key_event);
+                    }
+                }
+            }
+        }
+
+        // the menu is opened or closed from outside, e.g. by a menu item or a sibling menu
+        let selected = *ctx.widget().get::<bool>("selected");
+        let entity = ctx.entity;
+
+        if selected && !self.open {
+            self.open(ctx);
+        } else if self.open && (!selected || !is_popup_open(ctx, entity)) {
+            self.close(ctx);
+        }
+    }
+}
+
+widget!(
+    /// The `Menu` is a top level entry of a `MenuBar`. Its children (`MenuItem` and `MenuSeparator`)
+    /// are displayed in a popup on the overlay that is opened by clicking on the menu. If a menu is
+    /// open it could be navigated with the arrow keys, Enter and Escape.
+    ///
+    /// **CSS element:** `menuitem`
+    Menu<MenuState>: KeyDownHandler {
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the selected property. A selected menu is open.
+        selected: bool,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for Menu {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items = std::mem::replace(&mut self.children, vec![]);
+        build_popup(id, &items, ctx);
+
+        self.name("Menu")
+            .accessible_role("menu")
+            .selector("menuitem")
+            .text("")
+            .background("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto Regular")
+            .padding((8.0, 0.0, 8.0, 0.0))
+            .selected(false)
+            .pressed(false)
+            .child(
+                MouseBehavior::create()
+                    .pressed(id)
+                    .enabled(id)
+                    .selector(id)
+                    .child(
+                        Container::create()
+                            .background(id)
+                            .padding(id)
+                            .child(
+                                TextBlock::create()
+                                    .vertical_alignment("center")
+                                    .foreground(id)
+                                    .text(id)
+                                    .font_size(id)
+                                    .font(id)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .on_click(move |states, _| {
+                        states.get::<MenuState>(id).action(MenuAction::Toggle);
+                        false
+                    })
+                    .build(ctx),
+            )
+            .on_key_down(move |states, event| {
+                states.get::<MenuState>(id).action(MenuAction::Key(event));
+                false
+            })
+    }
+}
+
+// --- MenuItem ---
+
+/// The `MenuItemState` opens the sub menu or closes the menu and executes the command of the item
+/// if the item is clicked.
+#[derive(Default, AsAny)]
+pub struct MenuItemState {
+    request_activation: Cell<bool>,
+}
+
+impl MenuItemState {
+    fn activate(&self) {
+        self.request_activation.set(true);
+    }
+}
+
+impl State for MenuItemState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        // the shortcut of the command is displayed if no accelerator is set
+        let accelerator = ctx
+            .widget()
+            .try_clone::<Command>("command")
+            .and_then(|command| command.accelerator());
+
+        if let Some(accelerator) = accelerator {
+            if ctx.widget().get::<String16>("accelerator").is_empty() {
+                ctx.widget().set("accelerator", String16::from(accelerator));
+            }
+        }
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let command = ctx.widget().try_clone::<Command>("command");
+
+        if let Some(command) = &command {
+            command.update_enabled(ctx);
+        }
+
+        if !self.request_activation.get() {
+            return;
+        }
+
+        self.request_activation.set(false);
+
+        let entity = ctx.entity;
+
+        if has_popup(ctx, entity) {
+            if is_popup_open(ctx, entity) {
+                close_popup(ctx, entity);
+            } else {
+                let position = position_right(ctx, entity);
+                open_popup(ctx, entity, position);
+            }
+
+            return;
+        }
+
+        let root = root_menu(ctx);
+        ctx.get_widget(root).set("selected", false);
+        close_popup(ctx, root);
+
+        if let Some(command) = command {
+            command.execute(ctx);
+        }
+    }
+}
+
+widget!(
+    /// The `MenuItem` is an entry of a `Menu`. It displays an optional icon, a text and an
+    /// optional accelerator text. If the menu item has children they are displayed in a sub menu.
+    ///
+    /// **CSS element:** `menuitem`
+    MenuItem<MenuItemState>: MouseHandler {
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the accelerator text property, e.g. `Ctrl+S`.
+        accelerator: String16,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon property.
+        icon: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or share the icon font size property.
+        icon_size: f64,
+
+        /// Sets or shares the icon font property.
+        icon_font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the selected property.
+        selected: bool,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the command that is executed if the item is clicked. The item is
+        /// disabled while the command could not be executed and displays its shortcut if no
+        /// accelerator is set.
+        command: Command,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl MenuItem {
+    /// Sets the glyph, font, size and brush of the icon at once.
+    pub fn font_icon(self, icon: impl Into<Icon>) -> Self {
+        let icon = icon.into();
+        let mut this = self.icon(icon.glyph);
+
+        if let Some(font) = icon.font {
+            this = this.icon_font(font);
+        }
+
+        if let Some(size) = icon.size {
+            this = this.icon_size(size);
+        }
+
+        if let Some(brush) = icon.brush {
+            this = this.icon_brush(brush);
+        }
+
+        this
+    }
+}
+
+impl Template for MenuItem {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items = std::mem::replace(&mut self.children, vec![]);
+
+        let sub_menu_icon = if items.is_empty() {
+            ""
+        } else {
+            build_popup(id, &items, ctx);
+            material_font_icons::KEYBOARD_ARROW_RIGHT_FONT_ICON
+        };
+
+        self.name("MenuItem")
+            .accessible_role("menu-item")
+            .selector("menuitem")
+            .text("")
+            .accelerator("")
+            .height(28.0)
+            .background("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto Regular")
+            .icon("")
+            .icon_font("Material Icons")
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .padding((8.0, 0.0, 8.0, 0.0))
+            .selected(false)
+            .pressed(false)
+            .on_click(move |states, _| {
+                states.get::<MenuItemState>(id).activate();
+                false
+            })
+            .child(
+                MouseBehavior::create()
+                    .pressed(id)
+                    .enabled(id)
+                    .selector(id)
+                    .child(
+                        Container::create()
+                            .background(id)
+                            .padding(id)
+                            .child(
+                                Grid::create()
+                                    .columns(
+                                        Columns::create()
+                                            .column(24.0)
+                                            .column("*")
+                                            .column("auto")
+                                            .column(16.0)
+                                            .build(),
+                                    )
+                                    .child(
+                                        FontIconBlock::create()
+                                            .vertical_alignment("center")
+                                            .icon(id)
+                                            .icon_brush(id)
+                                            .icon_size(id)
+                                            .icon_font(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::create()
+                                            .attach(Grid::column(1))
+                                            .vertical_alignment("center")
+                                            .foreground(id)
+                                            .text(id)
+                                            .font_size(id)
+                                            .font(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::create()
+                                            .attach(Grid::column(2))
+                                            .margin((16.0, 0.0, 0.0, 0.0))
+                                            .vertical_alignment("center")
+                                            .foreground(id)
+                                            .text(("accelerator", id))
+                                            .font_size(id)
+                                            .font(id)
+                                            .opacity(0.7)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        FontIconBlock::create()
+                                            .attach(Grid::column(3))
+                                            .vertical_alignment("center")
+                                            .icon(sub_menu_icon)
+                                            .icon_brush(id)
+                                            .icon_size(id)
+                                            .icon_font(id)
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- MenuSeparator ---
+
+widget!(
+    /// The `MenuSeparator` draws a horizontal line between two groups of menu items.
+    ///
+    /// **CSS element:** `menu-separator`
+    MenuSeparator {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for MenuSeparator {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("MenuSeparator")
+            .selector("menu-separator")
+            .height(1.0)
+            .margin((0.0, 4.0, 0.0, 4.0))
+            .background(colors::BOMBAY_COLOR)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{pager::slide_offsets, prelude::*};
+
+/// Default seconds of the slide transition between two screens.
+pub const DEFAULT_SCREEN_TRANSITION_DURATION: f64 = 0.25;
+
+type ScreenBuilder = Box<dyn Fn(&mut BuildContext) -> Entity + 'static>;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Transition {
+    // screen that is moved out.
+    leaving: Entity,
+    start: f64,
+    forward: bool,
+    // popped screens are removed after the transition.
+    remove: bool,
+}
+
+/// The `NavigationViewState` builds the pushed screens and keeps the stack of the shown screens.
+#[derive(Default, AsAny)]
+pub struct NavigationViewState {
+    requests: RefCell<Vec<NavigationRequest>>,
+    screens: HashMap<String, ScreenBuilder>,
+    // names and widgets of the screens, the last one is shown.
+    stack: Vec<(String, Entity)>,
+    panel: Option<Entity>,
+    transition: Option<Transition>,
+}
+
+impl NavigationViewState {
+    /// Pushes the screen with the given name onto the stack of the navigation view.
+    pub fn navigate(&self, screen: impl Into<String>) {
+        self.requests
+            .borrow_mut()
+            .push(NavigationRequest::Navigate(screen.into()));
+    }
+
+    /// Pops the current screen of the navigation view and shows the screen below it.
+    pub fn navigate_back(&self) {
+        self.requests.borrow_mut().push(NavigationRequest::Back);
+    }
+
+    /// Returns the names of the screens on the stack, the last one is shown.
+    pub fn history(&self) -> Vec<&str> {
+        self.stack.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    fn push(&mut self, name: String, ctx: &mut Context<'_>) {
+        let panel = match self.panel {
+            Some(panel) => panel,
+            None => return,
+        };
+
+        let screen = match self.screens.get(&name) {
+            Some(builder) => builder(&mut ctx.build_context()),
+            None => {
+                eprintln!("NavigationView: screen {} not found.", name);
+                return;
+            }
+        };
+
+        self.finish_transition(ctx);
+        ctx.append_child_entity_to(screen, panel);
+        ctx.get_widget(screen).update_properties_by_theme();
+
+        if let Some((old_name, old_screen)) = self.stack.last().cloned() {
+            self.push_event(old_name, NavigationPhase::Leave, ctx);
+            self.start_transition(old_screen, true, false, ctx);
+        }
+
+        self.stack.push((name.clone(), screen));
+        self.push_event(name, NavigationPhase::Enter, ctx);
+    }
+
+    fn pop(&mut self, ctx: &mut Context<'_>) {
+        if self.stack.len() < 2 {
+            return;
+        }
+
+        self.finish_transition(ctx);
+
+        if let Some((name, screen)) = self.stack.pop() {
+            self.push_event(name, NavigationPhase::Leave, ctx);
+            self.start_transition(screen, false, true, ctx);
+        }
+
+        if let Some((name, _)) = self.stack.last().cloned() {
+            self.push_event(name, NavigationPhase::Enter, ctx);
+        }
+    }
+
+    fn push_event(&self, screen: String, phase: NavigationPhase, ctx: &mut Context<'_>) {
+        ctx.push_event_strategy_by_entity(
+            NavigationEvent { screen, phase },
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    fn start_transition(
+        &mut self,
+        leaving: Entity,
+        forward: bool,
+        remove: bool,
+        ctx: &mut Context<'_>,
+    ) {
+        self.transition = Some(Transition {
+            leaving,
+            start: Clock::current().now().as_secs_f64(),
+            forward: forward != ctx.flow_direction().is_right_to_left(),
+            remove,
+        });
+
+        if *ctx.widget().get::<f64>("transition_duration") <= 0.0 {
+            self.finish_transition(ctx);
+        }
+    }
+
+    // Hides the screen that is moved out or removes it if it is popped.
+    fn finish_transition(&mut self, ctx: &mut Context<'_>) {
+        let transition = match self.transition.take() {
+            Some(transition) => transition,
+            None => return,
+        };
+
+        if transition.remove {
+            if let Some(panel) = self.panel {
+                ctx.remove_child_from(transition.leaving, panel);
+            }
+
+            return;
+        }
+
+        let mut leaving = ctx.get_widget(transition.leaving);
+        leaving.set("visibility", Visibility::Collapsed);
+        leaving.set("margin", Thickness::default());
+    }
+
+    // Moves the current and the leaving screen by the progress of the transition.
+    fn update_screens(&mut self, ctx: &mut Context<'_>) {
+        let width = self.panel.map_or(0.0, |panel| {
+            ctx.get_widget(panel).get::<Rectangle>("bounds").width()
+        });
+        let duration = *ctx.widget().get::<f64>("transition_duration");
+
+        let mut offsets = (0.0, 0.0);
+
+        if let Some(transition) = self.transition {
+            let progress = (Clock::current().now().as_secs_f64() - transition.start) / duration;
+
+            if progress >= 1.0 || duration <= 0.0 {
+                self.finish_transition(ctx);
+            } else {
+                offsets = slide_offsets(progress, width, transition.forward);
+
+                ctx.get_widget(transition.leaving)
+                    .set("margin", Thickness::new(offsets.0, 0.0, -offsets.0, 0.0));
+            }
+        }
+
+        if let Some((_, screen)) = self.stack.last() {
+            let mut screen = ctx.get_widget(*screen);
+            let margin = Thickness::new(offsets.1, 0.0, -offsets.1, 0.0);
+
+            if *screen.get::<Visibility>("visibility") != Visibility::Visible {
+                screen.set("visibility", Visibility::Visible);
+            }
+
+            if *screen.get::<Thickness>("margin") != margin {
+                screen.set("margin", margin);
+            }
+        }
+
+        // the transition moves the screens without input
+        if self.transition.is_some() {
+            ctx.request_update();
+        }
+    }
+
+    fn update_properties(&self, ctx: &mut Context<'_>) {
+        let current_screen = self
+            .stack
+            .last()
+            .map(|(name, _)| name.clone())
+            .unwrap_or_default();
+        let can_go_back = self.stack.len() > 1;
+
+        let mut widget = ctx.widget();
+
+        if *widget.get::<String>("current_screen") != current_screen {
+            widget.set("current_screen", current_screen);
+        }
+
+        if *widget.get::<bool>("can_go_back") != can_go_back {
+            widget.set("can_go_back", can_go_back);
+        }
+    }
+}
+
+impl State for NavigationViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let start_screen = ctx.widget().clone::<String>("start_screen");
+
+        if !start_screen.is_empty() {
+            self.push(start_screen, ctx);
+        }
+
+        self.update_screens(ctx);
+        self.update_properties(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        // requests of the own handlers first, then the requests of `Context::navigate`
+        let mut requests: Vec<NavigationRequest> = self.requests.borrow_mut().drain(..).collect();
+        requests.extend(
+            ctx.window()
+                .get_mut::<Global>("global")
+                .navigation_requests
+                .drain(..),
+        );
+
+        for request in requests {
+            match request {
+                NavigationRequest::Navigate(screen) => self.push(screen, ctx),
+                NavigationRequest::Back => self.pop(ctx),
+            }
+        }
+
+        self.update_screens(ctx);
+        self.update_properties(ctx);
+    }
+}
+
+widget!(
+    /// The `NavigationView` shows one screen of a multi-screen app at a time. Screens are
+    /// registered by name with a builder. `Context::navigate` builds the screen with the given
+    /// name and pushes it onto the stack of the navigation view, `Context::navigate_back` pops it
+    /// and shows the screen below it again with its previous state. The new screen slides in and
+    /// pushes the old screen out.
+    ///
+    /// Each shown screen raises an `on_enter` event, each hidden screen an `on_leave` event,
+    /// both with the name of the screen.
+    ///
+    /// The requests of `Context::navigate` are handled by the first navigation view of the
+    /// window. Nested navigation views could be controlled by `NavigationViewState::navigate`.
+    ///
+    /// **CSS element:** `navigation-view`
+    NavigationView<NavigationViewState>: NavigationHandler {
+        /// Sets or shares the name of the screen that is shown first.
+        start_screen: String,
+
+        /// Sets or shares the name of the shown screen. It is set by the navigation view.
+        current_screen: String,
+
+        /// Sets or shares the value that describes if there is a screen below the shown screen.
+        /// It is set by the navigation view, e.g. to enable a back button.
+        can_go_back: bool,
+
+        /// Sets or shares the seconds of the slide transition, `0` shows a new screen at once.
+        transition_duration: f64,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl NavigationView {
+    /// Registers a screen with the given name and the builder of its widget. The screen is built
+    /// each time it is pushed.
+    pub fn screen<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        mut self,
+        name: impl Into<String>,
+        builder: F,
+    ) -> Self {
+        self.state_mut()
+            .screens
+            .insert(name.into(), Box::new(builder));
+        self
+    }
+}
+
+impl Template for NavigationView {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let panel = Container::create().clip(true).background(id).build(ctx);
+        self.state_mut().panel = Some(panel);
+
+        self.name("NavigationView")
+            .selector("navigation-view")
+            .start_screen("")
+            .current_screen("")
+            .can_go_back(false)
+            .transition_duration(DEFAULT_SCREEN_TRANSITION_DURATION)
+            .background("transparent")
+            .child(panel)
+    }
+}
@@ -0,0 +1,344 @@
+use std::cell::Cell;
+
+use super::text_box::set_validation;
+use crate::{prelude::*, shell::Key};
+
+type Validator = Option<Box<dyn Fn(f64) -> Result<(), String>>>;
+
+// Returns the number of decimal places of the given step.
+fn decimals(step: f64) -> usize {
+    step.to_string()
+        .split('.')
+        .nth(1)
+        .map(|d| d.len())
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum NumericBoxAction {
+    Increment,
+    Decrement,
+    Commit,
+    Validate,
+}
+
+/// The `NumericBoxState` validates the input and keeps the value of the `NumericBox` in range.
+#[derive(Default, AsAny)]
+pub struct NumericBoxState {
+    action: Cell<Option<NumericBoxAction>>,
+    value: f64,
+    text: String,
+    focused: bool,
+    validator: Validator,
+}
+
+impl NumericBoxState {
+    fn action(&self, action: NumericBoxAction) {
+        self.action.set(Some(action));
+    }
+
+    // Clamps the value to min and max and rounds it to the decimals of step.
+    fn coerce(&self, ctx: &mut Context<'_>, value: f64) -> f64 {
+        let widget = ctx.widget();
+        let min = *widget.get::<f64>("min");
+        let max = *widget.get::<f64>("max");
+        let factor = 10_f64.powi(decimals(*widget.get::<f64>("step")) as i32);
+
+        ((value * factor).round() / factor).max(min).min(max)
+    }
+
+    fn format(&self, ctx: &mut Context<'_>, value: f64) -> String {
+        format!("{:.*}", decimals(*ctx.widget().get::<f64>("step")), value)
+    }
+
+    // Sets the value, updates the text and raises the value changed event.
+    fn set_value(&mut self, ctx: &mut Context<'_>, value: f64) {
+        let value = self.coerce(ctx, value);
+        let text = self.format(ctx, value);
+
+        if text != self.text {
+            self.text = text.clone();
+            ctx.widget().set("text", String16::from(text));
+            ctx.child("input")
+                .get_mut::<TextSelection>("text_selection")
+                .start_index = self.text.len();
+        }
+
+        if (value - self.value).abs() > std::f64::EPSILON {
+            self.value = value;
+            ctx.widget().set("value", value);
+            ctx.push_event_strategy_by_entity(
+                ValueChangedEvent { value },
+                ctx.entity,
+                EventStrategy::Direct,
+            );
+            self.check_value(ctx);
+        }
+    }
+
+    // Checks the value with the validator and updates the error state of the input.
+    fn check_value(&self, ctx: &mut Context<'_>) {
+        if let Some(validator) = &self.validator {
+            set_validation(&mut ctx.widget(), validator(self.value));
+            ctx.child("input").update_theme_by_state(false);
+        }
+    }
+
+    // Checks if the text could be part of a number, e.g. `-`, `1.` or `-2.5`.
+    fn is_valid_input(&self, ctx: &mut Context<'_>, text: &str) -> bool {
+        let allow_negative = *ctx.widget().get::<f64>("min") < 0.0;
+        let allow_decimals = decimals(*ctx.widget().get::<f64>("step")) > 0;
+
+        let mut has_point = false;
+
+        text.chars().enumerate().all(|(i, c)| match c {
+            '0'..='9' => true,
+            '-' => allow_negative && i == 0,
+            '.' if allow_decimals && !has_point => {
+                has_point = true;
+                true
+            }
+            _ => false,
+        })
+    }
+
+    // Reverts the text to the last valid input if invalid characters are entered.
+    fn validate(&mut self, ctx: &mut Context<'_>) {
+        let text = ctx.widget().get::<String16>("text").to_string();
+
+        if self.is_valid_input(ctx, text.as_str()) {
+            self.text = text;
+            return;
+        }
+
+        let removed = text.len().saturating_sub(self.text.len());
+        ctx.widget().set("text", String16::from(self.text.as_str()));
+
+        let mut input = ctx.child("input");
+        let selection = input.get_mut::<TextSelection>("text_selection");
+        selection.start_index = selection
+            .start_index
+            .saturating_sub(removed)
+            .min(self.text.len());
+    }
+
+    // Parses the text and sets the value. Unparsable text is reverted to the current value.
+    fn commit(&mut self, ctx: &mut Context<'_>) {
+        let value = self.text.parse::<f64>().unwrap_or(self.value);
+        self.text.clear();
+        self.set_value(ctx, value);
+    }
+}
+
+impl State for NumericBoxState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.value = *ctx.widget().get::<f64>("value");
+        let value = self.value;
+        self.set_value(ctx, value);
+        self.check_value(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        // commits the input if the numeric box lost the focus
+        let focused = *ctx.child("input").get::<bool>("focused");
+
+        if self.focused && !focused {
+            self.commit(ctx);
+        }
+
+        self.focused = focused;
+
+        if let Some(action) = self.action.take() {
+            let step = *ctx.widget().get::<f64>("step");
+
+            match action {
+                NumericBoxAction::Increment => {
+                    let value = self.value + step;
+                    self.set_value(ctx, value);
+                }
+                NumericBoxAction::Decrement => {
+                    let value = self.value - step;
+                    self.set_value(ctx, value);
+                }
+                NumericBoxAction::Commit => self.commit(ctx),
+                NumericBoxAction::Validate => self.validate(ctx),
+            }
+        }
+
+        // value is changed from outside
+        let value = *ctx.widget().get::<f64>("value");
+
+        if (value - self.value).abs() > std::f64::EPSILON {
+            self.set_value(ctx, value);
+        }
+    }
+}
+
+widget!(
+    /// The `NumericBox` is used to enter a number. The value could be typed or changed by the
+    /// increment and decrement buttons or the up and down keys. The value is kept between `min`
+    /// and `max` and is rounded to the decimals of `step`.
+    ///
+    /// The value could be checked by a `validator`, the input of an invalid numeric box has the
+    /// `:invalid` pseudo class and the message of the validator is set as `error_message`.
+    ///
+    /// **CSS element:** `numeric-box`
+    NumericBox<NumericBoxState>: ValueChangedHandler {
+        /// Sets or shares the value property.
+        value: f64,
+
+        /// Sets or shares the minimum value.
+        min: f64,
+
+        /// Sets or shares the maximum value.
+        max: f64,
+
+        /// Sets or shares the value that is added or subtracted by the buttons and the up and down keys.
+        step: f64,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the value that describes if the value is accepted by the validator.
+        is_valid: bool,
+
+        /// Sets or shares the message of the validator if the value is invalid.
+        error_message: String16,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl NumericBox {
+    /// Inserts a callback that validates the value whenever it is changed. It returns an error
+    /// message if the value is invalid.
+    pub fn validator<F: Fn(f64) -> Result<(), String> + 'static>(mut self, validator: F) -> Self {
+        self.state_mut().validator = Some(Box::new(validator));
+        self
+    }
+}
+
+impl Template for NumericBox {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("NumericBox")
+            .selector("numeric-box")
+            .value(0.0)
+            .min(std::f64::MIN)
+            .max(std::f64::MAX)
+            .step(1.0)
+            .text("")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto Regular")
+            .background(colors::LYNCH_COLOR)
+            .border_brush("transparent")
+            .border_width(0.0)
+            .border_radius(2.0)
+            .padding(4.0)
+            .is_valid(true)
+            .error_message("")
+            .min_width(96.0)
+            .height(32.0)
+            .child(
+                Grid::create()
+                    .columns(Columns::create().column("*").column(24.0).build())
+                    .child(
+                        TextBox::create()
+                            .selector(Selector::from("text-box").id("input"))
+                            .min_width(0.0)
+                            .text(id)
+                            .foreground(id)
+                            .font_size(id)
+                            .font(id)
+                            .background(id)
+                            .border_radius(id)
+                            .border_width(id)
+                            .border_brush(id)
+                            .padding(id)
+                            .is_valid(id)
+                            .on_changed(move |states, _| {
+                                states
+                                    .get::<NumericBoxState>(id)
+                                    .action(NumericBoxAction::Validate);
+                            })
+                            .on_activate(move |states, _| {
+                                states
+                                    .get::<NumericBoxState>(id)
+                                    .action(NumericBoxAction::Commit);
+                            })
+                            .on_key_down(move |states, event| {
+                                let action = match event.key {
+                                    Key::Up => NumericBoxAction::Increment,
+                                    Key::Down => NumericBoxAction::Decrement,
+                                    _ => return false,
+                                };
+
+                                states.get::<NumericBoxState>(id).action(action);
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Stack::create()
+                            .attach(Grid::column(1))
+                            .child(
+                                Button::create()
+                                    .selector("numeric-box-button")
+                                    .min_width(0.0)
+                                    .height(16.0)
+                                    .padding(0.0)
+                                    .icon(material_font_icons::ARROW_DROP_UP_FONT_ICON)
+                                    .on_click(move |states, _| {
+                                        states
+                                            .get::<NumericBoxState>(id)
+                                            .action(NumericBoxAction::Increment);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::create()
+                                    .selector("numeric-box-button")
+                                    .min_width(0.0)
+                                    .height(16.0)
+                                    .padding(0.0)
+                                    .icon(material_font_icons::ARROW_DROP_DOWN_FONT_ICON)
+                                    .on_click(move |states, _| {
+                                        states
+                                            .get::<NumericBoxState>(id)
+                                            .action(NumericBoxAction::Decrement);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -0,0 +1,392 @@
+use std::cell::Cell;
+
+use crate::prelude::*;
+
+/// Default seconds of the slide transition between two pages.
+pub const DEFAULT_PAGE_TRANSITION_DURATION: f64 = 0.3;
+
+// Returns the page that is shown after a swipe in the given direction or `None` if there is no
+// page in this direction. A swipe to the left shows the next page, in a right to left layout the
+// previous one.
+fn swipe_index(
+    direction: SwipeDirection,
+    current: usize,
+    count: usize,
+    right_to_left: bool,
+) -> Option<usize> {
+    let next = match direction {
+        SwipeDirection::Left => !right_to_left,
+        SwipeDirection::Right => right_to_left,
+        _ => return None,
+    };
+
+    if next {
+        Some(current + 1).filter(|index| *index < count)
+    } else {
+        current.checked_sub(1)
+    }
+}
+
+// Eases the progress of a transition from `0` to `1` out, so the page slows down at the end.
+fn ease_out(progress: f64) -> f64 {
+    let progress = progress.max(0.0).min(1.0);
+
+    1.0 - (1.0 - progress).powi(3)
+}
+
+// Returns the horizontal offsets of the old and the new page at the given progress of the slide
+// transition. The new page comes in from the right if `forward` is `true`, otherwise from the
+// left, and pushes the old page out.
+pub(crate) fn slide_offsets(progress: f64, width: f64, forward: bool) -> (f64, f64) {
+    let direction = if forward { 1.0 } else { -1.0 };
+    let moved = ease_out(progress) * width;
+
+    (-direction * moved, direction * (width - moved))
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum PagerAction {
+    Select(usize),
+    Swipe(SwipeDirection),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Transition {
+    from: usize,
+    start: f64,
+    forward: bool,
+}
+
+/// The `PagerState` shows the selected page and slides between the pages.
+#[derive(Default, AsAny)]
+pub struct PagerState {
+    action: Cell<Option<PagerAction>>,
+    // containers of the pages, they are moved during the transition.
+    pages: Vec<Entity>,
+    dots: Vec<Entity>,
+    pages_panel: Option<Entity>,
+    dots_panel: Option<Entity>,
+    selected: usize,
+    transition: Option<Transition>,
+}
+
+impl PagerState {
+    fn action(&self, action: PagerAction) {
+        self.action.set(Some(action));
+    }
+
+    fn select(&mut self, action: PagerAction, ctx: &mut Context<'_>) {
+        let count = self.pages.len();
+        let index = match action {
+            PagerAction::Select(index) => Some(index).filter(|index| *index < count),
+            PagerAction::Swipe(direction) => {
+                if !*ctx.widget().get::<bool>("swipe_navigation") {
+                    return;
+                }
+
+                swipe_index(
+                    direction,
+                    self.selected,
+                    count,
+                    ctx.flow_direction().is_right_to_left(),
+                )
+            }
+        };
+
+        if let Some(index) = index {
+            ctx.widget().set("selected_index", SelectedIndex(index));
+        }
+    }
+
+    // Starts the transition to the selected page if it is changed since the last update.
+    fn update_selection(&mut self, ctx: &mut Context<'_>) {
+        if self.pages.is_empty() {
+            return;
+        }
+
+        let selected = ctx
+            .widget()
+            .get::<SelectedIndex>("selected_index")
+            .0
+            .min(self.pages.len() - 1);
+
+        if selected == self.selected {
+            return;
+        }
+
+        let duration = *ctx.widget().get::<f64>("transition_duration");
+
+        self.transition = if duration > 0.0 {
+            Some(Transition {
+                from: self.selected,
+                start: Clock::current().now().as_secs_f64(),
+                forward: (selected > self.selected) != ctx.flow_direction().is_right_to_left(),
+            })
+        } else {
+            None
+        };
+        self.selected = selected;
+
+        self.update_dots(ctx);
+        ctx.push_event_strategy_by_entity(
+            ChangedEvent(ctx.entity),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    fn update_dots(&self, ctx: &mut Context<'_>) {
+        for (index, dot) in self.dots.iter().enumerate() {
+            let mut dot = ctx.get_widget(*dot);
+
+            if index == self.selected {
+                add_selector_to_widget("selected", &mut dot);
+            } else {
+                remove_selector_from_widget("selected", &mut dot);
+            }
+
+            dot.update_properties_by_theme();
+        }
+    }
+
+    // Shows or hides the dots by the `show_indicator` property.
+    fn update_indicator(&self, ctx: &mut Context<'_>) {
+        let dots_panel = match self.dots_panel {
+            Some(dots_panel) => dots_panel,
+            None => return,
+        };
+
+        let visibility = if *ctx.widget().get::<bool>("show_indicator") {
+            Visibility::Visible
+        } else {
+            Visibility::Collapsed
+        };
+
+        let mut dots_panel = ctx.get_widget(dots_panel);
+
+        if *dots_panel.get::<Visibility>("visibility") != visibility {
+            dots_panel.set("visibility", visibility);
+        }
+    }
+
+    // Moves the old and the new page by the progress of the transition. Only the selected page is
+    // visible after the transition.
+    fn update_pages(&mut self, ctx: &mut Context<'_>) {
+        let width = self.pages_panel.map_or(0.0, |panel| {
+            ctx.get_widget(panel).get::<Rectangle>("bounds").width()
+        });
+        let duration = *ctx.widget().get::<f64>("transition_duration");
+
+        let mut offsets = (0.0, 0.0);
+
+        if let Some(transition) = self.transition {
+            let progress = (Clock::current().now().as_secs_f64() - transition.start) / duration;
+
+            if progress >= 1.0 || duration <= 0.0 {
+                self.transition = None;
+            } else {
+                offsets = slide_offsets(progress, width, transition.forward);
+            }
+        }
+
+        for (index, page) in self.pages.iter().enumerate() {
+            let (visibility, offset) = if index == self.selected {
+                (Visibility::Visible, offsets.1)
+            } else if self.transition.map(|t| t.from) == Some(index) {
+                (Visibility::Visible, offsets.0)
+            } else {
+                (Visibility::Collapsed, 0.0)
+            };
+
+            let mut page = ctx.get_widget(*page);
+            let margin = Thickness::new(offset, 0.0, -offset, 0.0);
+
+            if *page.get::<Visibility>("visibility") != visibility {
+                page.set("visibility", visibility);
+            }
+
+            if *page.get::<Thickness>("margin") != margin {
+                page.set("margin", margin);
+            }
+        }
+
+        // the transition moves the pages without input
+        if self.transition.is_some() {
+            ctx.request_update();
+        }
+    }
+}
+
+impl State for PagerState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.selected = ctx
+            .widget()
+            .get::<SelectedIndex>("selected_index")
+            .0
+            .min(self.pages.len().max(1) - 1);
+        self.update_dots(ctx);
+        self.update_indicator(ctx);
+        self.update_pages(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(action) = self.action.take() {
+            self.select(action, ctx);
+        }
+
+        self.update_selection(ctx);
+        self.update_indicator(ctx);
+        self.update_pages(ctx);
+    }
+}
+
+widget!(
+    /// The `Pager` widget hosts pages and shows one page at a time. A new selected page slides in
+    /// and pushes the old page out. The dots below the pages show the selected page, a tap on a
+    /// dot selects its page. With `swipe_navigation` a swipe to the left or right shows the next
+    /// or previous page. Each change of the page raises a changed event.
+    ///
+    /// **CSS element:** `pager`
+    ///
+    /// **CSS element of the dots:** `pager-dot`, the dot of the selected page has the
+    /// `:selected` pseudo class.
+    Pager<PagerState>: ChangedHandler {
+        /// Sets or shares the index of the shown page.
+        selected_index: SelectedIndex,
+
+        /// Sets or shares the seconds of the slide transition, `0` shows a new page at once.
+        transition_duration: f64,
+
+        /// Sets or shares the value that describes if the pages could be changed by a swipe.
+        swipe_navigation: bool,
+
+        /// Sets or shares the value that describes if the dots are shown.
+        show_indicator: bool,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Pager {
+    /// Adds a page.
+    pub fn page(mut self, page: Entity) -> Self {
+        self.state_mut().pages.push(page);
+        self
+    }
+}
+
+impl Template for Pager {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let pages: Vec<Entity> = self
+            .state_mut()
+            .pages
+            .drain(..)
+            .map(|page| {
+                Container::create()
+                    .visibility("collapsed")
+                    .child(page)
+                    .build(ctx)
+            })
+            .collect();
+
+        let mut pages_panel = Container::create().clip(true).background(id).on_gesture(
+            &[GestureKind::Swipe],
+            move |states, gesture| {
+                if let Gesture::Swipe(direction) = gesture {
+                    states
+                        .get::<PagerState>(id)
+                        .action(PagerAction::Swipe(direction));
+                    return true;
+                }
+
+                false
+            },
+        );
+
+        for page in &pages {
+            pages_panel = pages_panel.child(*page);
+        }
+
+        let pages_panel = pages_panel.build(ctx);
+
+        let mut dots_panel = Stack::create()
+            .attach(Grid::row(1))
+            .orientation("horizontal")
+            .horizontal_alignment("center")
+            .margin((0.0, 8.0, 0.0, 0.0));
+        let mut dots = vec![];
+
+        for index in 0..pages.len() {
+            let dot = Container::create()
+                .selector("pager-dot")
+                .margin((4.0, 0.0, 4.0, 0.0))
+                .width(8.0)
+                .height(8.0)
+                .border_radius(4.0)
+                .background(colors::BOMBAY_COLOR)
+                .on_gesture(&[GestureKind::Tap], move |states, _| {
+                    states
+                        .get::<PagerState>(id)
+                        .action(PagerAction::Select(index));
+                    true
+                })
+                .build(ctx);
+
+            dots_panel = dots_panel.child(dot);
+            dots.push(dot);
+        }
+
+        let dots_panel = dots_panel.build(ctx);
+
+        self.state_mut().pages = pages;
+        self.state_mut().dots = dots;
+        self.state_mut().pages_panel = Some(pages_panel);
+        self.state_mut().dots_panel = Some(dots_panel);
+
+        self.name("Pager")
+            .selector("pager")
+            .selected_index(0)
+            .transition_duration(DEFAULT_PAGE_TRANSITION_DURATION)
+            .swipe_navigation(true)
+            .show_indicator(true)
+            .background("transparent")
+            .child(
+                Grid::create()
+                    .rows(Rows::create().row("*").row("auto").build())
+                    .child(pages_panel)
+                    .child(dots_panel)
+                    .build(ctx),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swipe_index() {
+        assert_eq!(swipe_index(SwipeDirection::Left, 0, 3, false), Some(1));
+        assert_eq!(swipe_index(SwipeDirection::Right, 1, 3, false), Some(0));
+
+        // there is no page after the last and before the first page
+        assert_eq!(swipe_index(SwipeDirection::Left, 2, 3, false), None);
+        assert_eq!(swipe_index(SwipeDirection::Right, 0, 3, false), None);
+        assert_eq!(swipe_index(SwipeDirection::Up, 1, 3, false), None);
+
+        // right to left
+        assert_eq!(swipe_index(SwipeDirection::Right, 0, 3, true), Some(1));
+    }
+
+    #[test]
+    fn test_slide_offsets() {
+        assert_eq!(slide_offsets(0.0, 100.0, true), (0.0, 100.0));
+        assert_eq!(slide_offsets(1.0, 100.0, true), (-100.0, 0.0));
+        assert_eq!(slide_offsets(0.5, 100.0, true), (-87.5, 12.5));
+        assert_eq!(slide_offsets(0.5, 100.0, false), (87.5, -12.5));
+    }
+}
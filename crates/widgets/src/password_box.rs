@@ -0,0 +1,168 @@
+use super::behaviors::MouseBehavior;
+use crate::{prelude::*, text_box::TextBoxAction};
+
+widget!(
+    /// The `PasswordBox` widget represents a single line input widget for passwords. It uses the
+    /// text processing, cursor and selection of the `TextBox`, but displays a mask character for
+    /// each character of the text. The text itself is never displayed and could not be copied.
+    ///
+    /// * CSS element: `text-box`
+    PasswordBox<TextBoxState>: ActivateHandler, ChangedHandler, KeyDownHandler, TextInputHandler {
+        /// Sets or shares the text (password) property.
+        text: String16,
+
+        /// Sets or shares the displayed masked text. It is updated by the state.
+        masked_text: String16,
+
+        /// Sets or shares the character that is displayed for each character of the text.
+        mask: String,
+
+        /// Sets or shares the water_mark text property.
+        water_mark: String16,
+
+        /// Sets or shares the text selection property.
+        text_selection: TextSelection,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the text offset property.
+        scroll_offset: Point,
+
+        /// Sets or shares the (wheel, scroll) delta property.
+        delta: Point,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares ta value that describes if the PasswordBox should lost focus on activation (enter).
+        lost_focus_on_activation: bool,
+
+        /// Sets or shares the filter that drops typed characters that are not allowed.
+        input_filter: InputFilter,
+
+        /// Sets or shares the seconds the caret is shown and hidden while it blinks, `0` turns the blinking off.
+        caret_blink_rate: f64,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for PasswordBox {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("PasswordBox")
+            .accessible_role("text-input")
+            .selector("text-box")
+            .cursor("text")
+            .text("")
+            .masked_text("")
+            .mask("•")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto Regular")
+            .text_selection(TextSelection::default())
+            .scroll_offset(0.0)
+            .padding(4.0)
+            .background(colors::LYNCH_COLOR)
+            .border_brush("transparent")
+            .border_width(0.0)
+            .border_radius(2.0)
+            .min_width(128.0)
+            .height(32.0)
+            .focused(false)
+            .delta(0.0)
+            .lost_focus_on_activation(true)
+            .input_filter(InputFilter::default())
+            .caret_blink_rate(DEFAULT_CARET_BLINK_RATE)
+            .child(
+                MouseBehavior::create()
+                    .on_mouse_down(move |states, p| {
+                        states
+                            .get::<TextBoxState>(id)
+                            .action(TextBoxAction::Mouse(p));
+                        true
+                    })
+                    .child(
+                        Container::create()
+                            .background(id)
+                            .border_radius(id)
+                            .border_width(id)
+                            .border_brush(id)
+                            .padding(id)
+                            .child(
+                                Grid::create()
+                                    .child(
+                                        ScrollViewer::create()
+                                            .selector(Selector::default().id("scroll_viewer"))
+                                            .scroll_offset(id)
+                                            .scroll_viewer_mode(("custom", "disabled"))
+                                            .delta(id)
+                                            .child(
+                                                TextBlock::create()
+                                                    .selector(Selector::default().id("text_block"))
+                                                    .vertical_alignment("center")
+                                                    .foreground(id)
+                                                    .text(("masked_text", id))
+                                                    .water_mark(id)
+                                                    .font(id)
+                                                    .font_size(id)
+                                                    .build(ctx),
+                                            )
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        Cursor::create()
+                                            .selector(Selector::from("cursor").id("cursor"))
+                                            .margin(0.0)
+                                            .horizontal_alignment("start")
+                                            .text(("masked_text", id))
+                                            .font(id)
+                                            .font_size(id)
+                                            .scroll_offset(id)
+                                            .focused(id)
+                                            .text_selection(id)
+                                            .caret_blink_rate(id)
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get::<TextBoxState>(id)
+                    .action(TextBoxAction::Key(event));
+                false
+            })
+            .on_text_input(move |states, text| -> bool {
+                states
+                    .get::<TextBoxState>(id)
+                    .action(TextBoxAction::TextInput(text));
+                false
+            })
+    }
+}
@@ -10,7 +10,7 @@ pub use crate::*;
 pub use api::*;
 pub use ecs::*;
 pub use orbtk_api::css_engine::{Selector, Theme};
-pub use orbtk_render::prelude::Image;
+pub use orbtk_render::prelude::{Image, Svg};
 pub use proc_macros::*;
 pub use theme::{colors, default_theme, fonts, light_theme, vector_graphics::material_font_icons};
 pub use utils::*;
@@ -0,0 +1,259 @@
+use crate::prelude::*;
+
+#[derive(Copy, Clone)]
+enum PropertyInspectorAction {
+    Commit(usize),
+}
+
+/// The `PropertyInspectorState` shows the theme properties of the target selector and writes the
+/// edited values to the theme extension.
+#[derive(Default, AsAny)]
+pub struct PropertyInspectorState {
+    actions: Vec<PropertyInspectorAction>,
+    target: Option<String>,
+    theme_extension: ThemeExtension,
+    inputs: Vec<Entity>,
+}
+
+impl PropertyInspectorState {
+    fn action(&mut self, action: PropertyInspectorAction) {
+        self.actions.push(action);
+    }
+
+    // Returns the value of the property from the theme extension or the current theme.
+    fn value(&self, ctx: &mut Context<'_>, target: &str, property: &str) -> String {
+        if let Some(value) = ctx
+            .widget()
+            .get::<ThemeExtension>("theme_extension")
+            .get(target, property)
+        {
+            return value.to_string();
+        }
+
+        ctx.theme
+            .get(property, &parse_selector(target))
+            .map(|value| value.to_string())
+            .unwrap_or_default()
+    }
+
+    // Rebuilds the rows of the properties if the target or the extension is changed from outside.
+    fn update_rows(&mut self, ctx: &mut Context<'_>, id: Entity) {
+        let target = ctx.widget().clone::<String>("target");
+        let theme_extension = ctx.widget().clone::<ThemeExtension>("theme_extension");
+
+        if self.target.as_ref() == Some(&target) && self.theme_extension == theme_extension {
+            return;
+        }
+
+        let properties = if let Some(properties) = ctx.entity_of_child("properties") {
+            properties
+        } else {
+            return;
+        };
+
+        ctx.clear_children_of(properties);
+        self.inputs.clear();
+
+        for (index, property) in THEME_PROPERTIES.iter().enumerate() {
+            let value = self.value(ctx, target.as_str(), property);
+            let bctx = &mut ctx.build_context();
+
+            let input = TextBox::create()
+                .attach(Grid::column(1))
+                .min_width(0.0)
+                .text(value)
+                .on_activate(move |states, _| {
+                    states
+                        .get_mut::<PropertyInspectorState>(id)
+                        .action(PropertyInspectorAction::Commit(index));
+                })
+                .build(bctx);
+
+            let row = Grid::create()
+                .columns(Columns::create().column(112.0).column("*").build())
+                .child(
+                    TextBlock::create()
+                        .text(*property)
+                        .vertical_alignment("center")
+                        .build(bctx),
+                )
+                .child(input)
+                .build(bctx);
+
+            bctx.append_child(properties, row);
+            self.inputs.push(input);
+        }
+
+        self.target = Some(target);
+        self.theme_extension = theme_extension;
+    }
+
+    // Writes the entered value to the theme extension and raises the changed event. An empty
+    // value removes the property from the extension.
+    fn commit(&mut self, ctx: &mut Context<'_>, index: usize) {
+        let (input, property) = match (self.inputs.get(index), THEME_PROPERTIES.get(index)) {
+            (Some(input), Some(property)) => (*input, *property),
+            _ => return,
+        };
+
+        let target = ctx.widget().clone::<String>("target");
+        let text = ctx
+            .get_widget(input)
+            .get::<String16>("text")
+            .to_string()
+            .trim()
+            .to_string();
+
+        let mut theme_extension = ctx.widget().clone::<ThemeExtension>("theme_extension");
+
+        if text.is_empty() {
+            if theme_extension.remove(target.as_str(), property).is_none() {
+                return;
+            }
+        } else if theme_extension.get(target.as_str(), property) != Some(text.as_str()) {
+            theme_extension.set(target.as_str(), property, text);
+        } else {
+            return;
+        }
+
+        self.theme_extension = theme_extension.clone();
+        ctx.widget().set("theme_extension", theme_extension);
+        ctx.push_event_strategy_by_entity(
+            ChangedEvent(ctx.entity),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+}
+
+impl State for PropertyInspectorState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let id = ctx.entity;
+        self.update_rows(ctx, id);
+
+        let actions = std::mem::replace(&mut self.actions, vec![]);
+
+        for action in actions {
+            match action {
+                PropertyInspectorAction::Commit(index) => self.commit(ctx, index),
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `PropertyInspector` shows the theme properties of the `target` selector, e.g.
+    /// `button.primary`, and writes the edited values to its `theme_extension`. Apply the
+    /// extension with `Context::switch_theme` to see the changes live.
+    ///
+    /// **CSS element:** `property-inspector`
+    PropertyInspector<PropertyInspectorState>: ChangedHandler {
+        /// Sets or shares the css selector of the inspected widgets.
+        target: String,
+
+        /// Sets or shares the extension that contains the edited values.
+        theme_extension: ThemeExtension,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for PropertyInspector {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("PropertyInspector")
+            .selector("property-inspector")
+            .target("*")
+            .theme_extension(ThemeExtension::new())
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(4.0)
+            .min_width(200.0)
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(
+                        Stack::create()
+                            .selector(Selector::default().id("properties"))
+                            .spacing(4.0)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// Parses a css selector like `button.primary:active`.
+fn parse_selector(text: &str) -> Selector {
+    let mut selector = Selector::default();
+    let mut kind = ' ';
+    let mut name = String::new();
+
+    for c in text.trim().chars().chain(std::iter::once('.')) {
+        if c != '.' && c != '#' && c != ':' {
+            name.push(c);
+            continue;
+        }
+
+        if !name.is_empty() {
+            let part = std::mem::replace(&mut name, String::new());
+
+            selector = match kind {
+                '.' => selector.class(part),
+                '#' => selector.id(part),
+                ':' => selector.pseudo_class(part),
+                _ if part != "*" => selector.with(part),
+                _ => selector,
+            };
+        }
+
+        kind = c;
+    }
+
+    selector
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_selector() {
+        let selector = parse_selector("button.primary:active");
+        assert_eq!(selector.element, Some(Symbol::new("button")));
+        assert!(selector.has_class("primary"));
+        assert!(selector.has_pseudo_class("active"));
+        assert_eq!(selector.id, None);
+
+        let selector = parse_selector("#preview");
+        assert_eq!(selector.element, None);
+        assert_eq!(selector.id, Some(Symbol::new("preview")));
+
+        assert_eq!(parse_selector("*").element, None);
+    }
+}
@@ -0,0 +1,126 @@
+use std::mem;
+
+use crate::{prelude::*, render::RenderTarget};
+
+/// Describes the frame that a `SurfaceRenderer` draws.
+pub struct SurfaceFrame<'a> {
+    /// Pixel buffer in ARGB format with the size of the surface, it is composited into the
+    /// widget tree after the frame is rendered.
+    pub texture: &'a mut RenderTarget,
+
+    /// Number of the frame, starts with `0`.
+    pub frame: u64,
+
+    /// `true` if the size of the texture is changed since the last frame.
+    pub resized: bool,
+}
+
+/// Draws the frames of a `RenderSurface`, e.g. a 3D viewport or a game view.
+///
+/// A GPU renderer (OpenGL, wgpu) creates its device in `init`, draws each frame into an offscreen
+/// texture and reads it back into the texture of the frame.
+pub trait SurfaceRenderer {
+    /// Is called once before the first frame. `Context::try_raw_window_handle` returns the handle
+    /// to create a GPU instance or surface for the native window.
+    fn init(&mut self, _ctx: &mut Context<'_>) {}
+
+    /// Draws the next frame.
+    fn render(&mut self, frame: SurfaceFrame<'_>);
+}
+
+// The `RenderSurfaceState` renders the frames of the surface renderer.
+#[derive(Default, AsAny)]
+struct RenderSurfaceState {
+    renderer: Option<Box<dyn SurfaceRenderer>>,
+    frame: u64,
+}
+
+impl State for RenderSurfaceState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(renderer) = &mut self.renderer {
+            renderer.init(ctx);
+        }
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let renderer = match &mut self.renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
+
+        let bounds = ctx.widget().clone::<Rectangle>("bounds");
+        let (width, height) = (bounds.width() as u32, bounds.height() as u32);
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let continuous = *ctx.widget().get::<bool>("continuous");
+        let resized = {
+            let texture = ctx.widget().get::<RenderTarget>("texture");
+            texture.width() as u32 != width || texture.height() as u32 != height
+        };
+
+        if !continuous && !resized && self.frame > 0 {
+            return;
+        }
+
+        let mut texture = mem::take(ctx.widget().get_mut::<RenderTarget>("texture"));
+
+        if resized {
+            texture = RenderTarget::new(width, height);
+        }
+
+        renderer.render(SurfaceFrame {
+            texture: &mut texture,
+            frame: self.frame,
+            resized,
+        });
+
+        ctx.widget().set("texture", texture);
+        self.frame += 1;
+
+        // draws the new texture, a continuous surface requests its next frame with it also if
+        // the window renders on demand
+        ctx.request_update();
+    }
+}
+
+widget!(
+    /// The `RenderSurface` widget embeds the frames of a `SurfaceRenderer`, e.g. a 3D viewport
+    /// or a game view, into the widget tree. The renderer is called each frame with a texture of
+    /// the size of the widget.
+    ///
+    /// **CSS element:** `render-surface`
+    RenderSurface<RenderSurfaceState>: MouseHandler {
+        /// Sets or shares the texture that contains the last rendered frame.
+        texture: RenderTarget,
+
+        /// Sets or shares a value that describes if a new frame is rendered in each frame of the
+        /// window. If `false` only the first frame is rendered.
+        continuous: bool,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl RenderSurface {
+    /// Sets the renderer that draws the frames of the surface.
+    pub fn renderer(mut self, renderer: impl SurfaceRenderer + 'static) -> Self {
+        self.state_mut().renderer = Some(Box::new(renderer));
+        self
+    }
+}
+
+impl Template for RenderSurface {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("RenderSurface")
+            .selector("render-surface")
+            .continuous(true)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(TextureRenderObject)
+    }
+}
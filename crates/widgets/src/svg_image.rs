@@ -0,0 +1,35 @@
+use crate::prelude::*;
+
+widget!(
+    /// The `SvgImage` widget draws a scalable vector graphic, e.g. an icon that stays crisp at
+    /// any size. Without a fixed size it takes the size of the svg. It is not interactive.
+    ///
+    /// **CSS element:** `svg-image`
+    SvgImage {
+        /// Sets or shares the svg property.
+        svg: Svg,
+
+        /// Sets or shares the brush of the shapes that are painted with `currentColor`.
+        icon_brush: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for SvgImage {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("SvgImage")
+            .accessible_role("image")
+            .selector("svg-image")
+            .icon_brush(colors::LINK_WATER_COLOR)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(SvgRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(FixedSizeLayout::new())
+    }
+}
@@ -0,0 +1,886 @@
+use std::cell::Cell;
+
+use crate::{prelude::*, shell::Key};
+
+// Maximum time in milliseconds between two clicks on a header divider to auto size the column.
+static DOUBLE_CLICK_TIME: f64 = 500.0;
+
+// Minimum width of an auto sized column.
+static MIN_COLUMN_WIDTH: f64 = 16.0;
+
+type CellText = Option<Box<dyn Fn(&mut Context<'_>, usize) -> String + 'static>>;
+
+/// Used to build a table column, specifying additional details.
+#[derive(Default)]
+pub struct TableColumnBuilder {
+    header: String,
+    width: Column,
+    sortable: bool,
+    cell_builder: WidgetBuildContext,
+    cell_text: CellText,
+}
+
+impl TableColumnBuilder {
+    /// Creates a new `TableColumnBuilder` with default values.
+    pub fn new() -> Self {
+        TableColumnBuilder::default()
+    }
+
+    /// Inserts the header text.
+    pub fn header(mut self, header: impl Into<String>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Inserts the column width.
+    pub fn width<C: Into<Column>>(mut self, width: C) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Inserts if the column could be sorted by clicking on its header.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Inserts the builder that builds the cell of the column for the given row index.
+    pub fn cell_builder<F: Fn(&mut BuildContext, usize) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.cell_builder = Some(Box::new(builder));
+        self
+    }
+
+    /// Inserts the function that returns the text of the cell of the given row index, that is
+    /// copied to the clipboard. Without it the text of the built cell is copied.
+    pub fn cell_text<F: Fn(&mut Context<'_>, usize) -> String + 'static>(
+        mut self,
+        cell_text: F,
+    ) -> Self {
+        self.cell_text = Some(Box::new(cell_text));
+        self
+    }
+
+    /// Builds the table column.
+    pub fn build(self) -> TableColumn {
+        TableColumn {
+            header: self.header,
+            width: self.width,
+            sortable: self.sortable,
+            cell_builder: self.cell_builder,
+            cell_text: self.cell_text,
+        }
+    }
+}
+
+/// Describes a column of the `TableView` with its header and the builder of its cells.
+pub struct TableColumn {
+    header: String,
+    width: Column,
+    sortable: bool,
+    cell_builder: WidgetBuildContext,
+    cell_text: CellText,
+}
+
+impl TableColumn {
+    /// Creates a new `TableColumnBuilder` object with default values.
+    pub fn create() -> TableColumnBuilder {
+        TableColumnBuilder::new()
+    }
+
+    /// Gets the header text.
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Gets the column width.
+    pub fn width(&self) -> Column {
+        self.width
+    }
+
+    /// Gets if the column could be sorted.
+    pub fn sortable(&self) -> bool {
+        self.sortable
+    }
+}
+
+// Returns the current time of the application clock in milliseconds.
+fn timestamp() -> f64 {
+    Clock::current().now().as_secs_f64() * 1000.0
+}
+
+/// The `TableViewState` handles the sorting, the virtualized rows and columns, the frozen rows
+/// and columns, the keyboard cell navigation, the selection and the auto sizing of the columns
+/// of the `TableView` widget.
+#[derive(Default, AsAny)]
+pub struct TableViewState {
+    columns: Vec<TableColumn>,
+    headers: Vec<Entity>,
+    count: usize,
+    first_row: usize,
+    visible_rows: usize,
+    first_column: usize,
+    frozen_rows: usize,
+    frozen_columns: usize,
+    sort: Option<(usize, SortDirection)>,
+    request_sort: Cell<Option<usize>>,
+    delta: Cell<Option<Point>>,
+
+    // row, column and entity of the built cells.
+    cells: Vec<(usize, usize, Entity)>,
+    // index and entity of the built rows.
+    rows: Vec<(usize, Entity)>,
+    current_cell: Option<(usize, usize)>,
+    anchor_cell: Option<(usize, usize)>,
+    request_focus: Cell<bool>,
+    request_key: Cell<Option<Key>>,
+    request_auto_size: Cell<Option<usize>>,
+    // column and time of the last click on a header divider.
+    divider_click: Cell<Option<(usize, f64)>>,
+}
+
+impl TableViewState {
+    /// Resizes the given column to fit its header and its visible cells.
+    pub fn auto_size_column(&self, column: usize) {
+        self.request_auto_size.set(Some(column));
+    }
+
+    // Auto sizes the column if its header divider is clicked twice in a short time.
+    fn divider_click(&self, column: usize) {
+        let time = timestamp();
+
+        match self.divider_click.take() {
+            Some((last_column, last_time))
+                if last_column == column && time - last_time < DOUBLE_CLICK_TIME =>
+            {
+                self.auto_size_column(column)
+            }
+            _ => self.divider_click.set(Some((column, time))),
+        }
+    }
+
+    fn focus(&self) {
+        self.request_focus.set(true);
+    }
+
+    fn key(&self, key: Key) {
+        self.request_key.set(Some(key));
+    }
+
+    fn request_focus(&self, ctx: &mut Context<'_>) {
+        let focused_widget = ctx.window().get::<Global>("global").focused_widget;
+
+        if focused_widget == Some(ctx.entity) || !ctx.widget().get::<bool>("enabled") {
+            return;
+        }
+
+        if let Some(old_focused_element) = focused_widget {
+            let mut old_focused_element = ctx.get_widget(old_focused_element);
+            old_focused_element.set("focused", false);
+            old_focused_element.update_theme_by_state(false);
+        }
+
+        ctx.window().get_mut::<Global>("global").focused_widget = Some(ctx.entity);
+        ctx.widget().set("focused", true);
+        ctx.widget().update_theme_by_state(false);
+    }
+
+    // Frozen columns and the columns from the first scrolled column on are visible.
+    fn is_column_visible(&self, column: usize) -> bool {
+        column < self.frozen_columns || column >= self.first_column
+    }
+
+    // Keeps the first scrolled row and column behind the frozen ones.
+    fn clamp_scroll(&mut self) {
+        let max_first_row = self
+            .count
+            .saturating_sub(self.visible_rows)
+            .max(self.frozen_rows);
+        self.first_row = self.first_row.max(self.frozen_rows).min(max_first_row);
+
+        let max_first_column = self
+            .columns
+            .len()
+            .saturating_sub(1)
+            .max(self.frozen_columns);
+        self.first_column = self
+            .first_column
+            .max(self.frozen_columns)
+            .min(max_first_column);
+    }
+
+    // Collapses the columns and headers that are scrolled out behind the frozen columns.
+    fn update_columns(&self, ctx: &mut Context<'_>) {
+        let columns = Columns(
+            self.columns
+                .iter()
+                .enumerate()
+                .map(|(index, column)| {
+                    if self.is_column_visible(index) {
+                        column.width
+                    } else {
+                        Column::from(0.0)
+                    }
+                })
+                .collect(),
+        );
+
+        ctx.widget().set("columns", columns);
+
+        for (index, header) in self.headers.iter().enumerate() {
+            let visibility = if self.is_column_visible(index) {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            };
+
+            ctx.get_widget(*header).set("visibility", visibility);
+        }
+    }
+
+    // Returns the first scrolled column that is needed to show the given column completely in the
+    // visible area.
+    fn first_column_to_show(&self, column: usize, ctx: &mut Context<'_>) -> usize {
+        let available_width = ctx
+            .child("rows_viewport")
+            .get::<Rectangle>("bounds")
+            .width();
+        let widths: Vec<f64> = ctx
+            .widget()
+            .get::<Columns>("columns")
+            .iter()
+            .map(|column| column.current_width())
+            .collect();
+
+        let mut first_column = self.first_column;
+        let mut width: f64 = widths
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index <= column && self.is_column_visible(*index))
+            .map(|(_, width)| width)
+            .sum();
+
+        while width > available_width && first_column < column {
+            width -= widths[first_column];
+            first_column += 1;
+        }
+
+        first_column
+    }
+
+    // Handles the Ctrl shortcuts of the table. Returns `true` if the rows must be rebuilt.
+    fn handle_key(&mut self, key: Key, registry: &mut Registry, ctx: &mut Context<'_>) -> bool {
+        let keyboard_state = ctx.window().get::<Global>("global").keyboard_state.clone();
+
+        if !keyboard_state.is_ctrl_down() || self.count == 0 || self.columns.is_empty() {
+            return false;
+        }
+
+        match key {
+            Key::A(_) => {
+                self.anchor_cell = Some((0, 0));
+                self.current_cell = Some((self.count - 1, self.columns.len() - 1));
+                self.update_current_cell(ctx);
+                false
+            }
+            Key::C(_) => {
+                self.copy(registry, ctx);
+                false
+            }
+            _ => self.navigate(key, keyboard_state.is_shift_down(), ctx),
+        }
+    }
+
+    // Returns the first and the last cell of the selected range.
+    fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        let current_cell = self.current_cell?;
+        let anchor_cell = self.anchor_cell.unwrap_or(current_cell);
+
+        Some((
+            (
+                current_cell.0.min(anchor_cell.0),
+                current_cell.1.min(anchor_cell.1),
+            ),
+            (
+                current_cell.0.max(anchor_cell.0),
+                current_cell.1.max(anchor_cell.1),
+            ),
+        ))
+    }
+
+    // Returns the text of a cell from the text function of its column or from the built cell.
+    fn cell_text(&self, row: usize, column: usize, ctx: &mut Context<'_>) -> String {
+        if let Some(cell_text) = self.columns.get(column).and_then(|c| c.cell_text.as_ref()) {
+            return cell_text(ctx, row);
+        }
+
+        self.cells
+            .iter()
+            .find(|(r, c, _)| *r == row && *c == column)
+            .and_then(|(_, _, cell)| ctx.get_widget(*cell).try_clone::<String16>("text"))
+            .map(|text| text.to_string())
+            .unwrap_or_default()
+    }
+
+    // Copies the selected cells as tab separated text to the clipboard.
+    fn copy(&self, registry: &mut Registry, ctx: &mut Context<'_>) {
+        let ((first_row, first_column), (last_row, last_column)) =
+            if let Some(selection) = self.selection() {
+                selection
+            } else {
+                return;
+            };
+
+        let mut text = String::new();
+
+        for row in first_row..=last_row {
+            let cells: Vec<String> = (first_column..=last_column)
+                .map(|column| {
+                    self.cell_text(row, column, ctx)
+                        .replace(|c: char| c == '\t' || c == '\n', " ")
+                })
+                .collect();
+
+            text.push_str(&cells.join("\t"));
+            text.push('\n');
+        }
+
+        if let Some(clipboard) = registry.try_get_mut::<Clipboard>("clipboard") {
+            clipboard.set_text(text);
+        }
+    }
+
+    // Moves the current cell with Ctrl + arrow keys and scrolls it into the visible area. With
+    // Shift the selection is extended. Returns `true` if the rows must be rebuilt.
+    fn navigate(&mut self, key: Key, extend_selection: bool, ctx: &mut Context<'_>) -> bool {
+        let (row, column) = self.current_cell.unwrap_or((self.first_row, 0));
+        let (previous_column, next_column) = if ctx.flow_direction().is_right_to_left() {
+            (Key::Right, Key::Left)
+        } else {
+            (Key::Left, Key::Right)
+        };
+
+        let current_cell = match key {
+            Key::Up => (row.saturating_sub(1), column),
+            Key::Down => ((row + 1).min(self.count - 1), column),
+            k if k == previous_column => (row, column.saturating_sub(1)),
+            k if k == next_column => (row, (column + 1).min(self.columns.len() - 1)),
+            _ => return false,
+        };
+
+        if !extend_selection {
+            self.anchor_cell = Some(current_cell);
+        } else if self.anchor_cell.is_none() {
+            self.anchor_cell = Some((row, column));
+        }
+
+        self.current_cell = Some(current_cell);
+        let (row, column) = current_cell;
+
+        let first_row = if row < self.frozen_rows {
+            self.first_row
+        } else if row < self.first_row {
+            row
+        } else if row >= self.first_row + self.visible_rows {
+            row + 1 - self.visible_rows.max(1)
+        } else {
+            self.first_row
+        };
+
+        let first_column = if column < self.frozen_columns {
+            self.first_column
+        } else if column < self.first_column {
+            column
+        } else {
+            self.first_column_to_show(column, ctx)
+        };
+
+        if first_row != self.first_row || first_column != self.first_column {
+            self.first_row = first_row;
+            self.first_column = first_column;
+            self.update_columns(ctx);
+            return true;
+        }
+
+        self.update_current_cell(ctx);
+        false
+    }
+
+    // Exposes the current cell as active descendant of the table to assistive technologies and
+    // highlights the selected rows.
+    fn update_current_cell(&self, ctx: &mut Context<'_>) {
+        let ((first_row, _), (last_row, _)) = if let Some(selection) = self.selection() {
+            selection
+        } else {
+            return;
+        };
+
+        for (row, entity) in &self.rows {
+            let mut widget = ctx.get_widget(*entity);
+            widget.set("selected", *row >= first_row && *row <= last_row);
+            widget.update_theme_by_state(false);
+        }
+
+        let (row, column) = if let Some(current_cell) = self.current_cell {
+            current_cell
+        } else {
+            return;
+        };
+
+        if let Some((_, _, cell)) = self
+            .cells
+            .iter()
+            .find(|(r, c, _)| *r == row && *c == column)
+        {
+            let entity = ctx.entity;
+            ctx.build_context()
+                .register_property("accessible_active_descendant", entity, *cell);
+        }
+    }
+
+    // Sets the width of the given column to the widest of its header and its visible cells.
+    fn auto_size(&mut self, column: usize, ctx: &mut Context<'_>) {
+        if column >= self.columns.len() {
+            return;
+        }
+
+        let mut width = self
+            .headers
+            .get(column)
+            .map_or(0.0, |header| measure_width(*header, ctx));
+
+        // leaves space for the sort indicator
+        if self.columns[column].sortable {
+            if let Some(header) = self.headers.get(column) {
+                width += ctx.get_widget(*header).clone_or_default::<f64>("icon_size") + 2.0;
+            }
+        }
+
+        for (_, _, cell) in self.cells.iter().filter(|(_, c, _)| *c == column) {
+            width = width.max(measure_width(*cell, ctx));
+        }
+
+        self.columns[column].width = Column::from(width.ceil().max(MIN_COLUMN_WIDTH));
+        self.update_columns(ctx);
+    }
+
+    fn sort(&self, column: usize) {
+        self.request_sort.set(Some(column));
+    }
+
+    fn scroll(&self, delta: Point) {
+        self.delta.set(Some(delta));
+    }
+
+    // Toggles the sort direction of the given column and updates the sort indicators of the headers.
+    fn update_sort(&mut self, column: usize, ctx: &mut Context<'_>) {
+        if !self.columns.get(column).map_or(false, |c| c.sortable) {
+            return;
+        }
+
+        let direction = match self.sort {
+            Some((sort_column, direction)) if sort_column == column => direction.toggle(),
+            _ => SortDirection::Ascending,
+        };
+
+        if let Some((sort_column, _)) = self.sort {
+            if let Some(header) = self.headers.get(sort_column) {
+                ctx.get_widget(*header).set("icon", String::default());
+            }
+        }
+
+        if let Some(header) = self.headers.get(column) {
+            let icon = match direction {
+                SortDirection::Ascending => material_font_icons::ARROW_DROP_UP_FONT_ICON,
+                SortDirection::Descending => material_font_icons::ARROW_DROP_DOWN_FONT_ICON,
+            };
+            ctx.get_widget(*header).set("icon", String::from(icon));
+        }
+
+        self.sort = Some((column, direction));
+
+        ctx.push_event_strategy_by_entity(
+            SortEvent { column, direction },
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    // Builds the frozen rows and only the rows and columns that fits into the visible area of
+    // the table.
+    fn build_rows(&mut self, ctx: &mut Context<'_>) {
+        let (frozen_rows_panel, rows_panel) = match (
+            ctx.entity_of_child("frozen_rows_panel"),
+            ctx.entity_of_child("rows_panel"),
+        ) {
+            (Some(frozen_rows_panel), Some(rows_panel)) => (frozen_rows_panel, rows_panel),
+            _ => return,
+        };
+
+        ctx.clear_children_of(frozen_rows_panel);
+        ctx.clear_children_of(rows_panel);
+        self.cells.clear();
+        self.rows.clear();
+
+        let entity = ctx.entity;
+        let row_height = *ctx.widget().get::<f64>("row_height");
+        let frozen_rows = self.frozen_rows.min(self.count);
+        let last_row = (self.first_row + self.visible_rows).min(self.count);
+        let mut cells = vec![];
+
+        for row in (0..frozen_rows).chain(self.first_row..last_row) {
+            let build_context = &mut ctx.build_context();
+            let row_entity = Grid::create()
+                .selector("table-view-row")
+                .accessible_role("row")
+                .height(row_height)
+                .columns(entity)
+                .build(build_context);
+
+            // the header row has the row index 0
+            build_context.register_property::<usize>("accessible_row_index", row_entity, row + 1);
+            build_context.register_property("selected", row_entity, false);
+            self.rows.push((row, row_entity));
+
+            for (index, column) in self.columns.iter().enumerate() {
+                if !self.is_column_visible(index) {
+                    continue;
+                }
+
+                if let Some(builder) = &column.cell_builder {
+                    let cell = builder(build_context, row);
+                    build_context.register_property::<usize>("column", cell, index);
+                    register_cell_properties(build_context, cell, row + 1, index);
+
+                    if let Some(header) = self.headers.get(index) {
+                        build_context.register_property("accessible_header", cell, *header);
+                    }
+
+                    build_context.append_child(row_entity, cell);
+                    cells.push(cell);
+                    self.cells.push((row, index, cell));
+                }
+            }
+
+            if row < frozen_rows {
+                build_context.append_child(frozen_rows_panel, row_entity);
+            } else {
+                build_context.append_child(rows_panel, row_entity);
+            }
+        }
+
+        for cell in cells {
+            ctx.get_widget(cell).update_properties_by_theme();
+        }
+    }
+}
+
+// Registers the role and the coordinates of a cell or column header. Interactive cells, e.g. a
+// check box, keep their own role.
+fn register_cell_properties(ctx: &mut BuildContext, cell: Entity, row: usize, column: usize) {
+    let role = ctx
+        .get_widget(cell)
+        .try_clone::<AccessibleRole>("accessible_role")
+        .unwrap_or_default();
+
+    if role == AccessibleRole::None || role == AccessibleRole::Text {
+        ctx.register_property("accessible_role", cell, AccessibleRole::Cell);
+    }
+
+    ctx.register_property::<usize>("accessible_row_index", cell, row);
+    ctx.register_property::<usize>("accessible_column_index", cell, column);
+}
+
+// Measures the width of the text of a widget with its padding and margin. Widgets without a text
+// keep their current width.
+fn measure_width(entity: Entity, ctx: &mut Context<'_>) -> f64 {
+    let widget = ctx.get_widget(entity);
+    let text = widget.try_clone::<String16>("text");
+    let font_size = widget.clone_or_default::<f64>("font_size");
+    let font = widget.clone_or_default::<String>("font");
+    let padding = widget.clone_or_default::<Thickness>("padding");
+    let margin = widget.clone_or_default::<Thickness>("margin");
+    let width = widget.get::<Rectangle>("bounds").width();
+
+    match text {
+        Some(text) => {
+            ctx.render_context_2_d()
+                .measure(text.to_string().as_str(), font_size, font.as_str())
+                .width
+                + padding.left()
+                + padding.right()
+                + margin.left()
+                + margin.right()
+        }
+        None => width + margin.left() + margin.right(),
+    }
+}
+
+impl State for TableViewState {
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(column) = self.request_sort.take() {
+            self.update_sort(column, ctx);
+        }
+
+        if self.request_focus.take() {
+            self.request_focus(ctx);
+        }
+
+        if let Some(column) = self.request_auto_size.take() {
+            self.auto_size(column, ctx);
+        }
+
+        let count = ctx.widget().clone_or_default::<usize>("count");
+        let mut request_update = *ctx.widget().get::<bool>("request_update");
+
+        let frozen_rows = ctx.widget().clone_or_default::<usize>("frozen_rows");
+        let frozen_columns = ctx.widget().clone_or_default::<usize>("frozen_columns");
+
+        if frozen_rows != self.frozen_rows || frozen_columns != self.frozen_columns {
+            self.frozen_rows = frozen_rows;
+            self.frozen_columns = frozen_columns;
+            self.clamp_scroll();
+            self.update_columns(ctx);
+            request_update = true;
+        }
+
+        if count != self.count {
+            self.count = count;
+            self.clamp_scroll();
+            self.current_cell = self.current_cell.filter(|(row, _)| *row < count);
+            self.anchor_cell = self.anchor_cell.filter(|(row, _)| *row < count);
+            ctx.widget().set::<usize>("accessible_row_count", count + 1);
+            request_update = true;
+        }
+
+        if let Some(key) = self.request_key.take() {
+            request_update = self.handle_key(key, registry, ctx) || request_update;
+        }
+
+        if let Some(delta) = self.delta.take() {
+            if delta.y < 0.0 && self.first_row + self.visible_rows < self.count {
+                self.first_row += 1;
+                request_update = true;
+            } else if delta.y > 0.0 && self.first_row > self.frozen_rows {
+                self.first_row -= 1;
+                request_update = true;
+            }
+
+            if delta.x < 0.0 && self.first_column + 1 < self.columns.len() {
+                self.first_column += 1;
+                self.update_columns(ctx);
+                request_update = true;
+            } else if delta.x > 0.0 && self.first_column > self.frozen_columns {
+                self.first_column -= 1;
+                self.update_columns(ctx);
+                request_update = true;
+            }
+        }
+
+        if request_update {
+            self.build_rows(ctx);
+            self.update_current_cell(ctx);
+            ctx.widget().set("request_update", false);
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let row_height = *ctx.widget().get::<f64>("row_height");
+        let height = ctx
+            .child("rows_viewport")
+            .get::<Rectangle>("bounds")
+            .height();
+
+        let visible_rows = if row_height > 0.0 {
+            (height / row_height).ceil() as usize
+        } else {
+            0
+        };
+
+        if visible_rows != self.visible_rows {
+            self.visible_rows = visible_rows;
+            self.clamp_scroll();
+            ctx.widget().set("request_update", true);
+            let _ = ctx.request_sender().send(shell::ShellRequest::Update);
+        }
+    }
+}
+
+widget!(
+    /// The `TableView` displays rows of data in columns with a header row. Only the rows that fit
+    /// into the visible area are built. Clicking on the header of a sortable column raises a
+    /// sort event.
+    ///
+    /// Leading rows and columns could be frozen with `frozen_rows` and `frozen_columns`, they
+    /// stay in place while the rest of the table is scrolled.
+    ///
+    /// If the table is focused, the current cell is moved with `Ctrl` and the arrow keys and the
+    /// selection is extended with `Shift`. `Ctrl + C` copies the selected cells as tab separated
+    /// text to the clipboard. The cells, rows and column headers are exposed with their
+    /// coordinates to assistive technologies.
+    ///
+    /// A double click on the divider at the right of a column header or
+    /// `TableViewState::auto_size_column` resizes the column to fit its visible cells.
+    ///
+    /// **CSS element:** `table-view`
+    TableView<TableViewState>: KeyDownHandler, MouseHandler, SortHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the columns property.
+        columns: Columns,
+
+        /// Sets or shares the number of rows.
+        count: usize,
+
+        /// Sets or shares the height of a row.
+        row_height: f64,
+
+        /// Sets or shares the number of leading rows that are not scrolled.
+        frozen_rows: usize,
+
+        /// Sets or shares the number of leading columns that are not scrolled horizontally.
+        frozen_columns: usize,
+
+        /// Sets or shares a value to request an update of the rows, e.g. after the data is sorted.
+        request_update: bool,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl TableView {
+    /// Inserts a new column.
+    pub fn column(mut self, column: TableColumn) -> Self {
+        self.state_mut().columns.push(column);
+        self
+    }
+}
+
+impl Template for TableView {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let columns = Columns(self.state.columns.iter().map(|c| c.width).collect());
+        let mut header_panel = Grid::create()
+            .selector(Selector::default().id("header_panel"))
+            .clip(true)
+            .columns(id)
+            .attach(Grid::row(0));
+        let mut headers = vec![];
+
+        for (index, column) in self.state.columns.iter().enumerate() {
+            let header = Button::create()
+                .selector("table-view-header")
+                .accessible_role("column-header")
+                .min_width(0.0)
+                .text(column.header.as_str())
+                .attach(Grid::column(index))
+                .on_click(move |states, _| {
+                    states.get::<TableViewState>(id).sort(index);
+                    false
+                })
+                .build(ctx);
+
+            register_cell_properties(ctx, header, 0, index);
+            header_panel = header_panel.child(header).child(
+                Button::create()
+                    .selector("table-view-header-divider")
+                    .cursor("resize-horizontal")
+                    .accessible_role(AccessibleRole::None)
+                    .min_width(0.0)
+                    .width(4.0)
+                    .horizontal_alignment("end")
+                    .attach(Grid::column(index))
+                    .on_click(move |states, _| {
+                        states.get::<TableViewState>(id).divider_click(index);
+                        true
+                    })
+                    .build(ctx),
+            );
+            headers.push(header);
+        }
+
+        let header_panel = header_panel.accessible_role("row").build(ctx);
+        ctx.register_property::<usize>("accessible_row_index", header_panel, 0);
+        ctx.register_property::<usize>("accessible_row_count", id, 1);
+        ctx.register_property::<usize>("accessible_column_count", id, self.state.columns.len());
+
+        self.state_mut().headers = headers;
+
+        self.name("TableView")
+            .accessible_role("table")
+            .selector("table-view")
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding(2.0)
+            .row_height(24.0)
+            .columns(columns)
+            .on_scroll(move |states, delta| {
+                states.get::<TableViewState>(id).scroll(delta);
+                false
+            })
+            .on_mouse_down(move |states, _| {
+                states.get::<TableViewState>(id).focus();
+                false
+            })
+            .on_key_down(move |states, event| {
+                states.get::<TableViewState>(id).key(event.key);
+                false
+            })
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .opacity(id)
+                    .child(
+                        Grid::create()
+                            .rows(Rows::create().row("auto").row("auto").row("*").build())
+                            .child(header_panel)
+                            .child(
+                                Stack::create()
+                                    .selector(Selector::default().id("frozen_rows_panel"))
+                                    .clip(true)
+                                    .attach(Grid::row(1))
+                                    .build(ctx),
+                            )
+                            .child(
+                                Grid::create()
+                                    .selector(Selector::default().id("rows_viewport"))
+                                    .clip(true)
+                                    .attach(Grid::row(2))
+                                    .child(
+                                        Stack::create()
+                                            .selector(Selector::default().id("rows_panel"))
+                                            .vertical_alignment("start")
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -20,6 +20,15 @@ widget!(
         /// Sets or shares the font property.
         font: String,
 
+        /// Sets or shares the text trimming property.
+        text_trimming: TextTrimming,
+
+        /// Sets or shares the text wrap property.
+        text_wrap: TextWrap,
+
+        /// Sets or shares the composition string of an input method that is displayed inline.
+        composition: TextComposition,
+
         /// Sets or shares the css selector property.
         selector: Selector
     }
@@ -28,10 +37,13 @@ widget!(
 impl Template for TextBlock {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
         self.name("TextBlock")
+            .accessible_role("text")
             .text("")
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto Regular")
+            .text_trimming("none")
+            .text_wrap("no_wrap")
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
@@ -3,36 +3,60 @@ use std::cell::Cell;
 use super::behaviors::MouseBehavior;
 use crate::{
     prelude::*,
-    shell::{Key, KeyEvent},
+    shell::{CompositionEvent, Key, KeyEvent},
 };
 
 #[derive(Clone)]
-enum TextBoxAction {
+pub(crate) enum TextBoxAction {
     Key(KeyEvent),
+    TextInput(String),
+    Composition(CompositionEvent),
     Mouse(Point),
 }
 
+type Validator = Option<Box<dyn Fn(&str) -> Result<(), String>>>;
+
+// Sets the `is_valid` and `error_message` properties of the widget to the result of a validator.
+pub(crate) fn set_validation(widget: &mut WidgetContainer<'_>, result: Result<(), String>) {
+    let (valid, message) = match result {
+        Ok(()) => (true, String16::default()),
+        Err(message) => (false, String16::from(message)),
+    };
+
+    if *widget.get::<bool>("is_valid") != valid {
+        widget.set("is_valid", valid);
+    }
+
+    if *widget.get::<String16>("error_message") != message {
+        widget.set("error_message", message);
+    }
+}
+
 /// The `TextBoxState` handles the text processing of the `TextBox` widget.
 #[derive(AsAny)]
 pub struct TextBoxState {
-    action: RefCell<Option<TextBoxAction>>,
-    cursor_x: Cell<f64>,
+    actions: RefCell<Vec<TextBoxAction>>,
     len: Cell<usize>,
+    undo_stack: RefCell<UndoStack<TextEdit>>,
+    validator: Validator,
+    validated_text: Option<String16>,
 }
 
 impl Default for TextBoxState {
     fn default() -> Self {
         TextBoxState {
-            action: RefCell::new(None),
-            cursor_x: Cell::new(0.0),
+            actions: RefCell::new(vec![]),
             len: Cell::new(0),
+            undo_stack: RefCell::new(UndoStack::new()),
+            validator: None,
+            validated_text: None,
         }
     }
 }
 
 impl TextBoxState {
-    fn action(&self, action: TextBoxAction) {
-        *self.action.borrow_mut() = Some(action);
+    pub(crate) fn action(&self, action: TextBoxAction) {
+        self.actions.borrow_mut().push(action);
     }
 
     fn handle_key_event(&self, key_event: KeyEvent, ctx: &mut Context<'_>) {
@@ -41,10 +65,25 @@ impl TextBoxState {
         }
 
         let text = ctx.widget().clone::<String16>("text");
-        let mut current_selection = *ctx.child("cursor").get::<TextSelection>("text_selection");
+        let current_selection = *ctx.child("cursor").get::<TextSelection>("text_selection");
+        let ctrl = ctx
+            .window()
+            .get::<Global>("global")
+            .keyboard_state
+            .is_ctrl_down();
+        let shift = ctx
+            .window()
+            .get::<Global>("global")
+            .keyboard_state
+            .is_shift_down();
 
         match key_event.key {
+            Key::Z(_) if ctrl && shift => self.redo(ctx),
+            Key::Z(_) if ctrl => self.undo(ctx),
+            Key::Y(_) if ctrl => self.redo(ctx),
             Key::Left => {
+                self.undo_stack.borrow_mut().seal();
+
                 if let Some(selection) = ctx
                     .child("cursor")
                     .try_get_mut::<TextSelection>("text_selection")
@@ -54,6 +93,8 @@ impl TextBoxState {
                 }
             }
             Key::Right => {
+                self.undo_stack.borrow_mut().seal();
+
                 if let Some(selection) = ctx
                     .child("cursor")
                     .try_get_mut::<TextSelection>("text_selection")
@@ -63,40 +104,29 @@ impl TextBoxState {
             }
             Key::Backspace => {
                 if !text.is_empty() && current_selection.start_index > 0 {
-                    for _ in 0..=current_selection.length {
-                        ctx.widget()
-                            .get_mut::<String16>("text")
-                            .remove(current_selection.start_index - 1);
-                        current_selection.start_index =
-                            (current_selection.start_index as i32 - 1).max(0) as usize;
-                    }
-
-                    if let Some(selection) = ctx
-                        .child("cursor")
-                        .try_get_mut::<TextSelection>("text_selection")
-                    {
-                        selection.start_index = current_selection.start_index;
-                    }
-
-                    ctx.push_event_strategy_by_entity(
-                        ChangedEvent(ctx.entity),
-                        ctx.entity,
-                        EventStrategy::Direct,
+                    let end = current_selection.start_index;
+                    let start = end - (current_selection.length + 1).min(end);
+
+                    self.edit(
+                        TextEdit::Delete {
+                            index: start,
+                            text: text.get_string(start, end).unwrap_or_default(),
+                        },
+                        ctx,
                     );
                 }
             }
             Key::Delete => {
                 if !text.is_empty() && text.len() > current_selection.start_index {
-                    for _ in 0..=current_selection.length {
-                        ctx.widget()
-                            .get_mut::<String16>("text")
-                            .remove(current_selection.start_index);
-                    }
-
-                    ctx.push_event_strategy_by_entity(
-                        ChangedEvent(ctx.entity),
-                        ctx.entity,
-                        EventStrategy::Direct,
+                    let start = current_selection.start_index;
+                    let end = start + (current_selection.length + 1).min(text.len() - start);
+
+                    self.edit(
+                        TextEdit::Delete {
+                            index: start,
+                            text: text.get_string(start, end).unwrap_or_default(),
+                        },
+                        ctx,
                     );
                 }
             }
@@ -113,31 +143,107 @@ impl TextBoxState {
                     EventStrategy::Direct,
                 )
             }
-            _ => {
-                if key_event.text.is_empty() {
-                    return;
-                }
-                ctx.widget()
-                    .get_mut::<String16>("text")
-                    .insert_str(current_selection.start_index, key_event.text.as_str());
+            _ => {}
+        }
+    }
 
-                if let Some(selection) = ctx
-                    .child("cursor")
-                    .try_get_mut::<TextSelection>("text_selection")
-                {
-                    selection.start_index =
-                        current_selection.start_index + key_event.text.encode_utf16().count();
-                }
+    fn handle_composition_event(&self, event: CompositionEvent, ctx: &mut Context<'_>) {
+        if !ctx.widget().get::<bool>("focused") {
+            return;
+        }
 
-                ctx.push_event_strategy_by_entity(
-                    ChangedEvent(ctx.entity),
-                    ctx.entity,
-                    EventStrategy::Direct,
-                );
+        match event {
+            CompositionEvent::Update(text) => {
+                let index = ctx
+                    .child("cursor")
+                    .get::<TextSelection>("text_selection")
+                    .start_index;
+                ctx.widget()
+                    .set("composition", TextComposition::new(text, index));
+            }
+            CompositionEvent::Commit(text) => {
+                ctx.widget().set("composition", TextComposition::default());
+                self.insert_text(text.as_str(), ctx);
             }
+            CompositionEvent::Cancel => {
+                ctx.widget().set("composition", TextComposition::default());
+            }
+        }
+    }
+
+    // Inserts the text at the cursor position. Control characters, e.g. of shortcuts, and the
+    // characters that are rejected by the input filter are not inserted.
+    fn insert_text(&self, text: &str, ctx: &mut Context<'_>) {
+        if text.chars().all(char::is_control) {
+            return;
+        }
+
+        let index = ctx
+            .child("cursor")
+            .get::<TextSelection>("text_selection")
+            .start_index;
+
+        let filter = ctx.widget().try_clone::<InputFilter>("input_filter");
+        let text = match filter {
+            Some(filter) => filter.filter(ctx.widget().get::<String16>("text"), index, text),
+            None => text.to_string(),
+        };
+
+        if text.is_empty() {
+            return;
+        }
+
+        self.edit(TextEdit::Insert { index, text }, ctx);
+    }
+
+    // Applies the edit to the text, records it for undo and moves the cursor behind it.
+    fn edit(&self, edit: TextEdit, ctx: &mut Context<'_>) {
+        edit.apply(ctx.widget().get_mut::<String16>("text"));
+        self.set_caret(edit.caret_after_apply(), ctx);
+        self.undo_stack.borrow_mut().push(edit);
+    }
+
+    // Reverts the last edit of the text.
+    fn undo(&self, ctx: &mut Context<'_>) {
+        let edit = self
+            .undo_stack
+            .borrow_mut()
+            .undo(ctx.widget().get_mut::<String16>("text"));
+
+        if let Some(edit) = edit {
+            self.set_caret(edit.caret_after_revert(), ctx);
         }
     }
 
+    // Applies the last reverted edit of the text again.
+    fn redo(&self, ctx: &mut Context<'_>) {
+        let edit = self
+            .undo_stack
+            .borrow_mut()
+            .redo(ctx.widget().get_mut::<String16>("text"));
+
+        if let Some(edit) = edit {
+            self.set_caret(edit.caret_after_apply(), ctx);
+        }
+    }
+
+    // Moves the cursor to the index after the text is changed.
+    fn set_caret(&self, index: usize, ctx: &mut Context<'_>) {
+        if let Some(selection) = ctx
+            .child("cursor")
+            .try_get_mut::<TextSelection>("text_selection")
+        {
+            selection.start_index = index;
+            selection.length = 0;
+        }
+
+        ctx.push_event_strategy_by_entity(
+            ChangedEvent(ctx.entity),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
     fn request_focus(&self, ctx: &mut Context<'_>) {
         let focused_widget = ctx.window().get::<Global>("global").focused_widget;
 
@@ -160,8 +266,43 @@ impl TextBoxState {
         ctx.child("cursor").update_theme_by_state(false);
     }
 
+    // Updates the displayed mask characters if the widget has a mask, e.g. the `PasswordBox`.
+    fn update_mask(&self, ctx: &mut Context<'_>) {
+        let mask = if let Some(mask) = ctx.widget().try_clone::<String>("mask") {
+            mask
+        } else {
+            return;
+        };
+
+        let len = ctx.widget().get::<String16>("text").len();
+        let masked_text = String16::from(mask.repeat(len));
+
+        if *ctx.widget().get::<String16>("masked_text") != masked_text {
+            ctx.widget().set("masked_text", masked_text);
+        }
+    }
+
+    // Validates the text if it is changed since the last validation.
+    fn validate(&mut self, ctx: &mut Context<'_>) {
+        let validator = if let Some(validator) = &self.validator {
+            validator
+        } else {
+            return;
+        };
+
+        let text = ctx.widget().clone::<String16>("text");
+
+        if self.validated_text.as_ref() == Some(&text) {
+            return;
+        }
+
+        set_validation(&mut ctx.widget(), validator(text.as_string().as_str()));
+        self.validated_text = Some(text);
+    }
+
     // Reset selection and offset if text is changed from outside
     fn reset(&self, ctx: &mut Context<'_>) {
+        self.undo_stack.borrow_mut().clear();
         ctx.widget().set("text_selection", TextSelection::default());
         ctx.widget().set("scroll_offset", Point::default());
         ctx.push_event_strategy_by_entity(
@@ -175,6 +316,9 @@ impl TextBoxState {
 impl State for TextBoxState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
         self.len.set(ctx.widget().get::<String16>("text").len());
+        self.update_mask(ctx);
+        self.validate(ctx);
+        ctx.widget().update_theme_by_state(false);
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
@@ -184,77 +328,125 @@ impl State for TextBoxState {
             self.reset(ctx);
         }
 
-        if let Some(action) = self.action.borrow().clone() {
+        // all actions since the last update are handled in order, e.g. fast typed characters
+        let actions: Vec<TextBoxAction> = self.actions.borrow_mut().drain(..).collect();
+
+        for action in actions {
             match action {
                 TextBoxAction::Key(event) => {
                     self.handle_key_event(event, ctx);
                 }
+                TextBoxAction::TextInput(text) => {
+                    if *ctx.widget().get::<bool>("focused") {
+                        self.insert_text(text.as_str(), ctx);
+                    }
+                }
+                TextBoxAction::Composition(event) => {
+                    self.handle_composition_event(event, ctx);
+                }
                 TextBoxAction::Mouse(_p) => {
+                    self.undo_stack.borrow_mut().seal();
                     self.request_focus(ctx);
                 }
             }
         }
 
-        *self.action.borrow_mut() = None;
+        // a pending composition is discarded if the text box lost the focus
+        if !ctx.widget().get::<bool>("focused") {
+            if let Some(composition) = ctx.widget().try_get_mut::<TextComposition>("composition") {
+                if !composition.is_empty() {
+                    *composition = TextComposition::default();
+                }
+            }
+        }
+
+        self.validate(ctx);
         ctx.widget().update_theme_by_state(false);
         self.len.set(ctx.widget().get::<String16>("text").len());
+        self.update_mask(ctx);
     }
 
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
-        let mut cursor_x_delta = 0.0;
-        let mut scroll_viewer_width = 0.0;
-
-        {
-            if let Some(bounds) = ctx
-                .child("scroll_viewer")
-                .try_get_mut::<Rectangle>("bounds")
-            {
-                scroll_viewer_width = bounds.width();
-            }
-        }
-
-        // maybe not use scroll viewer here
-
-        // Adjust offset of text and cursor if cursor position is out of bounds
+        let view_width = ctx
+            .child("scroll_viewer")
+            .get::<Rectangle>("bounds")
+            .width();
+        let text_width = ctx.child("text_block").get::<Rectangle>("bounds").width();
+        let offset = ctx.widget().get::<Point>("scroll_offset").x;
+
+        // the cursor displays the text of the text block, e.g. the masked text of a password box
+        let (text, font, font_size) = {
+            let cursor = ctx.child("cursor");
+            let start_index = cursor.get::<TextSelection>("text_selection").start_index;
+
+            (
+                cursor
+                    .get::<String16>("text")
+                    .get_string(0, start_index)
+                    .unwrap_or_default(),
+                cursor.clone::<String>("font"),
+                *cursor.get::<f64>("font_size"),
+            )
+        };
 
-        {
-            let mut cursor = ctx.child("cursor");
+        let caret_x = ctx
+            .render_context_2_d()
+            .measure(text.as_str(), font_size, font.as_str())
+            .width;
+        let new_offset = scroll_to_caret(offset, caret_x, text_width, view_width);
 
-            if let Some(margin) = cursor.try_get_mut::<Thickness>("margin") {
-                if margin.left() < 0.0 || margin.left() > scroll_viewer_width {
-                    cursor_x_delta = self.cursor_x.get() - margin.left();
-                    margin.set_left(self.cursor_x.get());
-                }
-                self.cursor_x.set(margin.left());
-            }
+        // the text and the cursor are moved in this frame, the next layout uses the new offset
+        if (new_offset - offset).abs() > std::f64::EPSILON {
+            ctx.widget().get_mut::<Point>("scroll_offset").x = new_offset;
 
-            if let Some(bounds) = cursor.try_get_mut::<Rectangle>("bounds") {
-                bounds.set_x(self.cursor_x.get());
+            if let Some(bounds) = ctx.child("text_block").try_get_mut::<Rectangle>("bounds") {
+                bounds.set_x(new_offset);
             }
         }
 
-        if cursor_x_delta != 0.0 {
-            {
-                if let Some(bounds) = ctx.child("text_block").try_get_mut::<Rectangle>("bounds") {
-                    bounds.set_x(bounds.x() + cursor_x_delta);
-                }
-            }
+        let cursor_x = caret_x + new_offset;
+        let mut cursor = ctx.child("cursor");
 
-            if let Some(scroll_offset) = ctx.widget().try_get_mut::<Point>("scroll_offset") {
-                scroll_offset.x += cursor_x_delta;
-            }
+        if (cursor.get::<Thickness>("margin").left() - cursor_x).abs() > std::f64::EPSILON {
+            cursor.get_mut::<Thickness>("margin").set_left(cursor_x);
+            cursor.get_mut::<Rectangle>("bounds").set_x(cursor_x);
         }
     }
 }
 
+// Returns the horizontal scroll offset of a text with the given width that keeps the caret
+// visible inside of the view. The offset is only changed if the caret is outside of the view and
+// it is clamped, so the end of the text is not scrolled into the view.
+fn scroll_to_caret(offset: f64, caret_x: f64, text_width: f64, view_width: f64) -> f64 {
+    if text_width <= view_width {
+        return 0.0;
+    }
+
+    let offset = if caret_x + offset < 0.0 {
+        -caret_x
+    } else if caret_x + offset > view_width {
+        view_width - caret_x
+    } else {
+        offset
+    };
+
+    offset.min(0.0).max(view_width - text_width)
+}
+
 widget!(
     /// The `TextBox` widget represents a single line text input widget.
     ///
+    /// The text could be checked by a `validator`, an invalid text box has the `:invalid` pseudo
+    /// class and the message of the validator as `error_message`.
+    ///
     /// * CSS element: `text-box`
-    TextBox<TextBoxState>: ActivateHandler, ChangedHandler, KeyDownHandler {
+    TextBox<TextBoxState>: ActivateHandler, ChangedHandler, CompositionHandler, KeyDownHandler, TextInputHandler {
         /// Sets or shares the text property.
         text: String16,
 
+        /// Sets or shares the composition string of an input method.
+        composition: TextComposition,
+
         /// Sets or shares the water_mark text property.
         water_mark: String16,
 
@@ -274,7 +466,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -297,16 +489,40 @@ widget!(
         /// Sets or shares ta value that describes if the TextBox should lost focus on activation (enter).
         lost_focus_on_activation: bool,
 
+        /// Sets or shares the value that describes if the text is accepted by the validator.
+        is_valid: bool,
+
+        /// Sets or shares the message of the validator if the text is invalid.
+        error_message: String16,
+
+        /// Sets or shares the filter that drops typed characters that are not allowed.
+        input_filter: InputFilter,
+
+        /// Sets or shares the seconds the caret is shown and hidden while it blinks, `0` turns the blinking off.
+        caret_blink_rate: f64,
+
         /// Sets or shares the css selector property.
         selector: Selector
     }
 );
 
+impl TextBox {
+    /// Inserts a callback that validates the text whenever it is changed. It returns an error
+    /// message if the text is invalid.
+    pub fn validator<F: Fn(&str) -> Result<(), String> + 'static>(mut self, validator: F) -> Self {
+        self.state_mut().validator = Some(Box::new(validator));
+        self
+    }
+}
+
 impl Template for TextBox {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("TextBox")
+            .accessible_role("text-input")
             .selector("text-box")
+            .cursor("text")
             .text("")
+            .composition(TextComposition::default())
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto Regular")
@@ -322,6 +538,10 @@ impl Template for TextBox {
             .focused(false)
             .delta(0.0)
             .lost_focus_on_activation(true)
+            .is_valid(true)
+            .error_message("")
+            .input_filter(InputFilter::default())
+            .caret_blink_rate(DEFAULT_CARET_BLINK_RATE)
             .child(
                 MouseBehavior::create()
                     .on_mouse_down(move |states, p| {
@@ -351,6 +571,7 @@ impl Template for TextBox {
                                                     .vertical_alignment("center")
                                                     .foreground(id)
                                                     .text(id)
+                                                    .composition(id)
                                                     .water_mark(id)
                                                     .font(id)
                                                     .font_size(id)
@@ -369,6 +590,7 @@ impl Template for TextBox {
                                             .scroll_offset(id)
                                             .focused(id)
                                             .text_selection(id)
+                                            .caret_blink_rate(id)
                                             .build(ctx),
                                     )
                                     .build(ctx),
@@ -383,5 +605,40 @@ impl Template for TextBox {
                     .action(TextBoxAction::Key(event));
                 false
             })
+            .on_text_input(move |states, text| -> bool {
+                states
+                    .get::<TextBoxState>(id)
+                    .action(TextBoxAction::TextInput(text));
+                false
+            })
+            .on_composition(move |states, event| -> bool {
+                states
+                    .get::<TextBoxState>(id)
+                    .action(TextBoxAction::Composition(event));
+                false
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_to_caret() {
+        // the text fits into the view
+        assert_eq!(scroll_to_caret(-10.0, 40.0, 80.0, 100.0), 0.0);
+
+        // the caret is visible
+        assert_eq!(scroll_to_caret(-50.0, 120.0, 200.0, 100.0), -50.0);
+
+        // the caret is right of the view
+        assert_eq!(scroll_to_caret(0.0, 150.0, 200.0, 100.0), -50.0);
+
+        // the caret is left of the view
+        assert_eq!(scroll_to_caret(-80.0, 30.0, 200.0, 100.0), -30.0);
+
+        // the end of the text stays at the end of the view
+        assert_eq!(scroll_to_caret(-150.0, 160.0, 200.0, 100.0), -100.0);
     }
 }
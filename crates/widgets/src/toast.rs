@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use crate::prelude::*;
+
+/// The `ToastHostState` shows the toasts that are requested by `Context::show_toast` and removes
+/// each toast after its duration.
+#[derive(Default, AsAny)]
+pub struct ToastHostState {
+    // shown toasts with the time they are removed.
+    toasts: Vec<(Entity, Duration)>,
+}
+
+impl State for ToastHostState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let now = Clock::current().now();
+        let messages: Vec<ToastMessage> = ctx
+            .window()
+            .get_mut::<Global>("global")
+            .toasts
+            .drain(..)
+            .collect();
+
+        for message in messages {
+            let toast = Toast::create()
+                .text(message.text)
+                .build(&mut ctx.build_context());
+            ctx.append_child_entity(toast);
+            ctx.get_widget(toast).update_properties_by_theme();
+            self.toasts.push((toast, now + message.duration));
+        }
+
+        let (expired, toasts): (Vec<_>, Vec<_>) =
+            self.toasts.drain(..).partition(|(_, end)| *end <= now);
+        self.toasts = toasts;
+
+        for (toast, _) in expired {
+            ctx.remove_child(toast);
+        }
+
+        // the toasts are removed without input, so the host needs new frames while it shows one
+        if !self.toasts.is_empty() {
+            ctx.request_update();
+        }
+    }
+}
+
+widget!(
+    /// The `ToastHost` stacks the toasts of a window in its bottom right corner. It is added to
+    /// the overlay of the window with the first call of `Context::show_toast`.
+    ///
+    /// **CSS element:** `toast-host`
+    ToastHost<ToastHostState> {
+        /// Sets or shares the orientation property.
+        orientation: Orientation,
+
+        /// Sets or shares the space between two toasts.
+        spacing: f64,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for ToastHost {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("ToastHost")
+            .selector("toast-host")
+            .orientation("vertical")
+            .spacing(8.0)
+            .horizontal_alignment("end")
+            .vertical_alignment("end")
+            .margin(16.0)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(StackLayout::new())
+    }
+}
+
+widget!(
+    /// The `Toast` widget is a transient notification in the corner of a window, see
+    /// `Context::show_toast`.
+    ///
+    /// **CSS element:** `toast`
+    Toast {
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for Toast {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("Toast")
+            .accessible_role("alert")
+            .selector("toast")
+            .text("")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto Regular")
+            .background(colors::LYNCH_COLOR)
+            .border_brush(colors::BOMBAY_COLOR)
+            .border_width(1.0)
+            .border_radius(4.0)
+            .padding((12.0, 8.0, 12.0, 8.0))
+            .child(
+                TextBlock::create()
+                    .text(id)
+                    .foreground(id)
+                    .font(id)
+                    .font_size(id)
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
@@ -12,7 +12,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -58,9 +58,32 @@ widget!(
     }
 );
 
+impl ToggleButton {
+    /// Sets the glyph, font, size and brush of the icon at once.
+    pub fn font_icon(self, icon: impl Into<Icon>) -> Self {
+        let icon = icon.into();
+        let mut this = self.icon(icon.glyph);
+
+        if let Some(font) = icon.font {
+            this = this.icon_font(font);
+        }
+
+        if let Some(size) = icon.size {
+            this = this.icon_size(size);
+        }
+
+        if let Some(brush) = icon.brush {
+            this = this.icon_brush(brush);
+        }
+
+        this
+    }
+}
+
 impl Template for ToggleButton {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("ToggleButton")
+            .accessible_role("toggle-button")
             .selector("toggle-button")
             .selected(false)
             .height(32.0)
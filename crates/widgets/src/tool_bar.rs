@@ -0,0 +1,287 @@
+use std::{cell::Cell, collections::HashMap};
+
+use crate::prelude::*;
+
+// Width of the button that opens the overflow popup.
+const OVERFLOW_BUTTON_WIDTH: f64 = 32.0;
+
+/// The `ToolBarState` moves the items that don't fit into the tool bar into the overflow popup.
+#[derive(Default, AsAny)]
+pub struct ToolBarState {
+    items: Vec<Entity>,
+    items_panel: Option<Entity>,
+    popup: Option<Entity>,
+    popup_panel: Option<Entity>,
+    widths: HashMap<Entity, f64>,
+    visible_count: usize,
+    pressed_item: Option<Entity>,
+    request_toggle: Cell<bool>,
+}
+
+impl ToolBarState {
+    fn toggle_popup(&self) {
+        self.request_toggle.set(true);
+    }
+
+    fn is_popup_open(&self, ctx: &mut Context<'_>) -> bool {
+        if let Some(popup) = self.popup {
+            return *ctx.get_widget(popup).get::<Visibility>("visibility") == Visibility::Visible;
+        }
+
+        false
+    }
+
+    fn open_popup(&self, ctx: &mut Context<'_>) {
+        let popup = if let Some(popup) = self.popup {
+            popup
+        } else {
+            return;
+        };
+
+        let (position, bounds) = {
+            let button = ctx.child("overflow_button");
+            (
+                *button.get::<Point>("position"),
+                *button.get::<Rectangle>("bounds"),
+            )
+        };
+
+        // the popup is right aligned to the overflow button
+        let popup_width = ctx.get_widget(popup).get::<Rectangle>("bounds").width();
+        let x = (position.x + bounds.width() - popup_width).max(0.0);
+
+        let mut popup = ctx.get_widget(popup);
+        popup.set(
+            "margin",
+            Thickness::new(x, position.y + bounds.height(), 0.0, 0.0),
+        );
+        popup.set("visibility", Visibility::Visible);
+    }
+
+    fn close_popup(&mut self, ctx: &mut Context<'_>) {
+        if let Some(popup) = self.popup {
+            ctx.get_widget(popup)
+                .set("visibility", Visibility::Collapsed);
+        }
+
+        self.pressed_item = None;
+    }
+
+    // Returns the number of items that fits into the given width.
+    fn fitting_count(&self, available_width: f64) -> usize {
+        let widths: Vec<f64> = self
+            .items
+            .iter()
+            .map(|item| *self.widths.get(item).unwrap_or(&0.0))
+            .collect();
+
+        if widths.iter().sum::<f64>() <= available_width {
+            return widths.len();
+        }
+
+        let mut width = OVERFLOW_BUTTON_WIDTH;
+
+        widths
+            .iter()
+            .take_while(|w| {
+                width += *w;
+                width <= available_width
+            })
+            .count()
+    }
+
+    // Moves the items to the tool bar or to the overflow popup.
+    fn arrange_items(&mut self, ctx: &mut Context<'_>, visible_count: usize) {
+        let (items_panel, popup_panel) = match (self.items_panel, self.popup_panel) {
+            (Some(items_panel), Some(popup_panel)) => (items_panel, popup_panel),
+            _ => return,
+        };
+
+        for (index, item) in self.items.iter().enumerate() {
+            let result = if index < visible_count {
+                ctx.move_child_entity_to(*item, items_panel, index)
+            } else {
+                ctx.move_child_entity_to(*item, popup_panel, index - visible_count)
+            };
+
+            if let Err(message) = result {
+                eprintln!("{}", message);
+            }
+        }
+
+        self.visible_count = visible_count;
+
+        ctx.child("overflow_button").set(
+            "visibility",
+            if visible_count < self.items.len() {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            },
+        );
+
+        if visible_count == self.items.len() {
+            self.close_popup(ctx);
+        }
+
+        let _ = ctx.request_sender().send(shell::ShellRequest::Update);
+    }
+}
+
+impl State for ToolBarState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        self.items_panel = ctx.entity_of_child("items_panel");
+        self.visible_count = self.items.len();
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if self.request_toggle.get() {
+            self.request_toggle.set(false);
+
+            if self.is_popup_open(ctx) {
+                self.close_popup(ctx);
+            } else {
+                self.open_popup(ctx);
+            }
+        }
+
+        // closes the popup after an item of the popup is clicked
+        if self.is_popup_open(ctx) {
+            for item in self.items.iter().skip(self.visible_count) {
+                let pressed = ctx
+                    .get_widget(*item)
+                    .try_clone::<bool>("pressed")
+                    .unwrap_or(false);
+
+                if pressed {
+                    self.pressed_item = Some(*item);
+                } else if self.pressed_item == Some(*item) {
+                    self.close_popup(ctx);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        // only the items of the tool bar are measured, the items of the popup keep their last width
+        for item in self.items.iter().take(self.visible_count) {
+            let width = ctx.get_widget(*item).get::<Rectangle>("bounds").width();
+            self.widths.insert(*item, width);
+        }
+
+        let available_width = {
+            let widget = ctx.widget();
+            let padding = widget.get::<Thickness>("padding");
+            widget.get::<Rectangle>("bounds").width() - padding.left - padding.right
+        };
+
+        let visible_count = self.fitting_count(available_width);
+
+        if visible_count != self.visible_count {
+            self.arrange_items(ctx, visible_count);
+        }
+    }
+}
+
+widget!(
+    /// The `ToolBar` arranges its children horizontally. Children that don't fit into the tool bar
+    /// are moved into a popup that is opened by the overflow button at the end of the tool bar.
+    ///
+    /// **CSS element:** `tool-bar`
+    ToolBar<ToolBarState> {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for ToolBar {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items = std::mem::replace(&mut self.children, vec![]);
+        let mut items_panel = Stack::create()
+            .selector(Selector::default().id("items_panel"))
+            .orientation("horizontal");
+
+        for item in &items {
+            items_panel = items_panel.child(*item);
+        }
+
+        let popup_panel = Stack::create().orientation("vertical").build(ctx);
+
+        let popup = Container::create()
+            .selector("tool-bar-popup")
+            .horizontal_alignment("start")
+            .vertical_alignment("start")
+            .visibility("collapsed")
+            .padding(4.0)
+            .background(colors::LYNCH_COLOR)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .child(popup_panel)
+            .build(ctx);
+
+        if ctx.append_child_to_overlay(popup).is_err() {
+            ctx.append_child(id, popup);
+        }
+
+        self.state_mut().items = items;
+        self.state_mut().popup = Some(popup);
+        self.state_mut().popup_panel = Some(popup_panel);
+
+        self.name("ToolBar")
+            .accessible_role("group")
+            .selector("tool-bar")
+            .background(colors::LYNCH_COLOR)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(2.0)
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(
+                        Grid::create()
+                            .columns(Columns::create().column("*").column("auto").build())
+                            .child(
+                                Grid::create()
+                                    .clip(true)
+                                    .child(items_panel.build(ctx))
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::create()
+                                    .selector(
+                                        Selector::from("tool-bar-overflow").id("overflow_button"),
+                                    )
+                                    .attach(Grid::column(1))
+                                    .visibility("collapsed")
+                                    .min_width(OVERFLOW_BUTTON_WIDTH)
+                                    .width(OVERFLOW_BUTTON_WIDTH)
+                                    .padding(0.0)
+                                    .icon(material_font_icons::MORE_HORIZ_FONT_ICON)
+                                    .on_click(move |states, _| {
+                                        states.get::<ToolBarState>(id).toggle_popup();
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -0,0 +1,448 @@
+use std::{cell::RefCell, collections::HashSet};
+
+use crate::prelude::*;
+
+// Minimal distance the mouse has to be moved with pressed button to start a drag.
+const DRAG_THRESHOLD: f64 = 4.0;
+
+type DropValidator = Option<Box<dyn Fn(&TreeNodes, usize, usize, DropPosition) -> bool>>;
+
+#[derive(Debug, Copy, Clone)]
+enum Action {
+    Toggle(usize),
+    Press(Point),
+    Move(Point),
+    Release,
+}
+
+/// The `TreeViewState` handles the expanded nodes and the drag and drop of the `TreeView` widget.
+#[derive(Default, AsAny)]
+pub struct TreeViewState {
+    builder: WidgetBuildContext,
+    validator: DropValidator,
+    nodes: TreeNodes,
+    expanded: HashSet<usize>,
+    rows: Vec<(usize, Entity)>,
+    actions: RefCell<Vec<Action>>,
+    drag_source: Option<(usize, Point)>,
+    dragging: bool,
+    drop_target: Option<(usize, DropPosition)>,
+}
+
+impl TreeViewState {
+    fn action(&self, action: Action) {
+        self.actions.borrow_mut().push(action);
+    }
+
+    // Returns the node and the row entity under the given position.
+    fn row_at(&self, ctx: &mut Context<'_>, position: Point) -> Option<(usize, Entity)> {
+        self.rows
+            .iter()
+            .find(|(_, row)| check_mouse_condition(position, &ctx.get_widget(*row)))
+            .copied()
+    }
+
+    // Calculates the drop target under the given position. The upper and the lower quarter of a row
+    // inserts the dragged node as sibling, the middle inserts the node as child.
+    fn drop_target_at(
+        &self,
+        ctx: &mut Context<'_>,
+        source: usize,
+        position: Point,
+    ) -> Option<(usize, DropPosition, Entity)> {
+        let (target, row) = self.row_at(ctx, position)?;
+
+        if !self.nodes.can_move(source, target) {
+            return None;
+        }
+
+        let widget = ctx.get_widget(row);
+        let row_y = widget.get::<Point>("position").y;
+        let height = widget.get::<Rectangle>("bounds").height();
+        let offset = position.y - row_y;
+
+        let drop_position = if offset < height / 4.0 {
+            DropPosition::Before
+        } else if offset > height * 3.0 / 4.0 {
+            DropPosition::After
+        } else {
+            DropPosition::Into
+        };
+
+        if let Some(validator) = &self.validator {
+            if !validator(&self.nodes, source, target, drop_position) {
+                return None;
+            }
+        }
+
+        Some((target, drop_position, row))
+    }
+
+    // Moves the insertion indicator to the given row or hides it.
+    fn update_indicator(&self, ctx: &mut Context<'_>, target: Option<(DropPosition, Entity)>) {
+        let (drop_position, row) = if let Some(target) = target {
+            target
+        } else {
+            ctx.child("drop_indicator")
+                .set("visibility", Visibility::Collapsed);
+            return;
+        };
+
+        let origin = *ctx.child("rows_grid").get::<Point>("position");
+        let (row_position, row_bounds) = {
+            let widget = ctx.get_widget(row);
+            (
+                *widget.get::<Point>("position"),
+                *widget.get::<Rectangle>("bounds"),
+            )
+        };
+
+        let (y, height) = match drop_position {
+            DropPosition::Before => (row_position.y - 1.0, 2.0),
+            DropPosition::After => (row_position.y + row_bounds.height() - 1.0, 2.0),
+            DropPosition::Into => (row_position.y, row_bounds.height()),
+        };
+
+        let mut indicator = ctx.child("drop_indicator");
+        indicator.set(
+            "margin",
+            Thickness::new(row_position.x - origin.x, y - origin.y, 0.0, 0.0),
+        );
+        indicator
+            .get_mut::<Constraint>("constraint")
+            .set_size(row_bounds.width(), height);
+
+        // a node dropped into the target is indicated by a frame around the target
+        if drop_position == DropPosition::Into {
+            indicator.set("border_width", Thickness::from(1.0));
+            indicator.set("background", Brush::from("transparent"));
+        } else {
+            let brush = indicator.clone::<Brush>("border_brush");
+            indicator.set("border_width", Thickness::from(0.0));
+            indicator.set("background", brush);
+        }
+
+        indicator.set("visibility", Visibility::Visible);
+    }
+
+    fn drop(&mut self, ctx: &mut Context<'_>) {
+        if let (Some((source, _)), Some((target, position))) = (self.drag_source, self.drop_target)
+        {
+            if ctx
+                .widget()
+                .get_mut::<TreeNodes>("nodes")
+                .move_node(source, target, position)
+                .is_ok()
+                && position == DropPosition::Into
+            {
+                self.expanded.insert(target);
+            }
+        }
+    }
+
+    // Builds a row for each visible node.
+    fn build_rows(&mut self, ctx: &mut Context<'_>) {
+        let items_panel = if let Some(items_panel) = ctx.entity_of_child("items_panel") {
+            items_panel
+        } else {
+            return;
+        };
+
+        ctx.clear_children_of(items_panel);
+        self.rows.clear();
+
+        let id = ctx.entity;
+        let indent = *ctx.widget().get::<f64>("indent");
+        let row_height = *ctx.widget().get::<f64>("row_height");
+        let mut themed = vec![];
+
+        for (node, depth) in self.nodes.visible_nodes(&self.expanded) {
+            let build_context = &mut ctx.build_context();
+
+            let expander = if self.nodes.children(Some(node)).is_empty() {
+                Container::create().width(16.0).build(build_context)
+            } else {
+                let icon = if self.expanded.contains(&node) {
+                    material_font_icons::KEYBOARD_ARROW_DOWN_FONT_ICON
+                } else {
+                    material_font_icons::KEYBOARD_ARROW_RIGHT_FONT_ICON
+                };
+
+                Button::create()
+                    .selector("tree-view-expander")
+                    .vertical_alignment("center")
+                    .min_width(16.0)
+                    .height(16.0)
+                    .padding(0.0)
+                    .icon(icon)
+                    .on_click(move |states, _| {
+                        states.get::<TreeViewState>(id).action(Action::Toggle(node));
+                        true
+                    })
+                    .build(build_context)
+            };
+
+            let mut content = Stack::create()
+                .orientation("horizontal")
+                .margin((depth as f64 * indent, 0.0, 0.0, 0.0))
+                .child(expander);
+
+            if let Some(builder) = &self.builder {
+                content = content.child(builder(build_context, node));
+            }
+
+            let row = TreeViewItem::create()
+                .height(row_height)
+                .child(content.build(build_context))
+                .build(build_context);
+
+            build_context.append_child(items_panel, row);
+            self.rows.push((node, row));
+            themed.push(row);
+            themed.push(expander);
+        }
+
+        for entity in themed {
+            ctx.get_widget(entity).update_properties_by_theme();
+        }
+    }
+}
+
+impl State for TreeViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let mut request_update = *ctx.widget().get::<bool>("request_update");
+
+        let actions: Vec<Action> = self.actions.borrow_mut().drain(..).collect();
+
+        for action in actions {
+            match action {
+                Action::Toggle(node) => {
+                    if !self.expanded.remove(&node) {
+                        self.expanded.insert(node);
+                    }
+
+                    request_update = true;
+                }
+                Action::Press(position) => {
+                    self.drag_source = self.row_at(ctx, position).map(|(node, _)| (node, position));
+                }
+                Action::Move(position) => {
+                    if let Some((source, start)) = self.drag_source {
+                        if !self.dragging
+                            && ((position.x - start.x).abs() > DRAG_THRESHOLD
+                                || (position.y - start.y).abs() > DRAG_THRESHOLD)
+                        {
+                            self.dragging = true;
+                        }
+
+                        if self.dragging {
+                            let target = self.drop_target_at(ctx, source, position);
+                            self.drop_target = target.map(|(node, position, _)| (node, position));
+                            self.update_indicator(
+                                ctx,
+                                target.map(|(_, position, row)| (position, row)),
+                            );
+                        }
+                    }
+                }
+                Action::Release => {
+                    if self.dragging {
+                        self.drop(ctx);
+                        self.update_indicator(ctx, None);
+                    }
+
+                    self.drag_source = None;
+                    self.dragging = false;
+                    self.drop_target = None;
+                }
+            }
+        }
+
+        if *ctx.widget().get::<TreeNodes>("nodes") != self.nodes {
+            self.nodes = ctx.widget().clone::<TreeNodes>("nodes");
+            request_update = true;
+        }
+
+        if request_update {
+            self.build_rows(ctx);
+            ctx.widget().set("request_update", false);
+        }
+    }
+}
+
+widget!(
+    /// The `TreeViewItem` is the row of a node in the `TreeView`.
+    ///
+    /// **CSS element:** `tree-view-item`
+    TreeViewItem {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for TreeViewItem {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("TreeViewItem")
+            .accessible_role("tree-item")
+            .selector("tree-view-item")
+            .background("transparent")
+            .padding((4.0, 0.0, 4.0, 0.0))
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
+
+widget!(
+    /// The `TreeView` displays the hierarchy of `TreeNodes`. The content of a node is built by the
+    /// items builder. Nodes could be dragged onto other nodes to reparent them or between two
+    /// nodes to reorder them.
+    ///
+    /// **CSS element:** `tree-view`
+    TreeView<TreeViewState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the tree nodes property.
+        nodes: TreeNodes,
+
+        /// Sets or shares the height of a row.
+        row_height: f64,
+
+        /// Sets or shares the indentation of a child node.
+        indent: f64,
+
+        /// Sets or shares a value to request an update of the rows, e.g. after the node data is changed.
+        request_update: bool,
+
+        /// Sets or shares the (wheel, scroll) delta property.
+        delta: Point,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl TreeView {
+    /// Inserts the builder that builds the content of the node with the given index.
+    pub fn items_builder<F: Fn(&mut BuildContext, usize) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.state_mut().builder = Some(Box::new(builder));
+        self
+    }
+
+    /// Inserts a callback that is called with the nodes, the dragged node, the target node and the
+    /// drop position before a node is dropped. The drop is rejected if it returns `false`.
+    pub fn drop_validator<F: Fn(&TreeNodes, usize, usize, DropPosition) -> bool + 'static>(
+        mut self,
+        validator: F,
+    ) -> Self {
+        self.state_mut().validator = Some(Box::new(validator));
+        self
+    }
+}
+
+impl Template for TreeView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items_panel = Stack::create()
+            .vertical_alignment("start")
+            .selector(Selector::default().id("items_panel"))
+            .build(ctx);
+
+        let scroll_viewer = ScrollViewer::create()
+            .scroll_viewer_mode(("disabled", "auto"))
+            .delta(id)
+            .child(items_panel)
+            .build(ctx);
+
+        self.name("TreeView")
+            .accessible_role("tree")
+            .selector("tree-view")
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding(2.0)
+            .row_height(24.0)
+            .indent(16.0)
+            .delta(0.0)
+            .on_mouse_down(move |states, position| {
+                states
+                    .get::<TreeViewState>(id)
+                    .action(Action::Press(position));
+                false
+            })
+            .on_mouse_move(move |states, position| {
+                states
+                    .get::<TreeViewState>(id)
+                    .action(Action::Move(position));
+                false
+            })
+            .on_mouse_up(move |states, _| {
+                states.get::<TreeViewState>(id).action(Action::Release);
+                false
+            })
+            .child(
+                Container::create()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .opacity(id)
+                    .child(
+                        Grid::create()
+                            .selector(Selector::default().id("rows_grid"))
+                            .child(scroll_viewer)
+                            .child(
+                                ScrollIndicator::create()
+                                    .padding(2.0)
+                                    .content_id(items_panel.0)
+                                    .scroll_offset(scroll_viewer)
+                                    .opacity(id)
+                                    .build(ctx),
+                            )
+                            .child(
+                                Container::create()
+                                    .selector(
+                                        Selector::from("tree-view-drop-indicator")
+                                            .id("drop_indicator"),
+                                    )
+                                    .horizontal_alignment("start")
+                                    .vertical_alignment("start")
+                                    .visibility("collapsed")
+                                    .background(colors::LINK_WATER_COLOR)
+                                    .border_brush(colors::LINK_WATER_COLOR)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -0,0 +1,604 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, path::Path};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Value of a property in a declarative UI description.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PropertyValue {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    List(Vec<PropertyValue>),
+}
+
+impl PropertyValue {
+    /// Returns the value if it is a bool.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PropertyValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            PropertyValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it is a text.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::Text(value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if it is a list.
+    pub fn as_list(&self) -> Option<&[PropertyValue]> {
+        match self {
+            PropertyValue::List(values) => Some(values.as_slice()),
+            _ => None,
+        }
+    }
+}
+
+/// Describes a widget with its properties and children. A description could be read from `ron`
+/// or `json` and is built with the `UiLoader`.
+///
+/// # Example
+///
+/// ```text
+/// (
+///     type: "Stack",
+///     properties: { "spacing": 8, "margin": [8, 4] },
+///     children: [
+///         (type: "TextBox", id: "name", properties: { "water_mark": "Name" }),
+///         (type: "Button", properties: { "text": "Save" }),
+///     ],
+/// )
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WidgetDescription {
+    /// Type name of the widget, e.g. `Button`.
+    #[serde(rename = "type")]
+    pub widget_type: String,
+
+    /// Css id of the widget, could be used to access it with `ctx.child(id)`. The id is interned
+    /// as `Symbol`, so descriptions should be part of the application and not user input.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+
+    /// Properties by key, e.g. `text`, `margin` or the attached `row` and `column` of a grid.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, PropertyValue>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<WidgetDescription>,
+}
+
+impl WidgetDescription {
+    /// Reads a description from `ron`.
+    pub fn from_ron(content: &str) -> Result<Self, String> {
+        ron::de::from_str(content).map_err(|e| {
+            format!(
+                "WidgetDescription.from_ron: Could not read description: {}",
+                e
+            )
+        })
+    }
+
+    /// Reads a description from `json`.
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        serde_json::from_str(content).map_err(|e| {
+            format!(
+                "WidgetDescription.from_json: Could not read description: {}",
+                e
+            )
+        })
+    }
+
+    /// Reads a description from a `.ron` or `.json` file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|_| {
+            format!(
+                "WidgetDescription.load_file: Could not read file {:?}",
+                path
+            )
+        })?;
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ron") => WidgetDescription::from_ron(&content),
+            Some("json") => WidgetDescription::from_json(&content),
+            _ => Err(format!(
+                "WidgetDescription.load_file: Unsupported file format {:?}",
+                path
+            )),
+        }
+    }
+}
+
+type WidgetBuilder = Box<dyn Fn(&mut BuildContext) -> Entity>;
+
+type PropertyParser = Box<dyn Fn(&PropertyValue) -> Result<ComponentBox, String>>;
+
+/// The `UiLoader` builds widget trees from declarative descriptions, so screens could be
+/// defined in `ron` or `json` files and loaded at runtime. Custom widgets and properties are
+/// registered by name with `register_widget` and `register_property`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut loader = UiLoader::new();
+/// loader.register_widget("ColorEditor", |ctx| ColorEditor::create().build(ctx));
+/// loader.register_property("brush", |value| {
+///     value.as_str().map(Brush::from).ok_or_else(|| "expected a text".to_string())
+/// });
+///
+/// Window::create()
+///     .child(loader.build_file("res/ui/form.ron", ctx).unwrap())
+///     .build(ctx)
+/// ```
+pub struct UiLoader {
+    widgets: HashMap<String, WidgetBuilder>,
+    properties: HashMap<String, PropertyParser>,
+}
+
+impl Default for UiLoader {
+    fn default() -> Self {
+        UiLoader::new()
+    }
+}
+
+impl UiLoader {
+    /// Creates a new loader that knows the base widgets and their properties.
+    pub fn new() -> Self {
+        let mut loader = UiLoader {
+            widgets: HashMap::new(),
+            properties: HashMap::new(),
+        };
+
+        loader.register_widget("Button", |ctx| Button::create().build(ctx));
+        loader.register_widget("CheckBox", |ctx| CheckBox::create().build(ctx));
+        loader.register_widget("Container", |ctx| Container::create().build(ctx));
+        loader.register_widget("FontIconBlock", |ctx| FontIconBlock::create().build(ctx));
+        loader.register_widget("Grid", |ctx| Grid::create().build(ctx));
+        loader.register_widget("NumericBox", |ctx| NumericBox::create().build(ctx));
+        loader.register_widget("PasswordBox", |ctx| PasswordBox::create().build(ctx));
+        loader.register_widget("ScrollViewer", |ctx| ScrollViewer::create().build(ctx));
+        loader.register_widget("Slider", |ctx| Slider::create().build(ctx));
+        loader.register_widget("Stack", |ctx| Stack::create().build(ctx));
+        loader.register_widget("Switch", |ctx| Switch::create().build(ctx));
+        loader.register_widget("TextBlock", |ctx| TextBlock::create().build(ctx));
+        loader.register_widget("TextBox", |ctx| TextBox::create().build(ctx));
+        loader.register_widget("ToggleButton", |ctx| ToggleButton::create().build(ctx));
+
+        loader.insert_properties(&["text", "water_mark"], to_string16);
+        loader.insert_properties(
+            &[
+                "accessible_name",
+                "automation_id",
+                "font",
+                "icon",
+                "icon_font",
+                "name",
+                "title",
+            ],
+            to_string,
+        );
+        loader.insert_properties(
+            &[
+                "font_size",
+                "icon_size",
+                "max",
+                "maximum",
+                "min",
+                "minimum",
+                "row_height",
+                "spacing",
+                "step",
+                "value",
+            ],
+            to_f64,
+        );
+        loader.insert_properties(&["opacity"], |value| to_f64(value).map(|v| v as f32));
+        loader.insert_properties(
+            &[
+                "clip",
+                "enabled",
+                "lost_focus_on_activation",
+                "resizeable",
+                "selected",
+            ],
+            to_bool,
+        );
+        loader.insert_properties(
+            &["column", "column_span", "count", "row", "row_span"],
+            to_usize,
+        );
+        loader.insert_properties(&["border_width", "margin", "padding"], to_thickness);
+        loader.insert_properties(&["border_radius"], to_corner_radius);
+        loader.insert_properties(
+            &["background", "border_brush", "foreground", "icon_brush"],
+            from_text::<Brush>,
+        );
+        loader.insert_properties(
+            &["horizontal_alignment", "vertical_alignment"],
+            from_text::<Alignment>,
+        );
+        loader.insert_properties(&["accessible_role"], from_text::<AccessibleRole>);
+        loader.insert_properties(&["cursor"], from_text::<CursorIcon>);
+        loader.insert_properties(&["flow_direction"], from_text::<FlowDirection>);
+        loader.insert_properties(&["orientation"], from_text::<Orientation>);
+        loader.insert_properties(&["text_wrap"], from_text::<TextWrap>);
+        loader.insert_properties(&["visibility"], from_text::<Visibility>);
+        loader.insert_properties(&["columns"], |value| {
+            to_list(value, Column::from, |text| Column::from(text))
+                .map(|columns| Columns::create().columns(&columns).build())
+        });
+        loader.insert_properties(&["rows"], |value| {
+            to_list(value, Row::from, |text| Row::from(text))
+                .map(|rows| Rows::create().rows(&rows).build())
+        });
+
+        loader
+    }
+
+    /// Registers the builder of a widget type. Built-in widget types could be replaced.
+    pub fn register_widget<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        widget_type: &str,
+        builder: F,
+    ) {
+        self.widgets
+            .insert(widget_type.to_string(), Box::new(builder));
+    }
+
+    /// Registers the parser of a property, that converts the description value to the property
+    /// value of the widget. Returns an error message if the value is invalid.
+    pub fn register_property<P, F>(&mut self, key: &str, parser: F)
+    where
+        P: Component,
+        F: Fn(&PropertyValue) -> Result<P, String> + 'static,
+    {
+        self.properties.insert(
+            key.to_string(),
+            Box::new(move |value| parser(value).map(ComponentBox::new)),
+        );
+    }
+
+    /// Returns `true` if the loader could build widgets of the given type.
+    pub fn has_widget(&self, widget_type: &str) -> bool {
+        self.widgets.contains_key(widget_type)
+    }
+
+    /// Returns `true` if the loader could parse the property with the given key.
+    pub fn has_property(&self, key: &str) -> bool {
+        self.properties.contains_key(key)
+    }
+
+    fn insert_properties<P: Component>(
+        &mut self,
+        keys: &[&str],
+        parser: fn(&PropertyValue) -> Result<P, String>,
+    ) {
+        for key in keys {
+            self.register_property(key, parser);
+        }
+    }
+
+    /// Builds the widget tree of the given description and returns the entity of its root.
+    pub fn build(
+        &self,
+        description: &WidgetDescription,
+        ctx: &mut BuildContext,
+    ) -> Result<Entity, String> {
+        let widget_type = description.widget_type.as_str();
+        let builder = self
+            .widgets
+            .get(widget_type)
+            .ok_or_else(|| format!("UiLoader.build: Unknown widget type {}", widget_type))?;
+
+        let entity = builder(ctx);
+
+        if !description.id.is_empty() {
+            let id = Symbol::new(&description.id);
+
+            if let Some(selector) = ctx.get_widget(entity).try_get_mut::<Selector>("selector") {
+                selector.id = Some(id);
+                selector.set_dirty(true);
+            } else {
+                ctx.register_property("selector", entity, Selector::default().id(id));
+            }
+        }
+
+        for (key, value) in &description.properties {
+            let invalid_value = |e: String| {
+                format!(
+                    "UiLoader.build: Invalid value of property {} of {}: {}",
+                    key, widget_type, e
+                )
+            };
+
+            // the sizes of a widget are stored in its constraint
+            let constraint_setter: Option<fn(&mut Constraint, f64)> = match key.as_str() {
+                "width" => Some(Constraint::set_width),
+                "height" => Some(Constraint::set_height),
+                "width_percent" => Some(Constraint::set_width_percent),
+                "height_percent" => Some(Constraint::set_height_percent),
+                "min_width" => Some(Constraint::set_min_width),
+                "min_height" => Some(Constraint::set_min_height),
+                "max_width" => Some(Constraint::set_max_width),
+                "max_height" => Some(Constraint::set_max_height),
+                _ => None,
+            };
+
+            if let Some(setter) = constraint_setter {
+                let value = to_f64(value).map_err(invalid_value)?;
+
+                if let Some(constraint) = ctx
+                    .get_widget(entity)
+                    .try_get_mut::<Constraint>("constraint")
+                {
+                    setter(constraint, value);
+                }
+
+                continue;
+            }
+
+            let parser = self.properties.get(key).ok_or_else(|| {
+                format!(
+                    "UiLoader.build: Unknown property {} of {}",
+                    key, widget_type
+                )
+            })?;
+
+            ctx.register_property_box(key, entity, parser(value).map_err(invalid_value)?);
+        }
+
+        for child in &description.children {
+            let child = self.build(child, ctx)?;
+            ctx.append_child(entity, child);
+        }
+
+        Ok(entity)
+    }
+
+    /// Builds the widget tree of the given `ron` description.
+    pub fn build_ron(&self, content: &str, ctx: &mut BuildContext) -> Result<Entity, String> {
+        self.build(&WidgetDescription::from_ron(content)?, ctx)
+    }
+
+    /// Builds the widget tree of the given `json` description.
+    pub fn build_json(&self, content: &str, ctx: &mut BuildContext) -> Result<Entity, String> {
+        self.build(&WidgetDescription::from_json(content)?, ctx)
+    }
+
+    /// Builds the widget tree of the description in the given `.ron` or `.json` file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn build_file(
+        &self,
+        path: impl AsRef<Path>,
+        ctx: &mut BuildContext,
+    ) -> Result<Entity, String> {
+        self.build(&WidgetDescription::load_file(path)?, ctx)
+    }
+}
+
+// --- Parsers ---
+
+fn to_bool(value: &PropertyValue) -> Result<bool, String> {
+    value.as_bool().ok_or_else(|| "expected a bool".to_string())
+}
+
+fn to_f64(value: &PropertyValue) -> Result<f64, String> {
+    value
+        .as_f64()
+        .ok_or_else(|| "expected a number".to_string())
+}
+
+fn to_usize(value: &PropertyValue) -> Result<usize, String> {
+    match value.as_f64() {
+        Some(value) if value >= 0.0 && value.fract().abs() < std::f64::EPSILON => {
+            Ok(value as usize)
+        }
+        _ => Err("expected a positive integer".to_string()),
+    }
+}
+
+fn to_string(value: &PropertyValue) -> Result<String, String> {
+    value
+        .as_str()
+        .map(|value| value.to_string())
+        .ok_or_else(|| "expected a text".to_string())
+}
+
+fn to_string16(value: &PropertyValue) -> Result<String16, String> {
+    value
+        .as_str()
+        .map(String16::from)
+        .ok_or_else(|| "expected a text".to_string())
+}
+
+fn from_text<T: for<'a> From<&'a str>>(value: &PropertyValue) -> Result<T, String> {
+    value
+        .as_str()
+        .map(T::from)
+        .ok_or_else(|| "expected a text".to_string())
+}
+
+// A single number for all sides, two numbers for left and right, top and bottom or four numbers.
+fn to_thickness(value: &PropertyValue) -> Result<Thickness, String> {
+    if let Some(value) = value.as_f64() {
+        return Ok(Thickness::from(value));
+    }
+
+    let values: Vec<f64> = value
+        .as_list()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|value| value.as_f64())
+        .collect();
+
+    match values.as_slice() {
+        [horizontal, vertical] => Ok(Thickness::from((*horizontal, *vertical))),
+        [left, top, right, bottom] => Ok(Thickness::from((*left, *top, *right, *bottom))),
+        _ => Err("expected a number or a list of two or four numbers".to_string()),
+    }
+}
+
+fn to_corner_radius(value: &PropertyValue) -> Result<CornerRadius, String> {
+    if let Some(value) = value.as_f64() {
+        return Ok(CornerRadius::from(value));
+    }
+
+    let values: Vec<f64> = value
+        .as_list()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|value| value.as_f64())
+        .collect();
+
+    CornerRadius::from_values(&values)
+        .ok_or_else(|| "expected a number or a list of one to four numbers".to_string())
+}
+
+// A list of numbers for fixed sizes and texts like `auto` and `*`.
+fn to_list<T>(
+    value: &PropertyValue,
+    from_number: fn(f64) -> T,
+    from_text: fn(&str) -> T,
+) -> Result<Vec<T>, String> {
+    value
+        .as_list()
+        .ok_or_else(|| "expected a list".to_string())?
+        .iter()
+        .map(|value| match value {
+            PropertyValue::Number(value) => Ok(from_number(*value)),
+            PropertyValue::Text(value) => Ok(from_text(value.as_str())),
+            _ => Err("expected a list of numbers and texts".to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ron() {
+        let description = WidgetDescription::from_ron(
+            "(type: \"Stack\", id: \"form\", properties: { \"spacing\": 8, \"margin\": [8, 4] },
+              children: [(type: \"Button\", properties: { \"text\": \"Save\", \"enabled\": true })])",
+        )
+        .unwrap();
+
+        assert_eq!(description.widget_type, "Stack");
+        assert_eq!(description.id, "form");
+        assert_eq!(
+            description.properties["spacing"],
+            PropertyValue::Number(8.0)
+        );
+        assert_eq!(description.children.len(), 1);
+        assert_eq!(
+            description.children[0].properties["text"],
+            PropertyValue::Text("Save".to_string())
+        );
+        assert_eq!(
+            description.children[0].properties["enabled"],
+            PropertyValue::Bool(true)
+        );
+
+        assert!(WidgetDescription::from_ron("(id: \"form\")").is_err());
+    }
+
+    #[test]
+    fn test_from_json() {
+        let description = WidgetDescription::from_json(
+            "{ \"type\": \"Grid\", \"properties\": { \"columns\": [\"auto\", 64] },
+               \"children\": [{ \"type\": \"TextBlock\", \"properties\": { \"column\": 1 } }] }",
+        )
+        .unwrap();
+
+        assert_eq!(description.widget_type, "Grid");
+        assert!(description.id.is_empty());
+        assert_eq!(
+            description.properties["columns"],
+            PropertyValue::List(vec![
+                PropertyValue::Text("auto".to_string()),
+                PropertyValue::Number(64.0)
+            ])
+        );
+        assert_eq!(
+            to_usize(&description.children[0].properties["column"]),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn test_register() {
+        let mut loader = UiLoader::new();
+        assert!(loader.has_widget("Button"));
+        assert!(!loader.has_widget("Header"));
+        assert!(!loader.has_property("level"));
+
+        loader.register_widget("Header", |ctx| TextBlock::create().build(ctx));
+        loader.register_property("level", to_usize);
+
+        assert!(loader.has_widget("Header"));
+        assert!(loader.has_property("level"));
+        assert!(loader.properties["level"](&PropertyValue::Number(2.0)).is_ok());
+        assert!(loader.properties["level"](&PropertyValue::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn test_to_thickness() {
+        assert_eq!(
+            to_thickness(&PropertyValue::Number(2.0)),
+            Ok(Thickness::from(2.0))
+        );
+        assert_eq!(
+            to_thickness(&PropertyValue::List(vec![
+                PropertyValue::Number(8.0),
+                PropertyValue::Number(4.0)
+            ])),
+            Ok(Thickness::from((8.0, 4.0)))
+        );
+        assert!(to_thickness(&PropertyValue::Text("2".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_to_corner_radius() {
+        assert_eq!(
+            to_corner_radius(&PropertyValue::Number(2.0)),
+            Ok(CornerRadius::from(2.0))
+        );
+        assert_eq!(
+            to_corner_radius(&PropertyValue::List(vec![
+                PropertyValue::Number(4.0),
+                PropertyValue::Number(4.0),
+                PropertyValue::Number(0.0),
+                PropertyValue::Number(0.0)
+            ])),
+            Ok(CornerRadius::new(4.0, 4.0, 0.0, 0.0))
+        );
+        assert!(to_corner_radius(&PropertyValue::Text("2".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_to_usize() {
+        assert_eq!(to_usize(&PropertyValue::Number(3.0)), Ok(3));
+        assert!(to_usize(&PropertyValue::Number(-1.0)).is_err());
+        assert!(to_usize(&PropertyValue::Number(1.5)).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    mem,
+    sync::{mpsc::Sender, Arc, Mutex},
+};
+
+use crate::{prelude::*, render::RenderTarget, shell::ShellRequest};
+
+#[derive(Default)]
+struct FrameSlot {
+    frame: Option<RenderTarget>,
+    // buffer of the last displayed frame that is reused for the next frame of the same size
+    recycled: Option<RenderTarget>,
+    request_sender: Option<Sender<ShellRequest>>,
+}
+
+/// Handle to push frames into a `VideoView` from any thread, e.g. of a camera or a video decoder.
+/// Only the latest frame that is not displayed yet is kept, older frames are dropped.
+#[derive(Clone, Default)]
+pub struct FrameStream {
+    slot: Arc<Mutex<FrameSlot>>,
+}
+
+impl FrameStream {
+    /// Creates a new frame stream.
+    pub fn new() -> Self {
+        FrameStream::default()
+    }
+
+    /// Pushes a frame with pixels in ARGB format, the pixel format of the render targets.
+    pub fn push_argb(&self, width: u32, height: u32, data: Vec<u32>) -> Result<(), String> {
+        if data.len() != width as usize * height as usize {
+            return Err(String::from(
+                "FrameStream.push_argb: The length of the data doesn't match the frame size.",
+            ));
+        }
+
+        self.push(RenderTarget::from_data(width, height, data)?);
+        Ok(())
+    }
+
+    /// Pushes a frame with pixels in RGBA byte order.
+    pub fn push_rgba(&self, width: u32, height: u32, data: &[u8]) -> Result<(), String> {
+        if data.len() != width as usize * height as usize * 4 {
+            return Err(String::from(
+                "FrameStream.push_rgba: The length of the data doesn't match the frame size.",
+            ));
+        }
+
+        let mut frame = self
+            .take_recycled(width, height)
+            .unwrap_or_else(|| RenderTarget::new(width, height));
+
+        for (pixel, rgba) in frame.data.iter_mut().zip(data.chunks_exact(4)) {
+            *pixel = Color::rgba(rgba[0], rgba[1], rgba[2], rgba[3]).data;
+        }
+
+        self.push(frame);
+        Ok(())
+    }
+
+    fn push(&self, frame: RenderTarget) {
+        let mut slot = self.slot.lock().unwrap();
+        slot.frame = Some(frame);
+
+        if let Some(request_sender) = &slot.request_sender {
+            let _ = request_sender.send(ShellRequest::Update);
+        }
+    }
+
+    fn take_recycled(&self, width: u32, height: u32) -> Option<RenderTarget> {
+        let mut slot = self.slot.lock().unwrap();
+
+        match slot.recycled.take() {
+            Some(frame) if frame.width() as u32 == width && frame.height() as u32 == height => {
+                Some(frame)
+            }
+            _ => None,
+        }
+    }
+
+    // Sets the sender that wakes the window after a frame is pushed.
+    fn attach(&self, request_sender: Sender<ShellRequest>) {
+        self.slot.lock().unwrap().request_sender = Some(request_sender);
+    }
+
+    // Removes and returns the pending frame.
+    fn take_frame(&self) -> Option<RenderTarget> {
+        self.slot.lock().unwrap().frame.take()
+    }
+
+    // Keeps the buffer of a displayed frame for reuse.
+    fn recycle(&self, frame: RenderTarget) {
+        self.slot.lock().unwrap().recycled = Some(frame);
+    }
+}
+
+// The `VideoViewState` displays the latest frame of the stream.
+#[derive(Default, AsAny)]
+struct VideoViewState {
+    stream: Option<FrameStream>,
+}
+
+impl State for VideoViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(stream) = &self.stream {
+            stream.attach(ctx.request_sender());
+        }
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let stream = match &self.stream {
+            Some(stream) => stream,
+            None => return,
+        };
+
+        if let Some(frame) = stream.take_frame() {
+            let previous = mem::replace(ctx.widget().get_mut::<RenderTarget>("texture"), frame);
+            stream.recycle(previous);
+        }
+    }
+}
+
+widget!(
+    /// The `VideoView` widget displays the frames that are pushed with a `FrameStream` from
+    /// another thread, e.g. for camera previews and video players.
+    ///
+    /// **CSS element:** `video-view`
+    VideoView<VideoViewState>: MouseHandler {
+        /// Sets or shares the texture with the current frame.
+        texture: RenderTarget,
+
+        /// Sets or shares a value that describes if the frame keeps its aspect ratio. The
+        /// remaining space is filled with the background.
+        letterbox: bool,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl VideoView {
+    /// Sets the stream that delivers the frames of the view.
+    pub fn stream(mut self, stream: FrameStream) -> Self {
+        self.state_mut().stream = Some(stream);
+        self
+    }
+}
+
+impl Template for VideoView {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("VideoView")
+            .accessible_role("image")
+            .selector("video-view")
+            .letterbox(true)
+            .background("#000000")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(TextureRenderObject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_rgba() {
+        let stream = FrameStream::new();
+        assert!(stream.push_rgba(2, 1, &[0; 4]).is_err());
+
+        stream
+            .push_rgba(2, 1, &[255, 0, 0, 255, 0, 0, 255, 128])
+            .unwrap();
+        let frame = stream.take_frame().unwrap();
+        assert_eq!(
+            frame.data(),
+            &[
+                Color::rgba(255, 0, 0, 255).data,
+                Color::rgba(0, 0, 255, 128).data
+            ]
+        );
+
+        // only a pending frame is returned
+        assert!(stream.take_frame().is_none());
+
+        // the buffer of a displayed frame is reused
+        stream.recycle(frame);
+        stream.push_rgba(2, 1, &[0; 8]).unwrap();
+        assert!(stream.slot.lock().unwrap().recycled.is_none());
+        assert_eq!(stream.take_frame().unwrap().data(), &[0, 0]);
+    }
+
+    #[test]
+    fn test_latest_frame() {
+        let stream = FrameStream::new();
+        assert!(stream.push_argb(1, 2, vec![0]).is_err());
+
+        stream.push_argb(1, 1, vec![1]).unwrap();
+        stream.push_argb(1, 1, vec![2]).unwrap();
+
+        let frame = stream.take_frame().unwrap();
+        assert_eq!(frame.data(), &[2]);
+    }
+}
@@ -1,11 +1,18 @@
 use std::{collections::VecDeque, rc::Rc};
 
-use crate::prelude::*;
+use crate::{prelude::*, shell::Key};
 
 // The `WindowState` handles the window events.
 #[derive(Default, AsAny)]
 struct WindowState {
     events: VecDeque<WindowEvent>,
+    icon: Image,
+    badge: String,
+    always_on_top: bool,
+    window_opacity: f64,
+    keys: Vec<Key>,
+    toast_host: Option<Entity>,
+    dialog_host: Option<Entity>,
 }
 
 impl WindowState {
@@ -13,7 +20,20 @@ impl WindowState {
         self.events.push_front(event);
     }
 
+    fn push_key(&mut self, key: Key) {
+        self.keys.push(key);
+    }
+
     fn resize(&self, width: f64, height: f64, ctx: &mut Context) {
+        // with a virtual resolution the widgets keep their layout and are scaled to the window
+        if ctx
+            .window()
+            .get::<VirtualResolution>("virtual_resolution")
+            .is_enabled()
+        {
+            return;
+        }
+
         ctx.window()
             .get_mut::<Rectangle>("bounds")
             .set_size(width, height);
@@ -34,10 +54,138 @@ impl WindowState {
             }
         }
     }
+
+    // Passes a changed icon or badge of the window to the shell.
+    fn update_icon_and_badge(&mut self, ctx: &mut Context) {
+        let icon = ctx.window().clone_or_default::<Image>("icon");
+
+        if icon != self.icon {
+            if icon != Image::default() {
+                ctx.set_window_icon(&icon);
+            }
+
+            self.icon = icon;
+        }
+
+        let badge = ctx.window().clone_or_default::<String>("badge");
+
+        if badge != self.badge {
+            ctx.set_window_badge(if badge.is_empty() {
+                None
+            } else {
+                Some(badge.as_str())
+            });
+            self.badge = badge;
+        }
+    }
+
+    // Passes a changed always on top option or opacity of the window to the shell.
+    fn update_window_options(&mut self, ctx: &mut Context) {
+        let always_on_top = *ctx.window().get::<bool>("always_on_top");
+
+        if always_on_top != self.always_on_top {
+            ctx.set_window_always_on_top(always_on_top);
+            self.always_on_top = always_on_top;
+        }
+
+        let window_opacity = *ctx.window().get::<f64>("window_opacity");
+
+        if window_opacity != self.window_opacity {
+            ctx.set_window_opacity(window_opacity);
+            self.window_opacity = window_opacity;
+        }
+    }
+
+    // Executes the first command of the window whose shortcut matches a pressed key.
+    fn execute_shortcuts(&mut self, ctx: &mut Context) {
+        if self.keys.is_empty() {
+            return;
+        }
+
+        // a modal dialog blocks the shortcuts, escape cancels it
+        if !ctx.window().get::<Global>("global").dialogs.is_empty() {
+            if self.keys.drain(..).any(|key| key == Key::Escape) {
+                ctx.cancel_dialog();
+            }
+
+            return;
+        }
+
+        let commands = ctx.window().clone_or_default::<Commands>("commands");
+        let keyboard_state = ctx.window().get::<Global>("global").keyboard_state.clone();
+
+        for key in self.keys.drain(..) {
+            if let Some(command) = commands
+                .0
+                .iter()
+                .find(|command| command.matches(key, &keyboard_state))
+            {
+                command.execute(ctx);
+            }
+        }
+    }
+
+    // Adds the host of the toasts to the overlay when the first toast is requested.
+    fn create_toast_host(&mut self, ctx: &mut Context) {
+        if self.toast_host.is_some() || ctx.window().get::<Global>("global").toasts.is_empty() {
+            return;
+        }
+
+        let window = ctx.entity;
+        let build_context = &mut ctx.build_context();
+        let toast_host = ToastHost::create().build(build_context);
+
+        if build_context.append_child_to_overlay(toast_host).is_err() {
+            build_context.append_child(window, toast_host);
+        }
+
+        self.toast_host = Some(toast_host);
+    }
+
+    // Adds the host of the modal dialogs to the overlay when the first dialog is requested.
+    fn create_dialog_host(&mut self, ctx: &mut Context) {
+        if self.dialog_host.is_some() || ctx.window().get::<Global>("global").dialogs.is_empty() {
+            return;
+        }
+
+        let window = ctx.entity;
+        let build_context = &mut ctx.build_context();
+        let dialog_host = DialogHost::create().build(build_context);
+
+        if build_context.append_child_to_overlay(dialog_host).is_err() {
+            build_context.append_child(window, dialog_host);
+        }
+
+        self.dialog_host = Some(dialog_host);
+    }
 }
 
 impl State for WindowState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let resolution = *ctx.window().get::<VirtualResolution>("virtual_resolution");
+
+        if resolution.is_enabled() {
+            ctx.window()
+                .get_mut::<Rectangle>("bounds")
+                .set_size(resolution.width, resolution.height);
+            ctx.window()
+                .get_mut::<Constraint>("constraint")
+                .set_size(resolution.width, resolution.height);
+        }
+
+        // a new window is opaque and not on top
+        self.window_opacity = 1.0;
+        self.update_icon_and_badge(ctx);
+        self.update_window_options(ctx);
+    }
+
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_icon_and_badge(ctx);
+        self.update_window_options(ctx);
+        self.execute_shortcuts(ctx);
+        self.create_toast_host(ctx);
+        self.create_dialog_host(ctx);
+
         if let Some(event) = self.events.pop_front() {
             match event {
                 WindowEvent::Resize { width, height } => {
@@ -57,29 +205,69 @@ widget!(
     /// It also contains global properties like keyboard modifier and focused widget.
     ///
     /// **CSS element:** `window`
-    Window<WindowState> {
+    Window<WindowState>: KeyDownHandler, SystemTrayHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the title property.
         title: String,
 
+        /// Sets or shares the icon of the window, e.g. from a path or with `Image::from_bytes`.
+        icon: Image,
+
+        /// Sets or shares a badge of the window, e.g. an unread count. Only shown by the web
+        /// backend, an empty text removes the badge.
+        badge: String,
+
         /// Sets or shares the css selector property.
         selector: Selector,
 
         /// Sets or shares the resizeable property.
         resizeable: bool,
 
+        /// Sets or shares if the window is created without border and title bar, e.g. to draw
+        /// a custom title bar with `WindowHandle` widgets. Is read when the window is created.
+        borderless: bool,
+
+        /// Sets or shares if the window is kept above all other windows, e.g. a tool palette or
+        /// a notification. Only supported by some platforms.
+        always_on_top: bool,
+
+        /// Sets or shares the opacity of the whole window from `0.0` to `1.0`, e.g. of an
+        /// overlay. Only supported by some platforms.
+        window_opacity: f64,
+
         /// Sets or shares a value that describes if the current window is active.
         active: bool,
 
+        /// Sets or shares the render mode of the window. With `RenderMode::OnDemand` the window
+        /// renders only after input events or update requests. Is read when the window is created.
+        render_mode: RenderMode,
+
+        /// Sets or shares the max frames per second of the window. With `0` the frames are paced
+        /// by vsync or are not limited without vsync. Is read when the window is created.
+        max_fps: u32,
+
+        /// Sets or shares if the frames of the window are paced by the display refresh, turn it
+        /// off together with `max_fps(0)` to render uncapped, e.g. for an embedded game. Is read
+        /// when the window is created.
+        vsync: bool,
+
         /// Sets or shares the theme property.
-        theme: Theme
+        theme: Theme,
+
+        /// Sets or shares the commands whose shortcuts are handled by the window. A shortcut is
+        /// handled if the focused widget does not handle the key.
+        commands: Commands,
+
+        /// Sets or shares a fixed logical resolution. The content is laid out at this resolution
+        /// and scaled to the window with letterbox bars, e.g. for kiosk or game HUD overlays.
+        virtual_resolution: VirtualResolution
     }
 );
 
 impl Window {
-    fn on_window_event<H: Fn(&mut StatesContext, WindowEvent) -> bool + 'static>(
+    fn insert_window_event_handler<H: Fn(&mut StatesContext, WindowEvent) -> bool + 'static>(
         self,
         handler: H,
     ) -> Self {
@@ -87,21 +275,56 @@ impl Window {
             handler: Rc::new(handler),
         })
     }
+
+    /// Inserts a handler that is called if the window is resized, gains or loses the focus or is
+    /// minimized or restored.
+    pub fn on_window_event<H: Fn(&mut StatesContext, WindowEvent) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        // the event is always passed on to the state of the window
+        self.insert_window_event_handler(move |states, event| {
+            handler(states, event);
+            false
+        })
+    }
+
+    /// Inserts a handler that is called if the user requested to close the window. The handler
+    /// returns `true` to allow the window to close, e.g. `false` to ask the user to save unsaved
+    /// work first. Use `Context::close_window` to close the window afterwards, on the desktop the
+    /// close button of a vetoed window has no effect.
+    pub fn on_close_requested<H: Fn(&mut StatesContext) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(CloseRequestedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
 }
 
 impl Template for Window {
     fn template(self, id: Entity, _: &mut BuildContext) -> Self {   
         self.name("Window")
+            .accessible_role("window")
             .background(colors::BRIGHT_GRAY_COLOR)
             .size(100.0, 100.0)
             .selector("window")
             .title("Window")
             .theme(default_theme())
             .resizeable(false)
-            .on_window_event(move |ctx, event| {
+            .borderless(false)
+            .always_on_top(false)
+            .window_opacity(1.0)
+            .vsync(true)
+            .insert_window_event_handler(move |ctx, event| {
                 ctx.get_mut::<WindowState>(id).push_event(event);
                 true
-            })     
+            })
+            .on_key_down(move |ctx, event| {
+                ctx.get_mut::<WindowState>(id).push_key(event.key);
+                false
+            })
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
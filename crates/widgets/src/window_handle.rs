@@ -0,0 +1,145 @@
+use crate::prelude::*;
+
+// Smallest width and height a window could be resized to with a grip.
+const MIN_WINDOW_SIZE: f64 = 64.0;
+
+#[derive(Copy, Clone)]
+enum WindowHandleAction {
+    Press(Point),
+    Move(Point),
+    Release,
+}
+
+/// The `WindowHandleState` moves or resizes the window while the handle is dragged.
+#[derive(Default, AsAny)]
+pub struct WindowHandleState {
+    action: Option<WindowHandleAction>,
+    anchor: Option<Point>,
+}
+
+impl WindowHandleState {
+    fn action(&mut self, action: WindowHandleAction) {
+        self.action = Some(action);
+    }
+
+    fn drag(&mut self, anchor: Point, pointer: Point, ctx: &mut Context) {
+        let grip = ctx.widget().clone_or_default::<WindowGrip>("grip");
+        let (bounds, constraint) = {
+            let window = ctx.window();
+            (
+                window.clone::<Rectangle>("bounds"),
+                window.clone::<Constraint>("constraint"),
+            )
+        };
+
+        let drag = grip.drag(
+            (anchor.x, anchor.y),
+            (pointer.x, pointer.y),
+            (bounds.width(), bounds.height()),
+            (
+                constraint.min_width().max(MIN_WINDOW_SIZE),
+                constraint.min_height().max(MIN_WINDOW_SIZE),
+            ),
+        );
+
+        if drag.offset != (0.0, 0.0) {
+            ctx.move_window_by(drag.offset.0, drag.offset.1);
+        }
+
+        if drag.size != (bounds.width(), bounds.height()) {
+            ctx.resize_window_to(drag.size.0, drag.size.1);
+        }
+
+        self.anchor = Some(Point::new(drag.anchor.0, drag.anchor.1));
+    }
+}
+
+impl State for WindowHandleState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let grip = ctx.widget().clone_or_default::<WindowGrip>("grip");
+
+        if !grip.is_resize() {
+            return;
+        }
+
+        // a grip that could not resize the window is hidden
+        if !ctx.can_resize_window() {
+            ctx.widget().set("visibility", Visibility::Collapsed);
+            return;
+        }
+
+        if ctx.widget().get::<CursorIcon>("cursor") == &CursorIcon::Arrow {
+            ctx.widget().set("cursor", CursorIcon::ResizeAll);
+        }
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        match self.action.take() {
+            Some(WindowHandleAction::Press(position)) => self.anchor = Some(position),
+            Some(WindowHandleAction::Move(position)) => {
+                if let Some(anchor) = self.anchor {
+                    self.drag(anchor, position, ctx);
+                }
+            }
+            Some(WindowHandleAction::Release) => self.anchor = None,
+            None => {}
+        }
+    }
+}
+
+widget!(
+    /// The `WindowHandle` moves or resizes a window while it is dragged. It is used to draw the
+    /// title bar and the resize grips of a borderless window with widgets.
+    ///
+    /// **CSS element:** `window-handle`
+    WindowHandle<WindowHandleState>: MouseHandler {
+        /// Sets or shares the part of the window that is changed by the handle.
+        grip: WindowGrip,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the css selector property.
+        selector: Selector
+    }
+);
+
+impl Template for WindowHandle {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("WindowHandle")
+            .selector("window-handle")
+            .grip(WindowGrip::default())
+            .background("transparent")
+            .padding(0.0)
+            .cursor("arrow")
+            .on_mouse_down(move |states, p| {
+                states
+                    .get_mut::<WindowHandleState>(id)
+                    .action(WindowHandleAction::Press(p));
+                true
+            })
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<WindowHandleState>(id)
+                    .action(WindowHandleAction::Move(p));
+                false
+            })
+            .on_mouse_up(move |states, _| {
+                states
+                    .get_mut::<WindowHandleState>(id)
+                    .action(WindowHandleAction::Release);
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use async_std::task;
+
+use orbtk::prelude::*;
+
+// simulates a request to a server
+async fn fetch_message(request: usize) -> String {
+    task::sleep(Duration::from_secs(1)).await;
+    format!("Response {} from server", request)
+}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState;
+
+impl State for MainViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let ui_handle = ctx.ui_handle();
+
+        task::spawn(async move {
+            let mut request = 0;
+
+            loop {
+                request += 1;
+                let message = fetch_message(request).await;
+
+                // updates the widget on the ui thread and waits until it is done
+                let result = ui_handle
+                    .call(move |ctx| ctx.widget().set("message", String16::from(message)))
+                    .await;
+
+                if result.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+widget!(MainView<MainViewState> {
+    message: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .message("Waiting for server...")
+            .child(
+                TextBlock::create()
+                    .margin(8.0)
+                    .text(("message", id))
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - async example")
+                .position((100.0, 100.0))
+                .size(320.0, 120.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,49 @@
+use orbtk::prelude::*;
+
+static LANGUAGES: &[&str] = &[
+    "C",
+    "C#",
+    "C++",
+    "Clojure",
+    "Dart",
+    "Elixir",
+    "Erlang",
+    "F#",
+    "Go",
+    "Haskell",
+    "Java",
+    "JavaScript",
+    "Kotlin",
+    "Lua",
+    "OCaml",
+    "Python",
+    "Ruby",
+    "Rust",
+    "Scala",
+    "Swift",
+    "TypeScript",
+    "Zig",
+];
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - auto complete example")
+                .position((100.0, 100.0))
+                .size(320.0, 280.0)
+                .child(
+                    AutoComplete::create()
+                        .margin(8.0)
+                        .vertical_alignment("start")
+                        .water_mark("Programming language...")
+                        .suggestions(LANGUAGES.iter().map(|l| l.to_string()).collect::<Vec<_>>())
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
@@ -145,6 +145,7 @@ fn generate_digit_button(
     Button::create()
         .min_size(48.0, 48.0)
         .text(sight.to_string())
+        .automation_id(format!("button-{}", sight))
         .selector(get_button_selector(primary))
         .on_click(move |states, _| -> bool {
             state(id, states).action(Action::Digit(sight));
@@ -168,6 +169,7 @@ fn generate_operation_button(
     Button::create()
         .min_size(48.0, 48.0)
         .text(sight.to_string())
+        .automation_id(format!("button-{}", sight))
         .selector(get_button_selector(primary).class("square"))
         .on_click(move |states, _| -> bool {
             state(id, states).action(Action::Operator(sight));
@@ -218,6 +220,7 @@ impl Template for MainView {
                                     .child(
                                         TextBlock::create()
                                             .selector(Selector::from("text-block"))
+                                            .automation_id("result")
                                             .text(id)
                                             .vertical_alignment("end")
                                             .horizontal_alignment("end")
@@ -0,0 +1,83 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    changed: bool,
+}
+
+impl MainViewState {
+    fn date_changed(&mut self) {
+        self.changed = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if !self.changed {
+            return;
+        }
+
+        self.changed = false;
+
+        let status = match ctx.widget().get::<SelectedDate>("selected_date").0 {
+            Some(date) => format!("Selected: {}", date),
+            None => "No date selected".to_string(),
+        };
+
+        ctx.widget().set("status", String16::from(status));
+    }
+}
+
+widget!(MainView<MainViewState> {
+    selected_date: SelectedDate,
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .selected_date(Date::new(2020, 3, 1))
+            .status("")
+            .child(
+                Stack::create()
+                    .margin(8.0)
+                    .child(
+                        DatePicker::create()
+                            .margin((0.0, 0.0, 0.0, 8.0))
+                            .selected_date(id)
+                            .on_changed(move |states, _| {
+                                states.get_mut::<MainViewState>(id).date_changed();
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Calendar::create()
+                            .margin((0.0, 0.0, 0.0, 8.0))
+                            .horizontal_alignment("start")
+                            .selected_date(id)
+                            .on_changed(move |states, _| {
+                                states.get_mut::<MainViewState>(id).date_changed();
+                            })
+                            .build(ctx),
+                    )
+                    .child(TextBlock::create().text(("status", id)).build(ctx))
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - calendar example")
+                .position((100.0, 100.0))
+                .size(320.0, 420.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
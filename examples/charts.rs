@@ -0,0 +1,53 @@
+use orbtk::prelude::*;
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            let sales = ChartSeries::from(vec![
+                ("Jan", 12.0),
+                ("Feb", 18.5),
+                ("Mar", 9.0),
+                ("Apr", 24.0),
+                ("May", 21.0),
+            ]);
+
+            Window::create()
+                .title("OrbTk - charts example")
+                .position((100.0, 100.0))
+                .size(720.0, 300.0)
+                .resizeable(true)
+                .child(
+                    Grid::create()
+                        .margin(8.0)
+                        .columns(
+                            Columns::create()
+                                .column("*")
+                                .column(8.0)
+                                .column("*")
+                                .column(8.0)
+                                .column("*")
+                                .build(),
+                        )
+                        .child(LineChart::create().series(sales.clone()).build(ctx))
+                        .child(
+                            BarChart::create()
+                                .attach(Grid::column(2))
+                                .series(vec![3.0, -1.5, 4.0, 2.5])
+                                .series_brush("#647b91")
+                                .build(ctx),
+                        )
+                        .child(
+                            PieChart::create()
+                                .attach(Grid::column(4))
+                                .series(sales)
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,73 @@
+use orbtk::prelude::*;
+
+static KEYWORDS: [&str; 8] = ["fn", "let", "mut", "if", "else", "for", "in", "return"];
+
+static CODE: &str = "fn main() {
+    // prints the numbers from 1 to 3
+    for i in 1..4 {
+        println!(\"{}\", i);
+    }
+}";
+
+// Highlights the keywords, strings and line comments of a line of rust code.
+fn highlight(line: &str) -> Vec<TextSpan> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut spans = vec![];
+    let mut index = 0;
+
+    while index < chars.len() {
+        let start = index;
+
+        if chars[index] == '/' && chars.get(index + 1) == Some(&'/') {
+            spans.push(TextSpan::new(start, chars.len(), "#7f848e"));
+            break;
+        } else if chars[index] == '"' {
+            index += 1;
+
+            while index < chars.len() && chars[index] != '"' {
+                index += 1;
+            }
+
+            index += 1;
+            spans.push(TextSpan::new(start, index, "#98c379"));
+        } else if chars[index].is_alphanumeric() || chars[index] == '_' {
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                index += 1;
+            }
+
+            let word: String = chars[start..index].iter().collect();
+
+            if KEYWORDS.contains(&word.as_str()) {
+                spans.push(TextSpan::new(start, index, "#c678dd"));
+            }
+        } else {
+            index += 1;
+        }
+    }
+
+    spans
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - code editor example")
+                .position((100.0, 100.0))
+                .size(480.0, 320.0)
+                .resizeable(true)
+                .child(
+                    CodeEditor::create()
+                        .margin(8.0)
+                        .text(CODE)
+                        .font_size(14.0)
+                        .highlighter(highlight)
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,56 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    crash: bool,
+}
+
+impl MainViewState {
+    fn crash(&mut self) {
+        self.crash = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, _: &mut Context<'_>) {
+        if self.crash {
+            panic!("Crash button is clicked.");
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Button::create()
+                .margin(8.0)
+                .text("Crash")
+                .on_click(move |states, _| {
+                    states.get_mut::<MainViewState>(id).crash();
+                    true
+                })
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // the crash report bundle is written to the crash-reports directory
+    Application::new()
+        .crash_reporter(
+            CrashReporter::new("crash-reports")
+                .max_events(20)
+                .theme_name("dark"),
+        )
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - crash reporter example")
+                .position((100.0, 100.0))
+                .size(320.0, 120.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,97 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    close: bool,
+}
+
+impl MainViewState {
+    fn close(&mut self) {
+        self.close = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if self.close {
+            self.close = false;
+            ctx.close_window();
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Grid::create()
+                .rows(Rows::create().row(32.0).row("*").build())
+                .child(
+                    // the title bar moves the window
+                    WindowHandle::create()
+                        .selector(Selector::from("window-handle").id("title_bar"))
+                        .background("#3b434a")
+                        .padding((8.0, 0.0, 4.0, 0.0))
+                        .attach(Grid::row(0))
+                        .child(
+                            TextBlock::create()
+                                .text("OrbTk - custom chrome example")
+                                .vertical_alignment("center")
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::create()
+                                .selector(Selector::from("button").id("close"))
+                                .text("x")
+                                .horizontal_alignment("end")
+                                .vertical_alignment("center")
+                                .on_click(move |states, _| {
+                                    states.get_mut::<MainViewState>(id).close();
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::create()
+                        .text("Drag the title bar to move the window.")
+                        .margin(8.0)
+                        .attach(Grid::row(1))
+                        .build(ctx),
+                )
+                .child(
+                    // the grip in the bottom right corner resizes the window
+                    WindowHandle::create()
+                        .selector(Selector::from("window-handle").id("grip"))
+                        .grip("bottom-right")
+                        .background("#5b0f22")
+                        .horizontal_alignment("end")
+                        .vertical_alignment("end")
+                        .width(12.0)
+                        .height(12.0)
+                        .attach(Grid::row(1))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - custom chrome example")
+                .position((100.0, 100.0))
+                .size(420.0, 240.0)
+                .borderless(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,137 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct ConfirmDialogState {
+    answer: Option<bool>,
+}
+
+impl ConfirmDialogState {
+    fn answer(&mut self, answer: bool) {
+        self.answer = Some(answer);
+    }
+}
+
+impl State for ConfirmDialogState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(answer) = self.answer.take() {
+            ctx.close_dialog(answer);
+        }
+    }
+}
+
+widget!(ConfirmDialog<ConfirmDialogState> {
+    text: String16
+});
+
+impl Template for ConfirmDialog {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("ConfirmDialog").child(
+            Stack::create()
+                .spacing(16.0)
+                .child(TextBlock::create().text(("text", id)).build(ctx))
+                .child(
+                    Stack::create()
+                        .orientation("horizontal")
+                        .horizontal_alignment("end")
+                        .spacing(8.0)
+                        .child(
+                            Button::create()
+                                .text("Cancel")
+                                .on_click(move |states, _| {
+                                    states.get_mut::<ConfirmDialogState>(id).answer(false);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::create()
+                                .text("Delete")
+                                .on_click(move |states, _| {
+                                    states.get_mut::<ConfirmDialogState>(id).answer(true);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    delete: bool,
+}
+
+impl MainViewState {
+    fn delete(&mut self) {
+        self.delete = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if !self.delete {
+            return;
+        }
+
+        self.delete = false;
+        let main_view = ctx.entity;
+
+        ctx.show_dialog(
+            |ctx| ConfirmDialog::create().text("Delete the file?").build(ctx),
+            move |ctx, answer: Option<bool>| {
+                let status = match answer {
+                    Some(true) => "File deleted",
+                    Some(false) => "Delete canceled",
+                    None => "Dialog closed with escape",
+                };
+
+                ctx.get_widget(main_view)
+                    .set("status", String16::from(status));
+            },
+        );
+    }
+}
+
+widget!(MainView<MainViewState> {
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").status("").child(
+            Stack::create()
+                .margin(16.0)
+                .spacing(16.0)
+                .child(
+                    Button::create()
+                        .text("Delete file")
+                        .on_click(move |states, _| {
+                            states.get_mut::<MainViewState>(id).delete();
+                            true
+                        })
+                        .build(ctx),
+                )
+                .child(TextBlock::create().text(("status", id)).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - dialog example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
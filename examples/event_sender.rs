@@ -0,0 +1,85 @@
+use std::{thread, time::Duration};
+
+use orbtk::prelude::*;
+
+// Event that is sent by the simulated sensor thread.
+pub struct TemperatureEvent(f64);
+
+impl Event for TemperatureEvent {}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    temperature: Option<f64>,
+}
+
+impl MainViewState {
+    fn temperature(&mut self, temperature: f64) {
+        self.temperature = Some(temperature);
+    }
+}
+
+impl State for MainViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let event_sender = ctx.event_sender();
+
+        // simulates a sensor that sends a new value every second
+        thread::spawn(move || {
+            let mut temperature = 20.0;
+
+            loop {
+                thread::sleep(Duration::from_secs(1));
+                temperature += 0.5;
+
+                if event_sender.send(TemperatureEvent(temperature)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(temperature) = self.temperature.take() {
+            ctx.widget().set(
+                "temperature",
+                String16::from(format!("Temperature: {:.1} °C", temperature)),
+            );
+        }
+    }
+}
+
+widget!(MainView<MainViewState>: CustomHandler {
+    temperature: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .temperature("Temperature: -")
+            .on_event(move |states, event: &TemperatureEvent| {
+                states.get_mut::<MainViewState>(id).temperature(event.0);
+                true
+            })
+            .child(
+                TextBlock::create()
+                    .margin(8.0)
+                    .text(("temperature", id))
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - event sender example")
+                .position((100.0, 100.0))
+                .size(320.0, 120.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
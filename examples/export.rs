@@ -0,0 +1,117 @@
+use std::env;
+
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    format: Option<ExportFormat>,
+}
+
+impl MainViewState {
+    fn export(&mut self, format: ExportFormat) {
+        self.format = Some(format);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let format = match self.format.take() {
+            Some(format) => format,
+            None => return,
+        };
+
+        let file_name = match format {
+            ExportFormat::Png => "orbtk-report.png",
+            ExportFormat::Pdf => "orbtk-report.pdf",
+        };
+        let path = env::temp_dir().join(file_name);
+        let report = ctx.child("report").entity();
+        let message = format!("Exported report to {}", path.display());
+
+        // the png is rendered with twice the resolution of the window
+        ctx.export(
+            ExportRequest::new(path)
+                .entity(report)
+                .dpi(192.0)
+                .on_finish(move |result| match result {
+                    Ok(()) => println!("{}", message),
+                    Err(error) => eprintln!("{}", error),
+                }),
+        );
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::create()
+                .margin(16.0)
+                .spacing(16.0)
+                .child(
+                    Container::create()
+                        .selector(Selector::default().id("report"))
+                        .background("#3b434a")
+                        .border_radius(4.0)
+                        .padding(16.0)
+                        .child(
+                            Stack::create()
+                                .spacing(8.0)
+                                .child(
+                                    TextBlock::create()
+                                        .text("Monthly report")
+                                        .font_size(24.0)
+                                        .build(ctx),
+                                )
+                                .child(TextBlock::create().text("Visitors: 1,204").build(ctx))
+                                .child(TextBlock::create().text("Downloads: 318").build(ctx))
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    Stack::create()
+                        .orientation("horizontal")
+                        .spacing(8.0)
+                        .child(
+                            Button::create()
+                                .text("Export PNG")
+                                .on_click(move |states, _| {
+                                    states
+                                        .get_mut::<MainViewState>(id)
+                                        .export(ExportFormat::Png);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::create()
+                                .text("Export PDF")
+                                .on_click(move |states, _| {
+                                    states
+                                        .get_mut::<MainViewState>(id)
+                                        .export(ExportFormat::Pdf);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - export example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,65 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    selected_path: String,
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let selected_path = ctx.widget().clone::<String>("selected_path");
+
+        if selected_path != self.selected_path {
+            ctx.widget()
+                .set("status", String16::from(selected_path.as_str()));
+            self.selected_path = selected_path;
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    selected_path: String,
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").selected_path("").status("").child(
+            Grid::create()
+                .rows(Rows::create().row("*").row("auto").build())
+                .child(
+                    FileBrowser::create()
+                        .margin(8.0)
+                        .selected_path(id)
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::create()
+                        .attach(Grid::row(1))
+                        .margin(8.0)
+                        .text(("status", id))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - file browser example")
+                .position((100.0, 100.0))
+                .size(640.0, 480.0)
+                .resizeable(true)
+                // renders only after input or after a directory is read
+                .render_mode("on-demand")
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
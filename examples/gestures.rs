@@ -0,0 +1,85 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    gesture: Option<Gesture>,
+}
+
+impl MainViewState {
+    fn gesture(&mut self, gesture: Gesture) {
+        self.gesture = Some(gesture);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(gesture) = self.gesture.take() {
+            let status = match gesture {
+                Gesture::Tap(p) => format!("Tap at ({}, {})", p.x, p.y),
+                Gesture::LongPress(p) => format!("Long press at ({}, {})", p.x, p.y),
+                Gesture::Swipe(direction) => format!("Swipe {:?}", direction),
+                Gesture::Pinch { scale, .. } => format!("Pinch scale {:.2}", scale),
+            };
+
+            ctx.widget().set("status", String16::from(status));
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .status("Tap, long press, swipe or pinch the box")
+            .child(
+                Grid::create()
+                    .margin(8.0)
+                    .rows(Rows::create().row("*").row("auto").build())
+                    .child(
+                        Container::create()
+                            .margin((0.0, 0.0, 0.0, 8.0))
+                            .background("#3b434a")
+                            .border_radius(4.0)
+                            .on_gesture(
+                                &[
+                                    GestureKind::Tap,
+                                    GestureKind::LongPress,
+                                    GestureKind::Swipe,
+                                    GestureKind::Pinch,
+                                ],
+                                move |states, gesture| {
+                                    states.get_mut::<MainViewState>(id).gesture(gesture);
+                                    true
+                                },
+                            )
+                            .build(ctx),
+                    )
+                    .child(
+                        TextBlock::create()
+                            .attach(Grid::row(1))
+                            .text(("status", id))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - gestures example")
+                .position((100.0, 100.0))
+                .size(320.0, 320.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,95 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    changed: bool,
+}
+
+impl MainViewState {
+    fn changed(&mut self) {
+        self.changed = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if !self.changed {
+            return;
+        }
+
+        self.changed = false;
+
+        let stops = match ctx.widget().get::<Brush>("brush") {
+            Brush::LinearGradient { stops, .. } => stops
+                .iter()
+                .map(|stop| format!("{} {:.2}", stop.color.to_string(), stop.position))
+                .collect::<Vec<String>>()
+                .join(", "),
+            _ => String::new(),
+        };
+
+        ctx.widget().set("stops", String16::from(stops));
+    }
+}
+
+widget!(MainView<MainViewState> {
+    brush: Brush,
+    stops: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .brush(Brush::LinearGradient {
+                start: Point::new(0.0, 0.0),
+                end: Point::new(400.0, 0.0),
+                stops: vec![
+                    LinearGradientStop {
+                        position: 0.0,
+                        color: Color::from("#efd035"),
+                    },
+                    LinearGradientStop {
+                        position: 1.0,
+                        color: Color::from("#647b91"),
+                    },
+                ],
+            })
+            .stops("")
+            .child(
+                Stack::create()
+                    .margin(8.0)
+                    .spacing(8.0)
+                    .child(
+                        GradientEditor::create()
+                            .brush(id)
+                            .on_changed(move |states, _| {
+                                states.get_mut::<MainViewState>(id).changed();
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        TextBlock::create()
+                            .text(("stops", id))
+                            .text_wrap("wrap")
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - gradient editor example")
+                .position((100.0, 100.0))
+                .size(420.0, 240.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
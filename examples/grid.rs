@@ -1,12 +1,8 @@
 use orbtk::prelude::*;
-use orbtk::theme::DEFAULT_THEME_CSS;
-
-static CSS_EXT: &'static str = include_str!("../res/grid.css");
 
+// the default theme and its extension are parsed at build time
 fn get_theme() -> ThemeValue {
-    ThemeValue::create_from_css(DEFAULT_THEME_CSS)
-        .extension_css(CSS_EXT)
-        .build()
+    compile_theme!("crates/theme/src/dark.css", "res/grid.css").build()
 }
 
 widget!(MainView);
@@ -23,6 +19,8 @@ impl Template for MainView {
                         .build(),
                 )
                 .rows(Rows::create().row("*").row("*").build())
+                // draws the columns and rows with their computed sizes
+                .debug(true)
                 .child(
                     Grid::create()
                         .selector("lynch")
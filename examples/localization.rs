@@ -0,0 +1,106 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    language: Option<&'static str>,
+}
+
+impl MainViewState {
+    fn switch_language(&mut self, language: &'static str) {
+        self.language = Some(language);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, registry: &mut Registry, _: &mut Context<'_>) {
+        if let Some(language) = self.language.take() {
+            // all localized properties are updated with the new language
+            registry
+                .get_mut::<Localization>("localization")
+                .set_language(language);
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::create()
+                .child(
+                    TextBlock::create()
+                        .text(loc!("title"))
+                        .selector(Selector::new().with("text-block").class("h1"))
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::create()
+                        .text(loc!("greeting"))
+                        .margin((0.0, 8.0, 0.0, 8.0))
+                        .build(ctx),
+                )
+                .child(
+                    Stack::create()
+                        .orientation("horizontal")
+                        .child(
+                            Button::create()
+                                .text(loc!("english_button"))
+                                .on_click(move |states, _| {
+                                    state(id, states).switch_language("en");
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::create()
+                                .text(loc!("german_button"))
+                                .margin((8.0, 0.0, 0.0, 0.0))
+                                .on_click(move |states, _| {
+                                    state(id, states).switch_language("de");
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::create()
+                                .text(loc!("save_button"))
+                                .margin((8.0, 0.0, 0.0, 0.0))
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    let mut localization = Localization::new("en");
+    localization
+        .load_ron("en", include_str!("../res/localization/en.ron"))
+        .unwrap();
+    localization
+        .load_ftl("de", include_str!("../res/localization/de.ftl"))
+        .unwrap();
+
+    Application::new()
+        .localization(localization)
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - localization example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .child(MainView::create().margin(8.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
+
+// helper to request MainViewState
+fn state<'a>(id: Entity, states: &'a mut StatesContext) -> &'a mut MainViewState {
+    states.get_mut(id)
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use orbtk::prelude::*;
+
+static CONTACTS: [(&str, &str, &str); 4] = [
+    ("Ada Lovelace", "ada@example.com", "London"),
+    ("Grace Hopper", "grace@example.com", "New York"),
+    ("Alan Turing", "alan@example.com", "Manchester"),
+    ("Margaret Hamilton", "margaret@example.com", "Boston"),
+];
+
+widget!(MainView {
+    selected_indices: SelectedIndices
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .selected_indices(HashSet::new())
+            .child(
+                Grid::create()
+                    .margin(8.0)
+                    .columns(
+                        Columns::create()
+                            .column(160.0)
+                            .column(8.0)
+                            .column("*")
+                            .build(),
+                    )
+                    .child(
+                        ListView::create()
+                            .selected_indices(id)
+                            .count(CONTACTS.len())
+                            .items_builder(|bc, index| {
+                                TextBlock::create()
+                                    .vertical_alignment("center")
+                                    .text(CONTACTS[index].0)
+                                    .build(bc)
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        DetailView::create()
+                            .attach(Grid::column(2))
+                            .selected_indices(id)
+                            // keeps the notes of each contact
+                            .preserve_details(true)
+                            .detail_builder(|bc, index| {
+                                let (name, mail, city) = CONTACTS[index];
+
+                                Stack::create()
+                                    .spacing(8.0)
+                                    .child(TextBlock::create().font_size(24.0).text(name).build(bc))
+                                    .child(TextBlock::create().text(mail).build(bc))
+                                    .child(TextBlock::create().text(city).build(bc))
+                                    .child(TextBox::create().water_mark("Notes").build(bc))
+                                    .build(bc)
+                            })
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - master detail example")
+                .position((100.0, 100.0))
+                .size(480.0, 300.0)
+                .resizeable(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,120 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    action: Option<String>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: impl Into<String>) {
+        self.action = Some(action.into());
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(action) = self.action.take() {
+            ctx.widget()
+                .set("status", String16::from(format!("Clicked: {}", action)));
+        }
+    }
+}
+
+fn menu_item(id: Entity, text: &'static str) -> MenuItem {
+    MenuItem::create().text(text).on_click(move |states, _| {
+        states.get_mut::<MainViewState>(id).action(text);
+        false
+    })
+}
+
+widget!(MainView<MainViewState> {
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").status("").child(
+            Grid::create()
+                .rows(Rows::create().row("auto").row("*").build())
+                .child(
+                    MenuBar::create()
+                        .child(
+                            Menu::create()
+                                .text("File")
+                                .child(
+                                    menu_item(id, "New")
+                                        .icon(material_font_icons::ADD_FONT_ICON)
+                                        .accelerator("Ctrl+N")
+                                        .build(ctx),
+                                )
+                                .child(
+                                    menu_item(id, "Open")
+                                        .icon(material_font_icons::LOAD_FONT_ICON)
+                                        .accelerator("Ctrl+O")
+                                        .build(ctx),
+                                )
+                                .child(
+                                    menu_item(id, "Save")
+                                        .font_icon(
+                                            Icon::new(material_font_icons::FLOPPY_FONT_ICON)
+                                                .brush("#efd035"),
+                                        )
+                                        .accelerator("Ctrl+S")
+                                        .build(ctx),
+                                )
+                                .child(MenuSeparator::create().build(ctx))
+                                .child(
+                                    MenuItem::create()
+                                        .text("Recent")
+                                        .child(menu_item(id, "calculator.rs").build(ctx))
+                                        .child(menu_item(id, "menu.rs").build(ctx))
+                                        .build(ctx),
+                                )
+                                .child(MenuSeparator::create().build(ctx))
+                                .child(menu_item(id, "Quit").build(ctx))
+                                .build(ctx),
+                        )
+                        .child(
+                            Menu::create()
+                                .text("Edit")
+                                .child(menu_item(id, "Undo").accelerator("Ctrl+Z").build(ctx))
+                                .child(menu_item(id, "Redo").accelerator("Ctrl+Y").build(ctx))
+                                .child(MenuSeparator::create().build(ctx))
+                                .child(
+                                    menu_item(id, "Paste")
+                                        .accelerator("Ctrl+V")
+                                        .enabled(false)
+                                        .build(ctx),
+                                )
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::create()
+                        .attach(Grid::row(1))
+                        .margin(8.0)
+                        .text(("status", id))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - menu example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,105 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct ScreenState {
+    next: bool,
+    back: bool,
+}
+
+impl ScreenState {
+    fn next(&mut self) {
+        self.next = true;
+    }
+
+    fn back(&mut self) {
+        self.back = true;
+    }
+}
+
+impl State for ScreenState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if self.next {
+            self.next = false;
+            let next = ctx.widget().clone::<String>("next");
+            ctx.navigate(next);
+        }
+
+        if self.back {
+            self.back = false;
+            ctx.navigate_back();
+        }
+    }
+}
+
+widget!(Screen<ScreenState> {
+    title: String16,
+
+    /// Name of the screen that is pushed by the next button.
+    next: String
+});
+
+impl Template for Screen {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("Screen").child(
+            Stack::create()
+                .margin(16.0)
+                .spacing(8.0)
+                .child(
+                    TextBlock::create()
+                        .font_size(24.0)
+                        .text(("title", id))
+                        .build(ctx),
+                )
+                .child(
+                    Button::create()
+                        .text("Next")
+                        .on_click(move |states, _| {
+                            states.get_mut::<ScreenState>(id).next();
+                            true
+                        })
+                        .build(ctx),
+                )
+                .child(
+                    Button::create()
+                        .text("Back")
+                        .on_click(move |states, _| {
+                            states.get_mut::<ScreenState>(id).back();
+                            true
+                        })
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - navigation example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .child(
+                    NavigationView::create()
+                        .screen("home", |ctx| {
+                            Screen::create().title("Home").next("settings").build(ctx)
+                        })
+                        .screen("settings", |ctx| {
+                            Screen::create().title("Settings").next("about").build(ctx)
+                        })
+                        .screen("about", |ctx| {
+                            Screen::create().title("About").next("home").build(ctx)
+                        })
+                        .start_screen("home")
+                        .on_enter(|_, screen| println!("enter {}", screen))
+                        .on_leave(|_, screen| println!("leave {}", screen))
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    send: bool,
+    read: bool,
+}
+
+impl MainViewState {
+    fn send(&mut self) {
+        self.send = true;
+    }
+
+    fn read(&mut self) {
+        self.read = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if self.send {
+            self.send = false;
+
+            let unread = *ctx.widget().get::<usize>("unread") + 1;
+            ctx.widget().set("unread", unread);
+            ctx.show_toast(
+                format!("Message {} received", unread),
+                Duration::from_secs(3),
+            );
+        }
+
+        if self.read {
+            self.read = false;
+            ctx.widget().set("unread", 0_usize);
+            ctx.show_toast("All messages read", Duration::from_secs(2));
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    unread: usize
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").unread(0).child(
+            Stack::create()
+                .margin(16.0)
+                .spacing(16.0)
+                .orientation("horizontal")
+                .child(
+                    Button::create()
+                        .text("Send message")
+                        .on_click(move |states, _| {
+                            states.get_mut::<MainViewState>(id).send();
+                            true
+                        })
+                        .build(ctx),
+                )
+                .child(
+                    // the badge shows the unread count in the corner of the button
+                    Badge::create()
+                        .count(("unread", id))
+                        .child(
+                            Button::create()
+                                .text("Inbox")
+                                .on_click(move |states, _| {
+                                    states.get_mut::<MainViewState>(id).read();
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - notifications example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
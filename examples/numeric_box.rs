@@ -0,0 +1,74 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    value: Option<f64>,
+}
+
+impl MainViewState {
+    fn value_changed(&mut self, value: f64) {
+        self.value = Some(value);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(value) = self.value.take() {
+            ctx.widget()
+                .set("status", String16::from(format!("Value: {}", value)));
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").status("").child(
+            Stack::create()
+                .margin(8.0)
+                .child(
+                    NumericBox::create()
+                        .margin((0.0, 0.0, 0.0, 8.0))
+                        .min(0.0)
+                        .max(10.0)
+                        .step(0.5)
+                        .value(2.5)
+                        .on_value_changed(move |states, value| {
+                            states.get_mut::<MainViewState>(id).value_changed(value);
+                        })
+                        .build(ctx),
+                )
+                .child(
+                    NumericBox::create()
+                        .margin((0.0, 0.0, 0.0, 8.0))
+                        .min(-100.0)
+                        .max(100.0)
+                        .on_value_changed(move |states, value| {
+                            states.get_mut::<MainViewState>(id).value_changed(value);
+                        })
+                        .build(ctx),
+                )
+                .child(TextBlock::create().text(("status", id)).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - numeric box example")
+                .position((100.0, 100.0))
+                .size(320.0, 200.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
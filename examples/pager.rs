@@ -0,0 +1,39 @@
+use orbtk::prelude::*;
+
+fn page(ctx: &mut BuildContext, text: &str, background: &str) -> Entity {
+    Container::create()
+        .background(background)
+        .child(
+            TextBlock::create()
+                .text(text)
+                .font_size(24.0)
+                .horizontal_alignment("center")
+                .vertical_alignment("center")
+                .build(ctx),
+        )
+        .build(ctx)
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - pager example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .child(
+                    // swipe to the left or right or tap a dot to change the page
+                    Pager::create()
+                        .margin(16.0)
+                        .page(page(ctx, "Welcome", "#3b434a"))
+                        .page(page(ctx, "Swipe", "#5b0f22"))
+                        .page(page(ctx, "Done", "#1b5e20"))
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,72 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    login: bool,
+}
+
+impl MainViewState {
+    fn login(&mut self) {
+        self.login = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if !self.login {
+            return;
+        }
+
+        self.login = false;
+
+        let status = if ctx.widget().get::<String16>("password").to_string() == "orbtk" {
+            "Access granted"
+        } else {
+            "Wrong password, try orbtk"
+        };
+
+        ctx.widget().set("status", String16::from(status));
+    }
+}
+
+widget!(MainView<MainViewState> {
+    password: String16,
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").password("").status("").child(
+            Stack::create()
+                .margin(8.0)
+                .child(
+                    PasswordBox::create()
+                        .margin((0.0, 0.0, 0.0, 8.0))
+                        .water_mark("Password...")
+                        .text(("password", id))
+                        .on_activate(move |states, _| {
+                            states.get_mut::<MainViewState>(id).login();
+                        })
+                        .build(ctx),
+                )
+                .child(TextBlock::create().text(("status", id)).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - password box example")
+                .position((100.0, 100.0))
+                .size(320.0, 120.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,64 @@
+use std::{sync::mpsc::channel, thread, time::Duration};
+
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState;
+
+impl State for MainViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let (sender, receiver) = channel();
+
+        // simulates a sensor that sends a new value every millisecond
+        thread::spawn(move || {
+            let mut time = 0.0_f64;
+
+            loop {
+                thread::sleep(Duration::from_millis(1));
+                time += 0.001;
+
+                if sender.send(50.0 + (time * 2.0).sin() * 50.0).is_err() {
+                    break;
+                }
+            }
+        });
+
+        // the ui is updated at most every 30 milliseconds with the latest value
+        ctx.ui_handle()
+            .feed::<f64>("value")
+            .throttle(Duration::from_millis(30))
+            .pipe(receiver);
+    }
+}
+
+widget!(MainView<MainViewState> {
+    value: f64
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").value(0.0).child(
+            Slider::create()
+                .margin(8.0)
+                .vertical_alignment("center")
+                .value(id)
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - property feed example")
+                .position((100.0, 100.0))
+                .size(320.0, 120.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,60 @@
+use orbtk::prelude::*;
+
+// Draws an animated color gradient. A GPU renderer would draw into an offscreen texture and read
+// it back into the texture of the frame.
+#[derive(Default)]
+struct PlasmaRenderer;
+
+impl SurfaceRenderer for PlasmaRenderer {
+    fn render(&mut self, frame: SurfaceFrame<'_>) {
+        let width = frame.texture.width() as usize;
+        let time = frame.frame as f64 * 0.05;
+
+        for (i, pixel) in frame.texture.data_mut().iter_mut().enumerate() {
+            let x = (i % width) as f64 * 0.02;
+            let y = (i / width) as f64 * 0.02;
+            let value = ((x + time).sin() + (y + time * 0.5).cos() + 2.0) / 4.0;
+
+            *pixel = Color::rgb(
+                (value * 255.0) as u8,
+                ((1.0 - value) * 128.0) as u8,
+                (255.0 - value * 128.0) as u8,
+            )
+            .data;
+        }
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - render surface example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .child(
+                    Grid::create()
+                        .rows(Rows::create().row("auto").row("*").build())
+                        .child(
+                            TextBlock::create()
+                                .margin(8.0)
+                                .text("The surface below is rendered each frame")
+                                .build(ctx),
+                        )
+                        .child(
+                            RenderSurface::create()
+                                .attach(Grid::row(1))
+                                .margin(8.0)
+                                .renderer(PlasmaRenderer)
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
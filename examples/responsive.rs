@@ -0,0 +1,77 @@
+use orbtk::prelude::*;
+
+// Width of the window below which the narrow layout is used.
+static BREAKPOINT: f64 = 400.0;
+
+// Shows the widget only while the trigger is active.
+fn visible_while(active: bool) -> Visibility {
+    if active {
+        Visibility::Visible
+    } else {
+        Visibility::Collapsed
+    }
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::create()
+                .margin(8.0)
+                .spacing(8.0)
+                .child(
+                    TextBlock::create()
+                        .selector(Selector::from("h1").id("wide_header"))
+                        .text("Wide layout")
+                        .layout_trigger(LayoutTrigger::new().min_width(BREAKPOINT).on_change(
+                            |widget, active| widget.set("visibility", visible_while(active)),
+                        ))
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::create()
+                        .selector(Selector::default().id("narrow_header"))
+                        .text("Narrow layout")
+                        .layout_trigger(LayoutTrigger::new().max_width(BREAKPOINT).on_change(
+                            |widget, active| widget.set("visibility", visible_while(active)),
+                        ))
+                        .build(ctx),
+                )
+                .child(
+                    // the tool bar is arranged vertical in a narrow window
+                    Stack::create()
+                        .selector(Selector::from("stack").id("tool_bar"))
+                        .spacing(4.0)
+                        .layout_trigger(LayoutTrigger::new().max_width(BREAKPOINT).on_change(
+                            |widget, active| {
+                                let orientation = if active { "vertical" } else { "horizontal" };
+                                widget.set("orientation", Orientation::from(orientation));
+                            },
+                        ))
+                        .child(Button::create().text("New").build(ctx))
+                        .child(Button::create().text("Open").build(ctx))
+                        .child(Button::create().text("Save").build(ctx))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - responsive example")
+                .position((100.0, 100.0))
+                .size(480.0, 300.0)
+                .resizeable(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
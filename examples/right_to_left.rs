@@ -0,0 +1,58 @@
+use orbtk::prelude::*;
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            // all children inherit the flow direction
+            .flow_direction("rtl")
+            .child(
+                Stack::create()
+                    .child(
+                        TextBlock::create()
+                            .text("مرحبا بالعالم")
+                            .selector("h1")
+                            .build(ctx),
+                    )
+                    .child(
+                        Stack::create()
+                            .orientation("horizontal")
+                            .margin((0.0, 8.0, 0.0, 0.0))
+                            .child(Button::create().text("First").build(ctx))
+                            .child(
+                                Button::create()
+                                    .text("Second")
+                                    .margin((8.0, 0.0, 0.0, 0.0))
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(
+                        TextBox::create()
+                            .water_mark("Placeholder")
+                            .width(200.0)
+                            .margin((0.0, 8.0, 0.0, 0.0))
+                            .horizontal_alignment("start")
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - right to left example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .child(MainView::create().margin(8.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
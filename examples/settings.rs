@@ -26,17 +26,13 @@ impl MainViewState {
 }
 
 impl State for MainViewState {
-    fn update(&mut self, registry: &mut Registry, ctx: &mut Context<'_>) {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
         if let Some(action) = self.action {
             match action {
                 Action::Load => {
                     // load label from settings file.
-                    if let Ok(global) = registry
-                        .get::<Settings>("settings")
-                        .load::<Global>("global")
-                    {
-                        ctx.widget().set("text", String16::from(global.label));
-                    }
+                    let global = ctx.settings().load_or_default::<Global>("global");
+                    ctx.widget().set("text", String16::from(global.label));
 
                     ctx.widget().set(
                         "info_text",
@@ -45,15 +41,8 @@ impl State for MainViewState {
                 }
                 Action::Save => {
                     // save label to settings file.
-                    registry
-                        .get_mut::<Settings>("settings")
-                        .save(
-                            "global",
-                            &Global {
-                                label: ctx.widget().get::<String16>("text").to_string(),
-                            },
-                        )
-                        .unwrap();
+                    let label = ctx.widget().get::<String16>("text").to_string();
+                    ctx.settings().save("global", &Global { label }).unwrap();
                     ctx.widget()
                         .set("info_text", String16::from("Label saved to settings file."));
                 }
@@ -0,0 +1,137 @@
+use orbtk::prelude::*;
+
+#[derive(Debug, Copy, Clone)]
+enum Action {
+    Increment,
+    StepBackward,
+    StepForward,
+}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    action: Option<Action>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+impl State for MainViewState {
+    fn init(&mut self, registry: &mut Registry, _: &mut Context<'_>) {
+        // records all changes of the count property
+        registry.register(
+            "state_recorder",
+            StateRecorder::new().track::<String16>("count"),
+        );
+    }
+
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context<'_>) {
+        let recorder = registry.get_mut::<StateRecorder>("state_recorder");
+
+        if let Some(action) = self.action.take() {
+            match action {
+                Action::Increment => {
+                    let count = ctx
+                        .widget()
+                        .get::<String16>("count")
+                        .to_string()
+                        .parse::<i32>()
+                        .unwrap_or(0);
+                    ctx.widget()
+                        .set("count", String16::from((count + 1).to_string()));
+                }
+                Action::StepBackward => recorder.step_backward(),
+                Action::StepForward => recorder.step_forward(),
+            }
+        }
+
+        ctx.widget().set(
+            "status",
+            String16::from(format!(
+                "Frame {} of {}",
+                recorder.position(),
+                recorder.len()
+            )),
+        );
+    }
+}
+
+widget!(MainView<MainViewState> {
+    count: String16,
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").count("0").status("").child(
+            Stack::create()
+                .margin(8.0)
+                .child(
+                    TextBlock::create()
+                        .margin((0.0, 0.0, 0.0, 8.0))
+                        .text(("count", id))
+                        .build(ctx),
+                )
+                .child(
+                    Stack::create()
+                        .orientation("horizontal")
+                        .margin((0.0, 0.0, 0.0, 8.0))
+                        .spacing(4.0)
+                        .child(
+                            Button::create()
+                                .text("Increment")
+                                .on_click(move |states, _| {
+                                    states
+                                        .get_mut::<MainViewState>(id)
+                                        .action(Action::Increment);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::create()
+                                .text("Back")
+                                .on_click(move |states, _| {
+                                    states
+                                        .get_mut::<MainViewState>(id)
+                                        .action(Action::StepBackward);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::create()
+                                .text("Forward")
+                                .on_click(move |states, _| {
+                                    states
+                                        .get_mut::<MainViewState>(id)
+                                        .action(Action::StepForward);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(TextBlock::create().text(("status", id)).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - state recorder example")
+                .position((100.0, 100.0))
+                .size(320.0, 160.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,44 @@
+use orbtk::prelude::*;
+
+static STAR: &str = include_str!("../res/star.svg");
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            let star = Svg::from_markup(STAR).unwrap();
+
+            Window::create()
+                .title("OrbTk - svg example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .child(
+                    Stack::create()
+                        .orientation("horizontal")
+                        .margin(16.0)
+                        .spacing(16.0)
+                        // the svg is drawn crisp at each size
+                        .child(SvgImage::create().svg(star.clone()).build(ctx))
+                        .child(
+                            SvgImage::create()
+                                .svg(star.clone())
+                                .width(48.0)
+                                .height(48.0)
+                                .build(ctx),
+                        )
+                        .child(
+                            SvgImage::create()
+                                .svg(star)
+                                .icon_brush("#647b91")
+                                .width(128.0)
+                                .height(128.0)
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
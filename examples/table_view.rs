@@ -0,0 +1,143 @@
+use orbtk::prelude::*;
+
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct TableData(Vec<(String, usize)>);
+
+into_property_source!(TableData);
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    sort: Option<(usize, SortDirection)>,
+}
+
+impl MainViewState {
+    fn sort(&mut self, column: usize, direction: SortDirection) {
+        self.sort = Some((column, direction));
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some((column, direction)) = self.sort {
+            let rows = &mut ctx.widget().get_mut::<TableData>("rows").0;
+
+            match column {
+                0 => rows.sort_by(|a, b| a.0.cmp(&b.0)),
+                _ => rows.sort_by(|a, b| a.1.cmp(&b.1)),
+            }
+
+            if direction == SortDirection::Descending {
+                rows.reverse();
+            }
+
+            ctx.child("table").set("request_update", true);
+            self.sort = None;
+        }
+    }
+}
+
+widget!(
+    MainView<MainViewState> {
+        rows: TableData,
+        rows_count: usize
+    }
+);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let rows: Vec<(String, usize)> = (0..1000)
+            .map(|i| (format!("Item {}", i), (i * 7919) % 1000))
+            .collect();
+
+        self.name("MainView")
+            .rows_count(rows.len())
+            .rows(TableData(rows))
+            .child(
+                TableView::create()
+                    .selector(Selector::from("table-view").id("table"))
+                    .margin(8.0)
+                    .count(("rows_count", id))
+                    .frozen_columns(1)
+                    .column(
+                        TableColumn::create()
+                            .header("Name")
+                            .width(160.0)
+                            .sortable(true)
+                            .cell_builder(move |bc, row| {
+                                let text =
+                                    bc.get_widget(id).get::<TableData>("rows").0[row].0.clone();
+                                TextBlock::create()
+                                    .margin((8.0, 0.0, 0.0, 0.0))
+                                    .vertical_alignment("center")
+                                    .text(text)
+                                    .build(bc)
+                            })
+                            .cell_text(move |ctx, row| {
+                                ctx.get_widget(id).get::<TableData>("rows").0[row].0.clone()
+                            })
+                            .build(),
+                    )
+                    .column(
+                        TableColumn::create()
+                            .header("Value")
+                            .width(120.0)
+                            .sortable(true)
+                            .cell_builder(move |bc, row| {
+                                let value = bc.get_widget(id).get::<TableData>("rows").0[row].1;
+                                TextBlock::create()
+                                    .margin((8.0, 0.0, 0.0, 0.0))
+                                    .vertical_alignment("center")
+                                    .text(value.to_string())
+                                    .build(bc)
+                            })
+                            .cell_text(move |ctx, row| {
+                                ctx.get_widget(id).get::<TableData>("rows").0[row]
+                                    .1
+                                    .to_string()
+                            })
+                            .build(),
+                    )
+                    .column(
+                        TableColumn::create()
+                            .header("Hex")
+                            .width(160.0)
+                            .cell_builder(move |bc, row| {
+                                let value = bc.get_widget(id).get::<TableData>("rows").0[row].1;
+                                TextBlock::create()
+                                    .margin((8.0, 0.0, 0.0, 0.0))
+                                    .vertical_alignment("center")
+                                    .text(format!("{:#x}", value))
+                                    .build(bc)
+                            })
+                            .cell_text(move |ctx, row| {
+                                format!(
+                                    "{:#x}",
+                                    ctx.get_widget(id).get::<TableData>("rows").0[row].1
+                                )
+                            })
+                            .build(),
+                    )
+                    .on_sort(move |states, column, direction| {
+                        states.get_mut::<MainViewState>(id).sort(column, direction);
+                    })
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - table view example")
+                .position((100.0, 100.0))
+                .size(420.0, 600.0)
+                .resizeable(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
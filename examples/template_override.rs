@@ -0,0 +1,78 @@
+use orbtk::prelude::*;
+
+// Replaces the visual tree of all buttons with a pill shaped text-only button.
+fn pill_button(button: Button, id: Entity, ctx: &mut BuildContext) -> Button {
+    button
+        .name("Button")
+        .selector("button")
+        .height(32.0)
+        .min_width(80.0)
+        .background("#5b0f22")
+        .border_radius(16.0)
+        .border_width(0.0)
+        .border_brush("transparent")
+        .padding((16.0, 0.0, 16.0, 0.0))
+        .foreground("#f8de4c")
+        .text("")
+        .font_size(fonts::FONT_SIZE_12)
+        .font("Roboto Regular")
+        .pressed(false)
+        .child(
+            behaviors::MouseBehavior::create()
+                .pressed(id)
+                .enabled(id)
+                .selector(id)
+                .child(
+                    Container::create()
+                        .background(id)
+                        .border_radius(id)
+                        .border_width(id)
+                        .border_brush(id)
+                        .padding(id)
+                        .child(
+                            TextBlock::create()
+                                .horizontal_alignment("center")
+                                .vertical_alignment("center")
+                                .foreground(id)
+                                .text(id)
+                                .font_size(id)
+                                .font(id)
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .template(pill_button)
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - template override example")
+                .position((100.0, 100.0))
+                .size(372.0, 120.0)
+                .child(
+                    Stack::create()
+                        .orientation("horizontal")
+                        .margin(16.0)
+                        .spacing(8.0)
+                        .child(Button::create().text("Open").build(ctx))
+                        .child(Button::create().text("Save").build(ctx))
+                        // properties set by the app still win over the template
+                        .child(
+                            Button::create()
+                                .text("Delete")
+                                .background("#a91f1f")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
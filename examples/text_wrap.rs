@@ -0,0 +1,41 @@
+use orbtk::prelude::*;
+
+static TEXT: &str =
+    "OrbTk is a cross-platform GUI toolkit for building scalable user interfaces with \
+the programming language Rust. This text wraps at the width of the window, resize the window to \
+see the lines change.";
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - text wrap example")
+                .position((100.0, 100.0))
+                .size(320.0, 240.0)
+                .resizeable(true)
+                .child(
+                    Stack::create()
+                        .margin(8.0)
+                        .child(
+                            TextBlock::create()
+                                .margin((0.0, 0.0, 0.0, 8.0))
+                                .text(TEXT)
+                                .text_wrap("wrap")
+                                // the wrapped text is rendered once and cached until it is resized
+                                .cache_mode("texture")
+                                .build(ctx),
+                        )
+                        .child(
+                            TextBlock::create()
+                                .text("Text below the wrapped text")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,173 @@
+use orbtk::prelude::*;
+use orbtk::theme::DEFAULT_THEME_CSS;
+
+#[derive(Copy, Clone)]
+enum Action {
+    SetTarget,
+    SetBackground,
+    Apply,
+    Export,
+}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    actions: Vec<Action>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.actions.push(action);
+    }
+
+    // Applies the edited values to the window.
+    fn apply(&self, ctx: &mut Context<'_>) {
+        let css = ctx
+            .widget()
+            .get::<ThemeExtension>("theme_extension")
+            .to_css();
+
+        ctx.switch_theme(
+            ThemeValue::create_from_css(DEFAULT_THEME_CSS)
+                .extension_css(css)
+                .build(),
+        );
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let actions = std::mem::replace(&mut self.actions, vec![]);
+
+        for action in actions {
+            match action {
+                Action::SetTarget => {
+                    let target = ctx
+                        .child("target_input")
+                        .get::<String16>("text")
+                        .to_string();
+                    ctx.widget().set("target", target);
+                }
+                Action::SetBackground => {
+                    let target = ctx.widget().clone::<String>("target");
+                    let color = ctx.child("color_editor").clone::<Brush>("brush");
+
+                    if let Brush::SolidColor(color) = color {
+                        ctx.widget()
+                            .get_mut::<ThemeExtension>("theme_extension")
+                            .set(target, "background", color.to_string());
+                        self.apply(ctx);
+                    }
+                }
+                Action::Apply => self.apply(ctx),
+                Action::Export => println!(
+                    "{}",
+                    ctx.widget()
+                        .get::<ThemeExtension>("theme_extension")
+                        .to_css()
+                ),
+            }
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    target: String,
+    theme_extension: ThemeExtension
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .target("button")
+            .theme_extension(ThemeExtension::new())
+            .child(
+                Grid::create()
+                    .columns(Columns::create().column(320.0).column("*").build())
+                    .child(
+                        Stack::create()
+                            .margin(8.0)
+                            .spacing(8.0)
+                            .child(
+                                TextBox::create()
+                                    .selector(Selector::from("text-box").id("target_input"))
+                                    .water_mark("Selector, e.g. button.primary")
+                                    .text("button")
+                                    .on_activate(move |states, _| {
+                                        states
+                                            .get_mut::<MainViewState>(id)
+                                            .action(Action::SetTarget);
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                PropertyInspector::create()
+                                    .target(("target", id))
+                                    .theme_extension(("theme_extension", id))
+                                    .on_changed(move |states, _| {
+                                        states.get_mut::<MainViewState>(id).action(Action::Apply);
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(TextBlock::create().text("Background").build(ctx))
+                            .child(
+                                ColorEditor::create()
+                                    .selector(Selector::from("color-editor").id("color_editor"))
+                                    .brush("#647b91")
+                                    .on_changed(move |states, _| {
+                                        states
+                                            .get_mut::<MainViewState>(id)
+                                            .action(Action::SetBackground);
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::create()
+                                    .text("Export css")
+                                    .on_click(move |states, _| {
+                                        states.get_mut::<MainViewState>(id).action(Action::Export);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(
+                        Stack::create()
+                            .attach(Grid::column(1))
+                            .margin(8.0)
+                            .spacing(8.0)
+                            .child(Button::create().text("Button").build(ctx))
+                            .child(
+                                Button::create()
+                                    .selector(Selector::from("button").class("primary"))
+                                    .text("Primary button")
+                                    .build(ctx),
+                            )
+                            .child(ToggleButton::create().text("Toggle button").build(ctx))
+                            .child(CheckBox::create().text("Check box").build(ctx))
+                            .child(Switch::create().build(ctx))
+                            .child(TextBox::create().water_mark("Text box").build(ctx))
+                            .child(Slider::create().build(ctx))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - theme editor example")
+                .position((100.0, 100.0))
+                .size(720.0, 720.0)
+                .resizeable(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
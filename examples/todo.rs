@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use orbtk::prelude::*;
+
+#[derive(Debug, Copy, Clone)]
+enum Action {
+    Add,
+    Toggle(usize),
+    Remove(usize),
+}
+
+// Key and text of each todo. The key identifies the item of a todo if other todos are removed.
+type Todos = Vec<(usize, String)>;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    action: Option<Action>,
+    next_key: usize,
+    done: HashSet<usize>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: impl Into<Option<Action>>) {
+        self.action = action.into();
+    }
+
+    fn update_status(&self, ctx: &mut Context<'_>) {
+        let count = ctx.widget().get::<Todos>("todos").len();
+
+        ctx.widget().set("todo_count", count);
+        ctx.widget().set(
+            "status",
+            String16::from(format!("{} of {} done", self.done.len(), count)),
+        );
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(action) = self.action.take() {
+            match action {
+                Action::Add => {
+                    let text = ctx.widget().get::<String16>("new_todo").to_string();
+
+                    if text.trim().is_empty() {
+                        return;
+                    }
+
+                    ctx.widget()
+                        .get_mut::<Todos>("todos")
+                        .push((self.next_key, text.trim().to_string()));
+                    ctx.widget().set("new_todo", String16::from(""));
+                    self.next_key += 1;
+                }
+                Action::Toggle(key) => {
+                    if !self.done.remove(&key) {
+                        self.done.insert(key);
+                    }
+                }
+                Action::Remove(key) => {
+                    ctx.widget()
+                        .get_mut::<Todos>("todos")
+                        .retain(|(todo_key, _)| *todo_key != key);
+                    self.done.remove(&key);
+                }
+            }
+
+            self.update_status(ctx);
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    todos: Todos,
+    todo_count: usize,
+    new_todo: String16,
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .todos(vec![])
+            .todo_count(0)
+            .new_todo("")
+            .status("0 of 0 done")
+            .child(
+                Grid::create()
+                    .margin(8.0)
+                    .columns(
+                        Columns::create()
+                            .column("*")
+                            .column(8.0)
+                            .column("auto")
+                            .build(),
+                    )
+                    .rows(
+                        Rows::create()
+                            .row("auto")
+                            .row(8.0)
+                            .row("*")
+                            .row("auto")
+                            .build(),
+                    )
+                    .child(
+                        TextBox::create()
+                            .selector(Selector::from("text-box").id("new_todo"))
+                            .water_mark("What needs to be done?")
+                            .text(("new_todo", id))
+                            .on_activate(move |states, _| {
+                                state(id, states).action(Action::Add);
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::create()
+                            .selector(Selector::from("button").id("add"))
+                            .attach(Grid::column(2))
+                            .text("Add")
+                            .on_click(move |states, _| {
+                                state(id, states).action(Action::Add);
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        ItemsWidget::create()
+                            .attach(Grid::row(2))
+                            .attach(Grid::column_span(3))
+                            .items_builder(move |bc, index| {
+                                let (key, text) =
+                                    bc.get_widget(id).get::<Todos>("todos")[index].clone();
+
+                                Stack::create()
+                                    .orientation("horizontal")
+                                    .spacing(8.0)
+                                    .margin((0.0, 0.0, 0.0, 4.0))
+                                    .child(
+                                        CheckBox::create()
+                                            .text(text)
+                                            .on_changed(move |states, _| {
+                                                state(id, states).action(Action::Toggle(key));
+                                            })
+                                            .build(bc),
+                                    )
+                                    .child(
+                                        Button::create()
+                                            .text("Remove")
+                                            .on_click(move |states, _| {
+                                                state(id, states).action(Action::Remove(key));
+                                                true
+                                            })
+                                            .build(bc),
+                                    )
+                                    .build(bc)
+                            })
+                            // the items of the remaining todos keep their check boxes if a todo
+                            // is removed
+                            .items_key(move |bc, index| {
+                                bc.get_widget(id).get::<Todos>("todos")[index].0.to_string()
+                            })
+                            .count(("todo_count", id))
+                            .build(ctx),
+                    )
+                    .child(
+                        TextBlock::create()
+                            .attach(Grid::row(3))
+                            .attach(Grid::column_span(3))
+                            .text(("status", id))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - todo example")
+                .position((100.0, 100.0))
+                .size(360.0, 400.0)
+                .resizeable(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
+
+// helper to request MainViewState
+fn state<'a>(id: Entity, states: &'a mut StatesContext) -> &'a mut MainViewState {
+    states.get_mut(id)
+}
@@ -0,0 +1,65 @@
+use orbtk::prelude::*;
+
+fn tool_button(text: &str, icon: &str, ctx: &mut BuildContext) -> Entity {
+    Button::create()
+        .min_width(0.0)
+        .margin((0.0, 0.0, 2.0, 0.0))
+        .text(text)
+        .icon(icon)
+        .build(ctx)
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::create()
+                .child(
+                    ToolBar::create()
+                        .child(tool_button("New", material_font_icons::ADD_FONT_ICON, ctx))
+                        .child(tool_button(
+                            "Open",
+                            material_font_icons::LOAD_FONT_ICON,
+                            ctx,
+                        ))
+                        .child(tool_button(
+                            "Save",
+                            material_font_icons::FLOPPY_FONT_ICON,
+                            ctx,
+                        ))
+                        .child(tool_button("Remove", material_font_icons::REMOVE_ICON, ctx))
+                        .child(tool_button(
+                            "Check",
+                            material_font_icons::CHECK_FONT_ICON,
+                            ctx,
+                        ))
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::create()
+                        .margin(8.0)
+                        .text("Resize the window to move the buttons into the overflow popup.")
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - tool bar example")
+                .position((100.0, 100.0))
+                .size(480.0, 200.0)
+                .resizeable(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,68 @@
+use orbtk::prelude::*;
+
+static NAMES: [&str; 8] = [
+    "src",
+    "main.rs",
+    "lib.rs",
+    "widgets",
+    "button.rs",
+    "menu.rs",
+    "examples",
+    "README.md",
+];
+
+widget!(MainView { nodes: TreeNodes });
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut nodes = TreeNodes::new();
+        let src = nodes.push(None);
+        nodes.push(Some(src));
+        nodes.push(Some(src));
+        let widgets = nodes.push(Some(src));
+        nodes.push(Some(widgets));
+        nodes.push(Some(widgets));
+        let examples = nodes.push(None);
+        nodes.push(Some(examples));
+
+        self.name("MainView").nodes(nodes).child(
+            TreeView::create()
+                .margin(8.0)
+                .nodes(id)
+                .items_builder(|bc, node| {
+                    TextBlock::create()
+                        .margin((4.0, 0.0, 0.0, 0.0))
+                        .vertical_alignment("center")
+                        .text(NAMES[node])
+                        .build(bc)
+                })
+                // only directories (names without extension) could contain other nodes
+                .drop_validator(|nodes, _, target, position| {
+                    let parent = match position {
+                        DropPosition::Into => Some(target),
+                        _ => nodes.parent(target),
+                    };
+
+                    parent.map_or(true, |parent| !NAMES[parent].contains('.'))
+                })
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - tree view example")
+                .position((100.0, 100.0))
+                .size(320.0, 420.0)
+                .resizeable(true)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
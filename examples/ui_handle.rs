@@ -0,0 +1,62 @@
+use std::{thread, time::Duration};
+
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState;
+
+impl State for MainViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        let ui_handle = ctx.ui_handle();
+
+        // simulates a device that sends a new value every second
+        thread::spawn(move || {
+            let mut ticks = 0;
+
+            loop {
+                thread::sleep(Duration::from_secs(1));
+                ticks += 1;
+
+                let result = ui_handle.run(move |ctx| {
+                    ctx.widget()
+                        .set("ticks", String16::from(format!("Ticks: {}", ticks)));
+                });
+
+                if result.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+widget!(MainView<MainViewState> {
+    ticks: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").ticks("Ticks: 0").child(
+            TextBlock::create()
+                .margin(8.0)
+                .text(("ticks", id))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - ui handle example")
+                .position((100.0, 100.0))
+                .size(320.0, 120.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,31 @@
+use orbtk::prelude::*;
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            let mut loader = UiLoader::new();
+
+            // custom widget that could be used in the description.
+            loader.register_widget("Header", |ctx| {
+                TextBlock::create()
+                    .font_size(24.0)
+                    .margin((0.0, 0.0, 0.0, 8.0))
+                    .build(ctx)
+            });
+
+            let form = loader
+                .build_ron(include_str!("../res/ui/form.ron"), ctx)
+                .unwrap();
+
+            Window::create()
+                .title("OrbTk - ui loader example")
+                .position((100.0, 100.0))
+                .size(420.0, 240.0)
+                .child(form)
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,150 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    submit: bool,
+}
+
+impl MainViewState {
+    fn submit(&mut self) {
+        self.submit = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if !self.submit {
+            return;
+        }
+
+        self.submit = false;
+
+        let form = ctx.entity_of_child("form").unwrap();
+        let invalid = ctx.invalid_widgets(form).len();
+        let status = if invalid == 0 {
+            String16::from("Saved")
+        } else {
+            String16::from(format!("Please correct {} field(s)", invalid))
+        };
+
+        ctx.widget().set("status", status);
+    }
+}
+
+widget!(MainView<MainViewState> {
+    status: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let name = TextBox::create()
+            .selector(Selector::from("text-box").id("name"))
+            .water_mark("Name")
+            .validator(|text| {
+                if text.trim().is_empty() {
+                    return Err("Enter a name".to_string());
+                }
+
+                Ok(())
+            })
+            .build(ctx);
+
+        let email = TextBox::create()
+            .selector(Selector::from("text-box").id("email"))
+            .water_mark("E-Mail")
+            .validator(|text| {
+                if !text.contains('@') {
+                    return Err("Enter a valid e-mail address".to_string());
+                }
+
+                Ok(())
+            })
+            .build(ctx);
+
+        // only digits, spaces and a plus sign could be typed
+        let phone = TextBox::create()
+            .selector(Selector::from("text-box").id("phone"))
+            .water_mark("Phone")
+            .input_filter(
+                InputFilter::new()
+                    .chars(|c| c.is_ascii_digit() || c == ' ' || c == '+')
+                    .max_length(16),
+            )
+            .build(ctx);
+
+        let age = NumericBox::create()
+            .selector(Selector::from("numeric-box").id("age"))
+            .min(0.0)
+            .max(150.0)
+            .validator(|value| {
+                if value < 18.0 {
+                    return Err("You must be at least 18 years old".to_string());
+                }
+
+                Ok(())
+            })
+            .build(ctx);
+
+        self.name("MainView").status("").child(
+            Stack::create()
+                .margin(8.0)
+                .spacing(4.0)
+                .child(
+                    Stack::create()
+                        .selector(Selector::from("stack").id("form"))
+                        .spacing(4.0)
+                        .child(name)
+                        .child(
+                            TextBlock::create()
+                                .selector("validation-error")
+                                .text(("error_message", name))
+                                .build(ctx),
+                        )
+                        .child(email)
+                        .child(
+                            TextBlock::create()
+                                .selector("validation-error")
+                                .text(("error_message", email))
+                                .build(ctx),
+                        )
+                        .child(phone)
+                        .child(age)
+                        .child(
+                            TextBlock::create()
+                                .selector("validation-error")
+                                .text(("error_message", age))
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    Button::create()
+                        .selector(Selector::from("button").id("submit"))
+                        .text("Submit")
+                        .on_click(move |states, _| {
+                            states.get_mut::<MainViewState>(id).submit();
+                            true
+                        })
+                        .build(ctx),
+                )
+                .child(TextBlock::create().text(("status", id)).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - validation example")
+                .position((100.0, 100.0))
+                .size(320.0, 320.0)
+                .child(MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
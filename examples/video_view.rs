@@ -0,0 +1,54 @@
+use std::{thread, time::Duration};
+
+use orbtk::prelude::*;
+
+const WIDTH: u32 = 160;
+const HEIGHT: u32 = 90;
+
+// Pushes generated RGBA frames like a camera or a video decoder.
+fn start_camera(stream: FrameStream) {
+    thread::spawn(move || {
+        let mut frame = vec![0; (WIDTH * HEIGHT * 4) as usize];
+
+        for offset in (0..WIDTH).cycle() {
+            for (i, pixel) in frame.chunks_exact_mut(4).enumerate() {
+                let x = (i as u32 % WIDTH + offset) % WIDTH;
+                let y = i as u32 / WIDTH;
+                pixel.copy_from_slice(&[
+                    (x * 255 / WIDTH) as u8,
+                    (y * 255 / HEIGHT) as u8,
+                    128,
+                    255,
+                ]);
+            }
+
+            if stream.push_rgba(WIDTH, HEIGHT, &frame).is_err() {
+                return;
+            }
+
+            thread::sleep(Duration::from_millis(33));
+        }
+    });
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    let stream = FrameStream::new();
+    start_camera(stream.clone());
+
+    Application::new()
+        .window(move |ctx| {
+            Window::create()
+                .title("OrbTk - video view example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                // the frames of the stream wake the window
+                .render_mode("on-demand")
+                .child(VideoView::create().stream(stream.clone()).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
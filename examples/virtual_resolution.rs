@@ -0,0 +1,40 @@
+use orbtk::prelude::*;
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::create()
+                .title("OrbTk - virtual resolution example")
+                .position((100.0, 100.0))
+                .size(640.0, 360.0)
+                .resizeable(true)
+                // the content is always laid out at 320 x 180 and scaled to the window
+                .virtual_resolution((320.0, 180.0))
+                .child(
+                    Grid::create()
+                        .rows(Rows::create().row("*").row("auto").build())
+                        .child(
+                            TextBlock::create()
+                                .text("Resize the window")
+                                .selector("h1")
+                                .vertical_alignment("center")
+                                .horizontal_alignment("center")
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::create()
+                                .text("HUD button")
+                                .margin(8.0)
+                                .horizontal_alignment("end")
+                                .attach(Grid::row(1))
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
@@ -337,6 +337,10 @@ impl Template for MainView {
                                             .text(text)
                                             .build(bc)
                                     })
+                                    // existing buttons are kept if an item is added or removed
+                                    .items_key(move |bc, index| {
+                                        bc.get_widget(id).get::<Vec<String>>("list")[index].clone()
+                                    })
                                     .count(("list_count", id))
                                     .build(ctx),
                             )
@@ -0,0 +1,126 @@
+use orbtk::prelude::*;
+
+#[derive(Debug, Clone)]
+enum Action {
+    Edit,
+    CloseRequested,
+    Discard,
+    WindowEvent(WindowEvent),
+}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    action: Option<Action>,
+    unsaved: bool,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: impl Into<Option<Action>>) {
+        self.action = action.into();
+    }
+
+    // Vetoes the close request if there is unsaved work.
+    fn close_requested(&mut self) -> bool {
+        if self.unsaved {
+            self.action(Action::CloseRequested);
+        }
+
+        !self.unsaved
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context<'_>) {
+        if let Some(action) = self.action.take() {
+            match action {
+                Action::Edit => {
+                    self.unsaved = true;
+                    ctx.widget()
+                        .set("status", String16::from("Unsaved changes"));
+                }
+                // the close button of the window has no effect after the veto, the window is
+                // closed with the discard button
+                Action::CloseRequested => {
+                    ctx.widget().set(
+                        "status",
+                        String16::from("Unsaved changes, discard them and close?"),
+                    );
+                    ctx.child("discard").set("visibility", Visibility::Visible);
+                }
+                Action::Discard => ctx.close_window(),
+                Action::WindowEvent(event) => {
+                    ctx.widget()
+                        .set("window_status", String16::from(format!("{:?}", event)));
+                }
+            }
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    status: String16,
+    window_status: String16
+});
+
+impl MainView {
+    fn state_action(id: Entity, action: Action) -> impl Fn(&mut StatesContext, Point) -> bool {
+        move |states, _| {
+            states.get_mut::<MainViewState>(id).action(action.clone());
+            true
+        }
+    }
+}
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").status("No changes").child(
+            Stack::create()
+                .margin(8.0)
+                .spacing(8.0)
+                .child(TextBlock::create().text(("status", id)).build(ctx))
+                .child(TextBlock::create().text(("window_status", id)).build(ctx))
+                .child(
+                    Button::create()
+                        .text("Edit")
+                        .on_click(MainView::state_action(id, Action::Edit))
+                        .build(ctx),
+                )
+                .child(
+                    Button::create()
+                        .selector(Selector::from("button").id("discard"))
+                        .text("Discard and close")
+                        .visibility(Visibility::Collapsed)
+                        .on_click(MainView::state_action(id, Action::Discard))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            let main_view = MainView::create().build(ctx);
+
+            Window::create()
+                .title("OrbTk - window events example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .resizeable(true)
+                .on_close_requested(move |states| {
+                    states.get_mut::<MainViewState>(main_view).close_requested()
+                })
+                .on_window_event(move |states, event| {
+                    states
+                        .get_mut::<MainViewState>(main_view)
+                        .action(Action::WindowEvent(event));
+                })
+                .child(main_view)
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,6 @@
+# German translation of the localization example
+title = Lokalisierung
+greeting = Hallo, willkommen bei OrbTk!
+english_button = Englisch
+german_button = Deutsch
+save_button = Speichern
@@ -10,7 +10,7 @@ pub use dces::prelude::*;
 
 pub use crate::{
     api::*,
-    css_engine::{Selector, SelectorRelation, Theme as ThemeValue, ThemeBuilder},
+    css_engine::{Selector, SelectorRelation, Theme as ThemeValue, ThemeBuilder, ThemeLayer},
     proc_macros::*,
     render,
     shell::Key,
@@ -0,0 +1,67 @@
+//! Helpers of the integration tests that run widget trees headless.
+#![allow(dead_code)]
+
+use orbtk::prelude::*;
+
+// Runs the given view in a headless window and lays it out.
+pub fn run<F: Fn(&mut BuildContext) -> Entity + 'static>(
+    width: f64,
+    height: f64,
+    view: F,
+) -> ManualRunner {
+    let mut runner = Application::new()
+        .window(move |ctx| {
+            Window::create()
+                .size(width, height)
+                .child(view(ctx))
+                .build(ctx)
+        })
+        .run_manual();
+
+    runner.update();
+    runner.update();
+    runner
+}
+
+// Returns the entity of the descendant of the given widget that is reached by the child indices.
+pub fn descendant(runner: &mut ManualRunner, entity: Entity, path: &[usize]) -> Entity {
+    path.iter().fold(entity, |entity, index| {
+        let index = *index;
+        runner
+            .query(entity, move |ctx| ctx.child_from_index(index).entity())
+            .unwrap()
+    })
+}
+
+// Returns the entity of the child of the given widget with the given css id.
+pub fn child_by_id(runner: &mut ManualRunner, entity: Entity, id: &'static str) -> Entity {
+    runner
+        .query(entity, move |ctx| ctx.entity_of_child(id))
+        .unwrap()
+        .unwrap()
+}
+
+// Clicks in the center of the given widget.
+pub fn click(runner: &mut ManualRunner, entity: Entity) {
+    let (x, y) = runner
+        .query(entity, |ctx| {
+            let position = ctx.widget().clone::<Point>("position");
+            let bounds = ctx.widget().clone::<Rectangle>("bounds");
+            (
+                position.x + bounds.width() / 2.0,
+                position.y + bounds.height() / 2.0,
+            )
+        })
+        .unwrap();
+
+    runner.click(x, y);
+    runner.update();
+}
+
+pub fn text(runner: &mut ManualRunner, entity: Entity) -> String {
+    runner
+        .query(entity, |ctx| {
+            ctx.widget().clone::<String16>("text").to_string()
+        })
+        .unwrap()
+}
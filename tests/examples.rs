@@ -0,0 +1,438 @@
+//! Runs complete example applications headless with scripted input and checks their final state.
+//! The tests guard the interplay of the modules, e.g. of focus, layout and the rebuild of items.
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{collections::HashSet, env, fs, thread, time::Duration};
+
+use orbtk::prelude::*;
+
+use common::*;
+
+mod common;
+
+#[path = "../examples/calculator.rs"]
+#[allow(dead_code)]
+mod calculator;
+
+#[path = "../examples/file_browser.rs"]
+#[allow(dead_code)]
+mod file_browser;
+
+#[path = "../examples/master_detail.rs"]
+#[allow(dead_code)]
+mod master_detail;
+
+#[path = "../examples/responsive.rs"]
+#[allow(dead_code)]
+mod responsive;
+
+#[path = "../examples/todo.rs"]
+#[allow(dead_code)]
+mod todo;
+
+#[path = "../examples/validation.rs"]
+#[allow(dead_code)]
+mod validation;
+
+#[test]
+fn test_calculator() {
+    let mut runner = run(212.0, 336.0, |ctx| {
+        calculator::MainView::create().build(ctx)
+    });
+    let root = runner.root();
+    let main_view = descendant(&mut runner, root, &[0]);
+    let buttons = descendant(&mut runner, main_view, &[0, 1, 0]);
+
+    // 12 + 3 = 15
+    for index in &[10, 11, 13, 12, 16] {
+        let button = descendant(&mut runner, buttons, &[*index]);
+        click(&mut runner, button);
+    }
+
+    assert_eq!(text(&mut runner, main_view), "15.000000000");
+
+    let input = child_by_id(&mut runner, main_view, "input");
+    assert_eq!(text(&mut runner, input), "");
+
+    // C
+    let button = descendant(&mut runner, buttons, &[0]);
+    click(&mut runner, button);
+    assert_eq!(text(&mut runner, main_view), "");
+}
+
+#[test]
+fn test_calculator_driver() {
+    let mut driver = Application::new()
+        .window(|ctx| {
+            Window::create()
+                .size(212.0, 336.0)
+                .child(calculator::MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run_driver();
+
+    // 7 * 6 = 42
+    for id in &["button-7", "button-*", "button-6", "button-="] {
+        driver.click(id).unwrap();
+    }
+
+    assert_eq!(driver.text("result").unwrap(), "42.000000000");
+    assert!(driver.find("button-x").is_err());
+}
+
+#[test]
+fn test_calculator_bounds_of() {
+    let mut driver = Application::new()
+        .window(|ctx| {
+            Window::create()
+                .size(212.0, 336.0)
+                .child(calculator::MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run_driver();
+    let root = driver.runner().root();
+    let seven = driver.find("button-7").unwrap();
+    let eight = driver.find("button-8").unwrap();
+
+    let (seven, eight) = driver
+        .runner()
+        .query(root, move |ctx| {
+            (ctx.bounds_of(seven).unwrap(), ctx.bounds_of(eight).unwrap())
+        })
+        .unwrap();
+
+    // the buttons are in the same row of the keypad, 8 is right of 7
+    assert!(seven.width() > 0.0 && seven.height() > 0.0);
+    assert_eq!(seven.y(), eight.y());
+    assert!(eight.x() >= seven.x() + seven.width());
+    assert!(eight.x() + eight.width() <= 212.0 && eight.y() + eight.height() <= 336.0);
+}
+
+#[test]
+fn test_calculator_hit_test() {
+    let mut driver = Application::new()
+        .window(|ctx| {
+            Window::create()
+                .size(212.0, 336.0)
+                .child(calculator::MainView::create().build(ctx))
+                .build(ctx)
+        })
+        .run_driver();
+    let root = driver.runner().root();
+    let seven = driver.find("button-7").unwrap();
+
+    let (hits, outside) = driver
+        .runner()
+        .query(root, move |ctx| {
+            let bounds = ctx.bounds_of(seven).unwrap();
+            (
+                ctx.hit_test((
+                    bounds.x() + bounds.width() / 2.0,
+                    bounds.y() + bounds.height() / 2.0,
+                )),
+                ctx.hit_test((-1.0, -1.0)),
+            )
+        })
+        .unwrap();
+
+    // the parts of the button are on top of it, the window is the bottom most widget
+    assert!(hits.contains(&seven));
+    assert_eq!(hits.last(), Some(&root));
+    assert!(hits.iter().position(|e| *e == seven) < hits.iter().position(|e| *e == root));
+    assert!(outside.is_empty());
+}
+
+#[test]
+fn test_calculator_replay() {
+    let calculator = |ctx: &mut BuildContext| {
+        Window::create()
+            .size(212.0, 336.0)
+            .child(calculator::MainView::create().build(ctx))
+            .build(ctx)
+    };
+    let path = env::temp_dir().join(format!("orbtk-replay-test-{}.ron", std::process::id()));
+
+    // 9 - 4 = 5
+    let mut driver = Application::new()
+        .record_input(InputRecorder::create(&path).unwrap())
+        .window(calculator)
+        .run_driver();
+
+    for id in &["button-9", "button--", "button-4", "button-="] {
+        driver.click(id).unwrap();
+    }
+
+    assert_eq!(driver.text("result").unwrap(), "5.000000000");
+
+    let mut replay = InputReplay::load(&path).unwrap();
+    let _ = fs::remove_file(&path);
+    assert!(!replay.is_empty());
+
+    let mut driver = Application::new().window(calculator).run_driver();
+    assert!(replay.run(driver.runner()));
+    driver.update();
+
+    assert_eq!(driver.text("result").unwrap(), "5.000000000");
+}
+
+#[test]
+fn test_master_detail() {
+    let mut runner = run(480.0, 300.0, |ctx| {
+        master_detail::MainView::create().build(ctx)
+    });
+    let root = runner.root();
+    let main_view = descendant(&mut runner, root, &[0]);
+    let items_panel = child_by_id(&mut runner, main_view, "items_panel");
+    let detail_view = descendant(&mut runner, main_view, &[0, 1]);
+
+    let ada = descendant(&mut runner, items_panel, &[0]);
+    click(&mut runner, ada);
+
+    // focuses the notes of the first contact and types a note
+    let notes = descendant(&mut runner, detail_view, &[0, 3]);
+    click(&mut runner, notes);
+    runner.text_input("likes tea");
+    runner.update();
+
+    // the details of the first contact are kept while the second is selected
+    let grace = descendant(&mut runner, items_panel, &[1]);
+    click(&mut runner, grace);
+
+    let selected = runner
+        .query(main_view, |ctx| {
+            ctx.widget().clone::<SelectedIndices>("selected_indices").0
+        })
+        .unwrap();
+    assert_eq!(selected, [1].iter().copied().collect::<HashSet<usize>>());
+
+    let visibilities = runner
+        .query(detail_view, |ctx| {
+            (0..2)
+                .map(|index| *ctx.child_from_index(index).get::<Visibility>("visibility"))
+                .collect::<Vec<Visibility>>()
+        })
+        .unwrap();
+    assert_eq!(
+        visibilities,
+        vec![Visibility::Collapsed, Visibility::Visible]
+    );
+
+    click(&mut runner, ada);
+    assert_eq!(text(&mut runner, notes), "likes tea");
+    assert_eq!(
+        runner
+            .query(notes, |ctx| ctx.parent().clone::<Visibility>("visibility"))
+            .unwrap(),
+        Visibility::Visible
+    );
+}
+
+#[test]
+fn test_file_browser() {
+    let dir = env::temp_dir().join(format!("orbtk-examples-test-{}", std::process::id()));
+    fs::create_dir_all(dir.join("docs")).unwrap();
+    fs::write(dir.join("readme.txt"), "").unwrap();
+
+    let mut runner = run(640.0, 480.0, |ctx| {
+        file_browser::MainView::create().build(ctx)
+    });
+    let root = runner.root();
+    let main_view = descendant(&mut runner, root, &[0]);
+    let file_browser = descendant(&mut runner, main_view, &[0, 0]);
+
+    let path = dir.to_string_lossy().to_string();
+    runner
+        .query(file_browser, move |ctx| ctx.widget().set("path", path))
+        .unwrap();
+
+    // the directory is read in the background
+    let mut count = 0;
+    for _ in 0..100 {
+        count = runner
+            .query(file_browser, |ctx| {
+                *ctx.widget().get::<usize>("entries_count")
+            })
+            .unwrap();
+
+        if count > 0 {
+            break;
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(count, 2);
+    runner.update();
+
+    // directories are listed first
+    let file_list = child_by_id(&mut runner, file_browser, "file_list");
+    let items_panel = child_by_id(&mut runner, file_list, "items_panel");
+    let readme = descendant(&mut runner, items_panel, &[1]);
+    click(&mut runner, readme);
+    runner.update();
+
+    let status = runner
+        .query(main_view, |ctx| {
+            ctx.widget().clone::<String16>("status").to_string()
+        })
+        .unwrap();
+    assert_eq!(status, dir.join("readme.txt").to_string_lossy());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_responsive() {
+    let mut runner = run(480.0, 300.0, |ctx| {
+        responsive::MainView::create().build(ctx)
+    });
+    let root = runner.root();
+    let main_view = descendant(&mut runner, root, &[0]);
+    let wide_header = child_by_id(&mut runner, main_view, "wide_header");
+    let narrow_header = child_by_id(&mut runner, main_view, "narrow_header");
+    let tool_bar = child_by_id(&mut runner, main_view, "tool_bar");
+    let layout = |runner: &mut ManualRunner| {
+        let visibility = |runner: &mut ManualRunner, entity| {
+            runner
+                .query(entity, |ctx| ctx.widget().clone::<Visibility>("visibility"))
+                .unwrap()
+        };
+
+        (
+            visibility(runner, wide_header),
+            visibility(runner, narrow_header),
+            runner
+                .query(tool_bar, |ctx| {
+                    ctx.widget().clone::<Orientation>("orientation")
+                })
+                .unwrap(),
+        )
+    };
+
+    assert_eq!(
+        layout(&mut runner),
+        (
+            Visibility::Visible,
+            Visibility::Collapsed,
+            Orientation::Horizontal
+        )
+    );
+
+    // the window crosses the breakpoint
+    runner.tick(vec![orbtk::shell::ShellEvent::Resize(300.0, 300.0)]);
+    assert_eq!(
+        layout(&mut runner),
+        (
+            Visibility::Collapsed,
+            Visibility::Visible,
+            Orientation::Vertical
+        )
+    );
+}
+
+#[test]
+fn test_todo() {
+    let mut runner = run(360.0, 400.0, |ctx| todo::MainView::create().build(ctx));
+    let root = runner.root();
+    let main_view = descendant(&mut runner, root, &[0]);
+    let new_todo = child_by_id(&mut runner, main_view, "new_todo");
+    let add = child_by_id(&mut runner, main_view, "add");
+    let items_panel = child_by_id(&mut runner, main_view, "items_panel");
+    let status = |runner: &mut ManualRunner| {
+        runner
+            .query(main_view, |ctx| {
+                ctx.widget().clone::<String16>("status").to_string()
+            })
+            .unwrap()
+    };
+
+    for todo in &["Buy milk", "Write tests", "Ship"] {
+        click(&mut runner, new_todo);
+        runner.text_input(todo);
+        runner.update();
+        click(&mut runner, add);
+    }
+
+    assert_eq!(status(&mut runner), "0 of 3 done");
+    assert_eq!(text(&mut runner, new_todo), "");
+
+    let write_tests = descendant(&mut runner, items_panel, &[1]);
+    let check_box = descendant(&mut runner, write_tests, &[0]);
+    click(&mut runner, check_box);
+    assert_eq!(status(&mut runner), "1 of 3 done");
+
+    // removing the first todo keeps the item of the second with its checked box
+    let remove = descendant(&mut runner, items_panel, &[0, 1]);
+    click(&mut runner, remove);
+    assert_eq!(status(&mut runner), "1 of 2 done");
+    assert_eq!(descendant(&mut runner, items_panel, &[0]), write_tests);
+    assert!(runner
+        .query(check_box, |ctx| *ctx.widget().get::<bool>("selected"))
+        .unwrap());
+
+    let ship = descendant(&mut runner, items_panel, &[1, 0]);
+    assert_eq!(text(&mut runner, ship), "Ship");
+}
+
+#[test]
+fn test_validation() {
+    let mut runner = run(320.0, 320.0, |ctx| {
+        validation::MainView::create().build(ctx)
+    });
+    let root = runner.root();
+    let main_view = descendant(&mut runner, root, &[0]);
+    let name = child_by_id(&mut runner, main_view, "name");
+    let email = child_by_id(&mut runner, main_view, "email");
+    let phone = child_by_id(&mut runner, main_view, "phone");
+    let age = child_by_id(&mut runner, main_view, "age");
+    let submit = child_by_id(&mut runner, main_view, "submit");
+    let status = |runner: &mut ManualRunner| {
+        runner
+            .query(main_view, |ctx| {
+                ctx.widget().clone::<String16>("status").to_string()
+            })
+            .unwrap()
+    };
+
+    click(&mut runner, submit);
+    assert_eq!(status(&mut runner), "Please correct 3 field(s)");
+
+    click(&mut runner, name);
+    runner.text_input("Ada");
+    runner.update();
+
+    let (valid, message) = runner
+        .query(name, |ctx| {
+            (
+                *ctx.widget().get::<bool>("is_valid"),
+                ctx.widget().clone::<String16>("error_message").to_string(),
+            )
+        })
+        .unwrap();
+    assert!(valid);
+    assert_eq!(message, "");
+
+    click(&mut runner, email);
+    runner.text_input("ada");
+    runner.update();
+
+    let selector = runner
+        .query(email, |ctx| ctx.widget().clone::<Selector>("selector"))
+        .unwrap();
+    assert!(selector.has_pseudo_class("invalid"));
+
+    runner.text_input("@example.com");
+    runner.update();
+
+    // the letters never enter the text of the phone number
+    click(&mut runner, phone);
+    runner.text_input("+49 abc 123");
+    runner.update();
+    assert_eq!(text(&mut runner, phone), "+49  123");
+
+    runner
+        .query(age, |ctx| ctx.widget().set("value", 36.0))
+        .unwrap();
+
+    click(&mut runner, submit);
+    assert_eq!(status(&mut runner), "Saved");
+}
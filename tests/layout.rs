@@ -0,0 +1,247 @@
+//! Runs widget trees headless and checks their layout, hit testing and lifecycle hooks.
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{cell::RefCell, rc::Rc};
+
+use orbtk::prelude::*;
+
+use common::*;
+
+mod common;
+
+#[test]
+fn test_z_index_hit_test() {
+    let mut driver = Application::new()
+        .window(|ctx| {
+            Window::create()
+                .size(100.0, 100.0)
+                .child(
+                    Grid::create()
+                        .child(
+                            Container::create()
+                                .automation_id("front")
+                                .z_index(1)
+                                .build(ctx),
+                        )
+                        .child(Container::create().automation_id("back").build(ctx))
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run_driver();
+    let root = driver.runner().root();
+    let front = driver.find("front").unwrap();
+    let back = driver.find("back").unwrap();
+
+    let hits = driver
+        .runner()
+        .query(root, |ctx| ctx.hit_test((50.0, 50.0)))
+        .unwrap();
+
+    // the first child is rendered on top of its sibling because of its z index
+    let position = |entity| hits.iter().position(|hit| *hit == entity).unwrap();
+    assert!(position(front) < position(back));
+}
+
+#[test]
+fn test_preferred_size() {
+    // at least 100 but prefer 200
+    let width_in = |window_width: f64| {
+        let mut runner = run(window_width, 100.0, |ctx| {
+            Container::create()
+                .horizontal_alignment("start")
+                .min_width(100.0)
+                .preferred_width(200.0)
+                .height(20.0)
+                .build(ctx)
+        });
+        let root = runner.root();
+        let container = descendant(&mut runner, root, &[0]);
+
+        runner
+            .query(container, |ctx| ctx.widget().clone::<Rectangle>("bounds"))
+            .unwrap()
+            .width()
+    };
+
+    assert_eq!(width_in(400.0), 200.0);
+    assert_eq!(width_in(150.0), 150.0);
+    assert_eq!(width_in(50.0), 100.0);
+}
+
+#[test]
+fn test_size_changed() {
+    let sizes = Rc::new(RefCell::new(vec![]));
+    let handler_sizes = sizes.clone();
+    let mut runner = run(100.0, 50.0, move |ctx| {
+        let handler_sizes = handler_sizes.clone();
+
+        Container::create()
+            .on_size_changed(move |_, _, old_size, new_size| {
+                handler_sizes.borrow_mut().push((old_size, new_size));
+            })
+            .build(ctx)
+    });
+
+    // the size of the first layout is reported in the next frame
+    assert_eq!(*sizes.borrow(), vec![((0.0, 0.0), (100.0, 50.0))]);
+
+    runner.tick(vec![orbtk::shell::ShellEvent::Resize(80.0, 50.0)]);
+    runner.update();
+    assert_eq!(sizes.borrow().last(), Some(&((100.0, 50.0), (80.0, 50.0))));
+
+    // an unchanged size is not reported again
+    let count = sizes.borrow().len();
+    runner.update();
+    assert_eq!(sizes.borrow().len(), count);
+}
+
+thread_local! {
+    static LIFECYCLE: RefCell<Vec<(&'static str, String)>> = RefCell::new(vec![]);
+}
+
+// Logs when it is attached to or detached from the tree.
+#[derive(Default, AsAny)]
+struct LifecycleProbeState;
+
+impl LifecycleProbeState {
+    fn log(&self, hook: &'static str, ctx: &mut Context) {
+        let text = ctx.widget().clone::<String16>("text").to_string();
+        LIFECYCLE.with(|log| log.borrow_mut().push((hook, text)));
+    }
+}
+
+impl State for LifecycleProbeState {
+    fn attached(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.log("attached", ctx);
+    }
+
+    fn detached(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.log("detached", ctx);
+    }
+}
+
+widget!(LifecycleProbe<LifecycleProbeState> {
+    text: String16
+});
+
+impl Template for LifecycleProbe {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("LifecycleProbe")
+    }
+}
+
+#[test]
+fn test_lifecycle_hooks() {
+    let mut runner = run(100.0, 200.0, |ctx| {
+        ItemsWidget::create()
+            .items_builder(|bc, index| LifecycleProbe::create().text(index.to_string()).build(bc))
+            .count(3_usize)
+            .build(ctx)
+    });
+    let hooks = |hook: &'static str| {
+        LIFECYCLE.with(|log| {
+            log.borrow()
+                .iter()
+                .filter(|(h, _)| *h == hook)
+                .map(|(_, text)| text.clone())
+                .collect::<Vec<String>>()
+        })
+    };
+    assert_eq!(hooks("attached"), vec!["0", "1", "2"]);
+    assert!(hooks("detached").is_empty());
+
+    // the old items are detached after the new item is created
+    let root = runner.root();
+    let items_widget = descendant(&mut runner, root, &[0]);
+    runner
+        .query(items_widget, |ctx| ctx.widget().set("count", 1_usize))
+        .unwrap();
+    runner.update();
+
+    assert_eq!(hooks("attached"), vec!["0", "1", "2", "0"]);
+    assert_eq!(hooks("detached"), vec!["0", "1", "2"]);
+
+    let items_panel = child_by_id(&mut runner, items_widget, "items_panel");
+    assert!(runner
+        .query(items_panel, |ctx| ctx.try_child_from_index(1).is_none())
+        .unwrap());
+}
+
+#[test]
+fn test_grid_auto_flow() {
+    let mut runner = run(200.0, 200.0, |ctx| {
+        let cell = |ctx: &mut BuildContext| Container::create().height(20.0).build(ctx);
+
+        Grid::create()
+            .columns(Columns::create().column("*").column("*").build())
+            .auto_flow("row")
+            .child(cell(ctx))
+            .child(cell(ctx))
+            .child(cell(ctx))
+            .build(ctx)
+    });
+    let root = runner.root();
+    let grid = descendant(&mut runner, root, &[0]);
+
+    let mut position = |index: usize| {
+        let child = descendant(&mut runner, grid, &[index]);
+        let bounds = runner
+            .query(child, |ctx| ctx.widget().clone::<Rectangle>("bounds"))
+            .unwrap();
+        (bounds.x(), bounds.y())
+    };
+
+    // the third child wraps to an auto row below the first
+    assert_eq!(position(0), (0.0, 0.0));
+    assert_eq!(position(1), (100.0, 0.0));
+    assert_eq!(position(2), (0.0, 20.0));
+
+    let rows = runner
+        .query(grid, |ctx| ctx.widget().clone::<Rows>("rows"))
+        .unwrap();
+    assert_eq!(rows.len(), 2);
+}
+
+#[test]
+fn test_negative_margin() {
+    let mut runner = run(200.0, 200.0, |ctx| {
+        Stack::create()
+            .vertical_alignment("start")
+            .child(Container::create().height(20.0).build(ctx))
+            .child(
+                Container::create()
+                    .height(20.0)
+                    .margin((0.0, -5.0, 0.0, 0.0))
+                    .build(ctx),
+            )
+            .child(
+                Container::create()
+                    .child(
+                        Container::create()
+                            .horizontal_alignment("start")
+                            .width(50.0)
+                            .height(10.0)
+                            .margin((-10.0, 0.0, 0.0, 0.0))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+    let root = runner.root();
+    let bounds = |runner: &mut ManualRunner, path: &[usize]| {
+        let entity = descendant(runner, root, path);
+        runner
+            .query(entity, |ctx| ctx.widget().clone::<Rectangle>("bounds"))
+            .unwrap()
+    };
+
+    // the second child overlaps the first, the stack shrinks by the margin
+    assert_eq!(bounds(&mut runner, &[0, 1]).y(), 15.0);
+    assert_eq!(bounds(&mut runner, &[0, 2]).y(), 35.0);
+    assert_eq!(bounds(&mut runner, &[0]).height(), 45.0);
+
+    // the child overflows its parent by its own margin
+    assert_eq!(bounds(&mut runner, &[0, 2, 0]).x(), -10.0);
+}
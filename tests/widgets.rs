@@ -0,0 +1,770 @@
+//! Runs single widgets headless with scripted input and checks their state and events.
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+    env, fs,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use orbtk::prelude::*;
+
+use common::*;
+
+mod common;
+
+#[test]
+fn test_badge() {
+    let mut runner = run(200.0, 100.0, |ctx| {
+        Badge::create()
+            .count(120)
+            .child(Button::create().text("Inbox").build(ctx))
+            .build(ctx)
+    });
+    let root = runner.root();
+    let badge = descendant(&mut runner, root, &[0]);
+    let bubble = descendant(&mut runner, badge, &[1]);
+    let label = descendant(&mut runner, bubble, &[0]);
+    let visibility = |runner: &mut ManualRunner| {
+        runner
+            .query(bubble, |ctx| ctx.widget().clone::<Visibility>("visibility"))
+            .unwrap()
+    };
+
+    // the bubble is drawn on top of the child and counts above the max count are shortened
+    assert_eq!(text(&mut runner, label), "99+");
+    assert_eq!(visibility(&mut runner), Visibility::Visible);
+
+    // a count of zero hides the bubble
+    runner
+        .query(badge, |ctx| ctx.widget().set("count", 0_usize))
+        .unwrap();
+    runner.update();
+    assert_eq!(visibility(&mut runner), Visibility::Collapsed);
+}
+
+#[test]
+fn test_toast() {
+    let mut runner = Application::new()
+        .window(|ctx| {
+            Window::create()
+                .size(300.0, 200.0)
+                .child(TextBlock::create().text("content").build(ctx))
+                .build(ctx)
+        })
+        .run_stepped();
+    runner.update();
+
+    let root = runner.root();
+    runner
+        .query(root, |ctx| {
+            ctx.show_toast("Saved", Duration::from_secs(2));
+            ctx.show_toast("Synchronized", Duration::from_secs(4));
+        })
+        .unwrap();
+    runner.update();
+    runner.update();
+
+    // the toasts are stacked in the host in the overlay of the window
+    let overlay = descendant(&mut runner, root, &[1]);
+    let host = descendant(&mut runner, overlay, &[0]);
+    let toast_text = |runner: &mut ManualRunner, index: usize| {
+        let label = descendant(runner, host, &[index, 0]);
+        text(runner, label)
+    };
+    let count = |runner: &mut ManualRunner| {
+        runner
+            .query(host, |ctx| {
+                (0..)
+                    .take_while(|index| ctx.try_child_from_index(*index).is_some())
+                    .count()
+            })
+            .unwrap()
+    };
+    assert_eq!(toast_text(&mut runner, 0), "Saved");
+    assert_eq!(toast_text(&mut runner, 1), "Synchronized");
+
+    // each toast is removed after its duration
+    runner.advance(Duration::from_secs(3));
+    runner.update();
+    assert_eq!(count(&mut runner), 1);
+    assert_eq!(toast_text(&mut runner, 0), "Synchronized");
+
+    runner.advance(Duration::from_secs(1));
+    runner.update();
+    assert_eq!(count(&mut runner), 0);
+}
+
+#[test]
+fn test_items_widget_keys() {
+    let items = Rc::new(RefCell::new(vec![
+        String::from("a"),
+        String::from("b"),
+        String::from("c"),
+    ]));
+    let view_items = items.clone();
+    let mut runner = run(100.0, 200.0, move |ctx| {
+        let builder_items = view_items.clone();
+        let key_items = view_items.clone();
+
+        ItemsWidget::create()
+            .items_builder(move |bc, index| {
+                TextBlock::create()
+                    .text(builder_items.borrow()[index].as_str())
+                    .build(bc)
+            })
+            .items_key(move |_, index| key_items.borrow()[index].clone())
+            .count(3_usize)
+            .build(ctx)
+    });
+    let root = runner.root();
+    let items_widget = descendant(&mut runner, root, &[0]);
+    let items_panel = child_by_id(&mut runner, items_widget, "items_panel");
+    let children = |runner: &mut ManualRunner| {
+        runner
+            .query(items_panel, |ctx| {
+                (0..3)
+                    .map(|index| ctx.child_from_index(index).entity())
+                    .collect::<Vec<Entity>>()
+            })
+            .unwrap()
+    };
+    let old_children = children(&mut runner);
+
+    *items.borrow_mut() = vec![String::from("c"), String::from("a"), String::from("d")];
+    runner
+        .query(items_widget, |ctx| ctx.widget().set("request_update", true))
+        .unwrap();
+    runner.update();
+
+    // the items with the keys c and a are moved, b is replaced by the new item d
+    let new_children = children(&mut runner);
+    assert_eq!(new_children[0], old_children[2]);
+    assert_eq!(new_children[1], old_children[0]);
+    assert!(!old_children.contains(&new_children[2]));
+    assert_eq!(text(&mut runner, new_children[2]), "d");
+}
+
+#[test]
+fn test_command() {
+    let count = Rc::new(Cell::new(0));
+    let executable = Rc::new(Cell::new(true));
+    let command = {
+        let count = count.clone();
+        let executable = executable.clone();
+
+        Command::new(move |_| count.set(count.get() + 1))
+            .can_execute(move |_| executable.get())
+            .shortcut("ctrl+s")
+    };
+    let mut runner = Application::new()
+        .window(move |ctx| {
+            Window::create()
+                .size(100.0, 100.0)
+                .commands(vec![command.clone()])
+                .child(Button::create().command(command.clone()).build(ctx))
+                .build(ctx)
+        })
+        .run_manual();
+    runner.update();
+    runner.update();
+
+    let root = runner.root();
+    let button = descendant(&mut runner, root, &[0]);
+    click(&mut runner, button);
+    assert_eq!(count.get(), 1);
+
+    // the shortcut is handled by the window
+    let key = |key, state| {
+        orbtk::shell::ShellEvent::KeyEvent(orbtk::shell::KeyEvent {
+            key,
+            state,
+            repeat: false,
+        })
+    };
+    runner.tick(vec![
+        key(orbtk::shell::Key::Control, orbtk::shell::ButtonState::Down),
+        key(orbtk::shell::Key::S(false), orbtk::shell::ButtonState::Down),
+    ]);
+    assert_eq!(count.get(), 2);
+
+    // the button is disabled while the command could not be executed
+    executable.set(false);
+    runner.update();
+    assert!(!runner
+        .query(button, |ctx| *ctx.widget().get::<bool>("enabled"))
+        .unwrap());
+
+    click(&mut runner, button);
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn test_list_view_keyboard_navigation() {
+    let selections = Rc::new(RefCell::new(vec![]));
+    let mut runner = {
+        let selections = selections.clone();
+
+        run(100.0, 100.0, move |ctx| {
+            let selections = selections.clone();
+
+            ListView::create()
+                .count(10)
+                .items_builder(|ctx, index| TextBlock::create().text(index.to_string()).build(ctx))
+                .on_selection_changed(move |_, indices| {
+                    selections.borrow_mut().push(indices.to_vec())
+                })
+                .build(ctx)
+        })
+    };
+    runner.update();
+
+    let root = runner.root();
+    let list_view = descendant(&mut runner, root, &[0]);
+    let items_panel = child_by_id(&mut runner, list_view, "items_panel");
+    let key = |key| {
+        orbtk::shell::ShellEvent::KeyEvent(orbtk::shell::KeyEvent {
+            key,
+            state: orbtk::shell::ButtonState::Down,
+            repeat: false,
+        })
+    };
+
+    // the click focuses the list and sets the current item
+    let item = descendant(&mut runner, items_panel, &[1]);
+    click(&mut runner, item);
+    runner.update();
+    assert_eq!(selections.borrow().last(), Some(&vec![1]));
+
+    runner.tick(vec![key(orbtk::shell::Key::Down)]);
+    runner.update();
+    assert_eq!(selections.borrow().last(), Some(&vec![2]));
+    assert_eq!(
+        runner
+            .query(list_view, |ctx| ctx
+                .widget()
+                .clone::<SelectedIndices>("selected_indices")
+                .0)
+            .unwrap(),
+        vec![2].into_iter().collect::<HashSet<usize>>()
+    );
+
+    // the last item is scrolled into the visible area
+    runner.tick(vec![key(orbtk::shell::Key::End)]);
+    runner.update();
+    runner.update();
+    assert_eq!(selections.borrow().last(), Some(&vec![9]));
+
+    let scroll_viewer = descendant(&mut runner, list_view, &[0, 0]);
+    assert!(
+        runner
+            .query(scroll_viewer, |ctx| ctx
+                .widget()
+                .clone::<Point>("scroll_offset"))
+            .unwrap()
+            .y
+            < 0.0
+    );
+}
+
+#[test]
+fn test_code_editor() {
+    let highlighted = Rc::new(RefCell::new(vec![]));
+    let mut runner = {
+        let highlighted = highlighted.clone();
+
+        run(300.0, 200.0, move |ctx| {
+            let highlighted = highlighted.clone();
+
+            CodeEditor::create()
+                .text("fn a() {\n}")
+                .highlighter(move |line| {
+                    highlighted.borrow_mut().push(line.to_string());
+                    vec![TextSpan::new(0, 2, "#c678dd")]
+                })
+                .build(ctx)
+        })
+    };
+    let root = runner.root();
+    let editor = descendant(&mut runner, root, &[0]);
+    let key = |key| {
+        orbtk::shell::ShellEvent::KeyEvent(orbtk::shell::KeyEvent {
+            key,
+            state: orbtk::shell::ButtonState::Down,
+            repeat: false,
+        })
+    };
+    assert_eq!(*highlighted.borrow(), vec!["fn a() {", "}"]);
+
+    // the click below the last line focuses the editor and moves the caret into the last line
+    click(&mut runner, editor);
+    runner.tick(vec![
+        key(orbtk::shell::Key::Up),
+        key(orbtk::shell::Key::End),
+    ]);
+    runner.tick(vec![key(orbtk::shell::Key::Enter)]);
+    runner.tick(vec![orbtk::shell::ShellEvent::TextInput(
+        "\tlet b".to_string(),
+    )]);
+    runner.tick(vec![orbtk::shell::ShellEvent::TextInput(";".to_string())]);
+    runner.update();
+
+    // the tab is inserted as spaces and the caret stays behind the typed text
+    assert_eq!(
+        runner
+            .query(editor, |ctx| ctx.widget().clone::<String>("text"))
+            .unwrap(),
+        "fn a() {\n    let b;\n}"
+    );
+
+    // only the new and changed lines are highlighted
+    assert_eq!(
+        *highlighted.borrow(),
+        vec!["fn a() {", "}", "", "    let b", "    let b;"]
+    );
+}
+
+#[test]
+fn test_auto_complete() {
+    let changes = Rc::new(Cell::new(0));
+    let mut runner = {
+        let changes = changes.clone();
+
+        run(300.0, 200.0, move |ctx| {
+            let changes = changes.clone();
+
+            AutoComplete::create()
+                .suggestions(vec![
+                    "Apple".to_string(),
+                    "Apricot".to_string(),
+                    "Banana".to_string(),
+                ])
+                .on_changed(move |_, _| changes.set(changes.get() + 1))
+                .build(ctx)
+        })
+    };
+    let root = runner.root();
+    let auto_complete = descendant(&mut runner, root, &[0]);
+    let key = |key| {
+        orbtk::shell::ShellEvent::KeyEvent(orbtk::shell::KeyEvent {
+            key,
+            state: orbtk::shell::ButtonState::Down,
+            repeat: false,
+        })
+    };
+
+    // the typed text opens the popup, the second suggestion is selected by the keyboard
+    click(&mut runner, auto_complete);
+    runner.tick(vec![orbtk::shell::ShellEvent::TextInput("ap".to_string())]);
+    runner.update();
+    runner.tick(vec![key(orbtk::shell::Key::Down)]);
+    runner.update();
+    runner.tick(vec![key(orbtk::shell::Key::Down)]);
+    runner.update();
+    runner.tick(vec![key(orbtk::shell::Key::Enter)]);
+    runner.update();
+
+    assert_eq!(text(&mut runner, auto_complete), "Apricot");
+    assert_eq!(changes.get(), 1);
+
+    // enter keeps a text without matching suggestions
+    runner.tick(vec![orbtk::shell::ShellEvent::TextInput("s".to_string())]);
+    runner.update();
+    runner.tick(vec![key(orbtk::shell::Key::Enter)]);
+    runner.update();
+
+    assert_eq!(text(&mut runner, auto_complete), "Apricots");
+    assert_eq!(changes.get(), 1);
+}
+
+#[test]
+fn test_pager() {
+    let changes = Rc::new(Cell::new(0));
+    let mut runner = {
+        let changes = changes.clone();
+
+        Application::new()
+            .window(move |ctx| {
+                let changes = changes.clone();
+
+                Window::create()
+                    .size(300.0, 200.0)
+                    .child(
+                        Pager::create()
+                            .page(TextBlock::create().text("first").build(ctx))
+                            .page(TextBlock::create().text("second").build(ctx))
+                            .page(TextBlock::create().text("third").build(ctx))
+                            .on_changed(move |_, _| changes.set(changes.get() + 1))
+                            .build(ctx),
+                    )
+                    .build(ctx)
+            })
+            .run_stepped()
+    };
+    runner.update();
+    runner.update();
+
+    let root = runner.root();
+    let pager = descendant(&mut runner, root, &[0]);
+    let pages: Vec<Entity> = (0..3)
+        .map(|index| descendant(&mut runner, pager, &[0, 0, index]))
+        .collect();
+    let page = |runner: &mut ManualRunner, index: usize| {
+        runner
+            .query(pages[index], |ctx| {
+                (
+                    ctx.widget().clone::<Visibility>("visibility"),
+                    ctx.widget().get::<Thickness>("margin").left(),
+                )
+            })
+            .unwrap()
+    };
+
+    // only the selected page is shown
+    assert_eq!(page(&mut runner, 0), (Visibility::Visible, 0.0));
+    assert_eq!(page(&mut runner, 1).0, Visibility::Collapsed);
+    assert_eq!(page(&mut runner, 2).0, Visibility::Collapsed);
+
+    // a tap on the last dot slides the last page in and the first page out
+    let dot = descendant(&mut runner, pager, &[0, 1, 2]);
+    click(&mut runner, dot);
+    runner.advance(Duration::from_millis(150));
+    runner.update();
+
+    let (old, new) = (page(&mut runner, 0), page(&mut runner, 2));
+    assert_eq!((old.0, new.0), (Visibility::Visible, Visibility::Visible));
+    assert!(old.1 < 0.0 && new.1 > 0.0);
+
+    runner.advance(Duration::from_millis(300));
+    runner.update();
+    assert_eq!(page(&mut runner, 0).0, Visibility::Collapsed);
+    assert_eq!(page(&mut runner, 2), (Visibility::Visible, 0.0));
+    assert!(runner
+        .query(dot, |ctx| ctx
+            .widget()
+            .get::<Selector>("selector")
+            .has_pseudo_class("selected"))
+        .unwrap());
+    assert_eq!(changes.get(), 1);
+
+    // a swipe to the right shows the previous page
+    let touch = |x, phase| {
+        orbtk::shell::ShellEvent::TouchEvent(orbtk::shell::TouchEvent {
+            id: 1,
+            x,
+            y: 50.0,
+            phase,
+        })
+    };
+    runner.tick(vec![touch(100.0, orbtk::shell::TouchPhase::Down)]);
+    runner.tick(vec![touch(200.0, orbtk::shell::TouchPhase::Move)]);
+    runner.tick(vec![touch(200.0, orbtk::shell::TouchPhase::Up)]);
+    runner.advance(Duration::from_secs(1));
+    runner.update();
+
+    assert_eq!(page(&mut runner, 1), (Visibility::Visible, 0.0));
+    assert_eq!(page(&mut runner, 2).0, Visibility::Collapsed);
+    assert_eq!(changes.get(), 2);
+}
+
+#[test]
+fn test_navigation_view() {
+    let events = Rc::new(RefCell::new(vec![]));
+    let mut runner = {
+        let events = events.clone();
+
+        Application::new()
+            .window(move |ctx| {
+                let enter_events = events.clone();
+                let leave_events = events.clone();
+
+                Window::create()
+                    .size(300.0, 200.0)
+                    .child(
+                        NavigationView::create()
+                            .screen("home", |ctx| TextBlock::create().text("home").build(ctx))
+                            .screen("settings", |ctx| {
+                                TextBlock::create().text("settings").build(ctx)
+                            })
+                            .start_screen("home")
+                            .on_enter(move |_, screen| {
+                                enter_events.borrow_mut().push(format!("enter {}", screen))
+                            })
+                            .on_leave(move |_, screen| {
+                                leave_events.borrow_mut().push(format!("leave {}", screen))
+                            })
+                            .build(ctx),
+                    )
+                    .build(ctx)
+            })
+            .run_stepped()
+    };
+    runner.update();
+    runner.update();
+
+    let root = runner.root();
+    let navigation_view = descendant(&mut runner, root, &[0]);
+    let panel = descendant(&mut runner, navigation_view, &[0]);
+    let status = |runner: &mut ManualRunner| {
+        runner
+            .query(navigation_view, |ctx| {
+                (
+                    ctx.widget().clone::<String>("current_screen"),
+                    *ctx.widget().get::<bool>("can_go_back"),
+                )
+            })
+            .unwrap()
+    };
+    let screen = |runner: &mut ManualRunner, index: usize| {
+        runner
+            .query(panel, move |ctx| {
+                ctx.try_child_from_index(index).map(|child| {
+                    (
+                        child.clone::<Visibility>("visibility"),
+                        child.get::<Thickness>("margin").left(),
+                    )
+                })
+            })
+            .unwrap()
+    };
+
+    assert_eq!(status(&mut runner), (String::from("home"), false));
+    assert_eq!(*events.borrow(), vec!["enter home"]);
+
+    // the pushed screen slides in and the old screen is hidden after the transition
+    runner
+        .query(navigation_view, |ctx| ctx.navigate("settings"))
+        .unwrap();
+    runner.update();
+    runner.advance(Duration::from_millis(100));
+    runner.update();
+
+    assert_eq!(status(&mut runner), (String::from("settings"), true));
+    let (visibility, offset) = screen(&mut runner, 1).unwrap();
+    assert_eq!(visibility, Visibility::Visible);
+    assert!(offset > 0.0);
+
+    runner.advance(Duration::from_secs(1));
+    runner.update();
+    assert_eq!(screen(&mut runner, 0).unwrap().0, Visibility::Collapsed);
+    assert_eq!(screen(&mut runner, 1), Some((Visibility::Visible, 0.0)));
+    assert_eq!(
+        *events.borrow(),
+        vec!["enter home", "leave home", "enter settings"]
+    );
+
+    // unknown screens are ignored
+    runner
+        .query(navigation_view, |ctx| ctx.navigate("unknown"))
+        .unwrap();
+    runner.update();
+    assert_eq!(status(&mut runner), (String::from("settings"), true));
+
+    // back removes the popped screen after the transition
+    runner
+        .query(navigation_view, |ctx| ctx.navigate_back())
+        .unwrap();
+    runner.update();
+    runner.advance(Duration::from_secs(1));
+    runner.update();
+
+    assert_eq!(status(&mut runner), (String::from("home"), false));
+    assert_eq!(screen(&mut runner, 0), Some((Visibility::Visible, 0.0)));
+    assert_eq!(screen(&mut runner, 1), None);
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            "enter home",
+            "leave home",
+            "enter settings",
+            "leave settings",
+            "enter home"
+        ]
+    );
+
+    // there is nothing below the first screen
+    runner
+        .query(navigation_view, |ctx| ctx.navigate_back())
+        .unwrap();
+    runner.update();
+    assert_eq!(status(&mut runner), (String::from("home"), false));
+}
+
+#[test]
+fn test_dialog() {
+    let clicks = Rc::new(Cell::new(0));
+    let mut runner = {
+        let clicks = clicks.clone();
+
+        run(300.0, 200.0, move |ctx| {
+            let clicks = clicks.clone();
+
+            Stack::create()
+                .child(TextBox::create().width(100.0).build(ctx))
+                .child(
+                    Button::create()
+                        .text("below")
+                        .on_click(move |_, _| {
+                            clicks.set(clicks.get() + 1);
+                            true
+                        })
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+    };
+    let root = runner.root();
+    let text_box = descendant(&mut runner, root, &[0, 0]);
+    let button = descendant(&mut runner, root, &[0, 1]);
+    let focused_widget = |runner: &mut ManualRunner| {
+        runner
+            .query(root, |ctx| {
+                ctx.window().get::<Global>("global").focused_widget
+            })
+            .unwrap()
+    };
+    let results = Arc::new(Mutex::new(vec![]));
+    let show_dialog = |runner: &mut ManualRunner| {
+        let results = results.clone();
+
+        runner
+            .query(root, move |ctx| {
+                ctx.show_dialog(
+                    |ctx| TextBlock::create().text("Save changes?").build(ctx),
+                    move |_, result: Option<String>| results.lock().unwrap().push(result),
+                )
+            })
+            .unwrap();
+        runner.update();
+        runner.update();
+    };
+
+    click(&mut runner, text_box);
+    assert_eq!(focused_widget(&mut runner), Some(text_box));
+
+    // the dialog takes the focus and blocks the input of the widgets below
+    show_dialog(&mut runner);
+    let overlay = descendant(&mut runner, root, &[1]);
+    let content = descendant(&mut runner, overlay, &[0, 0, 0, 0]);
+    assert_eq!(text(&mut runner, content), "Save changes?");
+    assert_eq!(focused_widget(&mut runner), None);
+
+    click(&mut runner, button);
+    assert_eq!(clicks.get(), 0);
+
+    // the result is passed to the callback and the focus is restored
+    runner
+        .query(content, |ctx| ctx.close_dialog(String::from("save")))
+        .unwrap();
+    runner.update();
+
+    assert_eq!(*results.lock().unwrap(), vec![Some(String::from("save"))]);
+    assert_eq!(focused_widget(&mut runner), Some(text_box));
+    let dialog_host = descendant(&mut runner, overlay, &[0]);
+    assert!(runner
+        .query(dialog_host, |ctx| ctx.try_child_from_index(0).is_none())
+        .unwrap());
+
+    click(&mut runner, button);
+    assert_eq!(clicks.get(), 1);
+
+    // escape cancels the dialog
+    show_dialog(&mut runner);
+    runner.tick(vec![orbtk::shell::ShellEvent::KeyEvent(
+        orbtk::shell::KeyEvent {
+            key: orbtk::shell::Key::Escape,
+            state: orbtk::shell::ButtonState::Down,
+            repeat: false,
+        },
+    )]);
+    runner.update();
+
+    assert_eq!(
+        *results.lock().unwrap(),
+        vec![Some(String::from("save")), None]
+    );
+}
+
+#[test]
+fn test_export() {
+    let mut runner = run(300.0, 200.0, |ctx| {
+        Stack::create()
+            .child(
+                Container::create()
+                    .width(100.0)
+                    .height(50.0)
+                    .background("#ff0000")
+                    .build(ctx),
+            )
+            .child(TextBlock::create().text("Report").build(ctx))
+            .build(ctx)
+    });
+    let root = runner.root();
+    let container = descendant(&mut runner, root, &[0, 0]);
+    let dir = env::temp_dir().join(format!("orbtk-export-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let results = Arc::new(Mutex::new(vec![]));
+    let export = |runner: &mut ManualRunner, path: std::path::PathBuf, entity: Entity| {
+        let results = results.clone();
+
+        runner
+            .query(root, move |ctx| {
+                ctx.export(
+                    ExportRequest::new(path)
+                        .entity(entity)
+                        .dpi(192.0)
+                        .on_finish(move |result| results.lock().unwrap().push(result)),
+                )
+            })
+            .unwrap();
+        runner.update();
+        runner.update();
+    };
+
+    // the widget is rendered with the given resolution
+    export(&mut runner, dir.join("container.png"), container);
+    assert_eq!(*results.lock().unwrap(), vec![Ok(())]);
+    let png = fs::read(dir.join("container.png")).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(&png[16..24], &[0, 0, 0, 200, 0, 0, 0, 100]);
+
+    // the pdf contains the drawing commands of the window
+    export(&mut runner, dir.join("window.pdf"), root);
+    let pdf = String::from_utf8(fs::read(dir.join("window.pdf")).unwrap()).unwrap();
+    assert!(pdf.starts_with("%PDF-"));
+    assert!(pdf.contains("/MediaBox [0 0 225 150]"));
+    assert!(pdf.contains("1 0 0 rg"));
+    assert!(pdf.contains("(Report) Tj"));
+
+    // unknown widgets are reported
+    export(&mut runner, dir.join("unknown.png"), Entity(9999));
+    assert!(results.lock().unwrap()[2].is_err());
+    assert!(!dir.join("unknown.png").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_ui_loader_id() {
+    let mut runner = run(200.0, 100.0, |ctx| {
+        UiLoader::new()
+            .build_ron(
+                "(type: \"Stack\", children: [(type: \"TextBlock\", id: \"loaded_label\", \
+                 properties: { \"text\": \"Loaded\" })])",
+                ctx,
+            )
+            .unwrap()
+    });
+    let root = runner.root();
+    let stack = descendant(&mut runner, root, &[0]);
+
+    // the id of the description is new to the symbol table and is found by `ctx.child`
+    let label = runner
+        .query(stack, |ctx| ctx.child("loaded_label").entity())
+        .unwrap();
+    assert_eq!(label, descendant(&mut runner, stack, &[0]));
+    assert_eq!(text(&mut runner, label), "Loaded");
+}